@@ -29,6 +29,25 @@ mod m20250716_151508_add_foreign_key;
 mod m20251116_190300_add_use_time_to_command_usage;
 mod m20260210_000000_remove_user_banner;
 mod m20260225_000000_create_oauth_token;
+mod m20260301_000000_create_leveling;
+mod m20260305_000000_voice_session;
+mod m20260310_000000_message_privacy;
+mod m20260315_000000_daily_stats_rollup;
+mod m20260320_000000_guild_timezone;
+mod m20260325_000000_minigame_economy;
+mod m20260401_000000_fishing_gear;
+mod m20260405_000000_trade;
+mod m20260410_000000_item_catalog;
+mod m20260415_000000_minigame_score;
+mod m20260420_000000_character_gacha;
+mod m20260425_000000_guess_anime;
+mod m20260430_000000_anisong_quiz;
+mod m20260505_000000_achievement;
+mod m20260510_000000_daily_streak;
+mod m20260515_000000_anilist_oauth;
+mod m20260520_000000_anilist_user_stats;
+mod m20260525_000000_airing_subscription;
+mod m20260530_000000_vocal_recorded_at;
 
 pub struct Migrator;
 
@@ -65,6 +84,25 @@ impl MigratorTrait for Migrator {
 			Box::new(m20251116_190300_add_use_time_to_command_usage::Migration),
 			Box::new(m20260210_000000_remove_user_banner::Migration),
 			Box::new(m20260225_000000_create_oauth_token::Migration),
+			Box::new(m20260301_000000_create_leveling::Migration),
+			Box::new(m20260305_000000_voice_session::Migration),
+			Box::new(m20260310_000000_message_privacy::Migration),
+			Box::new(m20260315_000000_daily_stats_rollup::Migration),
+			Box::new(m20260320_000000_guild_timezone::Migration),
+			Box::new(m20260325_000000_minigame_economy::Migration),
+			Box::new(m20260401_000000_fishing_gear::Migration),
+			Box::new(m20260405_000000_trade::Migration),
+			Box::new(m20260410_000000_item_catalog::Migration),
+			Box::new(m20260415_000000_minigame_score::Migration),
+			Box::new(m20260420_000000_character_gacha::Migration),
+			Box::new(m20260425_000000_guess_anime::Migration),
+			Box::new(m20260430_000000_anisong_quiz::Migration),
+			Box::new(m20260505_000000_achievement::Migration),
+			Box::new(m20260510_000000_daily_streak::Migration),
+			Box::new(m20260515_000000_anilist_oauth::Migration),
+			Box::new(m20260520_000000_anilist_user_stats::Migration),
+			Box::new(m20260525_000000_airing_subscription::Migration),
+			Box::new(m20260530_000000_vocal_recorded_at::Migration),
 		]
 	}
 }
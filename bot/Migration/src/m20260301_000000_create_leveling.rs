@@ -0,0 +1,133 @@
+use crate::m20240815_180201_user_data::UserData;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(GuildLevelConfig::Table)
+					.if_not_exists()
+					.col(string(GuildLevelConfig::GuildId).primary_key())
+					.col(integer(GuildLevelConfig::MessageXp).default(15))
+					.col(integer(GuildLevelConfig::VoiceXpPerMinute).default(10))
+					.col(integer(GuildLevelConfig::MessageCooldownSecs).default(60))
+					.col(integer(GuildLevelConfig::CurveBase).default(100))
+					.col(double(GuildLevelConfig::CurveExponent).default(1.5))
+					.col(boolean(GuildLevelConfig::AnnounceLevelUp).default(true))
+					.col(string_null(GuildLevelConfig::LevelUpChannelId))
+					.col(timestamp(GuildLevelConfig::UpdatedAt).default(Expr::current_timestamp()))
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(LevelReward::Table)
+					.if_not_exists()
+					.col(string(LevelReward::GuildId))
+					.col(string(LevelReward::RoleId))
+					.col(integer(LevelReward::Level))
+					.primary_key(
+						Index::create()
+							.col(LevelReward::GuildId)
+							.col(LevelReward::RoleId),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(UserLevel::Table)
+					.if_not_exists()
+					.col(string(UserLevel::GuildId))
+					.col(string(UserLevel::UserId))
+					.col(big_integer(UserLevel::Xp).default(0))
+					.col(integer(UserLevel::Level).default(0))
+					.col(big_integer(UserLevel::MessageXp).default(0))
+					.col(big_integer(UserLevel::VoiceXp).default(0))
+					.col(timestamp_null(UserLevel::LastMessageXpAt))
+					.col(timestamp(UserLevel::UpdatedAt).default(Expr::current_timestamp()))
+					.primary_key(
+						Index::create()
+							.col(UserLevel::GuildId)
+							.col(UserLevel::UserId),
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_user_level_user")
+							.from(UserLevel::Table, UserLevel::UserId)
+							.to(UserData::Table, UserData::UserId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_user_level_guild_xp")
+					.table(UserLevel::Table)
+					.col(UserLevel::GuildId)
+					.col(UserLevel::Xp)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(UserLevel::Table).to_owned())
+			.await?;
+		manager
+			.drop_table(Table::drop().table(LevelReward::Table).to_owned())
+			.await?;
+		manager
+			.drop_table(Table::drop().table(GuildLevelConfig::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+pub enum GuildLevelConfig {
+	Table,
+	GuildId,
+	MessageXp,
+	VoiceXpPerMinute,
+	MessageCooldownSecs,
+	CurveBase,
+	CurveExponent,
+	AnnounceLevelUp,
+	LevelUpChannelId,
+	UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum LevelReward {
+	Table,
+	GuildId,
+	RoleId,
+	Level,
+}
+
+#[derive(DeriveIden)]
+pub enum UserLevel {
+	Table,
+	GuildId,
+	UserId,
+	Xp,
+	Level,
+	MessageXp,
+	VoiceXp,
+	LastMessageXpAt,
+	UpdatedAt,
+}
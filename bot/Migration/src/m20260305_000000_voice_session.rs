@@ -0,0 +1,124 @@
+use crate::m20240815_180201_user_data::UserData;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(VoiceSession::Table)
+					.if_not_exists()
+					.col(string(VoiceSession::GuildId))
+					.col(string(VoiceSession::UserId))
+					.col(string(VoiceSession::ChannelId))
+					.col(timestamp(VoiceSession::StartedAt))
+					.col(timestamp(VoiceSession::StateSince))
+					.col(timestamp(VoiceSession::LastSeenAt))
+					.col(boolean(VoiceSession::Muted).default(false))
+					.col(boolean(VoiceSession::Deafened).default(false))
+					.col(boolean(VoiceSession::Afk).default(false))
+					.col(integer(VoiceSession::MutedSecs).default(0))
+					.col(integer(VoiceSession::DeafenedSecs).default(0))
+					.col(integer(VoiceSession::AfkSecs).default(0))
+					.col(integer(VoiceSession::IdleSecs).default(0))
+					.primary_key(
+						Index::create()
+							.col(VoiceSession::GuildId)
+							.col(VoiceSession::UserId),
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_voice_session_user")
+							.from(VoiceSession::Table, VoiceSession::UserId)
+							.to(UserData::Table, UserData::UserId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		// SQLite only supports one column per ALTER TABLE
+		manager
+			.alter_table(
+				Table::alter()
+					.table(Vocal::Table)
+					.add_column(string_null(Vocal::GuildId))
+					.to_owned(),
+			)
+			.await?;
+
+		for column in [
+			Vocal::MutedSecs,
+			Vocal::DeafenedSecs,
+			Vocal::AfkSecs,
+			Vocal::IdleSecs,
+		] {
+			manager
+				.alter_table(
+					Table::alter()
+						.table(Vocal::Table)
+						.add_column(integer(column).default(0))
+						.to_owned(),
+				)
+				.await?;
+		}
+
+		Ok(())
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		for column in [
+			Vocal::GuildId,
+			Vocal::MutedSecs,
+			Vocal::DeafenedSecs,
+			Vocal::AfkSecs,
+			Vocal::IdleSecs,
+		] {
+			manager
+				.alter_table(
+					Table::alter()
+						.table(Vocal::Table)
+						.drop_column(column)
+						.to_owned(),
+				)
+				.await?;
+		}
+
+		manager
+			.drop_table(Table::drop().table(VoiceSession::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+pub enum VoiceSession {
+	Table,
+	GuildId,
+	UserId,
+	ChannelId,
+	StartedAt,
+	StateSince,
+	LastSeenAt,
+	Muted,
+	Deafened,
+	Afk,
+	MutedSecs,
+	DeafenedSecs,
+	AfkSecs,
+	IdleSecs,
+}
+
+#[derive(DeriveIden)]
+enum Vocal {
+	Table,
+	GuildId,
+	MutedSecs,
+	DeafenedSecs,
+	AfkSecs,
+	IdleSecs,
+}
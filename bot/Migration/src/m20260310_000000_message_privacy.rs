@@ -0,0 +1,155 @@
+use crate::m20240815_180201_user_data::UserData;
+use sea_orm_migration::sea_orm::DatabaseBackend;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(GuildMessageTracking::Table)
+					.if_not_exists()
+					.col(string(GuildMessageTracking::GuildId).primary_key())
+					.col(boolean(GuildMessageTracking::StoreContent).default(false))
+					.col(
+						timestamp(GuildMessageTracking::UpdatedAt)
+							.default(Expr::current_timestamp()),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(UserMessageTracking::Table)
+					.if_not_exists()
+					.col(string(UserMessageTracking::UserId).primary_key())
+					.col(boolean(UserMessageTracking::StoreContent).default(false))
+					.col(
+						timestamp(UserMessageTracking::UpdatedAt)
+							.default(Expr::current_timestamp()),
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_user_message_tracking_user")
+							.from(UserMessageTracking::Table, UserMessageTracking::UserId)
+							.to(UserData::Table, UserData::UserId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		// SQLite only supports one column per ALTER TABLE
+		manager
+			.alter_table(
+				Table::alter()
+					.table(Message::Table)
+					.add_column(string_null(Message::GuildId))
+					.to_owned(),
+			)
+			.await?;
+		// SQLite refuses a non-constant default when adding a column. Existing
+		// rows are backfilled below and the bot sets the time of new ones
+		manager
+			.alter_table(
+				Table::alter()
+					.table(Message::Table)
+					.add_column(timestamp(Message::CreatedAt).default("1970-01-01 00:00:00"))
+					.to_owned(),
+			)
+			.await?;
+
+		// Existing rows get their real send time back from the message snowflake
+		let db = manager.get_connection();
+		match manager.get_database_backend() {
+			DatabaseBackend::Postgres => {
+				db.execute_unprepared(
+					"UPDATE message SET created_at = to_timestamp(((id::bigint >> 22) + \
+					 1420070400000) / 1000.0) AT TIME ZONE 'UTC'",
+				)
+				.await?;
+			},
+			DatabaseBackend::Sqlite => {
+				db.execute_unprepared(
+					"UPDATE message SET created_at = datetime(((CAST(id AS INTEGER) >> 22) + \
+					 1420070400000) / 1000, 'unixepoch')",
+				)
+				.await?;
+			},
+			_ => {},
+		}
+
+		// Message bodies were stored without consent, drop them all
+		db.execute_unprepared("UPDATE message SET data = ''")
+			.await?;
+
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_message_created_at")
+					.table(Message::Table)
+					.col(Message::CreatedAt)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_index(
+				Index::drop()
+					.name("idx_message_created_at")
+					.table(Message::Table)
+					.to_owned(),
+			)
+			.await?;
+
+		for column in [Message::GuildId, Message::CreatedAt] {
+			manager
+				.alter_table(
+					Table::alter()
+						.table(Message::Table)
+						.drop_column(column)
+						.to_owned(),
+				)
+				.await?;
+		}
+
+		manager
+			.drop_table(Table::drop().table(UserMessageTracking::Table).to_owned())
+			.await?;
+		manager
+			.drop_table(Table::drop().table(GuildMessageTracking::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+pub enum GuildMessageTracking {
+	Table,
+	GuildId,
+	StoreContent,
+	UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum UserMessageTracking {
+	Table,
+	UserId,
+	StoreContent,
+	UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Message {
+	Table,
+	GuildId,
+	CreatedAt,
+}
@@ -0,0 +1,177 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(MessageDailyStats::Table)
+					.if_not_exists()
+					.col(string(MessageDailyStats::GuildId))
+					.col(string(MessageDailyStats::UserId))
+					.col(string(MessageDailyStats::ChannelId))
+					.col(date(MessageDailyStats::Day))
+					.col(big_integer(MessageDailyStats::MessageCount).default(0))
+					.col(big_integer(MessageDailyStats::TotalLength).default(0))
+					.primary_key(
+						Index::create()
+							.col(MessageDailyStats::GuildId)
+							.col(MessageDailyStats::UserId)
+							.col(MessageDailyStats::ChannelId)
+							.col(MessageDailyStats::Day),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(VocalDailyStats::Table)
+					.if_not_exists()
+					.col(string(VocalDailyStats::GuildId))
+					.col(string(VocalDailyStats::UserId))
+					.col(string(VocalDailyStats::ChannelId))
+					.col(date(VocalDailyStats::Day))
+					.col(big_integer(VocalDailyStats::SessionCount).default(0))
+					.col(big_integer(VocalDailyStats::DurationSecs).default(0))
+					.col(big_integer(VocalDailyStats::IdleSecs).default(0))
+					.primary_key(
+						Index::create()
+							.col(VocalDailyStats::GuildId)
+							.col(VocalDailyStats::UserId)
+							.col(VocalDailyStats::ChannelId)
+							.col(VocalDailyStats::Day),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_message_daily_stats_user_day")
+					.table(MessageDailyStats::Table)
+					.col(MessageDailyStats::UserId)
+					.col(MessageDailyStats::Day)
+					.to_owned(),
+			)
+			.await?;
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_message_daily_stats_day")
+					.table(MessageDailyStats::Table)
+					.col(MessageDailyStats::Day)
+					.to_owned(),
+			)
+			.await?;
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_vocal_daily_stats_user_day")
+					.table(VocalDailyStats::Table)
+					.col(VocalDailyStats::UserId)
+					.col(VocalDailyStats::Day)
+					.to_owned(),
+			)
+			.await?;
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_vocal_daily_stats_day")
+					.table(VocalDailyStats::Table)
+					.col(VocalDailyStats::Day)
+					.to_owned(),
+			)
+			.await?;
+
+		// Raw rows are read by user for the days not rolled up yet
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_message_user_created_at")
+					.table(Message::Table)
+					.col(Message::UserId)
+					.col(Message::CreatedAt)
+					.to_owned(),
+			)
+			.await?;
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_vocal_user_start")
+					.table(Vocal::Table)
+					.col(Vocal::UserId)
+					.col(Vocal::Start)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_index(
+				Index::drop()
+					.name("idx_vocal_user_start")
+					.table(Vocal::Table)
+					.to_owned(),
+			)
+			.await?;
+		manager
+			.drop_index(
+				Index::drop()
+					.name("idx_message_user_created_at")
+					.table(Message::Table)
+					.to_owned(),
+			)
+			.await?;
+		manager
+			.drop_table(Table::drop().table(VocalDailyStats::Table).to_owned())
+			.await?;
+		manager
+			.drop_table(Table::drop().table(MessageDailyStats::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+pub enum MessageDailyStats {
+	Table,
+	GuildId,
+	UserId,
+	ChannelId,
+	Day,
+	MessageCount,
+	TotalLength,
+}
+
+#[derive(DeriveIden)]
+pub enum VocalDailyStats {
+	Table,
+	GuildId,
+	UserId,
+	ChannelId,
+	Day,
+	SessionCount,
+	DurationSecs,
+	IdleSecs,
+}
+
+#[derive(DeriveIden)]
+enum Message {
+	Table,
+	UserId,
+	CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Vocal {
+	Table,
+	UserId,
+	Start,
+}
@@ -0,0 +1,35 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(GuildTimezone::Table)
+					.if_not_exists()
+					.col(string(GuildTimezone::GuildId).primary_key())
+					.col(string(GuildTimezone::Timezone).default("UTC"))
+					.col(timestamp(GuildTimezone::UpdatedAt).default(Expr::current_timestamp()))
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(GuildTimezone::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+pub enum GuildTimezone {
+	Table,
+	GuildId,
+	Timezone,
+	UpdatedAt,
+}
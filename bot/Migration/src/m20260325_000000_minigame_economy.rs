@@ -0,0 +1,100 @@
+use crate::m20240815_180201_user_data::UserData;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(Wallet::Table)
+					.if_not_exists()
+					.col(string(Wallet::GuildId))
+					.col(string(Wallet::UserId))
+					.col(big_integer(Wallet::Balance).default(0))
+					.col(timestamp(Wallet::UpdatedAt).default(Expr::current_timestamp()))
+					.primary_key(Index::create().col(Wallet::GuildId).col(Wallet::UserId))
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_wallet_user")
+							.from(Wallet::Table, Wallet::UserId)
+							.to(UserData::Table, UserData::UserId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(WalletLedger::Table)
+					.if_not_exists()
+					.col(string(WalletLedger::Id).primary_key())
+					.col(string(WalletLedger::GuildId))
+					.col(string(WalletLedger::UserId))
+					.col(big_integer(WalletLedger::Amount))
+					.col(big_integer(WalletLedger::BalanceAfter))
+					.col(string(WalletLedger::Reason))
+					.col(string_null(WalletLedger::Reference))
+					.col(timestamp(WalletLedger::CreatedAt).default(Expr::current_timestamp()))
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_wallet_ledger_user")
+							.from(WalletLedger::Table, WalletLedger::UserId)
+							.to(UserData::Table, UserData::UserId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_wallet_ledger_member_created_at")
+					.table(WalletLedger::Table)
+					.col(WalletLedger::GuildId)
+					.col(WalletLedger::UserId)
+					.col(WalletLedger::CreatedAt)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(WalletLedger::Table).to_owned())
+			.await?;
+		manager
+			.drop_table(Table::drop().table(Wallet::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+pub enum Wallet {
+	Table,
+	GuildId,
+	UserId,
+	Balance,
+	UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum WalletLedger {
+	Table,
+	Id,
+	GuildId,
+	UserId,
+	Amount,
+	BalanceAfter,
+	Reason,
+	Reference,
+	CreatedAt,
+}
@@ -0,0 +1,123 @@
+use crate::m20240815_180201_user_data::UserData;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		// SQLite only supports one column per ALTER TABLE
+		manager
+			.alter_table(
+				Table::alter()
+					.table(Item::Table)
+					.add_column(text_null(Item::Properties))
+					.to_owned(),
+			)
+			.await?;
+		manager
+			.alter_table(
+				Table::alter()
+					.table(UserInventory::Table)
+					.add_column(integer_null(UserInventory::Durability))
+					.to_owned(),
+			)
+			.await?;
+		manager
+			.alter_table(
+				Table::alter()
+					.table(UserLevel::Table)
+					.add_column(big_integer(UserLevel::MinigameXp).default(0))
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(FishingState::Table)
+					.if_not_exists()
+					.col(string(FishingState::GuildId))
+					.col(string(FishingState::UserId))
+					.col(timestamp_null(FishingState::LastCastAt))
+					.col(string_null(FishingState::RodItemId))
+					.col(string_null(FishingState::BaitItemId))
+					.col(timestamp(FishingState::UpdatedAt).default(Expr::current_timestamp()))
+					.primary_key(
+						Index::create()
+							.col(FishingState::GuildId)
+							.col(FishingState::UserId),
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_fishing_state_user")
+							.from(FishingState::Table, FishingState::UserId)
+							.to(UserData::Table, UserData::UserId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(FishingState::Table).to_owned())
+			.await?;
+		manager
+			.alter_table(
+				Table::alter()
+					.table(UserLevel::Table)
+					.drop_column(UserLevel::MinigameXp)
+					.to_owned(),
+			)
+			.await?;
+		manager
+			.alter_table(
+				Table::alter()
+					.table(UserInventory::Table)
+					.drop_column(UserInventory::Durability)
+					.to_owned(),
+			)
+			.await?;
+		manager
+			.alter_table(
+				Table::alter()
+					.table(Item::Table)
+					.drop_column(Item::Properties)
+					.to_owned(),
+			)
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+pub enum FishingState {
+	Table,
+	GuildId,
+	UserId,
+	LastCastAt,
+	RodItemId,
+	BaitItemId,
+	UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Item {
+	Table,
+	Properties,
+}
+
+#[derive(DeriveIden)]
+enum UserInventory {
+	Table,
+	Durability,
+}
+
+#[derive(DeriveIden)]
+enum UserLevel {
+	Table,
+	MinigameXp,
+}
@@ -0,0 +1,143 @@
+use crate::m20240815_180201_user_data::UserData;
+use crate::m20250712_120900_create_item::Item;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(Trade::Table)
+					.if_not_exists()
+					.col(string(Trade::Id).primary_key())
+					.col(string(Trade::GuildId))
+					.col(string(Trade::InitiatorId))
+					.col(string(Trade::PartnerId))
+					.col(string(Trade::Status))
+					.col(big_integer(Trade::InitiatorCoins).default(0))
+					.col(big_integer(Trade::PartnerCoins).default(0))
+					.col(boolean(Trade::InitiatorConfirmed).default(false))
+					.col(boolean(Trade::PartnerConfirmed).default(false))
+					.col(integer(Trade::Version).default(0))
+					.col(string_null(Trade::ChannelId))
+					.col(string_null(Trade::MessageId))
+					.col(timestamp(Trade::CreatedAt).default(Expr::current_timestamp()))
+					.col(timestamp(Trade::UpdatedAt).default(Expr::current_timestamp()))
+					.col(timestamp_null(Trade::ClosedAt))
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_trade_initiator")
+							.from(Trade::Table, Trade::InitiatorId)
+							.to(UserData::Table, UserData::UserId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_trade_partner")
+							.from(Trade::Table, Trade::PartnerId)
+							.to(UserData::Table, UserData::UserId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(TradeItem::Table)
+					.if_not_exists()
+					.col(string(TradeItem::TradeId))
+					.col(string(TradeItem::EntryId))
+					.col(string(TradeItem::UserId))
+					.col(string(TradeItem::ItemId))
+					.primary_key(
+						Index::create()
+							.col(TradeItem::TradeId)
+							.col(TradeItem::EntryId),
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_trade_item_trade")
+							.from(TradeItem::Table, TradeItem::TradeId)
+							.to(Trade::Table, Trade::Id)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_trade_item_item")
+							.from(TradeItem::Table, TradeItem::ItemId)
+							.to(Item::Table, Item::ItemId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_trade_guild_status")
+					.table(Trade::Table)
+					.col(Trade::GuildId)
+					.col(Trade::Status)
+					.to_owned(),
+			)
+			.await?;
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_trade_item_entry")
+					.table(TradeItem::Table)
+					.col(TradeItem::EntryId)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(TradeItem::Table).to_owned())
+			.await?;
+		manager
+			.drop_table(Table::drop().table(Trade::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+pub enum Trade {
+	Table,
+	Id,
+	GuildId,
+	InitiatorId,
+	PartnerId,
+	Status,
+	InitiatorCoins,
+	PartnerCoins,
+	InitiatorConfirmed,
+	PartnerConfirmed,
+	Version,
+	ChannelId,
+	MessageId,
+	CreatedAt,
+	UpdatedAt,
+	ClosedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum TradeItem {
+	Table,
+	TradeId,
+	EntryId,
+	UserId,
+	ItemId,
+}
@@ -0,0 +1,68 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.alter_table(
+				Table::alter()
+					.table(Item::Table)
+					.add_column(timestamp_null(Item::RetiredAt))
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(ItemCatalogSync::Table)
+					.if_not_exists()
+					.col(string(ItemCatalogSync::Id).primary_key())
+					.col(integer(ItemCatalogSync::Version))
+					.col(integer(ItemCatalogSync::Added))
+					.col(integer(ItemCatalogSync::Updated))
+					.col(integer(ItemCatalogSync::Retired))
+					.col(integer(ItemCatalogSync::Restored))
+					.col(text(ItemCatalogSync::Diff))
+					.col(timestamp(ItemCatalogSync::AppliedAt).default(Expr::current_timestamp()))
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(ItemCatalogSync::Table).to_owned())
+			.await?;
+		manager
+			.alter_table(
+				Table::alter()
+					.table(Item::Table)
+					.drop_column(Item::RetiredAt)
+					.to_owned(),
+			)
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+pub enum ItemCatalogSync {
+	Table,
+	Id,
+	Version,
+	Added,
+	Updated,
+	Retired,
+	Restored,
+	Diff,
+	AppliedAt,
+}
+
+#[derive(DeriveIden)]
+enum Item {
+	Table,
+	RetiredAt,
+}
@@ -0,0 +1,63 @@
+use crate::m20240815_180201_user_data::UserData;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		// leader_board keeps the all-time totals, this keeps every score for
+		// windowed leaderboards. Columns match leader_board on purpose.
+		manager
+			.create_table(
+				Table::create()
+					.table(MinigameScore::Table)
+					.if_not_exists()
+					.col(string(MinigameScore::Id).primary_key())
+					.col(string(MinigameScore::UserId))
+					.col(string(MinigameScore::MinigameType))
+					.col(string(MinigameScore::ServerId))
+					.col(integer(MinigameScore::Points))
+					.col(timestamp(MinigameScore::CreatedAt).default(Expr::current_timestamp()))
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_minigame_score_user")
+							.from(MinigameScore::Table, MinigameScore::UserId)
+							.to(UserData::Table, UserData::UserId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_minigame_score_created_at")
+					.table(MinigameScore::Table)
+					.col(MinigameScore::CreatedAt)
+					.col(MinigameScore::ServerId)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(MinigameScore::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+pub enum MinigameScore {
+	Table,
+	Id,
+	UserId,
+	MinigameType,
+	ServerId,
+	Points,
+	CreatedAt,
+}
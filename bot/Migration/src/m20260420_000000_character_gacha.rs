@@ -0,0 +1,207 @@
+use crate::m20240815_180201_user_data::UserData;
+use crate::m20260405_000000_trade::Trade;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		// Filled by the worker from AniList so rolls never wait on the API
+		manager
+			.create_table(
+				Table::create()
+					.table(CharacterPool::Table)
+					.if_not_exists()
+					.col(integer(CharacterPool::CharacterId).primary_key())
+					.col(string(CharacterPool::Name))
+					.col(string_null(CharacterPool::NativeName))
+					.col(string_null(CharacterPool::Series))
+					.col(string_null(CharacterPool::ImageUrl))
+					.col(string(CharacterPool::SiteUrl))
+					.col(integer(CharacterPool::Favourites))
+					.col(integer(CharacterPool::Rarity))
+					.col(timestamp(CharacterPool::UpdatedAt).default(Expr::current_timestamp()))
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(GachaState::Table)
+					.if_not_exists()
+					.col(string(GachaState::GuildId))
+					.col(string(GachaState::UserId))
+					.col(timestamp_null(GachaState::LastFreeRollAt))
+					.col(timestamp(GachaState::UpdatedAt).default(Expr::current_timestamp()))
+					.primary_key(
+						Index::create()
+							.col(GachaState::GuildId)
+							.col(GachaState::UserId),
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_gacha_state_user")
+							.from(GachaState::Table, GachaState::UserId)
+							.to(UserData::Table, UserData::UserId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(UserCharacter::Table)
+					.if_not_exists()
+					.col(string(UserCharacter::Id).primary_key())
+					.col(string(UserCharacter::UserId))
+					.col(string(UserCharacter::ServerId))
+					.col(integer(UserCharacter::CharacterId))
+					.col(timestamp(UserCharacter::ObtainedAt).default(Expr::current_timestamp()))
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_user_character_user")
+							.from(UserCharacter::Table, UserCharacter::UserId)
+							.to(UserData::Table, UserData::UserId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_user_character_character")
+							.from(UserCharacter::Table, UserCharacter::CharacterId)
+							.to(CharacterPool::Table, CharacterPool::CharacterId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		// Cards offered in a trade, the counterpart of trade_item
+		manager
+			.create_table(
+				Table::create()
+					.table(TradeCard::Table)
+					.if_not_exists()
+					.col(string(TradeCard::TradeId))
+					.col(string(TradeCard::CardId))
+					.col(string(TradeCard::UserId))
+					.col(integer(TradeCard::CharacterId))
+					.primary_key(
+						Index::create()
+							.col(TradeCard::TradeId)
+							.col(TradeCard::CardId),
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_trade_card_trade")
+							.from(TradeCard::Table, TradeCard::TradeId)
+							.to(Trade::Table, Trade::Id)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_trade_card_character")
+							.from(TradeCard::Table, TradeCard::CharacterId)
+							.to(CharacterPool::Table, CharacterPool::CharacterId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_character_pool_rarity")
+					.table(CharacterPool::Table)
+					.col(CharacterPool::Rarity)
+					.to_owned(),
+			)
+			.await?;
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_user_character_owner")
+					.table(UserCharacter::Table)
+					.col(UserCharacter::ServerId)
+					.col(UserCharacter::UserId)
+					.to_owned(),
+			)
+			.await?;
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_trade_card_card")
+					.table(TradeCard::Table)
+					.col(TradeCard::CardId)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(TradeCard::Table).to_owned())
+			.await?;
+		manager
+			.drop_table(Table::drop().table(UserCharacter::Table).to_owned())
+			.await?;
+		manager
+			.drop_table(Table::drop().table(GachaState::Table).to_owned())
+			.await?;
+		manager
+			.drop_table(Table::drop().table(CharacterPool::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+pub enum CharacterPool {
+	Table,
+	CharacterId,
+	Name,
+	NativeName,
+	Series,
+	ImageUrl,
+	SiteUrl,
+	Favourites,
+	Rarity,
+	UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum GachaState {
+	Table,
+	GuildId,
+	UserId,
+	LastFreeRollAt,
+	UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum UserCharacter {
+	Table,
+	Id,
+	UserId,
+	ServerId,
+	CharacterId,
+	ObtainedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum TradeCard {
+	Table,
+	TradeId,
+	CardId,
+	UserId,
+	CharacterId,
+}
@@ -0,0 +1,71 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		// One row per round, so the buttons and the answer modal work across
+		// shards and restarts. `answers` holds every accepted title, one per
+		// line.
+		manager
+			.create_table(
+				Table::create()
+					.table(GuessRound::Table)
+					.if_not_exists()
+					.col(string(GuessRound::Id).primary_key())
+					.col(string(GuessRound::GuildId))
+					.col(string(GuessRound::ChannelId))
+					.col(integer(GuessRound::MediaId))
+					.col(string(GuessRound::Title))
+					.col(text(GuessRound::Answers))
+					.col(string(GuessRound::ImageUrl))
+					.col(string(GuessRound::SiteUrl))
+					.col(string(GuessRound::Difficulty))
+					.col(integer(GuessRound::Stage).default(0))
+					.col(string(GuessRound::StartedBy))
+					.col(string_null(GuessRound::SolvedBy))
+					.col(timestamp(GuessRound::CreatedAt).default(Expr::current_timestamp()))
+					.col(timestamp_null(GuessRound::FinishedAt))
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_guess_round_channel")
+					.table(GuessRound::Table)
+					.col(GuessRound::ChannelId)
+					.col(GuessRound::FinishedAt)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(GuessRound::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+pub enum GuessRound {
+	Table,
+	Id,
+	GuildId,
+	ChannelId,
+	MediaId,
+	Title,
+	Answers,
+	ImageUrl,
+	SiteUrl,
+	Difficulty,
+	Stage,
+	StartedBy,
+	SolvedBy,
+	CreatedAt,
+	FinishedAt,
+}
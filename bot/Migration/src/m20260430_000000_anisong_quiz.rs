@@ -0,0 +1,116 @@
+use crate::m20240815_180201_user_data::UserData;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		// The quiz row holds the current round, answer modals check against it
+		manager
+			.create_table(
+				Table::create()
+					.table(AnisongQuiz::Table)
+					.if_not_exists()
+					.col(string(AnisongQuiz::Id).primary_key())
+					.col(string(AnisongQuiz::GuildId))
+					.col(string(AnisongQuiz::ChannelId))
+					.col(string(AnisongQuiz::StartedBy))
+					.col(string(AnisongQuiz::Status))
+					.col(integer(AnisongQuiz::Rounds))
+					.col(integer(AnisongQuiz::Round).default(0))
+					.col(string_null(AnisongQuiz::SongName))
+					.col(string_null(AnisongQuiz::SongType))
+					.col(string_null(AnisongQuiz::AnimeName))
+					.col(text_null(AnisongQuiz::Answers))
+					.col(timestamp_null(AnisongQuiz::RoundStartedAt))
+					.col(string_null(AnisongQuiz::SolvedBy))
+					.col(timestamp(AnisongQuiz::CreatedAt).default(Expr::current_timestamp()))
+					.col(timestamp_null(AnisongQuiz::FinishedAt))
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(AnisongQuizPlayer::Table)
+					.if_not_exists()
+					.col(string(AnisongQuizPlayer::QuizId))
+					.col(string(AnisongQuizPlayer::UserId))
+					.col(integer(AnisongQuizPlayer::Points).default(0))
+					.primary_key(
+						Index::create()
+							.col(AnisongQuizPlayer::QuizId)
+							.col(AnisongQuizPlayer::UserId),
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_anisong_quiz_player_quiz")
+							.from(AnisongQuizPlayer::Table, AnisongQuizPlayer::QuizId)
+							.to(AnisongQuiz::Table, AnisongQuiz::Id)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_anisong_quiz_player_user")
+							.from(AnisongQuizPlayer::Table, AnisongQuizPlayer::UserId)
+							.to(UserData::Table, UserData::UserId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_anisong_quiz_guild_status")
+					.table(AnisongQuiz::Table)
+					.col(AnisongQuiz::GuildId)
+					.col(AnisongQuiz::Status)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(AnisongQuizPlayer::Table).to_owned())
+			.await?;
+		manager
+			.drop_table(Table::drop().table(AnisongQuiz::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+pub enum AnisongQuiz {
+	Table,
+	Id,
+	GuildId,
+	ChannelId,
+	StartedBy,
+	Status,
+	Rounds,
+	Round,
+	SongName,
+	SongType,
+	AnimeName,
+	Answers,
+	RoundStartedAt,
+	SolvedBy,
+	CreatedAt,
+	FinishedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum AnisongQuizPlayer {
+	Table,
+	QuizId,
+	UserId,
+	Points,
+}
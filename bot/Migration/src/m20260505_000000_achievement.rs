@@ -0,0 +1,91 @@
+use crate::m20240815_180201_user_data::UserData;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		// Running totals per stat, so unlocks never rescan the history
+		manager
+			.create_table(
+				Table::create()
+					.table(AchievementProgress::Table)
+					.if_not_exists()
+					.col(string(AchievementProgress::UserId))
+					.col(string(AchievementProgress::Stat))
+					.col(big_integer(AchievementProgress::Value).default(0))
+					.col(
+						timestamp(AchievementProgress::UpdatedAt)
+							.default(Expr::current_timestamp()),
+					)
+					.primary_key(
+						Index::create()
+							.col(AchievementProgress::UserId)
+							.col(AchievementProgress::Stat),
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_achievement_progress_user")
+							.from(AchievementProgress::Table, AchievementProgress::UserId)
+							.to(UserData::Table, UserData::UserId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(UserAchievement::Table)
+					.if_not_exists()
+					.col(string(UserAchievement::UserId))
+					.col(string(UserAchievement::AchievementId))
+					.col(timestamp(UserAchievement::UnlockedAt).default(Expr::current_timestamp()))
+					.primary_key(
+						Index::create()
+							.col(UserAchievement::UserId)
+							.col(UserAchievement::AchievementId),
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_user_achievement_user")
+							.from(UserAchievement::Table, UserAchievement::UserId)
+							.to(UserData::Table, UserData::UserId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(UserAchievement::Table).to_owned())
+			.await?;
+		manager
+			.drop_table(Table::drop().table(AchievementProgress::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+pub enum AchievementProgress {
+	Table,
+	UserId,
+	Stat,
+	Value,
+	UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum UserAchievement {
+	Table,
+	UserId,
+	AchievementId,
+	UnlockedAt,
+}
@@ -0,0 +1,61 @@
+use crate::m20240815_180201_user_data::UserData;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		// The last claim is stored as a day of the guild timezone, so the
+		// reset follows the guild's midnight
+		manager
+			.create_table(
+				Table::create()
+					.table(DailyStreak::Table)
+					.if_not_exists()
+					.col(string(DailyStreak::GuildId))
+					.col(string(DailyStreak::UserId))
+					.col(integer(DailyStreak::CurrentStreak).default(0))
+					.col(integer(DailyStreak::BestStreak).default(0))
+					.col(integer(DailyStreak::TotalClaims).default(0))
+					.col(date_null(DailyStreak::LastClaimDay))
+					.col(timestamp_null(DailyStreak::LastClaimAt))
+					.col(timestamp(DailyStreak::UpdatedAt).default(Expr::current_timestamp()))
+					.primary_key(
+						Index::create()
+							.col(DailyStreak::GuildId)
+							.col(DailyStreak::UserId),
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_daily_streak_user")
+							.from(DailyStreak::Table, DailyStreak::UserId)
+							.to(UserData::Table, UserData::UserId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(DailyStreak::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+pub enum DailyStreak {
+	Table,
+	GuildId,
+	UserId,
+	CurrentStreak,
+	BestStreak,
+	TotalClaims,
+	LastClaimDay,
+	LastClaimAt,
+	UpdatedAt,
+}
@@ -0,0 +1,103 @@
+use crate::m20240815_180201_user_data::UserData;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		// Links made with `register` stay unverified until the member logs in
+		// on AniList
+		manager
+			.alter_table(
+				Table::alter()
+					.table(RegisteredUser::Table)
+					.add_column(boolean(RegisteredUser::Verified).default(false))
+					.to_owned(),
+			)
+			.await?;
+
+		// The token is stored encrypted, AniList tokens last a year and have no
+		// refresh token
+		manager
+			.create_table(
+				Table::create()
+					.table(AnilistToken::Table)
+					.if_not_exists()
+					.col(string(AnilistToken::UserId).primary_key())
+					.col(integer(AnilistToken::AnilistId))
+					.col(text(AnilistToken::AccessToken))
+					.col(timestamp(AnilistToken::ExpiresAt))
+					.col(timestamp(AnilistToken::CreatedAt).default(Expr::current_timestamp()))
+					.col(timestamp(AnilistToken::UpdatedAt).default(Expr::current_timestamp()))
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_anilist_token_user")
+							.from(AnilistToken::Table, AnilistToken::UserId)
+							.to(UserData::Table, UserData::UserId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(GuildAnilistSetting::Table)
+					.if_not_exists()
+					.col(string(GuildAnilistSetting::GuildId).primary_key())
+					.col(boolean(GuildAnilistSetting::VerifiedOnly).default(false))
+					.col(
+						timestamp(GuildAnilistSetting::UpdatedAt)
+							.default(Expr::current_timestamp()),
+					)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(GuildAnilistSetting::Table).to_owned())
+			.await?;
+		manager
+			.drop_table(Table::drop().table(AnilistToken::Table).to_owned())
+			.await?;
+		manager
+			.alter_table(
+				Table::alter()
+					.table(RegisteredUser::Table)
+					.drop_column(RegisteredUser::Verified)
+					.to_owned(),
+			)
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+enum RegisteredUser {
+	Table,
+	Verified,
+}
+
+#[derive(DeriveIden)]
+pub enum AnilistToken {
+	Table,
+	UserId,
+	AnilistId,
+	AccessToken,
+	ExpiresAt,
+	CreatedAt,
+	UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum GuildAnilistSetting {
+	Table,
+	GuildId,
+	VerifiedOnly,
+	UpdatedAt,
+}
@@ -0,0 +1,47 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		// Filled by the worker for every registered AniList account, the
+		// server leaderboards only read it
+		manager
+			.create_table(
+				Table::create()
+					.table(AnilistUserStats::Table)
+					.if_not_exists()
+					.col(integer(AnilistUserStats::AnilistId).primary_key())
+					.col(string(AnilistUserStats::Name))
+					.col(big_integer(AnilistUserStats::MinutesWatched).default(0))
+					.col(big_integer(AnilistUserStats::ChaptersRead).default(0))
+					.col(double(AnilistUserStats::MeanScore).default(0.0))
+					.col(big_integer(AnilistUserStats::Completed).default(0))
+					.col(double(AnilistUserStats::Xp).default(0.0))
+					.col(timestamp(AnilistUserStats::UpdatedAt).default(Expr::current_timestamp()))
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(AnilistUserStats::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+pub enum AnilistUserStats {
+	Table,
+	AnilistId,
+	Name,
+	MinutesWatched,
+	ChaptersRead,
+	MeanScore,
+	Completed,
+	Xp,
+	UpdatedAt,
+}
@@ -0,0 +1,141 @@
+use crate::m20240815_180201_user_data::UserData;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		// Anime a member gets a DM for. The rows copied from an AniList list
+		// are marked so the next sync can remove them again, and muted rather
+		// than deleted when unfollowed so the sync does not bring them back
+		manager
+			.create_table(
+				Table::create()
+					.table(AiringSubscription::Table)
+					.if_not_exists()
+					.col(string(AiringSubscription::UserId))
+					.col(integer(AiringSubscription::AnimeId))
+					.col(boolean(AiringSubscription::FromList).default(false))
+					.col(boolean(AiringSubscription::Muted).default(false))
+					.col(
+						timestamp(AiringSubscription::CreatedAt).default(Expr::current_timestamp()),
+					)
+					.primary_key(
+						Index::create()
+							.col(AiringSubscription::UserId)
+							.col(AiringSubscription::AnimeId),
+					)
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_airing_subscription_user")
+							.from(AiringSubscription::Table, AiringSubscription::UserId)
+							.to(UserData::Table, UserData::UserId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		// The next episode of every followed anime, the worker polls it like
+		// `activity_data` and moves it forward once the DMs are sent
+		manager
+			.create_table(
+				Table::create()
+					.table(AiringEpisode::Table)
+					.if_not_exists()
+					.col(integer(AiringEpisode::AnimeId).primary_key())
+					.col(string(AiringEpisode::Name))
+					.col(integer(AiringEpisode::Episode))
+					.col(timestamp(AiringEpisode::AiringAt))
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(AiringDmSetting::Table)
+					.if_not_exists()
+					.col(string(AiringDmSetting::UserId).primary_key())
+					.col(boolean(AiringDmSetting::AutoFollow).default(false))
+					.col(boolean(AiringDmSetting::Muted).default(false))
+					.col(timestamp_null(AiringDmSetting::SnoozedUntil))
+					.col(timestamp_null(AiringDmSetting::WindowStart))
+					.col(integer(AiringDmSetting::WindowCount).default(0))
+					.foreign_key(
+						ForeignKey::create()
+							.name("FK_airing_dm_setting_user")
+							.from(AiringDmSetting::Table, AiringDmSetting::UserId)
+							.to(UserData::Table, UserData::UserId)
+							.on_delete(ForeignKeyAction::Cascade)
+							.on_update(ForeignKeyAction::Cascade),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_airing_subscription_anime")
+					.table(AiringSubscription::Table)
+					.col(AiringSubscription::AnimeId)
+					.to_owned(),
+			)
+			.await?;
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_airing_episode_airing_at")
+					.table(AiringEpisode::Table)
+					.col(AiringEpisode::AiringAt)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(AiringDmSetting::Table).to_owned())
+			.await?;
+		manager
+			.drop_table(Table::drop().table(AiringEpisode::Table).to_owned())
+			.await?;
+		manager
+			.drop_table(Table::drop().table(AiringSubscription::Table).to_owned())
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+pub enum AiringSubscription {
+	Table,
+	UserId,
+	AnimeId,
+	FromList,
+	Muted,
+	CreatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum AiringEpisode {
+	Table,
+	AnimeId,
+	Name,
+	Episode,
+	AiringAt,
+}
+
+#[derive(DeriveIden)]
+pub enum AiringDmSetting {
+	Table,
+	UserId,
+	AutoFollow,
+	Muted,
+	SnoozedUntil,
+	WindowStart,
+	WindowCount,
+}
@@ -0,0 +1,66 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		// Rollups bucket vocal rows by when they were written. A session is only
+		// written once closed, so its start or end can already lie in a day the
+		// worker rolled up. SQLite refuses a non-constant default when adding a
+		// column, the bot sets the time of every new row
+		manager
+			.alter_table(
+				Table::alter()
+					.table(Vocal::Table)
+					.add_column(timestamp(Vocal::RecordedAt).default("1970-01-01 00:00:00"))
+					.to_owned(),
+			)
+			.await?;
+
+		// Existing rows were written when their session closed
+		manager
+			.get_connection()
+			.execute_unprepared("UPDATE vocal SET recorded_at = \"end\"")
+			.await?;
+
+		manager
+			.create_index(
+				Index::create()
+					.name("idx_vocal_user_recorded_at")
+					.table(Vocal::Table)
+					.col(Vocal::UserId)
+					.col(Vocal::RecordedAt)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_index(
+				Index::drop()
+					.name("idx_vocal_user_recorded_at")
+					.table(Vocal::Table)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.alter_table(
+				Table::alter()
+					.table(Vocal::Table)
+					.drop_column(Vocal::RecordedAt)
+					.to_owned(),
+			)
+			.await
+	}
+}
+
+#[derive(DeriveIden)]
+enum Vocal {
+	Table,
+	UserId,
+	RecordedAt,
+}
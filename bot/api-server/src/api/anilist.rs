@@ -0,0 +1,396 @@
+//! Verified AniList linking.
+//!
+//! The bot gives a member a link token pointing at `/api/anilist/login`. The
+//! login checks the token, then has the browser log in with Discord: a
+//! forwarded link must not let someone else bind their AniList account to the
+//! member. Once `/api/anilist/discord` saw the member themselves, it remembers
+//! which Discord user the AniList redirect belongs to, the callback then
+//! exchanges the code, asks AniList who logged in and stores the link as
+//! verified. The member's `user_data` row is created by the bot before it
+//! issues the token.
+use axum::{
+	extract::{Query, State},
+	response::{Html, IntoResponse, Redirect, Response},
+};
+use chrono::{Duration, Utc};
+use sea_orm::sea_query::OnConflict;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, TransactionTrait};
+use serde::Deserialize;
+use shared::anilist::oauth::{discord_redirect_uri, verify_link_token};
+use shared::config::AnilistOAuthConfig;
+use shared::database::prelude::{AnilistToken, RegisteredUser};
+use shared::database::{anilist_token, registered_user};
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+use crate::api::error::AppError;
+use crate::api::oauth::{exchange_code_for_token, get_user_info};
+use crate::api::state::AppState;
+
+const DISCORD_AUTHORIZE_URL: &str = "https://discord.com/api/oauth2/authorize";
+const ANILIST_AUTHORIZE_URL: &str = "https://anilist.co/api/v2/oauth/authorize";
+const ANILIST_TOKEN_URL: &str = "https://anilist.co/api/v2/oauth/token";
+const ANILIST_GRAPHQL_URL: &str = "https://graphql.anilist.co";
+const VIEWER_QUERY: &str = "query { Viewer { id name } }";
+
+#[derive(Debug, Deserialize)]
+pub struct AnilistLoginQuery {
+	token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AnilistCallbackQuery {
+	code: Option<String>,
+	state: Option<String>,
+	error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AnilistTokenResponse {
+	pub access_token: String,
+	pub expires_in: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Viewer {
+	pub id: i32,
+	pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ViewerData {
+	#[serde(rename = "Viewer")]
+	viewer: Viewer,
+}
+
+#[derive(Debug, Deserialize)]
+struct ViewerResponse {
+	data: ViewerData,
+}
+
+fn anilist_config(state: &AppState) -> Result<&AnilistOAuthConfig, AppError> {
+	state
+		.config
+		.api
+		.anilist
+		.as_ref()
+		.ok_or_else(|| AppError::not_found("AniList linking is disabled"))
+}
+
+pub async fn anilist_login(
+	State(state): State<AppState>, Query(query): Query<AnilistLoginQuery>,
+) -> Result<Redirect, AppError> {
+	let anilist_config = anilist_config(&state)?;
+
+	let user_id = verify_link_token(&state.jwt_secret, &query.token).map_err(|e| {
+		warn!(error = %e, "invalid anilist link token");
+		AppError::unauthorized()
+	})?;
+
+	let identity_state = Uuid::new_v4().to_string();
+	state
+		.anilist_identity_states
+		.insert(identity_state.clone(), user_id)
+		.await;
+
+	let redirect_uri = discord_redirect_uri(anilist_config);
+	let params = vec![
+		(
+			"client_id",
+			state.config.api.oauth.discord_client_id.as_str(),
+		),
+		("redirect_uri", redirect_uri.as_str()),
+		("response_type", "code"),
+		("scope", "identify"),
+		("state", identity_state.as_str()),
+	];
+
+	let query_string = serde_urlencoded::to_string(&params)
+		.map_err(|e| AppError::internal(format!("Failed to encode OAuth query params: {}", e)))?;
+
+	debug!("redirecting to discord to confirm the anilist link owner");
+	Ok(Redirect::temporary(&format!(
+		"{}?{}",
+		DISCORD_AUTHORIZE_URL, query_string
+	)))
+}
+
+/// Discord sends the member back here once they logged in. Only the Discord
+/// user the link token was issued to is sent on to AniList.
+pub async fn anilist_discord_callback(
+	State(state): State<AppState>, Query(query): Query<AnilistCallbackQuery>,
+) -> Response {
+	if let Some(error) = query.error {
+		warn!(error = %error, "discord oauth error during anilist link");
+		return link_page("The Discord login was cancelled or refused.").into_response();
+	}
+
+	let user_id = match query.state {
+		Some(identity_state) => state.anilist_identity_states.remove(&identity_state).await,
+		None => None,
+	};
+	let Some(user_id) = user_id else {
+		warn!("anilist discord callback with a missing or expired state");
+		return link_page("This link has expired, ask the bot for a new one.").into_response();
+	};
+
+	let Some(code) = query.code else {
+		warn!("anilist discord callback missing authorization code");
+		return link_page("Discord did not send an authorization code.").into_response();
+	};
+
+	let discord_user = match confirm_discord_user(&state, &code).await {
+		Ok(discord_user) => discord_user,
+		Err(e) => {
+			error!(error = %e.message, user = %user_id, "discord login for anilist link failed");
+			return link_page("Your Discord account could not be checked, try again later.")
+				.into_response();
+		},
+	};
+
+	if discord_user != user_id {
+		warn!(user = %user_id, opened_by = %discord_user, "anilist link opened by another discord user");
+		return link_page(
+			"This link was made for another Discord account, ask the bot for your own link.",
+		)
+		.into_response();
+	}
+
+	match anilist_authorize_url(&state, user_id).await {
+		Ok(url) => {
+			debug!("redirecting to anilist oauth");
+			Redirect::temporary(&url).into_response()
+		},
+		Err(e) => e.into_response(),
+	}
+}
+
+/// The Discord user who logged in with `code`.
+async fn confirm_discord_user(state: &AppState, code: &str) -> Result<String, AppError> {
+	let redirect_uri = discord_redirect_uri(anilist_config(state)?);
+	let token_response = exchange_code_for_token(state, code, &redirect_uri).await?;
+	let user_info = get_user_info(&state.http_client, &token_response.access_token).await?;
+
+	Ok(user_info.id)
+}
+
+/// Start the AniList login of the Discord user `user_id`.
+async fn anilist_authorize_url(state: &AppState, user_id: String) -> Result<String, AppError> {
+	let anilist_config = anilist_config(state)?;
+
+	let csrf_state = Uuid::new_v4().to_string();
+	state
+		.anilist_states
+		.insert(csrf_state.clone(), user_id)
+		.await;
+
+	let params = vec![
+		("client_id", anilist_config.client_id.as_str()),
+		("redirect_uri", anilist_config.redirect_uri.as_str()),
+		("response_type", "code"),
+		("state", csrf_state.as_str()),
+	];
+
+	let query_string = serde_urlencoded::to_string(&params)
+		.map_err(|e| AppError::internal(format!("Failed to encode OAuth query params: {}", e)))?;
+
+	Ok(format!("{}?{}", ANILIST_AUTHORIZE_URL, query_string))
+}
+
+pub async fn anilist_callback(
+	State(state): State<AppState>, Query(query): Query<AnilistCallbackQuery>,
+) -> Response {
+	if let Some(error) = query.error {
+		warn!(error = %error, "anilist oauth error");
+		return link_page("The AniList login was cancelled or refused.").into_response();
+	}
+
+	let user_id = match query.state {
+		Some(csrf_state) => state.anilist_states.remove(&csrf_state).await,
+		None => None,
+	};
+	let Some(user_id) = user_id else {
+		warn!("anilist callback with a missing or expired state");
+		return link_page("This link has expired, ask the bot for a new one.").into_response();
+	};
+
+	let Some(code) = query.code else {
+		warn!("anilist callback missing authorization code");
+		return link_page("AniList did not send an authorization code.").into_response();
+	};
+
+	let viewer = match link_account(&state, &user_id, &code).await {
+		Ok(viewer) => viewer,
+		Err(e) => {
+			error!(error = %e.message, user = %user_id, "anilist link failed");
+			return link_page("The AniList account could not be linked, try again later.")
+				.into_response();
+		},
+	};
+
+	info!(user = %user_id, anilist_id = viewer.id, "anilist account verified");
+
+	link_page(&format!(
+		"Your Discord account is now linked to the AniList account {}. You can close this page.",
+		escape_html(&viewer.name)
+	))
+	.into_response()
+}
+
+async fn link_account(state: &AppState, user_id: &str, code: &str) -> Result<Viewer, AppError> {
+	let cipher = state
+		.anilist_cipher
+		.as_ref()
+		.ok_or_else(|| AppError::not_found("AniList linking is disabled"))?;
+
+	let token_response = exchange_anilist_code(state, code).await?;
+	let viewer = get_viewer(&state.http_client, &token_response.access_token).await?;
+	let access_token = cipher.encrypt(&token_response.access_token)?;
+
+	let now = Utc::now().naive_utc();
+	let expires_at = now + Duration::seconds(token_response.expires_in);
+
+	let txn = state.db.begin().await?;
+
+	// Whoever claimed this AniList account before, verified or not, loses it
+	// to the member who proved they own it
+	RegisteredUser::delete_many()
+		.filter(registered_user::Column::AnilistId.eq(viewer.id))
+		.filter(registered_user::Column::UserId.ne(user_id))
+		.exec(&txn)
+		.await?;
+	AnilistToken::delete_many()
+		.filter(anilist_token::Column::AnilistId.eq(viewer.id))
+		.filter(anilist_token::Column::UserId.ne(user_id))
+		.exec(&txn)
+		.await?;
+
+	RegisteredUser::insert(registered_user::ActiveModel {
+		user_id: Set(user_id.to_string()),
+		anilist_id: Set(viewer.id),
+		registered_at: Set(now),
+		verified: Set(true),
+	})
+	.on_conflict(
+		OnConflict::column(registered_user::Column::UserId)
+			.update_columns([
+				registered_user::Column::AnilistId,
+				registered_user::Column::RegisteredAt,
+				registered_user::Column::Verified,
+			])
+			.to_owned(),
+	)
+	.exec_without_returning(&txn)
+	.await?;
+
+	AnilistToken::insert(anilist_token::ActiveModel {
+		user_id: Set(user_id.to_string()),
+		anilist_id: Set(viewer.id),
+		access_token: Set(access_token),
+		expires_at: Set(expires_at),
+		created_at: Set(now),
+		updated_at: Set(now),
+	})
+	.on_conflict(
+		OnConflict::column(anilist_token::Column::UserId)
+			.update_columns([
+				anilist_token::Column::AnilistId,
+				anilist_token::Column::AccessToken,
+				anilist_token::Column::ExpiresAt,
+				anilist_token::Column::UpdatedAt,
+			])
+			.to_owned(),
+	)
+	.exec_without_returning(&txn)
+	.await?;
+
+	txn.commit().await?;
+
+	debug!(user = %user_id, "persisted anilist token");
+	Ok(viewer)
+}
+
+async fn exchange_anilist_code(
+	state: &AppState, code: &str,
+) -> Result<AnilistTokenResponse, AppError> {
+	let anilist_config = anilist_config(state)?;
+
+	let body = serde_json::json!({
+		"grant_type": "authorization_code",
+		"client_id": anilist_config.client_id,
+		"client_secret": anilist_config.client_secret,
+		"redirect_uri": anilist_config.redirect_uri,
+		"code": code,
+	});
+
+	let response = state
+		.http_client
+		.post(ANILIST_TOKEN_URL)
+		.header("Accept", "application/json")
+		.json(&body)
+		.send()
+		.await
+		.map_err(|e| AppError::bad_gateway(format!("anilist api unreachable: {}", e)))?;
+
+	if !response.status().is_success() {
+		let status = response.status();
+		let body = response.text().await.unwrap_or_default();
+		error!(status = %status, body = %body, "anilist token exchange rejected");
+		return Err(AppError::bad_gateway(format!(
+			"Token exchange failed with status {}",
+			status
+		)));
+	}
+
+	response.json::<AnilistTokenResponse>().await.map_err(|e| {
+		error!(error = %e, "malformed anilist token response");
+		AppError::bad_gateway("Failed to parse AniList token response")
+	})
+}
+
+pub async fn get_viewer(client: &reqwest::Client, access_token: &str) -> Result<Viewer, AppError> {
+	let response = client
+		.post(ANILIST_GRAPHQL_URL)
+		.header("Authorization", format!("Bearer {}", access_token))
+		.header("Accept", "application/json")
+		.json(&serde_json::json!({ "query": VIEWER_QUERY }))
+		.send()
+		.await
+		.map_err(|e| AppError::bad_gateway(format!("anilist api unreachable: {}", e)))?;
+
+	if !response.status().is_success() {
+		let status = response.status();
+		let body = response.text().await.unwrap_or_default();
+		error!(status = %status, body = %body, "anilist viewer query failed");
+		return Err(AppError::bad_gateway(format!(
+			"Viewer query failed with status {}",
+			status
+		)));
+	}
+
+	let viewer = response.json::<ViewerResponse>().await.map_err(|e| {
+		error!(error = %e, "malformed anilist viewer response");
+		AppError::bad_gateway("Failed to parse AniList viewer")
+	})?;
+
+	Ok(viewer.data.viewer)
+}
+
+/// The page the member lands on once AniList sends them back.
+pub fn link_page(message: &str) -> Html<String> {
+	Html(format!(
+		"<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>Kasuki - AniList</title></head>\
+		 <body><p>{}</p></body></html>",
+		message
+	))
+}
+
+pub fn escape_html(value: &str) -> String {
+	value
+		.replace('&', "&amp;")
+		.replace('<', "&lt;")
+		.replace('>', "&gt;")
+		.replace('"', "&quot;")
+		.replace('\'', "&#39;")
+}
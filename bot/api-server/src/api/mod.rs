@@ -1,3 +1,4 @@
+pub mod anilist;
 pub mod auth;
 pub mod error;
 pub mod health;
@@ -5,6 +6,7 @@ pub mod oauth;
 pub mod rate_limit;
 pub mod server;
 pub mod state;
+pub mod stats;
 #[cfg(test)]
 mod tests;
 
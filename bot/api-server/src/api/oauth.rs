@@ -100,8 +100,8 @@ pub async fn oauth_login(State(state): State<AppState>) -> impl IntoResponse {
 		("state", csrf_state.as_str()),
 	];
 
-	let query_string = serde_urlencoded::to_string(&params)
-		.expect("failed to encode OAuth query params");
+	let query_string =
+		serde_urlencoded::to_string(&params).expect("failed to encode OAuth query params");
 	let discord_auth_url = format!("https://discord.com/api/oauth2/authorize?{}", query_string);
 
 	debug!("redirecting to discord oauth");
@@ -153,7 +153,8 @@ pub async fn oauth_callback(
 		},
 	};
 
-	let token_response = match exchange_code_for_token(&state, &code).await {
+	let redirect_uri = &state.config.api.oauth.discord_redirect_uri;
+	let token_response = match exchange_code_for_token(&state, &code, redirect_uri).await {
 		Ok(token) => token,
 		Err(e) => {
 			error!(error = %e.message, "discord token exchange failed");
@@ -252,7 +253,11 @@ pub async fn exchange_auth_code(
 	Ok(Json(TokenExchangeResponse { token }))
 }
 
-async fn exchange_code_for_token(state: &AppState, code: &str) -> Result<TokenResponse, AppError> {
+/// Exchange a Discord authorization code. `redirect_uri` must be the one the
+/// login was started with.
+pub async fn exchange_code_for_token(
+	state: &AppState, code: &str, redirect_uri: &str,
+) -> Result<TokenResponse, AppError> {
 	let oauth_config = &state.config.api.oauth;
 
 	let params = [
@@ -260,7 +265,7 @@ async fn exchange_code_for_token(state: &AppState, code: &str) -> Result<TokenRe
 		("client_secret", oauth_config.discord_client_secret.as_str()),
 		("grant_type", "authorization_code"),
 		("code", code),
-		("redirect_uri", oauth_config.discord_redirect_uri.as_str()),
+		("redirect_uri", redirect_uri),
 	];
 
 	let response = state
@@ -3,7 +3,7 @@ use crate::api::error::AppError;
 use crate::api::oauth::{get_user_guilds, get_user_info, refresh_discord_token, Guild, UserInfo};
 use crate::api::rate_limit::{create_rate_limiter, rate_limit_middleware};
 use crate::api::state::AppState;
-use crate::api::{health, oauth as oauth_handlers};
+use crate::api::{anilist, health, oauth as oauth_handlers, stats};
 use axum::{
 	extract::State,
 	http::Method,
@@ -135,6 +135,7 @@ pub async fn run_server(state: AppState) -> anyhow::Result<()> {
 	let cors = build_cors_layer(&state.config);
 
 	let rate_limiter = create_rate_limiter(state.config.api.rate_limit_per_minute);
+	let stats_rate_limiter = create_rate_limiter(state.config.api.stats_rate_limit_per_minute);
 
 	let oauth_router = Router::new()
 		.route("/login", get(oauth_handlers::oauth_login))
@@ -146,6 +147,27 @@ pub async fn run_server(state: AppState) -> anyhow::Result<()> {
 		))
 		.with_state(state.clone());
 
+	let anilist_router = Router::new()
+		.route("/login", get(anilist::anilist_login))
+		.route("/discord", get(anilist::anilist_discord_callback))
+		.route("/callback", get(anilist::anilist_callback))
+		.layer(middleware::from_fn_with_state(
+			rate_limiter.clone(),
+			rate_limit_middleware,
+		))
+		.with_state(state.clone());
+
+	let stats_router = Router::new()
+		.route("/overview", get(stats::get_overview))
+		.route("/commands", get(stats::get_command_usage))
+		.route("/commands/top", get(stats::get_top_commands))
+		.route("/shards", get(stats::get_shard_latency))
+		.layer(middleware::from_fn_with_state(
+			stats_rate_limiter,
+			rate_limit_middleware,
+		))
+		.with_state(state.clone());
+
 	let user_router = Router::new()
 		.route("/me", get(get_user_profile))
 		.route("/update", post(update_user_data))
@@ -158,6 +180,8 @@ pub async fn run_server(state: AppState) -> anyhow::Result<()> {
 	let app = Router::new()
 		.route("/api/health", get(health::health_check))
 		.nest("/api/oauth", oauth_router)
+		.nest("/api/anilist", anilist_router)
+		.nest("/api/stats", stats_router)
 		.nest("/api/user", user_router)
 		.layer(cors);
 
@@ -1,5 +1,6 @@
 use crate::api::oauth::{Guild, UserInfo};
 use moka::future::Cache;
+use shared::anilist::oauth::TokenCipher;
 use shared::config::Config;
 use std::sync::Arc;
 use std::time::Duration;
@@ -16,14 +17,24 @@ pub struct AppState {
 	pub user_cache: Cache<String, (UserInfo, Vec<Guild>)>,
 	pub auth_codes: Cache<String, AuthCodeEntry>,
 	pub oauth_states: Cache<String, ()>,
+	/// State of the Discord login confirming who opened an AniList link, and
+	/// the Discord user the link was issued to.
+	pub anilist_identity_states: Cache<String, String>,
+	/// CSRF state of a pending AniList login and the Discord user it links.
+	pub anilist_states: Cache<String, String>,
+	pub stats_cache: Cache<String, serde_json::Value>,
 	pub db: Arc<sea_orm::DatabaseConnection>,
 	pub jwt_encoding_key: jsonwebtoken::EncodingKey,
 	pub jwt_decoding_key: jsonwebtoken::DecodingKey,
+	/// Raw JWT secret, the bot signs AniList link tokens with it.
+	pub jwt_secret: Arc<Vec<u8>>,
+	pub anilist_cipher: Option<Arc<TokenCipher>>,
 }
 
 impl AppState {
 	pub fn new(
 		config: Arc<Config>, db: sea_orm::DatabaseConnection, jwt_secret_bytes: Vec<u8>,
+		anilist_cipher: Option<TokenCipher>,
 	) -> Self {
 		let cache_cfg = &config.api.cache;
 
@@ -42,6 +53,21 @@ impl AppState {
 			.time_to_live(Duration::from_secs(cache_cfg.oauth_state_ttl_secs))
 			.build();
 
+		let anilist_identity_states = Cache::builder()
+			.max_capacity(cache_cfg.oauth_state_capacity)
+			.time_to_live(Duration::from_secs(cache_cfg.oauth_state_ttl_secs))
+			.build();
+
+		let anilist_states = Cache::builder()
+			.max_capacity(cache_cfg.oauth_state_capacity)
+			.time_to_live(Duration::from_secs(cache_cfg.oauth_state_ttl_secs))
+			.build();
+
+		let stats_cache = Cache::builder()
+			.max_capacity(cache_cfg.stats_cache_capacity)
+			.time_to_live(Duration::from_secs(cache_cfg.stats_cache_ttl_secs))
+			.build();
+
 		let jwt_encoding_key = jsonwebtoken::EncodingKey::from_secret(&jwt_secret_bytes);
 		let jwt_decoding_key = jsonwebtoken::DecodingKey::from_secret(&jwt_secret_bytes);
 
@@ -51,9 +77,14 @@ impl AppState {
 			user_cache,
 			auth_codes,
 			oauth_states,
+			anilist_identity_states,
+			anilist_states,
+			stats_cache,
 			db: Arc::new(db),
 			jwt_encoding_key,
 			jwt_decoding_key,
+			jwt_secret: Arc::new(jwt_secret_bytes),
+			anilist_cipher: anilist_cipher.map(Arc::new),
 		}
 	}
 }
@@ -0,0 +1,316 @@
+use axum::{
+	extract::{Query, State},
+	response::IntoResponse,
+	Json,
+};
+use chrono::{Duration, Utc};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+	ColumnTrait, EntityTrait, FromQueryResult, Order, PaginatorTrait, QueryFilter, QueryOrder,
+	QuerySelect,
+};
+use serde::{Deserialize, Serialize};
+use shared::database::prelude::{CommandList, CommandUsage, GuildData, PingHistory, UserData};
+use shared::database::{command_usage, ping_history};
+use std::collections::BTreeMap;
+use std::future::Future;
+use tracing::debug;
+
+use crate::api::error::AppError;
+use crate::api::state::AppState;
+
+pub const DEFAULT_USAGE_DAYS: u32 = 7;
+pub const MAX_USAGE_DAYS: u32 = 90;
+pub const DEFAULT_LATENCY_HOURS: u32 = 24;
+pub const MAX_LATENCY_HOURS: u32 = 168;
+pub const DEFAULT_TOP_LIMIT: u32 = 10;
+pub const MAX_TOP_LIMIT: u32 = 50;
+
+/// SQL expression bucketing `command_usage.use_time` by calendar day.
+/// `DATE()` exists on both Postgres and SQLite; the cast keeps the result a `YYYY-MM-DD` string.
+const USAGE_DAY_EXPR: &str = "CAST(DATE(use_time) AS TEXT)";
+
+#[derive(Debug, Deserialize)]
+pub struct UsageQuery {
+	pub days: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LatencyQuery {
+	pub hours: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TopCommandsQuery {
+	pub limit: Option<u32>,
+	pub days: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OverviewStats {
+	pub guild_count: u64,
+	pub user_count: u64,
+	pub command_count: u64,
+	pub total_command_uses: u64,
+	pub command_uses_last_24h: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DailyUsage {
+	pub day: String,
+	pub uses: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CommandUsageSeries {
+	pub command: String,
+	pub total: i64,
+	pub daily: Vec<DailyUsage>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CommandUsageResponse {
+	pub days: u32,
+	pub commands: Vec<CommandUsageSeries>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LatencyPoint {
+	pub timestamp: i64,
+	pub latency_ms: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ShardLatency {
+	pub shard_id: String,
+	pub average_ms: u64,
+	pub history: Vec<LatencyPoint>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ShardLatencyResponse {
+	pub hours: u32,
+	pub shards: Vec<ShardLatency>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TopCommand {
+	pub command: String,
+	pub uses: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TopCommandsResponse {
+	pub days: Option<u32>,
+	pub commands: Vec<TopCommand>,
+}
+
+#[derive(Debug, FromQueryResult)]
+pub(crate) struct CommandDayRow {
+	pub command: String,
+	pub day: String,
+	pub uses: i64,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct CommandCountRow {
+	command: String,
+	uses: i64,
+}
+
+pub fn clamp_days(days: Option<u32>) -> u32 {
+	days.unwrap_or(DEFAULT_USAGE_DAYS).clamp(1, MAX_USAGE_DAYS)
+}
+
+pub fn clamp_hours(hours: Option<u32>) -> u32 {
+	hours
+		.unwrap_or(DEFAULT_LATENCY_HOURS)
+		.clamp(1, MAX_LATENCY_HOURS)
+}
+
+pub fn clamp_limit(limit: Option<u32>) -> u32 {
+	limit.unwrap_or(DEFAULT_TOP_LIMIT).clamp(1, MAX_TOP_LIMIT)
+}
+
+/// Serve `key` from the stats cache, computing and storing it on a miss.
+async fn cached<T, F, Fut>(
+	state: &AppState, key: String, fetch: F,
+) -> Result<Json<serde_json::Value>, AppError>
+where
+	T: Serialize,
+	F: FnOnce() -> Fut,
+	Fut: Future<Output = Result<T, AppError>>,
+{
+	if let Some(value) = state.stats_cache.get(&key).await {
+		debug!(key = %key, "stats cache hit");
+		return Ok(Json(value));
+	}
+
+	let value = serde_json::to_value(fetch().await?)
+		.map_err(|e| AppError::internal(format!("Failed to serialize stats: {}", e)))?;
+	state.stats_cache.insert(key, value.clone()).await;
+
+	Ok(Json(value))
+}
+
+pub async fn get_overview(State(state): State<AppState>) -> Result<impl IntoResponse, AppError> {
+	cached(&state, String::from("overview"), || async {
+		let db = &*state.db;
+		let since = (Utc::now() - Duration::hours(24)).naive_utc();
+
+		Ok(OverviewStats {
+			guild_count: GuildData::find().count(db).await?,
+			user_count: UserData::find().count(db).await?,
+			command_count: CommandList::find().count(db).await?,
+			total_command_uses: CommandUsage::find().count(db).await?,
+			command_uses_last_24h: CommandUsage::find()
+				.filter(command_usage::Column::UseTime.gte(since))
+				.count(db)
+				.await?,
+		})
+	})
+	.await
+}
+
+pub async fn get_command_usage(
+	State(state): State<AppState>, Query(query): Query<UsageQuery>,
+) -> Result<impl IntoResponse, AppError> {
+	let days = clamp_days(query.days);
+
+	cached(&state, format!("usage:{}", days), || async {
+		let since = (Utc::now() - Duration::days(days as i64)).naive_utc();
+
+		let rows = CommandUsage::find()
+			.select_only()
+			.column(command_usage::Column::Command)
+			.column_as(Expr::cust(USAGE_DAY_EXPR), "day")
+			.column_as(command_usage::Column::Command.count(), "uses")
+			.filter(command_usage::Column::UseTime.gte(since))
+			.group_by(command_usage::Column::Command)
+			.group_by(Expr::cust(USAGE_DAY_EXPR))
+			.into_model::<CommandDayRow>()
+			.all(&*state.db)
+			.await?;
+
+		Ok(CommandUsageResponse {
+			days,
+			commands: group_usage_rows(rows),
+		})
+	})
+	.await
+}
+
+pub async fn get_shard_latency(
+	State(state): State<AppState>, Query(query): Query<LatencyQuery>,
+) -> Result<impl IntoResponse, AppError> {
+	let hours = clamp_hours(query.hours);
+
+	cached(&state, format!("latency:{}", hours), || async {
+		let since = (Utc::now() - Duration::hours(hours as i64)).naive_utc();
+
+		let rows = PingHistory::find()
+			.filter(ping_history::Column::Timestamp.gte(since))
+			.order_by_asc(ping_history::Column::Timestamp)
+			.all(&*state.db)
+			.await?;
+
+		let mut shards: BTreeMap<String, Vec<LatencyPoint>> = BTreeMap::new();
+		for row in rows {
+			// Latency is persisted as text by the bot's ping manager
+			let Ok(latency_ms) = row.latency.parse::<u64>() else {
+				continue;
+			};
+			shards.entry(row.shard_id).or_default().push(LatencyPoint {
+				timestamp: row.timestamp.and_utc().timestamp(),
+				latency_ms,
+			});
+		}
+
+		Ok(ShardLatencyResponse {
+			hours,
+			shards: shards
+				.into_iter()
+				.map(|(shard_id, history)| {
+					let average_ms = history.iter().map(|p| p.latency_ms).sum::<u64>()
+						/ history.len().max(1) as u64;
+					ShardLatency {
+						shard_id,
+						average_ms,
+						history,
+					}
+				})
+				.collect(),
+		})
+	})
+	.await
+}
+
+pub async fn get_top_commands(
+	State(state): State<AppState>, Query(query): Query<TopCommandsQuery>,
+) -> Result<impl IntoResponse, AppError> {
+	let limit = clamp_limit(query.limit);
+	let days = query.days.map(|d| clamp_days(Some(d)));
+	let key = format!("top:{}:{}", limit, days.unwrap_or(0));
+
+	cached(&state, key, || async {
+		let mut select = CommandUsage::find()
+			.select_only()
+			.column(command_usage::Column::Command)
+			.column_as(command_usage::Column::Command.count(), "uses");
+
+		if let Some(days) = days {
+			let since = (Utc::now() - Duration::days(days as i64)).naive_utc();
+			select = select.filter(command_usage::Column::UseTime.gte(since));
+		}
+
+		let rows = select
+			.group_by(command_usage::Column::Command)
+			.order_by(command_usage::Column::Command.count(), Order::Desc)
+			.limit(limit as u64)
+			.into_model::<CommandCountRow>()
+			.all(&*state.db)
+			.await?;
+
+		Ok(TopCommandsResponse {
+			days,
+			commands: rows
+				.into_iter()
+				.map(|row| TopCommand {
+					command: row.command,
+					uses: row.uses,
+				})
+				.collect(),
+		})
+	})
+	.await
+}
+
+/// Fold `(command, day, uses)` rows into one series per command, busiest command first.
+pub(crate) fn group_usage_rows(rows: Vec<CommandDayRow>) -> Vec<CommandUsageSeries> {
+	let mut by_command: BTreeMap<String, Vec<DailyUsage>> = BTreeMap::new();
+	for row in rows {
+		by_command.entry(row.command).or_default().push(DailyUsage {
+			day: row.day,
+			uses: row.uses,
+		});
+	}
+
+	let mut series: Vec<CommandUsageSeries> = by_command
+		.into_iter()
+		.map(|(command, mut daily)| {
+			daily.sort_by(|a, b| a.day.cmp(&b.day));
+			CommandUsageSeries {
+				command,
+				total: daily.iter().map(|d| d.uses).sum(),
+				daily,
+			}
+		})
+		.collect();
+	series.sort_by(|a, b| {
+		b.total
+			.cmp(&a.total)
+			.then_with(|| a.command.cmp(&b.command))
+	});
+
+	series
+}
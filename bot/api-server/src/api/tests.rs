@@ -207,4 +207,66 @@ mod tests {
 
 		assert!(result.is_err());
 	}
+
+	#[test]
+	fn test_stats_query_clamping() {
+		use crate::api::stats::{
+			clamp_days, clamp_hours, clamp_limit, DEFAULT_LATENCY_HOURS, DEFAULT_TOP_LIMIT,
+			DEFAULT_USAGE_DAYS, MAX_LATENCY_HOURS, MAX_TOP_LIMIT, MAX_USAGE_DAYS,
+		};
+
+		assert_eq!(clamp_days(None), DEFAULT_USAGE_DAYS);
+		assert_eq!(clamp_days(Some(0)), 1);
+		assert_eq!(clamp_days(Some(10_000)), MAX_USAGE_DAYS);
+		assert_eq!(clamp_hours(None), DEFAULT_LATENCY_HOURS);
+		assert_eq!(clamp_hours(Some(10_000)), MAX_LATENCY_HOURS);
+		assert_eq!(clamp_limit(None), DEFAULT_TOP_LIMIT);
+		assert_eq!(clamp_limit(Some(0)), 1);
+		assert_eq!(clamp_limit(Some(10_000)), MAX_TOP_LIMIT);
+	}
+
+	#[test]
+	fn test_stats_usage_rows_grouped_by_command() {
+		use crate::api::stats::{group_usage_rows, CommandDayRow};
+
+		let row = |command: &str, day: &str, uses: i64| CommandDayRow {
+			command: command.into(),
+			day: day.into(),
+			uses,
+		};
+
+		let series = group_usage_rows(vec![
+			row("bot ping", "2026-01-02", 3),
+			row("anime", "2026-01-01", 4),
+			row("bot ping", "2026-01-01", 5),
+		]);
+
+		assert_eq!(series.len(), 2);
+		assert_eq!(series[0].command, "bot ping");
+		assert_eq!(series[0].total, 8);
+		assert_eq!(series[0].daily[0].day, "2026-01-01");
+		assert_eq!(series[0].daily[1].day, "2026-01-02");
+		assert_eq!(series[1].command, "anime");
+		assert_eq!(series[1].total, 4);
+	}
+
+	#[test]
+	fn test_anilist_escape_html() {
+		use crate::api::anilist::escape_html;
+
+		assert_eq!(
+			escape_html("<b>\"Tom\" & 'Jerry'</b>"),
+			"&lt;b&gt;&quot;Tom&quot; &amp; &#39;Jerry&#39;&lt;/b&gt;"
+		);
+	}
+
+	#[test]
+	fn test_anilist_token_response_deserialization() {
+		use crate::api::anilist::AnilistTokenResponse;
+
+		let json = r#"{"token_type":"Bearer","expires_in":31536000,"access_token":"abc"}"#;
+		let token: AnilistTokenResponse = serde_json::from_str(json).unwrap();
+		assert_eq!(token.access_token, "abc");
+		assert_eq!(token.expires_in, 31536000);
+	}
 }
@@ -2,6 +2,7 @@ mod api;
 
 use api::state::AppState;
 use base64::{engine::general_purpose::STANDARD, Engine as _};
+use shared::anilist::oauth::TokenCipher;
 use shared::config::Config;
 use std::sync::Arc;
 use std::time::Duration;
@@ -41,6 +42,19 @@ async fn main() -> anyhow::Result<()> {
 	})?;
 	info!("jwt secret validated");
 
+	let anilist_cipher = match &config.api.anilist {
+		Some(anilist) => Some(
+			TokenCipher::from_base64_key(&anilist.token_key).map_err(|e| {
+				error!(error = %e, "invalid anilist token key in config");
+				e
+			})?,
+		),
+		None => {
+			info!("anilist linking not configured");
+			None
+		},
+	};
+
 	let config = Arc::new(config);
 
 	let db_url = get_db_url(&config);
@@ -74,7 +88,7 @@ async fn main() -> anyhow::Result<()> {
 		})?;
 	info!("database connected");
 
-	let state = AppState::new(config, db, jwt_secret_bytes);
+	let state = AppState::new(config, db, jwt_secret_bytes, anilist_cipher);
 
 	api::start_api_server(state).await;
 
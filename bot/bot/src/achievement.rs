@@ -0,0 +1,476 @@
+//! Achievements unlocked from what members do across the bot.
+//!
+//! Definitions live in `json/achievements/achievements.json`: each names a
+//! [`Stat`] and the threshold it unlocks at, with its name and description
+//! per locale. Events add to a running total per member and stat in
+//! `achievement_progress`, and only the achievements of that stat are checked
+//! against the new total, so an unlock never rescans history. Unlocks are
+//! global to the member, like `command_usage` and AniList registration.
+use crate::constant::COLOR;
+use crate::event_handler::BotData;
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+use fluent_templates::fluent_bundle::FluentValue;
+use sea_orm::sea_query::{Expr, ExprTrait, OnConflict};
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder};
+use serde::Deserialize;
+use serenity::all::{
+	Context as SerenityContext, CreateEmbed, CreateMessage, GenericChannelId, GuildId, UserId,
+};
+use shared::database::prelude::{AchievementProgress, UserAchievement};
+use shared::database::{achievement_progress, user_achievement};
+use shared::helper::read_file::read_file_as_string;
+use shared::localization::{get_language_identifier, Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+use tracing::{error, info, warn};
+use unic_langid::LanguageIdentifier;
+
+const CATALOG_PATH: &str = "./json/achievements/achievements.json";
+/// Locale used when a definition lacks the reader's.
+const FALLBACK_LOCALE: &str = "en-US";
+
+static CATALOG: LazyLock<Vec<Achievement>> = LazyLock::new(|| match load_catalog() {
+	Ok(catalog) => {
+		info!(count = catalog.len(), "Loaded the achievement catalog");
+		catalog
+	},
+	Err(e) => {
+		error!(error = ?e, "Invalid achievement catalog, achievements are disabled");
+		Vec::new()
+	},
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stat {
+	CommandsRun,
+	FishCaught,
+	TriviaAnswered,
+	LevelReached,
+	AnilistRegistered,
+	VoiceHours,
+	DailyStreak,
+}
+
+impl Stat {
+	pub const ALL: &'static [Stat] = &[
+		Stat::CommandsRun,
+		Stat::FishCaught,
+		Stat::TriviaAnswered,
+		Stat::LevelReached,
+		Stat::AnilistRegistered,
+		Stat::VoiceHours,
+		Stat::DailyStreak,
+	];
+
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Stat::CommandsRun => "commands_run",
+			Stat::FishCaught => "fish_caught",
+			Stat::TriviaAnswered => "trivia_answered",
+			Stat::LevelReached => "level_reached",
+			Stat::AnilistRegistered => "anilist_registered",
+			Stat::VoiceHours => "voice_hours",
+			Stat::DailyStreak => "daily_streak",
+		}
+	}
+
+	pub fn parse(value: &str) -> Option<Self> {
+		Self::ALL
+			.iter()
+			.copied()
+			.find(|stat| stat.as_str() == value)
+	}
+
+	/// Counters add up, the others keep their best value.
+	fn accumulates(self) -> bool {
+		!matches!(
+			self,
+			Stat::LevelReached | Stat::AnilistRegistered | Stat::DailyStreak
+		)
+	}
+
+	/// Stored units per threshold unit, voice time is stored in seconds.
+	fn scale(self) -> i64 {
+		match self {
+			Stat::VoiceHours => 3600,
+			_ => 1,
+		}
+	}
+}
+
+#[derive(Debug, Deserialize)]
+struct CatalogJson {
+	achievements: Vec<AchievementJson>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AchievementJson {
+	id: String,
+	icon: String,
+	criteria: CriteriaJson,
+	name: HashMap<String, String>,
+	description: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CriteriaJson {
+	stat: String,
+	threshold: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Achievement {
+	pub id: String,
+	pub icon: String,
+	pub stat: Stat,
+	pub threshold: i64,
+	name: HashMap<String, String>,
+	description: HashMap<String, String>,
+}
+
+impl Achievement {
+	/// Stored stat value the achievement unlocks at.
+	fn target(&self) -> i64 {
+		self.threshold.saturating_mul(self.stat.scale())
+	}
+
+	fn localized<'a>(values: &'a HashMap<String, String>, lang_id: &LanguageIdentifier) -> &'a str {
+		values
+			.get(&lang_id.to_string())
+			.or_else(|| values.get(FALLBACK_LOCALE))
+			.map(String::as_str)
+			.unwrap_or_default()
+	}
+
+	pub fn name(&self, lang_id: &LanguageIdentifier) -> &str {
+		Self::localized(&self.name, lang_id)
+	}
+
+	pub fn description(&self, lang_id: &LanguageIdentifier) -> &str {
+		Self::localized(&self.description, lang_id)
+	}
+}
+
+/// Every problem with the catalog, so a broken file reports them all.
+fn validate(catalog: &CatalogJson) -> Vec<String> {
+	let mut errors = Vec::new();
+	let mut seen = HashSet::new();
+
+	for achievement in &catalog.achievements {
+		let id = &achievement.id;
+
+		if id.trim().is_empty() {
+			errors.push(String::from("an achievement has an empty id"));
+			continue;
+		}
+		if !seen.insert(id.as_str()) {
+			errors.push(format!("{}: duplicate id", id));
+		}
+		if Stat::parse(&achievement.criteria.stat).is_none() {
+			errors.push(format!(
+				"{}: unknown stat {}",
+				id, achievement.criteria.stat
+			));
+		}
+		if achievement.criteria.threshold <= 0 {
+			errors.push(format!("{}: threshold must be positive", id));
+		}
+		if !achievement.name.contains_key(FALLBACK_LOCALE)
+			|| !achievement.description.contains_key(FALLBACK_LOCALE)
+		{
+			errors.push(format!(
+				"{}: name and description need a {} entry",
+				id, FALLBACK_LOCALE
+			));
+		}
+		if achievement.icon.trim().is_empty() {
+			errors.push(format!("{}: empty icon", id));
+		}
+	}
+
+	errors
+}
+
+fn parse_catalog(json: &str) -> Result<Vec<Achievement>> {
+	let catalog: CatalogJson =
+		serde_json::from_str(json).context("Failed to parse the achievement catalog")?;
+
+	let errors = validate(&catalog);
+	if !errors.is_empty() {
+		bail!("{}", errors.join("; "));
+	}
+
+	Ok(catalog
+		.achievements
+		.into_iter()
+		.filter_map(|achievement| {
+			Some(Achievement {
+				stat: Stat::parse(&achievement.criteria.stat)?,
+				threshold: achievement.criteria.threshold,
+				id: achievement.id,
+				icon: achievement.icon,
+				name: achievement.name,
+				description: achievement.description,
+			})
+		})
+		.collect())
+}
+
+fn load_catalog() -> Result<Vec<Achievement>> {
+	parse_catalog(&read_file_as_string(CATALOG_PATH)?)
+}
+
+pub fn catalog() -> &'static [Achievement] {
+	&CATALOG
+}
+
+/// Achievements of `stat` reached at `value`, in catalog order.
+fn reached(stat: Stat, value: i64) -> impl Iterator<Item = &'static Achievement> {
+	catalog()
+		.iter()
+		.filter(move |achievement| achievement.stat == stat && achievement.target() <= value)
+}
+
+/// Add `amount` to a counter stat, or raise a best-value stat to `amount`,
+/// then unlock what the new total reaches. Returns the achievements this
+/// call unlocked. The member must already exist in `user_data`.
+pub async fn record_progress(
+	db_connection: &DatabaseConnection, user_id: &str, stat: Stat, amount: i64,
+) -> Result<Vec<&'static Achievement>> {
+	if amount <= 0 || !catalog().iter().any(|achievement| achievement.stat == stat) {
+		return Ok(Vec::new());
+	}
+
+	let now = Utc::now().naive_utc();
+
+	AchievementProgress::insert(achievement_progress::ActiveModel {
+		user_id: Set(user_id.to_string()),
+		stat: Set(stat.as_str().to_string()),
+		value: Set(0),
+		updated_at: Set(now),
+	})
+	.on_conflict(
+		OnConflict::columns([
+			achievement_progress::Column::UserId,
+			achievement_progress::Column::Stat,
+		])
+		.do_nothing()
+		.to_owned(),
+	)
+	.exec_without_returning(db_connection)
+	.await?;
+
+	let mut update = AchievementProgress::update_many()
+		.col_expr(achievement_progress::Column::UpdatedAt, Expr::value(now))
+		.filter(achievement_progress::Column::UserId.eq(user_id))
+		.filter(achievement_progress::Column::Stat.eq(stat.as_str()));
+	update = if stat.accumulates() {
+		update.col_expr(
+			achievement_progress::Column::Value,
+			Expr::col(achievement_progress::Column::Value).add(amount),
+		)
+	} else {
+		update
+			.col_expr(achievement_progress::Column::Value, Expr::value(amount))
+			.filter(achievement_progress::Column::Value.lt(amount))
+	};
+	update.exec(db_connection).await?;
+
+	let Some(progress) =
+		AchievementProgress::find_by_id((user_id.to_string(), stat.as_str().to_string()))
+			.one(db_connection)
+			.await?
+	else {
+		return Ok(Vec::new());
+	};
+
+	let candidates: Vec<&'static Achievement> = reached(stat, progress.value).collect();
+	if candidates.is_empty() {
+		return Ok(Vec::new());
+	}
+
+	let owned: HashSet<String> = UserAchievement::find()
+		.filter(user_achievement::Column::UserId.eq(user_id))
+		.filter(
+			user_achievement::Column::AchievementId
+				.is_in(candidates.iter().map(|achievement| achievement.id.clone())),
+		)
+		.all(db_connection)
+		.await?
+		.into_iter()
+		.map(|unlocked| unlocked.achievement_id)
+		.collect();
+
+	let mut unlocked = Vec::new();
+	for achievement in candidates {
+		if owned.contains(&achievement.id) {
+			continue;
+		}
+
+		// A concurrent event may unlock it first, only one insert lands
+		let inserted = UserAchievement::insert(user_achievement::ActiveModel {
+			user_id: Set(user_id.to_string()),
+			achievement_id: Set(achievement.id.clone()),
+			unlocked_at: Set(now),
+		})
+		.on_conflict(
+			OnConflict::columns([
+				user_achievement::Column::UserId,
+				user_achievement::Column::AchievementId,
+			])
+			.do_nothing()
+			.to_owned(),
+		)
+		.exec_without_returning(db_connection)
+		.await?;
+
+		if inserted > 0 {
+			info!(user_id, achievement = %achievement.id, "Achievement unlocked");
+			unlocked.push(achievement);
+		}
+	}
+
+	Ok(unlocked)
+}
+
+/// Achievements the member unlocked that are still in the catalog, oldest
+/// first.
+pub async fn unlocked_achievements(
+	db_connection: &DatabaseConnection, user_id: &str,
+) -> Result<Vec<&'static Achievement>> {
+	let unlocked = UserAchievement::find()
+		.filter(user_achievement::Column::UserId.eq(user_id))
+		.order_by_asc(user_achievement::Column::UnlockedAt)
+		.all(db_connection)
+		.await?;
+
+	Ok(unlocked
+		.iter()
+		.filter_map(|unlocked| {
+			catalog()
+				.iter()
+				.find(|achievement| achievement.id == unlocked.achievement_id)
+		})
+		.collect())
+}
+
+fn unlock_embed(
+	user_id: UserId, achievement: &Achievement, lang_id: &LanguageIdentifier,
+) -> CreateEmbed<'static> {
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("user"),
+		FluentValue::from(format!("<@{}>", user_id)),
+	);
+	args.insert(
+		Cow::Borrowed("icon"),
+		FluentValue::from(achievement.icon.clone()),
+	);
+	args.insert(
+		Cow::Borrowed("name"),
+		FluentValue::from(achievement.name(lang_id).to_string()),
+	);
+	args.insert(
+		Cow::Borrowed("description"),
+		FluentValue::from(achievement.description(lang_id).to_string()),
+	);
+
+	CreateEmbed::new()
+		.title(USABLE_LOCALES.lookup(lang_id, "achievement-unlocked_title"))
+		.description(USABLE_LOCALES.lookup_with_args(lang_id, "achievement-unlocked", &args))
+		.colour(COLOR)
+}
+
+/// Record progress and announce what it unlocked, in `channel` when the
+/// event happened in one and by direct message otherwise.
+///
+/// Achievements are a side effect of the event that fed them, so failures
+/// are logged rather than returned.
+pub async fn track(
+	ctx: &SerenityContext, user_id: UserId, guild_id: Option<GuildId>,
+	channel: Option<GenericChannelId>, stat: Stat, amount: i64,
+) {
+	let bot_data = ctx.data::<BotData>().clone();
+	let db_connection = bot_data.db_connection.clone();
+
+	let unlocked = match record_progress(&db_connection, &user_id.to_string(), stat, amount).await {
+		Ok(unlocked) => unlocked,
+		Err(e) => {
+			warn!(user_id = %user_id, stat = stat.as_str(), error = ?e, "Failed to record achievement progress");
+			return;
+		},
+	};
+	if unlocked.is_empty() {
+		return;
+	}
+
+	let guild_id = guild_id
+		.map(|guild_id| guild_id.to_string())
+		.unwrap_or(String::from("0"));
+	let lang_id = get_language_identifier(guild_id, db_connection).await;
+
+	for achievement in unlocked {
+		let message = CreateMessage::new().embed(unlock_embed(user_id, achievement, &lang_id));
+		let sent = match channel {
+			Some(channel) => channel.send_message(&ctx.http, message).await,
+			None => user_id.direct_message(&ctx.http, message).await,
+		};
+
+		if let Err(e) = sent {
+			warn!(user_id = %user_id, achievement = %achievement.id, error = ?e, "Failed to announce an achievement");
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn catalog_json(id: &str, stat: &str, threshold: i64) -> String {
+		format!(
+			r#"{{"achievements": [{{"id": "{}", "icon": "*", "criteria": {{"stat": "{}", "threshold": {}}},
+			"name": {{"en-US": "Name", "fr": "Nom"}}, "description": {{"en-US": "Description"}}}}]}}"#,
+			id, stat, threshold
+		)
+	}
+
+	#[test]
+	fn test_catalog_file_is_valid() {
+		let json = std::fs::read_to_string(concat!(
+			env!("CARGO_MANIFEST_DIR"),
+			"/../json/achievements/achievements.json"
+		))
+		.unwrap();
+
+		let catalog = parse_catalog(&json).unwrap();
+		assert!(!catalog.is_empty());
+		for stat in Stat::ALL {
+			assert!(catalog.iter().any(|achievement| achievement.stat == *stat));
+		}
+	}
+
+	#[test]
+	fn test_invalid_definitions_are_rejected() {
+		assert!(parse_catalog(&catalog_json("a", "commands_run", 1)).is_ok());
+		assert!(parse_catalog(&catalog_json("a", "dances_danced", 1)).is_err());
+		assert!(parse_catalog(&catalog_json("a", "fish_caught", 0)).is_err());
+		assert!(parse_catalog(&catalog_json(" ", "fish_caught", 1)).is_err());
+	}
+
+	#[test]
+	fn test_localized_falls_back_to_english() {
+		let achievement = parse_catalog(&catalog_json("a", "voice_hours", 2))
+			.unwrap()
+			.remove(0);
+		let fr: LanguageIdentifier = "fr".parse().unwrap();
+		let ja: LanguageIdentifier = "ja".parse().unwrap();
+
+		assert_eq!(achievement.name(&fr), "Nom");
+		assert_eq!(achievement.name(&ja), "Name");
+		assert_eq!(achievement.description(&fr), "Description");
+		assert_eq!(achievement.target(), 7200);
+	}
+}
@@ -0,0 +1,453 @@
+//! Comparing two AniList users title by title.
+//!
+//! `compare` used to look only at the aggregate statistics of both profiles.
+//! This loads both anime and manga lists, keeps the titles both users have
+//! seen and correlates their scores, which says far more about shared taste
+//! than matching genre names. Scores are requested out of 100 so users with
+//! different score formats can be compared.
+use crate::structure::run::anilist::list_collection::{
+	ListCollection, ListCollectionVariables, MediaListStatus, MediaType,
+};
+use anyhow::{anyhow, Result};
+use cynic::{GraphQlResponse, QueryBuilder};
+use shared::anilist::make_request::make_request_anilist;
+use shared::cache::CacheInterface;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// Entries shown per page of disagreements or recommendations.
+pub const COMPARE_PAGE_SIZE: usize = 10;
+/// Scores out of 100 from which a title counts as loved.
+pub const LOVED_SCORE: f64 = 80.0;
+const CHUNK_SIZE: i32 = 500;
+/// Stops after 5000 entries per list type, only a handful of users go beyond.
+const MAX_CHUNKS: i32 = 10;
+/// Pairs of scores needed before a correlation means anything.
+const MIN_CORRELATION_PAIRS: usize = 3;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ListItem {
+	pub media_id: i32,
+	pub title: String,
+	pub url: Option<String>,
+	/// Out of 100, 0 when the user did not score it.
+	pub score: f64,
+	/// Only on the planning list, the user has not started it.
+	pub planned: bool,
+	/// Completed or being rewatched.
+	pub completed: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SharedEntry {
+	pub title: String,
+	pub url: Option<String>,
+	pub score: f64,
+	pub score2: f64,
+}
+
+impl SharedEntry {
+	pub fn gap(&self) -> f64 {
+		(self.score - self.score2).abs()
+	}
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ListComparison {
+	/// Titles both users started or finished.
+	pub shared: usize,
+	/// Shared titles both users scored.
+	pub scored: usize,
+	pub pearson: Option<f64>,
+	pub spearman: Option<f64>,
+	/// Shared scored titles, the largest score gap first.
+	pub disagreements: Vec<SharedEntry>,
+	/// Titles the second user loved that the first one has not seen.
+	pub for_first: Vec<ListItem>,
+	/// Titles the first user loved that the second one has not seen.
+	pub for_second: Vec<ListItem>,
+}
+
+/// Which part of the list comparison a page shows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompareView {
+	Disagreements,
+	ForFirst,
+	ForSecond,
+}
+
+impl CompareView {
+	pub const ALL: [CompareView; 3] = [
+		CompareView::Disagreements,
+		CompareView::ForFirst,
+		CompareView::ForSecond,
+	];
+
+	pub fn as_str(self) -> &'static str {
+		match self {
+			CompareView::Disagreements => "disagreements",
+			CompareView::ForFirst => "first",
+			CompareView::ForSecond => "second",
+		}
+	}
+
+	pub fn parse(value: &str) -> Option<Self> {
+		Self::ALL.into_iter().find(|view| view.as_str() == value)
+	}
+}
+
+pub const COMPARE_BUTTON: &str = "compare_list";
+
+pub fn compare_button_id(user_id: i32, user_id2: i32, view: CompareView, page: usize) -> String {
+	format!(
+		"{}_{}_{}_{}_{}",
+		COMPARE_BUTTON,
+		user_id,
+		user_id2,
+		view.as_str(),
+		page
+	)
+}
+
+/// Both AniList user ids, the view and the page from a button custom id.
+pub fn parse_compare_button(custom_id: &str) -> Option<(i32, i32, CompareView, usize)> {
+	let rest = custom_id.strip_prefix(COMPARE_BUTTON)?.strip_prefix('_')?;
+	let mut parts = rest.split('_');
+
+	let user_id = parts.next()?.parse().ok()?;
+	let user_id2 = parts.next()?.parse().ok()?;
+	let view = CompareView::parse(parts.next()?)?;
+	let page = parts.next()?.parse().ok()?;
+
+	Some((user_id, user_id2, view, page))
+}
+
+pub fn page_count(len: usize) -> usize {
+	len.div_ceil(COMPARE_PAGE_SIZE).max(1)
+}
+
+pub fn page_of<T>(items: &[T], page: usize) -> &[T] {
+	let start = (page * COMPARE_PAGE_SIZE).min(items.len());
+	let end = (start + COMPARE_PAGE_SIZE).min(items.len());
+
+	&items[start..end]
+}
+
+/// Every anime and manga on the user's list, each title once.
+pub async fn fetch_list(
+	user_id: i32, anilist_cache: Arc<RwLock<CacheInterface>>,
+) -> Result<Vec<ListItem>> {
+	let mut items = fetch_list_type(user_id, MediaType::Anime, anilist_cache.clone()).await?;
+	items.extend(fetch_list_type(user_id, MediaType::Manga, anilist_cache).await?);
+
+	Ok(items)
+}
+
+/// The anime or the manga list of the user, each title once.
+pub async fn fetch_list_type(
+	user_id: i32, media_type: MediaType, anilist_cache: Arc<RwLock<CacheInterface>>,
+) -> Result<Vec<ListItem>> {
+	// Custom lists repeat entries already in a status list
+	let mut items: HashMap<i32, ListItem> = HashMap::new();
+
+	for chunk in 1..=MAX_CHUNKS {
+		let operation = ListCollection::build(ListCollectionVariables {
+			user_id: Some(user_id),
+			media_type: Some(media_type),
+			chunk: Some(chunk),
+			per_chunk: Some(CHUNK_SIZE),
+		});
+		let response: GraphQlResponse<ListCollection> =
+			make_request_anilist(operation, true, anilist_cache.clone()).await?;
+
+		let collection = response
+			.data
+			.and_then(|data| data.media_list_collection)
+			.ok_or(anyhow!("No list for the AniList user {}", user_id))?;
+
+		let entries = collection
+			.lists
+			.unwrap_or_default()
+			.into_iter()
+			.flatten()
+			.flat_map(|group| group.entries.unwrap_or_default())
+			.flatten();
+
+		for entry in entries {
+			let Some(media) = entry.media else {
+				continue;
+			};
+
+			items.entry(media.id).or_insert(ListItem {
+				media_id: media.id,
+				title: media
+					.title
+					.and_then(|title| title.user_preferred)
+					.unwrap_or_default(),
+				url: media.site_url,
+				score: entry.score.unwrap_or(0.0),
+				planned: entry.status == Some(MediaListStatus::Planning),
+				completed: matches!(
+					entry.status,
+					Some(MediaListStatus::Completed) | Some(MediaListStatus::Repeating)
+				),
+			});
+		}
+
+		if !collection.has_next_chunk.unwrap_or(false) {
+			break;
+		}
+	}
+
+	Ok(items.into_values().collect())
+}
+
+pub fn compare_lists(first: &[ListItem], second: &[ListItem]) -> ListComparison {
+	let seen = |items: &[ListItem]| -> HashMap<i32, ListItem> {
+		items
+			.iter()
+			.filter(|item| !item.planned)
+			.map(|item| (item.media_id, item.clone()))
+			.collect()
+	};
+	let seen_first = seen(first);
+	let seen_second = seen(second);
+
+	let mut shared = 0;
+	let mut disagreements = Vec::new();
+	for (media_id, item) in &seen_first {
+		let Some(item2) = seen_second.get(media_id) else {
+			continue;
+		};
+		shared += 1;
+
+		if item.score > 0.0 && item2.score > 0.0 {
+			disagreements.push(SharedEntry {
+				title: item.title.clone(),
+				url: item.url.clone(),
+				score: item.score,
+				score2: item2.score,
+			});
+		}
+	}
+
+	let pairs: Vec<(f64, f64)> = disagreements
+		.iter()
+		.map(|entry| (entry.score, entry.score2))
+		.collect();
+
+	disagreements.sort_by(|a, b| {
+		b.gap()
+			.partial_cmp(&a.gap())
+			.unwrap_or(Ordering::Equal)
+			.then_with(|| a.title.cmp(&b.title))
+	});
+
+	let first_ids: HashSet<i32> = seen_first.keys().copied().collect();
+	let second_ids: HashSet<i32> = seen_second.keys().copied().collect();
+
+	ListComparison {
+		shared,
+		scored: pairs.len(),
+		pearson: pearson(&pairs),
+		spearman: spearman(&pairs),
+		disagreements,
+		for_first: recommendations(seen_second.values(), &first_ids),
+		for_second: recommendations(seen_first.values(), &second_ids),
+	}
+}
+
+/// Loved titles from one list that the other user has not seen, the best
+/// scored first.
+fn recommendations<'a>(
+	from: impl Iterator<Item = &'a ListItem>, seen: &HashSet<i32>,
+) -> Vec<ListItem> {
+	let mut items: Vec<ListItem> = from
+		.filter(|item| item.score >= LOVED_SCORE && !seen.contains(&item.media_id))
+		.cloned()
+		.collect();
+
+	items.sort_by(|a, b| {
+		b.score
+			.partial_cmp(&a.score)
+			.unwrap_or(Ordering::Equal)
+			.then_with(|| a.title.cmp(&b.title))
+	});
+
+	items
+}
+
+/// Pearson correlation of the pairs, `None` with too few pairs or when one
+/// side gave every title the same score.
+pub fn pearson(pairs: &[(f64, f64)]) -> Option<f64> {
+	if pairs.len() < MIN_CORRELATION_PAIRS {
+		return None;
+	}
+
+	let n = pairs.len() as f64;
+	let mean_x = pairs.iter().map(|(x, _)| x).sum::<f64>() / n;
+	let mean_y = pairs.iter().map(|(_, y)| y).sum::<f64>() / n;
+
+	let mut covariance = 0.0;
+	let mut variance_x = 0.0;
+	let mut variance_y = 0.0;
+	for (x, y) in pairs {
+		covariance += (x - mean_x) * (y - mean_y);
+		variance_x += (x - mean_x).powi(2);
+		variance_y += (y - mean_y).powi(2);
+	}
+
+	if variance_x == 0.0 || variance_y == 0.0 {
+		return None;
+	}
+
+	Some(covariance / (variance_x * variance_y).sqrt())
+}
+
+/// Spearman correlation, the Pearson correlation of the score ranks. It only
+/// cares about the order, so a harsh and a generous scorer who rank titles
+/// the same way still match.
+pub fn spearman(pairs: &[(f64, f64)]) -> Option<f64> {
+	let xs: Vec<f64> = pairs.iter().map(|(x, _)| *x).collect();
+	let ys: Vec<f64> = pairs.iter().map(|(_, y)| *y).collect();
+
+	let ranked: Vec<(f64, f64)> = ranks(&xs).into_iter().zip(ranks(&ys)).collect();
+
+	pearson(&ranked)
+}
+
+/// 1-based ranks, tied values share the average of their ranks.
+fn ranks(values: &[f64]) -> Vec<f64> {
+	let mut order: Vec<usize> = (0..values.len()).collect();
+	order.sort_by(|a, b| {
+		values[*a]
+			.partial_cmp(&values[*b])
+			.unwrap_or(Ordering::Equal)
+	});
+
+	let mut ranks = vec![0.0; values.len()];
+	let mut start = 0;
+	while start < order.len() {
+		let mut end = start;
+		while end + 1 < order.len() && values[order[end + 1]] == values[order[start]] {
+			end += 1;
+		}
+
+		let rank = (start + end) as f64 / 2.0 + 1.0;
+		for index in &order[start..=end] {
+			ranks[*index] = rank;
+		}
+
+		start = end + 1;
+	}
+
+	ranks
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn item(media_id: i32, score: f64, planned: bool) -> ListItem {
+		ListItem {
+			media_id,
+			title: format!("title {}", media_id),
+			url: None,
+			score,
+			planned,
+			completed: !planned,
+		}
+	}
+
+	fn assert_close(value: Option<f64>, expected: f64) {
+		let value = value.expect("a correlation");
+		assert!((value - expected).abs() < 1e-9, "{} != {}", value, expected);
+	}
+
+	#[test]
+	fn test_pearson() {
+		assert_close(pearson(&[(1.0, 2.0), (2.0, 4.0), (3.0, 6.0)]), 1.0);
+		assert_close(pearson(&[(1.0, 3.0), (2.0, 2.0), (3.0, 1.0)]), -1.0);
+		assert_eq!(pearson(&[(1.0, 2.0), (2.0, 4.0)]), None);
+		assert_eq!(pearson(&[(5.0, 1.0), (5.0, 2.0), (5.0, 3.0)]), None);
+	}
+
+	#[test]
+	fn test_spearman_ignores_scale() {
+		// Same order, very different scores
+		assert_close(
+			spearman(&[(10.0, 60.0), (20.0, 61.0), (90.0, 100.0), (50.0, 70.0)]),
+			1.0,
+		);
+	}
+
+	#[test]
+	fn test_ranks_with_ties() {
+		assert_eq!(ranks(&[50.0, 80.0, 50.0, 90.0]), vec![1.5, 3.0, 1.5, 4.0]);
+	}
+
+	#[test]
+	fn test_compare_lists() {
+		let first = vec![
+			item(1, 90.0, false),
+			item(2, 40.0, false),
+			item(3, 0.0, false),
+			item(4, 85.0, false),
+			item(5, 0.0, true),
+		];
+		let second = vec![
+			item(1, 80.0, false),
+			item(2, 95.0, false),
+			item(3, 70.0, false),
+			item(5, 90.0, false),
+			item(6, 60.0, false),
+		];
+
+		let comparison = compare_lists(&first, &second);
+
+		assert_eq!(comparison.shared, 3);
+		assert_eq!(comparison.scored, 2);
+		assert_eq!(comparison.pearson, None);
+		assert_eq!(comparison.disagreements[0].title, "title 2");
+		// Planned is not seen, so it is still worth recommending
+		assert_eq!(
+			comparison
+				.for_first
+				.iter()
+				.map(|item| item.media_id)
+				.collect::<Vec<_>>(),
+			vec![5]
+		);
+		assert_eq!(
+			comparison
+				.for_second
+				.iter()
+				.map(|item| item.media_id)
+				.collect::<Vec<_>>(),
+			vec![4]
+		);
+	}
+
+	#[test]
+	fn test_compare_button_round_trip() {
+		let custom_id = compare_button_id(1, 22, CompareView::ForSecond, 3);
+
+		assert_eq!(
+			parse_compare_button(&custom_id),
+			Some((1, 22, CompareView::ForSecond, 3))
+		);
+		assert_eq!(parse_compare_button("compare_list_1_22_other_0"), None);
+	}
+
+	#[test]
+	fn test_pages() {
+		let items: Vec<usize> = (0..23).collect();
+
+		assert_eq!(page_count(items.len()), 3);
+		assert_eq!(page_count(0), 1);
+		assert_eq!(page_of(&items, 2), &[20, 21, 22]);
+		assert!(page_of(&items, 5).is_empty());
+	}
+}
@@ -0,0 +1,238 @@
+//! Following anime for the airing DMs the worker sends, and the member's
+//! notification settings.
+//!
+//! Anime followed from the CURRENT list are muted rather than deleted when
+//! the member unfollows them, otherwise the next list sync would follow them
+//! again.
+use anyhow::Result;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::sea_query::OnConflict;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{
+	ColumnTrait, DatabaseConnection, EntityTrait, IntoActiveModel, ModelTrait, QueryFilter,
+};
+use shared::anilist::airing_dm::{display_name, track_next_episode};
+use shared::anilist::minimal_anime::Media;
+use shared::database::prelude::{AiringDmSetting, AiringEpisode, AiringSubscription};
+use shared::database::{airing_dm_setting, airing_episode, airing_subscription};
+use std::collections::HashMap;
+
+/// Followed anime listed by `anilist notifications`.
+pub const FOLLOWED_LIST_LIMIT: usize = 15;
+
+pub enum Followed {
+	/// The next episode and when it airs.
+	Airing {
+		name: String,
+		episode: i32,
+		airing_at: i64,
+	},
+	/// Nothing scheduled, the anime is finished or not announced yet.
+	NotAiring { name: String },
+}
+
+/// Follows an anime, a muted one from the list is followed again.
+pub async fn follow_anime(
+	db: &DatabaseConnection, user_id: &str, media: Media,
+) -> Result<Followed> {
+	let name = media
+		.title
+		.map(|title| display_name(title.english, title.romaji, media.id))
+		.unwrap_or_else(|| media.id.to_string());
+	let Some(next) = media
+		.next_airing_episode
+		.filter(|next| next.airing_at as i64 > Utc::now().timestamp())
+	else {
+		return Ok(Followed::NotAiring { name });
+	};
+
+	AiringSubscription::insert(airing_subscription::ActiveModel {
+		user_id: Set(user_id.to_string()),
+		anime_id: Set(media.id),
+		from_list: Set(false),
+		muted: Set(false),
+		created_at: Set(Utc::now().naive_utc()),
+	})
+	.on_conflict(
+		OnConflict::columns([
+			airing_subscription::Column::UserId,
+			airing_subscription::Column::AnimeId,
+		])
+		.update_columns([
+			airing_subscription::Column::FromList,
+			airing_subscription::Column::Muted,
+		])
+		.to_owned(),
+	)
+	.exec(db)
+	.await?;
+
+	track_next_episode(
+		db,
+		media.id,
+		name.clone(),
+		Some((next.airing_at as i64, next.episode)),
+	)
+	.await?;
+
+	Ok(Followed::Airing {
+		name,
+		episode: next.episode,
+		airing_at: next.airing_at as i64,
+	})
+}
+
+/// Stops the DMs of an anime, `false` when the member did not follow it.
+pub async fn unfollow_anime(db: &DatabaseConnection, user_id: &str, anime_id: i32) -> Result<bool> {
+	let Some(subscription) = AiringSubscription::find_by_id((user_id.to_string(), anime_id))
+		.one(db)
+		.await?
+	else {
+		return Ok(false);
+	};
+
+	if subscription.muted {
+		return Ok(false);
+	}
+
+	if subscription.from_list {
+		let mut active = subscription.into_active_model();
+		active.muted = Set(true);
+		AiringSubscription::update(active).exec(db).await?;
+	} else {
+		subscription.delete(db).await?;
+	}
+
+	Ok(true)
+}
+
+/// A followed anime and its next episode, when AniList scheduled one.
+pub struct FollowedAnime {
+	pub anime_id: i32,
+	pub name: Option<String>,
+	pub next_episode: Option<(i32, NaiveDateTime)>,
+	pub from_list: bool,
+}
+
+/// The anime the member gets DMs for, the next to air first.
+pub async fn followed_anime(db: &DatabaseConnection, user_id: &str) -> Result<Vec<FollowedAnime>> {
+	let subscriptions = AiringSubscription::find()
+		.filter(airing_subscription::Column::UserId.eq(user_id))
+		.filter(airing_subscription::Column::Muted.eq(false))
+		.all(db)
+		.await?;
+
+	let episodes: HashMap<i32, airing_episode::Model> = AiringEpisode::find()
+		.filter(
+			airing_episode::Column::AnimeId.is_in(
+				subscriptions
+					.iter()
+					.map(|subscription| subscription.anime_id),
+			),
+		)
+		.all(db)
+		.await?
+		.into_iter()
+		.map(|episode| (episode.anime_id, episode))
+		.collect();
+
+	let mut followed: Vec<FollowedAnime> = subscriptions
+		.into_iter()
+		.map(|subscription| {
+			let episode = episodes.get(&subscription.anime_id);
+
+			FollowedAnime {
+				anime_id: subscription.anime_id,
+				name: episode.map(|episode| episode.name.clone()),
+				next_episode: episode.map(|episode| (episode.episode, episode.airing_at)),
+				from_list: subscription.from_list,
+			}
+		})
+		.collect();
+	followed.sort_by_key(|anime| {
+		(
+			anime.next_episode.is_none(),
+			anime.next_episode.map(|(_, airing_at)| airing_at),
+		)
+	});
+
+	Ok(followed)
+}
+
+pub async fn dm_setting(
+	db: &DatabaseConnection, user_id: &str,
+) -> Result<Option<airing_dm_setting::Model>> {
+	Ok(AiringDmSetting::find_by_id(user_id.to_string())
+		.one(db)
+		.await?)
+}
+
+/// What `anilist notifications` changes, `None` keeps the current value.
+#[derive(Default)]
+pub struct SettingChange {
+	pub auto_follow: Option<bool>,
+	pub muted: Option<bool>,
+	/// `Some(None)` ends the snooze.
+	pub snoozed_until: Option<Option<NaiveDateTime>>,
+}
+
+impl SettingChange {
+	pub fn is_empty(&self) -> bool {
+		self.auto_follow.is_none() && self.muted.is_none() && self.snoozed_until.is_none()
+	}
+}
+
+/// Applies the change and returns the new settings. Turning auto-follow off
+/// unfollows the anime the list followed.
+pub async fn update_dm_setting(
+	db: &DatabaseConnection, user_id: &str, change: SettingChange,
+) -> Result<airing_dm_setting::Model> {
+	let current = dm_setting(db, user_id).await?;
+	let was_auto_follow = current.as_ref().is_some_and(|setting| setting.auto_follow);
+
+	let setting = airing_dm_setting::Model {
+		user_id: user_id.to_string(),
+		auto_follow: change.auto_follow.unwrap_or(was_auto_follow),
+		muted: change
+			.muted
+			.unwrap_or(current.as_ref().is_some_and(|setting| setting.muted)),
+		snoozed_until: change
+			.snoozed_until
+			.unwrap_or(current.as_ref().and_then(|setting| setting.snoozed_until)),
+		window_start: current.as_ref().and_then(|setting| setting.window_start),
+		window_count: current
+			.as_ref()
+			.map(|setting| setting.window_count)
+			.unwrap_or(0),
+	};
+
+	AiringDmSetting::insert(airing_dm_setting::ActiveModel {
+		user_id: Set(setting.user_id.clone()),
+		auto_follow: Set(setting.auto_follow),
+		muted: Set(setting.muted),
+		snoozed_until: Set(setting.snoozed_until),
+		window_start: Set(setting.window_start),
+		window_count: Set(setting.window_count),
+	})
+	.on_conflict(
+		OnConflict::column(airing_dm_setting::Column::UserId)
+			.update_columns([
+				airing_dm_setting::Column::AutoFollow,
+				airing_dm_setting::Column::Muted,
+				airing_dm_setting::Column::SnoozedUntil,
+			])
+			.to_owned(),
+	)
+	.exec(db)
+	.await?;
+
+	if was_auto_follow && !setting.auto_follow {
+		AiringSubscription::delete_many()
+			.filter(airing_subscription::Column::UserId.eq(user_id))
+			.filter(airing_subscription::Column::FromList.eq(true))
+			.exec(db)
+			.await?;
+	}
+
+	Ok(setting)
+}
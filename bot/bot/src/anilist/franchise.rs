@@ -0,0 +1,457 @@
+//! Walking the relations of a title for `anilist franchise`.
+//!
+//! The walk is breadth first, one `Page` request per layer, and stops after
+//! [`MAX_ENTRIES`] titles or [`MAX_REQUESTS`] requests. Only story relations
+//! between titles of the same type as the first one are followed: adaptations
+//! would pull the manga into an anime franchise, and character links reach
+//! unrelated crossovers. Every layer goes through the AniList cache, and a
+//! finished walk is cached under each of its titles, so asking for another
+//! entry of the same franchise costs no request.
+use crate::structure::autocomplete::anilist::media::{
+	MediaAutocomplete, MediaAutocompleteVariables,
+};
+use crate::structure::run::anilist::media_relation::{
+	Media, MediaRelation, MediaRelations, MediaRelationsVariables, MediaType,
+};
+use anyhow::Result;
+use cynic::{GraphQlResponse, QueryBuilder};
+use serde::{Deserialize, Serialize};
+use shared::anilist::make_request::{make_request_anilist, make_request_anilist_with_ttl};
+use shared::cache::CacheInterface;
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap, HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{debug, warn};
+
+/// Titles kept in a franchise, the graph has to stay readable.
+pub const MAX_ENTRIES: usize = 40;
+/// Layers fetched at most, one request each.
+const MAX_REQUESTS: usize = 10;
+/// AniList pages hold at most 50 media.
+const PER_PAGE: usize = 50;
+/// Relations rarely change, a walk and its layers stay cached for a day.
+const FRANCHISE_TTL: u64 = 24 * 60 * 60;
+const CACHE_KEY_PREFIX: &str = "anilist_franchise:";
+/// Missing parts of a start date, they sort after the known ones.
+const UNKNOWN_MONTH: i32 = 13;
+const UNKNOWN_DAY: i32 = 32;
+
+/// How two titles of a franchise are related, from the older to the newer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Link {
+	Sequel,
+	SideStory,
+	SpinOff,
+	Summary,
+	Compilation,
+	/// Retellings of the same story, in no particular order.
+	Alternative,
+}
+
+impl Link {
+	/// Whether the first title of the edge should be watched before the other.
+	pub fn orders(self) -> bool {
+		self != Link::Alternative
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Edge {
+	pub from: i32,
+	pub to: i32,
+	pub link: Link,
+}
+
+impl Edge {
+	/// The edge for `relation` of `source` to `target`, `None` for the
+	/// relations the walk does not follow. Both sides of a relation give the
+	/// same edge.
+	pub fn from_relation(source: i32, relation: MediaRelation, target: i32) -> Option<Self> {
+		let (from, to, link) = match relation {
+			MediaRelation::Sequel => (source, target, Link::Sequel),
+			MediaRelation::Prequel => (target, source, Link::Sequel),
+			MediaRelation::SideStory => (source, target, Link::SideStory),
+			MediaRelation::Parent => (target, source, Link::SideStory),
+			MediaRelation::SpinOff => (source, target, Link::SpinOff),
+			MediaRelation::Summary => (source, target, Link::Summary),
+			MediaRelation::Compilation => (source, target, Link::Compilation),
+			MediaRelation::Contains => (target, source, Link::Compilation),
+			MediaRelation::Alternative => {
+				(source.min(target), source.max(target), Link::Alternative)
+			},
+			MediaRelation::Adaptation
+			| MediaRelation::Source
+			| MediaRelation::Character
+			| MediaRelation::Other => return None,
+		};
+
+		Some(Edge { from, to, link })
+	}
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+	pub id: i32,
+	pub title: String,
+	/// Drawn on the graph, the pixel font only has latin letters.
+	pub romaji: Option<String>,
+	pub format: Option<String>,
+	/// Year, month and day, `None` when not even the year is known.
+	pub start: Option<(i32, i32, i32)>,
+}
+
+impl Entry {
+	pub fn year(&self) -> Option<i32> {
+		self.start.map(|(year, _, _)| year)
+	}
+
+	fn order_key(&self) -> ((i32, i32, i32), i32) {
+		(
+			self.start.unwrap_or((i32::MAX, UNKNOWN_MONTH, UNKNOWN_DAY)),
+			self.id,
+		)
+	}
+}
+
+impl From<&Media> for Entry {
+	fn from(media: &Media) -> Self {
+		let english = media.title.as_ref().and_then(|title| title.english.clone());
+		let romaji = media.title.as_ref().and_then(|title| title.romaji.clone());
+
+		Entry {
+			id: media.id,
+			title: english
+				.or(romaji.clone())
+				.unwrap_or_else(|| media.id.to_string()),
+			romaji,
+			format: media.format.map(|format| format.label().to_string()),
+			start: media.start_date.as_ref().and_then(|date| {
+				date.year.map(|year| {
+					(
+						year,
+						date.month.unwrap_or(UNKNOWN_MONTH),
+						date.day.unwrap_or(UNKNOWN_DAY),
+					)
+				})
+			}),
+		}
+	}
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Franchise {
+	/// The title the walk started from.
+	pub root: i32,
+	/// Every title has the type of the first one.
+	pub manga: bool,
+	/// In the suggested watch order.
+	pub entries: Vec<Entry>,
+	pub edges: Vec<Edge>,
+	/// The walk hit a bound, some titles are missing.
+	pub truncated: bool,
+	/// Loops in the relations, broken at their earliest release.
+	pub cycles: usize,
+}
+
+/// The franchise of `root_id`, `None` when AniList does not know the title.
+pub async fn franchise(
+	root_id: i32, anilist_cache: Arc<RwLock<CacheInterface>>,
+) -> Result<Option<Franchise>> {
+	let key = format!("{}{}", CACHE_KEY_PREFIX, root_id);
+	match anilist_cache.read().await.read(&key).await {
+		Ok(Some(cached)) => match serde_json::from_str::<Franchise>(&cached) {
+			Ok(franchise) => return Ok(Some(franchise)),
+			Err(e) => warn!("Failed to read the cached franchise of {}: {}", root_id, e),
+		},
+		Ok(None) => {},
+		Err(e) => warn!(
+			"Failed to read the cached franchise of {}: {:#}",
+			root_id, e
+		),
+	}
+
+	let Some(franchise) = walk(root_id, anilist_cache.clone()).await? else {
+		return Ok(None);
+	};
+
+	// A complete walk is the same from any of its titles
+	let ids: Vec<i32> = if franchise.truncated {
+		vec![root_id]
+	} else {
+		franchise.entries.iter().map(|entry| entry.id).collect()
+	};
+	let cache = anilist_cache.write().await;
+	for id in ids {
+		let cached = Franchise {
+			root: id,
+			..franchise.clone()
+		};
+		cache
+			.write_with_ttl(
+				format!("{}{}", CACHE_KEY_PREFIX, id),
+				serde_json::to_string(&cached)?,
+				FRANCHISE_TTL,
+			)
+			.await?;
+	}
+
+	Ok(Some(franchise))
+}
+
+/// The id picked from the autocomplete, or the best match of a typed title.
+pub async fn resolve_media_id(
+	value: &str, anilist_cache: Arc<RwLock<CacheInterface>>,
+) -> Result<Option<i32>> {
+	if let Ok(id) = value.trim().parse::<i32>() {
+		return Ok(Some(id));
+	}
+
+	let operation = MediaAutocomplete::build(MediaAutocompleteVariables {
+		search: Some(value),
+		in_media_format: None,
+		media_type: None,
+	});
+	let response: GraphQlResponse<MediaAutocomplete> =
+		make_request_anilist(operation, true, anilist_cache).await?;
+
+	Ok(response
+		.data
+		.and_then(|data| data.page)
+		.and_then(|page| page.media)
+		.and_then(|media| media.into_iter().flatten().next())
+		.map(|media| media.id))
+}
+
+async fn walk(
+	root_id: i32, anilist_cache: Arc<RwLock<CacheInterface>>,
+) -> Result<Option<Franchise>> {
+	let mut entries: Vec<Entry> = Vec::new();
+	let mut edges: BTreeSet<Edge> = BTreeSet::new();
+	let mut queued: HashSet<i32> = HashSet::from([root_id]);
+	let mut frontier: Vec<i32> = vec![root_id];
+	let mut media_type: Option<MediaType> = None;
+	let mut truncated = false;
+	let mut requests = 0;
+
+	while !frontier.is_empty() {
+		if requests == MAX_REQUESTS {
+			truncated = true;
+			break;
+		}
+		requests += 1;
+
+		let layer: Vec<i32> = frontier.drain(..frontier.len().min(PER_PAGE)).collect();
+		for media in fetch_layer(layer, anilist_cache.clone()).await? {
+			let media_type =
+				*media_type.get_or_insert(media.media_type.unwrap_or(MediaType::Anime));
+
+			let related = media
+				.relations
+				.iter()
+				.flat_map(|relations| relations.edges.iter().flatten().flatten());
+			for related in related {
+				let (Some(relation), Some(node)) = (related.relation_type, &related.node) else {
+					continue;
+				};
+				if node.media_type != Some(media_type) {
+					continue;
+				}
+				let Some(edge) = Edge::from_relation(media.id, relation, node.id) else {
+					continue;
+				};
+
+				if !queued.contains(&node.id) {
+					if queued.len() >= MAX_ENTRIES {
+						truncated = true;
+						continue;
+					}
+					queued.insert(node.id);
+					frontier.push(node.id);
+				}
+				edges.insert(edge);
+			}
+
+			entries.push(Entry::from(&media));
+		}
+	}
+
+	if !entries.iter().any(|entry| entry.id == root_id) {
+		return Ok(None);
+	}
+	debug!(
+		"Walked the franchise of {} in {} requests: {} titles, truncated: {}",
+		root_id,
+		requests,
+		entries.len(),
+		truncated
+	);
+
+	// Titles AniList did not return or the bounds cut off
+	let ids: HashSet<i32> = entries.iter().map(|entry| entry.id).collect();
+	let edges: Vec<Edge> = edges
+		.into_iter()
+		.filter(|edge| ids.contains(&edge.from) && ids.contains(&edge.to))
+		.collect();
+	let (entries, cycles) = watch_order(entries, &edges);
+
+	Ok(Some(Franchise {
+		root: root_id,
+		manga: media_type == Some(MediaType::Manga),
+		entries,
+		edges,
+		truncated,
+		cycles,
+	}))
+}
+
+async fn fetch_layer(
+	ids: Vec<i32>, anilist_cache: Arc<RwLock<CacheInterface>>,
+) -> Result<Vec<Media>> {
+	let operation = MediaRelations::build(MediaRelationsVariables {
+		per_page: Some(ids.len() as i32),
+		ids: Some(ids),
+	});
+	let response: GraphQlResponse<MediaRelations> =
+		make_request_anilist_with_ttl(operation, anilist_cache, |_: &MediaRelations| FRANCHISE_TTL)
+			.await?;
+
+	Ok(response
+		.data
+		.and_then(|data| data.page)
+		.and_then(|page| page.media)
+		.unwrap_or_default()
+		.into_iter()
+		.flatten()
+		.collect())
+}
+
+/// Sorts the titles so each one comes after those it follows, the earliest
+/// released first when several are free. Returns the number of loops that
+/// had to be broken.
+pub fn watch_order(entries: Vec<Entry>, edges: &[Edge]) -> (Vec<Entry>, usize) {
+	let index: HashMap<i32, usize> = entries
+		.iter()
+		.enumerate()
+		.map(|(index, entry)| (entry.id, index))
+		.collect();
+
+	let mut waiting_on = vec![0usize; entries.len()];
+	let mut next: Vec<Vec<usize>> = vec![Vec::new(); entries.len()];
+	for edge in edges.iter().filter(|edge| edge.link.orders()) {
+		if let (Some(&from), Some(&to)) = (index.get(&edge.from), index.get(&edge.to)) {
+			if from != to {
+				next[from].push(to);
+				waiting_on[to] += 1;
+			}
+		}
+	}
+
+	let mut ready: BinaryHeap<Reverse<(((i32, i32, i32), i32), usize)>> = waiting_on
+		.iter()
+		.enumerate()
+		.filter(|(_, waiting)| **waiting == 0)
+		.map(|(index, _)| Reverse((entries[index].order_key(), index)))
+		.collect();
+	let mut placed = vec![false; entries.len()];
+	let mut order = Vec::with_capacity(entries.len());
+	let mut cycles = 0;
+
+	while order.len() < entries.len() {
+		let current = match ready.pop() {
+			Some(Reverse((_, index))) => index,
+			None => {
+				// Every title left waits on another one, the relations loop
+				cycles += 1;
+				match (0..entries.len())
+					.filter(|index| !placed[*index])
+					.min_by_key(|index| entries[*index].order_key())
+				{
+					Some(index) => index,
+					None => break,
+				}
+			},
+		};
+
+		placed[current] = true;
+		order.push(current);
+		for &following in &next[current] {
+			waiting_on[following] = waiting_on[following].saturating_sub(1);
+			if waiting_on[following] == 0 && !placed[following] {
+				ready.push(Reverse((entries[following].order_key(), following)));
+			}
+		}
+	}
+
+	let mut entries: Vec<Option<Entry>> = entries.into_iter().map(Some).collect();
+	let ordered = order
+		.into_iter()
+		.filter_map(|index| entries[index].take())
+		.collect();
+
+	(ordered, cycles)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn entry(id: i32, year: i32) -> Entry {
+		Entry {
+			id,
+			title: format!("title {}", id),
+			romaji: None,
+			format: None,
+			start: Some((year, 1, 1)),
+		}
+	}
+
+	fn ids(entries: &[Entry]) -> Vec<i32> {
+		entries.iter().map(|entry| entry.id).collect()
+	}
+
+	#[test]
+	fn test_both_sides_of_a_relation_give_one_edge() {
+		assert_eq!(
+			Edge::from_relation(1, MediaRelation::Sequel, 2),
+			Edge::from_relation(2, MediaRelation::Prequel, 1)
+		);
+		assert_eq!(
+			Edge::from_relation(1, MediaRelation::SideStory, 2),
+			Edge::from_relation(2, MediaRelation::Parent, 1)
+		);
+		assert_eq!(
+			Edge::from_relation(1, MediaRelation::Alternative, 2),
+			Edge::from_relation(2, MediaRelation::Alternative, 1)
+		);
+		assert_eq!(Edge::from_relation(1, MediaRelation::Adaptation, 2), None);
+	}
+
+	#[test]
+	fn test_sequels_come_after_their_prequel() {
+		// The sequel is dated before its prequel, the relation wins
+		let entries = vec![entry(3, 2015), entry(1, 2012), entry(2, 2010)];
+		let edges = vec![
+			Edge::from_relation(1, MediaRelation::Sequel, 2).unwrap(),
+			Edge::from_relation(1, MediaRelation::SideStory, 3).unwrap(),
+		];
+
+		let (ordered, cycles) = watch_order(entries, &edges);
+		assert_eq!(ids(&ordered), vec![1, 2, 3]);
+		assert_eq!(cycles, 0);
+	}
+
+	#[test]
+	fn test_loops_are_broken_at_the_earliest_release() {
+		let entries = vec![entry(1, 2012), entry(2, 2010), entry(3, 2014)];
+		let edges = vec![
+			Edge::from_relation(1, MediaRelation::Sequel, 2).unwrap(),
+			Edge::from_relation(2, MediaRelation::Sequel, 1).unwrap(),
+			Edge::from_relation(2, MediaRelation::Sequel, 3).unwrap(),
+		];
+
+		let (ordered, cycles) = watch_order(entries, &edges);
+		assert_eq!(ids(&ordered), vec![2, 1, 3]);
+		assert_eq!(cycles, 1);
+	}
+}
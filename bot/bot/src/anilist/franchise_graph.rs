@@ -0,0 +1,342 @@
+//! Relation graph image for `anilist franchise`.
+//!
+//! Titles are boxes laid out in rows: a title sits one row below the
+//! furthest title it follows, so a sequel chain reads from top to bottom.
+//! Rows wider than [`COLUMNS`] wrap. Like the level cards, rendering is CPU
+//! bound and callers run it through `tokio::task::spawn_blocking`.
+use crate::anilist::franchise::{Franchise, Link};
+use crate::helper::pixel_font::{draw_text, text_width, GLYPH_HEIGHT, GLYPH_WIDTH};
+use anyhow::Result;
+use image::{ImageFormat, Rgba, RgbaImage};
+use std::collections::HashMap;
+use std::io::Cursor;
+
+const BACKGROUND: Rgba<u8> = Rgba([30, 30, 36, 255]);
+const BOX_BACKGROUND: Rgba<u8> = Rgba([48, 48, 58, 255]);
+const BOX_BORDER: Rgba<u8> = Rgba([80, 80, 92, 255]);
+const ROOT_BORDER: Rgba<u8> = Rgba([61, 180, 242, 255]);
+const TEXT: Rgba<u8> = Rgba([235, 235, 240, 255]);
+const MUTED_TEXT: Rgba<u8> = Rgba([160, 160, 170, 255]);
+
+pub const BOX_WIDTH: u32 = 232;
+pub const BOX_HEIGHT: u32 = 56;
+const GAP_X: u32 = 24;
+const GAP_Y: u32 = 40;
+pub const PADDING: u32 = 24;
+/// Titles per row before it wraps.
+pub const COLUMNS: usize = 5;
+const TEXT_SCALE: u32 = 2;
+const BORDER: u32 = 2;
+const SWATCH: u32 = 16;
+const LEGEND_HEIGHT: u32 = SWATCH + PADDING;
+/// Square drawn at the end of an edge, on the newer title.
+const ARROW: u32 = 8;
+
+fn link_color(link: Link) -> Rgba<u8> {
+	match link {
+		Link::Sequel => Rgba([61, 180, 242, 255]),
+		Link::SideStory => Rgba([120, 200, 120, 255]),
+		Link::SpinOff => Rgba([240, 170, 70, 255]),
+		Link::Summary => Rgba([170, 130, 230, 255]),
+		Link::Compilation => Rgba([230, 110, 150, 255]),
+		Link::Alternative => Rgba([140, 140, 150, 255]),
+	}
+}
+
+fn link_label(link: Link) -> &'static str {
+	match link {
+		Link::Sequel => "SEQUEL",
+		Link::SideStory => "SIDE STORY",
+		Link::SpinOff => "SPIN-OFF",
+		Link::Summary => "SUMMARY",
+		Link::Compilation => "COMPILATION",
+		Link::Alternative => "ALTERNATIVE",
+	}
+}
+
+/// The rows of titles, as indexes into the watch order.
+pub fn rows(franchise: &Franchise) -> Vec<Vec<usize>> {
+	let position: HashMap<i32, usize> = franchise
+		.entries
+		.iter()
+		.enumerate()
+		.map(|(index, entry)| (entry.id, index))
+		.collect();
+
+	// Edges going back in the watch order are the broken loops
+	let mut layer = vec![0usize; franchise.entries.len()];
+	for (index, entry) in franchise.entries.iter().enumerate() {
+		layer[index] = franchise
+			.edges
+			.iter()
+			.filter(|edge| edge.to == entry.id && edge.link.orders())
+			.filter_map(|edge| position.get(&edge.from))
+			.filter(|from| **from < index)
+			.map(|from| layer[*from] + 1)
+			.max()
+			.unwrap_or(0);
+	}
+
+	let layers = layer.iter().max().map(|max| max + 1).unwrap_or(0);
+	(0..layers)
+		.flat_map(|current| {
+			let titles: Vec<usize> = (0..layer.len())
+				.filter(|index| layer[*index] == current)
+				.collect();
+
+			titles
+				.chunks(COLUMNS)
+				.map(<[usize]>::to_vec)
+				.collect::<Vec<_>>()
+		})
+		.collect()
+}
+
+fn legend_links(franchise: &Franchise) -> Vec<Link> {
+	let mut links: Vec<Link> = franchise.edges.iter().map(|edge| edge.link).collect();
+	links.sort();
+	links.dedup();
+	links
+}
+
+fn legend_width(links: &[Link]) -> u32 {
+	links
+		.iter()
+		.map(|link| SWATCH + 6 + text_width(link_label(*link), TEXT_SCALE) + PADDING)
+		.sum()
+}
+
+pub fn render_franchise(franchise: &Franchise) -> Result<Vec<u8>> {
+	let rows = rows(franchise);
+	let links = legend_links(franchise);
+
+	let columns = rows.iter().map(Vec::len).max().unwrap_or(1) as u32;
+	let graph_width = columns * BOX_WIDTH + (columns - 1) * GAP_X;
+	let width = 2 * PADDING + graph_width.max(legend_width(&links));
+	let height = 2 * PADDING + rows.len() as u32 * (BOX_HEIGHT + GAP_Y) - GAP_Y + LEGEND_HEIGHT;
+	let mut img = RgbaImage::from_pixel(width, height, BACKGROUND);
+
+	let mut boxes: HashMap<i32, (u32, u32)> = HashMap::new();
+	for (row_index, row) in rows.iter().enumerate() {
+		let row_width = row.len() as u32 * (BOX_WIDTH + GAP_X) - GAP_X;
+		let left = (width - row_width) / 2;
+		let top = PADDING + row_index as u32 * (BOX_HEIGHT + GAP_Y);
+
+		for (column, index) in row.iter().enumerate() {
+			let x = left + column as u32 * (BOX_WIDTH + GAP_X);
+			boxes.insert(franchise.entries[*index].id, (x, top));
+		}
+	}
+
+	// Edges first, the boxes cover their ends
+	for edge in &franchise.edges {
+		let (Some(&(from_x, from_y)), Some(&(to_x, to_y))) =
+			(boxes.get(&edge.from), boxes.get(&edge.to))
+		else {
+			continue;
+		};
+		let color = link_color(edge.link);
+
+		let (start, end) = if to_y > from_y {
+			(
+				(from_x + BOX_WIDTH / 2, from_y + BOX_HEIGHT),
+				(to_x + BOX_WIDTH / 2, to_y),
+			)
+		} else {
+			(
+				(from_x + BOX_WIDTH / 2, from_y + BOX_HEIGHT / 2),
+				(to_x + BOX_WIDTH / 2, to_y + BOX_HEIGHT / 2),
+			)
+		};
+		draw_line(&mut img, start, end, color);
+		draw_line(&mut img, (start.0 + 1, start.1), (end.0 + 1, end.1), color);
+
+		if to_y > from_y {
+			fill_rect(
+				&mut img,
+				end.0.saturating_sub(ARROW / 2),
+				end.1.saturating_sub(ARROW),
+				ARROW,
+				ARROW,
+				color,
+			);
+		}
+	}
+
+	let max_chars = ((BOX_WIDTH - 2 * 8) / ((GLYPH_WIDTH + 1) * TEXT_SCALE)) as usize;
+	for (position, entry) in franchise.entries.iter().enumerate() {
+		let Some(&(x, y)) = boxes.get(&entry.id) else {
+			continue;
+		};
+
+		let border = if entry.id == franchise.root {
+			ROOT_BORDER
+		} else {
+			BOX_BORDER
+		};
+		fill_rect(&mut img, x, y, BOX_WIDTH, BOX_HEIGHT, border);
+		fill_rect(
+			&mut img,
+			x + BORDER,
+			y + BORDER,
+			BOX_WIDTH - 2 * BORDER,
+			BOX_HEIGHT - 2 * BORDER,
+			BOX_BACKGROUND,
+		);
+
+		let mut header = format!("{}.", position + 1);
+		if let Some(format) = &entry.format {
+			header.push(' ');
+			header.push_str(format);
+		}
+		if let Some(year) = entry.year() {
+			header.push_str(&format!(" {}", year));
+		}
+		draw_text(&mut img, &header, x + 8, y + 8, TEXT_SCALE, MUTED_TEXT);
+
+		let title: String = entry
+			.romaji
+			.as_deref()
+			.unwrap_or(&entry.title)
+			.chars()
+			.take(max_chars)
+			.collect();
+		draw_text(
+			&mut img,
+			&title,
+			x + 8,
+			y + BOX_HEIGHT - 8 - GLYPH_HEIGHT * TEXT_SCALE,
+			TEXT_SCALE,
+			TEXT,
+		);
+	}
+
+	let mut legend_x = PADDING;
+	let legend_y = height - PADDING / 2 - SWATCH;
+	for link in links {
+		fill_rect(
+			&mut img,
+			legend_x,
+			legend_y,
+			SWATCH,
+			SWATCH,
+			link_color(link),
+		);
+		legend_x += SWATCH + 6;
+
+		let label = link_label(link);
+		draw_text(
+			&mut img,
+			label,
+			legend_x,
+			legend_y + (SWATCH - GLYPH_HEIGHT * TEXT_SCALE) / 2,
+			TEXT_SCALE,
+			TEXT,
+		);
+		legend_x += text_width(label, TEXT_SCALE) + PADDING;
+	}
+
+	let mut bytes: Vec<u8> = Vec::new();
+	img.write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)?;
+
+	Ok(bytes)
+}
+
+fn fill_rect(img: &mut RgbaImage, x: u32, y: u32, width: u32, height: u32, color: Rgba<u8>) {
+	for px in x..(x + width).min(img.width()) {
+		for py in y..(y + height).min(img.height()) {
+			img.put_pixel(px, py, color);
+		}
+	}
+}
+
+/// Bresenham line, pixels outside the image are clipped.
+fn draw_line(img: &mut RgbaImage, start: (u32, u32), end: (u32, u32), color: Rgba<u8>) {
+	let (mut x, mut y) = (start.0 as i64, start.1 as i64);
+	let (end_x, end_y) = (end.0 as i64, end.1 as i64);
+	let dx = (end_x - x).abs();
+	let dy = -(end_y - y).abs();
+	let step_x = if x < end_x { 1 } else { -1 };
+	let step_y = if y < end_y { 1 } else { -1 };
+	let mut error = dx + dy;
+
+	loop {
+		if x >= 0 && y >= 0 && (x as u32) < img.width() && (y as u32) < img.height() {
+			img.put_pixel(x as u32, y as u32, color);
+		}
+		if x == end_x && y == end_y {
+			break;
+		}
+
+		let doubled = 2 * error;
+		if doubled >= dy {
+			error += dy;
+			x += step_x;
+		}
+		if doubled <= dx {
+			error += dx;
+			y += step_y;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::anilist::franchise::{Edge, Entry};
+
+	fn franchise(count: i32, side_stories: i32) -> Franchise {
+		let entries: Vec<Entry> = (1..=count + side_stories)
+			.map(|id| Entry {
+				id,
+				title: format!("title {}", id),
+				romaji: None,
+				format: Some(String::from("TV")),
+				start: Some((2000 + id, 1, 1)),
+			})
+			.collect();
+		let mut edges: Vec<Edge> = (1..count)
+			.map(|id| Edge {
+				from: id,
+				to: id + 1,
+				link: Link::Sequel,
+			})
+			.collect();
+		edges.extend((count + 1..=count + side_stories).map(|id| Edge {
+			from: 1,
+			to: id,
+			link: Link::SideStory,
+		}));
+
+		Franchise {
+			root: 1,
+			manga: false,
+			entries,
+			edges,
+			truncated: false,
+			cycles: 0,
+		}
+	}
+
+	#[test]
+	fn test_sequels_go_one_row_down_and_wide_rows_wrap() {
+		let rows = rows(&franchise(3, 6));
+
+		// The side stories and the sequel all follow the first title
+		assert_eq!(rows[0], vec![0]);
+		assert_eq!(rows[1].len(), COLUMNS);
+		assert_eq!(rows[2].len(), 7 - COLUMNS);
+		assert_eq!(rows[3], vec![2]);
+	}
+
+	#[test]
+	fn test_graph_height_follows_rows() {
+		let png = render_franchise(&franchise(3, 0)).unwrap();
+
+		let img = image::load_from_memory(&png).unwrap();
+		assert_eq!(
+			img.height(),
+			2 * PADDING + 3 * (BOX_HEIGHT + GAP_Y) - GAP_Y + LEGEND_HEIGHT
+		);
+	}
+}
@@ -0,0 +1,296 @@
+//! Server leaderboards and the registered user list.
+//!
+//! The worker keeps `anilist_user_stats` up to date for every registered
+//! account, so both only read the database: the guild's members come from
+//! `server_user_relation` and their accounts from `registered_user`, without
+//! asking Discord or AniList anything while the member waits.
+use anyhow::Result;
+use chrono::NaiveDateTime;
+use sea_orm::sea_query::{Query, SelectStatement};
+use sea_orm::{
+	ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+	QuerySelect, QueryTrait, Select,
+};
+use shared::database::prelude::{AnilistUserStats, RegisteredUser, ServerUserRelation};
+use shared::database::{anilist_user_stats, registered_user, server_user_relation};
+use std::collections::HashMap;
+
+pub const LEADERBOARD_PAGE_SIZE: u64 = 10;
+/// Registered users shown per page of `list_user`.
+pub const REGISTERED_PAGE_SIZE: u64 = 20;
+
+/// What a leaderboard ranks members by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stat {
+	Level,
+	MinutesWatched,
+	ChaptersRead,
+	MeanScore,
+	Completed,
+}
+
+impl Stat {
+	pub const ALL: &'static [Stat] = &[
+		Stat::Level,
+		Stat::MinutesWatched,
+		Stat::ChaptersRead,
+		Stat::MeanScore,
+		Stat::Completed,
+	];
+
+	/// Name used by the command choices, the button ids and the translation keys.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Stat::Level => "level",
+			Stat::MinutesWatched => "minutes_watched",
+			Stat::ChaptersRead => "chapters_read",
+			Stat::MeanScore => "mean_score",
+			Stat::Completed => "completed",
+		}
+	}
+
+	pub fn parse(value: &str) -> Option<Self> {
+		Self::ALL
+			.iter()
+			.copied()
+			.find(|stat| stat.as_str() == value)
+	}
+
+	fn column(self) -> anilist_user_stats::Column {
+		match self {
+			// The level only grows with the experience
+			Stat::Level => anilist_user_stats::Column::Xp,
+			Stat::MinutesWatched => anilist_user_stats::Column::MinutesWatched,
+			Stat::ChaptersRead => anilist_user_stats::Column::ChaptersRead,
+			Stat::MeanScore => anilist_user_stats::Column::MeanScore,
+			Stat::Completed => anilist_user_stats::Column::Completed,
+		}
+	}
+
+	pub fn value(self, stats: &anilist_user_stats::Model) -> f64 {
+		match self {
+			Stat::Level => stats.xp,
+			Stat::MinutesWatched => stats.minutes_watched as f64,
+			Stat::ChaptersRead => stats.chapters_read as f64,
+			Stat::MeanScore => stats.mean_score,
+			Stat::Completed => stats.completed as f64,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct StatEntry {
+	pub rank: u64,
+	pub user_id: String,
+	pub stats: anilist_user_stats::Model,
+}
+
+pub struct StatPage {
+	pub entries: Vec<StatEntry>,
+	pub page: u64,
+	pub total_pages: u64,
+	/// The viewer's own position, `None` when they are not ranked.
+	pub viewer: Option<StatEntry>,
+	/// When the worker last refreshed the shown stats.
+	pub updated_at: Option<NaiveDateTime>,
+}
+
+/// Discord ids of the guild members.
+fn guild_members(guild_id: &str) -> SelectStatement {
+	Query::select()
+		.column(server_user_relation::Column::UserId)
+		.from(ServerUserRelation)
+		.and_where(server_user_relation::Column::GuildId.eq(guild_id))
+		.to_owned()
+}
+
+/// AniList ids of the guild members with a registered account.
+fn guild_accounts(guild_id: &str, verified_only: bool) -> SelectStatement {
+	let mut accounts = RegisteredUser::find()
+		.select_only()
+		.column(registered_user::Column::AnilistId)
+		.filter(registered_user::Column::UserId.in_subquery(guild_members(guild_id)));
+	if verified_only {
+		accounts = accounts.filter(registered_user::Column::Verified.eq(true));
+	}
+
+	accounts.into_query()
+}
+
+fn ranked_stats(guild_id: &str, stat: Stat, verified_only: bool) -> Select<AnilistUserStats> {
+	let mut query = AnilistUserStats::find().filter(
+		anilist_user_stats::Column::AnilistId.in_subquery(guild_accounts(guild_id, verified_only)),
+	);
+
+	// Members who score nothing have no mean to compare
+	if stat == Stat::MeanScore {
+		query = query.filter(anilist_user_stats::Column::MeanScore.gt(0.0));
+	}
+
+	query
+}
+
+/// The Discord user behind each AniList account, among the guild members.
+async fn discord_ids(
+	db: &DatabaseConnection, guild_id: &str, anilist_ids: Vec<i32>,
+) -> Result<HashMap<i32, String>> {
+	Ok(RegisteredUser::find()
+		.filter(registered_user::Column::AnilistId.is_in(anilist_ids))
+		.filter(registered_user::Column::UserId.in_subquery(guild_members(guild_id)))
+		.all(db)
+		.await?
+		.into_iter()
+		.map(|row| (row.anilist_id, row.user_id))
+		.collect())
+}
+
+pub async fn stat_page(
+	db: &DatabaseConnection, guild_id: &str, stat: Stat, verified_only: bool, viewer_id: &str,
+	page: u64,
+) -> Result<StatPage> {
+	let total = ranked_stats(guild_id, stat, verified_only)
+		.count(db)
+		.await?;
+	let total_pages = total.div_ceil(LEADERBOARD_PAGE_SIZE).max(1);
+	let page = page.min(total_pages - 1);
+
+	let rows = ranked_stats(guild_id, stat, verified_only)
+		.order_by_desc(stat.column())
+		.order_by_asc(anilist_user_stats::Column::AnilistId)
+		.offset(page * LEADERBOARD_PAGE_SIZE)
+		.limit(LEADERBOARD_PAGE_SIZE)
+		.all(db)
+		.await?;
+
+	let users = discord_ids(
+		db,
+		guild_id,
+		rows.iter().map(|row| row.anilist_id).collect(),
+	)
+	.await?;
+	let updated_at = rows.iter().map(|row| row.updated_at).max();
+
+	let entries = rows
+		.into_iter()
+		.enumerate()
+		.filter_map(|(index, stats)| {
+			Some(StatEntry {
+				rank: page * LEADERBOARD_PAGE_SIZE + index as u64 + 1,
+				user_id: users.get(&stats.anilist_id)?.clone(),
+				stats,
+			})
+		})
+		.collect();
+
+	let viewer = match RegisteredUser::find_by_id(viewer_id.to_string())
+		.one(db)
+		.await?
+	{
+		Some(registered) => {
+			match ranked_stats(guild_id, stat, verified_only)
+				.filter(anilist_user_stats::Column::AnilistId.eq(registered.anilist_id))
+				.one(db)
+				.await?
+			{
+				// Ties share the better rank
+				Some(stats) => {
+					let above = ranked_stats(guild_id, stat, verified_only)
+						.filter(stat.column().gt(stat.value(&stats)))
+						.count(db)
+						.await?;
+
+					Some(StatEntry {
+						rank: above + 1,
+						user_id: registered.user_id,
+						stats,
+					})
+				},
+				None => None,
+			}
+		},
+		None => None,
+	};
+
+	Ok(StatPage {
+		entries,
+		page,
+		total_pages,
+		viewer,
+		updated_at,
+	})
+}
+
+#[derive(Debug, Clone)]
+pub struct RegisteredEntry {
+	pub user_id: String,
+	pub anilist_id: i32,
+	pub verified: bool,
+	/// The AniList name once the worker fetched the account.
+	pub name: Option<String>,
+}
+
+pub struct RegisteredPage {
+	pub entries: Vec<RegisteredEntry>,
+	pub page: u64,
+	pub total_pages: u64,
+}
+
+/// The guild members with a registered AniList account, a page at a time.
+pub async fn registered_page(
+	db: &DatabaseConnection, guild_id: &str, verified_only: bool, page: u64,
+) -> Result<RegisteredPage> {
+	let mut query = RegisteredUser::find()
+		.filter(registered_user::Column::UserId.in_subquery(guild_members(guild_id)));
+	if verified_only {
+		query = query.filter(registered_user::Column::Verified.eq(true));
+	}
+
+	let total = query.clone().count(db).await?;
+	let total_pages = total.div_ceil(REGISTERED_PAGE_SIZE).max(1);
+	let page = page.min(total_pages - 1);
+
+	let rows = query
+		.order_by_asc(registered_user::Column::RegisteredAt)
+		.order_by_asc(registered_user::Column::UserId)
+		.offset(page * REGISTERED_PAGE_SIZE)
+		.limit(REGISTERED_PAGE_SIZE)
+		.all(db)
+		.await?;
+
+	let names: HashMap<i32, String> = AnilistUserStats::find()
+		.filter(anilist_user_stats::Column::AnilistId.is_in(rows.iter().map(|row| row.anilist_id)))
+		.all(db)
+		.await?
+		.into_iter()
+		.map(|stats| (stats.anilist_id, stats.name))
+		.collect();
+
+	let entries = rows
+		.into_iter()
+		.map(|row| RegisteredEntry {
+			name: names.get(&row.anilist_id).cloned(),
+			user_id: row.user_id,
+			anilist_id: row.anilist_id,
+			verified: row.verified,
+		})
+		.collect();
+
+	Ok(RegisteredPage {
+		entries,
+		page,
+		total_pages,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_stat_round_trip() {
+		for stat in Stat::ALL {
+			assert_eq!(Stat::parse(stat.as_str()), Some(*stat));
+		}
+		assert_eq!(Stat::parse("episodes"), None);
+	}
+}
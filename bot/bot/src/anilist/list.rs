@@ -0,0 +1,331 @@
+//! Editing a member's AniList list from Discord.
+//!
+//! Members who linked their account through `verify` have an encrypted
+//! AniList token in `anilist_token`. [`linked_account`] decrypts it, the other
+//! functions send the list queries and the `SaveMediaListEntry` mutation with
+//! it. AniList tokens last a year and cannot be refreshed, so an expired or
+//! revoked one means the member has to link again, see [`needs_relink`].
+use crate::structure::run::anilist::list_entry::{
+	CurrentList, CurrentListVariables, ListEntry, ListEntryVariables, MediaList, MediaListStatus,
+	SaveMediaListEntry, SaveMediaListEntryVariables, ScoreFormat, ViewerOptions,
+};
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use cynic::{GraphQlResponse, MutationBuilder, QueryBuilder};
+use sea_orm::{DatabaseConnection, EntityTrait};
+use shared::anilist::make_request::{make_authed_request_anilist, InvalidAnilistToken};
+use shared::anilist::oauth::TokenCipher;
+use shared::config::Config;
+use shared::database::prelude::AnilistToken;
+
+/// Entries offered by the autocomplete, Discord shows at most 25.
+pub const CURRENT_LIST_SIZE: i32 = 25;
+
+pub struct LinkedAccount {
+	pub anilist_id: i32,
+	pub access_token: String,
+}
+
+pub enum Link {
+	Linked(LinkedAccount),
+	/// The member never logged in on AniList.
+	Missing,
+	/// The stored token expired, the member has to log in again.
+	Expired,
+}
+
+/// The member's AniList account and token, when verified linking is set up.
+pub async fn linked_account(
+	db_connection: &DatabaseConnection, config: &Config, user_id: &str,
+) -> Result<Link> {
+	let Some(anilist_config) = config.api.anilist.as_ref() else {
+		return Ok(Link::Missing);
+	};
+
+	let Some(token) = AnilistToken::find_by_id(user_id.to_string())
+		.one(db_connection)
+		.await?
+	else {
+		return Ok(Link::Missing);
+	};
+
+	if token.expires_at <= Utc::now().naive_utc() {
+		return Ok(Link::Expired);
+	}
+
+	let cipher = TokenCipher::from_base64_key(&anilist_config.token_key)?;
+
+	Ok(Link::Linked(LinkedAccount {
+		anilist_id: token.anilist_id,
+		access_token: cipher.decrypt(&token.access_token)?,
+	}))
+}
+
+/// Whether `error` comes from AniList refusing the member's token.
+pub fn needs_relink(error: &anyhow::Error) -> bool {
+	error.downcast_ref::<InvalidAnilistToken>().is_some()
+}
+
+fn into_data<U>(response: GraphQlResponse<U>) -> Result<U> {
+	if let Some(data) = response.data {
+		return Ok(data);
+	}
+
+	let message = response
+		.errors
+		.unwrap_or_default()
+		.into_iter()
+		.map(|error| error.message)
+		.collect::<Vec<_>>()
+		.join(", ");
+
+	Err(anyhow!("AniList returned no data: {}", message))
+}
+
+/// The member's entry for `media_id`, `None` when it is not on their list.
+pub async fn get_entry(account: &LinkedAccount, media_id: i32) -> Result<Option<MediaList>> {
+	let operation = ListEntry::build(ListEntryVariables {
+		user_id: Some(account.anilist_id),
+		media_id: Some(media_id),
+	});
+	let response: GraphQlResponse<ListEntry> =
+		make_authed_request_anilist(operation, &account.access_token).await?;
+
+	// AniList answers "Not Found." with no data for media off the list
+	Ok(response.data.and_then(|data| data.media_list))
+}
+
+/// What the member is watching or reading, most recently updated first.
+pub async fn current_list(account: &LinkedAccount) -> Result<Vec<MediaList>> {
+	let operation = CurrentList::build(CurrentListVariables {
+		user_id: Some(account.anilist_id),
+		per_page: Some(CURRENT_LIST_SIZE),
+	});
+	let response: GraphQlResponse<CurrentList> =
+		make_authed_request_anilist(operation, &account.access_token).await?;
+
+	Ok(into_data(response)?
+		.page
+		.and_then(|page| page.media_list)
+		.unwrap_or_default()
+		.into_iter()
+		.flatten()
+		.collect())
+}
+
+pub async fn score_format(account: &LinkedAccount) -> Result<ScoreFormat> {
+	let operation = ViewerOptions::build(());
+	let response: GraphQlResponse<ViewerOptions> =
+		make_authed_request_anilist(operation, &account.access_token).await?;
+
+	Ok(into_data(response)?
+		.viewer
+		.and_then(|viewer| viewer.media_list_options)
+		.and_then(|options| options.score_format)
+		.unwrap_or(ScoreFormat::Point10))
+}
+
+pub async fn save_entry(
+	account: &LinkedAccount, variables: SaveMediaListEntryVariables,
+) -> Result<MediaList> {
+	let operation = SaveMediaListEntry::build(variables);
+	let response: GraphQlResponse<SaveMediaListEntry> =
+		make_authed_request_anilist(operation, &account.access_token).await?;
+
+	into_data(response)?
+		.save_media_list_entry
+		.ok_or(anyhow!("AniList did not return the saved entry"))
+}
+
+/// Progress after adding `by`, kept between 0 and the episode or chapter
+/// count when AniList knows it.
+pub fn next_progress(current: Option<i32>, by: i32, total: Option<i32>) -> i32 {
+	let progress = current.unwrap_or(0).saturating_add(by).max(0);
+
+	match total {
+		Some(total) if total > 0 => progress.min(total),
+		_ => progress,
+	}
+}
+
+/// The status an entry moves to with its new progress, `None` to keep it.
+/// Reaching the end completes it, any other progress on an entry that is
+/// not being watched makes it current.
+pub fn progress_status(
+	status: Option<MediaListStatus>, progress: i32, total: Option<i32>,
+) -> Option<MediaListStatus> {
+	if total.is_some_and(|total| total > 0 && progress >= total) {
+		return (status != Some(MediaListStatus::Completed)).then_some(MediaListStatus::Completed);
+	}
+
+	match status {
+		Some(MediaListStatus::Current) | Some(MediaListStatus::Repeating) => None,
+		_ if progress > 0 => Some(MediaListStatus::Current),
+		_ => None,
+	}
+}
+
+pub fn score_max(format: ScoreFormat) -> f64 {
+	match format {
+		ScoreFormat::Point100 => 100.0,
+		ScoreFormat::Point10Decimal | ScoreFormat::Point10 => 10.0,
+		ScoreFormat::Point5 => 5.0,
+		ScoreFormat::Point3 => 3.0,
+	}
+}
+
+/// The score AniList expects for `value` in the member's format, `None` when
+/// it is out of range.
+pub fn validate_score(format: ScoreFormat, value: f64) -> Option<f64> {
+	if !value.is_finite() || !(0.0..=score_max(format)).contains(&value) {
+		return None;
+	}
+
+	Some(match format {
+		ScoreFormat::Point10Decimal => (value * 10.0).round() / 10.0,
+		_ => value.round(),
+	})
+}
+
+pub struct ProgressUpdate {
+	pub entry: MediaList,
+	pub previous: i32,
+}
+
+/// Add `by` episodes or chapters to the member's entry for `media_id`,
+/// adding the media to their list when it is not on it yet.
+pub async fn add_progress(
+	account: &LinkedAccount, media_id: i32, by: i32,
+) -> Result<ProgressUpdate> {
+	let entry = get_entry(account, media_id).await?;
+
+	save_progress(account, media_id, entry, by).await
+}
+
+/// Same as [`add_progress`] for callers that already fetched the entry.
+pub async fn save_progress(
+	account: &LinkedAccount, media_id: i32, entry: Option<MediaList>, by: i32,
+) -> Result<ProgressUpdate> {
+	let previous = entry.as_ref().and_then(|entry| entry.progress).unwrap_or(0);
+	let status = entry.as_ref().and_then(|entry| entry.status);
+	let total = entry
+		.as_ref()
+		.and_then(|entry| entry.media.as_ref())
+		.and_then(|media| media.episodes.or(media.chapters));
+
+	let progress = next_progress(Some(previous), by, total);
+	let entry = save_entry(
+		account,
+		SaveMediaListEntryVariables {
+			media_id: Some(media_id),
+			status: progress_status(status, progress, total),
+			score: None,
+			progress: Some(progress),
+		},
+	)
+	.await?;
+
+	Ok(ProgressUpdate { entry, previous })
+}
+
+/// The media id behind a command argument. The autocomplete sends the id,
+/// a typed title is looked up in the member's current list.
+pub async fn resolve_media(account: &LinkedAccount, value: &str) -> Result<Option<i32>> {
+	if let Ok(media_id) = value.trim().parse::<i32>() {
+		return Ok(Some(media_id));
+	}
+
+	let search = value.trim().to_lowercase();
+	let entries = current_list(account).await?;
+
+	Ok(entries
+		.iter()
+		.find(|entry| entry_title(entry).to_lowercase() == search)
+		.or_else(|| {
+			entries
+				.iter()
+				.find(|entry| entry_title(entry).to_lowercase().contains(&search))
+		})
+		.and_then(|entry| entry.media.as_ref())
+		.map(|media| media.id))
+}
+
+/// The media title in the member's preferred language.
+pub fn entry_title(entry: &MediaList) -> String {
+	entry
+		.media
+		.as_ref()
+		.and_then(|media| media.title.as_ref())
+		.and_then(|title| {
+			title
+				.user_preferred
+				.clone()
+				.or(title.english.clone())
+				.or(title.romaji.clone())
+		})
+		.unwrap_or_default()
+}
+
+/// `progress/total`, or just the progress when the total is unknown.
+pub fn entry_progress(entry: &MediaList) -> String {
+	let progress = entry.progress.unwrap_or(0);
+	let total = entry
+		.media
+		.as_ref()
+		.and_then(|media| media.episodes.or(media.chapters));
+
+	match total {
+		Some(total) => format!("{}/{}", progress, total),
+		None => progress.to_string(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_next_progress_bounds() {
+		assert_eq!(next_progress(Some(3), 1, Some(12)), 4);
+		assert_eq!(next_progress(None, 2, None), 2);
+		assert_eq!(next_progress(Some(11), 5, Some(12)), 12);
+		assert_eq!(next_progress(Some(1), -3, Some(12)), 0);
+		// A total of 0 means AniList does not know it
+		assert_eq!(next_progress(Some(20), 1, Some(0)), 21);
+	}
+
+	#[test]
+	fn test_progress_status() {
+		use MediaListStatus::*;
+
+		assert_eq!(progress_status(None, 1, Some(12)), Some(Current));
+		assert_eq!(progress_status(Some(Planning), 1, Some(12)), Some(Current));
+		assert_eq!(progress_status(Some(Current), 5, Some(12)), None);
+		assert_eq!(progress_status(Some(Repeating), 5, Some(12)), None);
+		assert_eq!(
+			progress_status(Some(Current), 12, Some(12)),
+			Some(Completed)
+		);
+		assert_eq!(progress_status(Some(Completed), 12, Some(12)), None);
+		assert_eq!(progress_status(Some(Paused), 0, Some(12)), None);
+	}
+
+	#[test]
+	fn test_validate_score() {
+		assert_eq!(validate_score(ScoreFormat::Point100, 85.0), Some(85.0));
+		assert_eq!(validate_score(ScoreFormat::Point100, 101.0), None);
+		assert_eq!(validate_score(ScoreFormat::Point10Decimal, 7.56), Some(7.6));
+		assert_eq!(validate_score(ScoreFormat::Point10, 7.4), Some(7.0));
+		assert_eq!(validate_score(ScoreFormat::Point5, 6.0), None);
+		assert_eq!(validate_score(ScoreFormat::Point3, -1.0), None);
+		assert_eq!(validate_score(ScoreFormat::Point3, f64::NAN), None);
+	}
+
+	#[test]
+	fn test_status_round_trip() {
+		for status in MediaListStatus::ALL {
+			assert_eq!(MediaListStatus::parse(status.as_str()), Some(*status));
+		}
+		assert_eq!(MediaListStatus::parse("watching"), None);
+	}
+}
@@ -0,0 +1,138 @@
+//! AniList features that act on the member's own account.
+//!
+//! They need the verified link from `verify`; commands answer with
+//! [`relink_embed`] when it is missing or AniList stopped accepting the token.
+pub mod compare;
+pub mod follow;
+pub mod franchise;
+pub mod franchise_graph;
+pub mod leaderboard;
+pub mod list;
+pub mod recommend;
+pub mod schedule;
+pub mod season;
+
+use crate::command::embed_content::{
+	ComponentVersion, ComponentVersion2, EmbedContent, EmbedsContents,
+};
+use crate::components::anilist::verify::VERIFY_BUTTON;
+use crate::structure::run::anilist::list_entry::MediaList;
+use anyhow::Result;
+use fluent_templates::fluent_bundle::FluentValue;
+use list::{entry_progress, entry_title, needs_relink};
+use serenity::all::{ButtonStyle, CreateActionRow, CreateButton, CreateComponent};
+use shared::localization::{Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use unic_langid::LanguageIdentifier;
+
+/// Why the member has to log in on AniList again.
+#[derive(Clone, Copy)]
+pub enum Relink {
+	NotLinked,
+	Expired,
+}
+
+pub fn relink_text(lang_id: &LanguageIdentifier, reason: Relink) -> String {
+	let key = match reason {
+		Relink::NotLinked => "anilist_list-not_linked",
+		Relink::Expired => "anilist_list-expired",
+	};
+
+	USABLE_LOCALES.lookup(lang_id, key)
+}
+
+/// The `verify` button, so the member gets a login link without typing the
+/// command.
+pub fn relink_row<'a>(lang_id: &LanguageIdentifier) -> CreateComponent<'a> {
+	let button = CreateButton::new(VERIFY_BUTTON)
+		.label(USABLE_LOCALES.lookup(lang_id, "anilist_user_verify-button"))
+		.style(ButtonStyle::Primary);
+
+	CreateComponent::ActionRow(CreateActionRow::Buttons(Cow::Owned(vec![button])))
+}
+
+pub fn relink_embed<'a>(lang_id: &LanguageIdentifier, reason: Relink) -> EmbedsContents<'a> {
+	let embed_content =
+		EmbedContent::new(USABLE_LOCALES.lookup(lang_id, "anilist_list-relink_title"))
+			.description(relink_text(lang_id, reason));
+
+	EmbedsContents::new(vec![embed_content]).action_row(ComponentVersion::V2(ComponentVersion2 {
+		components: Cow::Owned(vec![relink_row(lang_id)]),
+	}))
+}
+
+/// The relink embed when AniList refused the member's token, `error`
+/// otherwise.
+pub fn relink_on_refusal<'a>(
+	lang_id: &LanguageIdentifier, error: anyhow::Error,
+) -> Result<EmbedsContents<'a>> {
+	if needs_relink(&error) {
+		return Ok(relink_embed(lang_id, Relink::Expired));
+	}
+
+	Err(error)
+}
+
+/// Answer for a typed title that matches nothing on the member's current list.
+pub fn not_found_embed<'a>(lang_id: &LanguageIdentifier, media: String) -> EmbedsContents<'a> {
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(Cow::Borrowed("media"), FluentValue::from(media));
+
+	let embed_content =
+		EmbedContent::new(USABLE_LOCALES.lookup(lang_id, "anilist_list-not_found_title"))
+			.description(USABLE_LOCALES.lookup_with_args(lang_id, "anilist_list-not_found", &args));
+
+	EmbedsContents::new(vec![embed_content])
+}
+
+/// An embed showing the entry after an update, `description` says what changed.
+pub fn entry_embed(
+	lang_id: &LanguageIdentifier, entry: &MediaList, description: String,
+) -> EmbedContent {
+	let media = entry.media.as_ref();
+
+	let status = entry
+		.status
+		.map(|status| {
+			USABLE_LOCALES.lookup(lang_id, &format!("anilist_list-status_{}", status.as_str()))
+		})
+		.unwrap_or_else(|| String::from("-"));
+	let score = entry
+		.score
+		.filter(|score| *score > 0.0)
+		.map(|score| score.to_string())
+		.unwrap_or_else(|| String::from("-"));
+
+	let mut embed_content = EmbedContent::new(entry_title(entry))
+		.description(description)
+		.fields(vec![
+			(
+				USABLE_LOCALES.lookup(lang_id, "anilist_list-progress"),
+				entry_progress(entry),
+				true,
+			),
+			(
+				USABLE_LOCALES.lookup(lang_id, "anilist_list-status"),
+				status,
+				true,
+			),
+			(
+				USABLE_LOCALES.lookup(lang_id, "anilist_list-score"),
+				score,
+				true,
+			),
+		]);
+
+	if let Some(url) = media.and_then(|media| media.site_url.clone()) {
+		embed_content = embed_content.url(url);
+	}
+	if let Some(cover) = media
+		.and_then(|media| media.cover_image.as_ref())
+		.and_then(|cover| cover.large.clone())
+	{
+		embed_content = embed_content.thumbnail(cover);
+	}
+
+	embed_content
+}
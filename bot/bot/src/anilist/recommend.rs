@@ -0,0 +1,527 @@
+//! Recommending titles from a user's AniList list.
+//!
+//! The best scored completed titles are the seeds. AniList users vote on
+//! which titles to recommend for each of them, so every recommended title
+//! collects the votes of the seeds that point to it, weighted by how much the
+//! user liked that seed. The tags the user scores above their own mean add a
+//! smaller bonus. The seeds are fetched in one `Page` query and everything
+//! goes through the AniList cache, so asking again costs no request.
+use crate::anilist::compare::{fetch_list_type, ListItem};
+use crate::structure::run::anilist::list_collection;
+use crate::structure::run::anilist::media_recommendation::{
+	Media, MediaFormat, MediaRecommendations, MediaRecommendationsVariables, MediaType, SeedMedia,
+	UserStatistics, UserTagStatistics, UserTagStatisticsVariables,
+};
+use anyhow::Result;
+use cynic::{GraphQlResponse, QueryBuilder};
+use shared::anilist::make_request::make_request_anilist;
+use shared::cache::CacheInterface;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// Picks shown by the command.
+pub const RECOMMEND_COUNT: usize = 10;
+/// Scores out of 100 from which a completed title is used as a seed.
+const SEED_SCORE: f64 = 70.0;
+const MAX_SEEDS: usize = 25;
+/// AniList pages hold at most 50 media.
+const SEED_BATCH: usize = 50;
+const RECOMMENDATIONS_PER_SEED: i32 = 15;
+/// Titles a tag needs on the list before its mean score is trusted.
+const MIN_TAG_COUNT: i32 = 3;
+/// How much the tag bonus weighs against the recommendation votes.
+const TAG_WEIGHT: f64 = 2.0;
+/// Matched tags named in the explanation of a pick.
+const EXPLAINED_TAGS: usize = 2;
+
+#[derive(Clone, Debug, Default)]
+pub struct RecommendFilter {
+	/// Compared without case.
+	pub genre: Option<String>,
+	pub format: Option<MediaFormat>,
+	pub year: Option<i32>,
+}
+
+impl RecommendFilter {
+	pub fn matches(&self, media: &Media) -> bool {
+		if let Some(genre) = &self.genre {
+			let genres = media.genres.as_deref().unwrap_or_default();
+			if !genres
+				.iter()
+				.flatten()
+				.any(|name| name.eq_ignore_ascii_case(genre.trim()))
+			{
+				return false;
+			}
+		}
+
+		if self.format.is_some() && media.format != self.format {
+			return false;
+		}
+
+		if self.year.is_some() && media_year(media) != self.year {
+			return false;
+		}
+
+		true
+	}
+}
+
+fn media_year(media: &Media) -> Option<i32> {
+	media
+		.season_year
+		.or(media.start_date.as_ref().and_then(|date| date.year))
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pick {
+	pub media_id: i32,
+	pub title: String,
+	pub url: Option<String>,
+	pub mean_score: Option<i32>,
+	pub score: f64,
+	/// The seed that pushed this pick the most, with the user's score out of 100.
+	pub because: (String, f64),
+	/// Tags of the pick the user likes, the strongest first.
+	pub tags: Vec<String>,
+}
+
+/// The completed titles the user scored best, the best first.
+pub fn seeds(items: &[ListItem]) -> Vec<&ListItem> {
+	let mut seeds: Vec<&ListItem> = items
+		.iter()
+		.filter(|item| item.completed && item.score >= SEED_SCORE)
+		.collect();
+
+	seeds.sort_by(|a, b| {
+		b.score
+			.partial_cmp(&a.score)
+			.unwrap_or(Ordering::Equal)
+			.then_with(|| a.media_id.cmp(&b.media_id))
+	});
+	seeds.truncate(MAX_SEEDS);
+
+	seeds
+}
+
+/// How much more than their mean the user scores each tag, lowercased, only
+/// for the tags they like.
+pub fn tag_affinity(statistics: &UserStatistics) -> HashMap<String, f64> {
+	statistics
+		.tags
+		.as_deref()
+		.unwrap_or_default()
+		.iter()
+		.flatten()
+		.filter(|stat| stat.count >= MIN_TAG_COUNT && stat.mean_score > statistics.mean_score)
+		.filter_map(|stat| {
+			let tag = stat.tag.as_ref()?;
+
+			Some((
+				tag.name.to_lowercase(),
+				(stat.mean_score - statistics.mean_score) / 100.0,
+			))
+		})
+		.collect()
+}
+
+struct Candidate {
+	media: Media,
+	votes: f64,
+	because: (String, f64),
+	because_weight: f64,
+}
+
+/// Ranks the titles recommended for the seeds. Titles in `seen` are left out,
+/// as are adult titles, the other media type and titles the filter rejects.
+pub fn rank(
+	seeds: &[&ListItem], seed_media: Vec<SeedMedia>, affinity: &HashMap<String, f64>,
+	seen: &HashSet<i32>, media_type: MediaType, filter: &RecommendFilter,
+) -> Vec<Pick> {
+	let seed_by_id: HashMap<i32, &ListItem> =
+		seeds.iter().map(|seed| (seed.media_id, *seed)).collect();
+	let mut candidates: HashMap<i32, Candidate> = HashMap::new();
+
+	for seed_media in seed_media {
+		let Some(seed) = seed_by_id.get(&seed_media.id) else {
+			continue;
+		};
+
+		let recommendations = seed_media
+			.recommendations
+			.and_then(|connection| connection.nodes)
+			.unwrap_or_default();
+
+		for recommendation in recommendations.into_iter().flatten() {
+			// Downvoted recommendations are AniList users saying it does not fit
+			let rating = recommendation.rating.unwrap_or(0);
+			let Some(media) = recommendation.media_recommendation else {
+				continue;
+			};
+			if rating <= 0
+				|| seen.contains(&media.id)
+				|| media.is_adult == Some(true)
+				|| media.media_type != Some(media_type)
+				|| !filter.matches(&media)
+			{
+				continue;
+			}
+
+			let weight = seed.score / 100.0 * (1.0 + rating as f64).ln();
+			let because = (seed.title.clone(), seed.score);
+
+			let candidate = candidates.entry(media.id).or_insert(Candidate {
+				media,
+				votes: 0.0,
+				because: because.clone(),
+				because_weight: 0.0,
+			});
+			candidate.votes += weight;
+			if weight > candidate.because_weight {
+				candidate.because = because;
+				candidate.because_weight = weight;
+			}
+		}
+	}
+
+	let mut picks: Vec<Pick> = candidates
+		.into_values()
+		.map(|candidate| {
+			let mut tags: Vec<(String, f64)> = candidate
+				.media
+				.tags
+				.as_deref()
+				.unwrap_or_default()
+				.iter()
+				.flatten()
+				.filter_map(|tag| {
+					let affinity = affinity.get(&tag.name.to_lowercase())?;
+
+					Some((
+						tag.name.clone(),
+						affinity * tag.rank.unwrap_or(0) as f64 / 100.0,
+					))
+				})
+				.filter(|(_, bonus)| *bonus > 0.0)
+				.collect();
+			tags.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+
+			let bonus: f64 = tags.iter().map(|(_, bonus)| bonus).sum();
+			let media = candidate.media;
+
+			Pick {
+				media_id: media.id,
+				title: media
+					.title
+					.and_then(|title| title.user_preferred)
+					.unwrap_or_default(),
+				url: media.site_url,
+				mean_score: media.mean_score,
+				score: candidate.votes + TAG_WEIGHT * bonus,
+				because: candidate.because,
+				tags: tags
+					.into_iter()
+					.take(EXPLAINED_TAGS)
+					.map(|(name, _)| name)
+					.collect(),
+			}
+		})
+		.collect();
+
+	picks.sort_by(|a, b| {
+		b.score
+			.partial_cmp(&a.score)
+			.unwrap_or(Ordering::Equal)
+			.then_with(|| a.media_id.cmp(&b.media_id))
+	});
+
+	picks
+}
+
+/// A score out of 100 as the user reads it out of 10, `9` or `8.5`.
+pub fn score_out_of_ten(score: f64) -> String {
+	let score = score.round() / 10.0;
+
+	if score.fract() == 0.0 {
+		format!("{}", score as i32)
+	} else {
+		format!("{:.1}", score)
+	}
+}
+
+/// The recommendation edges of every seed, a page of 50 at a time.
+async fn fetch_seed_media(
+	seed_ids: &[i32], anilist_cache: Arc<RwLock<CacheInterface>>,
+) -> Result<Vec<SeedMedia>> {
+	let mut seed_media = Vec::new();
+
+	for ids in seed_ids.chunks(SEED_BATCH) {
+		let operation = MediaRecommendations::build(MediaRecommendationsVariables {
+			ids: Some(ids.to_vec()),
+			per_page: Some(ids.len() as i32),
+			per_media: Some(RECOMMENDATIONS_PER_SEED),
+		});
+		let response: GraphQlResponse<MediaRecommendations> =
+			make_request_anilist(operation, true, anilist_cache.clone()).await?;
+
+		seed_media.extend(
+			response
+				.data
+				.and_then(|data| data.page)
+				.and_then(|page| page.media)
+				.unwrap_or_default()
+				.into_iter()
+				.flatten(),
+		);
+	}
+
+	Ok(seed_media)
+}
+
+/// The tag affinity of the user for one media type, empty when AniList has
+/// no statistics for it.
+async fn fetch_tag_affinity(
+	user_id: i32, media_type: MediaType, anilist_cache: Arc<RwLock<CacheInterface>>,
+) -> Result<HashMap<String, f64>> {
+	let operation = UserTagStatistics::build(UserTagStatisticsVariables { id: Some(user_id) });
+	let response: GraphQlResponse<UserTagStatistics> =
+		make_request_anilist(operation, true, anilist_cache).await?;
+
+	let statistics = response
+		.data
+		.and_then(|data| data.user)
+		.and_then(|user| user.statistics)
+		.and_then(|statistics| match media_type {
+			MediaType::Anime => statistics.anime,
+			MediaType::Manga => statistics.manga,
+		});
+
+	Ok(statistics.as_ref().map(tag_affinity).unwrap_or_default())
+}
+
+/// Up to [`RECOMMEND_COUNT`] titles of `media_type` the user has not added to
+/// their list yet.
+pub async fn recommend(
+	user_id: i32, media_type: MediaType, filter: &RecommendFilter,
+	anilist_cache: Arc<RwLock<CacheInterface>>,
+) -> Result<Vec<Pick>> {
+	let list_type = match media_type {
+		MediaType::Anime => list_collection::MediaType::Anime,
+		MediaType::Manga => list_collection::MediaType::Manga,
+	};
+	let items = fetch_list_type(user_id, list_type, anilist_cache.clone()).await?;
+
+	let seeds = seeds(&items);
+	if seeds.is_empty() {
+		return Ok(Vec::new());
+	}
+
+	let seed_ids: Vec<i32> = seeds.iter().map(|seed| seed.media_id).collect();
+	let seed_media = fetch_seed_media(&seed_ids, anilist_cache.clone()).await?;
+	let affinity = fetch_tag_affinity(user_id, media_type, anilist_cache).await?;
+
+	// Planned, dropped and paused titles are on the list too, the user knows them
+	let seen: HashSet<i32> = items.iter().map(|item| item.media_id).collect();
+
+	let mut picks = rank(&seeds, seed_media, &affinity, &seen, media_type, filter);
+	picks.truncate(RECOMMEND_COUNT);
+
+	Ok(picks)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::structure::run::anilist::media_recommendation::{
+		MediaTag, MediaTitle, Recommendation, RecommendationConnection, UserStatisticTag,
+		UserTagStatistic,
+	};
+
+	fn item(media_id: i32, score: f64, completed: bool) -> ListItem {
+		ListItem {
+			media_id,
+			title: format!("title {}", media_id),
+			url: None,
+			score,
+			planned: !completed,
+			completed,
+		}
+	}
+
+	fn media(id: i32, tags: &[(&str, i32)]) -> Media {
+		Media {
+			id,
+			media_type: Some(MediaType::Anime),
+			format: Some(MediaFormat::Tv),
+			title: Some(MediaTitle {
+				user_preferred: Some(format!("media {}", id)),
+			}),
+			site_url: None,
+			genres: Some(vec![Some(String::from("Drama"))]),
+			season_year: Some(2020),
+			start_date: None,
+			mean_score: Some(80),
+			is_adult: Some(false),
+			tags: Some(
+				tags.iter()
+					.map(|(name, rank)| {
+						Some(MediaTag {
+							name: name.to_string(),
+							rank: Some(*rank),
+						})
+					})
+					.collect(),
+			),
+		}
+	}
+
+	fn seed_media(id: i32, recommendations: Vec<(i32, Media)>) -> SeedMedia {
+		SeedMedia {
+			id,
+			recommendations: Some(RecommendationConnection {
+				nodes: Some(
+					recommendations
+						.into_iter()
+						.map(|(rating, media)| {
+							Some(Recommendation {
+								rating: Some(rating),
+								media_recommendation: Some(media),
+							})
+						})
+						.collect(),
+				),
+			}),
+		}
+	}
+
+	#[test]
+	fn test_seeds_keep_completed_high_scores() {
+		let items = vec![
+			item(1, 90.0, true),
+			item(2, 60.0, true),
+			item(3, 95.0, false),
+			item(4, 75.0, true),
+		];
+
+		let ids: Vec<i32> = seeds(&items).iter().map(|seed| seed.media_id).collect();
+
+		assert_eq!(ids, vec![1, 4]);
+	}
+
+	#[test]
+	fn test_rank_votes_and_explanation() {
+		let items = vec![
+			item(1, 90.0, true),
+			item(2, 70.0, true),
+			item(30, 0.0, false),
+		];
+		let seeds = seeds(&items);
+		let seen: HashSet<i32> = items.iter().map(|item| item.media_id).collect();
+
+		let picks = rank(
+			&seeds,
+			vec![
+				seed_media(1, vec![(50, media(10, &[])), (5, media(30, &[]))]),
+				seed_media(
+					2,
+					vec![
+						(100, media(10, &[])),
+						(20, media(11, &[])),
+						(-3, media(12, &[])),
+					],
+				),
+			],
+			&HashMap::new(),
+			&seen,
+			MediaType::Anime,
+			&RecommendFilter::default(),
+		);
+
+		let ids: Vec<i32> = picks.iter().map(|pick| pick.media_id).collect();
+		// 30 is already planned and 12 was downvoted
+		assert_eq!(ids, vec![10, 11]);
+		// 0.9 ln 51 beats 0.7 ln 101
+		assert_eq!(picks[0].because, (String::from("title 1"), 90.0));
+	}
+
+	#[test]
+	fn test_rank_tag_bonus_and_filter() {
+		let items = vec![item(1, 80.0, true)];
+		let seeds = seeds(&items);
+		let affinity = HashMap::from([(String::from("time skip"), 0.2)]);
+
+		let mut movie = media(12, &[]);
+		movie.format = Some(MediaFormat::Movie);
+
+		let recommendations = vec![
+			(10, media(10, &[])),
+			(10, media(11, &[("Time Skip", 90)])),
+			(10, movie),
+		];
+
+		let picks = rank(
+			&seeds,
+			vec![seed_media(1, recommendations.clone())],
+			&affinity,
+			&HashSet::new(),
+			MediaType::Anime,
+			&RecommendFilter {
+				genre: Some(String::from("drama")),
+				format: Some(MediaFormat::Tv),
+				year: Some(2020),
+			},
+		);
+
+		let ids: Vec<i32> = picks.iter().map(|pick| pick.media_id).collect();
+		assert_eq!(ids, vec![11, 10]);
+		assert_eq!(picks[0].tags, vec![String::from("Time Skip")]);
+
+		let picks = rank(
+			&seeds,
+			vec![seed_media(1, recommendations)],
+			&affinity,
+			&HashSet::new(),
+			MediaType::Anime,
+			&RecommendFilter {
+				year: Some(2021),
+				..Default::default()
+			},
+		);
+		assert!(picks.is_empty());
+	}
+
+	#[test]
+	fn test_tag_affinity_keeps_liked_tags() {
+		let stat = |name: &str, count: i32, mean_score: f64| {
+			Some(UserTagStatistic {
+				count,
+				mean_score,
+				tag: Some(UserStatisticTag {
+					name: name.to_string(),
+				}),
+			})
+		};
+		let statistics = UserStatistics {
+			mean_score: 75.0,
+			tags: Some(vec![
+				stat("Isekai", 10, 65.0),
+				stat("Iyashikei", 8, 85.0),
+				stat("Rare", 1, 99.0),
+			]),
+		};
+
+		let affinity = tag_affinity(&statistics);
+
+		assert_eq!(affinity.len(), 1);
+		assert!((affinity["iyashikei"] - 0.1).abs() < 1e-9);
+	}
+
+	#[test]
+	fn test_score_out_of_ten() {
+		assert_eq!(score_out_of_ten(90.0), "9");
+		assert_eq!(score_out_of_ten(85.0), "8.5");
+		assert_eq!(score_out_of_ten(100.0), "10");
+	}
+}
@@ -0,0 +1,382 @@
+//! The episodes airing on a day, for `anilist schedule`.
+//!
+//! The day is taken in the guild timezone, while the times are Discord
+//! timestamps each member sees in their own. The timetable can be limited to
+//! the anime the guild follows with `add_anime_activity` or those on the member's
+//! list.
+use crate::anilist::compare::fetch_list_type;
+use crate::minigame::daily::day_start;
+use crate::structure::run::anilist::airing::{
+	AiringSchedule, AiringScheduleVariables, AiringSchedules, MediaFormat,
+};
+use crate::structure::run::anilist::list_collection::MediaType;
+use anyhow::Result;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc, Weekday};
+use chrono_tz::Tz;
+use cynic::{GraphQlResponse, QueryBuilder};
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use shared::anilist::make_request::make_request_anilist_with_ttl;
+use shared::cache::CacheInterface;
+use shared::database::activity_data;
+use shared::database::prelude::{ActivityData, RegisteredUser};
+use shared::helper::get_guild_timezone::get_guild_timezone;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+pub const SCHEDULE_PAGE_SIZE: usize = 15;
+/// Episodes per AniList request, its maximum.
+const PER_PAGE: i32 = 50;
+/// A day rarely has more than a hundred episodes.
+const MAX_PAGES: i32 = 4;
+/// Shortest time a schedule page stays cached.
+pub const MIN_TTL: u64 = 5 * 60;
+/// Longest time a page with episodes still to air stays cached, as they can
+/// be delayed until they air.
+pub const UPCOMING_TTL: u64 = 60 * 60;
+/// How long a page of aired episodes stays cached, they no longer move.
+pub const AIRED_TTL: u64 = 6 * 60 * 60;
+
+/// The day a timetable is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleDay {
+	Today,
+	Tomorrow,
+	/// The next one, today when it is that day.
+	Weekday(Weekday),
+}
+
+const WEEKDAYS: [(Weekday, &str); 7] = [
+	(Weekday::Mon, "monday"),
+	(Weekday::Tue, "tuesday"),
+	(Weekday::Wed, "wednesday"),
+	(Weekday::Thu, "thursday"),
+	(Weekday::Fri, "friday"),
+	(Weekday::Sat, "saturday"),
+	(Weekday::Sun, "sunday"),
+];
+
+impl ScheduleDay {
+	/// Name used by the command choices and the button ids.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			ScheduleDay::Today => "today",
+			ScheduleDay::Tomorrow => "tomorrow",
+			ScheduleDay::Weekday(weekday) => WEEKDAYS
+				.iter()
+				.find(|(day, _)| *day == weekday)
+				.map(|(_, name)| *name)
+				.unwrap_or("today"),
+		}
+	}
+
+	pub fn parse(value: &str) -> Option<Self> {
+		match value {
+			"today" => Some(ScheduleDay::Today),
+			"tomorrow" => Some(ScheduleDay::Tomorrow),
+			_ => WEEKDAYS
+				.iter()
+				.find(|(_, name)| *name == value)
+				.map(|(weekday, _)| ScheduleDay::Weekday(*weekday)),
+		}
+	}
+
+	pub fn date(self, today: NaiveDate) -> NaiveDate {
+		match self {
+			ScheduleDay::Today => today,
+			ScheduleDay::Tomorrow => today + Duration::days(1),
+			ScheduleDay::Weekday(weekday) => {
+				let ahead = (weekday.num_days_from_monday() + 7
+					- today.weekday().num_days_from_monday())
+					% 7;
+				today + Duration::days(ahead as i64)
+			},
+		}
+	}
+}
+
+/// Which anime the timetable shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleScope {
+	All,
+	/// Those the guild follows with `add_anime_activity`.
+	Server,
+	/// Those on the member's list they have not completed.
+	List,
+}
+
+impl ScheduleScope {
+	pub const ALL: &'static [ScheduleScope] = &[
+		ScheduleScope::All,
+		ScheduleScope::Server,
+		ScheduleScope::List,
+	];
+
+	/// Name used by the command choices and the button ids.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			ScheduleScope::All => "all",
+			ScheduleScope::Server => "server",
+			ScheduleScope::List => "list",
+		}
+	}
+
+	pub fn parse(value: &str) -> Option<Self> {
+		Self::ALL
+			.iter()
+			.copied()
+			.find(|scope| scope.as_str() == value)
+	}
+}
+
+/// When `date` starts and ends in `timezone`.
+pub fn day_window(date: NaiveDate, timezone: &Tz) -> (DateTime<Utc>, DateTime<Utc>) {
+	(
+		day_start(date, timezone),
+		day_start(date + Duration::days(1), timezone),
+	)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduleEntry {
+	pub airing_at: i64,
+	pub episode: i32,
+	pub title: String,
+	pub url: Option<String>,
+	pub format: Option<MediaFormat>,
+	pub episodes: Option<i32>,
+}
+
+impl ScheduleEntry {
+	fn from_airing(airing: AiringSchedule) -> Option<Self> {
+		let media = airing.media?;
+		if media.is_adult.unwrap_or(false) {
+			return None;
+		}
+
+		Some(Self {
+			airing_at: airing.airing_at as i64,
+			episode: airing.episode,
+			title: media
+				.title
+				.and_then(|title| title.user_preferred)
+				.unwrap_or_else(|| media.id.to_string()),
+			url: media.site_url,
+			format: media.format,
+			episodes: media.episodes,
+		})
+	}
+}
+
+/// How long a page of episodes stays cached: until the next one airs, as it
+/// can still be delayed before, and long once they all aired.
+pub fn schedule_ttl(airings: impl Iterator<Item = i64>, now: i64) -> u64 {
+	match airings.filter(|airing_at| *airing_at > now).min() {
+		Some(airing_at) => ((airing_at - now) as u64).clamp(MIN_TTL, UPCOMING_TTL),
+		None => AIRED_TTL,
+	}
+}
+
+/// The episodes airing from `start` until `end`, in airing order. With
+/// `media_ids`, only the episodes of those anime.
+pub async fn fetch_schedule(
+	start: DateTime<Utc>, end: DateTime<Utc>, media_ids: Option<Vec<i32>>,
+	anilist_cache: Arc<RwLock<CacheInterface>>,
+) -> Result<Vec<ScheduleEntry>> {
+	if media_ids.as_ref().is_some_and(|ids| ids.is_empty()) {
+		return Ok(Vec::new());
+	}
+
+	let mut entries = Vec::new();
+
+	for page in 1..=MAX_PAGES {
+		let operation = AiringSchedules::build(AiringScheduleVariables {
+			// Both bounds are exclusive
+			airing_at_greater: Some(start.timestamp() as i32 - 1),
+			airing_at_lesser: Some(end.timestamp() as i32),
+			media_ids: media_ids.clone(),
+			page: Some(page),
+			per_page: Some(PER_PAGE),
+		});
+
+		let now = Utc::now().timestamp();
+		let response: GraphQlResponse<AiringSchedules> = make_request_anilist_with_ttl(
+			operation,
+			anilist_cache.clone(),
+			|data: &AiringSchedules| {
+				let airings = data
+					.page
+					.iter()
+					.flat_map(|page| page.airing_schedules.iter().flatten().flatten())
+					.map(|airing| airing.airing_at as i64);
+
+				schedule_ttl(airings, now)
+			},
+		)
+		.await?;
+
+		let Some(schedule_page) = response.data.and_then(|data| data.page) else {
+			break;
+		};
+
+		entries.extend(
+			schedule_page
+				.airing_schedules
+				.unwrap_or_default()
+				.into_iter()
+				.flatten()
+				.filter_map(ScheduleEntry::from_airing),
+		);
+
+		if !schedule_page
+			.page_info
+			.and_then(|info| info.has_next_page)
+			.unwrap_or(false)
+		{
+			break;
+		}
+	}
+
+	Ok(entries)
+}
+
+/// The anime the guild follows with `add_anime_activity`.
+pub async fn server_anime_ids(db: &DatabaseConnection, guild_id: &str) -> Result<Vec<i32>> {
+	Ok(ActivityData::find()
+		.filter(activity_data::Column::ServerId.eq(guild_id))
+		.all(db)
+		.await?
+		.into_iter()
+		.map(|activity| activity.anime_id)
+		.collect())
+}
+
+/// The anime on the list of the AniList user `user_id` they have not completed.
+pub async fn list_anime_ids(
+	user_id: i32, anilist_cache: Arc<RwLock<CacheInterface>>,
+) -> Result<Vec<i32>> {
+	Ok(fetch_list_type(user_id, MediaType::Anime, anilist_cache)
+		.await?
+		.into_iter()
+		.filter(|item| !item.completed)
+		.map(|item| item.media_id)
+		.collect())
+}
+
+/// A day of episodes, in the timezone the day was taken in.
+pub struct Timetable {
+	pub date: NaiveDate,
+	pub timezone: Tz,
+	pub entries: Vec<ScheduleEntry>,
+}
+
+/// The timetable of `day` for the member `user_id`, `None` when `scope` is
+/// their list and they have no registered account. Outside a guild the day
+/// is taken in UTC and no anime is followed.
+pub async fn load_timetable(
+	db: Arc<DatabaseConnection>, anilist_cache: Arc<RwLock<CacheInterface>>,
+	guild_id: Option<String>, user_id: &str, day: ScheduleDay, scope: ScheduleScope,
+) -> Result<Option<Timetable>> {
+	let timezone = match &guild_id {
+		Some(guild_id) => get_guild_timezone(guild_id.clone(), db.clone()).await,
+		None => Tz::UTC,
+	};
+
+	let media_ids = match scope {
+		ScheduleScope::All => None,
+		ScheduleScope::Server => Some(match &guild_id {
+			Some(guild_id) => server_anime_ids(&db, guild_id).await?,
+			None => Vec::new(),
+		}),
+		ScheduleScope::List => match RegisteredUser::find_by_id(user_id.to_string())
+			.one(&*db)
+			.await?
+		{
+			Some(registered) => {
+				Some(list_anime_ids(registered.anilist_id, anilist_cache.clone()).await?)
+			},
+			None => return Ok(None),
+		},
+	};
+
+	let date = day.date(Utc::now().with_timezone(&timezone).date_naive());
+	let (start, end) = day_window(date, &timezone);
+	let entries = fetch_schedule(start, end, media_ids, anilist_cache).await?;
+
+	Ok(Some(Timetable {
+		date,
+		timezone,
+		entries,
+	}))
+}
+
+pub struct SchedulePage {
+	pub entries: Vec<ScheduleEntry>,
+	pub page: usize,
+	pub total_pages: usize,
+}
+
+/// The `page`-th slice of the timetable, clamped to the last page.
+pub fn schedule_page(entries: Vec<ScheduleEntry>, page: usize) -> SchedulePage {
+	let total_pages = entries.len().div_ceil(SCHEDULE_PAGE_SIZE).max(1);
+	let page = page.min(total_pages - 1);
+
+	SchedulePage {
+		entries: entries
+			.into_iter()
+			.skip(page * SCHEDULE_PAGE_SIZE)
+			.take(SCHEDULE_PAGE_SIZE)
+			.collect(),
+		page,
+		total_pages,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use chrono::TimeZone;
+
+	#[test]
+	fn test_day_round_trip() {
+		for name in ["today", "tomorrow", "monday", "sunday"] {
+			assert_eq!(ScheduleDay::parse(name).unwrap().as_str(), name);
+		}
+		assert_eq!(ScheduleDay::parse("someday"), None);
+	}
+
+	#[test]
+	fn test_weekday_is_the_next_one() {
+		// A Wednesday
+		let today = NaiveDate::from_ymd_opt(2025, 10, 15).unwrap();
+
+		assert_eq!(ScheduleDay::Weekday(Weekday::Wed).date(today), today);
+		assert_eq!(
+			ScheduleDay::Weekday(Weekday::Fri).date(today),
+			NaiveDate::from_ymd_opt(2025, 10, 17).unwrap()
+		);
+		assert_eq!(
+			ScheduleDay::Weekday(Weekday::Mon).date(today),
+			NaiveDate::from_ymd_opt(2025, 10, 20).unwrap()
+		);
+	}
+
+	#[test]
+	fn test_window_follows_the_timezone() {
+		let tokyo: Tz = "Asia/Tokyo".parse().unwrap();
+		let date = NaiveDate::from_ymd_opt(2025, 10, 15).unwrap();
+
+		let (start, end) = day_window(date, &tokyo);
+
+		assert_eq!(start, Utc.with_ymd_and_hms(2025, 10, 14, 15, 0, 0).unwrap());
+		assert_eq!(end - start, Duration::hours(24));
+	}
+
+	#[test]
+	fn test_ttl_until_the_next_episode() {
+		let now = 1_000_000;
+
+		assert_eq!(schedule_ttl([now - 100, now + 1200].into_iter(), now), 1200);
+		assert_eq!(schedule_ttl([now + 86_400].into_iter(), now), UPCOMING_TTL);
+		assert_eq!(schedule_ttl([now - 100].into_iter(), now), AIRED_TTL);
+	}
+}
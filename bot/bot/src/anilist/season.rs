@@ -0,0 +1,324 @@
+//! The anime of a season, for `anilist season`.
+//!
+//! AniList filters by format and genre but not by studio, so the whole season
+//! is fetched, the studio filtered here and the pages cut from the result.
+//! Each AniList page stays cached until the next episode of its shows airs.
+use crate::structure::run::anilist::airing::{
+	Media, MediaFormat, MediaSeason, MediaStatus, SeasonMedia, SeasonMediaVariables,
+};
+use anyhow::Result;
+use chrono::{Datelike, NaiveDate, Utc};
+use cynic::{GraphQlResponse, QueryBuilder};
+use shared::anilist::make_request::make_request_anilist_with_ttl;
+use shared::cache::CacheInterface;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+pub const SEASON_PAGE_SIZE: usize = 10;
+/// Shows per AniList request, its maximum.
+const PER_PAGE: i32 = 50;
+/// Enough for the busiest seasons once music videos and specials are counted.
+const MAX_PAGES: i32 = 8;
+/// Longest a genre or studio filter kept in the button ids can be.
+const FILTER_MAX_LEN: usize = 30;
+/// Shortest time a season page stays cached.
+pub const MIN_TTL: u64 = 5 * 60;
+/// Longest time a season page stays cached, for finished seasons.
+pub const MAX_TTL: u64 = 6 * 60 * 60;
+
+/// The season airing on `date`, AniList counts December in the winter of the
+/// next year.
+pub fn current_season(date: NaiveDate) -> (i32, MediaSeason) {
+	match date.month() {
+		12 => (date.year() + 1, MediaSeason::Winter),
+		1 | 2 => (date.year(), MediaSeason::Winter),
+		3..=5 => (date.year(), MediaSeason::Spring),
+		6..=8 => (date.year(), MediaSeason::Summer),
+		_ => (date.year(), MediaSeason::Fall),
+	}
+}
+
+/// A season and the filters applied to it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeasonView {
+	pub year: i32,
+	pub season: MediaSeason,
+	pub format: Option<MediaFormat>,
+	pub genre: Option<String>,
+	pub studio: Option<String>,
+}
+
+/// Keeps a filter short enough for a button id and free of the separator.
+fn clean_filter(filter: Option<String>) -> Option<String> {
+	filter
+		.map(|filter| {
+			filter
+				.replace('|', "")
+				.trim()
+				.chars()
+				.take(FILTER_MAX_LEN)
+				.collect::<String>()
+		})
+		.filter(|filter| !filter.is_empty())
+}
+
+impl SeasonView {
+	pub fn new(
+		year: i32, season: MediaSeason, format: Option<MediaFormat>, genre: Option<String>,
+		studio: Option<String>,
+	) -> Self {
+		Self {
+			year,
+			season,
+			format,
+			genre: clean_filter(genre),
+			studio: clean_filter(studio),
+		}
+	}
+
+	/// The view as kept in the button ids, fields separated by `|`.
+	pub fn encode(&self) -> String {
+		format!(
+			"{}|{}|{}|{}|{}",
+			self.year,
+			self.season.as_str(),
+			self.format
+				.map(|format| format.as_str())
+				.unwrap_or_default(),
+			self.genre.as_deref().unwrap_or_default(),
+			self.studio.as_deref().unwrap_or_default()
+		)
+	}
+
+	pub fn decode(value: &str) -> Option<Self> {
+		let mut fields = value.split('|');
+		let year = fields.next()?.parse().ok()?;
+		let season = MediaSeason::parse(fields.next()?)?;
+		let format = match fields.next()? {
+			"" => None,
+			format => Some(MediaFormat::parse(format)?),
+		};
+		let genre = fields.next()?.to_string();
+		let studio = fields.next()?.to_string();
+
+		Some(Self::new(year, season, format, Some(genre), Some(studio)))
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeasonEntry {
+	pub title: String,
+	pub url: Option<String>,
+	pub format: Option<MediaFormat>,
+	pub episodes: Option<i32>,
+	pub genres: Vec<String>,
+	pub studios: Vec<String>,
+	pub mean_score: Option<i32>,
+	/// When the next episode airs and its number, for shows still airing.
+	pub next_episode: Option<(i64, i32)>,
+	pub upcoming: bool,
+}
+
+impl From<Media> for SeasonEntry {
+	fn from(media: Media) -> Self {
+		Self {
+			title: media
+				.title
+				.and_then(|title| title.user_preferred)
+				.unwrap_or_else(|| media.id.to_string()),
+			url: media.site_url,
+			format: media.format,
+			episodes: media.episodes,
+			genres: media
+				.genres
+				.unwrap_or_default()
+				.into_iter()
+				.flatten()
+				.collect(),
+			studios: media
+				.studios
+				.and_then(|studios| studios.nodes)
+				.unwrap_or_default()
+				.into_iter()
+				.flatten()
+				.map(|studio| studio.name)
+				.collect(),
+			mean_score: media.mean_score,
+			next_episode: media
+				.next_airing_episode
+				.map(|next| (next.airing_at as i64, next.episode)),
+			upcoming: media.status == Some(MediaStatus::NotYetReleased),
+		}
+	}
+}
+
+/// How long a page of shows stays cached: until the next of their episodes
+/// airs, when AniList moves on to the following one.
+pub fn season_ttl(next_airings: impl Iterator<Item = i64>, now: i64) -> u64 {
+	match next_airings.filter(|airing_at| *airing_at > now).min() {
+		Some(airing_at) => ((airing_at - now) as u64).clamp(MIN_TTL, MAX_TTL),
+		None => MAX_TTL,
+	}
+}
+
+/// Every show of the season matching the format and genre, the most popular
+/// first.
+pub async fn fetch_season(
+	view: &SeasonView, anilist_cache: Arc<RwLock<CacheInterface>>,
+) -> Result<Vec<SeasonEntry>> {
+	let mut entries = Vec::new();
+
+	for page in 1..=MAX_PAGES {
+		let operation = SeasonMedia::build(SeasonMediaVariables {
+			season: Some(view.season),
+			season_year: Some(view.year),
+			format_in: view.format.map(|format| vec![Some(format)]),
+			genre: view.genre.clone(),
+			page: Some(page),
+			per_page: Some(PER_PAGE),
+		});
+
+		let now = Utc::now().timestamp();
+		let response: GraphQlResponse<SeasonMedia> = make_request_anilist_with_ttl(
+			operation,
+			anilist_cache.clone(),
+			|data: &SeasonMedia| {
+				let next_airings = data
+					.page
+					.iter()
+					.flat_map(|page| page.media.iter().flatten().flatten())
+					.filter_map(|media| media.next_airing_episode.as_ref())
+					.map(|next| next.airing_at as i64);
+
+				season_ttl(next_airings, now)
+			},
+		)
+		.await?;
+
+		let Some(season_page) = response.data.and_then(|data| data.page) else {
+			break;
+		};
+
+		entries.extend(
+			season_page
+				.media
+				.unwrap_or_default()
+				.into_iter()
+				.flatten()
+				.map(SeasonEntry::from),
+		);
+
+		if !season_page
+			.page_info
+			.and_then(|info| info.has_next_page)
+			.unwrap_or(false)
+		{
+			break;
+		}
+	}
+
+	if let Some(studio) = &view.studio {
+		let studio = studio.to_lowercase();
+		entries.retain(|entry| {
+			entry
+				.studios
+				.iter()
+				.any(|name| name.to_lowercase().contains(&studio))
+		});
+	}
+
+	Ok(entries)
+}
+
+pub struct SeasonPage {
+	pub entries: Vec<SeasonEntry>,
+	pub page: usize,
+	pub total_pages: usize,
+	pub total: usize,
+}
+
+/// The `page`-th slice of the season, clamped to the last page.
+pub fn season_page(entries: Vec<SeasonEntry>, page: usize) -> SeasonPage {
+	let total = entries.len();
+	let total_pages = total.div_ceil(SEASON_PAGE_SIZE).max(1);
+	let page = page.min(total_pages - 1);
+
+	SeasonPage {
+		entries: entries
+			.into_iter()
+			.skip(page * SEASON_PAGE_SIZE)
+			.take(SEASON_PAGE_SIZE)
+			.collect(),
+		page,
+		total_pages,
+		total,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_december_is_next_winter() {
+		let date = |month, day| NaiveDate::from_ymd_opt(2025, month, day).unwrap();
+
+		assert_eq!(current_season(date(12, 1)), (2026, MediaSeason::Winter));
+		assert_eq!(current_season(date(2, 28)), (2025, MediaSeason::Winter));
+		assert_eq!(current_season(date(4, 10)), (2025, MediaSeason::Spring));
+		assert_eq!(current_season(date(8, 31)), (2025, MediaSeason::Summer));
+		assert_eq!(current_season(date(11, 30)), (2025, MediaSeason::Fall));
+	}
+
+	#[test]
+	fn test_view_round_trip() {
+		let view = SeasonView::new(
+			2025,
+			MediaSeason::Fall,
+			Some(MediaFormat::TvShort),
+			Some(String::from("Slice of Life")),
+			Some(String::from("Kyoto|Animation")),
+		);
+
+		assert_eq!(view.studio.as_deref(), Some("KyotoAnimation"));
+		assert_eq!(SeasonView::decode(&view.encode()), Some(view));
+
+		let bare = SeasonView::new(2024, MediaSeason::Winter, None, None, None);
+		assert_eq!(SeasonView::decode(&bare.encode()), Some(bare));
+	}
+
+	#[test]
+	fn test_ttl_follows_the_next_episode() {
+		let now = 1_000_000;
+
+		assert_eq!(
+			season_ttl([now + 3600, now + 60_000].into_iter(), now),
+			3600
+		);
+		assert_eq!(season_ttl([now + 10].into_iter(), now), MIN_TTL);
+		assert_eq!(season_ttl([now - 10].into_iter(), now), MAX_TTL);
+		assert_eq!(season_ttl(std::iter::empty(), now), MAX_TTL);
+	}
+
+	#[test]
+	fn test_page_is_clamped() {
+		let entry = SeasonEntry {
+			title: String::from("show"),
+			url: None,
+			format: None,
+			episodes: None,
+			genres: Vec::new(),
+			studios: Vec::new(),
+			mean_score: None,
+			next_episode: None,
+			upcoming: false,
+		};
+
+		let page = season_page(vec![entry; 25], 7);
+
+		assert_eq!(page.page, 2);
+		assert_eq!(page.total_pages, 3);
+		assert_eq!(page.entries.len(), 5);
+		assert_eq!(season_page(Vec::new(), 0).total_pages, 1);
+	}
+}
@@ -0,0 +1,45 @@
+use crate::constant::{AUTOCOMPLETE_COUNT_LIMIT, DEFAULT_STRING};
+use crate::helper::get_option::subcommand_group::get_option_map_string_autocomplete_subcommand_group;
+use chrono_tz::TZ_VARIANTS;
+use serenity::all::{
+	AutocompleteChoice, CommandInteraction, Context as SerenityContext, CreateAutocompleteResponse,
+	CreateInteractionResponse,
+};
+
+pub async fn autocomplete(ctx: SerenityContext, autocomplete_interaction: CommandInteraction) {
+	let map = get_option_map_string_autocomplete_subcommand_group(&autocomplete_interaction);
+
+	let timezone_search = map
+		.get(&String::from("timezone"))
+		.map(String::as_str)
+		.unwrap_or(DEFAULT_STRING)
+		.to_ascii_lowercase();
+
+	// Names starting with the search come before names merely containing it
+	let mut prefix_matches = Vec::new();
+	let mut contain_matches = Vec::new();
+	for timezone in TZ_VARIANTS.iter() {
+		let name = timezone.name();
+		let lowercase = name.to_ascii_lowercase();
+		if lowercase.starts_with(&timezone_search) {
+			prefix_matches.push(name);
+		} else if lowercase.contains(&timezone_search) {
+			contain_matches.push(name);
+		}
+	}
+
+	let choices: Vec<AutocompleteChoice> = prefix_matches
+		.into_iter()
+		.chain(contain_matches)
+		.take(AUTOCOMPLETE_COUNT_LIMIT as usize)
+		.map(|name| AutocompleteChoice::new(name, name))
+		.collect();
+
+	let data = CreateAutocompleteResponse::new().set_choices(choices);
+
+	let builder = CreateInteractionResponse::Autocomplete(data);
+
+	let _ = autocomplete_interaction
+		.create_response(&ctx.http, builder)
+		.await;
+}
@@ -0,0 +1,27 @@
+use crate::autocomplete::anilist_user::anime::get_autocomplete_media_variables;
+use crate::constant::DEFAULT_STRING;
+use crate::event_handler::BotData;
+use crate::helper::get_option::subcommand::get_option_map_string_autocomplete_subcommand;
+use crate::structure::autocomplete::anilist::media::send_auto_complete;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+
+/// Suggests any anime, the value is the media id.
+pub async fn autocomplete(ctx: SerenityContext, autocomplete_interaction: CommandInteraction) {
+	let map = get_option_map_string_autocomplete_subcommand(&autocomplete_interaction);
+	let bot_data = ctx.data::<BotData>().clone();
+
+	let anime_search = map
+		.get(&String::from("anime"))
+		.map(String::as_str)
+		.unwrap_or(DEFAULT_STRING);
+
+	let var = get_autocomplete_media_variables(anime_search);
+
+	send_auto_complete(
+		&ctx,
+		autocomplete_interaction,
+		var,
+		bot_data.anilist_cache.clone(),
+	)
+	.await;
+}
@@ -0,0 +1,32 @@
+use crate::constant::DEFAULT_STRING;
+use crate::event_handler::BotData;
+use crate::helper::get_option::subcommand::get_option_map_string_autocomplete_subcommand;
+use crate::structure::autocomplete::anilist::media::{
+	send_auto_complete, MediaAutocompleteVariables,
+};
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+
+/// Suggests any anime or manga, the value is the media id.
+pub async fn autocomplete(ctx: SerenityContext, autocomplete_interaction: CommandInteraction) {
+	let map = get_option_map_string_autocomplete_subcommand(&autocomplete_interaction);
+	let bot_data = ctx.data::<BotData>().clone();
+
+	let media_search = map
+		.get(&String::from("media"))
+		.map(String::as_str)
+		.unwrap_or(DEFAULT_STRING);
+
+	let var = MediaAutocompleteVariables {
+		search: Some(media_search),
+		in_media_format: None,
+		media_type: None,
+	};
+
+	send_auto_complete(
+		&ctx,
+		autocomplete_interaction,
+		var,
+		bot_data.anilist_cache.clone(),
+	)
+	.await;
+}
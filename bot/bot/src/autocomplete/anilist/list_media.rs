@@ -0,0 +1,67 @@
+use crate::anilist::list::{current_list, entry_progress, entry_title, linked_account, Link};
+use crate::constant::{AUTOCOMPLETE_COUNT_LIMIT, DEFAULT_STRING};
+use crate::event_handler::BotData;
+use crate::helper::get_option::subcommand::get_option_map_string_autocomplete_subcommand;
+use serenity::all::{
+	AutocompleteChoice, CommandInteraction, Context as SerenityContext, CreateAutocompleteResponse,
+	CreateInteractionResponse,
+};
+use tracing::error;
+
+/// Suggests entries from the member's current list, the value is the media id.
+pub async fn autocomplete(ctx: SerenityContext, autocomplete_interaction: CommandInteraction) {
+	let map = get_option_map_string_autocomplete_subcommand(&autocomplete_interaction);
+	let bot_data = ctx.data::<BotData>().clone();
+
+	let media_search = map
+		.get(&String::from("media"))
+		.map(String::as_str)
+		.unwrap_or(DEFAULT_STRING)
+		.to_lowercase();
+
+	let user_id = autocomplete_interaction.user.id.to_string();
+	let account = match linked_account(&bot_data.db_connection, &bot_data.config, &user_id).await {
+		Ok(Link::Linked(account)) => account,
+		Ok(_) => return,
+		Err(e) => {
+			error!(?e);
+
+			return;
+		},
+	};
+
+	// An expired or revoked token simply gives no suggestions, the command
+	// itself asks the member to link again
+	let entries = match current_list(&account).await {
+		Ok(entries) => entries,
+		Err(e) => {
+			error!(?e);
+
+			return;
+		},
+	};
+
+	let mut choices: Vec<AutocompleteChoice> = entries
+		.iter()
+		.filter(|entry| entry_title(entry).to_lowercase().contains(&media_search))
+		.filter_map(|entry| {
+			let media = entry.media.as_ref()?;
+			let name = format!("{} ({})", entry_title(entry), entry_progress(entry));
+
+			Some(AutocompleteChoice::new(
+				name.chars().take(100).collect::<String>(),
+				media.id.to_string(),
+			))
+		})
+		.collect();
+
+	choices.truncate(AUTOCOMPLETE_COUNT_LIMIT as usize);
+
+	let data = CreateAutocompleteResponse::new().set_choices(choices);
+
+	let builder = CreateInteractionResponse::Autocomplete(data);
+
+	let _ = autocomplete_interaction
+		.create_response(&ctx.http, builder)
+		.await;
+}
@@ -0,0 +1,4 @@
+pub mod follow;
+pub mod franchise;
+pub mod list_media;
+pub mod unfollow;
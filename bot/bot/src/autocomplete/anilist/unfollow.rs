@@ -0,0 +1,57 @@
+use crate::anilist::follow::followed_anime;
+use crate::constant::{AUTOCOMPLETE_COUNT_LIMIT, DEFAULT_STRING};
+use crate::event_handler::BotData;
+use crate::helper::get_option::subcommand::get_option_map_string_autocomplete_subcommand;
+use serenity::all::{
+	AutocompleteChoice, CommandInteraction, Context as SerenityContext, CreateAutocompleteResponse,
+	CreateInteractionResponse,
+};
+use tracing::error;
+
+/// Suggests the anime the member follows, the value is the media id.
+pub async fn autocomplete(ctx: SerenityContext, autocomplete_interaction: CommandInteraction) {
+	let map = get_option_map_string_autocomplete_subcommand(&autocomplete_interaction);
+	let bot_data = ctx.data::<BotData>().clone();
+
+	let anime_search = map
+		.get(&String::from("anime"))
+		.map(String::as_str)
+		.unwrap_or(DEFAULT_STRING)
+		.to_lowercase();
+
+	let user_id = autocomplete_interaction.user.id.to_string();
+	let followed = match followed_anime(&bot_data.db_connection, &user_id).await {
+		Ok(followed) => followed,
+		Err(e) => {
+			error!(?e);
+
+			return;
+		},
+	};
+
+	let mut choices: Vec<AutocompleteChoice> = followed
+		.into_iter()
+		.map(|anime| {
+			let name = anime.name.unwrap_or_else(|| anime.anime_id.to_string());
+
+			(name, anime.anime_id)
+		})
+		.filter(|(name, _)| name.to_lowercase().contains(&anime_search))
+		.map(|(name, anime_id)| {
+			AutocompleteChoice::new(
+				name.chars().take(100).collect::<String>(),
+				anime_id.to_string(),
+			)
+		})
+		.collect();
+
+	choices.truncate(AUTOCOMPLETE_COUNT_LIMIT as usize);
+
+	let data = CreateAutocompleteResponse::new().set_choices(choices);
+
+	let builder = CreateInteractionResponse::Autocomplete(data);
+
+	let _ = autocomplete_interaction
+		.create_response(&ctx.http, builder)
+		.await;
+}
@@ -13,13 +13,22 @@ use tracing::trace;
 
 pub async fn autocomplete(ctx: Context, autocomplete_interaction: CommandInteraction) {
 	let map = get_option_map_string(&autocomplete_interaction);
-	let bot_data = ctx.data::<BotData>().clone();
 
 	let user_search = map
 		.get(&FixedString::from_str_trunc("username"))
 		.map(String::as_str)
 		.unwrap_or(DEFAULT_STRING);
 
+	respond(ctx, autocomplete_interaction, user_search).await
+}
+
+/// Answers with the AniList users matching `user_search`, for commands that
+/// read the option themselves.
+pub async fn respond(
+	ctx: Context, autocomplete_interaction: CommandInteraction, user_search: &str,
+) {
+	let bot_data = ctx.data::<BotData>().clone();
+
 	trace!("user_search: {}", user_search);
 
 	let var = UserAutocompleteVariables {
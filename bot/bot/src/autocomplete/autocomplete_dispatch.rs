@@ -1,11 +1,16 @@
+use crate::autocomplete::admin_server::timezone;
+use crate::autocomplete::anilist::{follow, franchise, list_media, unfollow};
 use crate::autocomplete::anilist_server::{add_anime_activity, delete_activity};
 use crate::autocomplete::anilist_user::{
 	anime, character, compare, ln, manga, search, staff, studio, user,
 };
 use crate::autocomplete::game::steam_game_info;
 use crate::autocomplete::management::give_premium_sub::give_premium_sub_autocomplete;
+use crate::autocomplete::minigame;
 use crate::autocomplete::vn;
 use crate::autocomplete::vn::{game, producer};
+use crate::constant::DEFAULT_STRING;
+use crate::helper::get_option::subcommand::get_option_map_string_autocomplete_subcommand;
 use crate::helper::get_option::subcommand_group::get_subcommand;
 use serenity::all::{CommandInteraction, Context};
 use tracing::trace;
@@ -28,21 +33,33 @@ pub async fn autocomplete_dispatching(ctx: Context, autocomplete_interaction: Co
 		"steam" => steam_autocomplete(ctx, autocomplete_interaction).await,
 		"vn" => vn_autocomplete(ctx, autocomplete_interaction).await,
 		"give_premium_sub" => give_premium_sub_autocomplete(ctx, autocomplete_interaction).await,
+		"minigame" => minigame_autocomplete(ctx, autocomplete_interaction).await,
+		"anilist" => anilist_autocomplete(ctx, autocomplete_interaction).await,
 		_ => {},
 	}
 }
 
 async fn admin_autocomplete(ctx: Context, autocomplete_interaction: CommandInteraction) {
-	if autocomplete_interaction
+	match autocomplete_interaction
 		.data
 		.options
 		.first()
 		.unwrap()
 		.name
 		.as_str()
-		== "anilist"
 	{
-		anilist_admin_autocomplete(ctx, autocomplete_interaction).await
+		"anilist" => anilist_admin_autocomplete(ctx, autocomplete_interaction).await,
+		"general" => general_admin_autocomplete(ctx, autocomplete_interaction).await,
+		_ => {},
+	}
+}
+
+async fn general_admin_autocomplete(ctx: Context, autocomplete_interaction: CommandInteraction) {
+	let interaction = autocomplete_interaction.clone();
+	let subcommand = get_subcommand(&interaction).unwrap();
+
+	if subcommand.name == "timezone" {
+		timezone::autocomplete(ctx, autocomplete_interaction).await
 	}
 }
 
@@ -62,6 +79,47 @@ async fn vn_autocomplete(ctx: Context, autocomplete_interaction: CommandInteract
 	}
 }
 
+async fn minigame_autocomplete(ctx: Context, autocomplete_interaction: CommandInteraction) {
+	match autocomplete_interaction
+		.data
+		.options
+		.first()
+		.unwrap()
+		.name
+		.as_str()
+	{
+		"equip" => minigame::equip::autocomplete(ctx, autocomplete_interaction).await,
+		"shop" => minigame::shop::autocomplete(ctx, autocomplete_interaction).await,
+		"sell" | "gift" => minigame::sell::autocomplete(ctx, autocomplete_interaction).await,
+		_ => {},
+	}
+}
+
+async fn anilist_autocomplete(ctx: Context, autocomplete_interaction: CommandInteraction) {
+	match autocomplete_interaction
+		.data
+		.options
+		.first()
+		.unwrap()
+		.name
+		.as_str()
+	{
+		"recommend" => {
+			let map = get_option_map_string_autocomplete_subcommand(&autocomplete_interaction);
+			let user_search = map
+				.get("username")
+				.cloned()
+				.unwrap_or(DEFAULT_STRING.to_string());
+
+			user::respond(ctx, autocomplete_interaction, &user_search).await
+		},
+		"follow" => follow::autocomplete(ctx, autocomplete_interaction).await,
+		"franchise" => franchise::autocomplete(ctx, autocomplete_interaction).await,
+		"unfollow" => unfollow::autocomplete(ctx, autocomplete_interaction).await,
+		_ => list_media::autocomplete(ctx, autocomplete_interaction).await,
+	}
+}
+
 async fn anilist_admin_autocomplete(ctx: Context, autocomplete_interaction: CommandInteraction) {
 	let interaction = autocomplete_interaction.clone();
 	let subcommand = get_subcommand(&interaction).unwrap();
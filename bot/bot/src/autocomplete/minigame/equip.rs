@@ -0,0 +1,97 @@
+use crate::constant::{AUTOCOMPLETE_COUNT_LIMIT, DEFAULT_STRING};
+use crate::event_handler::BotData;
+use crate::helper::get_option::subcommand::get_option_map_string_autocomplete_subcommand;
+use crate::minigame::fishing::{get_state, EquipSelection, GearSlot, ItemProperties};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use serenity::all::{
+	AutocompleteChoice, CommandInteraction, Context as SerenityContext, CreateAutocompleteResponse,
+	CreateInteractionResponse,
+};
+use shared::database::prelude::{Item, UserInventory};
+use shared::database::user_inventory;
+use std::collections::BTreeMap;
+use tracing::error;
+
+pub async fn autocomplete(ctx: SerenityContext, autocomplete_interaction: CommandInteraction) {
+	let map = get_option_map_string_autocomplete_subcommand(&autocomplete_interaction);
+	let bot_data = ctx.data::<BotData>().clone();
+
+	let Some(guild_id) = autocomplete_interaction.guild_id else {
+		return;
+	};
+	let guild_id = guild_id.to_string();
+	let user_id = autocomplete_interaction.user.id.to_string();
+
+	let gear_search = map
+		.get(&String::from("gear"))
+		.map(String::as_str)
+		.unwrap_or(DEFAULT_STRING)
+		.to_lowercase();
+
+	let entries = match UserInventory::find()
+		.filter(user_inventory::Column::UserId.eq(user_id.clone()))
+		.filter(user_inventory::Column::ServerId.eq(guild_id.clone()))
+		.find_also_related(Item)
+		.all(&*bot_data.db_connection)
+		.await
+	{
+		Ok(entries) => entries,
+		Err(e) => {
+			error!(?e);
+
+			return;
+		},
+	};
+
+	let state = get_state(&*bot_data.db_connection, &guild_id, &user_id)
+		.await
+		.unwrap_or_default();
+
+	// One choice per gear item owned, with how many entries are left
+	let mut owned: BTreeMap<String, (String, GearSlot, usize)> = BTreeMap::new();
+	for (_, item) in entries {
+		let Some(item) = item else {
+			continue;
+		};
+		let Some(gear) = ItemProperties::of(&item).gear else {
+			continue;
+		};
+
+		owned
+			.entry(item.item_id.clone())
+			.or_insert((item.name, gear.slot, 0))
+			.2 += 1;
+	}
+
+	let mut choices: Vec<AutocompleteChoice> = owned
+		.into_iter()
+		.filter(|(_, (name, _, _))| name.to_lowercase().contains(&gear_search))
+		.map(|(item_id, (name, slot, count))| {
+			AutocompleteChoice::new(format!("{} ({}) x{}", name, slot.as_str(), count), item_id)
+		})
+		.collect();
+
+	if let Some(state) = state {
+		for (slot, equipped) in [
+			(GearSlot::Rod, state.rod_item_id),
+			(GearSlot::Bait, state.bait_item_id),
+		] {
+			if equipped.is_some() {
+				choices.push(AutocompleteChoice::new(
+					format!("Unequip {}", slot.as_str()),
+					EquipSelection::unequip(slot),
+				));
+			}
+		}
+	}
+
+	choices.truncate(AUTOCOMPLETE_COUNT_LIMIT as usize);
+
+	let data = CreateAutocompleteResponse::new().set_choices(choices);
+
+	let builder = CreateInteractionResponse::Autocomplete(data);
+
+	let _ = autocomplete_interaction
+		.create_response(&ctx.http, builder)
+		.await;
+}
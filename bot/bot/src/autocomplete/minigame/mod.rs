@@ -0,0 +1,3 @@
+pub mod equip;
+pub mod sell;
+pub mod shop;
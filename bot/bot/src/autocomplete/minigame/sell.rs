@@ -0,0 +1,117 @@
+use crate::constant::{AUTOCOMPLETE_COUNT_LIMIT, DEFAULT_STRING};
+use crate::event_handler::BotData;
+use crate::helper::get_option::subcommand::get_option_map_string_autocomplete_subcommand;
+use crate::minigame::economy::{sell_value, SaleSelection};
+use crate::minigame::rarity_stars;
+use crate::minigame::trade::held_entry_ids;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use serenity::all::{
+	AutocompleteChoice, CommandInteraction, Context as SerenityContext, CreateAutocompleteResponse,
+	CreateInteractionResponse,
+};
+use shared::database::prelude::{Item, UserInventory};
+use shared::database::user_inventory;
+use std::collections::BTreeMap;
+use tracing::error;
+
+pub async fn autocomplete(ctx: SerenityContext, autocomplete_interaction: CommandInteraction) {
+	let map = get_option_map_string_autocomplete_subcommand(&autocomplete_interaction);
+	let bot_data = ctx.data::<BotData>().clone();
+
+	let Some(guild_id) = autocomplete_interaction.guild_id else {
+		return;
+	};
+
+	let item_search = map
+		.get(&String::from("item"))
+		.map(String::as_str)
+		.unwrap_or(DEFAULT_STRING)
+		.to_lowercase();
+
+	let user_id = autocomplete_interaction.user.id.to_string();
+	let held = match held_entry_ids(
+		&*bot_data.db_connection,
+		&guild_id.to_string(),
+		&user_id,
+		None,
+	)
+	.await
+	{
+		Ok(held) => held,
+		Err(e) => {
+			error!(?e);
+
+			return;
+		},
+	};
+
+	// Entries on offer in a trade cannot be sold or gifted
+	let entries = match UserInventory::find()
+		.filter(user_inventory::Column::UserId.eq(user_id))
+		.filter(user_inventory::Column::ServerId.eq(guild_id.to_string()))
+		.filter(user_inventory::Column::Id.is_not_in(held))
+		.find_also_related(Item)
+		.all(&*bot_data.db_connection)
+		.await
+	{
+		Ok(entries) => entries,
+		Err(e) => {
+			error!(?e);
+
+			return;
+		},
+	};
+
+	let entries: Vec<_> = entries
+		.into_iter()
+		.filter_map(|(entry, item)| item.map(|item| (entry, item)))
+		.filter(|(_, item)| item.name.to_lowercase().contains(&item_search))
+		.collect();
+
+	// One "sell all" choice per item the user owns, then the single entries
+	let mut stacks: BTreeMap<&str, (&str, usize, i64)> = BTreeMap::new();
+	for (entry, item) in &entries {
+		let stack = stacks.entry(&item.item_id).or_insert((&item.name, 0, 0));
+		stack.1 += 1;
+		stack.2 += sell_value(item, entry);
+	}
+
+	let mut choices: Vec<AutocompleteChoice> = stacks
+		.iter()
+		.map(|(item_id, (name, count, value))| {
+			AutocompleteChoice::new(
+				format!("All {} (x{}) — {}", name, count, value),
+				SaleSelection::all_of(item_id),
+			)
+		})
+		.collect();
+
+	choices.extend(entries.iter().map(|(entry, item)| {
+		let size = if entry.size > 0 {
+			format!(" {} cm", entry.size)
+		} else {
+			String::new()
+		};
+
+		AutocompleteChoice::new(
+			format!(
+				"{} {}{} — {}",
+				item.name,
+				rarity_stars(entry.rarity),
+				size,
+				sell_value(item, entry)
+			),
+			entry.id.clone(),
+		)
+	}));
+
+	choices.truncate(AUTOCOMPLETE_COUNT_LIMIT as usize);
+
+	let data = CreateAutocompleteResponse::new().set_choices(choices);
+
+	let builder = CreateInteractionResponse::Autocomplete(data);
+
+	let _ = autocomplete_interaction
+		.create_response(&ctx.http, builder)
+		.await;
+}
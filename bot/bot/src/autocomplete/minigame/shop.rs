@@ -0,0 +1,56 @@
+use crate::constant::{AUTOCOMPLETE_COUNT_LIMIT, DEFAULT_STRING};
+use crate::event_handler::BotData;
+use crate::helper::get_option::subcommand::get_option_map_string_autocomplete_subcommand;
+use crate::minigame::economy::PURCHASABLE_TYPES;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+use serenity::all::{
+	AutocompleteChoice, CommandInteraction, Context as SerenityContext, CreateAutocompleteResponse,
+	CreateInteractionResponse,
+};
+use shared::database::item;
+use shared::database::prelude::Item;
+use tracing::error;
+
+pub async fn autocomplete(ctx: SerenityContext, autocomplete_interaction: CommandInteraction) {
+	let map = get_option_map_string_autocomplete_subcommand(&autocomplete_interaction);
+	let bot_data = ctx.data::<BotData>().clone();
+
+	let item_search = map
+		.get(&String::from("buy"))
+		.map(String::as_str)
+		.unwrap_or(DEFAULT_STRING)
+		.to_lowercase();
+
+	let items = match Item::find()
+		.filter(item::Column::Type.is_in(PURCHASABLE_TYPES.iter().copied()))
+		.filter(item::Column::Price.gt(0))
+		.filter(item::Column::RetiredAt.is_null())
+		.order_by_asc(item::Column::Price)
+		.all(&*bot_data.db_connection)
+		.await
+	{
+		Ok(items) => items,
+		Err(e) => {
+			error!(?e);
+
+			return;
+		},
+	};
+
+	let choices: Vec<AutocompleteChoice> = items
+		.into_iter()
+		.filter(|item| item.name.to_lowercase().contains(&item_search))
+		.take(AUTOCOMPLETE_COUNT_LIMIT as usize)
+		.map(|item| {
+			AutocompleteChoice::new(format!("{} — {}", item.name, item.price), item.item_id)
+		})
+		.collect();
+
+	let data = CreateAutocompleteResponse::new().set_choices(choices);
+
+	let builder = CreateInteractionResponse::Autocomplete(data);
+
+	let _ = autocomplete_interaction
+		.create_response(&ctx.http, builder)
+		.await;
+}
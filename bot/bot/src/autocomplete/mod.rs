@@ -1,6 +1,9 @@
+pub mod admin_server;
+pub mod anilist;
 pub mod anilist_server;
 pub mod anilist_user;
 pub mod autocomplete_dispatch;
 pub mod game;
 pub mod management;
+pub mod minigame;
 pub mod vn;
@@ -1,11 +1,12 @@
-use chrono::{DateTime, Timelike, Utc};
+use chrono::Timelike;
 use lavalink_rs::client::LavalinkClient;
+use moka::future::Cache;
 use reqwest::Client;
 use sea_orm::ActiveValue::Set;
 use sea_orm::{
 	ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
 };
-use serenity::all::{CurrentApplicationInfo, ShardId};
+use serenity::all::{CurrentApplicationInfo, GuildId, ShardId, UserId};
 use serenity::gateway::ShardRunnerInfo;
 use shared::cache::CacheInterface;
 use shared::config::Config;
@@ -34,13 +35,17 @@ pub struct BotData {
 	pub shard_manager: Arc<RwLock<HashMap<ShardId, Arc<parking_lot::RwLock<ShardRunnerInfo>>>>>,
 	pub lavalink: Arc<RwLock<Option<LavalinkClient>>>,
 	pub shutdown_signal: Arc<tokio::sync::broadcast::Sender<()>>,
-	pub vocal_session: Arc<RwLock<HashMap<(String, String), DateTime<Utc>>>>,
+	/// One lock per guild and member, serializing updates of their stored
+	/// voice session.
+	pub voice_session_locks: Cache<(GuildId, UserId), Arc<tokio::sync::Mutex<()>>>,
 	pub user_color_update_count: Arc<AtomicUsize>,
 	pub server_image_running: Arc<AtomicBool>,
 	pub redis_connection: RedisConnection,
 	pub user_color_task_tx: tokio::sync::mpsc::UnboundedSender<ImageTask>,
 	pub server_image_task_tx: tokio::sync::mpsc::UnboundedSender<ImageTask>,
 	pub image_store: Arc<dyn ImageStore>,
+	/// Rendered rank cards and leaderboard pages, keyed by the XP they show.
+	pub level_image_cache: Cache<String, Arc<Vec<u8>>>,
 }
 impl BotData {
 	pub async fn get_hourly_usage(&self, command_name: String, user_id: String) -> u128 {
@@ -0,0 +1,82 @@
+//! The `AddRewardCommand` struct registers a role that members receive once
+//! they reach a given level in the guild.
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::event_handler::BotData;
+use crate::helper::get_option::subcommand_group::{
+	get_option_map_integer_subcommand_group, get_option_map_role_subcommand_group,
+};
+use anyhow::anyhow;
+use fluent_templates::fluent_bundle::FluentValue;
+use fluent_templates::Loader;
+use kasuki_macros::slash_command;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::ActiveValue::Set;
+use sea_orm::EntityTrait;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::database::level_reward;
+use shared::database::prelude::LevelReward;
+use shared::localization::{get_language_identifier, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+#[slash_command(
+	name = "add_reward", desc = "Give a role to members when they reach a level.",
+	command_type = SubCommandGroup(parent = "admin", group = "level"),
+	args = [
+		(name = "level", desc = "The level that unlocks the role.", arg_type = Integer, required = true, autocomplete = false),
+		(name = "role", desc = "The role to give.", arg_type = Role, required = true, autocomplete = false)
+	],
+)]
+async fn add_reward_command(self_: AddRewardCommand) -> Result<EmbedsContents<'_>> {
+	let ctx = self_.get_ctx();
+	let command_interaction = self_.get_command_interaction();
+	let bot_data = ctx.data::<BotData>().clone();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = match command_interaction.guild_id {
+		Some(id) => id.to_string(),
+		None => String::from("0"),
+	};
+
+	let level = *get_option_map_integer_subcommand_group(command_interaction)
+		.get("level")
+		.ok_or(anyhow!("No option for level"))?;
+	let role = *get_option_map_role_subcommand_group(command_interaction)
+		.get("role")
+		.ok_or(anyhow!("No option for role"))?;
+	let level = level.clamp(1, 1000) as i32;
+
+	LevelReward::insert(level_reward::ActiveModel {
+		guild_id: Set(guild_id.clone()),
+		role_id: Set(role.to_string()),
+		level: Set(level),
+	})
+	.on_conflict(
+		OnConflict::columns([level_reward::Column::GuildId, level_reward::Column::RoleId])
+			.update_column(level_reward::Column::Level)
+			.to_owned(),
+	)
+	.exec(&*db_connection)
+	.await?;
+
+	let lang_id = get_language_identifier(guild_id, db_connection).await;
+
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("role"),
+		FluentValue::from(format!("<@&{}>", role)),
+	);
+	args.insert(Cow::Borrowed("level"), FluentValue::from(level));
+
+	let embed_content =
+		EmbedContent::new(USABLE_LOCALES.lookup(&lang_id, "admin_level_add_reward-title"))
+			.description(USABLE_LOCALES.lookup_with_args(
+				&lang_id,
+				"admin_level_add_reward-desc",
+				&args,
+			));
+
+	let embed_contents = EmbedsContents::new(vec![embed_content]);
+
+	Ok(embed_contents)
+}
@@ -0,0 +1,3 @@
+pub mod add_reward;
+pub mod remove_reward;
+pub mod settings;
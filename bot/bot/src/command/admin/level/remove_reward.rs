@@ -0,0 +1,64 @@
+//! The `RemoveRewardCommand` struct stops a role from being granted as a
+//! level reward. Members who already received it keep it.
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::event_handler::BotData;
+use crate::helper::get_option::subcommand_group::get_option_map_role_subcommand_group;
+use anyhow::anyhow;
+use fluent_templates::fluent_bundle::FluentValue;
+use fluent_templates::Loader;
+use kasuki_macros::slash_command;
+use sea_orm::EntityTrait;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::database::prelude::LevelReward;
+use shared::localization::{get_language_identifier, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+#[slash_command(
+	name = "remove_reward", desc = "Stop giving a role as a level reward.",
+	command_type = SubCommandGroup(parent = "admin", group = "level"),
+	args = [
+		(name = "role", desc = "The role to remove from the rewards.", arg_type = Role, required = true, autocomplete = false)
+	],
+)]
+async fn remove_reward_command(self_: RemoveRewardCommand) -> Result<EmbedsContents<'_>> {
+	let ctx = self_.get_ctx();
+	let command_interaction = self_.get_command_interaction();
+	let bot_data = ctx.data::<BotData>().clone();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = match command_interaction.guild_id {
+		Some(id) => id.to_string(),
+		None => String::from("0"),
+	};
+
+	let role = *get_option_map_role_subcommand_group(command_interaction)
+		.get("role")
+		.ok_or(anyhow!("No option for role"))?;
+
+	let deleted = LevelReward::delete_by_id((guild_id.clone(), role.to_string()))
+		.exec(&*db_connection)
+		.await?;
+
+	let lang_id = get_language_identifier(guild_id, db_connection).await;
+
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("role"),
+		FluentValue::from(format!("<@&{}>", role)),
+	);
+
+	let desc_key = if deleted.rows_affected > 0 {
+		"admin_level_remove_reward-desc"
+	} else {
+		"admin_level_remove_reward-not_found"
+	};
+
+	let embed_content =
+		EmbedContent::new(USABLE_LOCALES.lookup(&lang_id, "admin_level_remove_reward-title"))
+			.description(USABLE_LOCALES.lookup_with_args(&lang_id, desc_key, &args));
+
+	let embed_contents = EmbedsContents::new(vec![embed_content]);
+
+	Ok(embed_contents)
+}
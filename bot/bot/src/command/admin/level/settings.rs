@@ -0,0 +1,232 @@
+//! The `SettingsCommand` struct lets guild admins tune the leveling engine:
+//! XP rates, the message cooldown, the level curve and where level-ups are
+//! announced. Every option is optional; running it bare shows the current setup.
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::event_handler::BotData;
+use crate::helper::get_option::subcommand_group::{
+	get_option_map_boolean_subcommand_group, get_option_map_channel_subcommand_group,
+	get_option_map_integer_subcommand_group, get_option_map_number_subcommand_group,
+};
+use crate::leveling::get_level_config;
+use chrono::Utc;
+use fluent_templates::fluent_bundle::FluentValue;
+use fluent_templates::Loader;
+use kasuki_macros::slash_command;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::database::prelude::{GuildLevelConfig, LevelReward};
+use shared::database::{guild_level_config, level_reward};
+use shared::helper::get_module_state::{is_module_enabled, GuildModule};
+use shared::localization::{get_language_identifier, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+#[slash_command(
+	name = "settings", desc = "View or change the leveling settings.",
+	command_type = SubCommandGroup(parent = "admin", group = "level"),
+	args = [
+		(name = "message_xp", desc = "XP given per message (0-1000).", arg_type = Integer, required = false, autocomplete = false),
+		(name = "voice_xp", desc = "XP given per minute in voice (0-1000).", arg_type = Integer, required = false, autocomplete = false),
+		(name = "cooldown", desc = "Seconds between two messages earning XP (0-3600).", arg_type = Integer, required = false, autocomplete = false),
+		(name = "curve_base", desc = "XP needed for level 1 (1-100000).", arg_type = Integer, required = false, autocomplete = false),
+		(name = "curve_exponent", desc = "How steeply levels get harder (1.0-3.0).", arg_type = Number, required = false, autocomplete = false),
+		(name = "announce", desc = "Announce level-ups.", arg_type = Boolean, required = false, autocomplete = false),
+		(name = "channel", desc = "Channel for level-up announcements.", arg_type = Channel, required = false, autocomplete = false),
+		(name = "reset_channel", desc = "Announce in the channel the message was sent in.", arg_type = Boolean, required = false, autocomplete = false)
+	],
+)]
+async fn settings_command(self_: SettingsCommand) -> Result<EmbedsContents<'_>> {
+	let ctx = self_.get_ctx();
+	let command_interaction = self_.get_command_interaction();
+	let bot_data = ctx.data::<BotData>().clone();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = match command_interaction.guild_id {
+		Some(id) => id.to_string(),
+		None => String::from("0"),
+	};
+
+	let integers = get_option_map_integer_subcommand_group(command_interaction);
+	let numbers = get_option_map_number_subcommand_group(command_interaction);
+	let booleans = get_option_map_boolean_subcommand_group(command_interaction);
+	let channels = get_option_map_channel_subcommand_group(command_interaction);
+
+	let mut config = get_level_config(guild_id.clone(), &db_connection).await?;
+	let mut changed = false;
+
+	if let Some(value) = integers.get("message_xp") {
+		config.message_xp = (*value).clamp(0, 1000) as i32;
+		changed = true;
+	}
+	if let Some(value) = integers.get("voice_xp") {
+		config.voice_xp_per_minute = (*value).clamp(0, 1000) as i32;
+		changed = true;
+	}
+	if let Some(value) = integers.get("cooldown") {
+		config.message_cooldown_secs = (*value).clamp(0, 3600) as i32;
+		changed = true;
+	}
+	if let Some(value) = integers.get("curve_base") {
+		config.curve_base = (*value).clamp(1, 100_000) as i32;
+		changed = true;
+	}
+	if let Some(value) = numbers.get("curve_exponent") {
+		config.curve_exponent = value.clamp(1.0, 3.0);
+		changed = true;
+	}
+	if let Some(value) = booleans.get("announce") {
+		config.announce_level_up = *value;
+		changed = true;
+	}
+	if let Some(channel) = channels.get("channel") {
+		config.level_up_channel_id = Some(channel.to_string());
+		changed = true;
+	}
+	if booleans.get("reset_channel").copied().unwrap_or(false) {
+		config.level_up_channel_id = None;
+		changed = true;
+	}
+
+	if changed {
+		GuildLevelConfig::insert(guild_level_config::ActiveModel {
+			guild_id: Set(config.guild_id.clone()),
+			message_xp: Set(config.message_xp),
+			voice_xp_per_minute: Set(config.voice_xp_per_minute),
+			message_cooldown_secs: Set(config.message_cooldown_secs),
+			curve_base: Set(config.curve_base),
+			curve_exponent: Set(config.curve_exponent),
+			announce_level_up: Set(config.announce_level_up),
+			level_up_channel_id: Set(config.level_up_channel_id.clone()),
+			updated_at: Set(Utc::now().naive_utc()),
+		})
+		.on_conflict(
+			OnConflict::column(guild_level_config::Column::GuildId)
+				.update_columns([
+					guild_level_config::Column::MessageXp,
+					guild_level_config::Column::VoiceXpPerMinute,
+					guild_level_config::Column::MessageCooldownSecs,
+					guild_level_config::Column::CurveBase,
+					guild_level_config::Column::CurveExponent,
+					guild_level_config::Column::AnnounceLevelUp,
+					guild_level_config::Column::LevelUpChannelId,
+					guild_level_config::Column::UpdatedAt,
+				])
+				.to_owned(),
+		)
+		.exec(&*db_connection)
+		.await?;
+	}
+
+	let rewards = LevelReward::find()
+		.filter(level_reward::Column::GuildId.eq(guild_id.clone()))
+		.order_by_asc(level_reward::Column::Level)
+		.all(&*db_connection)
+		.await?;
+
+	let module_enabled =
+		is_module_enabled(guild_id.clone(), GuildModule::Level, db_connection.clone()).await;
+
+	let lang_id = get_language_identifier(guild_id, db_connection).await;
+
+	let mut cooldown_args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	cooldown_args.insert(
+		Cow::Borrowed("seconds"),
+		FluentValue::from(config.message_cooldown_secs),
+	);
+
+	let mut curve_args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	curve_args.insert(Cow::Borrowed("base"), FluentValue::from(config.curve_base));
+	curve_args.insert(
+		Cow::Borrowed("exponent"),
+		FluentValue::from(format!("{:.2}", config.curve_exponent)),
+	);
+
+	let announce = if config.announce_level_up {
+		USABLE_LOCALES.lookup(&lang_id, "admin_level_settings-on")
+	} else {
+		USABLE_LOCALES.lookup(&lang_id, "admin_level_settings-off")
+	};
+
+	let channel = match &config.level_up_channel_id {
+		Some(id) => format!("<#{}>", id),
+		None => USABLE_LOCALES.lookup(&lang_id, "admin_level_settings-channel_default"),
+	};
+
+	let rewards = if rewards.is_empty() {
+		USABLE_LOCALES.lookup(&lang_id, "admin_level_settings-no_rewards")
+	} else {
+		rewards
+			.iter()
+			.map(|reward| {
+				let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+				args.insert(Cow::Borrowed("level"), FluentValue::from(reward.level));
+				args.insert(
+					Cow::Borrowed("role"),
+					FluentValue::from(format!("<@&{}>", reward.role_id)),
+				);
+				USABLE_LOCALES.lookup_with_args(&lang_id, "admin_level_settings-reward", &args)
+			})
+			.collect::<Vec<_>>()
+			.join("\n")
+	};
+
+	let mut embed_content = EmbedContent::new(
+		USABLE_LOCALES.lookup(&lang_id, "admin_level_settings-title"),
+	)
+	.fields(vec![
+		(
+			USABLE_LOCALES.lookup(&lang_id, "admin_level_settings-message_xp"),
+			config.message_xp.to_string(),
+			true,
+		),
+		(
+			USABLE_LOCALES.lookup(&lang_id, "admin_level_settings-voice_xp"),
+			config.voice_xp_per_minute.to_string(),
+			true,
+		),
+		(
+			USABLE_LOCALES.lookup(&lang_id, "admin_level_settings-cooldown"),
+			USABLE_LOCALES.lookup_with_args(
+				&lang_id,
+				"admin_level_settings-cooldown_value",
+				&cooldown_args,
+			),
+			true,
+		),
+		(
+			USABLE_LOCALES.lookup(&lang_id, "admin_level_settings-curve"),
+			USABLE_LOCALES.lookup_with_args(
+				&lang_id,
+				"admin_level_settings-curve_value",
+				&curve_args,
+			),
+			true,
+		),
+		(
+			USABLE_LOCALES.lookup(&lang_id, "admin_level_settings-announce"),
+			announce,
+			true,
+		),
+		(
+			USABLE_LOCALES.lookup(&lang_id, "admin_level_settings-channel"),
+			channel,
+			true,
+		),
+		(
+			USABLE_LOCALES.lookup(&lang_id, "admin_level_settings-rewards"),
+			rewards,
+			false,
+		),
+	]);
+
+	if !module_enabled {
+		embed_content = embed_content
+			.description(USABLE_LOCALES.lookup(&lang_id, "admin_level_settings-module_off"));
+	}
+
+	let embed_contents = EmbedsContents::new(vec![embed_content]);
+
+	Ok(embed_contents)
+}
@@ -1,2 +1,3 @@
 pub mod anilist;
+pub mod level;
 pub(crate) mod server;
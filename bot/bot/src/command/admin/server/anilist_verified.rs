@@ -0,0 +1,71 @@
+//! The `AnilistVerifiedCommand` struct lets guild admins restrict the server
+//! lists to AniList accounts their members linked through the AniList login,
+//! hiding usernames claimed with `register` alone.
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::event_handler::BotData;
+use crate::helper::get_option::subcommand_group::get_option_map_boolean_subcommand_group;
+use anyhow::anyhow;
+use chrono::Utc;
+use fluent_templates::Loader;
+use kasuki_macros::slash_command;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::ActiveValue::Set;
+use sea_orm::EntityTrait;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::database::guild_anilist_setting;
+use shared::database::prelude::GuildAnilistSetting;
+use shared::localization::{get_language_identifier, USABLE_LOCALES};
+
+#[slash_command(
+	name = "anilist_verified", desc = "Only list AniList accounts verified through the AniList login.",
+	command_type = SubCommandGroup(parent = "admin", group = "general"),
+	args = [
+		(name = "state", desc = "Whether only verified accounts are listed.", arg_type = Boolean, required = true, autocomplete = false)
+	],
+)]
+async fn anilist_verified_command(self_: AnilistVerifiedCommand) -> Result<EmbedsContents<'_>> {
+	let ctx = self_.get_ctx();
+	let command_interaction = self_.get_command_interaction();
+	let bot_data = ctx.data::<BotData>().clone();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = command_interaction
+		.guild_id
+		.ok_or(anyhow!("This setting only exists in servers"))?
+		.to_string();
+
+	let map = get_option_map_boolean_subcommand_group(command_interaction);
+	let state = *map
+		.get(&String::from("state"))
+		.ok_or(anyhow!("No option for state"))?;
+
+	GuildAnilistSetting::insert(guild_anilist_setting::ActiveModel {
+		guild_id: Set(guild_id.clone()),
+		verified_only: Set(state),
+		updated_at: Set(Utc::now().naive_utc()),
+	})
+	.on_conflict(
+		OnConflict::column(guild_anilist_setting::Column::GuildId)
+			.update_columns([
+				guild_anilist_setting::Column::VerifiedOnly,
+				guild_anilist_setting::Column::UpdatedAt,
+			])
+			.to_owned(),
+	)
+	.exec(&*db_connection)
+	.await?;
+
+	let lang_id = get_language_identifier(guild_id, db_connection).await;
+
+	let key = if state {
+		"admin_server_anilist_verified-on"
+	} else {
+		"admin_server_anilist_verified-off"
+	};
+
+	let embed_content =
+		EmbedContent::new(USABLE_LOCALES.lookup(&lang_id, "admin_server_anilist_verified-title"))
+			.description(USABLE_LOCALES.lookup(&lang_id, key));
+
+	Ok(EmbedsContents::new(vec![embed_content]))
+}
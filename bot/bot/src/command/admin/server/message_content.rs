@@ -0,0 +1,83 @@
+//! The `MessageContentCommand` struct lets guild admins choose whether message
+//! bodies may be stored for members who opted in themselves. Turning it off
+//! also strips the bodies already stored for the guild.
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::event_handler::BotData;
+use crate::helper::get_option::subcommand_group::get_option_map_boolean_subcommand_group;
+use anyhow::anyhow;
+use chrono::Utc;
+use fluent_templates::Loader;
+use kasuki_macros::slash_command;
+use sea_orm::prelude::Expr;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::database::prelude::{GuildMessageTracking, Message};
+use shared::database::{guild_message_tracking, message};
+use shared::localization::{get_language_identifier, USABLE_LOCALES};
+use tracing::debug;
+
+#[slash_command(
+	name = "message_content", desc = "Allow storing the content of messages from members who opted in.",
+	command_type = SubCommandGroup(parent = "admin", group = "general"),
+	args = [
+		(name = "state", desc = "Whether message content may be stored.", arg_type = Boolean, required = true, autocomplete = false)
+	],
+)]
+async fn message_content_command(self_: MessageContentCommand) -> Result<EmbedsContents<'_>> {
+	let ctx = self_.get_ctx();
+	let command_interaction = self_.get_command_interaction();
+	let bot_data = ctx.data::<BotData>().clone();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = command_interaction
+		.guild_id
+		.ok_or(anyhow!("This setting only exists in servers"))?
+		.to_string();
+
+	let map = get_option_map_boolean_subcommand_group(command_interaction);
+	let state = *map
+		.get(&String::from("state"))
+		.ok_or(anyhow!("No option for state"))?;
+
+	GuildMessageTracking::insert(guild_message_tracking::ActiveModel {
+		guild_id: Set(guild_id.clone()),
+		store_content: Set(state),
+		updated_at: Set(Utc::now().naive_utc()),
+	})
+	.on_conflict(
+		OnConflict::column(guild_message_tracking::Column::GuildId)
+			.update_columns([
+				guild_message_tracking::Column::StoreContent,
+				guild_message_tracking::Column::UpdatedAt,
+			])
+			.to_owned(),
+	)
+	.exec(&*db_connection)
+	.await?;
+
+	if !state {
+		let stripped = Message::update_many()
+			.col_expr(message::Column::Data, Expr::value(""))
+			.filter(message::Column::GuildId.eq(guild_id.clone()))
+			.filter(message::Column::Data.ne(""))
+			.exec(&*db_connection)
+			.await?;
+		debug!(guild_id = %guild_id, rows = stripped.rows_affected, "Stripped stored message content");
+	}
+
+	let lang_id = get_language_identifier(guild_id, db_connection).await;
+
+	let key = if state {
+		"admin_server_message_content-on"
+	} else {
+		"admin_server_message_content-off"
+	};
+
+	let embed_content =
+		EmbedContent::new(USABLE_LOCALES.lookup(&lang_id, "admin_server_message_content-title"))
+			.description(USABLE_LOCALES.lookup(&lang_id, key));
+
+	Ok(EmbedsContents::new(vec![embed_content]))
+}
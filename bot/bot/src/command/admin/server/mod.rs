@@ -1,2 +1,5 @@
+pub mod anilist_verified;
 pub mod lang;
+pub mod message_content;
 pub mod module;
+pub mod timezone;
@@ -0,0 +1,82 @@
+//! The `TimezoneCommand` struct sets the IANA timezone of a guild. It is used
+//! wherever the bot groups activity by local day or hour, such as the levels
+//! heatmap.
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::event_handler::BotData;
+use crate::helper::get_option::subcommand_group::get_option_map_string_subcommand_group;
+use anyhow::anyhow;
+use chrono::Utc;
+use chrono_tz::Tz;
+use fluent_templates::fluent_bundle::FluentValue;
+use fluent_templates::Loader;
+use kasuki_macros::slash_command;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::ActiveValue::Set;
+use sea_orm::EntityTrait;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::database::guild_timezone;
+use shared::database::prelude::GuildTimezone;
+use shared::localization::{get_language_identifier, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+#[slash_command(
+	name = "timezone", desc = "Set the timezone of the server.",
+	command_type = SubCommandGroup(parent = "admin", group = "general"),
+	args = [
+		(name = "timezone", desc = "The timezone, for example Europe/Paris.", arg_type = String, required = true, autocomplete = true)
+	],
+)]
+async fn timezone_command(self_: TimezoneCommand) -> Result<EmbedsContents<'_>> {
+	let ctx = self_.get_ctx();
+	let command_interaction = self_.get_command_interaction();
+	let bot_data = ctx.data::<BotData>().clone();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = command_interaction
+		.guild_id
+		.ok_or(anyhow!("This setting only exists in servers"))?
+		.to_string();
+
+	let map = get_option_map_string_subcommand_group(command_interaction);
+	let timezone = map
+		.get(&String::from("timezone"))
+		.ok_or(anyhow!("No option for timezone"))?;
+	let timezone =
+		Tz::from_str(timezone.trim()).map_err(|_| anyhow!("Unknown timezone: {}", timezone))?;
+
+	GuildTimezone::insert(guild_timezone::ActiveModel {
+		guild_id: Set(guild_id.clone()),
+		timezone: Set(timezone.name().to_string()),
+		updated_at: Set(Utc::now().naive_utc()),
+	})
+	.on_conflict(
+		OnConflict::column(guild_timezone::Column::GuildId)
+			.update_columns([
+				guild_timezone::Column::Timezone,
+				guild_timezone::Column::UpdatedAt,
+			])
+			.to_owned(),
+	)
+	.exec(&*db_connection)
+	.await?;
+
+	let lang_id = get_language_identifier(guild_id, db_connection).await;
+
+	let mut args = HashMap::new();
+	args.insert(
+		Cow::Borrowed("timezone"),
+		FluentValue::from(timezone.name()),
+	);
+
+	let embed_content =
+		EmbedContent::new(USABLE_LOCALES.lookup(&lang_id, "admin_server_timezone-title"))
+			.description(USABLE_LOCALES.lookup_with_args(
+				&lang_id,
+				"admin_server_timezone-desc",
+				&args,
+			));
+
+	Ok(EmbedsContents::new(vec![embed_content]))
+}
@@ -0,0 +1,71 @@
+//! The `AnilistFollowCommand` struct follows an anime, the worker then sends
+//! the member a DM when each of its episodes airs.
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::anilist::minimal_anime::get_minimal_anime_media;
+use shared::localization::{Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::anilist::follow::{follow_anime, Followed};
+use crate::command::command::CommandRun;
+use crate::command::context::CommandContext;
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::helper::get_option::subcommand::get_option_map_string_subcommand;
+
+#[slash_command(
+	name = "follow", desc = "Get a DM when each episode of an anime airs.",
+	command_type = SubCommand(parent = "anilist"),
+	contexts = [Guild, BotDm, PrivateChannel],
+	install_contexts = [Guild, User],
+	args = [
+		(name = "anime", desc = "The anime to follow.", arg_type = String, required = true, autocomplete = true)
+	],
+)]
+async fn anilist_follow_command(self_: AnilistFollowCommand) -> Result<EmbedsContents<'_>> {
+	let cx = CommandContext::new(
+		self_.get_ctx().clone(),
+		self_.get_command_interaction().clone(),
+	);
+	let lang_id = cx.lang_id().await;
+
+	let anime = get_option_map_string_subcommand(&cx.command_interaction)
+		.get("anime")
+		.cloned()
+		.ok_or(anyhow!("No option for anime"))?;
+
+	let media = get_minimal_anime_media(anime, cx.anilist_cache.clone()).await?;
+	let anime_id = media.id;
+	let user_id = cx.command_interaction.user.id.to_string();
+
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	let (name, description) = match follow_anime(&cx.db, &user_id, media).await? {
+		Followed::Airing {
+			name,
+			episode,
+			airing_at,
+		} => {
+			args.insert(Cow::Borrowed("episode"), FluentValue::from(episode));
+			args.insert(
+				Cow::Borrowed("time"),
+				FluentValue::from(format!("<t:{}:R>", airing_at)),
+			);
+			let description =
+				USABLE_LOCALES.lookup_with_args(&lang_id, "anilist_airing-following", &args);
+
+			(name, description)
+		},
+		Followed::NotAiring { name } => (
+			name,
+			USABLE_LOCALES.lookup(&lang_id, "anilist_airing-not_airing"),
+		),
+	};
+
+	let embed_content = EmbedContent::new(name)
+		.description(description)
+		.url(format!("https://anilist.co/anime/{}", anime_id));
+
+	Ok(EmbedsContents::new(vec![embed_content]))
+}
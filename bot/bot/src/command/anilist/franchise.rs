@@ -0,0 +1,150 @@
+//! The `AnilistFranchiseCommand` struct walks the relations of an anime or
+//! manga and suggests the order to watch or read its franchise in, with the
+//! relation graph as an image.
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::localization::{Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use unic_langid::LanguageIdentifier;
+
+use crate::anilist::franchise::{franchise, resolve_media_id, Entry, Franchise};
+use crate::anilist::franchise_graph::render_franchise;
+use crate::command::command::CommandRun;
+use crate::command::context::CommandContext;
+use crate::command::embed_content::{CommandFiles, CreateFooter, EmbedContent, EmbedsContents};
+use crate::helper::get_option::subcommand::get_option_map_string_subcommand;
+
+/// Titles listed in the embed, the graph shows them all.
+const WATCH_ORDER_LIMIT: usize = 20;
+
+#[slash_command(
+	name = "franchise", desc = "Get the watch order and relation graph of a franchise.",
+	command_type = SubCommand(parent = "anilist"),
+	contexts = [Guild, BotDm, PrivateChannel],
+	install_contexts = [Guild, User],
+	args = [
+		(name = "media", desc = "An anime or manga of the franchise.", arg_type = String, required = true, autocomplete = true)
+	],
+)]
+async fn anilist_franchise_command(self_: AnilistFranchiseCommand) -> Result<EmbedsContents<'_>> {
+	let cx = CommandContext::new(
+		self_.get_ctx().clone(),
+		self_.get_command_interaction().clone(),
+	);
+	let lang_id = cx.lang_id().await;
+
+	let media = get_option_map_string_subcommand(&cx.command_interaction)
+		.get("media")
+		.cloned()
+		.ok_or(anyhow!("No option for media"))?;
+
+	let franchise = match resolve_media_id(&media, cx.anilist_cache.clone()).await? {
+		Some(media_id) => franchise(media_id, cx.anilist_cache.clone()).await?,
+		None => None,
+	};
+	let Some(franchise) = franchise else {
+		let embed_content =
+			EmbedContent::new(USABLE_LOCALES.lookup(&lang_id, "anilist_franchise-title"))
+				.description(USABLE_LOCALES.lookup(&lang_id, "anilist_franchise-not_found"));
+
+		return Ok(EmbedsContents::new(vec![embed_content]));
+	};
+
+	let root = franchise
+		.entries
+		.iter()
+		.find(|entry| entry.id == franchise.root)
+		.ok_or(anyhow!("The franchise lost its first title"))?;
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("title"),
+		FluentValue::from(root.title.clone()),
+	);
+	args.insert(
+		Cow::Borrowed("count"),
+		FluentValue::from(franchise.entries.len()),
+	);
+	let title = USABLE_LOCALES.lookup_with_args(&lang_id, "anilist_franchise-title_media", &args);
+	let url = media_url(&franchise, root);
+
+	let mut footer = Vec::new();
+	if franchise.truncated {
+		footer.push(USABLE_LOCALES.lookup_with_args(
+			&lang_id,
+			"anilist_franchise-truncated",
+			&args,
+		));
+	}
+	if franchise.cycles > 0 {
+		footer.push(USABLE_LOCALES.lookup(&lang_id, "anilist_franchise-cycles"));
+	}
+
+	let description = watch_order_description(&franchise, &lang_id);
+	let filename = format!("franchise_{}.png", franchise.root);
+	let image = tokio::task::spawn_blocking(move || render_franchise(&franchise))
+		.await
+		.map_err(|e| anyhow!("Franchise render task failed: {}", e))??;
+
+	let mut embed_content = EmbedContent::new(title)
+		.description(description)
+		.url(url)
+		.images_url(format!("attachment://{}", filename));
+	if !footer.is_empty() {
+		embed_content = embed_content.footer(CreateFooter::new(footer.join("\n")));
+	}
+
+	let mut embed_contents = EmbedsContents::new(vec![embed_content]);
+	embed_contents.add_files(vec![CommandFiles::new(filename, image)]);
+
+	Ok(embed_contents)
+}
+
+fn media_url(franchise: &Franchise, entry: &Entry) -> String {
+	let media_type = if franchise.manga { "manga" } else { "anime" };
+
+	format!("https://anilist.co/{}/{}", media_type, entry.id)
+}
+
+/// One line per title in the suggested order, the asked one in bold.
+fn watch_order_description(franchise: &Franchise, lang_id: &LanguageIdentifier) -> String {
+	let mut lines: Vec<String> = franchise
+		.entries
+		.iter()
+		.take(WATCH_ORDER_LIMIT)
+		.enumerate()
+		.map(|(index, entry)| {
+			let link = format!("[{}]({})", entry.title, media_url(franchise, entry));
+			let mut line = if entry.id == franchise.root {
+				format!("{}. **{}**", index + 1, link)
+			} else {
+				format!("{}. {}", index + 1, link)
+			};
+
+			let details: Vec<String> = entry
+				.format
+				.iter()
+				.cloned()
+				.chain(entry.year().map(|year| year.to_string()))
+				.collect();
+			if !details.is_empty() {
+				line.push_str(&format!(" · {}", details.join(" · ")));
+			}
+
+			line
+		})
+		.collect();
+
+	if franchise.entries.len() > WATCH_ORDER_LIMIT {
+		let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+		args.insert(
+			Cow::Borrowed("count"),
+			FluentValue::from(franchise.entries.len() - WATCH_ORDER_LIMIT),
+		);
+		lines.push(USABLE_LOCALES.lookup_with_args(lang_id, "anilist_franchise-more", &args));
+	}
+
+	lines.join("\n")
+}
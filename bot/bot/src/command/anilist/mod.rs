@@ -0,0 +1,10 @@
+pub mod follow;
+pub mod franchise;
+pub mod notifications;
+pub mod progress;
+pub mod recommend;
+pub mod schedule;
+pub mod score;
+pub mod season;
+pub mod status;
+pub mod unfollow;
@@ -0,0 +1,198 @@
+//! The `AnilistNotificationsCommand` struct changes how the member gets
+//! airing DMs, then shows the settings and the followed anime.
+//!
+//! Auto-follow copies the airing anime of the member's CURRENT AniList list,
+//! once right away and then on every sync of the worker.
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use fluent_templates::fluent_bundle::FluentValue;
+use kasuki_macros::slash_command;
+use sea_orm::EntityTrait;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::anilist::airing_dm::{is_quiet, sync_list_follows};
+use shared::database::prelude::RegisteredUser;
+use shared::localization::{Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use tracing::warn;
+use unic_langid::LanguageIdentifier;
+
+use crate::anilist::follow::{
+	dm_setting, followed_anime, update_dm_setting, FollowedAnime, SettingChange,
+	FOLLOWED_LIST_LIMIT,
+};
+use crate::anilist::{relink_embed, Relink};
+use crate::command::command::CommandRun;
+use crate::command::context::CommandContext;
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::helper::get_option::subcommand::{
+	get_option_map_boolean_subcommand, get_option_map_integer_subcommand,
+};
+
+/// Longest snooze, a longer break is what muting is for.
+const MAX_SNOOZE_HOURS: i64 = 30 * 24;
+
+#[slash_command(
+	name = "notifications", desc = "Set up your airing DMs and see the anime you follow.",
+	command_type = SubCommand(parent = "anilist"),
+	contexts = [Guild, BotDm, PrivateChannel],
+	install_contexts = [Guild, User],
+	args = [
+		(name = "auto_follow", desc = "Follow the airing anime of your AniList watching list.", arg_type = Boolean, required = false, autocomplete = false),
+		(name = "mute", desc = "Stop every airing DM until you unmute them.", arg_type = Boolean, required = false, autocomplete = false),
+		(name = "snooze", desc = "Hours without airing DMs, 0 to end the snooze.", arg_type = Integer, required = false, autocomplete = false)
+	],
+)]
+async fn anilist_notifications_command(
+	self_: AnilistNotificationsCommand,
+) -> Result<EmbedsContents<'_>> {
+	let cx = CommandContext::new(
+		self_.get_ctx().clone(),
+		self_.get_command_interaction().clone(),
+	);
+	let lang_id = cx.lang_id().await;
+	let user_id = cx.command_interaction.user.id.to_string();
+
+	let boolean_map = get_option_map_boolean_subcommand(&cx.command_interaction);
+	let integer_map = get_option_map_integer_subcommand(&cx.command_interaction);
+	let change = SettingChange {
+		auto_follow: boolean_map.get("auto_follow").copied(),
+		muted: boolean_map.get("mute").copied(),
+		snoozed_until: integer_map.get("snooze").map(|hours| {
+			let hours = (*hours).clamp(0, MAX_SNOOZE_HOURS);
+
+			(hours > 0).then(|| Utc::now().naive_utc() + Duration::hours(hours))
+		}),
+	};
+
+	let setting = if change.is_empty() {
+		dm_setting(&cx.db, &user_id).await?
+	} else {
+		// Auto-follow reads the list of the linked account
+		let registered = RegisteredUser::find_by_id(user_id.clone())
+			.one(&*cx.db)
+			.await?;
+		if change.auto_follow == Some(true) && registered.is_none() {
+			return Ok(relink_embed(&lang_id, Relink::NotLinked));
+		}
+
+		let setting = update_dm_setting(&cx.db, &user_id, change).await?;
+
+		if let (true, Some(registered)) = (setting.auto_follow, registered) {
+			// The worker syncs again later, a failure here only delays it
+			if let Err(e) = sync_list_follows(
+				&cx.db,
+				&user_id,
+				registered.anilist_id,
+				cx.anilist_cache.clone(),
+			)
+			.await
+			{
+				warn!(
+					"Failed to sync the airing follows of user {}: {:#}",
+					user_id, e
+				);
+			}
+		}
+
+		Some(setting)
+	};
+
+	let now = Utc::now().naive_utc();
+	let state = match &setting {
+		Some(setting) if setting.muted => {
+			USABLE_LOCALES.lookup(&lang_id, "anilist_airing-state_muted")
+		},
+		Some(setting) if is_quiet(Some(setting), now) => {
+			let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+			args.insert(
+				Cow::Borrowed("time"),
+				FluentValue::from(format!(
+					"<t:{}:R>",
+					setting.snoozed_until.unwrap_or(now).and_utc().timestamp()
+				)),
+			);
+			USABLE_LOCALES.lookup_with_args(&lang_id, "anilist_airing-state_snoozed", &args)
+		},
+		_ => USABLE_LOCALES.lookup(&lang_id, "anilist_airing-state_on"),
+	};
+	let auto_follow = if setting.as_ref().is_some_and(|setting| setting.auto_follow) {
+		USABLE_LOCALES.lookup(&lang_id, "anilist_airing-auto_follow_on")
+	} else {
+		USABLE_LOCALES.lookup(&lang_id, "anilist_airing-auto_follow_off")
+	};
+
+	let followed = followed_anime(&cx.db, &user_id).await?;
+
+	let embed_content =
+		EmbedContent::new(USABLE_LOCALES.lookup(&lang_id, "anilist_airing-settings_title"))
+			.description(followed_description(&followed, &lang_id))
+			.fields(vec![
+				(
+					USABLE_LOCALES.lookup(&lang_id, "anilist_airing-state"),
+					state,
+					true,
+				),
+				(
+					USABLE_LOCALES.lookup(&lang_id, "anilist_airing-auto_follow"),
+					auto_follow,
+					true,
+				),
+			]);
+
+	Ok(EmbedsContents::new(vec![embed_content]))
+}
+
+/// One line per followed anime, the next to air first.
+fn followed_description(followed: &[FollowedAnime], lang_id: &LanguageIdentifier) -> String {
+	if followed.is_empty() {
+		return USABLE_LOCALES.lookup(lang_id, "anilist_airing-none_followed");
+	}
+
+	let mut lines: Vec<String> = followed
+		.iter()
+		.take(FOLLOWED_LIST_LIMIT)
+		.map(|anime| {
+			let name = anime
+				.name
+				.clone()
+				.unwrap_or_else(|| anime.anime_id.to_string());
+			let title = format!("[{}](https://anilist.co/anime/{})", name, anime.anime_id);
+
+			let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+			let next = match anime.next_episode {
+				Some((episode, airing_at)) => {
+					args.insert(Cow::Borrowed("episode"), FluentValue::from(episode));
+					args.insert(
+						Cow::Borrowed("time"),
+						FluentValue::from(format!("<t:{}:R>", airing_at.and_utc().timestamp())),
+					);
+					USABLE_LOCALES.lookup_with_args(lang_id, "anilist_airing-next", &args)
+				},
+				None => USABLE_LOCALES.lookup(lang_id, "anilist_airing-no_next"),
+			};
+
+			if anime.from_list {
+				format!(
+					"**{}** · {} · {}",
+					title,
+					next,
+					USABLE_LOCALES.lookup(lang_id, "anilist_airing-from_list")
+				)
+			} else {
+				format!("**{}** · {}", title, next)
+			}
+		})
+		.collect();
+
+	if followed.len() > FOLLOWED_LIST_LIMIT {
+		let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+		args.insert(
+			Cow::Borrowed("count"),
+			FluentValue::from(followed.len() - FOLLOWED_LIST_LIMIT),
+		);
+		lines.push(USABLE_LOCALES.lookup_with_args(lang_id, "anilist_airing-more", &args));
+	}
+
+	lines.join("\n")
+}
@@ -0,0 +1,83 @@
+//! The `AnilistProgressCommand` struct moves the progress of an entry on the
+//! member's AniList list, one episode or chapter by default. Reaching the end
+//! completes the entry, progress on an entry that is not being watched makes
+//! it current again.
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::localization::{Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::anilist::list::{add_progress, entry_progress, linked_account, resolve_media, Link};
+use crate::anilist::{entry_embed, not_found_embed, relink_embed, relink_on_refusal, Relink};
+use crate::command::command::CommandRun;
+use crate::command::context::CommandContext;
+use crate::command::embed_content::EmbedsContents;
+use crate::helper::get_option::subcommand::{
+	get_option_map_integer_subcommand, get_option_map_string_subcommand,
+};
+
+#[slash_command(
+	name = "progress", desc = "Add watched episodes or read chapters to your AniList list.",
+	command_type = SubCommand(parent = "anilist"),
+	contexts = [Guild, BotDm, PrivateChannel],
+	install_contexts = [Guild, User],
+	args = [
+		(name = "media", desc = "An anime or manga from your current list.", arg_type = String, required = true, autocomplete = true),
+		(name = "amount", desc = "How many episodes or chapters to add, 1 by default. Negative to go back.", arg_type = Integer, required = false, autocomplete = false)
+	],
+)]
+async fn anilist_progress_command(self_: AnilistProgressCommand) -> Result<EmbedsContents<'_>> {
+	let cx = CommandContext::new(
+		self_.get_ctx().clone(),
+		self_.get_command_interaction().clone(),
+	);
+	let lang_id = cx.lang_id().await;
+
+	let media = get_option_map_string_subcommand(&cx.command_interaction)
+		.get("media")
+		.cloned()
+		.ok_or(anyhow!("No option for media"))?;
+	let amount = get_option_map_integer_subcommand(&cx.command_interaction)
+		.get("amount")
+		.copied()
+		.unwrap_or(1)
+		.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+
+	let user_id = cx.command_interaction.user.id.to_string();
+	let account = match linked_account(&cx.db, &cx.bot_data.config, &user_id).await? {
+		Link::Linked(account) => account,
+		Link::Missing => return Ok(relink_embed(&lang_id, Relink::NotLinked)),
+		Link::Expired => return Ok(relink_embed(&lang_id, Relink::Expired)),
+	};
+
+	let media_id = match resolve_media(&account, &media).await {
+		Ok(Some(media_id)) => media_id,
+		Ok(None) => return Ok(not_found_embed(&lang_id, media)),
+		Err(e) => return relink_on_refusal(&lang_id, e),
+	};
+
+	let update = match add_progress(&account, media_id, amount).await {
+		Ok(update) => update,
+		Err(e) => return relink_on_refusal(&lang_id, e),
+	};
+
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("previous"),
+		FluentValue::from(update.previous),
+	);
+	args.insert(
+		Cow::Borrowed("progress"),
+		FluentValue::from(entry_progress(&update.entry)),
+	);
+	let description = USABLE_LOCALES.lookup_with_args(&lang_id, "anilist_progress-desc", &args);
+
+	Ok(EmbedsContents::new(vec![entry_embed(
+		&lang_id,
+		&update.entry,
+		description,
+	)]))
+}
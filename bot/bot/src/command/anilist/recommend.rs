@@ -0,0 +1,139 @@
+//! The `AnilistRecommendCommand` struct recommends anime or manga a user has
+//! not added to their AniList list yet, from the titles they scored best.
+use anyhow::Result;
+use fluent_templates::fluent_bundle::FluentValue;
+use kasuki_macros::slash_command;
+use sea_orm::EntityTrait;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::database::prelude::RegisteredUser;
+use shared::localization::{Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use unic_langid::LanguageIdentifier;
+
+use crate::anilist::recommend::{recommend, score_out_of_ten, Pick, RecommendFilter};
+use crate::command::anilist_user::user::get_user;
+use crate::command::command::CommandRun;
+use crate::command::context::CommandContext;
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::helper::get_option::subcommand::{
+	get_option_map_integer_subcommand, get_option_map_string_subcommand,
+};
+use crate::structure::run::anilist::media_recommendation::{MediaFormat, MediaType};
+use crate::structure::run::anilist::user::get_user_url;
+
+#[slash_command(
+	name = "recommend", desc = "Get anime or manga picked from the best scored titles of a list.",
+	command_type = SubCommand(parent = "anilist"),
+	contexts = [Guild, BotDm, PrivateChannel],
+	install_contexts = [Guild, User],
+	args = [
+		(name = "username", desc = "Username of the user, yourself if empty.", arg_type = String, required = false, autocomplete = true),
+		(name = "type", desc = "Anime or manga, anime if empty.", arg_type = String, required = false, autocomplete = false,
+			choices = [(name = "anime"), (name = "manga")]),
+		(name = "genre", desc = "Only titles of this genre.", arg_type = String, required = false, autocomplete = false),
+		(name = "format", desc = "Only titles of this format.", arg_type = String, required = false, autocomplete = false,
+			choices = [(name = "tv"), (name = "tv_short"), (name = "movie"), (name = "special"), (name = "ova"), (name = "ona"), (name = "manga"), (name = "novel"), (name = "one_shot")]),
+		(name = "year", desc = "Only titles released this year.", arg_type = Integer, required = false, autocomplete = false)
+	],
+)]
+async fn anilist_recommend_command(self_: AnilistRecommendCommand) -> Result<EmbedsContents<'_>> {
+	let cx = CommandContext::new(
+		self_.get_ctx().clone(),
+		self_.get_command_interaction().clone(),
+	);
+	let lang_id = cx.lang_id().await;
+
+	let map = get_option_map_string_subcommand(&cx.command_interaction);
+	let integer_map = get_option_map_integer_subcommand(&cx.command_interaction);
+
+	let media_type = match map.get("type").map(String::as_str) {
+		Some("manga") => MediaType::Manga,
+		_ => MediaType::Anime,
+	};
+	let filter = RecommendFilter {
+		genre: map.get("genre").cloned(),
+		format: map
+			.get("format")
+			.and_then(|format| MediaFormat::parse(format)),
+		year: integer_map.get("year").map(|year| *year as i32),
+	};
+
+	let value = match map.get("username") {
+		Some(username) => username.clone(),
+		None => {
+			let registered = RegisteredUser::find_by_id(cx.command_interaction.user.id.to_string())
+				.one(&*cx.db)
+				.await?;
+
+			match registered {
+				Some(registered) => registered.anilist_id.to_string(),
+				None => {
+					let embed_content = EmbedContent::new(
+						USABLE_LOCALES.lookup(&lang_id, "anilist_recommend-title"),
+					)
+					.description(USABLE_LOCALES.lookup(&lang_id, "anilist_recommend-no_user"));
+
+					return Ok(EmbedsContents::new(vec![embed_content]));
+				},
+			}
+		},
+	};
+
+	let user = get_user(&value, cx.anilist_cache.clone()).await?;
+	let picks = recommend(user.id, media_type, &filter, cx.anilist_cache.clone()).await?;
+
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(Cow::Borrowed("user"), FluentValue::from(user.name.clone()));
+	let title = USABLE_LOCALES.lookup_with_args(&lang_id, "anilist_recommend-title_user", &args);
+
+	let description = if picks.is_empty() {
+		USABLE_LOCALES.lookup(&lang_id, "anilist_recommend-nothing")
+	} else {
+		picks
+			.iter()
+			.enumerate()
+			.map(|(index, pick)| pick_line(&lang_id, index, pick))
+			.collect::<Vec<_>>()
+			.join("\n\n")
+	};
+
+	let embed_content = EmbedContent::new(title)
+		.description(description)
+		.url(get_user_url(&user.id));
+
+	Ok(EmbedsContents::new(vec![embed_content]))
+}
+
+/// The title of a pick and why it was picked.
+fn pick_line(lang_id: &LanguageIdentifier, index: usize, pick: &Pick) -> String {
+	let title = match &pick.url {
+		Some(url) => format!("[{}]({})", pick.title, url),
+		None => pick.title.clone(),
+	};
+	let mut line = match pick.mean_score {
+		Some(mean_score) => format!("{}. **{}** ({}%)", index + 1, title, mean_score),
+		None => format!("{}. **{}**", index + 1, title),
+	};
+
+	let (seed, score) = &pick.because;
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(Cow::Borrowed("title"), FluentValue::from(seed.clone()));
+	args.insert(
+		Cow::Borrowed("score"),
+		FluentValue::from(score_out_of_ten(*score)),
+	);
+	line.push('\n');
+	line.push_str(&USABLE_LOCALES.lookup_with_args(lang_id, "anilist_recommend-because", &args));
+
+	if !pick.tags.is_empty() {
+		let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+		args.insert(
+			Cow::Borrowed("tags"),
+			FluentValue::from(pick.tags.join(", ")),
+		);
+		line.push_str(&USABLE_LOCALES.lookup_with_args(lang_id, "anilist_recommend-tags", &args));
+	}
+
+	line
+}
@@ -0,0 +1,90 @@
+//! The `AnilistScheduleCommand` struct shows the episodes airing on a day of
+//! the guild timezone, optionally only those the guild or the member follows.
+use anyhow::Result;
+use fluent_templates::Loader;
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::localization::USABLE_LOCALES;
+use std::borrow::Cow;
+
+use crate::anilist::schedule::{load_timetable, schedule_page, ScheduleDay, ScheduleScope};
+use crate::command::command::CommandRun;
+use crate::command::context::CommandContext;
+use crate::command::embed_content::{
+	ComponentVersion, ComponentVersion2, CreateFooter, EmbedContent, EmbedsContents,
+};
+use crate::components::anilist::schedule::{
+	schedule_buttons, schedule_description, schedule_footer, schedule_title,
+};
+use crate::helper::get_option::subcommand::get_option_map_string_subcommand;
+
+#[slash_command(
+	name = "schedule", desc = "Show the anime episodes airing on a day.",
+	command_type = SubCommand(parent = "anilist"),
+	contexts = [Guild, BotDm, PrivateChannel],
+	install_contexts = [Guild, User],
+	args = [
+		(name = "day", desc = "The day, today if empty.", arg_type = String, required = false, autocomplete = false,
+			choices = [(name = "today"), (name = "tomorrow"), (name = "monday"), (name = "tuesday"), (name = "wednesday"), (name = "thursday"), (name = "friday"), (name = "saturday"), (name = "sunday")]),
+		(name = "scope", desc = "Every anime, those the server follows or those on your list.", arg_type = String, required = false, autocomplete = false,
+			choices = [(name = "all"), (name = "server"), (name = "list")])
+	],
+)]
+async fn anilist_schedule_command(self_: AnilistScheduleCommand) -> Result<EmbedsContents<'_>> {
+	let cx = CommandContext::new(
+		self_.get_ctx().clone(),
+		self_.get_command_interaction().clone(),
+	);
+	let lang_id = cx.lang_id().await;
+
+	let map = get_option_map_string_subcommand(&cx.command_interaction);
+	let day = map
+		.get("day")
+		.and_then(|day| ScheduleDay::parse(day))
+		.unwrap_or(ScheduleDay::Today);
+	let scope = map
+		.get("scope")
+		.and_then(|scope| ScheduleScope::parse(scope))
+		.unwrap_or(ScheduleScope::All);
+
+	let timetable = load_timetable(
+		cx.db.clone(),
+		cx.anilist_cache.clone(),
+		cx.command_interaction
+			.guild_id
+			.map(|guild_id| guild_id.to_string()),
+		&cx.command_interaction.user.id.to_string(),
+		day,
+		scope,
+	)
+	.await?;
+
+	let Some(timetable) = timetable else {
+		let embed_content =
+			EmbedContent::new(USABLE_LOCALES.lookup(&lang_id, "anilist_schedule-no_user_title"))
+				.description(USABLE_LOCALES.lookup(&lang_id, "anilist_schedule-no_user"));
+
+		return Ok(EmbedsContents::new(vec![embed_content]));
+	};
+
+	let title = schedule_title(&timetable, &lang_id);
+	let schedule = schedule_page(timetable.entries.clone(), 0);
+
+	let embed_content = EmbedContent::new(title)
+		.description(schedule_description(&timetable, scope, &schedule, &lang_id))
+		.footer(CreateFooter::new(schedule_footer(&schedule, &lang_id)));
+
+	Ok(
+		EmbedsContents::new(vec![embed_content]).action_row(ComponentVersion::V2(
+			ComponentVersion2 {
+				components: Cow::Owned(schedule_buttons(
+					day,
+					scope,
+					schedule.page,
+					schedule.total_pages,
+					&lang_id,
+				)),
+			},
+		)),
+	)
+}
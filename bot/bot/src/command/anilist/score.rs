@@ -0,0 +1,104 @@
+//! The `AnilistScoreCommand` struct scores an entry on the member's AniList
+//! list. The score is read in the member's own AniList score format, so a 7
+//! means 7/10 for one member and 7/100 for another.
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::localization::{Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::anilist::list::{
+	linked_account, resolve_media, save_entry, score_format, score_max, validate_score, Link,
+};
+use crate::anilist::{entry_embed, not_found_embed, relink_embed, relink_on_refusal, Relink};
+use crate::command::command::CommandRun;
+use crate::command::context::CommandContext;
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::helper::get_option::subcommand::{
+	get_option_map_number_subcommand, get_option_map_string_subcommand,
+};
+use crate::structure::run::anilist::list_entry::SaveMediaListEntryVariables;
+
+#[slash_command(
+	name = "score", desc = "Score an anime or manga on your AniList list.",
+	command_type = SubCommand(parent = "anilist"),
+	contexts = [Guild, BotDm, PrivateChannel],
+	install_contexts = [Guild, User],
+	args = [
+		(name = "media", desc = "An anime or manga from your current list.", arg_type = String, required = true, autocomplete = true),
+		(name = "score", desc = "The score, in the format set on your AniList profile.", arg_type = Number, required = true, autocomplete = false)
+	],
+)]
+async fn anilist_score_command(self_: AnilistScoreCommand) -> Result<EmbedsContents<'_>> {
+	let cx = CommandContext::new(
+		self_.get_ctx().clone(),
+		self_.get_command_interaction().clone(),
+	);
+	let lang_id = cx.lang_id().await;
+
+	let media = get_option_map_string_subcommand(&cx.command_interaction)
+		.get("media")
+		.cloned()
+		.ok_or(anyhow!("No option for media"))?;
+	let score = get_option_map_number_subcommand(&cx.command_interaction)
+		.get("score")
+		.copied()
+		.ok_or(anyhow!("No option for score"))?;
+
+	let user_id = cx.command_interaction.user.id.to_string();
+	let account = match linked_account(&cx.db, &cx.bot_data.config, &user_id).await? {
+		Link::Linked(account) => account,
+		Link::Missing => return Ok(relink_embed(&lang_id, Relink::NotLinked)),
+		Link::Expired => return Ok(relink_embed(&lang_id, Relink::Expired)),
+	};
+
+	let media_id = match resolve_media(&account, &media).await {
+		Ok(Some(media_id)) => media_id,
+		Ok(None) => return Ok(not_found_embed(&lang_id, media)),
+		Err(e) => return relink_on_refusal(&lang_id, e),
+	};
+
+	let format = match score_format(&account).await {
+		Ok(format) => format,
+		Err(e) => return relink_on_refusal(&lang_id, e),
+	};
+
+	let Some(score) = validate_score(format, score) else {
+		let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+		args.insert(Cow::Borrowed("score"), FluentValue::from(score));
+		args.insert(Cow::Borrowed("max"), FluentValue::from(score_max(format)));
+		let embed_content = EmbedContent::new(
+			USABLE_LOCALES.lookup(&lang_id, "anilist_score-title"),
+		)
+		.description(USABLE_LOCALES.lookup_with_args(&lang_id, "anilist_score-invalid", &args));
+
+		return Ok(EmbedsContents::new(vec![embed_content]));
+	};
+
+	let entry = match save_entry(
+		&account,
+		SaveMediaListEntryVariables {
+			media_id: Some(media_id),
+			status: None,
+			score: Some(score),
+			progress: None,
+		},
+	)
+	.await
+	{
+		Ok(entry) => entry,
+		Err(e) => return relink_on_refusal(&lang_id, e),
+	};
+
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(Cow::Borrowed("score"), FluentValue::from(score));
+	let description = USABLE_LOCALES.lookup_with_args(&lang_id, "anilist_score-desc", &args);
+
+	Ok(EmbedsContents::new(vec![entry_embed(
+		&lang_id,
+		&entry,
+		description,
+	)]))
+}
@@ -0,0 +1,92 @@
+//! The `AnilistSeasonCommand` struct lists the anime of a season, the most
+//! popular first, optionally of one format, genre or studio.
+use anyhow::Result;
+use chrono::{Datelike, Utc};
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use std::borrow::Cow;
+
+use crate::anilist::season::{current_season, fetch_season, season_page, SeasonView};
+use crate::command::command::CommandRun;
+use crate::command::context::CommandContext;
+use crate::command::embed_content::{
+	ComponentVersion, ComponentVersion2, CreateFooter, EmbedContent, EmbedsContents,
+};
+use crate::components::anilist::season::{
+	season_buttons, season_description, season_footer, season_title,
+};
+use crate::helper::get_option::subcommand::{
+	get_option_map_integer_subcommand, get_option_map_string_subcommand,
+};
+use crate::structure::run::anilist::airing::{MediaFormat, MediaSeason};
+
+#[slash_command(
+	name = "season", desc = "List the anime of a season.",
+	command_type = SubCommand(parent = "anilist"),
+	contexts = [Guild, BotDm, PrivateChannel],
+	install_contexts = [Guild, User],
+	args = [
+		(name = "year", desc = "Year of the season, the current one if empty.", arg_type = Integer, required = false, autocomplete = false),
+		(name = "season", desc = "The season, the current one if empty.", arg_type = String, required = false, autocomplete = false,
+			choices = [(name = "winter"), (name = "spring"), (name = "summer"), (name = "fall")]),
+		(name = "format", desc = "Only anime of this format.", arg_type = String, required = false, autocomplete = false,
+			choices = [(name = "tv"), (name = "tv_short"), (name = "movie"), (name = "special"), (name = "ova"), (name = "ona"), (name = "music")]),
+		(name = "genre", desc = "Only anime of this genre.", arg_type = String, required = false, autocomplete = false),
+		(name = "studio", desc = "Only anime made by this studio.", arg_type = String, required = false, autocomplete = false)
+	],
+)]
+async fn anilist_season_command(self_: AnilistSeasonCommand) -> Result<EmbedsContents<'_>> {
+	let cx = CommandContext::new(
+		self_.get_ctx().clone(),
+		self_.get_command_interaction().clone(),
+	);
+	let lang_id = cx.lang_id().await;
+
+	let map = get_option_map_string_subcommand(&cx.command_interaction);
+	let integer_map = get_option_map_integer_subcommand(&cx.command_interaction);
+
+	let today = Utc::now().date_naive();
+	let (current_year, current) = current_season(today);
+	let season = map
+		.get("season")
+		.and_then(|season| MediaSeason::parse(season))
+		.unwrap_or(current);
+	// December already airs the winter of the next year
+	let default_year = if season == current {
+		current_year
+	} else {
+		today.year()
+	};
+
+	let view = SeasonView::new(
+		integer_map
+			.get("year")
+			.map(|year| *year as i32)
+			.unwrap_or(default_year),
+		season,
+		map.get("format")
+			.and_then(|format| MediaFormat::parse(format)),
+		map.get("genre").cloned(),
+		map.get("studio").cloned(),
+	);
+
+	let entries = fetch_season(&view, cx.anilist_cache.clone()).await?;
+	let season = season_page(entries, 0);
+
+	let embed_content = EmbedContent::new(season_title(&view, &lang_id))
+		.description(season_description(&view, &season, &lang_id))
+		.footer(CreateFooter::new(season_footer(&season, &lang_id)));
+
+	Ok(
+		EmbedsContents::new(vec![embed_content]).action_row(ComponentVersion::V2(
+			ComponentVersion2 {
+				components: Cow::Owned(season_buttons(
+					&view,
+					season.page,
+					season.total_pages,
+					&lang_id,
+				)),
+			},
+		)),
+	)
+}
@@ -0,0 +1,90 @@
+//! The `AnilistStatusCommand` struct moves an entry of the member's AniList
+//! list to another status, adding the media to the list when it is not on it.
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::localization::{Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::anilist::list::{linked_account, resolve_media, save_entry, Link};
+use crate::anilist::{entry_embed, not_found_embed, relink_embed, relink_on_refusal, Relink};
+use crate::command::command::CommandRun;
+use crate::command::context::CommandContext;
+use crate::command::embed_content::EmbedsContents;
+use crate::helper::get_option::subcommand::get_option_map_string_subcommand;
+use crate::structure::run::anilist::list_entry::{MediaListStatus, SaveMediaListEntryVariables};
+
+#[slash_command(
+	name = "status", desc = "Change the status of an anime or manga on your AniList list.",
+	command_type = SubCommand(parent = "anilist"),
+	contexts = [Guild, BotDm, PrivateChannel],
+	install_contexts = [Guild, User],
+	args = [
+		(name = "media", desc = "An anime or manga from your current list.", arg_type = String, required = true, autocomplete = true),
+		(name = "status", desc = "The new status.", arg_type = String, required = true, autocomplete = false,
+			choices = [(name = "current"), (name = "planning"), (name = "completed"), (name = "dropped"), (name = "paused"), (name = "repeating")])
+	],
+)]
+async fn anilist_status_command(self_: AnilistStatusCommand) -> Result<EmbedsContents<'_>> {
+	let cx = CommandContext::new(
+		self_.get_ctx().clone(),
+		self_.get_command_interaction().clone(),
+	);
+	let lang_id = cx.lang_id().await;
+
+	let map = get_option_map_string_subcommand(&cx.command_interaction);
+	let media = map
+		.get("media")
+		.cloned()
+		.ok_or(anyhow!("No option for media"))?;
+	let status = map
+		.get("status")
+		.and_then(|status| MediaListStatus::parse(status))
+		.ok_or(anyhow!("No option for status"))?;
+
+	let user_id = cx.command_interaction.user.id.to_string();
+	let account = match linked_account(&cx.db, &cx.bot_data.config, &user_id).await? {
+		Link::Linked(account) => account,
+		Link::Missing => return Ok(relink_embed(&lang_id, Relink::NotLinked)),
+		Link::Expired => return Ok(relink_embed(&lang_id, Relink::Expired)),
+	};
+
+	let media_id = match resolve_media(&account, &media).await {
+		Ok(Some(media_id)) => media_id,
+		Ok(None) => return Ok(not_found_embed(&lang_id, media)),
+		Err(e) => return relink_on_refusal(&lang_id, e),
+	};
+
+	let entry = match save_entry(
+		&account,
+		SaveMediaListEntryVariables {
+			media_id: Some(media_id),
+			status: Some(status),
+			score: None,
+			progress: None,
+		},
+	)
+	.await
+	{
+		Ok(entry) => entry,
+		Err(e) => return relink_on_refusal(&lang_id, e),
+	};
+
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("status"),
+		FluentValue::from(USABLE_LOCALES.lookup(
+			&lang_id,
+			&format!("anilist_list-status_{}", status.as_str()),
+		)),
+	);
+	let description = USABLE_LOCALES.lookup_with_args(&lang_id, "anilist_status-desc", &args);
+
+	Ok(EmbedsContents::new(vec![entry_embed(
+		&lang_id,
+		&entry,
+		description,
+	)]))
+}
@@ -0,0 +1,70 @@
+//! The `AnilistUnfollowCommand` struct stops the airing DMs of an anime.
+use anyhow::{anyhow, Result};
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::localization::{Loader, USABLE_LOCALES};
+
+use crate::anilist::follow::{followed_anime, unfollow_anime};
+use crate::command::command::CommandRun;
+use crate::command::context::CommandContext;
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::helper::get_option::subcommand::get_option_map_string_subcommand;
+
+#[slash_command(
+	name = "unfollow", desc = "Stop the airing DMs of an anime.",
+	command_type = SubCommand(parent = "anilist"),
+	contexts = [Guild, BotDm, PrivateChannel],
+	install_contexts = [Guild, User],
+	args = [
+		(name = "anime", desc = "An anime you follow.", arg_type = String, required = true, autocomplete = true)
+	],
+)]
+async fn anilist_unfollow_command(self_: AnilistUnfollowCommand) -> Result<EmbedsContents<'_>> {
+	let cx = CommandContext::new(
+		self_.get_ctx().clone(),
+		self_.get_command_interaction().clone(),
+	);
+	let lang_id = cx.lang_id().await;
+
+	let anime = get_option_map_string_subcommand(&cx.command_interaction)
+		.get("anime")
+		.cloned()
+		.ok_or(anyhow!("No option for anime"))?;
+	let user_id = cx.command_interaction.user.id.to_string();
+
+	// The autocomplete gives the id, a typed title is looked up among the
+	// followed anime
+	let anime_id = match anime.parse::<i32>() {
+		Ok(anime_id) => Some(anime_id),
+		Err(_) => {
+			let anime = anime.to_lowercase();
+
+			followed_anime(&cx.db, &user_id)
+				.await?
+				.into_iter()
+				.find(|followed| {
+					followed
+						.name
+						.as_ref()
+						.is_some_and(|name| name.to_lowercase().contains(&anime))
+				})
+				.map(|followed| followed.anime_id)
+		},
+	};
+
+	let unfollowed = match anime_id {
+		Some(anime_id) => unfollow_anime(&cx.db, &user_id, anime_id).await?,
+		None => false,
+	};
+	let description = if unfollowed {
+		USABLE_LOCALES.lookup(&lang_id, "anilist_airing-unfollowed")
+	} else {
+		USABLE_LOCALES.lookup(&lang_id, "anilist_airing-not_following")
+	};
+
+	let embed_content =
+		EmbedContent::new(USABLE_LOCALES.lookup(&lang_id, "anilist_airing-unfollow_title"))
+			.description(description);
+
+	Ok(EmbedsContents::new(vec![embed_content]))
+}
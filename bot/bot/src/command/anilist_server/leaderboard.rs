@@ -0,0 +1,95 @@
+//! The `AnilistLeaderboardCommand` ranks the server members by the statistics
+//! of their registered AniList account, as last refreshed by the worker.
+use crate::anilist::leaderboard::{stat_page, Stat};
+use crate::command::command::CommandRun;
+use crate::command::embed_content::{
+	ComponentVersion, ComponentVersion2, CreateFooter, EmbedContent, EmbedsContents,
+};
+use crate::components::anilist::leaderboard::{
+	leaderboard_buttons, leaderboard_description, leaderboard_footer, leaderboard_title,
+};
+use crate::components::pagination::page_index;
+use crate::event_handler::BotData;
+use crate::helper::get_option::command::{get_i64, get_string_opt};
+use anyhow::{anyhow, Result};
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::helper::anilist_link::verified_links_only;
+use shared::localization::get_language_identifier;
+use std::borrow::Cow;
+use tracing::debug;
+
+#[slash_command(
+	name = "anilist_leaderboard", desc = "Rank the server members by their AniList statistics.",
+	command_type = ChatInput,
+	contexts = [Guild],
+	install_contexts = [Guild],
+	args = [(name = "stat", desc = "What to rank by, the level by default.", arg_type = String, required = false, autocomplete = false,
+		choices = [(name = "level"), (name = "minutes_watched"), (name = "chapters_read"), (name = "mean_score"), (name = "completed")]),
+		(name = "page", desc = "The page to start on.", arg_type = Integer, required = false, autocomplete = false)],
+)]
+async fn anilist_leaderboard_command(
+	self_: AnilistLeaderboardCommand,
+) -> Result<EmbedsContents<'_>> {
+	let ctx = self_.get_ctx();
+	let bot_data = ctx.data::<BotData>().clone();
+	let command_interaction = self_.get_command_interaction();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = command_interaction
+		.guild_id
+		.ok_or(anyhow!("Leaderboards are only available in servers"))?
+		.to_string();
+
+	let stat = get_string_opt(command_interaction, "stat")
+		.and_then(|stat| Stat::parse(&stat))
+		.unwrap_or(Stat::Level);
+
+	let page = page_index(get_i64(command_interaction, "page"));
+
+	let viewer_id = command_interaction.user.id.to_string();
+	let verified_only = verified_links_only(guild_id.clone(), &db_connection).await;
+	let leaderboard = stat_page(
+		&db_connection,
+		&guild_id,
+		stat,
+		verified_only,
+		&viewer_id,
+		page,
+	)
+	.await?;
+	debug!(
+		"AniList leaderboard {} page {}/{} with {} entries",
+		stat.as_str(),
+		leaderboard.page + 1,
+		leaderboard.total_pages,
+		leaderboard.entries.len()
+	);
+
+	let lang_id = get_language_identifier(guild_id, db_connection).await;
+
+	let embed_content = EmbedContent::new(leaderboard_title(stat, &lang_id))
+		.description(leaderboard_description(
+			stat,
+			&leaderboard,
+			&viewer_id,
+			&lang_id,
+		))
+		.footer(CreateFooter::new(leaderboard_footer(
+			&leaderboard,
+			&lang_id,
+		)));
+
+	Ok(
+		EmbedsContents::new(vec![embed_content]).action_row(ComponentVersion::V2(
+			ComponentVersion2 {
+				components: Cow::Owned(leaderboard_buttons(
+					stat,
+					leaderboard.page,
+					leaderboard.total_pages,
+					&lang_id,
+				)),
+			},
+		)),
+	)
+}
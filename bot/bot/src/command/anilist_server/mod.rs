@@ -1,2 +1,3 @@
+pub mod leaderboard;
 pub mod list_all_activity;
 pub mod list_register_user;
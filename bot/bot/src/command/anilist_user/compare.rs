@@ -3,19 +3,26 @@
 use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 
+use anyhow::Result;
 use fluent_templates::fluent_bundle::FluentValue;
 use fluent_templates::Loader;
 use kasuki_macros::slash_command;
-use serenity::all::{CommandInteraction, Context as SerenityContext};
+use serenity::all::{ButtonStyle, CommandInteraction, Context as SerenityContext};
 use serenity::builder::{
-	CreateComponent, CreateContainer, CreateContainerComponent, CreateSection,
-	CreateSectionAccessory, CreateSectionComponent, CreateSeparator, CreateTextDisplay,
-	CreateThumbnail, CreateUnfurledMediaItem,
+	CreateActionRow, CreateButton, CreateComponent, CreateContainer, CreateContainerComponent,
+	CreateSection, CreateSectionAccessory, CreateSectionComponent, CreateSeparator,
+	CreateTextDisplay, CreateThumbnail, CreateUnfurledMediaItem,
 };
+use shared::cache::CacheInterface;
 use shared::localization::{LanguageIdentifier, USABLE_LOCALES};
 use small_fixed_array::FixedString;
+use std::sync::Arc;
+use tokio::sync::RwLock;
 use tracing::trace;
 
+use crate::anilist::compare::{
+	compare_button_id, compare_lists, fetch_list, page_count, page_of, CompareView, ListComparison,
+};
 use crate::command::anilist_user::user::get_user;
 use crate::command::context::CommandContext;
 use crate::command::embed_content::ComponentVersion::V2;
@@ -57,11 +64,47 @@ async fn compare_command(self_: CompareCommand) -> Result<EmbedsContents<'_>> {
 	// Fetch the user data for both users
 	let user: User = get_user(&value, anilist_cache.clone()).await?;
 
-	let user2: User = get_user(&value2, anilist_cache).await?;
+	let user2: User = get_user(&value2, anilist_cache.clone()).await?;
 
 	// Get the language identifier for localization
 	let lang_id = cx.lang_id().await;
 
+	let components = compare_components(
+		user,
+		user2,
+		CompareView::Disagreements,
+		0,
+		&lang_id,
+		anilist_cache,
+	)
+	.await?;
+
+	let embed_contents = EmbedsContents::new(vec![]).action_row(V2(ComponentVersion2 {
+		components: Cow::Owned(components),
+	}));
+
+	Ok(embed_contents)
+}
+
+/// The statistics comparison, then one page of the list comparison with the
+/// buttons to move through it. The buttons rebuild the whole message with it.
+pub async fn compare_components(
+	user: User, user2: User, view: CompareView, page: usize, lang_id: &LanguageIdentifier,
+	anilist_cache: Arc<RwLock<CacheInterface>>,
+) -> Result<Vec<CreateComponent<'static>>> {
+	let first = fetch_list(user.id, anilist_cache.clone()).await?;
+	let second = fetch_list(user2.id, anilist_cache).await?;
+	let comparison = compare_lists(&first, &second);
+
+	let mut components = list_components(&user, &user2, &comparison, view, page, lang_id);
+	components.insert(0, stats_container(user, user2, lang_id));
+
+	Ok(components)
+}
+
+fn stats_container(
+	user: User, user2: User, lang_id: &LanguageIdentifier,
+) -> CreateComponent<'static> {
 	// Clone the user data
 	let username = user.name.clone();
 
@@ -410,14 +453,172 @@ async fn compare_command(self_: CompareCommand) -> Result<EmbedsContents<'_>> {
 		u2,
 	];
 
-	let create_components = CreateComponent::Container(CreateContainer::new(data));
+	CreateComponent::Container(CreateContainer::new(data))
+}
 
-	let embed_contents =
-		EmbedsContents::new(vec![]).action_row(V2(ComponentVersion2 {
-			components: Cow::Owned(vec![create_components]),
-		}));
+fn list_components(
+	user: &User, user2: &User, comparison: &ListComparison, view: CompareView, page: usize,
+	lang_id: &LanguageIdentifier,
+) -> Vec<CreateComponent<'static>> {
+	let mut args: HashMap<Cow<'static, str>, FluentValue<'_>> = HashMap::new();
+	args.insert(Cow::Borrowed("var1"), FluentValue::from(user.name.as_str()));
+	args.insert(
+		Cow::Borrowed("var2"),
+		FluentValue::from(user2.name.as_str()),
+	);
+	args.insert(
+		Cow::Borrowed("shared"),
+		FluentValue::from(comparison.shared),
+	);
+	args.insert(
+		Cow::Borrowed("scored"),
+		FluentValue::from(comparison.scored),
+	);
 
-	Ok(embed_contents)
+	let mut summary = format!(
+		"### {}\n{}\n",
+		USABLE_LOCALES.lookup(lang_id, "anilist_user_compare-list_title"),
+		USABLE_LOCALES.lookup_with_args(lang_id, "anilist_user_compare-shared", &args)
+	);
+	match (comparison.pearson, comparison.spearman) {
+		(Some(pearson), Some(spearman)) => {
+			args.insert(
+				Cow::Borrowed("pearson"),
+				FluentValue::from(format!("{:.2}", pearson)),
+			);
+			args.insert(
+				Cow::Borrowed("spearman"),
+				FluentValue::from(format!("{:.2}", spearman)),
+			);
+			summary.push_str(&USABLE_LOCALES.lookup_with_args(
+				lang_id,
+				"anilist_user_compare-correlation",
+				&args,
+			));
+		},
+		_ => {
+			summary.push_str(&USABLE_LOCALES.lookup(lang_id, "anilist_user_compare-no_correlation"))
+		},
+	}
+
+	let (heading_key, lines, len) = match view {
+		CompareView::Disagreements => {
+			let lines: Vec<String> = page_of(&comparison.disagreements, page)
+				.iter()
+				.map(|entry| {
+					format!(
+						"- {} — {}: {} · {}: {}",
+						list_title(&entry.title, &entry.url),
+						user.name,
+						entry.score,
+						user2.name,
+						entry.score2
+					)
+				})
+				.collect();
+
+			(
+				"anilist_user_compare-disagreements",
+				lines,
+				comparison.disagreements.len(),
+			)
+		},
+		CompareView::ForFirst | CompareView::ForSecond => {
+			let (items, owner) = if view == CompareView::ForFirst {
+				(&comparison.for_first, user2)
+			} else {
+				(&comparison.for_second, user)
+			};
+			let lines: Vec<String> = page_of(items, page)
+				.iter()
+				.map(|item| {
+					format!(
+						"- {} — {}: {}",
+						list_title(&item.title, &item.url),
+						owner.name,
+						item.score
+					)
+				})
+				.collect();
+			let heading_key = if view == CompareView::ForFirst {
+				"anilist_user_compare-for_first"
+			} else {
+				"anilist_user_compare-for_second"
+			};
+
+			(heading_key, lines, items.len())
+		},
+	};
+
+	let page_total = page_count(len);
+	let page = page.min(page_total - 1);
+	args.insert(Cow::Borrowed("page"), FluentValue::from(page + 1));
+	args.insert(Cow::Borrowed("total"), FluentValue::from(page_total));
+
+	let mut text = format!(
+		"{}\n### {}\n-# {}\n",
+		summary,
+		USABLE_LOCALES.lookup_with_args(lang_id, heading_key, &args),
+		USABLE_LOCALES.lookup_with_args(lang_id, "anilist_user_compare-page", &args)
+	);
+	if lines.is_empty() {
+		text.push_str(&USABLE_LOCALES.lookup(lang_id, "anilist_user_compare-nothing"));
+	} else {
+		text.push_str(&lines.join("\n"));
+	}
+
+	let list = CreateComponent::Container(CreateContainer::new(vec![
+		CreateContainerComponent::TextDisplay(CreateTextDisplay::new(text)),
+	]));
+
+	let view_buttons = CompareView::ALL
+		.into_iter()
+		.map(|button_view| {
+			let label = match button_view {
+				CompareView::Disagreements => {
+					USABLE_LOCALES.lookup(lang_id, "anilist_user_compare-disagreements_button")
+				},
+				CompareView::ForFirst => format!("{} ➜ {}", user2.name, user.name),
+				CompareView::ForSecond => format!("{} ➜ {}", user.name, user2.name),
+			};
+
+			CreateButton::new(compare_button_id(user.id, user2.id, button_view, 0))
+				.label(label)
+				.style(if button_view == view {
+					ButtonStyle::Primary
+				} else {
+					ButtonStyle::Secondary
+				})
+				.disabled(button_view == view)
+		})
+		.collect::<Vec<_>>();
+
+	let page_buttons = vec![
+		CreateButton::new(compare_button_id(
+			user.id,
+			user2.id,
+			view,
+			page.saturating_sub(1),
+		))
+		.label(USABLE_LOCALES.lookup(lang_id, "anilist_user_compare-previous"))
+		.disabled(page == 0),
+		CreateButton::new(compare_button_id(user.id, user2.id, view, page + 1))
+			.label(USABLE_LOCALES.lookup(lang_id, "anilist_user_compare-next"))
+			.disabled(page + 1 >= page_total),
+	];
+
+	vec![
+		list,
+		CreateComponent::ActionRow(CreateActionRow::Buttons(Cow::Owned(view_buttons))),
+		CreateComponent::ActionRow(CreateActionRow::Buttons(Cow::Owned(page_buttons))),
+	]
+}
+
+fn list_title(title: &str, url: &Option<String>) -> String {
+	match url {
+		Some(url) => format!("[{}]({})", title, url),
+		None => title.to_string(),
+	}
 }
 
 /// Calculates the affinity score between two users based on their anime and manga preferences.
@@ -7,6 +7,7 @@ use fluent_templates::Loader;
 use once_cell::sync::Lazy;
 use sea_orm::EntityTrait;
 use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::anilist::user_stats::anilist_xp;
 use shared::localization::USABLE_LOCALES;
 use std::borrow::Cow;
 use std::collections::HashMap;
@@ -101,9 +102,11 @@ async fn level_command(self_: LevelCommand) -> Result<EmbedsContents<'_>> {
 	};
 
 	// Calculate the experience points
-	let xp = (8.0 * (manga_completed + anime_completed) as f64)
-		+ (2.0 * chap_read as f64)
-		+ (tw as f64 * 0.5);
+	let xp = anilist_xp(
+		(manga_completed + anime_completed) as i64,
+		chap_read as i64,
+		tw as i64,
+	);
 
 	// Get the username
 	let username = user.name.clone();
@@ -192,7 +195,7 @@ fn generate_levels() -> [(u32, f64, f64); TOTAL_LEVELS] {
 /// - `u32`: The user's current level.
 /// - `f64`: The amount of XP the user has accumulated within their current level.
 /// - `f64`: The amount of XP required to reach the next level from the start of the current level.
-fn get_level(xp: f64) -> (u32, f64, f64) {
+pub fn get_level(xp: f64) -> (u32, f64, f64) {
 	for &(level, required_xp, next_level_xp) in LEVELS.iter().rev() {
 		if xp >= required_xp {
 			let xp_in_current_level = xp - required_xp;
@@ -11,3 +11,4 @@ pub mod staff;
 pub mod studio;
 pub mod user;
 pub mod waifu;
+pub mod verify;
@@ -3,7 +3,8 @@
 //! and a database for user registrations.
 //!
 //! This command allows users to register their AniList account with the bot, storing a mapping
-//! between their Discord ID and AniList ID in the database.
+//! between their Discord ID and AniList ID in the database. Such a link is unverified until the
+//! user logs in on AniList through `verify`.
 //!
 //! # Fields
 //! - `ctx`: The `SerenityContext` that represents the current bot state and provides access to shared
@@ -14,21 +15,25 @@
 //! This struct implements the `Command` trait, defining the behavior and response of the "register" command.
 use anyhow::anyhow;
 
+use chrono::Utc;
 use fluent_templates::fluent_bundle::FluentValue;
 use fluent_templates::Loader;
 use kasuki_macros::slash_command;
+use sea_orm::sea_query::OnConflict;
 use sea_orm::ActiveValue::Set;
-use sea_orm::EntityTrait;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
 use serenity::all::{CommandInteraction, Context as SerenityContext};
 use shared::localization::USABLE_LOCALES;
 use small_fixed_array::FixedString;
 use std::borrow::Cow;
 use std::collections::HashMap;
 
+use crate::achievement::{track, Stat};
 use crate::command::anilist_user::user::get_user;
 use crate::command::command::CommandRun;
 use crate::command::context::CommandContext;
 use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::handlers::user_db::add_user_data_to_db;
 use crate::helper::get_option::command::get_option_map_string;
 use crate::structure::run::anilist::user::{get_color, get_user_url, User};
 use shared::database::prelude::RegisteredUser;
@@ -66,19 +71,53 @@ async fn register_command(self_: RegisterCommand) -> Result<EmbedsContents<'_>>
 
 	let username = &cx.command_interaction.user.name;
 
+	// registered_user and achievement progress reference user_data
+	add_user_data_to_db(cx.command_interaction.user.clone(), connection.clone()).await?;
+
+	let existing = RegisteredUser::find()
+		.filter(Column::AnilistId.eq(user_data.id))
+		.one(&*connection)
+		.await?;
+	if let Some(owner) = existing.as_ref().filter(|row| row.user_id != *user_id) {
+		// Only the AniList login can take an account someone else claimed
+		let key = if owner.verified {
+			"anilist_user_register-taken_verified"
+		} else {
+			"anilist_user_register-taken"
+		};
+		let embed_content = EmbedContent::new(user_data.name.clone())
+			.description(USABLE_LOCALES.lookup(&lang_id, key))
+			.url(get_user_url(&user_data.id));
+
+		return Ok(EmbedsContents::new(vec![embed_content]));
+	}
+	// Registering the account again keeps a verification already made
+	let verified = existing.is_some_and(|row| row.verified);
+
 	RegisteredUser::insert(ActiveModel {
 		user_id: Set(user_id.to_string()),
 		anilist_id: Set(user_data.id),
-		..Default::default()
+		registered_at: Set(Utc::now().naive_utc()),
+		verified: Set(verified),
 	})
 	.on_conflict(
-		sea_orm::sea_query::OnConflict::column(Column::AnilistId)
-			.update_column(Column::AnilistId)
+		OnConflict::column(Column::UserId)
+			.update_columns([Column::AnilistId, Column::RegisteredAt, Column::Verified])
 			.to_owned(),
 	)
 	.exec(&*connection)
 	.await?;
 
+	track(
+		&cx.ctx,
+		cx.command_interaction.user.id,
+		cx.command_interaction.guild_id,
+		Some(cx.command_interaction.channel_id),
+		Stat::AnilistRegistered,
+		1,
+	)
+	.await;
+
 	// Construct the description for the embed using Fluent
 	let mut args: HashMap<Cow<'static, str>, FluentValue<'_>> = HashMap::new();
 	args.insert(Cow::Borrowed("user"), FluentValue::from(username.as_str()));
@@ -88,7 +127,11 @@ async fn register_command(self_: RegisterCommand) -> Result<EmbedsContents<'_>>
 		FluentValue::from(user_data.name.clone()),
 	);
 
-	let desc = USABLE_LOCALES.lookup_with_args(&lang_id, "anilist_user_register-desc", &args);
+	let mut desc = USABLE_LOCALES.lookup_with_args(&lang_id, "anilist_user_register-desc", &args);
+	if !verified {
+		desc.push_str("\n\n");
+		desc.push_str(&USABLE_LOCALES.lookup(&lang_id, "anilist_user_register-unverified"));
+	}
 
 	let embed_content = EmbedContent::new(user_data.clone().name)
 		.description(desc)
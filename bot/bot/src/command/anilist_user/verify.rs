@@ -0,0 +1,76 @@
+//! The `VerifyCommand` struct starts the verified AniList linking. Anyone can
+//! claim a username with `register`, logging in on AniList proves the account
+//! is theirs. The login link itself is personal, so the command only posts a
+//! button and the link is sent privately to whoever presses it.
+use anyhow::Result;
+use fluent_templates::fluent_bundle::FluentValue;
+use fluent_templates::Loader;
+use kasuki_macros::slash_command;
+use sea_orm::EntityTrait;
+use serenity::all::{
+	ButtonStyle, CommandInteraction, Context as SerenityContext, CreateActionRow, CreateButton,
+	CreateComponent,
+};
+use shared::database::prelude::RegisteredUser;
+use shared::localization::USABLE_LOCALES;
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::command::command::CommandRun;
+use crate::command::context::CommandContext;
+use crate::command::embed_content::{
+	ComponentVersion, ComponentVersion2, EmbedContent, EmbedsContents,
+};
+use crate::components::anilist::verify::VERIFY_BUTTON;
+use crate::structure::run::anilist::user::get_user_url;
+
+#[slash_command(
+	name = "verify", desc = "Prove you own your AniList account by logging in on AniList.", command_type = ChatInput,
+	contexts = [Guild, BotDm, PrivateChannel],
+	install_contexts = [Guild, User],
+)]
+async fn verify_command(self_: VerifyCommand) -> Result<EmbedsContents<'_>> {
+	let cx = CommandContext::new(
+		self_.get_ctx().clone(),
+		self_.get_command_interaction().clone(),
+	);
+	let lang_id = cx.lang_id().await;
+	let title = USABLE_LOCALES.lookup(&lang_id, "anilist_user_verify-title");
+
+	if cx.bot_data.config.api.anilist.is_none() {
+		let embed_content = EmbedContent::new(title)
+			.description(USABLE_LOCALES.lookup(&lang_id, "anilist_user_verify-disabled"));
+
+		return Ok(EmbedsContents::new(vec![embed_content]));
+	}
+
+	let mut lines = vec![USABLE_LOCALES.lookup(&lang_id, "anilist_user_verify-desc")];
+
+	let registered = RegisteredUser::find_by_id(cx.command_interaction.user.id.to_string())
+		.one(&*cx.db)
+		.await?;
+	if let Some(registered) = registered.filter(|row| row.verified) {
+		let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+		args.insert(
+			Cow::Borrowed("url"),
+			FluentValue::from(get_user_url(&registered.anilist_id)),
+		);
+		lines.push(USABLE_LOCALES.lookup_with_args(&lang_id, "anilist_user_verify-already", &args));
+	}
+
+	let button = CreateButton::new(VERIFY_BUTTON)
+		.label(USABLE_LOCALES.lookup(&lang_id, "anilist_user_verify-button"))
+		.style(ButtonStyle::Primary);
+
+	let embed_content = EmbedContent::new(title).description(lines.join("\n\n"));
+
+	Ok(
+		EmbedsContents::new(vec![embed_content]).action_row(ComponentVersion::V2(
+			ComponentVersion2 {
+				components: Cow::Owned(vec![CreateComponent::ActionRow(CreateActionRow::Buttons(
+					Cow::Owned(vec![button]),
+				))]),
+			},
+		)),
+	)
+}
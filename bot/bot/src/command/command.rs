@@ -109,6 +109,10 @@ impl<T: Command> CommandRun for T {
 		let mut builder = CreateInteractionResponseFollowup::new().files(files);
 		if has_embed {
 			builder = builder.embeds(embeds);
+			// Classic action rows (e.g. pagination buttons) can sit under embeds
+			if let Some(component) = component {
+				builder = builder.components(component);
+			}
 		} else if let Some(component) = component {
 			builder = builder
 				.components(component)
@@ -1,6 +1,8 @@
+use crate::achievement::{track, Stat};
 use crate::command::guess_kind::guess_command_kind;
 use crate::command::registry::{get_message_registry, get_slash_registry, get_user_registry};
 use crate::event_handler::BotData;
+use crate::handlers::user_db::add_user_data_to_db;
 use anyhow::{Context as AnyhowContext, Result};
 use serenity::all::{CommandInteraction, Context as SerenityContext};
 use std::time::Instant;
@@ -61,6 +63,25 @@ pub async fn dispatch_command(
 		)
 		.await;
 
+	// command_usage has no foreign key, achievement progress does
+	if add_user_data_to_db(
+		command_interaction.user.clone(),
+		bot_data.db_connection.clone(),
+	)
+	.await
+	.is_ok()
+	{
+		track(
+			ctx,
+			command_interaction.user.id,
+			command_interaction.guild_id,
+			Some(command_interaction.channel_id),
+			Stat::CommandsRun,
+			1,
+		)
+		.await;
+	}
+
 	info!("Command {} executed successfully", full_command_name);
 	Ok(())
 }
@@ -0,0 +1,154 @@
+use crate::command::command::CommandRun;
+use crate::command::embed_content::{CommandFiles, CreateFooter, EmbedContent, EmbedsContents};
+use crate::command::user::avatar::get_user_command;
+use crate::event_handler::BotData;
+use crate::helper::get_option::subcommand::{
+	get_option_map_boolean_subcommand, get_option_map_string_subcommand,
+};
+use crate::leveling::card::render_heatmap;
+use crate::leveling::heatmap::{load_heatmap, HeatmapPeriod};
+use crate::leveling::leaderboard::{guild_accent, user_accent};
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::helper::get_guild_timezone::get_guild_timezone;
+use shared::localization::{get_language_identifier, Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use tracing::{debug, info};
+
+#[slash_command(
+	name = "heatmap", desc = "Show when a member or the server is active.",
+	command_type = SubCommand(parent = "levels"),
+	contexts = [Guild],
+	install_contexts = [Guild],
+	args = [
+		(name = "username", desc = "The member to show the activity of.", arg_type = User, required = false, autocomplete = false),
+		(name = "server", desc = "Show the activity of the whole server instead.", arg_type = Boolean, required = false, autocomplete = false),
+		(name = "period", desc = "How far back to look.", arg_type = String, required = false, autocomplete = false,
+			choices = [(name = "week"), (name = "month"), (name = "quarter"), (name = "year")])
+	],
+)]
+async fn levels_heatmap_command(self_: LevelsHeatmapCommand) -> Result<EmbedsContents<'_>> {
+	info!("Processing levels heatmap command");
+	let ctx = self_.get_ctx();
+	let bot_data = ctx.data::<BotData>().clone();
+	let command_interaction = self_.get_command_interaction();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = command_interaction
+		.guild_id
+		.ok_or(anyhow!("Heatmaps are only available in servers"))?;
+
+	let whole_server = get_option_map_boolean_subcommand(command_interaction)
+		.get("server")
+		.copied()
+		.unwrap_or(false);
+	let period = get_option_map_string_subcommand(command_interaction)
+		.get("period")
+		.map(|period| HeatmapPeriod::from_choice(period))
+		.unwrap_or(HeatmapPeriod::Month);
+
+	let channel_ids: Vec<String> = guild_id
+		.channels(&ctx.http)
+		.await?
+		.iter()
+		.map(|channel| channel.id.to_string())
+		.collect();
+	let timezone = get_guild_timezone(guild_id.to_string(), db_connection.clone()).await;
+
+	let (user, accent) = if whole_server {
+		(None, guild_accent())
+	} else {
+		let user = get_user_command(ctx, command_interaction).await?;
+		let accent = user_accent(&bot_data, &user.id.to_string()).await?;
+		(Some(user), accent)
+	};
+	let user_id = user.as_ref().map(|user| user.id.to_string());
+
+	let retention_days = bot_data.config.message_tracking.retention_days;
+	let heatmap = load_heatmap(
+		&db_connection,
+		user_id.as_deref(),
+		channel_ids,
+		period,
+		retention_days,
+		&timezone,
+	)
+	.await?;
+	debug!(
+		"Heatmap for {:?} over {} days: {} messages, {} seconds in voice",
+		user_id,
+		period.days(),
+		heatmap.total_messages(),
+		heatmap.total_voice_secs()
+	);
+
+	let lang_id = get_language_identifier(guild_id.to_string(), db_connection).await;
+
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(Cow::Borrowed("days"), FluentValue::from(period.days()));
+	args.insert(
+		Cow::Borrowed("messages"),
+		FluentValue::from(heatmap.total_messages()),
+	);
+	args.insert(
+		Cow::Borrowed("hours"),
+		FluentValue::from(heatmap.total_voice_secs() / 3600),
+	);
+	args.insert(
+		Cow::Borrowed("minutes"),
+		FluentValue::from((heatmap.total_voice_secs() % 3600) / 60),
+	);
+	args.insert(
+		Cow::Borrowed("timezone"),
+		FluentValue::from(timezone.name()),
+	);
+
+	let title = match &user {
+		Some(user) => {
+			args.insert(
+				Cow::Borrowed("user"),
+				FluentValue::from(user.name.to_string()),
+			);
+			USABLE_LOCALES.lookup_with_args(&lang_id, "levels_heatmap-title_user", &args)
+		},
+		None => USABLE_LOCALES.lookup(&lang_id, "levels_heatmap-title_server"),
+	};
+
+	let image = tokio::task::spawn_blocking(move || render_heatmap(&heatmap, accent))
+		.await
+		.map_err(|e| anyhow!("Heatmap render task failed: {}", e))??;
+
+	let filename = format!("heatmap_{}.png", user_id.as_deref().unwrap_or("server"));
+	let mut description = USABLE_LOCALES.lookup_with_args(&lang_id, "levels_heatmap-desc", &args);
+	// Older messages are purged, the grid would silently miss them
+	let message_days = period.message_days(retention_days);
+	if message_days < period.days() {
+		args.insert(
+			Cow::Borrowed("message_days"),
+			FluentValue::from(message_days),
+		);
+		description.push('\n');
+		description.push_str(&USABLE_LOCALES.lookup_with_args(
+			&lang_id,
+			"levels_heatmap-message_retention",
+			&args,
+		));
+	}
+
+	let embed_content = EmbedContent::new(title)
+		.description(description)
+		.images_url(format!("attachment://{}", filename))
+		.footer(CreateFooter::new(USABLE_LOCALES.lookup_with_args(
+			&lang_id,
+			"levels_heatmap-footer",
+			&args,
+		)));
+
+	let mut embed_contents = EmbedsContents::new(vec![embed_content]);
+	embed_contents.add_files(vec![CommandFiles::new(filename, image)]);
+
+	Ok(embed_contents)
+}
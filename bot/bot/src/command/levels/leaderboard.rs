@@ -0,0 +1,85 @@
+use crate::command::command::CommandRun;
+use crate::command::embed_content::{
+	CommandFiles, ComponentVersion, ComponentVersion2, CreateFooter, EmbedContent, EmbedsContents,
+};
+use crate::components::levels::leaderboard::{
+	leaderboard_buttons, leaderboard_description, leaderboard_filename, leaderboard_footer,
+};
+use crate::components::pagination::page_index;
+use crate::event_handler::BotData;
+use crate::helper::get_option::subcommand::get_option_map_integer_subcommand;
+use crate::leveling::curve::LevelCurve;
+use crate::leveling::get_level_config;
+use crate::leveling::leaderboard::leaderboard_page;
+use anyhow::{anyhow, Result};
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::localization::{get_language_identifier, Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use tracing::{debug, info};
+
+#[slash_command(
+	name = "leaderboard", desc = "Show the most active members of the server.",
+	command_type = SubCommand(parent = "levels"),
+	contexts = [Guild],
+	install_contexts = [Guild],
+	args = [(name = "page", desc = "The page to start on.", arg_type = Integer, required = false, autocomplete = false)],
+)]
+async fn levels_leaderboard_command(self_: LevelsLeaderboardCommand) -> Result<EmbedsContents<'_>> {
+	info!("Processing levels leaderboard command");
+	let ctx = self_.get_ctx();
+	let bot_data = ctx.data::<BotData>().clone();
+	let command_interaction = self_.get_command_interaction();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = command_interaction
+		.guild_id
+		.ok_or(anyhow!("Leaderboards are only available in servers"))?
+		.to_string();
+
+	let page = page_index(
+		get_option_map_integer_subcommand(command_interaction)
+			.get("page")
+			.copied(),
+	);
+
+	let config = get_level_config(guild_id.clone(), &db_connection).await?;
+	let curve = LevelCurve::from(&config);
+	let viewer_id = command_interaction.user.id.to_string();
+	let leaderboard = leaderboard_page(&bot_data, &guild_id, &viewer_id, page, &curve).await?;
+	debug!(
+		"Leaderboard page {}/{} with {} entries",
+		leaderboard.page + 1,
+		leaderboard.total_pages,
+		leaderboard.entries.len()
+	);
+
+	let lang_id = get_language_identifier(guild_id, db_connection).await;
+	let filename = leaderboard_filename(leaderboard.page);
+
+	let embed_content =
+		EmbedContent::new(USABLE_LOCALES.lookup(&lang_id, "levels_leaderboard-title"))
+			.description(leaderboard_description(&leaderboard.entries, &lang_id))
+			.images_url(format!("attachment://{}", filename))
+			.footer(CreateFooter::new(leaderboard_footer(
+				leaderboard.page,
+				leaderboard.total_pages,
+				&lang_id,
+			)));
+
+	let mut embed_contents = EmbedsContents::new(vec![embed_content]).action_row(
+		ComponentVersion::V2(ComponentVersion2 {
+			components: Cow::Owned(leaderboard_buttons(
+				leaderboard.page,
+				leaderboard.total_pages,
+				&lang_id,
+			)),
+		}),
+	);
+	embed_contents.add_files(vec![CommandFiles::new(
+		filename,
+		leaderboard.image.as_ref().clone(),
+	)]);
+
+	Ok(embed_contents)
+}
@@ -1 +1,4 @@
+pub mod heatmap;
+pub mod leaderboard;
+pub mod rank;
 pub mod stats;
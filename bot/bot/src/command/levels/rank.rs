@@ -0,0 +1,69 @@
+use crate::command::command::CommandRun;
+use crate::command::embed_content::{CommandFiles, EmbedContent, EmbedsContents};
+use crate::command::user::avatar::get_user_command;
+use crate::event_handler::BotData;
+use crate::leveling::curve::LevelCurve;
+use crate::leveling::get_level_config;
+use crate::leveling::leaderboard::rank_card;
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::localization::{get_language_identifier, Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use tracing::{debug, info};
+
+#[slash_command(
+	name = "rank", desc = "Show the rank card of a member.",
+	command_type = SubCommand(parent = "levels"),
+	contexts = [Guild],
+	install_contexts = [Guild],
+	args = [(name = "username", desc = "The member to show the rank card of.", arg_type = User, required = false, autocomplete = false)],
+)]
+async fn levels_rank_command(self_: LevelsRankCommand) -> Result<EmbedsContents<'_>> {
+	info!("Processing levels rank command");
+	let ctx = self_.get_ctx();
+	let bot_data = ctx.data::<BotData>().clone();
+	let command_interaction = self_.get_command_interaction();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = command_interaction
+		.guild_id
+		.ok_or(anyhow!("Rank cards are only available in servers"))?
+		.to_string();
+	let user = get_user_command(ctx, command_interaction).await?;
+
+	let config = get_level_config(guild_id.clone(), &db_connection).await?;
+	let curve = LevelCurve::from(&config);
+	let card = rank_card(&bot_data, &guild_id, &user, &curve).await?;
+	debug!(
+		"Rank card for {}: level {}, xp {}, rank {}",
+		user.id, card.level, card.xp, card.rank
+	);
+
+	let lang_id = get_language_identifier(guild_id, db_connection).await;
+
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("user"),
+		FluentValue::from(user.name.to_string()),
+	);
+	args.insert(Cow::Borrowed("rank"), FluentValue::from(card.rank));
+	args.insert(Cow::Borrowed("level"), FluentValue::from(card.level));
+	args.insert(Cow::Borrowed("xp"), FluentValue::from(card.xp));
+
+	let filename = format!("rank_{}.png", user.id);
+	let embed_content =
+		EmbedContent::new(USABLE_LOCALES.lookup_with_args(&lang_id, "levels_rank-title", &args))
+			.description(USABLE_LOCALES.lookup_with_args(&lang_id, "levels_rank-desc", &args))
+			.images_url(format!("attachment://{}", filename));
+
+	let mut embed_contents = EmbedsContents::new(vec![embed_content]);
+	embed_contents.add_files(vec![CommandFiles::new(
+		filename,
+		card.image.as_ref().clone(),
+	)]);
+
+	Ok(embed_contents)
+}
@@ -3,16 +3,16 @@ use crate::command::embed_content::{CommandFiles, EmbedContent, EmbedsContents};
 use crate::constant::COLOR;
 use crate::event_handler::BotData;
 use crate::helper::progress_bar_generator::generate_progress_bar_image_in_memory;
+use crate::leveling::activity::user_activity;
+use crate::leveling::curve::LevelCurve;
+use crate::leveling::get_level_config;
 use anyhow::{anyhow, Result};
 use fluent_templates::fluent_bundle::FluentValue;
 use kasuki_macros::slash_command;
 use sea_orm::EntityTrait;
-use sea_orm::QueryFilter;
-use sea_orm::{ColumnTrait, Condition};
 use serenity::all::{ChannelId, CommandInteraction, Context as SerenityContext};
 use serenity::model::Colour;
-use shared::database::prelude::{Message as DatabaseMessage, Vocal as DatabaseVocal};
-use shared::database::{message, vocal};
+use shared::database::prelude::UserLevel;
 use shared::localization::{get_language_identifier, Loader, USABLE_LOCALES};
 use std::borrow::Cow;
 use std::collections::HashMap;
@@ -49,41 +49,19 @@ async fn levels_stats_command(self_: LevelsStatsCommand) -> Result<EmbedsContent
 		vec_channel_id.len()
 	);
 
-	debug!("Creating message query condition");
-	let condition = Condition::all()
-		.add(message::Column::UserId.eq(user_id.clone()))
-		.add(message::Column::ChannelId.is_in(vec_string.clone()));
-
 	let db_connection = bot_data.db_connection.clone();
 
-	debug!("Querying database for user messages");
-	let messages = DatabaseMessage::find()
-		.filter(condition)
-		.all(&*db_connection)
-		.await?;
-
-	let total_message = messages.len() as i128;
-	debug!("Found {} messages for user", total_message);
-
-	debug!("Creating vocal query condition");
-	let condition = Condition::all()
-		.add(vocal::Column::UserId.eq(user_id))
-		.add(vocal::Column::ChannelId.is_in(vec_string));
+	debug!("Aggregating user activity");
+	let activity = user_activity(&db_connection, &user_id, vec_string).await?;
 
-	debug!("Querying database for user vocal sessions");
-	let vocals = DatabaseVocal::find()
-		.filter(condition)
-		.all(&*db_connection)
-		.await?;
-
-	let total_vocal = vocals.len() as i128;
-	debug!("Found {} vocal sessions for user", total_vocal);
-
-	let mut total_vocal_len: i128 = 0;
-	for vocal in vocals {
-		total_vocal_len += vocal.duration as i128;
-	}
-	debug!("Total vocal duration: {} seconds", total_vocal_len);
+	let total_message = activity.messages;
+	let total_vocal = activity.vocal_sessions;
+	let total_vocal_len = activity.vocal_secs;
+	let total_vocal_idle = activity.vocal_idle_secs;
+	debug!(
+		"Found {} messages and {} vocal sessions for user, {} seconds in voice, {} idle",
+		total_message, total_vocal, total_vocal_len, total_vocal_idle
+	);
 
 	// Convert seconds to hours, minutes, seconds
 	let hours = total_vocal_len / 3600;
@@ -91,29 +69,30 @@ async fn levels_stats_command(self_: LevelsStatsCommand) -> Result<EmbedsContent
 	let seconds = total_vocal_len % 60;
 	debug!("Formatted vocal time: {}h {}m {}s", hours, minutes, seconds);
 
-	// Calculate XP components
-	debug!("Calculating XP components");
-	let xp_message = total_message;
-	let xp_vocal = total_vocal;
-	let xp_vocal_len = total_vocal_len / 10;
+	// Active time leaves out muted, deafened and AFK time
+	let total_vocal_active = total_vocal_len - total_vocal_idle;
+	let active_hours = total_vocal_active / 3600;
+	let active_minutes = (total_vocal_active % 3600) / 60;
+	let active_seconds = total_vocal_active % 60;
+
+	debug!("Loading stored XP for user");
+	let guild_id = command_interaction.guild_id.unwrap().to_string();
+	let config = get_level_config(guild_id.clone(), &db_connection).await?;
+	let curve = LevelCurve::from(&config);
+	let user_level = UserLevel::find_by_id((guild_id, command_interaction.user.id.to_string()))
+		.one(&*db_connection)
+		.await?;
+	let (xp, xp_message, xp_vocal, xp_minigame) = user_level
+		.map(|row| (row.xp, row.message_xp, row.voice_xp, row.minigame_xp))
+		.unwrap_or_default();
+
+	let level = curve.level_for_xp(xp);
+	let (xp_progress, xp_needed) = curve.progress(xp);
 	debug!(
-		"XP from messages: {}, XP from vocal sessions: {}, XP from vocal duration: {}",
-		xp_message, xp_vocal, xp_vocal_len
+		"Total XP: {}, Current level: {}, progress: {}/{}",
+		xp, level, xp_progress, xp_needed
 	);
 
-	// Calculate total XP and level
-	let xp = xp_vocal_len + xp_message + xp_vocal;
-	let level = get_level(xp);
-	debug!("Total XP: {}, Current level: {}", xp, level);
-
-	// Calculate level progression
-	debug!("Calculating level progression");
-	let current_level_xp = get_xp_for_level(level);
-	let next_level_xp = get_xp_for_next_level(level);
-	let xp_progress = xp - current_level_xp;
-	let xp_needed = next_level_xp - current_level_xp;
-	debug!("XP progress: {}/{} for next level", xp_progress, xp_needed);
-
 	// Create progress bar with user color
 	debug!("Creating progress bar with user color");
 	let user_color = command_interaction.user.accent_colour;
@@ -167,6 +146,21 @@ async fn levels_stats_command(self_: LevelsStatsCommand) -> Result<EmbedsContent
 		FluentValue::from(seconds.to_string()),
 	);
 
+	// Build arguments for vocal_active
+	let mut vocal_active_args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	vocal_active_args.insert(
+		Cow::Borrowed("hours"),
+		FluentValue::from(active_hours.to_string()),
+	);
+	vocal_active_args.insert(
+		Cow::Borrowed("minutes"),
+		FluentValue::from(active_minutes.to_string()),
+	);
+	vocal_active_args.insert(
+		Cow::Borrowed("seconds"),
+		FluentValue::from(active_seconds.to_string()),
+	);
+
 	// Build arguments for message
 	let mut message_args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
 	message_args.insert(
@@ -184,10 +178,10 @@ async fn levels_stats_command(self_: LevelsStatsCommand) -> Result<EmbedsContent
 	let mut xp_vocal_args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
 	xp_vocal_args.insert(Cow::Borrowed("xp"), FluentValue::from(xp_vocal.to_string()));
 
-	let mut xp_vocal_len_args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
-	xp_vocal_len_args.insert(
+	let mut xp_minigame_args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	xp_minigame_args.insert(
 		Cow::Borrowed("xp"),
-		FluentValue::from(xp_vocal_len.to_string()),
+		FluentValue::from(xp_minigame.to_string()),
 	);
 
 	let mut xp_total_args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
@@ -236,6 +230,15 @@ async fn levels_stats_command(self_: LevelsStatsCommand) -> Result<EmbedsContent
 				String::new(),
 				true,
 			),
+			(
+				USABLE_LOCALES.lookup_with_args(
+					&lang_id,
+					"levels_stats-vocal_active",
+					&vocal_active_args,
+				),
+				String::new(),
+				true,
+			),
 			// Message Activity Section
 			(
 				USABLE_LOCALES.lookup(&lang_id, "levels_stats-message_title"),
@@ -270,8 +273,8 @@ async fn levels_stats_command(self_: LevelsStatsCommand) -> Result<EmbedsContent
 			(
 				USABLE_LOCALES.lookup_with_args(
 					&lang_id,
-					"levels_stats-xp_vocal_len",
-					&xp_vocal_len_args,
+					"levels_stats-xp_minigame",
+					&xp_minigame_args,
 				),
 				String::new(),
 				true,
@@ -292,84 +295,8 @@ async fn levels_stats_command(self_: LevelsStatsCommand) -> Result<EmbedsContent
 	Ok(embed_contents)
 }
 
-fn get_level(xp: i128) -> i32 {
-	match xp {
-		0..=500 => 1,
-		501..=1500 => 2,
-		1501..=3000 => 3,
-		3001..=5000 => 4,
-		5001..=8000 => 5,
-		8001..=12000 => 6,
-		12001..=17000 => 7,
-		17001..=23000 => 8,
-		23001..=30000 => 9,
-		30001..=38000 => 10,
-		38001..=47000 => 11,
-		47001..=57000 => 12,
-		57001..=68000 => 13,
-		68001..=80000 => 14,
-		80001..=93000 => 15,
-		93001..=107000 => 16,
-		107001..=122000 => 17,
-		122001..=138000 => 18,
-		138001..=155000 => 19,
-		_ => 20,
-	}
-}
-
-fn get_xp_for_level(level: i32) -> i128 {
-	match level {
-		1 => 0,
-		2 => 501,
-		3 => 1501,
-		4 => 3001,
-		5 => 5001,
-		6 => 8001,
-		7 => 12001,
-		8 => 17001,
-		9 => 23001,
-		10 => 30001,
-		11 => 38001,
-		12 => 47001,
-		13 => 57001,
-		14 => 68001,
-		15 => 80001,
-		16 => 93001,
-		17 => 107001,
-		18 => 122001,
-		19 => 138001,
-		20 => 155001,
-		_ => 155001, // Cap at level 20
-	}
-}
-
-fn get_xp_for_next_level(level: i32) -> i128 {
-	match level {
-		1 => 501,
-		2 => 1501,
-		3 => 3001,
-		4 => 5001,
-		5 => 8001,
-		6 => 12001,
-		7 => 17001,
-		8 => 23001,
-		9 => 30001,
-		10 => 38001,
-		11 => 47001,
-		12 => 57001,
-		13 => 68001,
-		14 => 80001,
-		15 => 93001,
-		16 => 107001,
-		17 => 122001,
-		18 => 138001,
-		19 => 155001,
-		_ => 999999, // No next level after 20
-	}
-}
-
 async fn create_progress_bar(
-	current: i128, max: i128, user_color: Option<Colour>,
+	current: i64, max: i64, user_color: Option<Colour>,
 ) -> Result<(CommandFiles, i32)> {
 	// Calculate percentage
 	let percent = if max > 0 {
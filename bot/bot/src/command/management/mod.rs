@@ -1,3 +1,4 @@
 pub mod give_premium_sub;
 pub mod kill_switch;
+pub mod reload_items;
 pub mod remove_test_sub;
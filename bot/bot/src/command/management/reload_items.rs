@@ -0,0 +1,86 @@
+//! The `reload_items` owner command re-runs the item catalog sync without a
+//! restart and shows the resulting diff, or every validation error when the
+//! catalog was rejected.
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::event_handler::BotData;
+use crate::helper::load_items::load_items_from_json;
+use fluent_templates::fluent_bundle::FluentValue;
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::localization::{get_language_identifier, Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use tracing::warn;
+
+/// Room left in the description once the summary line is in.
+const DIFF_LIMIT: usize = 3500;
+
+#[slash_command(
+	name = "reload_items", desc = "Validate and sync the item catalog.",
+	command_type = GuildChatInput { guild_id = 1117152661620408531 },
+	permissions = [Administrator],
+)]
+async fn reload_items_command(self_: ReloadItemsCommand) -> Result<EmbedsContents<'_>> {
+	let ctx = self_.get_ctx();
+	let command_interaction = self_.get_command_interaction();
+	let bot_data = ctx.data::<BotData>().clone();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = match command_interaction.guild_id {
+		Some(id) => id.to_string(),
+		None => String::from("0"),
+	};
+	let lang_id = get_language_identifier(guild_id, db_connection.clone()).await;
+
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	let description = match load_items_from_json(&db_connection).await {
+		Ok(sync) if sync.changes.is_empty() => {
+			args.insert(Cow::Borrowed("version"), FluentValue::from(sync.version));
+			USABLE_LOCALES.lookup_with_args(&lang_id, "management_reload_items-up_to_date", &args)
+		},
+		Ok(sync) => {
+			args.insert(Cow::Borrowed("version"), FluentValue::from(sync.version));
+			args.insert(Cow::Borrowed("added"), FluentValue::from(sync.added()));
+			args.insert(Cow::Borrowed("updated"), FluentValue::from(sync.updated()));
+			args.insert(Cow::Borrowed("retired"), FluentValue::from(sync.retired()));
+			args.insert(
+				Cow::Borrowed("restored"),
+				FluentValue::from(sync.restored()),
+			);
+
+			format!(
+				"{}\n```diff\n{}\n```",
+				USABLE_LOCALES.lookup_with_args(&lang_id, "management_reload_items-synced", &args),
+				truncate(sync.diff_lines())
+			)
+		},
+		Err(e) => {
+			warn!(error = %e, "Item catalog reload rejected");
+
+			format!(
+				"{}\n```\n{}\n```",
+				USABLE_LOCALES.lookup(&lang_id, "management_reload_items-failed"),
+				truncate(format!("{:#}", e))
+			)
+		},
+	};
+
+	let embed_content =
+		EmbedContent::new(USABLE_LOCALES.lookup(&lang_id, "management_reload_items-title"))
+			.description(description);
+
+	Ok(EmbedsContents::new(vec![embed_content]))
+}
+
+fn truncate(mut text: String) -> String {
+	if text.len() > DIFF_LIMIT {
+		let mut end = DIFF_LIMIT;
+		while !text.is_char_boundary(end) {
+			end -= 1;
+		}
+		text.truncate(end);
+		text.push_str("\n…");
+	}
+
+	text
+}
@@ -0,0 +1,102 @@
+use crate::command::command::CommandRun;
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::command::music::join::join;
+use crate::components::minigame::anisong_quiz::{quiz_title, run_quiz};
+use crate::event_handler::BotData;
+use crate::helper::get_option::subcommand::get_option_map_integer_subcommand;
+use crate::minigame::anisong_quiz::{running_quiz, start_quiz, DEFAULT_ROUNDS, MAX_ROUNDS};
+use crate::minigame::{minigame_enabled, module_disabled_embed};
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::localization::{get_language_identifier, Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+#[slash_command(
+	name = "anisong_quiz", desc = "Start a quiz naming the anime of songs played in your voice channel.",
+	command_type = SubCommand(parent = "minigame"),
+	contexts = [Guild],
+	install_contexts = [Guild],
+	args = [(name = "rounds", desc = "Number of songs to play, 5 by default and 10 at most.", arg_type = Integer, required = false, autocomplete = false)],
+)]
+async fn anisong_quiz_command(self_: AnisongQuizCommand) -> Result<EmbedsContents<'_>> {
+	let ctx = self_.get_ctx().clone();
+	let bot_data = ctx.data::<BotData>().clone();
+	let command_interaction = self_.get_command_interaction().clone();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = command_interaction
+		.guild_id
+		.ok_or(anyhow!("The anisong quiz is only available in servers"))?;
+
+	let lang_id = get_language_identifier(guild_id.to_string(), db_connection.clone()).await;
+	if !minigame_enabled(&guild_id.to_string(), db_connection.clone()).await {
+		return Ok(module_disabled_embed(&lang_id));
+	}
+
+	if running_quiz(&*db_connection, &guild_id.to_string())
+		.await?
+		.is_some()
+	{
+		let embed_content = EmbedContent::new(quiz_title(&lang_id))
+			.description(USABLE_LOCALES.lookup(&lang_id, "minigame_anisong_quiz-already_running"));
+
+		return Ok(EmbedsContents::new(vec![embed_content]));
+	}
+
+	let rounds = get_option_map_integer_subcommand(&command_interaction)
+		.get("rounds")
+		.map(|rounds| (*rounds).clamp(1, MAX_ROUNDS as i64) as i32)
+		.unwrap_or(DEFAULT_ROUNDS);
+
+	let lava_client = bot_data
+		.lavalink
+		.read()
+		.await
+		.clone()
+		.ok_or(anyhow!("Lavalink is disabled"))?;
+	let (joined_now, join_embed) =
+		join(ctx.clone(), bot_data.clone(), command_interaction.clone()).await?;
+
+	let Some(player) =
+		lava_client.get_player_context(lavalink_rs::model::GuildId::from(guild_id.get()))
+	else {
+		// Not in a voice channel, join explains why
+		return Ok(join_embed);
+	};
+
+	// The quiz takes over the player, it must not cut someone's music
+	if !joined_now && player.get_player().await?.track.is_some() {
+		let embed_content = EmbedContent::new(quiz_title(&lang_id))
+			.description(USABLE_LOCALES.lookup(&lang_id, "minigame_anisong_quiz-music_playing"));
+
+		return Ok(EmbedsContents::new(vec![embed_content]));
+	}
+
+	let quiz = start_quiz(
+		&*db_connection,
+		&guild_id.to_string(),
+		&command_interaction.channel_id.to_string(),
+		&command_interaction.user.id.to_string(),
+		rounds,
+	)
+	.await?;
+
+	tokio::spawn(run_quiz(
+		ctx.clone(),
+		bot_data.clone(),
+		quiz,
+		lang_id.clone(),
+		joined_now,
+	));
+
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(Cow::Borrowed("rounds"), FluentValue::from(rounds));
+	let embed_content = EmbedContent::new(quiz_title(&lang_id)).description(
+		USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_anisong_quiz-starting", &args),
+	);
+
+	Ok(EmbedsContents::new(vec![embed_content]))
+}
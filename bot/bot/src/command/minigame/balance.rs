@@ -0,0 +1,80 @@
+use crate::command::command::CommandRun;
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::event_handler::BotData;
+use crate::minigame::economy::{get_balance, recent_ledger};
+use crate::minigame::{minigame_enabled, module_disabled_embed};
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::localization::{get_language_identifier, Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// Ledger entries listed under the balance.
+const RECENT_ENTRIES: u64 = 5;
+
+#[slash_command(
+	name = "balance", desc = "Check your coins in this server.",
+	command_type = SubCommand(parent = "minigame"),
+	contexts = [Guild],
+	install_contexts = [Guild],
+)]
+async fn balance_command(self_: BalanceCommand) -> Result<EmbedsContents<'_>> {
+	let ctx = self_.get_ctx();
+	let bot_data = ctx.data::<BotData>().clone();
+	let command_interaction = self_.get_command_interaction();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = command_interaction
+		.guild_id
+		.ok_or(anyhow!("Wallets only exist in servers"))?
+		.to_string();
+	let user_id = command_interaction.user.id.to_string();
+
+	let lang_id = get_language_identifier(guild_id.clone(), db_connection.clone()).await;
+	if !minigame_enabled(&guild_id, db_connection.clone()).await {
+		return Ok(module_disabled_embed(&lang_id));
+	}
+
+	let balance = get_balance(&*db_connection, &guild_id, &user_id).await?;
+	let entries = recent_ledger(&*db_connection, &guild_id, &user_id, RECENT_ENTRIES).await?;
+
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(Cow::Borrowed("balance"), FluentValue::from(balance));
+
+	let history = if entries.is_empty() {
+		USABLE_LOCALES.lookup(&lang_id, "minigame_balance-no_history")
+	} else {
+		entries
+			.iter()
+			.map(|entry| {
+				let reason = USABLE_LOCALES
+					.try_lookup(
+						&lang_id,
+						&format!("minigame_balance-reason_{}", entry.reason),
+					)
+					.unwrap_or_else(|| entry.reason.clone());
+
+				format!(
+					"`{:+}` {} <t:{}:R>",
+					entry.amount,
+					reason,
+					entry.created_at.and_utc().timestamp()
+				)
+			})
+			.collect::<Vec<_>>()
+			.join("\n")
+	};
+
+	let embed_content =
+		EmbedContent::new(USABLE_LOCALES.lookup(&lang_id, "minigame_balance-title"))
+			.description(USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_balance-desc", &args))
+			.fields(vec![(
+				USABLE_LOCALES.lookup(&lang_id, "minigame_balance-history"),
+				history,
+				false,
+			)]);
+
+	Ok(EmbedsContents::new(vec![embed_content]))
+}
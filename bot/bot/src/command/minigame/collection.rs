@@ -0,0 +1,72 @@
+use crate::command::command::CommandRun;
+use crate::command::embed_content::{
+	ComponentVersion, ComponentVersion2, CreateFooter, EmbedContent, EmbedsContents,
+};
+use crate::components::minigame::collection::{
+	collection_components, collection_description, collection_footer, collection_title,
+};
+use crate::components::pagination::page_index;
+use crate::event_handler::BotData;
+use crate::helper::get_option::subcommand::{
+	get_option_map_integer_subcommand, get_option_map_user_subcommand,
+};
+use crate::minigame::gacha::collection_page;
+use crate::minigame::{minigame_enabled, module_disabled_embed};
+use anyhow::{anyhow, Result};
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::localization::get_language_identifier;
+use std::borrow::Cow;
+
+#[slash_command(
+	name = "collection", desc = "Show the characters a member has rolled.",
+	command_type = SubCommand(parent = "minigame"),
+	contexts = [Guild],
+	install_contexts = [Guild],
+	args = [
+		(name = "user", desc = "The member whose collection to show, you by default.", arg_type = User, required = false, autocomplete = false),
+		(name = "page", desc = "The page to start on.", arg_type = Integer, required = false, autocomplete = false)
+	],
+)]
+async fn collection_command(self_: CollectionCommand) -> Result<EmbedsContents<'_>> {
+	let ctx = self_.get_ctx();
+	let bot_data = ctx.data::<BotData>().clone();
+	let command_interaction = self_.get_command_interaction();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = command_interaction
+		.guild_id
+		.ok_or(anyhow!("Collections are only available in servers"))?
+		.to_string();
+
+	let lang_id = get_language_identifier(guild_id.clone(), db_connection.clone()).await;
+	if !minigame_enabled(&guild_id, db_connection.clone()).await {
+		return Ok(module_disabled_embed(&lang_id));
+	}
+
+	let owner = match get_option_map_user_subcommand(command_interaction).get("user") {
+		Some(user) => user.to_user(&ctx.http).await?,
+		None => command_interaction.user.clone(),
+	};
+	let owner_id = owner.id.to_string();
+
+	let page = page_index(
+		get_option_map_integer_subcommand(command_interaction)
+			.get("page")
+			.copied(),
+	);
+
+	let collection = collection_page(&*db_connection, &guild_id, &owner_id, page).await?;
+
+	let embed_content = EmbedContent::new(collection_title(&owner.name, &lang_id))
+		.description(collection_description(&collection, &lang_id))
+		.footer(CreateFooter::new(collection_footer(&collection, &lang_id)));
+
+	Ok(
+		EmbedsContents::new(vec![embed_content]).action_row(ComponentVersion::V2(
+			ComponentVersion2 {
+				components: Cow::Owned(collection_components(&owner_id, &collection, &lang_id)),
+			},
+		)),
+	)
+}
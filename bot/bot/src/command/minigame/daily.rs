@@ -0,0 +1,155 @@
+use crate::achievement::{track, Stat};
+use crate::command::command::CommandRun;
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::event_handler::BotData;
+use crate::handlers::user_db::add_user_data_to_db;
+use crate::minigame::daily::{claim_daily, DailyOutcome, StreakKind};
+use crate::minigame::{minigame_enabled, module_disabled_embed, rarity_stars};
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use fluent_templates::fluent_bundle::FluentValue;
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::helper::get_guild_timezone::get_guild_timezone;
+use shared::localization::{get_language_identifier, Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+#[slash_command(
+	name = "daily", desc = "Claim your daily reward and keep your streak going.",
+	command_type = SubCommand(parent = "minigame"),
+	contexts = [Guild],
+	install_contexts = [Guild],
+)]
+async fn daily_command(self_: DailyCommand) -> Result<EmbedsContents<'_>> {
+	let ctx = self_.get_ctx();
+	let bot_data = ctx.data::<BotData>().clone();
+	let command_interaction = self_.get_command_interaction();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = command_interaction
+		.guild_id
+		.ok_or(anyhow!("Daily rewards are only available in servers"))?
+		.to_string();
+	let user_id = command_interaction.user.id.to_string();
+
+	let lang_id = get_language_identifier(guild_id.clone(), db_connection.clone()).await;
+	if !minigame_enabled(&guild_id, db_connection.clone()).await {
+		return Ok(module_disabled_embed(&lang_id));
+	}
+
+	// daily_streak, wallet and user_inventory reference user_data
+	add_user_data_to_db(command_interaction.user.clone(), db_connection.clone()).await?;
+
+	let timezone = get_guild_timezone(guild_id.clone(), db_connection.clone()).await;
+	let title = USABLE_LOCALES.lookup(&lang_id, "minigame_daily-title");
+
+	let claim = match claim_daily(&db_connection, &guild_id, &user_id, &timezone).await? {
+		DailyOutcome::Claimed(claim) => claim,
+		DailyOutcome::AlreadyClaimed { streak, next_reset } => {
+			let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+			args.insert(
+				Cow::Borrowed("next"),
+				FluentValue::from(relative(next_reset)),
+			);
+			args.insert(Cow::Borrowed("streak"), FluentValue::from(streak));
+			let embed_content = EmbedContent::new(title).description(
+				USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_daily-already_claimed", &args),
+			);
+
+			return Ok(EmbedsContents::new(vec![embed_content]));
+		},
+	};
+
+	track(
+		ctx,
+		command_interaction.user.id,
+		command_interaction.guild_id,
+		Some(command_interaction.channel_id),
+		Stat::DailyStreak,
+		i64::from(claim.streak),
+	)
+	.await;
+
+	let mut lines = Vec::new();
+
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(Cow::Borrowed("coins"), FluentValue::from(claim.tier.coins));
+	args.insert(Cow::Borrowed("balance"), FluentValue::from(claim.balance));
+	lines.push(USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_daily-coins", &args));
+
+	for reward in &claim.items {
+		let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+		args.insert(
+			Cow::Borrowed("name"),
+			FluentValue::from(reward.item.name.clone()),
+		);
+		args.insert(
+			Cow::Borrowed("rarity"),
+			FluentValue::from(rarity_stars(reward.rarity)),
+		);
+		lines.push(USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_daily-item", &args));
+	}
+
+	let mut streak_args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	streak_args.insert(Cow::Borrowed("streak"), FluentValue::from(claim.streak));
+	streak_args.insert(Cow::Borrowed("best"), FluentValue::from(claim.best_streak));
+	let mut streak_lines =
+		vec![USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_daily-streak", &streak_args)];
+	match claim.kind {
+		StreakKind::Grace => {
+			streak_lines.push(USABLE_LOCALES.lookup(&lang_id, "minigame_daily-streak_grace"))
+		},
+		StreakKind::Broken { previous } if previous > 1 => {
+			let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+			args.insert(Cow::Borrowed("previous"), FluentValue::from(previous));
+			streak_lines.push(USABLE_LOCALES.lookup_with_args(
+				&lang_id,
+				"minigame_daily-streak_lost",
+				&args,
+			));
+		},
+		_ => {},
+	}
+	if let Some(next_tier) = claim.next_tier {
+		let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+		args.insert(Cow::Borrowed("day"), FluentValue::from(next_tier.streak));
+		args.insert(Cow::Borrowed("coins"), FluentValue::from(next_tier.coins));
+		streak_lines.push(USABLE_LOCALES.lookup_with_args(
+			&lang_id,
+			"minigame_daily-next_tier",
+			&args,
+		));
+	}
+
+	let mut next_args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	next_args.insert(
+		Cow::Borrowed("next"),
+		FluentValue::from(relative(claim.next_reset)),
+	);
+	next_args.insert(
+		Cow::Borrowed("deadline"),
+		FluentValue::from(relative(claim.deadline)),
+	);
+
+	let embed_content = EmbedContent::new(title)
+		.description(lines.join("\n"))
+		.fields(vec![
+			(
+				USABLE_LOCALES.lookup(&lang_id, "minigame_daily-streak_field"),
+				streak_lines.join("\n"),
+				false,
+			),
+			(
+				USABLE_LOCALES.lookup(&lang_id, "minigame_daily-next_field"),
+				USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_daily-next", &next_args),
+				false,
+			),
+		]);
+
+	Ok(EmbedsContents::new(vec![embed_content]))
+}
+
+fn relative(time: DateTime<Utc>) -> String {
+	format!("<t:{}:R>", time.timestamp())
+}
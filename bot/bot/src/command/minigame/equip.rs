@@ -0,0 +1,116 @@
+use crate::command::command::CommandRun;
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::event_handler::BotData;
+use crate::handlers::user_db::add_user_data_to_db;
+use crate::helper::get_option::subcommand::get_option_map_string_subcommand;
+use crate::minigame::fishing::{load_equipped, set_equipped, EquipSelection, ItemProperties};
+use crate::minigame::{minigame_enabled, module_disabled_embed};
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use kasuki_macros::slash_command;
+use sea_orm::EntityTrait;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::database::prelude::Item;
+use shared::localization::{get_language_identifier, Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+#[slash_command(
+	name = "equip", desc = "Equip a fishing rod or bait.",
+	command_type = SubCommand(parent = "minigame"),
+	contexts = [Guild],
+	install_contexts = [Guild],
+	args = [(name = "gear", desc = "The rod or bait to equip.", arg_type = String, required = true, autocomplete = true)],
+)]
+async fn equip_command(self_: EquipCommand) -> Result<EmbedsContents<'_>> {
+	let ctx = self_.get_ctx();
+	let bot_data = ctx.data::<BotData>().clone();
+	let command_interaction = self_.get_command_interaction();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = command_interaction
+		.guild_id
+		.ok_or(anyhow!("Gear is only available in servers"))?
+		.to_string();
+	let user_id = command_interaction.user.id.to_string();
+
+	let lang_id = get_language_identifier(guild_id.clone(), db_connection.clone()).await;
+	if !minigame_enabled(&guild_id, db_connection.clone()).await {
+		return Ok(module_disabled_embed(&lang_id));
+	}
+
+	let selection = get_option_map_string_subcommand(command_interaction)
+		.get("gear")
+		.map(|value| EquipSelection::parse(value))
+		.ok_or(anyhow!("No option for gear"))?;
+
+	// fishing_state references user_data
+	add_user_data_to_db(command_interaction.user.clone(), db_connection.clone()).await?;
+
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+
+	let description = match selection {
+		EquipSelection::Unequip(slot) => {
+			set_equipped(&*db_connection, &guild_id, &user_id, slot, None).await?;
+
+			args.insert(
+				Cow::Borrowed("slot"),
+				FluentValue::from(
+					USABLE_LOCALES
+						.lookup(&lang_id, &format!("minigame_equip-slot_{}", slot.as_str())),
+				),
+			);
+			USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_equip-unequipped", &args)
+		},
+		EquipSelection::Equip(item_id) => {
+			let gear = Item::find_by_id(item_id.clone())
+				.one(&*db_connection)
+				.await?
+				.and_then(|item| ItemProperties::of(&item).gear);
+
+			let equipped = match gear {
+				Some(gear) => {
+					load_equipped(&*db_connection, &guild_id, &user_id, gear.slot, &item_id).await?
+				},
+				None => None,
+			};
+
+			match equipped {
+				Some(equipped) => {
+					set_equipped(
+						&*db_connection,
+						&guild_id,
+						&user_id,
+						equipped.gear.slot,
+						Some(item_id),
+					)
+					.await?;
+
+					args.insert(
+						Cow::Borrowed("name"),
+						FluentValue::from(equipped.item.name.clone()),
+					);
+					args.insert(
+						Cow::Borrowed("slot"),
+						FluentValue::from(USABLE_LOCALES.lookup(
+							&lang_id,
+							&format!("minigame_equip-slot_{}", equipped.gear.slot.as_str()),
+						)),
+					);
+					args.insert(
+						Cow::Borrowed("uses"),
+						FluentValue::from(equipped.uses_left()),
+					);
+					args.insert(Cow::Borrowed("owned"), FluentValue::from(equipped.owned));
+					USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_equip-equipped", &args)
+				},
+				None => USABLE_LOCALES.lookup(&lang_id, "minigame_equip-not_owned"),
+			}
+		},
+	};
+
+	let embed_content = EmbedContent::new(USABLE_LOCALES.lookup(&lang_id, "minigame_equip-title"))
+		.description(description);
+
+	Ok(EmbedsContents::new(vec![embed_content]))
+}
@@ -1,15 +1,31 @@
+use crate::achievement::{track, Stat};
 use crate::command::command::CommandRun;
 use crate::command::embed_content::{EmbedContent, EmbedsContents};
 use crate::event_handler::BotData;
-use anyhow::{Context as AnyhowContext, Result};
+use crate::handlers::user_db::add_user_data_to_db;
+use crate::leveling::curve::LevelCurve;
+use crate::leveling::{add_xp, get_level_config, handle_level_up, XpSource};
+use crate::minigame::fishing::{
+	catch_weights, catch_xp, claim_cast, cooldown_secs, load_loadout, rarity_range, wear,
+	CastConditions, Gear,
+};
+use crate::minigame::leaderboard::{fishing_points, record_score, MinigameType};
+use crate::minigame::{minigame_enabled, module_disabled_embed};
+use anyhow::{anyhow, Context as AnyhowContext, Result};
+use chrono::Utc;
 use fluent_templates::fluent_bundle::FluentValue;
 use kasuki_macros::slash_command;
 use rand::distr::weighted::WeightedIndex;
 use rand::prelude::*;
-use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set};
+use sea_orm::{
+	ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+	Set, TransactionTrait,
+};
 use serenity::all::{CommandInteraction, Context as SerenityContext};
 use shared::database::item::{Entity as Item, Model as ItemModel};
 use shared::database::user_inventory::ActiveModel as UserInventoryActiveModel;
+use shared::helper::get_guild_timezone::get_guild_timezone;
+use shared::helper::get_module_state::{is_module_enabled, GuildModule};
 use shared::localization::{get_language_identifier, Loader, USABLE_LOCALES};
 use std::borrow::Cow;
 use std::collections::HashMap;
@@ -18,7 +34,7 @@ use tracing::{debug, info};
 #[slash_command(
 	name = "fishing", desc = "Go fishing!",
 	command_type = SubCommand(parent = "minigame"),
-	contexts = [Guild, PrivateChannel],
+	contexts = [Guild],
 	install_contexts = [Guild],
 )]
 async fn fishing_command(self_: FishingCommand) -> Result<EmbedsContents<'_>> {
@@ -27,35 +43,70 @@ async fn fishing_command(self_: FishingCommand) -> Result<EmbedsContents<'_>> {
 	let command_interaction = self_.get_command_interaction();
 
 	let user_id = command_interaction.user.id.to_string();
-	let server_id = command_interaction.guild_id.unwrap().to_string();
+	let server_id = command_interaction
+		.guild_id
+		.ok_or(anyhow!("Fishing is only available in servers"))?
+		.to_string();
 
-	// Get all fish items from the database
 	let db_connection = bot_data.db_connection.clone();
 
 	// Load localization data
 	let lang_id = get_language_identifier(server_id.clone(), db_connection.clone()).await;
+	if !minigame_enabled(&server_id, db_connection.clone()).await {
+		return Ok(module_disabled_embed(&lang_id));
+	}
+
+	// fishing_state and user_inventory reference user_data
+	add_user_data_to_db(command_interaction.user.clone(), db_connection.clone()).await?;
+
+	let loadout = load_loadout(&*db_connection, &server_id, &user_id).await?;
+
+	let cooldown = cooldown_secs(loadout.rod.as_ref().map(|rod| &rod.gear));
+	if let Some(next_cast) = claim_cast(&*db_connection, &server_id, &user_id, cooldown).await? {
+		let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+		args.insert(
+			Cow::Borrowed("time"),
+			FluentValue::from(format!("<t:{}:R>", next_cast.and_utc().timestamp())),
+		);
+
+		let embed_content =
+			EmbedContent::new(USABLE_LOCALES.lookup(&lang_id, "minigame_fishing-title"))
+				.description(USABLE_LOCALES.lookup_with_args(
+					&lang_id,
+					"minigame_fishing-cooldown",
+					&args,
+				));
+
+		return Ok(EmbedsContents::new(vec![embed_content]));
+	}
 
 	let fish_items = get_fish_items(&db_connection).await?;
 
 	if fish_items.is_empty() {
-		return Err(anyhow::anyhow!("No fish items found in the database"));
+		return Err(anyhow!("No fish items found in the database"));
 	}
 
-	// Generate a random fish based on weights
-	let caught_fish = catch_random_fish(&fish_items)?;
+	let timezone = get_guild_timezone(server_id.clone(), db_connection.clone()).await;
+	let conditions = CastConditions::at(Utc::now(), &timezone);
+	let equipped = loadout.equipped();
+	let gear: Vec<&Gear> = equipped.iter().map(|equipped| &equipped.gear).collect();
+
+	// Generate a random fish based on weights, time, season and gear
+	let caught_fish = catch_random_fish(&fish_items, conditions, &gear)?;
 
 	// Generate a random size for the fish (between 1 and 100)
 	let fish_size = rand::rng().random_range(1..=100);
 
-	// Generate a random rarity for the fish (between minimum_rarity and maximum_rarity)
-	let fish_rarity =
-		rand::rng().random_range(caught_fish.minimum_rarity..=caught_fish.maximum_rarity);
+	// Generate a random rarity for the fish, gear can raise the lower bound
+	let fish_rarity = rand::rng().random_range(rarity_range(caught_fish, &gear));
+
+	// The catch and the wear of the gear used for it land together
+	let txn = db_connection.begin().await?;
 
-	// Add the fish to the user's inventory
 	add_fish_to_inventory(
-		&db_connection,
-		user_id,
-		server_id,
+		&txn,
+		user_id.clone(),
+		server_id.clone(),
 		caught_fish.item_id.clone(),
 		fish_size,
 		fish_rarity,
@@ -63,6 +114,57 @@ async fn fishing_command(self_: FishingCommand) -> Result<EmbedsContents<'_>> {
 	)
 	.await?;
 
+	let points = fishing_points(fish_rarity, fish_size);
+	record_score(&txn, &server_id, &user_id, MinigameType::Fishing, points).await?;
+
+	let mut gear_lines = Vec::new();
+	for equipped in &equipped {
+		let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+		args.insert(
+			Cow::Borrowed("name"),
+			FluentValue::from(equipped.item.name.clone()),
+		);
+
+		if wear(&txn, equipped).await? {
+			gear_lines.push(USABLE_LOCALES.lookup_with_args(
+				&lang_id,
+				"minigame_fishing-gear_broken",
+				&args,
+			));
+		} else {
+			args.insert(
+				Cow::Borrowed("uses"),
+				FluentValue::from(equipped.uses_left() - 1),
+			);
+			gear_lines.push(USABLE_LOCALES.lookup_with_args(
+				&lang_id,
+				"minigame_fishing-gear_entry",
+				&args,
+			));
+		}
+	}
+
+	txn.commit().await?;
+
+	track(
+		ctx,
+		command_interaction.user.id,
+		command_interaction.guild_id,
+		Some(command_interaction.channel_id),
+		Stat::FishCaught,
+		1,
+	)
+	.await;
+
+	let xp = grant_catch_xp(
+		ctx,
+		command_interaction,
+		&server_id,
+		fish_rarity,
+		caught_fish.base_xp_boost,
+	)
+	.await?;
+
 	// Create a message to display the caught fish
 	let rarity_text = match fish_rarity {
 		1 => USABLE_LOCALES.lookup(&lang_id, "minigame_fishing-common"),
@@ -110,7 +212,29 @@ async fn fishing_command(self_: FishingCommand) -> Result<EmbedsContents<'_>> {
 	let fish_details =
 		USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_fishing-fish_details_format", &args);
 
-	let embed_content =
+	let mut conditions_args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	conditions_args.insert(
+		Cow::Borrowed("time_of_day"),
+		FluentValue::from(USABLE_LOCALES.lookup(
+			&lang_id,
+			&format!("minigame_fishing-time_{}", conditions.time_of_day.as_str()),
+		)),
+	);
+	conditions_args.insert(
+		Cow::Borrowed("season"),
+		FluentValue::from(USABLE_LOCALES.lookup(
+			&lang_id,
+			&format!("minigame_fishing-season_{}", conditions.season.as_str()),
+		)),
+	);
+
+	let gear_text = if gear_lines.is_empty() {
+		USABLE_LOCALES.lookup(&lang_id, "minigame_fishing-no_gear")
+	} else {
+		gear_lines.join("\n")
+	};
+
+	let mut embed_content =
 		EmbedContent::new(USABLE_LOCALES.lookup(&lang_id, "minigame_fishing-title")).fields(vec![
 			(
 				USABLE_LOCALES.lookup(&lang_id, "minigame_fishing-caught_fish"),
@@ -127,19 +251,99 @@ async fn fishing_command(self_: FishingCommand) -> Result<EmbedsContents<'_>> {
 				caught_fish.description.clone(),
 				false,
 			),
+			(
+				USABLE_LOCALES.lookup(&lang_id, "minigame_fishing-conditions_field"),
+				USABLE_LOCALES.lookup_with_args(
+					&lang_id,
+					"minigame_fishing-conditions",
+					&conditions_args,
+				),
+				true,
+			),
+			(
+				USABLE_LOCALES.lookup(&lang_id, "minigame_fishing-gear_field"),
+				gear_text,
+				true,
+			),
 		]);
 
+	let mut points_args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	points_args.insert(Cow::Borrowed("points"), FluentValue::from(points));
+	let mut description =
+		vec![USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_fishing-points", &points_args)];
+
+	if let Some(xp) = xp {
+		let mut xp_args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+		xp_args.insert(Cow::Borrowed("xp"), FluentValue::from(xp));
+		description.push(USABLE_LOCALES.lookup_with_args(
+			&lang_id,
+			"minigame_fishing-xp_gained",
+			&xp_args,
+		));
+	}
+	embed_content = embed_content.description(description.join("\n"));
+
 	let embeds_contents = EmbedsContents::new(vec![embed_content]);
 
 	Ok(embeds_contents)
 }
 
+/// Credit the leveling XP of a catch when the guild has leveling enabled,
+/// returning the amount credited.
+async fn grant_catch_xp(
+	ctx: &SerenityContext, command_interaction: &CommandInteraction, guild_id: &str, rarity: i32,
+	xp_boost: f32,
+) -> Result<Option<i64>> {
+	let bot_data = ctx.data::<BotData>().clone();
+	let db_connection = bot_data.db_connection.clone();
+
+	if !is_module_enabled(
+		guild_id.to_string(),
+		GuildModule::Level,
+		db_connection.clone(),
+	)
+	.await
+	{
+		return Ok(None);
+	}
+
+	let config = get_level_config(guild_id.to_string(), &db_connection).await?;
+	let curve = LevelCurve::from(&config);
+	let xp = catch_xp(config.message_xp, rarity, xp_boost);
+
+	let level_up = add_xp(
+		&db_connection,
+		guild_id,
+		&command_interaction.user.id.to_string(),
+		XpSource::Minigame,
+		xp,
+		None,
+		&curve,
+	)
+	.await?;
+
+	if let (Some(level_up), Some(guild)) = (level_up, command_interaction.guild_id) {
+		handle_level_up(
+			ctx,
+			guild,
+			command_interaction.user.id,
+			&config,
+			level_up,
+			Some(command_interaction.channel_id),
+		)
+		.await?;
+	}
+
+	Ok((xp > 0).then_some(xp))
+}
+
 /// Get all fish items from the database
 async fn get_fish_items(db: &DatabaseConnection) -> Result<Vec<ItemModel>> {
 	debug!("Getting all fish items from the database");
 
 	let fish_items = Item::find()
 		.filter(shared::database::item::Column::Type.eq("fish"))
+		.filter(shared::database::item::Column::RetiredAt.is_null())
 		.all(db)
 		.await
 		.context("Failed to get fish items from database")?;
@@ -150,12 +354,17 @@ async fn get_fish_items(db: &DatabaseConnection) -> Result<Vec<ItemModel>> {
 }
 
 /// Catch a random fish based on weights
-fn catch_random_fish(fish_items: &[ItemModel]) -> Result<&ItemModel> {
+fn catch_random_fish<'a>(
+	fish_items: &'a [ItemModel], conditions: CastConditions, gear: &[&Gear],
+) -> Result<&'a ItemModel> {
 	debug!("Generating random fish based on weights");
 
-	// Create a weighted distribution based on the weights of the fish
-	let weights: Vec<i32> = fish_items.iter().map(|item| item.weight).collect();
-	let dist = WeightedIndex::new(&weights).context("Failed to create weighted distribution")?;
+	// Create a weighted distribution based on the weights of the fish, falling
+	// back to the plain weights if the catch tables rule every fish out
+	let weights = catch_weights(fish_items, conditions, gear);
+	let dist = WeightedIndex::new(&weights)
+		.or_else(|_| WeightedIndex::new(fish_items.iter().map(|item| item.weight.max(0) as f64)))
+		.context("Failed to create weighted distribution")?;
 
 	// Generate a random index based on the weights
 	let mut rng = rand::rng();
@@ -167,9 +376,9 @@ fn catch_random_fish(fish_items: &[ItemModel]) -> Result<&ItemModel> {
 }
 
 /// Add a fish to the user's inventory
-async fn add_fish_to_inventory(
-	db: &DatabaseConnection, user_id: String, server_id: String, item_id: String, size: i32,
-	rarity: i32, item_xp_boost: f32,
+async fn add_fish_to_inventory<C: ConnectionTrait>(
+	db: &C, user_id: String, server_id: String, item_id: String, size: i32, rarity: i32,
+	item_xp_boost: f32,
 ) -> Result<()> {
 	debug!(
 		"Adding fish to user's inventory: user_id={}, server_id={}, item_id={}",
@@ -185,6 +394,7 @@ async fn add_fish_to_inventory(
 		size: Set(size),
 		rarity: Set(rarity),
 		item_xp_boost: Set(item_xp_boost),
+		durability: Set(None),
 	};
 
 	// Insert the item into the database
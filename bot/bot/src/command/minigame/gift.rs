@@ -0,0 +1,126 @@
+use crate::command::command::CommandRun;
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::event_handler::BotData;
+use crate::handlers::user_db::add_user_data_to_db;
+use crate::helper::get_option::subcommand::{
+	get_option_map_integer_subcommand, get_option_map_string_subcommand,
+	get_option_map_user_subcommand,
+};
+use crate::minigame::economy::SaleSelection;
+use crate::minigame::trade::{gift, GiftOutcome};
+use crate::minigame::{minigame_enabled, module_disabled_embed};
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::localization::{get_language_identifier, Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+#[slash_command(
+	name = "gift", desc = "Give items or coins to another member.",
+	command_type = SubCommand(parent = "minigame"),
+	contexts = [Guild],
+	install_contexts = [Guild],
+	args = [
+		(name = "user", desc = "The member to give to.", arg_type = User, required = true, autocomplete = false),
+		(name = "item", desc = "The item to give.", arg_type = String, required = false, autocomplete = true),
+		(name = "coins", desc = "How many coins to give.", arg_type = Integer, required = false, autocomplete = false)
+	],
+)]
+async fn gift_command(self_: GiftCommand) -> Result<EmbedsContents<'_>> {
+	let ctx = self_.get_ctx();
+	let bot_data = ctx.data::<BotData>().clone();
+	let command_interaction = self_.get_command_interaction();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = command_interaction
+		.guild_id
+		.ok_or(anyhow!("Gifting is only available in servers"))?
+		.to_string();
+	let user_id = command_interaction.user.id.to_string();
+
+	let lang_id = get_language_identifier(guild_id.clone(), db_connection.clone()).await;
+	if !minigame_enabled(&guild_id, db_connection.clone()).await {
+		return Ok(module_disabled_embed(&lang_id));
+	}
+
+	let recipient = get_option_map_user_subcommand(command_interaction)
+		.get("user")
+		.ok_or(anyhow!("No option for user"))?
+		.to_user(&ctx.http)
+		.await?;
+	let selection = get_option_map_string_subcommand(command_interaction)
+		.get("item")
+		.filter(|value| !value.is_empty())
+		.map(|value| SaleSelection::parse(value));
+	let coins = get_option_map_integer_subcommand(command_interaction)
+		.get("coins")
+		.copied()
+		.unwrap_or(0);
+
+	let title = USABLE_LOCALES.lookup(&lang_id, "minigame_gift-title");
+	let refusal = if recipient.id == command_interaction.user.id || recipient.bot() {
+		Some("minigame_gift-invalid_recipient")
+	} else if selection.is_none() && coins <= 0 {
+		Some("minigame_gift-nothing_chosen")
+	} else if coins < 0 {
+		Some("minigame_gift-invalid_coins")
+	} else {
+		None
+	};
+	if let Some(refusal) = refusal {
+		let embed_content =
+			EmbedContent::new(title).description(USABLE_LOCALES.lookup(&lang_id, refusal));
+
+		return Ok(EmbedsContents::new(vec![embed_content]));
+	}
+
+	// wallets and inventories reference user_data for both sides
+	add_user_data_to_db(command_interaction.user.clone(), db_connection.clone()).await?;
+	add_user_data_to_db(recipient.clone(), db_connection.clone()).await?;
+
+	let outcome = gift(
+		&db_connection,
+		&guild_id,
+		&user_id,
+		&recipient.id.to_string(),
+		selection,
+		coins,
+	)
+	.await?;
+
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("user"),
+		FluentValue::from(format!("<@{}>", recipient.id)),
+	);
+	let description = match outcome {
+		GiftOutcome::Given {
+			item_name,
+			count,
+			coins,
+		} => {
+			let mut lines = Vec::new();
+			if let Some(item_name) = item_name {
+				args.insert(Cow::Borrowed("name"), FluentValue::from(item_name));
+				args.insert(Cow::Borrowed("count"), FluentValue::from(count));
+				lines.push(USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_gift-items", &args));
+			}
+			if coins > 0 {
+				args.insert(Cow::Borrowed("coins"), FluentValue::from(coins));
+				lines.push(USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_gift-coins", &args));
+			}
+			lines.join("\n")
+		},
+		GiftOutcome::NothingGiven => USABLE_LOCALES.lookup(&lang_id, "minigame_gift-nothing"),
+		GiftOutcome::InsufficientFunds { balance } => {
+			args.insert(Cow::Borrowed("balance"), FluentValue::from(balance));
+			USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_gift-insufficient_funds", &args)
+		},
+	};
+
+	let embed_content = EmbedContent::new(title).description(description);
+
+	Ok(EmbedsContents::new(vec![embed_content]))
+}
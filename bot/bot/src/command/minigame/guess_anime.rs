@@ -0,0 +1,93 @@
+use crate::command::command::CommandRun;
+use crate::command::embed_content::{
+	CommandFiles, ComponentVersion, ComponentVersion2, EmbedContent, EmbedsContents,
+};
+use crate::components::minigame::guess_anime::{
+	round_buttons, round_description, round_filename, round_title,
+};
+use crate::event_handler::BotData;
+use crate::helper::get_option::subcommand::get_option_map_string_subcommand;
+use crate::minigame::guess_anime::{
+	fetch_image, open_round, pick_media, render_stage, start_round, Difficulty,
+};
+use crate::minigame::{minigame_enabled, module_disabled_embed};
+use anyhow::{anyhow, Result};
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::localization::{get_language_identifier, Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+
+#[slash_command(
+	name = "guess_anime", desc = "Start a round of guess the anime from its cover.",
+	command_type = SubCommand(parent = "minigame"),
+	contexts = [Guild],
+	install_contexts = [Guild],
+	args = [(name = "difficulty", desc = "How well known the anime is, normal by default.", arg_type = String, required = false, autocomplete = false,
+		choices = [(name = "easy"), (name = "normal"), (name = "hard")])],
+)]
+async fn guess_anime_command(self_: GuessAnimeCommand) -> Result<EmbedsContents<'_>> {
+	let ctx = self_.get_ctx();
+	let bot_data = ctx.data::<BotData>().clone();
+	let command_interaction = self_.get_command_interaction();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = command_interaction
+		.guild_id
+		.ok_or(anyhow!("Guess the anime is only available in servers"))?
+		.to_string();
+	let channel_id = command_interaction.channel_id.to_string();
+
+	let lang_id = get_language_identifier(guild_id.clone(), db_connection.clone()).await;
+	if !minigame_enabled(&guild_id, db_connection.clone()).await {
+		return Ok(module_disabled_embed(&lang_id));
+	}
+
+	if open_round(&*db_connection, &channel_id).await?.is_some() {
+		let embed_content = EmbedContent::new(round_title(&lang_id))
+			.description(USABLE_LOCALES.lookup(&lang_id, "minigame_guess_anime-already_running"));
+
+		return Ok(EmbedsContents::new(vec![embed_content]));
+	}
+
+	let difficulty = get_option_map_string_subcommand(command_interaction)
+		.get("difficulty")
+		.and_then(|difficulty| Difficulty::parse(difficulty))
+		.unwrap_or(Difficulty::Normal);
+
+	let Some(media) = pick_media(difficulty, bot_data.anilist_cache.clone()).await? else {
+		let embed_content = EmbedContent::new(round_title(&lang_id))
+			.description(USABLE_LOCALES.lookup(&lang_id, "minigame_guess_anime-no_anime"));
+
+		return Ok(EmbedsContents::new(vec![embed_content]));
+	};
+
+	let round = start_round(
+		&*db_connection,
+		&guild_id,
+		&channel_id,
+		&command_interaction.user.id.to_string(),
+		difficulty,
+		&media,
+	)
+	.await?;
+
+	let source = fetch_image(&bot_data.http_client, &round.image_url).await?;
+	let round_id = round.id.clone();
+	let image = tokio::task::spawn_blocking(move || render_stage(&source, 0, &round_id))
+		.await
+		.map_err(|e| anyhow!("spawn_blocking panicked: {}", e))??;
+
+	let filename = round_filename(&round);
+	let embed_content = EmbedContent::new(round_title(&lang_id))
+		.description(round_description(&round, &lang_id))
+		.images_url(format!("attachment://{}", filename));
+
+	let mut embed_contents = EmbedsContents::new(vec![embed_content]).action_row(
+		ComponentVersion::V2(ComponentVersion2 {
+			components: Cow::Owned(round_buttons(&round, &lang_id)),
+		}),
+	);
+	embed_contents.add_files(vec![CommandFiles::new(filename, image)]);
+
+	Ok(embed_contents)
+}
@@ -0,0 +1,100 @@
+use crate::command::command::CommandRun;
+use crate::command::embed_content::{
+	ComponentVersion, ComponentVersion2, CreateFooter, EmbedContent, EmbedsContents,
+};
+use crate::components::minigame::leaderboard::{
+	leaderboard_buttons, leaderboard_description, leaderboard_footer, leaderboard_title,
+};
+use crate::components::pagination::page_index;
+use crate::event_handler::BotData;
+use crate::helper::get_option::subcommand::{
+	get_option_map_integer_subcommand, get_option_map_string_subcommand,
+};
+use crate::minigame::leaderboard::{score_page, LeaderboardView, MinigameType, Scope, Window};
+use crate::minigame::{minigame_enabled, module_disabled_embed};
+use anyhow::{anyhow, Result};
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::localization::get_language_identifier;
+use std::borrow::Cow;
+use tracing::{debug, info};
+
+#[slash_command(
+	name = "leaderboard", desc = "Show the best minigame players.",
+	command_type = SubCommand(parent = "minigame"),
+	contexts = [Guild],
+	install_contexts = [Guild],
+	args = [(name = "game", desc = "The minigame to rank, all of them by default.", arg_type = String, required = false, autocomplete = false,
+		choices = [(name = "all"), (name = "fishing"), (name = "gacha"), (name = "guess_anime"), (name = "anisong_quiz"), (name = "daily")]),
+		(name = "scope", desc = "This server or every server.", arg_type = String, required = false, autocomplete = false,
+		choices = [(name = "server"), (name = "global")]),
+		(name = "window", desc = "This week or all time.", arg_type = String, required = false, autocomplete = false,
+		choices = [(name = "weekly"), (name = "all_time")]),
+		(name = "page", desc = "The page to start on.", arg_type = Integer, required = false, autocomplete = false)],
+)]
+async fn leaderboard_command(self_: LeaderboardCommand) -> Result<EmbedsContents<'_>> {
+	info!("Processing minigame leaderboard command");
+	let ctx = self_.get_ctx();
+	let bot_data = ctx.data::<BotData>().clone();
+	let command_interaction = self_.get_command_interaction();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = command_interaction
+		.guild_id
+		.ok_or(anyhow!("Leaderboards are only available in servers"))?
+		.to_string();
+
+	let lang_id = get_language_identifier(guild_id.clone(), db_connection.clone()).await;
+	if !minigame_enabled(&guild_id, db_connection.clone()).await {
+		return Ok(module_disabled_embed(&lang_id));
+	}
+
+	let map = get_option_map_string_subcommand(command_interaction);
+	let view = LeaderboardView {
+		game: map.get("game").and_then(|game| MinigameType::parse(game)),
+		scope: map
+			.get("scope")
+			.and_then(|scope| Scope::parse(scope))
+			.unwrap_or(Scope::Server),
+		window: map
+			.get("window")
+			.and_then(|window| Window::parse(window))
+			.unwrap_or(Window::AllTime),
+	};
+
+	let page = page_index(
+		get_option_map_integer_subcommand(command_interaction)
+			.get("page")
+			.copied(),
+	);
+
+	let viewer_id = command_interaction.user.id.to_string();
+	let leaderboard = score_page(&*db_connection, view, &guild_id, &viewer_id, page).await?;
+	debug!(
+		"Minigame leaderboard {} page {}/{} with {} entries",
+		view.encode(),
+		leaderboard.page + 1,
+		leaderboard.total_pages,
+		leaderboard.entries.len()
+	);
+
+	let embed_content = EmbedContent::new(leaderboard_title(view, &lang_id))
+		.description(leaderboard_description(&leaderboard, &viewer_id, &lang_id))
+		.footer(CreateFooter::new(leaderboard_footer(
+			&leaderboard,
+			&lang_id,
+		)));
+
+	Ok(
+		EmbedsContents::new(vec![embed_content]).action_row(ComponentVersion::V2(
+			ComponentVersion2 {
+				components: Cow::Owned(leaderboard_buttons(
+					view,
+					leaderboard.page,
+					leaderboard.total_pages,
+					&lang_id,
+				)),
+			},
+		)),
+	)
+}
@@ -1,3 +1,15 @@
+pub mod anisong_quiz;
+pub mod balance;
+pub mod collection;
+pub mod daily;
+pub mod equip;
 pub mod fish_inventory;
 pub mod fishing;
+pub mod gift;
+pub mod guess_anime;
 pub mod inventory;
+pub mod leaderboard;
+pub mod roll;
+pub mod sell;
+pub mod shop;
+pub mod trade;
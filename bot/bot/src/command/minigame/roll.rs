@@ -0,0 +1,119 @@
+use crate::command::command::CommandRun;
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::components::minigame::collection::{card_fields, card_title};
+use crate::event_handler::BotData;
+use crate::handlers::user_db::add_user_data_to_db;
+use crate::minigame::gacha::{roll, RollOutcome, RollPayment, FREE_ROLL_COOLDOWN_SECS, ROLL_COST};
+use crate::minigame::{minigame_enabled, module_disabled_embed};
+use anyhow::{anyhow, Result};
+use chrono::Duration;
+use fluent_templates::fluent_bundle::FluentValue;
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::localization::{get_language_identifier, Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+#[slash_command(
+	name = "roll", desc = "Roll a random AniList character for your collection.",
+	command_type = SubCommand(parent = "minigame"),
+	contexts = [Guild],
+	install_contexts = [Guild],
+)]
+async fn roll_command(self_: RollCommand) -> Result<EmbedsContents<'_>> {
+	let ctx = self_.get_ctx();
+	let bot_data = ctx.data::<BotData>().clone();
+	let command_interaction = self_.get_command_interaction();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = command_interaction
+		.guild_id
+		.ok_or(anyhow!("Rolling is only available in servers"))?
+		.to_string();
+	let user_id = command_interaction.user.id.to_string();
+
+	let lang_id = get_language_identifier(guild_id.clone(), db_connection.clone()).await;
+	if !minigame_enabled(&guild_id, db_connection.clone()).await {
+		return Ok(module_disabled_embed(&lang_id));
+	}
+
+	// gacha_state and user_character reference user_data
+	add_user_data_to_db(command_interaction.user.clone(), db_connection.clone()).await?;
+
+	let title = USABLE_LOCALES.lookup(&lang_id, "minigame_gacha-roll_title");
+	let (card, character, payment, duplicate) =
+		match roll(&db_connection, &guild_id, &user_id).await? {
+			RollOutcome::Rolled {
+				card,
+				character,
+				payment,
+				duplicate,
+			} => (card, character, payment, duplicate),
+			RollOutcome::PoolEmpty => {
+				let embed_content = EmbedContent::new(title)
+					.description(USABLE_LOCALES.lookup(&lang_id, "minigame_gacha-pool_empty"));
+
+				return Ok(EmbedsContents::new(vec![embed_content]));
+			},
+			RollOutcome::InsufficientFunds { balance, next_free } => {
+				let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+				args.insert(
+					Cow::Borrowed("next"),
+					FluentValue::from(format!("<t:{}:R>", next_free.and_utc().timestamp())),
+				);
+				args.insert(Cow::Borrowed("cost"), FluentValue::from(ROLL_COST));
+				args.insert(Cow::Borrowed("balance"), FluentValue::from(balance));
+				let embed_content =
+					EmbedContent::new(title).description(USABLE_LOCALES.lookup_with_args(
+						&lang_id,
+						"minigame_gacha-insufficient_funds",
+						&args,
+					));
+
+				return Ok(EmbedsContents::new(vec![embed_content]));
+			},
+		};
+
+	let mut lines = Vec::new();
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	match payment {
+		RollPayment::Free => {
+			let next_free = card.obtained_at + Duration::seconds(FREE_ROLL_COOLDOWN_SECS);
+			args.insert(
+				Cow::Borrowed("next"),
+				FluentValue::from(format!("<t:{}:R>", next_free.and_utc().timestamp())),
+			);
+			lines.push(USABLE_LOCALES.lookup_with_args(
+				&lang_id,
+				"minigame_gacha-free_roll",
+				&args,
+			));
+		},
+		RollPayment::Paid(cost) => {
+			args.insert(Cow::Borrowed("cost"), FluentValue::from(cost));
+			lines.push(USABLE_LOCALES.lookup_with_args(
+				&lang_id,
+				"minigame_gacha-paid_roll",
+				&args,
+			));
+		},
+	}
+	lines.push(USABLE_LOCALES.lookup(
+		&lang_id,
+		if duplicate {
+			"minigame_gacha-duplicate"
+		} else {
+			"minigame_gacha-new_card"
+		},
+	));
+
+	let mut embed_content = EmbedContent::new(card_title(&character))
+		.description(lines.join("\n"))
+		.url(character.site_url.clone())
+		.fields(card_fields(&character, &lang_id));
+	if let Some(image) = character.image_url.clone() {
+		embed_content = embed_content.images_url(image);
+	}
+
+	Ok(EmbedsContents::new(vec![embed_content]))
+}
@@ -0,0 +1,71 @@
+use crate::command::command::CommandRun;
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::event_handler::BotData;
+use crate::handlers::user_db::add_user_data_to_db;
+use crate::helper::get_option::subcommand::get_option_map_string_subcommand;
+use crate::minigame::economy::{sell_items, SaleOutcome, SaleSelection};
+use crate::minigame::{minigame_enabled, module_disabled_embed};
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::localization::{get_language_identifier, Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+#[slash_command(
+	name = "sell", desc = "Sell items from your inventory.",
+	command_type = SubCommand(parent = "minigame"),
+	contexts = [Guild],
+	install_contexts = [Guild],
+	args = [(name = "item", desc = "The item to sell.", arg_type = String, required = true, autocomplete = true)],
+)]
+async fn sell_command(self_: SellCommand) -> Result<EmbedsContents<'_>> {
+	let ctx = self_.get_ctx();
+	let bot_data = ctx.data::<BotData>().clone();
+	let command_interaction = self_.get_command_interaction();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = command_interaction
+		.guild_id
+		.ok_or(anyhow!("Selling is only available in servers"))?
+		.to_string();
+	let user_id = command_interaction.user.id.to_string();
+
+	let lang_id = get_language_identifier(guild_id.clone(), db_connection.clone()).await;
+	if !minigame_enabled(&guild_id, db_connection.clone()).await {
+		return Ok(module_disabled_embed(&lang_id));
+	}
+
+	let selection = get_option_map_string_subcommand(command_interaction)
+		.get("item")
+		.map(|value| SaleSelection::parse(value))
+		.ok_or(anyhow!("No option for item"))?;
+
+	// wallet and wallet_ledger reference user_data
+	add_user_data_to_db(command_interaction.user.clone(), db_connection.clone()).await?;
+
+	let outcome = sell_items(&db_connection, &guild_id, &user_id, selection).await?;
+
+	let description = match outcome {
+		SaleOutcome::Sold {
+			item_name,
+			count,
+			earned,
+			balance,
+		} => {
+			let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+			args.insert(Cow::Borrowed("name"), FluentValue::from(item_name));
+			args.insert(Cow::Borrowed("count"), FluentValue::from(count));
+			args.insert(Cow::Borrowed("earned"), FluentValue::from(earned));
+			args.insert(Cow::Borrowed("balance"), FluentValue::from(balance));
+			USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_sell-sold", &args)
+		},
+		SaleOutcome::NothingSold => USABLE_LOCALES.lookup(&lang_id, "minigame_sell-nothing"),
+	};
+
+	let embed_content = EmbedContent::new(USABLE_LOCALES.lookup(&lang_id, "minigame_sell-title"))
+		.description(description);
+
+	Ok(EmbedsContents::new(vec![embed_content]))
+}
@@ -0,0 +1,143 @@
+use crate::command::command::CommandRun;
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::event_handler::BotData;
+use crate::handlers::user_db::add_user_data_to_db;
+use crate::helper::get_option::subcommand::{
+	get_option_map_integer_subcommand, get_option_map_string_subcommand,
+};
+use crate::minigame::economy::{
+	buy_item, get_balance, is_for_sale, PurchaseOutcome, PURCHASABLE_TYPES,
+};
+use crate::minigame::{minigame_enabled, module_disabled_embed};
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use kasuki_macros::slash_command;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::database::item;
+use shared::database::prelude::Item;
+use shared::localization::{get_language_identifier, Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+#[slash_command(
+	name = "shop", desc = "Browse the shop or buy an item.",
+	command_type = SubCommand(parent = "minigame"),
+	contexts = [Guild],
+	install_contexts = [Guild],
+	args = [
+		(name = "buy", desc = "The item to buy.", arg_type = String, required = false, autocomplete = true),
+		(name = "quantity", desc = "How many to buy (1-10).", arg_type = Integer, required = false, autocomplete = false)
+	],
+)]
+async fn shop_command(self_: ShopCommand) -> Result<EmbedsContents<'_>> {
+	let ctx = self_.get_ctx();
+	let bot_data = ctx.data::<BotData>().clone();
+	let command_interaction = self_.get_command_interaction();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = command_interaction
+		.guild_id
+		.ok_or(anyhow!("The shop only exists in servers"))?
+		.to_string();
+	let user_id = command_interaction.user.id.to_string();
+
+	let lang_id = get_language_identifier(guild_id.clone(), db_connection.clone()).await;
+	if !minigame_enabled(&guild_id, db_connection.clone()).await {
+		return Ok(module_disabled_embed(&lang_id));
+	}
+
+	let item_id = get_option_map_string_subcommand(command_interaction)
+		.get("buy")
+		.cloned()
+		.filter(|item_id| !item_id.is_empty());
+
+	let Some(item_id) = item_id else {
+		let items = Item::find()
+			.filter(item::Column::Type.is_in(PURCHASABLE_TYPES.to_vec()))
+			.filter(item::Column::Price.gt(0))
+			.filter(item::Column::RetiredAt.is_null())
+			.order_by_asc(item::Column::Price)
+			.all(&*db_connection)
+			.await?;
+		let balance = get_balance(&*db_connection, &guild_id, &user_id).await?;
+
+		let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+		args.insert(Cow::Borrowed("balance"), FluentValue::from(balance));
+
+		let fields = items
+			.into_iter()
+			.map(|item| {
+				let mut item_args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+				item_args.insert(Cow::Borrowed("name"), FluentValue::from(item.name));
+				item_args.insert(Cow::Borrowed("price"), FluentValue::from(item.price));
+
+				(
+					USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_shop-entry", &item_args),
+					item.description,
+					false,
+				)
+			})
+			.collect::<Vec<_>>();
+
+		let description = if fields.is_empty() {
+			USABLE_LOCALES.lookup(&lang_id, "minigame_shop-empty")
+		} else {
+			USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_shop-desc", &args)
+		};
+
+		let embed_content =
+			EmbedContent::new(USABLE_LOCALES.lookup(&lang_id, "minigame_shop-title"))
+				.description(description)
+				.fields(fields);
+
+		return Ok(EmbedsContents::new(vec![embed_content]));
+	};
+
+	let item = Item::find_by_id(item_id.clone())
+		.one(&*db_connection)
+		.await?
+		.filter(is_for_sale);
+	let Some(item) = item else {
+		let embed_content =
+			EmbedContent::new(USABLE_LOCALES.lookup(&lang_id, "minigame_shop-title"))
+				.description(USABLE_LOCALES.lookup(&lang_id, "minigame_shop-not_for_sale"));
+
+		return Ok(EmbedsContents::new(vec![embed_content]));
+	};
+
+	let quantity = get_option_map_integer_subcommand(command_interaction)
+		.get("quantity")
+		.copied()
+		.unwrap_or(1);
+
+	// wallet and wallet_ledger reference user_data
+	add_user_data_to_db(command_interaction.user.clone(), db_connection.clone()).await?;
+
+	let outcome = buy_item(&db_connection, &guild_id, &user_id, &item, quantity).await?;
+
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(Cow::Borrowed("name"), FluentValue::from(item.name.clone()));
+
+	let description = match outcome {
+		PurchaseOutcome::Bought { total, balance } => {
+			args.insert(Cow::Borrowed("total"), FluentValue::from(total));
+			args.insert(Cow::Borrowed("balance"), FluentValue::from(balance));
+			args.insert(
+				Cow::Borrowed("quantity"),
+				FluentValue::from(total / item.price as i64),
+			);
+			USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_shop-bought", &args)
+		},
+		PurchaseOutcome::InsufficientFunds { total, balance } => {
+			args.insert(Cow::Borrowed("total"), FluentValue::from(total));
+			args.insert(Cow::Borrowed("balance"), FluentValue::from(balance));
+			USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_shop-insufficient", &args)
+		},
+	};
+
+	let embed_content = EmbedContent::new(USABLE_LOCALES.lookup(&lang_id, "minigame_shop-title"))
+		.description(description);
+
+	Ok(EmbedsContents::new(vec![embed_content]))
+}
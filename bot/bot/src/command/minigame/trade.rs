@@ -0,0 +1,78 @@
+use crate::command::command::CommandRun;
+use crate::command::embed_content::{
+	ComponentVersion, ComponentVersion2, EmbedContent, EmbedsContents,
+};
+use crate::components::minigame::trade::{trade_buttons, trade_description};
+use crate::event_handler::BotData;
+use crate::handlers::user_db::add_user_data_to_db;
+use crate::helper::get_option::subcommand::get_option_map_user_subcommand;
+use crate::minigame::trade::{load_trade, open_trade};
+use crate::minigame::{minigame_enabled, module_disabled_embed};
+use anyhow::{anyhow, Result};
+use kasuki_macros::slash_command;
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::localization::{get_language_identifier, Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+
+#[slash_command(
+	name = "trade", desc = "Trade items and coins with another member.",
+	command_type = SubCommand(parent = "minigame"),
+	contexts = [Guild],
+	install_contexts = [Guild],
+	args = [(name = "user", desc = "The member to trade with.", arg_type = User, required = true, autocomplete = false)],
+)]
+async fn trade_command(self_: TradeCommand) -> Result<EmbedsContents<'_>> {
+	let ctx = self_.get_ctx();
+	let bot_data = ctx.data::<BotData>().clone();
+	let command_interaction = self_.get_command_interaction();
+	let db_connection = bot_data.db_connection.clone();
+
+	let guild_id = command_interaction
+		.guild_id
+		.ok_or(anyhow!("Trading is only available in servers"))?
+		.to_string();
+
+	let lang_id = get_language_identifier(guild_id.clone(), db_connection.clone()).await;
+	if !minigame_enabled(&guild_id, db_connection.clone()).await {
+		return Ok(module_disabled_embed(&lang_id));
+	}
+
+	let partner = get_option_map_user_subcommand(command_interaction)
+		.get("user")
+		.ok_or(anyhow!("No option for user"))?
+		.to_user(&ctx.http)
+		.await?;
+
+	let title = USABLE_LOCALES.lookup(&lang_id, "minigame_trade-title");
+	if partner.id == command_interaction.user.id || partner.bot() {
+		let embed_content = EmbedContent::new(title)
+			.description(USABLE_LOCALES.lookup(&lang_id, "minigame_trade-invalid_partner"));
+
+		return Ok(EmbedsContents::new(vec![embed_content]));
+	}
+
+	// trade references user_data for both sides
+	add_user_data_to_db(command_interaction.user.clone(), db_connection.clone()).await?;
+	add_user_data_to_db(partner.clone(), db_connection.clone()).await?;
+
+	let trade = open_trade(
+		&db_connection,
+		&guild_id,
+		&command_interaction.user.id.to_string(),
+		&partner.id.to_string(),
+	)
+	.await?;
+	let view = load_trade(&*db_connection, &trade.id)
+		.await?
+		.ok_or(anyhow!("Trade {} vanished after opening", trade.id))?;
+
+	let embed_content = EmbedContent::new(title).description(trade_description(&view, &lang_id));
+
+	Ok(
+		EmbedsContents::new(vec![embed_content]).action_row(ComponentVersion::V2(
+			ComponentVersion2 {
+				components: Cow::Owned(trade_buttons(&view, &lang_id)),
+			},
+		)),
+	)
+}
@@ -7,6 +7,7 @@ pub mod user;
 
 pub mod admin;
 pub mod ai;
+pub mod anilist;
 pub mod anilist_server;
 pub mod anilist_user;
 pub mod anime;
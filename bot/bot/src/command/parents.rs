@@ -140,6 +140,20 @@ inventory::submit!(&ParentCommand {
 	groups: &[],
 });
 
+inventory::submit!(&ParentCommand {
+	name: "anilist",
+	desc: "Commands using your AniList list.",
+	nsfw: false,
+	permissions: &[],
+	contexts: &[
+		ContextType::Guild,
+		ContextType::BotDm,
+		ContextType::PrivateChannel
+	],
+	install_contexts: &[InstallType::Guild, InstallType::User],
+	groups: &[],
+});
+
 // ─── Subcommand group parent (admin with nested groups) ─────────────────────
 
 inventory::submit!(&ParentCommand {
@@ -158,5 +172,9 @@ inventory::submit!(&ParentCommand {
 			name: "general",
 			desc: "Bot configuration configuration for admin only.",
 		},
+		GroupDef {
+			name: "level",
+			desc: "Admin commands for the LEVEL module.",
+		},
 	],
 });
@@ -0,0 +1,87 @@
+//! The `MessageContentCommand` struct lets a user opt in or out of having the
+//! content of their messages stored. Without opt-in, only the length, channel
+//! and time of a message are kept. Opting out strips what was already stored.
+use crate::command::command::CommandRun;
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use crate::event_handler::BotData;
+use crate::handlers::user_db::add_user_data_to_db;
+use crate::helper::get_option::subcommand::get_option_map_boolean_subcommand;
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use kasuki_macros::slash_command;
+use sea_orm::prelude::Expr;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use serenity::all::{CommandInteraction, Context as SerenityContext};
+use shared::database::prelude::{Message, UserMessageTracking};
+use shared::database::{message, user_message_tracking};
+use shared::localization::{get_language_identifier, Loader, USABLE_LOCALES};
+use tracing::debug;
+
+#[slash_command(
+	name = "message_content", desc = "Choose whether the content of your messages may be stored.",
+	command_type = SubCommand(parent = "user"),
+	contexts = [Guild, BotDm, PrivateChannel],
+	install_contexts = [Guild, User],
+	args = [(name = "state", desc = "Whether your message content may be stored.", arg_type = Boolean, required = true, autocomplete = false)],
+)]
+async fn message_content_command(self_: MessageContentCommand) -> Result<EmbedsContents<'_>> {
+	let ctx = self_.get_ctx();
+	let bot_data = ctx.data::<BotData>().clone();
+	let command_interaction = self_.get_command_interaction();
+	let db_connection = bot_data.db_connection.clone();
+
+	let state = *get_option_map_boolean_subcommand(command_interaction)
+		.get("state")
+		.ok_or(anyhow!("No option for state"))?;
+
+	let user = command_interaction.user.clone();
+	let user_id = user.id.to_string();
+	// user_message_tracking references user_data
+	add_user_data_to_db(user, db_connection.clone()).await?;
+
+	UserMessageTracking::insert(user_message_tracking::ActiveModel {
+		user_id: Set(user_id.clone()),
+		store_content: Set(state),
+		updated_at: Set(Utc::now().naive_utc()),
+	})
+	.on_conflict(
+		OnConflict::column(user_message_tracking::Column::UserId)
+			.update_columns([
+				user_message_tracking::Column::StoreContent,
+				user_message_tracking::Column::UpdatedAt,
+			])
+			.to_owned(),
+	)
+	.exec(&*db_connection)
+	.await?;
+
+	if !state {
+		let stripped = Message::update_many()
+			.col_expr(message::Column::Data, Expr::value(""))
+			.filter(message::Column::UserId.eq(user_id.clone()))
+			.filter(message::Column::Data.ne(""))
+			.exec(&*db_connection)
+			.await?;
+		debug!(user_id = %user_id, rows = stripped.rows_affected, "Stripped stored message content");
+	}
+
+	let guild_id = command_interaction
+		.guild_id
+		.map(|id| id.to_string())
+		.unwrap_or("0".to_string());
+	let lang_id = get_language_identifier(guild_id, db_connection).await;
+
+	let key = if state {
+		"user_message_content-on"
+	} else {
+		"user_message_content-off"
+	};
+
+	let embed_content =
+		EmbedContent::new(USABLE_LOCALES.lookup(&lang_id, "user_message_content-title"))
+			.description(USABLE_LOCALES.lookup(&lang_id, key));
+
+	Ok(EmbedsContents::new(vec![embed_content]))
+}
@@ -1,4 +1,5 @@
 pub mod avatar;
 pub mod banner;
 pub mod command_usage;
+pub mod message_content;
 pub mod profile;
@@ -1,6 +1,7 @@
 //! The `ProfileCommand` struct represents a command for handling and displaying user profile
 //! information in a Discord bot. It contains the Serenity context and the interaction data for
 //! processing and responding to the user command.
+use crate::achievement::{catalog, unlocked_achievements, Achievement};
 use crate::command::command::CommandRun;
 use crate::command::embed_content::{EmbedContent, EmbedsContents};
 use crate::command::user::avatar::{get_user_command, get_user_command_user};
@@ -13,6 +14,9 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 use unic_langid::LanguageIdentifier;
 
+/// Room left for the ellipsis under Discord's 1024 character field limit.
+const ACHIEVEMENTS_FIELD_LIMIT: usize = 1000;
+
 #[slash_command(
 	name = "profile", desc = "Show the profile of a user.",
 	command_type = SubCommand(parent = "user"),
@@ -39,7 +43,7 @@ async fn profile_command(self_: ProfileCommand) -> Result<EmbedsContents<'_>> {
 		.unwrap_or("0".to_string());
 	let db_connection = bot_data.db_connection.clone();
 
-	let lang_id = get_language_identifier(guild_id, db_connection).await;
+	let lang_id = get_language_identifier(guild_id, db_connection.clone()).await;
 
 	let mut fields = get_fields(&lang_id, user.clone());
 
@@ -115,6 +119,9 @@ async fn profile_command(self_: ProfileCommand) -> Result<EmbedsContents<'_>> {
 		}
 	}
 
+	let unlocked = unlocked_achievements(&db_connection, &user.id.to_string()).await?;
+	fields.push(achievements_field(&lang_id, &unlocked));
+
 	let mut title_args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
 	title_args.insert(
 		Cow::Borrowed("user"),
@@ -209,3 +216,34 @@ fn get_fields(lang_id: &LanguageIdentifier, user: User) -> Vec<(String, String,
 
 	fields
 }
+
+/// Unlocked achievements out of the whole catalog, kept within the embed
+/// field limit.
+fn achievements_field(
+	lang_id: &LanguageIdentifier, unlocked: &[&Achievement],
+) -> (String, String, bool) {
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(Cow::Borrowed("count"), FluentValue::from(unlocked.len()));
+	args.insert(Cow::Borrowed("total"), FluentValue::from(catalog().len()));
+	let name = USABLE_LOCALES.lookup_with_args(lang_id, "user_profile-achievements", &args);
+
+	if unlocked.is_empty() {
+		return (
+			name,
+			USABLE_LOCALES.lookup(lang_id, "user_profile-no_achievements"),
+			false,
+		);
+	}
+
+	let mut value = String::new();
+	for achievement in unlocked {
+		let line = format!("{} {}\n", achievement.icon, achievement.name(lang_id));
+		if value.len() + line.len() > ACHIEVEMENTS_FIELD_LIMIT {
+			value.push('…');
+			break;
+		}
+		value.push_str(&line);
+	}
+
+	(name, value.trim_end().to_string(), false)
+}
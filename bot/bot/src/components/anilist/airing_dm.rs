@@ -0,0 +1,107 @@
+//! The unfollow, snooze and mute buttons under the airing DMs the worker
+//! sends.
+use anyhow::{anyhow, Result};
+use chrono::{Duration, Utc};
+use fluent_templates::fluent_bundle::FluentValue;
+use fluent_templates::Loader;
+use sea_orm::DatabaseConnection;
+use serenity::all::{
+	ComponentInteraction, Context as SerenityContext, CreateInteractionResponse,
+	CreateInteractionResponseMessage,
+};
+use shared::anilist::airing_dm::{AiringDmAction, AIRING_DM_BUTTON, SNOOZE_BUTTON_HOURS};
+use shared::localization::{get_language_identifier, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::anilist::follow::{unfollow_anime, update_dm_setting, SettingChange};
+use crate::components::handler::ComponentHandler;
+
+async fn answer(
+	ctx: &SerenityContext, interaction: &ComponentInteraction, db: Arc<DatabaseConnection>,
+) -> Result<()> {
+	let action = AiringDmAction::parse(&interaction.data.custom_id)
+		.ok_or(anyhow!("Invalid airing DM button"))?;
+
+	let guild_id = interaction
+		.guild_id
+		.map(|id| id.to_string())
+		.unwrap_or("0".to_string());
+	let lang_id = get_language_identifier(guild_id, db.clone()).await;
+	let user_id = interaction.user.id.to_string();
+
+	let content = match action {
+		AiringDmAction::Unfollow(anime_id) => {
+			if unfollow_anime(&db, &user_id, anime_id).await? {
+				USABLE_LOCALES.lookup(&lang_id, "anilist_airing-unfollowed")
+			} else {
+				USABLE_LOCALES.lookup(&lang_id, "anilist_airing-not_following")
+			}
+		},
+		AiringDmAction::Snooze => {
+			let snoozed_until = Utc::now().naive_utc() + Duration::hours(SNOOZE_BUTTON_HOURS);
+			update_dm_setting(
+				&db,
+				&user_id,
+				SettingChange {
+					snoozed_until: Some(Some(snoozed_until)),
+					..Default::default()
+				},
+			)
+			.await?;
+
+			let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+			args.insert(
+				Cow::Borrowed("time"),
+				FluentValue::from(format!("<t:{}:R>", snoozed_until.and_utc().timestamp())),
+			);
+			USABLE_LOCALES.lookup_with_args(&lang_id, "anilist_airing-snoozed", &args)
+		},
+		AiringDmAction::Mute => {
+			update_dm_setting(
+				&db,
+				&user_id,
+				SettingChange {
+					muted: Some(true),
+					..Default::default()
+				},
+			)
+			.await?;
+
+			USABLE_LOCALES.lookup(&lang_id, "anilist_airing-muted")
+		},
+	};
+
+	interaction
+		.create_response(
+			&ctx.http,
+			CreateInteractionResponse::Message(
+				CreateInteractionResponseMessage::new()
+					.content(content)
+					.ephemeral(true),
+			),
+		)
+		.await?;
+
+	Ok(())
+}
+
+pub struct AiringDmHandler;
+
+impl ComponentHandler for AiringDmHandler {
+	fn prefix(&self) -> &'static str {
+		AIRING_DM_BUTTON
+	}
+
+	fn handle<'a>(
+		&'a self, ctx: &'a SerenityContext, interaction: &'a ComponentInteraction,
+		db: Arc<DatabaseConnection>,
+	) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+		Box::pin(async move { answer(ctx, interaction, db).await })
+	}
+}
+
+inventory::submit! { &AiringDmHandler as &dyn ComponentHandler }
@@ -0,0 +1,76 @@
+//! Pages of the list comparison under `compare`. The custom id carries both
+//! AniList user ids, the lists come back from the AniList cache.
+use anyhow::{anyhow, Result};
+use sea_orm::DatabaseConnection;
+use serenity::all::{
+	ComponentInteraction, Context as SerenityContext, CreateInteractionResponse,
+	EditInteractionResponse,
+};
+use shared::localization::get_language_identifier;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::anilist::compare::{parse_compare_button, COMPARE_BUTTON};
+use crate::command::anilist_user::compare::compare_components;
+use crate::command::anilist_user::user::get_user;
+use crate::components::handler::ComponentHandler;
+use crate::event_handler::BotData;
+
+async fn show_page(
+	ctx: &SerenityContext, interaction: &ComponentInteraction, db: Arc<DatabaseConnection>,
+) -> Result<()> {
+	let bot_data = ctx.data::<BotData>().clone();
+	let (user_id, user_id2, view, page) = parse_compare_button(&interaction.data.custom_id)
+		.ok_or(anyhow!("Invalid compare button"))?;
+
+	let guild_id = interaction
+		.guild_id
+		.map(|id| id.to_string())
+		.unwrap_or("0".to_string());
+	let lang_id = get_language_identifier(guild_id, db).await;
+
+	// A list evicted from the cache takes a few requests to load again
+	interaction
+		.create_response(&ctx.http, CreateInteractionResponse::Acknowledge)
+		.await?;
+
+	let user = get_user(&user_id.to_string(), bot_data.anilist_cache.clone()).await?;
+	let user2 = get_user(&user_id2.to_string(), bot_data.anilist_cache.clone()).await?;
+
+	let components = compare_components(
+		user,
+		user2,
+		view,
+		page,
+		&lang_id,
+		bot_data.anilist_cache.clone(),
+	)
+	.await?;
+
+	interaction
+		.edit_response(
+			&ctx.http,
+			EditInteractionResponse::new().components(components),
+		)
+		.await?;
+
+	Ok(())
+}
+
+pub struct CompareListHandler;
+
+impl ComponentHandler for CompareListHandler {
+	fn prefix(&self) -> &'static str {
+		COMPARE_BUTTON
+	}
+
+	fn handle<'a>(
+		&'a self, ctx: &'a SerenityContext, interaction: &'a ComponentInteraction,
+		db: Arc<DatabaseConnection>,
+	) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+		Box::pin(async move { show_page(ctx, interaction, db).await })
+	}
+}
+
+inventory::submit! { &CompareListHandler as &dyn ComponentHandler }
@@ -0,0 +1,142 @@
+//! The "episode +1" button on anime activity notifications. The notification
+//! is posted for the whole channel, so every answer is ephemeral and only
+//! touches the list of whoever pressed the button.
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use fluent_templates::Loader;
+use sea_orm::DatabaseConnection;
+use serenity::all::{
+	ComponentInteraction, Context as SerenityContext, CreateInteractionResponse,
+	CreateInteractionResponseMessage,
+};
+use shared::anilist::episode_button::{parse_episode_button, EPISODE_BUTTON};
+use shared::localization::{get_language_identifier, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use unic_langid::LanguageIdentifier;
+
+use crate::anilist::list::{
+	entry_progress, entry_title, get_entry, linked_account, needs_relink, save_progress, Link,
+};
+use crate::anilist::{relink_row, relink_text, Relink};
+use crate::components::handler::ComponentHandler;
+use crate::event_handler::BotData;
+
+async fn reply(
+	ctx: &SerenityContext, interaction: &ComponentInteraction,
+	message: CreateInteractionResponseMessage<'_>,
+) -> Result<()> {
+	interaction
+		.create_response(
+			&ctx.http,
+			CreateInteractionResponse::Message(message.ephemeral(true)),
+		)
+		.await?;
+
+	Ok(())
+}
+
+async fn reply_relink(
+	ctx: &SerenityContext, interaction: &ComponentInteraction, lang_id: &LanguageIdentifier,
+	reason: Relink,
+) -> Result<()> {
+	let message = CreateInteractionResponseMessage::new()
+		.content(relink_text(lang_id, reason))
+		.components(vec![relink_row(lang_id)]);
+
+	reply(ctx, interaction, message).await
+}
+
+async fn mark_episode(
+	ctx: &SerenityContext, interaction: &ComponentInteraction, db: Arc<DatabaseConnection>,
+) -> Result<()> {
+	let bot_data = ctx.data::<BotData>().clone();
+	let (anime_id, episode) = parse_episode_button(&interaction.data.custom_id)
+		.ok_or(anyhow!("Invalid episode button"))?;
+
+	let guild_id = interaction
+		.guild_id
+		.map(|id| id.to_string())
+		.unwrap_or("0".to_string());
+	let lang_id = get_language_identifier(guild_id, db.clone()).await;
+
+	let user_id = interaction.user.id.to_string();
+	let account = match linked_account(&db, &bot_data.config, &user_id).await? {
+		Link::Linked(account) => account,
+		Link::Missing => return reply_relink(ctx, interaction, &lang_id, Relink::NotLinked).await,
+		Link::Expired => return reply_relink(ctx, interaction, &lang_id, Relink::Expired).await,
+	};
+
+	let update = match get_entry(&account, anime_id).await {
+		// Pressing the button again, or after updating the list elsewhere,
+		// must not count the episode twice
+		Ok(Some(entry)) if entry.progress.unwrap_or(0) >= episode => {
+			let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+			args.insert(
+				Cow::Borrowed("anime"),
+				FluentValue::from(entry_title(&entry)),
+			);
+			args.insert(
+				Cow::Borrowed("progress"),
+				FluentValue::from(entry_progress(&entry)),
+			);
+			let content =
+				USABLE_LOCALES.lookup_with_args(&lang_id, "anilist_list-episode_done", &args);
+
+			return reply(
+				ctx,
+				interaction,
+				CreateInteractionResponseMessage::new().content(content),
+			)
+			.await;
+		},
+		Ok(entry) => save_progress(&account, anime_id, entry, 1).await,
+		Err(e) => Err(e),
+	};
+
+	let update = match update {
+		Ok(update) => update,
+		Err(e) if needs_relink(&e) => {
+			return reply_relink(ctx, interaction, &lang_id, Relink::Expired).await
+		},
+		Err(e) => return Err(e),
+	};
+
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("anime"),
+		FluentValue::from(entry_title(&update.entry)),
+	);
+	args.insert(
+		Cow::Borrowed("progress"),
+		FluentValue::from(entry_progress(&update.entry)),
+	);
+	let content = USABLE_LOCALES.lookup_with_args(&lang_id, "anilist_list-episode_saved", &args);
+
+	reply(
+		ctx,
+		interaction,
+		CreateInteractionResponseMessage::new().content(content),
+	)
+	.await
+}
+
+pub struct AnilistEpisodeHandler;
+
+impl ComponentHandler for AnilistEpisodeHandler {
+	fn prefix(&self) -> &'static str {
+		EPISODE_BUTTON
+	}
+
+	fn handle<'a>(
+		&'a self, ctx: &'a SerenityContext, interaction: &'a ComponentInteraction,
+		db: Arc<DatabaseConnection>,
+	) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+		Box::pin(async move { mark_episode(ctx, interaction, db).await })
+	}
+}
+
+inventory::submit! { &AnilistEpisodeHandler as &dyn ComponentHandler }
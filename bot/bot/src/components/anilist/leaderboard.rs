@@ -0,0 +1,251 @@
+use crate::anilist::leaderboard::{stat_page, Stat, StatEntry, StatPage};
+use crate::command::anilist_user::level::get_level;
+use crate::components::handler::ComponentHandler;
+use crate::components::pagination::page_buttons;
+use crate::constant::COLOR;
+use crate::structure::run::anilist::user::get_user_url;
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use fluent_templates::Loader;
+use sea_orm::DatabaseConnection;
+use serenity::all::{
+	ComponentInteraction, Context as SerenityContext, CreateComponent, CreateEmbed,
+	CreateEmbedFooter, CreateInteractionResponse, CreateInteractionResponseMessage, Timestamp,
+};
+use shared::helper::anilist_link::verified_links_only;
+use shared::localization::{get_language_identifier, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use unic_langid::LanguageIdentifier;
+
+/// Custom ids are `anilist_lb_{stat}_{page}`.
+pub const ANILIST_LEADERBOARD_PREFIX: &str = "anilist_lb_";
+
+pub fn leaderboard_title(stat: Stat, lang_id: &LanguageIdentifier) -> String {
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("stat"),
+		FluentValue::from(USABLE_LOCALES.lookup(
+			lang_id,
+			&format!("anilist_leaderboard-stat_{}", stat.as_str()),
+		)),
+	);
+
+	USABLE_LOCALES.lookup_with_args(lang_id, "anilist_leaderboard-title", &args)
+}
+
+/// The ranked value of an entry, the level with its experience for `Level`.
+fn entry_value(stat: Stat, entry: &StatEntry, lang_id: &LanguageIdentifier) -> String {
+	let stats = &entry.stats;
+
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	match stat {
+		Stat::Level => {
+			let (level, _, _) = get_level(stats.xp);
+			args.insert(Cow::Borrowed("level"), FluentValue::from(level));
+			args.insert(
+				Cow::Borrowed("xp"),
+				FluentValue::from(format!("{:.0}", stats.xp)),
+			);
+		},
+		Stat::MinutesWatched => {
+			args.insert(
+				Cow::Borrowed("days"),
+				FluentValue::from(format!("{:.1}", stats.minutes_watched as f64 / 1440.0)),
+			);
+		},
+		Stat::MeanScore => {
+			args.insert(
+				Cow::Borrowed("value"),
+				FluentValue::from(format!("{:.1}", stats.mean_score)),
+			);
+		},
+		Stat::ChaptersRead | Stat::Completed => {
+			args.insert(
+				Cow::Borrowed("value"),
+				FluentValue::from(stat.value(stats) as i64),
+			);
+		},
+	}
+
+	USABLE_LOCALES.lookup_with_args(
+		lang_id,
+		&format!("anilist_leaderboard-value_{}", stat.as_str()),
+		&args,
+	)
+}
+
+/// One line per entry, the viewer's own line in bold, then when the stats
+/// were last refreshed.
+pub fn leaderboard_description(
+	stat: Stat, leaderboard: &StatPage, viewer_id: &str, lang_id: &LanguageIdentifier,
+) -> String {
+	if leaderboard.entries.is_empty() {
+		return USABLE_LOCALES.lookup(lang_id, "anilist_leaderboard-empty");
+	}
+
+	let mut lines: Vec<String> = leaderboard
+		.entries
+		.iter()
+		.map(|entry| {
+			let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+			args.insert(Cow::Borrowed("rank"), FluentValue::from(entry.rank));
+			args.insert(
+				Cow::Borrowed("user"),
+				FluentValue::from(format!("<@{}>", entry.user_id)),
+			);
+			args.insert(
+				Cow::Borrowed("name"),
+				FluentValue::from(entry.stats.name.clone()),
+			);
+			args.insert(
+				Cow::Borrowed("url"),
+				FluentValue::from(get_user_url(&entry.stats.anilist_id)),
+			);
+			args.insert(
+				Cow::Borrowed("value"),
+				FluentValue::from(entry_value(stat, entry, lang_id)),
+			);
+			let line = USABLE_LOCALES.lookup_with_args(lang_id, "anilist_leaderboard-entry", &args);
+
+			if entry.user_id == viewer_id {
+				format!("**{}**", line)
+			} else {
+				line
+			}
+		})
+		.collect();
+
+	if let Some(updated_at) = leaderboard.updated_at {
+		let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+		args.insert(
+			Cow::Borrowed("time"),
+			FluentValue::from(format!("<t:{}:R>", updated_at.and_utc().timestamp())),
+		);
+		lines.push(String::new());
+		lines.push(USABLE_LOCALES.lookup_with_args(lang_id, "anilist_leaderboard-updated", &args));
+	}
+
+	lines.join("\n")
+}
+
+/// Page number and the viewer's position, which may be on another page.
+pub fn leaderboard_footer(leaderboard: &StatPage, lang_id: &LanguageIdentifier) -> String {
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("page"),
+		FluentValue::from(leaderboard.page + 1),
+	);
+	args.insert(
+		Cow::Borrowed("total"),
+		FluentValue::from(leaderboard.total_pages),
+	);
+	let page = USABLE_LOCALES.lookup_with_args(lang_id, "anilist_leaderboard-page", &args);
+
+	let position = match &leaderboard.viewer {
+		Some(viewer) => {
+			let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+			args.insert(Cow::Borrowed("rank"), FluentValue::from(viewer.rank));
+			USABLE_LOCALES.lookup_with_args(lang_id, "anilist_leaderboard-position", &args)
+		},
+		None => USABLE_LOCALES.lookup(lang_id, "anilist_leaderboard-unranked"),
+	};
+
+	format!("{} · {}", page, position)
+}
+
+/// Previous/next buttons, omitted entirely when everything fits on one page.
+pub fn leaderboard_buttons(
+	stat: Stat, page: u64, total_pages: u64, lang_id: &LanguageIdentifier,
+) -> Vec<CreateComponent<'static>> {
+	page_buttons(
+		ANILIST_LEADERBOARD_PREFIX,
+		Some(stat.as_str()),
+		page,
+		total_pages,
+		USABLE_LOCALES.lookup(lang_id, "anilist_leaderboard-previous"),
+		USABLE_LOCALES.lookup(lang_id, "anilist_leaderboard-next"),
+	)
+}
+
+pub async fn update(
+	ctx: &SerenityContext, component_interaction: &ComponentInteraction, stat: Stat, page: u64,
+	db_connection: Arc<DatabaseConnection>,
+) -> Result<()> {
+	let guild_id = component_interaction
+		.guild_id
+		.ok_or(anyhow!("Guild ID not found"))?
+		.to_string();
+
+	let viewer_id = component_interaction.user.id.to_string();
+	let verified_only = verified_links_only(guild_id.clone(), &db_connection).await;
+	let leaderboard = stat_page(
+		&db_connection,
+		&guild_id,
+		stat,
+		verified_only,
+		&viewer_id,
+		page,
+	)
+	.await?;
+
+	let lang_id = get_language_identifier(guild_id, db_connection).await;
+
+	let embed = CreateEmbed::new()
+		.timestamp(Timestamp::now())
+		.color(COLOR)
+		.title(leaderboard_title(stat, &lang_id))
+		.description(leaderboard_description(
+			stat,
+			&leaderboard,
+			&viewer_id,
+			&lang_id,
+		))
+		.footer(CreateEmbedFooter::new(leaderboard_footer(
+			&leaderboard,
+			&lang_id,
+		)));
+
+	let message = CreateInteractionResponseMessage::new()
+		.embed(embed)
+		.components(leaderboard_buttons(
+			stat,
+			leaderboard.page,
+			leaderboard.total_pages,
+			&lang_id,
+		));
+
+	component_interaction
+		.create_response(&ctx.http, CreateInteractionResponse::UpdateMessage(message))
+		.await?;
+
+	Ok(())
+}
+
+pub struct AnilistLeaderboardHandler;
+
+impl ComponentHandler for AnilistLeaderboardHandler {
+	fn prefix(&self) -> &'static str {
+		ANILIST_LEADERBOARD_PREFIX
+	}
+
+	fn handle<'a>(
+		&'a self, ctx: &'a SerenityContext, interaction: &'a ComponentInteraction,
+		db: Arc<DatabaseConnection>,
+	) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+		Box::pin(async move {
+			let custom_id = &interaction.data.custom_id[ANILIST_LEADERBOARD_PREFIX.len()..];
+			let (stat, page) = custom_id
+				.rsplit_once('_')
+				.ok_or(anyhow!("Malformed leaderboard id {}", custom_id))?;
+			let stat = Stat::parse(stat).ok_or(anyhow!("Unknown leaderboard {}", stat))?;
+
+			update(ctx, interaction, stat, page.parse()?, db).await
+		})
+	}
+}
+
+inventory::submit! { &AnilistLeaderboardHandler as &dyn ComponentHandler }
@@ -1,96 +1,106 @@
 use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
 use fluent_templates::Loader;
 use sea_orm::DatabaseConnection;
 use serenity::all::{
-	ComponentInteraction, Context as SerenityContext, CreateButton, EditMessage, UserId,
+	ComponentInteraction, Context as SerenityContext, CreateComponent, CreateEmbed,
+	CreateEmbedFooter, CreateInteractionResponse, CreateInteractionResponseMessage, Timestamp,
 };
-use shared::helper::get_guild_lang::get_guild_language;
-use shared::localization::USABLE_LOCALES;
+use shared::helper::anilist_link::verified_links_only;
+use shared::localization::{get_language_identifier, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::future::Future;
 use std::pin::Pin;
-use std::str::FromStr;
 use std::sync::Arc;
 use unic_langid::LanguageIdentifier;
 
-use crate::command::anilist_server::list_register_user::get_the_list;
+use crate::anilist::leaderboard::{registered_page, RegisteredPage};
 use crate::components::handler::ComponentHandler;
-use crate::constant::MEMBER_LIST_LIMIT;
-use crate::event_handler::BotData;
-use crate::helper::create_default_embed::get_default_embed;
+use crate::components::pagination::page_buttons;
+use crate::constant::COLOR;
+use crate::structure::run::anilist::user::get_user_url;
+
+/// Custom ids are `user_{page}`.
+pub const LIST_USER_PREFIX: &str = "user_";
+
+/// One line per registered member, verified links marked.
+pub fn list_description(list: &RegisteredPage, lang_id: &LanguageIdentifier) -> String {
+	if list.entries.is_empty() {
+		return USABLE_LOCALES.lookup(lang_id, "anilist_server_list_register_user-empty");
+	}
+
+	list.entries
+		.iter()
+		.map(|entry| {
+			// The worker has not fetched accounts registered since its last run
+			let name = entry
+				.name
+				.clone()
+				.unwrap_or_else(|| entry.anilist_id.to_string());
+
+			format!(
+				"<@{}> · [{}](<{}>){}",
+				entry.user_id,
+				name,
+				get_user_url(&entry.anilist_id),
+				if entry.verified { " ✓" } else { "" }
+			)
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+pub fn list_footer(list: &RegisteredPage, lang_id: &LanguageIdentifier) -> String {
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(Cow::Borrowed("page"), FluentValue::from(list.page + 1));
+	args.insert(Cow::Borrowed("total"), FluentValue::from(list.total_pages));
+
+	USABLE_LOCALES.lookup_with_args(lang_id, "anilist_server_list_register_user-page", &args)
+}
+
+/// Previous/next buttons, omitted entirely when everything fits on one page.
+pub fn list_buttons(
+	page: u64, total_pages: u64, lang_id: &LanguageIdentifier,
+) -> Vec<CreateComponent<'static>> {
+	page_buttons(
+		LIST_USER_PREFIX,
+		None,
+		page,
+		total_pages,
+		USABLE_LOCALES.lookup(lang_id, "anilist_server_list_register_user-previous"),
+		USABLE_LOCALES.lookup(lang_id, "anilist_server_list_register_user-next"),
+	)
+}
 
 pub async fn update(
-	ctx: &SerenityContext, component_interaction: &ComponentInteraction, user_id: &str,
-	prev_id: &str,
+	ctx: &SerenityContext, component_interaction: &ComponentInteraction, page: u64,
+	db_connection: Arc<DatabaseConnection>,
 ) -> Result<()> {
-	let bot_data = ctx.data::<BotData>().clone();
-	let connection = bot_data.db_connection.clone();
-	// Retrieve the guild ID from the component interaction
-	let guild_id = match component_interaction.guild_id {
-		Some(id) => id.to_string(),
-		None => String::from("0"),
-	};
-	let db_connection = bot_data.db_connection.clone();
-
-	// Load the localized user list
-	let lang = get_guild_language(guild_id.clone(), db_connection).await;
-	let lang_code = match lang.as_str() {
-		"jp" => "ja",
-		"en" => "en-US",
-		other => other,
-	};
-	let lang_id = LanguageIdentifier::from_str(lang_code)
-		.unwrap_or_else(|_| LanguageIdentifier::from_str("en-US").unwrap());
-	let previous = USABLE_LOCALES.lookup(&lang_id, "anilist_server_list_register_user-previous");
-	let next = USABLE_LOCALES.lookup(&lang_id, "anilist_server_list_register_user-next");
-
-	// Retrieve the guild ID from the component interaction
 	let guild_id = component_interaction
 		.guild_id
-		.ok_or(anyhow!("Guild ID not found"))?;
-
-	// Retrieve the guild with counts
-	let guild = guild_id.to_partial_guild_with_counts(&ctx.http).await?;
-
-	// Parse the user ID
-	let id = if user_id == "0" {
-		None
-	} else {
-		user_id.parse().ok()
-	};
-
-	// Get the list of users
-	let (builder_message, len, last_id): (String, usize, Option<UserId>) =
-		get_the_list(guild, ctx, id, connection).await?;
-
-	let old_embed_title = component_interaction
-		.message
-		.embeds
-		.first()
-		.and_then(|embed| embed.title.clone())
-		.unwrap_or_default();
-
-	let embed = get_default_embed(None, &None)
-		.title(old_embed_title)
-		.description(builder_message);
-
-	// Create the response message
-	let mut response = EditMessage::new().embed(embed);
-
-	if user_id != "0" {
-		response = response
-			.button(CreateButton::new(format!("user_{}_{}", user_id, prev_id)).label(&previous));
-	}
+		.ok_or(anyhow!("Guild ID not found"))?
+		.to_string();
 
-	if len > MEMBER_LIST_LIMIT as usize {
-		response = response
-			.button(CreateButton::new(format!("user_{}_{}", last_id.unwrap(), user_id)).label(next))
-	}
+	let verified_only = verified_links_only(guild_id.clone(), &db_connection).await;
+	let list = registered_page(&db_connection, &guild_id, verified_only, page).await?;
+
+	let lang_id = get_language_identifier(guild_id, db_connection).await;
 
-	// Clone the component interaction message
-	let mut message = component_interaction.message.clone();
+	let embed = CreateEmbed::new()
+		.timestamp(Timestamp::now())
+		.color(COLOR)
+		.title(USABLE_LOCALES.lookup(&lang_id, "anilist_server_list_register_user-title"))
+		.description(list_description(&list, &lang_id))
+		.footer(CreateEmbedFooter::new(list_footer(&list, &lang_id)));
 
-	// Edit the message with the response
-	message.edit(&ctx.http, response).await?;
+	let message = CreateInteractionResponseMessage::new()
+		.embed(embed)
+		.components(list_buttons(list.page, list.total_pages, &lang_id));
+
+	component_interaction
+		.create_response(&ctx.http, CreateInteractionResponse::UpdateMessage(message))
+		.await?;
 
 	Ok(())
 }
@@ -99,18 +109,17 @@ pub struct ListRegisterUserHandler;
 
 impl ComponentHandler for ListRegisterUserHandler {
 	fn prefix(&self) -> &'static str {
-		"user_"
+		LIST_USER_PREFIX
 	}
 
 	fn handle<'a>(
 		&'a self, ctx: &'a SerenityContext, interaction: &'a ComponentInteraction,
-		_db: Arc<DatabaseConnection>,
+		db: Arc<DatabaseConnection>,
 	) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
 		Box::pin(async move {
-			let s = interaction.data.custom_id.as_str();
-			let user_id = s.split_at("_".len()).1;
-			let prev_id = user_id.split_at("_".len()).1;
-			update(ctx, interaction, user_id, prev_id).await
+			let page = interaction.data.custom_id[LIST_USER_PREFIX.len()..].parse()?;
+
+			update(ctx, interaction, page, db).await
 		})
 	}
 }
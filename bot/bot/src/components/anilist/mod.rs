@@ -1,2 +1,9 @@
+pub mod airing_dm;
+pub mod compare_list;
+pub mod episode;
+pub mod leaderboard;
 pub mod list_all_activity;
 pub mod list_register_user;
+pub mod schedule;
+pub mod season;
+pub mod verify;
@@ -0,0 +1,198 @@
+use crate::anilist::schedule::{
+	load_timetable, schedule_page, ScheduleDay, SchedulePage, ScheduleScope, Timetable,
+};
+use crate::components::handler::ComponentHandler;
+use crate::components::pagination::page_buttons;
+use crate::constant::COLOR;
+use crate::event_handler::BotData;
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use fluent_templates::Loader;
+use sea_orm::DatabaseConnection;
+use serenity::all::{
+	ComponentInteraction, Context as SerenityContext, CreateComponent, CreateEmbed,
+	CreateEmbedFooter, CreateInteractionResponse, CreateInteractionResponseMessage, Timestamp,
+};
+use shared::localization::{get_language_identifier, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use unic_langid::LanguageIdentifier;
+
+/// Custom ids are `anilist_schedule_{day}_{scope}_{page}`. The `list` scope
+/// shows the list of whoever pressed the button.
+pub const SCHEDULE_PREFIX: &str = "anilist_schedule_";
+
+pub fn schedule_title(timetable: &Timetable, lang_id: &LanguageIdentifier) -> String {
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("date"),
+		FluentValue::from(timetable.date.format("%Y-%m-%d").to_string()),
+	);
+
+	USABLE_LOCALES.lookup_with_args(lang_id, "anilist_schedule-title", &args)
+}
+
+/// The timezone of the day and which anime are shown, then one line per
+/// episode at the time of whoever reads it.
+pub fn schedule_description(
+	timetable: &Timetable, scope: ScheduleScope, schedule: &SchedulePage,
+	lang_id: &LanguageIdentifier,
+) -> String {
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("timezone"),
+		FluentValue::from(timetable.timezone.name()),
+	);
+	let mut lines = vec![USABLE_LOCALES.lookup_with_args(
+		lang_id,
+		&format!("anilist_schedule-scope_{}", scope.as_str()),
+		&args,
+	)];
+	lines.push(String::new());
+
+	if schedule.entries.is_empty() {
+		lines.push(USABLE_LOCALES.lookup(lang_id, "anilist_schedule-empty"));
+		return lines.join("\n");
+	}
+
+	lines.extend(schedule.entries.iter().map(|entry| {
+		let title = match &entry.url {
+			Some(url) => format!("[{}]({})", entry.title, url),
+			None => entry.title.clone(),
+		};
+
+		let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+		args.insert(Cow::Borrowed("episode"), FluentValue::from(entry.episode));
+		let episode = match entry.episodes {
+			Some(episodes) => {
+				args.insert(Cow::Borrowed("episodes"), FluentValue::from(episodes));
+				USABLE_LOCALES.lookup_with_args(lang_id, "anilist_schedule-episode_of", &args)
+			},
+			None => USABLE_LOCALES.lookup_with_args(lang_id, "anilist_schedule-episode", &args),
+		};
+
+		format!("<t:{}:t> · **{}** · {}", entry.airing_at, title, episode)
+	}));
+
+	lines.join("\n")
+}
+
+pub fn schedule_footer(schedule: &SchedulePage, lang_id: &LanguageIdentifier) -> String {
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(Cow::Borrowed("page"), FluentValue::from(schedule.page + 1));
+	args.insert(
+		Cow::Borrowed("total"),
+		FluentValue::from(schedule.total_pages),
+	);
+
+	USABLE_LOCALES.lookup_with_args(lang_id, "anilist_schedule-page", &args)
+}
+
+/// Previous/next buttons, omitted entirely when everything fits on one page.
+pub fn schedule_buttons(
+	day: ScheduleDay, scope: ScheduleScope, page: usize, total_pages: usize,
+	lang_id: &LanguageIdentifier,
+) -> Vec<CreateComponent<'static>> {
+	page_buttons(
+		SCHEDULE_PREFIX,
+		Some(&format!("{}_{}", day.as_str(), scope.as_str())),
+		page as u64,
+		total_pages as u64,
+		USABLE_LOCALES.lookup(lang_id, "anilist_schedule-previous"),
+		USABLE_LOCALES.lookup(lang_id, "anilist_schedule-next"),
+	)
+}
+
+pub async fn update(
+	ctx: &SerenityContext, component_interaction: &ComponentInteraction, day: ScheduleDay,
+	scope: ScheduleScope, page: usize, db_connection: Arc<DatabaseConnection>,
+) -> Result<()> {
+	let bot_data = ctx.data::<BotData>().clone();
+
+	let guild_id = component_interaction
+		.guild_id
+		.map(|guild_id| guild_id.to_string());
+	let lang_id = get_language_identifier(
+		guild_id.clone().unwrap_or_else(|| String::from("0")),
+		db_connection.clone(),
+	)
+	.await;
+
+	let embed = CreateEmbed::new().timestamp(Timestamp::now()).color(COLOR);
+	let response = match load_timetable(
+		db_connection,
+		bot_data.anilist_cache.clone(),
+		guild_id,
+		&component_interaction.user.id.to_string(),
+		day,
+		scope,
+	)
+	.await?
+	{
+		Some(timetable) => {
+			let schedule = schedule_page(timetable.entries.clone(), page);
+			let embed = embed
+				.title(schedule_title(&timetable, &lang_id))
+				.description(schedule_description(&timetable, scope, &schedule, &lang_id))
+				.footer(CreateEmbedFooter::new(schedule_footer(&schedule, &lang_id)));
+
+			CreateInteractionResponse::UpdateMessage(
+				CreateInteractionResponseMessage::new()
+					.embed(embed)
+					.components(schedule_buttons(
+						day,
+						scope,
+						schedule.page,
+						schedule.total_pages,
+						&lang_id,
+					)),
+			)
+		},
+		// Someone without an account pressed the button of a list timetable
+		None => CreateInteractionResponse::Message(
+			CreateInteractionResponseMessage::new()
+				.embed(
+					embed.description(USABLE_LOCALES.lookup(&lang_id, "anilist_schedule-no_user")),
+				)
+				.ephemeral(true),
+		),
+	};
+
+	component_interaction
+		.create_response(&ctx.http, response)
+		.await?;
+
+	Ok(())
+}
+
+pub struct ScheduleHandler;
+
+impl ComponentHandler for ScheduleHandler {
+	fn prefix(&self) -> &'static str {
+		SCHEDULE_PREFIX
+	}
+
+	fn handle<'a>(
+		&'a self, ctx: &'a SerenityContext, interaction: &'a ComponentInteraction,
+		db: Arc<DatabaseConnection>,
+	) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+		Box::pin(async move {
+			let custom_id = &interaction.data.custom_id[SCHEDULE_PREFIX.len()..];
+			let mut fields = custom_id.splitn(3, '_');
+			let (Some(day), Some(scope), Some(page)) =
+				(fields.next(), fields.next(), fields.next())
+			else {
+				return Err(anyhow!("Malformed schedule id {}", custom_id));
+			};
+			let day = ScheduleDay::parse(day).ok_or(anyhow!("Unknown day {}", day))?;
+			let scope = ScheduleScope::parse(scope).ok_or(anyhow!("Unknown scope {}", scope))?;
+
+			update(ctx, interaction, day, scope, page.parse()?, db).await
+		})
+	}
+}
+
+inventory::submit! { &ScheduleHandler as &dyn ComponentHandler }
@@ -0,0 +1,215 @@
+use crate::anilist::season::{
+	fetch_season, season_page, SeasonEntry, SeasonPage, SeasonView, SEASON_PAGE_SIZE,
+};
+use crate::components::handler::ComponentHandler;
+use crate::components::pagination::page_buttons;
+use crate::constant::COLOR;
+use crate::event_handler::BotData;
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use fluent_templates::Loader;
+use sea_orm::DatabaseConnection;
+use serenity::all::{
+	ComponentInteraction, Context as SerenityContext, CreateComponent, CreateEmbed,
+	CreateEmbedFooter, CreateInteractionResponse, CreateInteractionResponseMessage, Timestamp,
+};
+use shared::localization::{get_language_identifier, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use unic_langid::LanguageIdentifier;
+
+/// Custom ids are `anilist_season_{view}_{page}`, see [`SeasonView::encode`].
+pub const SEASON_PREFIX: &str = "anilist_season_";
+
+pub fn season_title(view: &SeasonView, lang_id: &LanguageIdentifier) -> String {
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("season"),
+		FluentValue::from(USABLE_LOCALES.lookup(
+			lang_id,
+			&format!("anilist_season-season_{}", view.season.as_str()),
+		)),
+	);
+	args.insert(Cow::Borrowed("year"), FluentValue::from(view.year));
+
+	USABLE_LOCALES.lookup_with_args(lang_id, "anilist_season-title", &args)
+}
+
+/// The title, then its format, episodes, studios, score and next episode.
+fn entry_lines(index: usize, entry: &SeasonEntry, lang_id: &LanguageIdentifier) -> String {
+	let title = match &entry.url {
+		Some(url) => format!("[{}]({})", entry.title, url),
+		None => entry.title.clone(),
+	};
+
+	let mut details = Vec::new();
+	if let Some(format) = entry.format {
+		details.push(USABLE_LOCALES.lookup(
+			lang_id,
+			&format!("anilist_season-format_{}", format.as_str()),
+		));
+	}
+	if let Some(episodes) = entry.episodes {
+		let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+		args.insert(Cow::Borrowed("count"), FluentValue::from(episodes));
+		details.push(USABLE_LOCALES.lookup_with_args(lang_id, "anilist_season-episodes", &args));
+	}
+	if !entry.studios.is_empty() {
+		details.push(entry.studios.join(", "));
+	}
+	if let Some(mean_score) = entry.mean_score {
+		details.push(format!("{}%", mean_score));
+	}
+
+	let next = match entry.next_episode {
+		Some((airing_at, episode)) => {
+			let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+			args.insert(Cow::Borrowed("episode"), FluentValue::from(episode));
+			args.insert(
+				Cow::Borrowed("time"),
+				FluentValue::from(format!("<t:{}:R>", airing_at)),
+			);
+			Some(USABLE_LOCALES.lookup_with_args(lang_id, "anilist_season-next", &args))
+		},
+		None if entry.upcoming => Some(USABLE_LOCALES.lookup(lang_id, "anilist_season-upcoming")),
+		None => None,
+	};
+	details.extend(next);
+
+	format!("{}. **{}**\n{}", index + 1, title, details.join(" · "))
+}
+
+/// The filters in use, then one entry per show.
+pub fn season_description(
+	view: &SeasonView, season: &SeasonPage, lang_id: &LanguageIdentifier,
+) -> String {
+	let mut blocks = Vec::new();
+
+	let filters: Vec<String> = view
+		.format
+		.map(|format| {
+			USABLE_LOCALES.lookup(
+				lang_id,
+				&format!("anilist_season-format_{}", format.as_str()),
+			)
+		})
+		.into_iter()
+		.chain(view.genre.clone())
+		.chain(view.studio.clone())
+		.collect();
+	if !filters.is_empty() {
+		let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+		args.insert(
+			Cow::Borrowed("filters"),
+			FluentValue::from(filters.join(", ")),
+		);
+		blocks.push(USABLE_LOCALES.lookup_with_args(lang_id, "anilist_season-filters", &args));
+	}
+
+	if season.entries.is_empty() {
+		blocks.push(USABLE_LOCALES.lookup(lang_id, "anilist_season-empty"));
+	} else {
+		let offset = season.page * SEASON_PAGE_SIZE;
+		blocks.extend(
+			season
+				.entries
+				.iter()
+				.enumerate()
+				.map(|(index, entry)| entry_lines(offset + index, entry, lang_id)),
+		);
+	}
+
+	blocks.join("\n\n")
+}
+
+pub fn season_footer(season: &SeasonPage, lang_id: &LanguageIdentifier) -> String {
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(Cow::Borrowed("page"), FluentValue::from(season.page + 1));
+	args.insert(
+		Cow::Borrowed("total"),
+		FluentValue::from(season.total_pages),
+	);
+	args.insert(Cow::Borrowed("count"), FluentValue::from(season.total));
+
+	USABLE_LOCALES.lookup_with_args(lang_id, "anilist_season-page", &args)
+}
+
+/// Previous/next buttons, omitted entirely when everything fits on one page.
+pub fn season_buttons(
+	view: &SeasonView, page: usize, total_pages: usize, lang_id: &LanguageIdentifier,
+) -> Vec<CreateComponent<'static>> {
+	page_buttons(
+		SEASON_PREFIX,
+		Some(&view.encode()),
+		page as u64,
+		total_pages as u64,
+		USABLE_LOCALES.lookup(lang_id, "anilist_season-previous"),
+		USABLE_LOCALES.lookup(lang_id, "anilist_season-next_page"),
+	)
+}
+
+pub async fn update(
+	ctx: &SerenityContext, component_interaction: &ComponentInteraction, view: SeasonView,
+	page: usize, db_connection: Arc<DatabaseConnection>,
+) -> Result<()> {
+	let bot_data = ctx.data::<BotData>().clone();
+
+	let guild_id = component_interaction
+		.guild_id
+		.map(|guild_id| guild_id.to_string())
+		.unwrap_or_else(|| String::from("0"));
+	let lang_id = get_language_identifier(guild_id, db_connection).await;
+
+	let entries = fetch_season(&view, bot_data.anilist_cache.clone()).await?;
+	let season = season_page(entries, page);
+
+	let embed = CreateEmbed::new()
+		.timestamp(Timestamp::now())
+		.color(COLOR)
+		.title(season_title(&view, &lang_id))
+		.description(season_description(&view, &season, &lang_id))
+		.footer(CreateEmbedFooter::new(season_footer(&season, &lang_id)));
+
+	let message = CreateInteractionResponseMessage::new()
+		.embed(embed)
+		.components(season_buttons(
+			&view,
+			season.page,
+			season.total_pages,
+			&lang_id,
+		));
+
+	component_interaction
+		.create_response(&ctx.http, CreateInteractionResponse::UpdateMessage(message))
+		.await?;
+
+	Ok(())
+}
+
+pub struct SeasonHandler;
+
+impl ComponentHandler for SeasonHandler {
+	fn prefix(&self) -> &'static str {
+		SEASON_PREFIX
+	}
+
+	fn handle<'a>(
+		&'a self, ctx: &'a SerenityContext, interaction: &'a ComponentInteraction,
+		db: Arc<DatabaseConnection>,
+	) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+		Box::pin(async move {
+			let custom_id = &interaction.data.custom_id[SEASON_PREFIX.len()..];
+			let (view, page) = custom_id
+				.rsplit_once('_')
+				.ok_or(anyhow!("Malformed season id {}", custom_id))?;
+			let view = SeasonView::decode(view).ok_or(anyhow!("Unknown season {}", view))?;
+
+			update(ctx, interaction, view, page.parse()?, db).await
+		})
+	}
+}
+
+inventory::submit! { &SeasonHandler as &dyn ComponentHandler }
@@ -0,0 +1,94 @@
+//! Hands out the personal AniList login link. The link is only ever sent
+//! ephemerally to whoever pressed the button, so nobody can link their AniList
+//! account to someone else's Discord account.
+use anyhow::{anyhow, Result};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine as _;
+use fluent_templates::Loader;
+use sea_orm::DatabaseConnection;
+use serenity::all::{
+	ComponentInteraction, Context as SerenityContext, CreateActionRow, CreateButton,
+	CreateComponent, CreateInteractionResponse, CreateInteractionResponseMessage,
+};
+use shared::anilist::oauth::{create_link_token, login_url};
+use shared::localization::{get_language_identifier, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::components::handler::ComponentHandler;
+use crate::event_handler::BotData;
+use crate::handlers::user_db::add_user_data_to_db;
+
+pub const VERIFY_BUTTON: &str = "anilist_verify";
+
+async fn send_link(
+	ctx: &SerenityContext, interaction: &ComponentInteraction, db: Arc<DatabaseConnection>,
+) -> Result<()> {
+	let bot_data = ctx.data::<BotData>().clone();
+	let guild_id = interaction
+		.guild_id
+		.map(|id| id.to_string())
+		.unwrap_or("0".to_string());
+	let lang_id = get_language_identifier(guild_id, db.clone()).await;
+
+	let Some(anilist_config) = bot_data.config.api.anilist.as_ref() else {
+		interaction
+			.create_response(
+				&ctx.http,
+				CreateInteractionResponse::Message(
+					CreateInteractionResponseMessage::new()
+						.content(USABLE_LOCALES.lookup(&lang_id, "anilist_user_verify-disabled"))
+						.ephemeral(true),
+				),
+			)
+			.await?;
+		return Ok(());
+	};
+
+	// The api server writes registered_user and anilist_token, both reference
+	// user_data
+	add_user_data_to_db(interaction.user.clone(), db).await?;
+
+	let secret = STANDARD
+		.decode(&bot_data.config.api.oauth.jwt_secret)
+		.map_err(|e| anyhow!("Invalid base64 JWT secret: {}", e))?;
+	let token = create_link_token(&secret, &interaction.user.id.to_string())?;
+
+	let button = CreateButton::new_link(login_url(anilist_config, &token))
+		.label(USABLE_LOCALES.lookup(&lang_id, "anilist_user_verify-open"));
+
+	interaction
+		.create_response(
+			&ctx.http,
+			CreateInteractionResponse::Message(
+				CreateInteractionResponseMessage::new()
+					.content(USABLE_LOCALES.lookup(&lang_id, "anilist_user_verify-link"))
+					.components(vec![CreateComponent::ActionRow(CreateActionRow::Buttons(
+						Cow::Owned(vec![button]),
+					))])
+					.ephemeral(true),
+			),
+		)
+		.await?;
+
+	Ok(())
+}
+
+pub struct AnilistVerifyHandler;
+
+impl ComponentHandler for AnilistVerifyHandler {
+	fn prefix(&self) -> &'static str {
+		VERIFY_BUTTON
+	}
+
+	fn handle<'a>(
+		&'a self, ctx: &'a SerenityContext, interaction: &'a ComponentInteraction,
+		db: Arc<DatabaseConnection>,
+	) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+		Box::pin(async move { send_link(ctx, interaction, db).await })
+	}
+}
+
+inventory::submit! { &AnilistVerifyHandler as &dyn ComponentHandler }
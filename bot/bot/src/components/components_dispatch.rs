@@ -1,10 +1,10 @@
 use anyhow::Result;
 use sea_orm::DatabaseConnection;
-use serenity::all::{ComponentInteraction, Context as SerenityContext};
+use serenity::all::{ComponentInteraction, Context as SerenityContext, ModalInteraction};
 use std::sync::Arc;
 use tracing::trace;
 
-use crate::components::handler::ComponentHandler;
+use crate::components::handler::{ComponentHandler, ModalHandler};
 
 pub async fn components_dispatching(
 	ctx: SerenityContext, component_interaction: ComponentInteraction,
@@ -24,3 +24,22 @@ pub async fn components_dispatching(
 	trace!("does not exist.");
 	Ok(())
 }
+
+pub async fn modals_dispatching(
+	ctx: SerenityContext, modal_interaction: ModalInteraction,
+	db_connection: Arc<DatabaseConnection>,
+) -> Result<()> {
+	let custom_id = modal_interaction.data.custom_id.as_str();
+
+	for handler in inventory::iter::<&'static dyn ModalHandler> {
+		if custom_id.starts_with(handler.prefix()) {
+			handler
+				.handle(&ctx, &modal_interaction, db_connection)
+				.await?;
+			return Ok(());
+		}
+	}
+
+	trace!("Modal {} does not exist.", custom_id);
+	Ok(())
+}
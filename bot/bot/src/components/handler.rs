@@ -1,6 +1,6 @@
 use anyhow::Result;
 use sea_orm::DatabaseConnection;
-use serenity::all::{ComponentInteraction, Context as SerenityContext};
+use serenity::all::{ComponentInteraction, Context as SerenityContext, ModalInteraction};
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
@@ -40,3 +40,20 @@ pub trait ComponentHandler: Send + Sync + 'static {
 }
 
 inventory::collect!(&'static dyn ComponentHandler);
+
+/// Trait for handling modal submissions, the forms components open.
+///
+/// Works like [`ComponentHandler`]: implement it on a unit struct and register
+/// it via `inventory::submit!`. The modal's custom_id is matched by prefix.
+pub trait ModalHandler: Send + Sync + 'static {
+	/// The custom_id prefix this handler matches.
+	fn prefix(&self) -> &'static str;
+
+	/// Handle the modal submission asynchronously.
+	fn handle<'a>(
+		&'a self, ctx: &'a SerenityContext, interaction: &'a ModalInteraction,
+		db: Arc<DatabaseConnection>,
+	) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>;
+}
+
+inventory::collect!(&'static dyn ModalHandler);
@@ -0,0 +1,149 @@
+use crate::components::handler::ComponentHandler;
+use crate::components::pagination::page_buttons;
+use crate::constant::COLOR;
+use crate::event_handler::BotData;
+use crate::leveling::curve::LevelCurve;
+use crate::leveling::get_level_config;
+use crate::leveling::leaderboard::{leaderboard_page, LeaderboardEntry};
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use fluent_templates::Loader;
+use sea_orm::DatabaseConnection;
+use serenity::all::{
+	ComponentInteraction, Context as SerenityContext, CreateAttachment, CreateComponent,
+	CreateEmbed, CreateEmbedFooter, CreateInteractionResponse, CreateInteractionResponseMessage,
+	Timestamp,
+};
+use shared::localization::{get_language_identifier, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use unic_langid::LanguageIdentifier;
+
+pub const LEADERBOARD_PREFIX: &str = "levels_lb_";
+
+pub fn leaderboard_filename(page: u64) -> String {
+	format!("leaderboard_{}.png", page)
+}
+
+/// One line per entry, the image only carries ranks, levels and XP.
+pub fn leaderboard_description(
+	entries: &[LeaderboardEntry], lang_id: &LanguageIdentifier,
+) -> String {
+	if entries.is_empty() {
+		return USABLE_LOCALES.lookup(lang_id, "levels_leaderboard-empty");
+	}
+
+	entries
+		.iter()
+		.map(|entry| {
+			let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+			args.insert(Cow::Borrowed("rank"), FluentValue::from(entry.rank));
+			args.insert(
+				Cow::Borrowed("user"),
+				FluentValue::from(format!("<@{}>", entry.user_id)),
+			);
+			args.insert(Cow::Borrowed("level"), FluentValue::from(entry.level));
+			args.insert(Cow::Borrowed("xp"), FluentValue::from(entry.xp));
+			USABLE_LOCALES.lookup_with_args(lang_id, "levels_leaderboard-entry", &args)
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+pub fn leaderboard_footer(page: u64, total_pages: u64, lang_id: &LanguageIdentifier) -> String {
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(Cow::Borrowed("page"), FluentValue::from(page + 1));
+	args.insert(Cow::Borrowed("total"), FluentValue::from(total_pages));
+
+	USABLE_LOCALES.lookup_with_args(lang_id, "levels_leaderboard-page", &args)
+}
+
+pub fn leaderboard_buttons(
+	page: u64, total_pages: u64, lang_id: &LanguageIdentifier,
+) -> Vec<CreateComponent<'static>> {
+	page_buttons(
+		LEADERBOARD_PREFIX,
+		None,
+		page,
+		total_pages,
+		USABLE_LOCALES.lookup(lang_id, "levels_leaderboard-previous"),
+		USABLE_LOCALES.lookup(lang_id, "levels_leaderboard-next"),
+	)
+}
+
+pub async fn update(
+	ctx: &SerenityContext, component_interaction: &ComponentInteraction, page: u64,
+	db_connection: Arc<DatabaseConnection>,
+) -> Result<()> {
+	let bot_data = ctx.data::<BotData>().clone();
+	let guild_id = component_interaction
+		.guild_id
+		.ok_or(anyhow!("Guild ID not found"))?
+		.to_string();
+
+	let config = get_level_config(guild_id.clone(), &db_connection).await?;
+	let curve = LevelCurve::from(&config);
+	let viewer_id = component_interaction.user.id.to_string();
+	let leaderboard = leaderboard_page(&bot_data, &guild_id, &viewer_id, page, &curve).await?;
+
+	let lang_id = get_language_identifier(guild_id, db_connection).await;
+	let filename = leaderboard_filename(leaderboard.page);
+
+	let embed = CreateEmbed::new()
+		.timestamp(Timestamp::now())
+		.color(COLOR)
+		.title(USABLE_LOCALES.lookup(&lang_id, "levels_leaderboard-title"))
+		.description(leaderboard_description(&leaderboard.entries, &lang_id))
+		.image(format!("attachment://{}", filename))
+		.footer(CreateEmbedFooter::new(leaderboard_footer(
+			leaderboard.page,
+			leaderboard.total_pages,
+			&lang_id,
+		)));
+
+	let message = CreateInteractionResponseMessage::new()
+		.embed(embed)
+		.files(vec![CreateAttachment::bytes(
+			leaderboard.image.as_ref().clone(),
+			filename,
+		)])
+		.components(leaderboard_buttons(
+			leaderboard.page,
+			leaderboard.total_pages,
+			&lang_id,
+		));
+
+	component_interaction
+		.create_response(&ctx.http, CreateInteractionResponse::UpdateMessage(message))
+		.await?;
+
+	Ok(())
+}
+
+pub struct LevelsLeaderboardHandler;
+
+impl ComponentHandler for LevelsLeaderboardHandler {
+	fn prefix(&self) -> &'static str {
+		LEADERBOARD_PREFIX
+	}
+
+	fn handle<'a>(
+		&'a self, ctx: &'a SerenityContext, interaction: &'a ComponentInteraction,
+		db: Arc<DatabaseConnection>,
+	) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+		Box::pin(async move {
+			let page: u64 = interaction
+				.data
+				.custom_id
+				.split_at(LEADERBOARD_PREFIX.len())
+				.1
+				.parse()?;
+			update(ctx, interaction, page, db).await
+		})
+	}
+}
+
+inventory::submit! { &LevelsLeaderboardHandler as &dyn ComponentHandler }
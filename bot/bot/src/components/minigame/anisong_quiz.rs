@@ -0,0 +1,510 @@
+use crate::achievement::{track, Stat};
+use crate::components::handler::{ComponentHandler, ModalHandler};
+use crate::constant::COLOR;
+use crate::event_handler::BotData;
+use crate::handlers::user_db::add_user_data_to_db;
+use crate::minigame::anisong_quiz::{
+	begin_round, clip_start_secs, close_quiz, load_quiz, random_song, standings, stop_quiz,
+	submit_answer, AnswerOutcome, QuizStatus, CLIP_SECS, PICK_ATTEMPTS, ROUND_SECS,
+};
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use fluent_templates::Loader;
+use lavalink_rs::model::track::TrackData;
+use lavalink_rs::player_context::PlayerContext;
+use lavalink_rs::prelude::{LavalinkClient, TrackLoadData};
+use sea_orm::DatabaseConnection;
+use serenity::all::{
+	ButtonStyle, Component, ComponentInteraction, Context as SerenityContext, CreateActionRow,
+	CreateButton, CreateComponent, CreateEmbed, CreateInputText, CreateInteractionResponse,
+	CreateInteractionResponseMessage, CreateLabel, CreateMessage, CreateModal,
+	CreateModalComponent, EditMessage, GenericChannelId, GuildId, InputTextStyle, LabelComponent,
+	ModalInteraction, Timestamp,
+};
+use shared::database::anisong_quiz;
+use shared::localization::{get_language_identifier, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+use unic_langid::LanguageIdentifier;
+
+/// Button custom ids are `minigame_anisong_answer_{quiz}_{round}` and
+/// `minigame_anisong_stop_{quiz}`.
+pub const ANISONG_PREFIX: &str = "minigame_anisong_";
+/// The answer modal is `minigame_anisong_modal_{quiz}_{round}`.
+pub const ANISONG_MODAL_PREFIX: &str = "minigame_anisong_modal_";
+const ANSWER_INPUT: &str = "answer";
+/// How often the driver checks whether the round was answered.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Pause between the answer of a round and the next clip.
+const ROUND_BREAK: Duration = Duration::from_secs(5);
+
+pub fn quiz_title(lang_id: &LanguageIdentifier) -> String {
+	USABLE_LOCALES.lookup(lang_id, "minigame_anisong_quiz-title")
+}
+
+fn round_embed(quiz: &anisong_quiz::Model, lang_id: &LanguageIdentifier) -> CreateEmbed<'static> {
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(Cow::Borrowed("round"), FluentValue::from(quiz.round));
+	args.insert(Cow::Borrowed("rounds"), FluentValue::from(quiz.rounds));
+	args.insert(Cow::Borrowed("seconds"), FluentValue::from(ROUND_SECS));
+
+	CreateEmbed::new()
+		.timestamp(Timestamp::now())
+		.color(COLOR)
+		.title(USABLE_LOCALES.lookup_with_args(lang_id, "minigame_anisong_quiz-round_title", &args))
+		.description(USABLE_LOCALES.lookup_with_args(
+			lang_id,
+			"minigame_anisong_quiz-round_description",
+			&args,
+		))
+}
+
+fn round_buttons(
+	quiz: &anisong_quiz::Model, lang_id: &LanguageIdentifier,
+) -> Vec<CreateComponent<'static>> {
+	let answer = CreateButton::new(format!(
+		"{}answer_{}_{}",
+		ANISONG_PREFIX, quiz.id, quiz.round
+	))
+	.label(USABLE_LOCALES.lookup(lang_id, "minigame_anisong_quiz-answer"))
+	.style(ButtonStyle::Primary);
+	let stop = CreateButton::new(format!("{}stop_{}", ANISONG_PREFIX, quiz.id))
+		.label(USABLE_LOCALES.lookup(lang_id, "minigame_anisong_quiz-stop"))
+		.style(ButtonStyle::Danger);
+
+	vec![CreateComponent::ActionRow(CreateActionRow::Buttons(
+		Cow::Owned(vec![answer, stop]),
+	))]
+}
+
+/// The round with its song and who named it, if anyone did.
+fn answer_embed(quiz: &anisong_quiz::Model, lang_id: &LanguageIdentifier) -> CreateEmbed<'static> {
+	let description = match &quiz.solved_by {
+		Some(user_id) => {
+			let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+			args.insert(
+				Cow::Borrowed("user"),
+				FluentValue::from(format!("<@{}>", user_id)),
+			);
+			USABLE_LOCALES.lookup_with_args(lang_id, "minigame_anisong_quiz-solved", &args)
+		},
+		None => USABLE_LOCALES.lookup(lang_id, "minigame_anisong_quiz-unsolved"),
+	};
+
+	round_embed(quiz, lang_id)
+		.description(description)
+		.field(
+			USABLE_LOCALES.lookup(lang_id, "minigame_anisong_quiz-anime"),
+			quiz.anime_name.clone().unwrap_or_default(),
+			false,
+		)
+		.field(
+			USABLE_LOCALES.lookup(lang_id, "minigame_anisong_quiz-song"),
+			format!(
+				"{} · {}",
+				quiz.song_type.clone().unwrap_or_default(),
+				quiz.song_name.clone().unwrap_or_default()
+			),
+			false,
+		)
+}
+
+async fn standings_embed(
+	db_connection: &DatabaseConnection, quiz: &anisong_quiz::Model, lang_id: &LanguageIdentifier,
+) -> Result<CreateEmbed<'static>> {
+	let players = standings(db_connection, &quiz.id).await?;
+
+	let description = if players.is_empty() {
+		USABLE_LOCALES.lookup(lang_id, "minigame_anisong_quiz-standings_empty")
+	} else {
+		players
+			.iter()
+			.enumerate()
+			.map(|(index, player)| {
+				let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+				args.insert(Cow::Borrowed("rank"), FluentValue::from(index + 1));
+				args.insert(
+					Cow::Borrowed("user"),
+					FluentValue::from(format!("<@{}>", player.user_id)),
+				);
+				args.insert(Cow::Borrowed("points"), FluentValue::from(player.points));
+				USABLE_LOCALES.lookup_with_args(lang_id, "minigame_anisong_quiz-standing", &args)
+			})
+			.collect::<Vec<_>>()
+			.join("\n")
+	};
+
+	Ok(CreateEmbed::new()
+		.timestamp(Timestamp::now())
+		.color(COLOR)
+		.title(USABLE_LOCALES.lookup(lang_id, "minigame_anisong_quiz-standings_title"))
+		.description(description))
+}
+
+/// A song from `anime_song` Lavalink could load, with its clip already
+/// opened as the quiz's next round.
+async fn next_round(
+	db_connection: &DatabaseConnection, lava_client: &LavalinkClient, guild_id: GuildId,
+	quiz: &anisong_quiz::Model, round: i32,
+) -> Result<Option<(anisong_quiz::Model, TrackData)>> {
+	for _ in 0..PICK_ATTEMPTS {
+		let Some(song) = random_song(db_connection).await? else {
+			return Ok(None);
+		};
+
+		let loaded = lava_client
+			.load_tracks(
+				lavalink_rs::model::GuildId::from(guild_id.get()),
+				&song.audio,
+			)
+			.await?;
+		if let Some(TrackLoadData::Track(track)) = loaded.data {
+			let quiz = begin_round(db_connection, quiz, round, &song).await?;
+			return Ok(Some((quiz, track)));
+		}
+
+		warn!(song = %song.ann_song_id, "Anisong quiz could not load a song");
+	}
+
+	Ok(None)
+}
+
+/// Play one round and wait for it to be answered, to time out or for the
+/// quiz to be stopped. Returns the quiz as the round left it.
+async fn play_round(
+	ctx: &SerenityContext, db_connection: &DatabaseConnection, player: &PlayerContext,
+	channel: GenericChannelId, quiz: &anisong_quiz::Model, track: &TrackData,
+	lang_id: &LanguageIdentifier,
+) -> Result<anisong_quiz::Model> {
+	player.play_now(track).await?;
+	player
+		.set_position(Duration::from_secs(clip_start_secs(track.info.length)))
+		.await?;
+
+	let message = channel
+		.send_message(
+			&ctx.http,
+			CreateMessage::new()
+				.embed(round_embed(quiz, lang_id))
+				.components(round_buttons(quiz, lang_id)),
+		)
+		.await?;
+
+	let mut clip_playing = true;
+	let mut current = quiz.clone();
+	for tick in 1.. {
+		tokio::time::sleep(POLL_INTERVAL).await;
+
+		if clip_playing && tick >= CLIP_SECS {
+			player.stop_now().await?;
+			clip_playing = false;
+		}
+
+		current = load_quiz(db_connection, &quiz.id)
+			.await?
+			.ok_or(anyhow!("Anisong quiz {} not found", quiz.id))?;
+		if current.solved_by.is_some()
+			|| QuizStatus::parse(&current.status) != QuizStatus::Running
+			|| tick as i64 >= ROUND_SECS
+		{
+			break;
+		}
+	}
+
+	if clip_playing {
+		player.stop_now().await?;
+	}
+
+	channel
+		.edit_message(
+			&ctx.http,
+			message.id,
+			EditMessage::new()
+				.embed(answer_embed(&current, lang_id))
+				.components(vec![]),
+		)
+		.await?;
+
+	Ok(current)
+}
+
+async fn play_quiz(
+	ctx: &SerenityContext, bot_data: &BotData, quiz: &anisong_quiz::Model,
+	lang_id: &LanguageIdentifier,
+) -> Result<()> {
+	let db_connection = &bot_data.db_connection;
+	let lava_client = bot_data
+		.lavalink
+		.read()
+		.await
+		.clone()
+		.ok_or(anyhow!("Lavalink is disabled"))?;
+	let guild_id = GuildId::new(quiz.guild_id.parse()?);
+	let channel = GenericChannelId::new(quiz.channel_id.parse()?);
+
+	for round in 1..=quiz.rounds {
+		let Some((quiz, track)) =
+			next_round(db_connection, &lava_client, guild_id, quiz, round).await?
+		else {
+			let embed = CreateEmbed::new()
+				.timestamp(Timestamp::now())
+				.color(COLOR)
+				.title(quiz_title(lang_id))
+				.description(USABLE_LOCALES.lookup(lang_id, "minigame_anisong_quiz-no_song"));
+			channel
+				.send_message(&ctx.http, CreateMessage::new().embed(embed))
+				.await?;
+			break;
+		};
+
+		let player = lava_client
+			.get_player_context(lavalink_rs::model::GuildId::from(guild_id.get()))
+			.ok_or(anyhow!("Anisong quiz lost its voice connection"))?;
+		let quiz = play_round(ctx, db_connection, &player, channel, &quiz, &track, lang_id).await?;
+		if QuizStatus::parse(&quiz.status) != QuizStatus::Running || round == quiz.rounds {
+			break;
+		}
+
+		tokio::time::sleep(ROUND_BREAK).await;
+	}
+
+	Ok(())
+}
+
+/// Run the quiz to its end in the background, then post the standings.
+/// `leave_after` disconnects from voice when the bot joined for the quiz.
+pub async fn run_quiz(
+	ctx: SerenityContext, bot_data: Arc<BotData>, quiz: anisong_quiz::Model,
+	lang_id: LanguageIdentifier, leave_after: bool,
+) {
+	if let Err(e) = play_quiz(&ctx, &bot_data, &quiz, &lang_id).await {
+		warn!(quiz_id = %quiz.id, ?e, "Anisong quiz ended on an error");
+	}
+
+	let db_connection = &bot_data.db_connection;
+	if let Err(e) = close_quiz(&**db_connection, &quiz.id, QuizStatus::Finished).await {
+		warn!(quiz_id = %quiz.id, ?e, "Failed to close the anisong quiz");
+	}
+
+	match (
+		standings_embed(db_connection, &quiz, &lang_id).await,
+		quiz.channel_id.parse::<u64>(),
+	) {
+		(Ok(embed), Ok(channel_id)) => {
+			if let Err(e) = GenericChannelId::new(channel_id)
+				.send_message(&ctx.http, CreateMessage::new().embed(embed))
+				.await
+			{
+				warn!(quiz_id = %quiz.id, ?e, "Failed to post the anisong quiz standings");
+			}
+		},
+		(Err(e), _) => warn!(quiz_id = %quiz.id, ?e, "Failed to load the anisong quiz standings"),
+		(_, Err(e)) => warn!(quiz_id = %quiz.id, ?e, "Anisong quiz channel id is malformed"),
+	}
+
+	if leave_after {
+		if let Err(e) = leave_voice(&bot_data, &quiz.guild_id).await {
+			warn!(quiz_id = %quiz.id, ?e, "Failed to leave voice after the anisong quiz");
+		}
+	}
+
+	info!(quiz_id = %quiz.id, guild_id = %quiz.guild_id, "Anisong quiz over");
+}
+
+async fn leave_voice(bot_data: &BotData, guild_id: &str) -> Result<()> {
+	let guild_id = GuildId::new(guild_id.parse()?);
+	if let Some(lava_client) = bot_data.lavalink.read().await.clone() {
+		lava_client
+			.delete_player(lavalink_rs::model::GuildId::from(guild_id.get()))
+			.await?;
+	}
+
+	let manager = bot_data.manager.clone();
+	if manager.get(guild_id).is_some() {
+		manager.remove(guild_id).await?;
+	}
+
+	Ok(())
+}
+
+fn ephemeral(key: &str, lang_id: &LanguageIdentifier) -> CreateInteractionResponse<'static> {
+	CreateInteractionResponse::Message(
+		CreateInteractionResponseMessage::new()
+			.content(USABLE_LOCALES.lookup(lang_id, key))
+			.ephemeral(true),
+	)
+}
+
+fn answer_modal(quiz_id: &str, round: &str, lang_id: &LanguageIdentifier) -> CreateModal<'static> {
+	let input = CreateInputText::new(InputTextStyle::Short, ANSWER_INPUT)
+		.placeholder(USABLE_LOCALES.lookup(lang_id, "minigame_anisong_quiz-answer_placeholder"))
+		.max_length(200)
+		.required(true);
+
+	CreateModal::new(
+		format!("{}{}_{}", ANISONG_MODAL_PREFIX, quiz_id, round),
+		USABLE_LOCALES.lookup(lang_id, "minigame_anisong_quiz-title"),
+	)
+	.components(vec![CreateModalComponent::Label(CreateLabel::input_text(
+		USABLE_LOCALES.lookup(lang_id, "minigame_anisong_quiz-answer_label"),
+		input,
+	))])
+}
+
+pub async fn update(
+	ctx: &SerenityContext, interaction: &ComponentInteraction,
+	db_connection: Arc<DatabaseConnection>,
+) -> Result<()> {
+	let guild_id = interaction
+		.guild_id
+		.ok_or(anyhow!("Guild ID not found"))?
+		.to_string();
+	let lang_id = get_language_identifier(guild_id, db_connection.clone()).await;
+
+	let (action, rest) = interaction.data.custom_id[ANISONG_PREFIX.len()..]
+		.split_once('_')
+		.ok_or(anyhow!("Malformed anisong custom id"))?;
+
+	let response = match action {
+		"answer" => {
+			let (quiz_id, round) = rest
+				.rsplit_once('_')
+				.ok_or(anyhow!("Malformed anisong answer custom id"))?;
+			CreateInteractionResponse::Modal(answer_modal(quiz_id, round, &lang_id))
+		},
+		"stop" => {
+			let quiz = load_quiz(&*db_connection, rest)
+				.await?
+				.ok_or(anyhow!("Anisong quiz {} not found", rest))?;
+			let user_id = interaction.user.id.to_string();
+			if quiz.started_by != user_id {
+				ephemeral("minigame_anisong_quiz-not_starter", &lang_id)
+			} else if stop_quiz(&*db_connection, &quiz, &user_id).await? {
+				ephemeral("minigame_anisong_quiz-stopped", &lang_id)
+			} else {
+				ephemeral("minigame_anisong_quiz-closed", &lang_id)
+			}
+		},
+		_ => return Err(anyhow!("Unknown anisong action {}", action)),
+	};
+
+	interaction.create_response(&ctx.http, response).await?;
+
+	Ok(())
+}
+
+/// The text typed in the answer modal.
+fn modal_answer(interaction: &ModalInteraction) -> Option<String> {
+	interaction
+		.data
+		.components
+		.iter()
+		.find_map(|component| match component {
+			Component::Label(label) => match &label.component {
+				LabelComponent::InputText(input) if input.custom_id == ANSWER_INPUT => {
+					input.value.as_ref().map(|value| value.to_string())
+				},
+				_ => None,
+			},
+			_ => None,
+		})
+}
+
+pub async fn submit(
+	ctx: &SerenityContext, interaction: &ModalInteraction, db_connection: Arc<DatabaseConnection>,
+) -> Result<()> {
+	let guild_id = interaction
+		.guild_id
+		.ok_or(anyhow!("Guild ID not found"))?
+		.to_string();
+	let lang_id = get_language_identifier(guild_id, db_connection.clone()).await;
+
+	let (quiz_id, round) = interaction.data.custom_id[ANISONG_MODAL_PREFIX.len()..]
+		.rsplit_once('_')
+		.ok_or(anyhow!("Malformed anisong modal custom id"))?;
+	let guess = modal_answer(interaction).unwrap_or_default();
+
+	// leader_board references user_data
+	add_user_data_to_db(interaction.user.clone(), db_connection.clone()).await?;
+
+	let user_id = interaction.user.id.to_string();
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	// The driver reveals the answer in the round message
+	let outcome = submit_answer(&db_connection, quiz_id, round.parse()?, &user_id, &guess).await?;
+	let correct = matches!(outcome, AnswerOutcome::Correct { .. });
+	let content = match outcome {
+		AnswerOutcome::Correct { points } => {
+			args.insert(Cow::Borrowed("points"), FluentValue::from(points));
+			USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_anisong_quiz-correct", &args)
+		},
+		AnswerOutcome::Wrong => {
+			args.insert(Cow::Borrowed("guess"), FluentValue::from(guess));
+			USABLE_LOCALES.lookup_with_args(&lang_id, "minigame_anisong_quiz-wrong", &args)
+		},
+		AnswerOutcome::Closed => USABLE_LOCALES.lookup(&lang_id, "minigame_anisong_quiz-closed"),
+	};
+
+	interaction
+		.create_response(
+			&ctx.http,
+			CreateInteractionResponse::Message(
+				CreateInteractionResponseMessage::new()
+					.content(content)
+					.ephemeral(true),
+			),
+		)
+		.await?;
+
+	if correct {
+		track(
+			ctx,
+			interaction.user.id,
+			interaction.guild_id,
+			Some(interaction.channel_id),
+			Stat::TriviaAnswered,
+			1,
+		)
+		.await;
+	}
+
+	Ok(())
+}
+
+pub struct MinigameAnisongHandler;
+
+impl ComponentHandler for MinigameAnisongHandler {
+	fn prefix(&self) -> &'static str {
+		ANISONG_PREFIX
+	}
+
+	fn handle<'a>(
+		&'a self, ctx: &'a SerenityContext, interaction: &'a ComponentInteraction,
+		db: Arc<DatabaseConnection>,
+	) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+		Box::pin(async move { update(ctx, interaction, db).await })
+	}
+}
+
+inventory::submit! { &MinigameAnisongHandler as &dyn ComponentHandler }
+
+pub struct MinigameAnisongModalHandler;
+
+impl ModalHandler for MinigameAnisongModalHandler {
+	fn prefix(&self) -> &'static str {
+		ANISONG_MODAL_PREFIX
+	}
+
+	fn handle<'a>(
+		&'a self, ctx: &'a SerenityContext, interaction: &'a ModalInteraction,
+		db: Arc<DatabaseConnection>,
+	) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+		Box::pin(async move { submit(ctx, interaction, db).await })
+	}
+}
+
+inventory::submit! { &MinigameAnisongModalHandler as &dyn ModalHandler }
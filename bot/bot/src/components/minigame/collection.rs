@@ -0,0 +1,298 @@
+use crate::components::handler::ComponentHandler;
+use crate::components::pagination::page_buttons;
+use crate::constant::COLOR;
+use crate::minigame::gacha::{collection_page, CollectionPage};
+use crate::minigame::rarity_stars;
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use fluent_templates::Loader;
+use sea_orm::{DatabaseConnection, EntityTrait};
+use serenity::all::{
+	ComponentInteraction, ComponentInteractionDataKind, Context as SerenityContext,
+	CreateActionRow, CreateComponent, CreateEmbed, CreateEmbedFooter, CreateInteractionResponse,
+	CreateInteractionResponseMessage, CreateSelectMenu, CreateSelectMenuKind,
+	CreateSelectMenuOption, Timestamp,
+};
+use shared::database::character_pool;
+use shared::database::prelude::{CharacterPool, UserCharacter};
+use shared::localization::{get_language_identifier, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use unic_langid::LanguageIdentifier;
+
+/// Custom ids are `minigame_collection_{page|view}_{owner}_{page}`.
+pub const COLLECTION_PREFIX: &str = "minigame_collection_";
+
+/// Name as the `character` command shows it, romanized then native.
+pub fn card_title(character: &character_pool::Model) -> String {
+	match &character.native_name {
+		Some(native) => format!("{}/{}", character.name, native),
+		None => character.name.clone(),
+	}
+}
+
+pub fn card_fields(
+	character: &character_pool::Model, lang_id: &LanguageIdentifier,
+) -> Vec<(String, String, bool)> {
+	let mut fields = vec![(
+		USABLE_LOCALES.lookup(lang_id, "minigame_gacha-rarity"),
+		rarity_stars(character.rarity),
+		true,
+	)];
+
+	if let Some(series) = &character.series {
+		fields.push((
+			USABLE_LOCALES.lookup(lang_id, "minigame_gacha-series"),
+			series.clone(),
+			true,
+		));
+	}
+
+	fields.push((
+		USABLE_LOCALES.lookup(lang_id, "minigame_gacha-favourites"),
+		character.favourites.to_string(),
+		true,
+	));
+
+	fields
+}
+
+pub fn collection_title(owner_name: &str, lang_id: &LanguageIdentifier) -> String {
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("user"),
+		FluentValue::from(owner_name.to_string()),
+	);
+
+	USABLE_LOCALES.lookup_with_args(lang_id, "minigame_gacha-collection_title", &args)
+}
+
+pub fn collection_description(collection: &CollectionPage, lang_id: &LanguageIdentifier) -> String {
+	if collection.cards.is_empty() {
+		return USABLE_LOCALES.lookup(lang_id, "minigame_gacha-collection_empty");
+	}
+
+	collection
+		.cards
+		.iter()
+		.map(|(_, character)| {
+			let line = format!("{} **{}**", rarity_stars(character.rarity), character.name);
+			match &character.series {
+				Some(series) => format!("{} · {}", line, series),
+				None => line,
+			}
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+pub fn collection_footer(collection: &CollectionPage, lang_id: &LanguageIdentifier) -> String {
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("page"),
+		FluentValue::from(collection.page + 1),
+	);
+	args.insert(
+		Cow::Borrowed("total"),
+		FluentValue::from(collection.total_pages),
+	);
+	args.insert(Cow::Borrowed("count"), FluentValue::from(collection.total));
+
+	USABLE_LOCALES.lookup_with_args(lang_id, "minigame_gacha-collection_page", &args)
+}
+
+/// A picker to view one card of the page, then previous/next buttons when
+/// the collection spans several pages.
+pub fn collection_components(
+	owner_id: &str, collection: &CollectionPage, lang_id: &LanguageIdentifier,
+) -> Vec<CreateComponent<'static>> {
+	let mut components = Vec::new();
+
+	if !collection.cards.is_empty() {
+		let options: Vec<CreateSelectMenuOption> = collection
+			.cards
+			.iter()
+			.map(|(card, character)| {
+				CreateSelectMenuOption::new(
+					format!("{} {}", character.name, rarity_stars(character.rarity)),
+					card.id.clone(),
+				)
+			})
+			.collect();
+
+		let menu = CreateSelectMenu::new(
+			format!("{}view_{}_{}", COLLECTION_PREFIX, owner_id, collection.page),
+			CreateSelectMenuKind::String {
+				options: Cow::Owned(options),
+			},
+		)
+		.placeholder(USABLE_LOCALES.lookup(lang_id, "minigame_gacha-view_card"));
+		components.push(CreateComponent::ActionRow(CreateActionRow::SelectMenu(
+			menu,
+		)));
+	}
+
+	components.extend(page_buttons(
+		&format!("{}page_", COLLECTION_PREFIX),
+		Some(owner_id),
+		collection.page,
+		collection.total_pages,
+		USABLE_LOCALES.lookup(lang_id, "minigame_gacha-previous"),
+		USABLE_LOCALES.lookup(lang_id, "minigame_gacha-next"),
+	));
+
+	components
+}
+
+async fn update_page(
+	ctx: &SerenityContext, interaction: &ComponentInteraction, guild_id: &str, owner_id: &str,
+	page: u64, db_connection: &DatabaseConnection, lang_id: &LanguageIdentifier,
+) -> Result<()> {
+	let collection = collection_page(db_connection, guild_id, owner_id, page).await?;
+
+	// Keep the title the command drew, it has the owner's name
+	let title = interaction
+		.message
+		.embeds
+		.first()
+		.and_then(|embed| embed.title.clone())
+		.map(|title| title.to_string())
+		.unwrap_or_default();
+
+	let embed = CreateEmbed::new()
+		.timestamp(Timestamp::now())
+		.color(COLOR)
+		.title(title)
+		.description(collection_description(&collection, lang_id))
+		.footer(CreateEmbedFooter::new(collection_footer(
+			&collection,
+			lang_id,
+		)));
+
+	let message = CreateInteractionResponseMessage::new()
+		.embed(embed)
+		.components(collection_components(owner_id, &collection, lang_id));
+
+	interaction
+		.create_response(&ctx.http, CreateInteractionResponse::UpdateMessage(message))
+		.await?;
+
+	Ok(())
+}
+
+/// Show one card privately, in the style of the `character` command.
+async fn view_card(
+	ctx: &SerenityContext, interaction: &ComponentInteraction, guild_id: &str,
+	db_connection: &DatabaseConnection, lang_id: &LanguageIdentifier,
+) -> Result<()> {
+	let card_id = match &interaction.data.kind {
+		ComponentInteractionDataKind::StringSelect { values } => values
+			.first()
+			.map(|value| value.to_string())
+			.ok_or(anyhow!("No card picked"))?,
+		_ => return Err(anyhow!("Card picker is not a string select")),
+	};
+
+	let card = UserCharacter::find_by_id(card_id.clone())
+		.find_also_related(CharacterPool)
+		.one(db_connection)
+		.await?
+		.filter(|(card, _)| card.server_id == guild_id);
+
+	let message = match card {
+		Some((card, Some(character))) => {
+			let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+			args.insert(
+				Cow::Borrowed("user"),
+				FluentValue::from(format!("<@{}>", card.user_id)),
+			);
+			args.insert(
+				Cow::Borrowed("obtained"),
+				FluentValue::from(format!("<t:{}:D>", card.obtained_at.and_utc().timestamp())),
+			);
+
+			let mut embed = CreateEmbed::new()
+				.timestamp(Timestamp::now())
+				.color(COLOR)
+				.title(card_title(&character))
+				.url(character.site_url.clone())
+				.description(USABLE_LOCALES.lookup_with_args(
+					lang_id,
+					"minigame_gacha-owned_by",
+					&args,
+				))
+				.fields(card_fields(&character, lang_id));
+			if let Some(image) = character.image_url {
+				embed = embed.image(image);
+			}
+
+			CreateInteractionResponseMessage::new().embed(embed)
+		},
+		// Traded away since the page was drawn
+		_ => CreateInteractionResponseMessage::new()
+			.content(USABLE_LOCALES.lookup(lang_id, "minigame_gacha-card_gone")),
+	};
+
+	interaction
+		.create_response(
+			&ctx.http,
+			CreateInteractionResponse::Message(message.ephemeral(true)),
+		)
+		.await?;
+
+	Ok(())
+}
+
+pub async fn update(
+	ctx: &SerenityContext, interaction: &ComponentInteraction,
+	db_connection: Arc<DatabaseConnection>,
+) -> Result<()> {
+	let guild_id = interaction
+		.guild_id
+		.ok_or(anyhow!("Guild ID not found"))?
+		.to_string();
+	let lang_id = get_language_identifier(guild_id.clone(), db_connection.clone()).await;
+
+	let mut parts = interaction.data.custom_id[COLLECTION_PREFIX.len()..].split('_');
+	let (Some(action), Some(owner_id), Some(page)) = (parts.next(), parts.next(), parts.next())
+	else {
+		return Err(anyhow!("Malformed collection custom id"));
+	};
+
+	match action {
+		"page" => {
+			update_page(
+				ctx,
+				interaction,
+				&guild_id,
+				owner_id,
+				page.parse()?,
+				&db_connection,
+				&lang_id,
+			)
+			.await
+		},
+		"view" => view_card(ctx, interaction, &guild_id, &db_connection, &lang_id).await,
+		_ => Err(anyhow!("Unknown collection action {}", action)),
+	}
+}
+
+pub struct MinigameCollectionHandler;
+
+impl ComponentHandler for MinigameCollectionHandler {
+	fn prefix(&self) -> &'static str {
+		COLLECTION_PREFIX
+	}
+
+	fn handle<'a>(
+		&'a self, ctx: &'a SerenityContext, interaction: &'a ComponentInteraction,
+		db: Arc<DatabaseConnection>,
+	) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+		Box::pin(async move { update(ctx, interaction, db).await })
+	}
+}
+
+inventory::submit! { &MinigameCollectionHandler as &dyn ComponentHandler }
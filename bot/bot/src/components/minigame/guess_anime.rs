@@ -0,0 +1,356 @@
+use crate::achievement::{track, Stat};
+use crate::components::handler::{ComponentHandler, ModalHandler};
+use crate::constant::COLOR;
+use crate::event_handler::BotData;
+use crate::handlers::user_db::add_user_data_to_db;
+use crate::minigame::guess_anime::{
+	difficulty, fetch_image, give_up, is_open, render_stage, reveal, round_points, submit_guess,
+	GuessOutcome, REVEAL_STAGES,
+};
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use fluent_templates::Loader;
+use sea_orm::{DatabaseConnection, EntityTrait};
+use serenity::all::{
+	ButtonStyle, Component, ComponentInteraction, Context as SerenityContext, CreateActionRow,
+	CreateAttachment, CreateButton, CreateComponent, CreateEmbed, CreateInputText,
+	CreateInteractionResponse, CreateInteractionResponseMessage, CreateLabel, CreateModal,
+	CreateModalComponent, EditAttachments, EditInteractionResponse, InputTextStyle, LabelComponent,
+	ModalInteraction, Timestamp,
+};
+use shared::database::guess_round;
+use shared::database::prelude::GuessRound;
+use shared::localization::{get_language_identifier, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use unic_langid::LanguageIdentifier;
+
+/// Button custom ids are `minigame_guess_{answer|reveal|giveup}_{round}`.
+pub const GUESS_PREFIX: &str = "minigame_guess_";
+/// The answer modal is `minigame_guess_modal_{round}`.
+pub const GUESS_MODAL_PREFIX: &str = "minigame_guess_modal_";
+const ANSWER_INPUT: &str = "answer";
+
+pub fn round_filename(round: &guess_round::Model) -> String {
+	format!("guess_{}.png", round.stage)
+}
+
+pub fn round_title(lang_id: &LanguageIdentifier) -> String {
+	USABLE_LOCALES.lookup(lang_id, "minigame_guess_anime-title")
+}
+
+pub fn round_description(round: &guess_round::Model, lang_id: &LanguageIdentifier) -> String {
+	let difficulty = difficulty(round);
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("difficulty"),
+		FluentValue::from(USABLE_LOCALES.lookup(
+			lang_id,
+			&format!("minigame_guess_anime-difficulty_{}", difficulty.as_str()),
+		)),
+	);
+	args.insert(
+		Cow::Borrowed("points"),
+		FluentValue::from(round_points(difficulty, round.stage)),
+	);
+	args.insert(Cow::Borrowed("stage"), FluentValue::from(round.stage + 1));
+	args.insert(Cow::Borrowed("total"), FluentValue::from(REVEAL_STAGES));
+
+	USABLE_LOCALES.lookup_with_args(lang_id, "minigame_guess_anime-description", &args)
+}
+
+/// Guess, reveal more and give up. Reveal is disabled on the last stage.
+pub fn round_buttons(
+	round: &guess_round::Model, lang_id: &LanguageIdentifier,
+) -> Vec<CreateComponent<'static>> {
+	let answer = CreateButton::new(format!("{}answer_{}", GUESS_PREFIX, round.id))
+		.label(USABLE_LOCALES.lookup(lang_id, "minigame_guess_anime-answer"))
+		.style(ButtonStyle::Primary);
+	let reveal = CreateButton::new(format!("{}reveal_{}", GUESS_PREFIX, round.id))
+		.label(USABLE_LOCALES.lookup(lang_id, "minigame_guess_anime-reveal"))
+		.style(ButtonStyle::Secondary)
+		.disabled(round.stage + 1 >= REVEAL_STAGES);
+	let give_up = CreateButton::new(format!("{}giveup_{}", GUESS_PREFIX, round.id))
+		.label(USABLE_LOCALES.lookup(lang_id, "minigame_guess_anime-give_up"))
+		.style(ButtonStyle::Danger);
+
+	vec![CreateComponent::ActionRow(CreateActionRow::Buttons(
+		Cow::Owned(vec![answer, reveal, give_up]),
+	))]
+}
+
+/// The round's answer with the full image, solved or not.
+fn finished_embed(
+	round: &guess_round::Model, points: Option<i32>, lang_id: &LanguageIdentifier,
+) -> CreateEmbed<'static> {
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	let description = match (&round.solved_by, points) {
+		(Some(user_id), Some(points)) => {
+			args.insert(
+				Cow::Borrowed("user"),
+				FluentValue::from(format!("<@{}>", user_id)),
+			);
+			args.insert(Cow::Borrowed("points"), FluentValue::from(points));
+			USABLE_LOCALES.lookup_with_args(lang_id, "minigame_guess_anime-solved", &args)
+		},
+		_ => USABLE_LOCALES.lookup(lang_id, "minigame_guess_anime-unsolved"),
+	};
+
+	CreateEmbed::new()
+		.timestamp(Timestamp::now())
+		.color(COLOR)
+		.title(round.title.clone())
+		.url(round.site_url.clone())
+		.description(description)
+		.image(round.image_url.clone())
+}
+
+/// Replace the round's image and buttons with its answer.
+fn finished_edit(
+	round: &guess_round::Model, points: Option<i32>, lang_id: &LanguageIdentifier,
+) -> EditInteractionResponse<'static> {
+	EditInteractionResponse::new()
+		.embed(finished_embed(round, points, lang_id))
+		.attachments(EditAttachments::new())
+		.components(vec![])
+}
+
+async fn load_round(
+	db_connection: &DatabaseConnection, round_id: &str,
+) -> Result<guess_round::Model> {
+	GuessRound::find_by_id(round_id.to_string())
+		.one(db_connection)
+		.await?
+		.ok_or(anyhow!("Guess round {} not found", round_id))
+}
+
+fn ephemeral(key: &str, lang_id: &LanguageIdentifier) -> CreateInteractionResponse<'static> {
+	CreateInteractionResponse::Message(
+		CreateInteractionResponseMessage::new()
+			.content(USABLE_LOCALES.lookup(lang_id, key))
+			.ephemeral(true),
+	)
+}
+
+fn answer_modal(round_id: &str, lang_id: &LanguageIdentifier) -> CreateModal<'static> {
+	let input = CreateInputText::new(InputTextStyle::Short, ANSWER_INPUT)
+		.placeholder(USABLE_LOCALES.lookup(lang_id, "minigame_guess_anime-answer_placeholder"))
+		.max_length(200)
+		.required(true);
+
+	CreateModal::new(
+		format!("{}{}", GUESS_MODAL_PREFIX, round_id),
+		USABLE_LOCALES.lookup(lang_id, "minigame_guess_anime-title"),
+	)
+	.components(vec![CreateModalComponent::Label(CreateLabel::input_text(
+		USABLE_LOCALES.lookup(lang_id, "minigame_guess_anime-answer_label"),
+		input,
+	))])
+}
+
+/// Redraw the round one stage further.
+async fn reveal_more(
+	ctx: &SerenityContext, interaction: &ComponentInteraction, round: &guess_round::Model,
+	db_connection: &DatabaseConnection, lang_id: &LanguageIdentifier,
+) -> Result<()> {
+	let Some(round) = reveal(db_connection, round).await? else {
+		let key = if is_open(round) {
+			"minigame_guess_anime-fully_revealed"
+		} else {
+			"minigame_guess_anime-over"
+		};
+		interaction
+			.create_response(&ctx.http, ephemeral(key, lang_id))
+			.await?;
+		return Ok(());
+	};
+
+	// Downloading and rendering can outlast the response window
+	interaction
+		.create_response(&ctx.http, CreateInteractionResponse::Acknowledge)
+		.await?;
+
+	let bot_data = ctx.data::<BotData>().clone();
+	let source = fetch_image(&bot_data.http_client, &round.image_url).await?;
+	let (stage, round_id) = (round.stage, round.id.clone());
+	let image = tokio::task::spawn_blocking(move || render_stage(&source, stage, &round_id))
+		.await
+		.map_err(|e| anyhow!("spawn_blocking panicked: {}", e))??;
+
+	let filename = round_filename(&round);
+	let embed = CreateEmbed::new()
+		.timestamp(Timestamp::now())
+		.color(COLOR)
+		.title(round_title(lang_id))
+		.description(round_description(&round, lang_id))
+		.image(format!("attachment://{}", filename));
+
+	let message = EditInteractionResponse::new()
+		.embed(embed)
+		.attachments(EditAttachments::new().add(CreateAttachment::bytes(image, filename)))
+		.components(round_buttons(&round, lang_id));
+
+	interaction.edit_response(&ctx.http, message).await?;
+
+	Ok(())
+}
+
+pub async fn update(
+	ctx: &SerenityContext, interaction: &ComponentInteraction,
+	db_connection: Arc<DatabaseConnection>,
+) -> Result<()> {
+	let guild_id = interaction
+		.guild_id
+		.ok_or(anyhow!("Guild ID not found"))?
+		.to_string();
+	let lang_id = get_language_identifier(guild_id, db_connection.clone()).await;
+
+	let (action, round_id) = interaction.data.custom_id[GUESS_PREFIX.len()..]
+		.split_once('_')
+		.ok_or(anyhow!("Malformed guess custom id"))?;
+	let round = load_round(&db_connection, round_id).await?;
+
+	match action {
+		"answer" => {
+			let response = if is_open(&round) {
+				CreateInteractionResponse::Modal(answer_modal(round_id, &lang_id))
+			} else {
+				ephemeral("minigame_guess_anime-over", &lang_id)
+			};
+			interaction.create_response(&ctx.http, response).await?;
+			Ok(())
+		},
+		"reveal" => reveal_more(ctx, interaction, &round, &db_connection, &lang_id).await,
+		"giveup" => {
+			let user_id = interaction.user.id.to_string();
+			let response = match give_up(&*db_connection, &round, &user_id).await? {
+				Some(round) => {
+					interaction
+						.create_response(&ctx.http, CreateInteractionResponse::Acknowledge)
+						.await?;
+					interaction
+						.edit_response(&ctx.http, finished_edit(&round, None, &lang_id))
+						.await?;
+					return Ok(());
+				},
+				None if round.started_by != user_id => {
+					ephemeral("minigame_guess_anime-not_starter", &lang_id)
+				},
+				None => ephemeral("minigame_guess_anime-over", &lang_id),
+			};
+			interaction.create_response(&ctx.http, response).await?;
+			Ok(())
+		},
+		_ => Err(anyhow!("Unknown guess action {}", action)),
+	}
+}
+
+/// The text typed in the answer modal.
+fn modal_answer(interaction: &ModalInteraction) -> Option<String> {
+	interaction
+		.data
+		.components
+		.iter()
+		.find_map(|component| match component {
+			Component::Label(label) => match &label.component {
+				LabelComponent::InputText(input) if input.custom_id == ANSWER_INPUT => {
+					input.value.as_ref().map(|value| value.to_string())
+				},
+				_ => None,
+			},
+			_ => None,
+		})
+}
+
+pub async fn submit(
+	ctx: &SerenityContext, interaction: &ModalInteraction, db_connection: Arc<DatabaseConnection>,
+) -> Result<()> {
+	let guild_id = interaction
+		.guild_id
+		.ok_or(anyhow!("Guild ID not found"))?
+		.to_string();
+	let lang_id = get_language_identifier(guild_id, db_connection.clone()).await;
+
+	let round_id = &interaction.data.custom_id[GUESS_MODAL_PREFIX.len()..];
+	let guess = modal_answer(interaction).unwrap_or_default();
+
+	// leader_board references user_data
+	add_user_data_to_db(interaction.user.clone(), db_connection.clone()).await?;
+
+	let user_id = interaction.user.id.to_string();
+	let response = match submit_guess(&db_connection, round_id, &user_id, &guess).await? {
+		GuessOutcome::Correct { round, points } => {
+			interaction
+				.create_response(&ctx.http, CreateInteractionResponse::Acknowledge)
+				.await?;
+			interaction
+				.edit_response(&ctx.http, finished_edit(&round, Some(points), &lang_id))
+				.await?;
+			track(
+				ctx,
+				interaction.user.id,
+				interaction.guild_id,
+				Some(interaction.channel_id),
+				Stat::TriviaAnswered,
+				1,
+			)
+			.await;
+			return Ok(());
+		},
+		GuessOutcome::Wrong => {
+			let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+			args.insert(Cow::Borrowed("guess"), FluentValue::from(guess));
+			CreateInteractionResponse::Message(
+				CreateInteractionResponseMessage::new()
+					.content(USABLE_LOCALES.lookup_with_args(
+						&lang_id,
+						"minigame_guess_anime-wrong",
+						&args,
+					))
+					.ephemeral(true),
+			)
+		},
+		GuessOutcome::Over => ephemeral("minigame_guess_anime-over", &lang_id),
+	};
+
+	interaction.create_response(&ctx.http, response).await?;
+
+	Ok(())
+}
+
+pub struct MinigameGuessHandler;
+
+impl ComponentHandler for MinigameGuessHandler {
+	fn prefix(&self) -> &'static str {
+		GUESS_PREFIX
+	}
+
+	fn handle<'a>(
+		&'a self, ctx: &'a SerenityContext, interaction: &'a ComponentInteraction,
+		db: Arc<DatabaseConnection>,
+	) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+		Box::pin(async move { update(ctx, interaction, db).await })
+	}
+}
+
+inventory::submit! { &MinigameGuessHandler as &dyn ComponentHandler }
+
+pub struct MinigameGuessModalHandler;
+
+impl ModalHandler for MinigameGuessModalHandler {
+	fn prefix(&self) -> &'static str {
+		GUESS_MODAL_PREFIX
+	}
+
+	fn handle<'a>(
+		&'a self, ctx: &'a SerenityContext, interaction: &'a ModalInteraction,
+		db: Arc<DatabaseConnection>,
+	) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+		Box::pin(async move { submit(ctx, interaction, db).await })
+	}
+}
+
+inventory::submit! { &MinigameGuessModalHandler as &dyn ModalHandler }
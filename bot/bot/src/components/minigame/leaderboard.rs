@@ -0,0 +1,194 @@
+use crate::components::handler::ComponentHandler;
+use crate::components::pagination::page_buttons;
+use crate::constant::COLOR;
+use crate::minigame::leaderboard::{score_page, LeaderboardView, Scope, ScorePage, Window};
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use fluent_templates::Loader;
+use sea_orm::DatabaseConnection;
+use serenity::all::{
+	ComponentInteraction, Context as SerenityContext, CreateComponent, CreateEmbed,
+	CreateEmbedFooter, CreateInteractionResponse, CreateInteractionResponseMessage, Timestamp,
+};
+use shared::localization::{get_language_identifier, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use unic_langid::LanguageIdentifier;
+
+/// Custom ids are `minigame_lb_{view}_{page}`, see [`LeaderboardView::encode`].
+pub const LEADERBOARD_PREFIX: &str = "minigame_lb_";
+
+pub fn leaderboard_title(view: LeaderboardView, lang_id: &LanguageIdentifier) -> String {
+	let game = view.game.map(|game| game.as_str()).unwrap_or("all");
+	let scope = match view.scope {
+		Scope::Server => "scope_server",
+		Scope::Global => "scope_global",
+	};
+	let window = match view.window {
+		Window::Weekly => "window_weekly",
+		Window::AllTime => "window_all_time",
+	};
+
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("game"),
+		FluentValue::from(
+			USABLE_LOCALES.lookup(lang_id, &format!("minigame_leaderboard-game_{}", game)),
+		),
+	);
+	args.insert(
+		Cow::Borrowed("scope"),
+		FluentValue::from(
+			USABLE_LOCALES.lookup(lang_id, &format!("minigame_leaderboard-{}", scope)),
+		),
+	);
+	args.insert(
+		Cow::Borrowed("window"),
+		FluentValue::from(
+			USABLE_LOCALES.lookup(lang_id, &format!("minigame_leaderboard-{}", window)),
+		),
+	);
+
+	USABLE_LOCALES.lookup_with_args(lang_id, "minigame_leaderboard-title", &args)
+}
+
+/// One line per entry, the viewer's own line in bold.
+pub fn leaderboard_description(
+	leaderboard: &ScorePage, viewer_id: &str, lang_id: &LanguageIdentifier,
+) -> String {
+	if leaderboard.entries.is_empty() {
+		return USABLE_LOCALES.lookup(lang_id, "minigame_leaderboard-empty");
+	}
+
+	leaderboard
+		.entries
+		.iter()
+		.map(|entry| {
+			let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+			args.insert(Cow::Borrowed("rank"), FluentValue::from(entry.rank));
+			args.insert(
+				Cow::Borrowed("user"),
+				FluentValue::from(format!("<@{}>", entry.user_id)),
+			);
+			args.insert(Cow::Borrowed("points"), FluentValue::from(entry.points));
+			let line =
+				USABLE_LOCALES.lookup_with_args(lang_id, "minigame_leaderboard-entry", &args);
+
+			if entry.user_id == viewer_id {
+				format!("**{}**", line)
+			} else {
+				line
+			}
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+/// Page number and the viewer's position, which may be on another page.
+pub fn leaderboard_footer(leaderboard: &ScorePage, lang_id: &LanguageIdentifier) -> String {
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("page"),
+		FluentValue::from(leaderboard.page + 1),
+	);
+	args.insert(
+		Cow::Borrowed("total"),
+		FluentValue::from(leaderboard.total_pages),
+	);
+	let page = USABLE_LOCALES.lookup_with_args(lang_id, "minigame_leaderboard-page", &args);
+
+	let position = match &leaderboard.viewer {
+		Some(viewer) => {
+			let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+			args.insert(Cow::Borrowed("rank"), FluentValue::from(viewer.rank));
+			args.insert(Cow::Borrowed("points"), FluentValue::from(viewer.points));
+			USABLE_LOCALES.lookup_with_args(lang_id, "minigame_leaderboard-position", &args)
+		},
+		None => USABLE_LOCALES.lookup(lang_id, "minigame_leaderboard-unranked"),
+	};
+
+	format!("{} · {}", page, position)
+}
+
+/// Previous/next buttons, omitted entirely when everything fits on one page.
+pub fn leaderboard_buttons(
+	view: LeaderboardView, page: u64, total_pages: u64, lang_id: &LanguageIdentifier,
+) -> Vec<CreateComponent<'static>> {
+	page_buttons(
+		LEADERBOARD_PREFIX,
+		Some(&view.encode()),
+		page,
+		total_pages,
+		USABLE_LOCALES.lookup(lang_id, "minigame_leaderboard-previous"),
+		USABLE_LOCALES.lookup(lang_id, "minigame_leaderboard-next"),
+	)
+}
+
+pub async fn update(
+	ctx: &SerenityContext, component_interaction: &ComponentInteraction, view: LeaderboardView,
+	page: u64, db_connection: Arc<DatabaseConnection>,
+) -> Result<()> {
+	let guild_id = component_interaction
+		.guild_id
+		.ok_or(anyhow!("Guild ID not found"))?
+		.to_string();
+
+	let viewer_id = component_interaction.user.id.to_string();
+	let leaderboard = score_page(&*db_connection, view, &guild_id, &viewer_id, page).await?;
+
+	let lang_id = get_language_identifier(guild_id, db_connection).await;
+
+	let embed = CreateEmbed::new()
+		.timestamp(Timestamp::now())
+		.color(COLOR)
+		.title(leaderboard_title(view, &lang_id))
+		.description(leaderboard_description(&leaderboard, &viewer_id, &lang_id))
+		.footer(CreateEmbedFooter::new(leaderboard_footer(
+			&leaderboard,
+			&lang_id,
+		)));
+
+	let message = CreateInteractionResponseMessage::new()
+		.embed(embed)
+		.components(leaderboard_buttons(
+			view,
+			leaderboard.page,
+			leaderboard.total_pages,
+			&lang_id,
+		));
+
+	component_interaction
+		.create_response(&ctx.http, CreateInteractionResponse::UpdateMessage(message))
+		.await?;
+
+	Ok(())
+}
+
+pub struct MinigameLeaderboardHandler;
+
+impl ComponentHandler for MinigameLeaderboardHandler {
+	fn prefix(&self) -> &'static str {
+		LEADERBOARD_PREFIX
+	}
+
+	fn handle<'a>(
+		&'a self, ctx: &'a SerenityContext, interaction: &'a ComponentInteraction,
+		db: Arc<DatabaseConnection>,
+	) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+		Box::pin(async move {
+			let custom_id = &interaction.data.custom_id[LEADERBOARD_PREFIX.len()..];
+			let (view, page) = custom_id
+				.rsplit_once('_')
+				.ok_or(anyhow!("Malformed leaderboard id {}", custom_id))?;
+			let view =
+				LeaderboardView::decode(view).ok_or(anyhow!("Unknown leaderboard {}", view))?;
+
+			update(ctx, interaction, view, page.parse()?, db).await
+		})
+	}
+}
+
+inventory::submit! { &MinigameLeaderboardHandler as &dyn ComponentHandler }
@@ -0,0 +1,5 @@
+pub mod anisong_quiz;
+pub mod collection;
+pub mod guess_anime;
+pub mod leaderboard;
+pub mod trade;
@@ -0,0 +1,523 @@
+use crate::components::handler::ComponentHandler;
+use crate::constant::COLOR;
+use crate::minigame::gacha::offerable_cards;
+use crate::minigame::rarity_stars;
+use crate::minigame::trade::{
+	cancel_trade, change_offered_coins, confirm_trade, load_trade, offerable_entries,
+	set_offered_cards, set_offered_items, set_trade_message, ConfirmOutcome, Offer, OfferChange,
+	Side, TradeStatus, TradeView, MAX_TRADE_CARDS, MAX_TRADE_ITEMS,
+};
+use anyhow::{anyhow, Result};
+use fluent_templates::fluent_bundle::FluentValue;
+use fluent_templates::Loader;
+use sea_orm::DatabaseConnection;
+use serenity::all::{
+	ButtonStyle, ComponentInteraction, ComponentInteractionDataKind, Context as SerenityContext,
+	CreateActionRow, CreateButton, CreateComponent, CreateEmbed, CreateInteractionResponse,
+	CreateInteractionResponseMessage, CreateSelectMenu, CreateSelectMenuKind,
+	CreateSelectMenuOption, EditMessage, GenericChannelId, MessageId, Timestamp,
+};
+use shared::localization::{get_language_identifier, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use tracing::warn;
+use unic_langid::LanguageIdentifier;
+
+pub const TRADE_PREFIX: &str = "minigame_trade_";
+/// Coin buttons on the offer panel, 0 resets the offer.
+const COIN_STEPS: [i64; 4] = [10, 100, 1000, 0];
+
+fn offer_lines(offer: &Offer, lang_id: &LanguageIdentifier) -> String {
+	let state = if offer.confirmed {
+		"minigame_trade-confirmed"
+	} else {
+		"minigame_trade-pending"
+	};
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("user"),
+		FluentValue::from(format!("<@{}>", offer.user_id)),
+	);
+	args.insert(
+		Cow::Borrowed("state"),
+		FluentValue::from(USABLE_LOCALES.lookup(lang_id, state)),
+	);
+	let mut lines =
+		vec![USABLE_LOCALES.lookup_with_args(lang_id, "minigame_trade-offer_header", &args)];
+
+	let offered = offer
+		.items
+		.iter()
+		.map(|item| (&item.name, item.rarity, "•"))
+		.chain(
+			offer
+				.cards
+				.iter()
+				.map(|card| (&card.name, card.rarity, "🃏")),
+		);
+	for (name, rarity, bullet) in offered {
+		lines.push(match rarity {
+			Some(rarity) => format!("{} {} {}", bullet, name, rarity_stars(rarity)),
+			None => {
+				let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+				args.insert(Cow::Borrowed("name"), FluentValue::from(name.clone()));
+				format!(
+					"{} {}",
+					bullet,
+					USABLE_LOCALES.lookup_with_args(lang_id, "minigame_trade-missing", &args)
+				)
+			},
+		});
+	}
+
+	if offer.coins > 0 {
+		let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+		args.insert(Cow::Borrowed("coins"), FluentValue::from(offer.coins));
+		lines.push(format!(
+			"• {}",
+			USABLE_LOCALES.lookup_with_args(lang_id, "minigame_trade-coins", &args)
+		));
+	}
+
+	if offer.is_empty() {
+		lines.push(USABLE_LOCALES.lookup(lang_id, "minigame_trade-empty_offer"));
+	}
+
+	lines.join("\n")
+}
+
+/// Status line followed by both offers, mentions only render in descriptions.
+pub fn trade_description(view: &TradeView, lang_id: &LanguageIdentifier) -> String {
+	let status = match view.status {
+		TradeStatus::Open => {
+			let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+			args.insert(
+				Cow::Borrowed("expires"),
+				FluentValue::from(format!("<t:{}:R>", view.expires_at().and_utc().timestamp())),
+			);
+			USABLE_LOCALES.lookup_with_args(lang_id, "minigame_trade-open", &args)
+		},
+		TradeStatus::Completed => USABLE_LOCALES.lookup(lang_id, "minigame_trade-completed"),
+		TradeStatus::Cancelled => USABLE_LOCALES.lookup(lang_id, "minigame_trade-cancelled"),
+		TradeStatus::Expired => USABLE_LOCALES.lookup(lang_id, "minigame_trade-expired"),
+	};
+
+	format!(
+		"{}\n\n{}\n\n{}",
+		status,
+		offer_lines(&view.initiator, lang_id),
+		offer_lines(&view.partner, lang_id)
+	)
+}
+
+/// Offer/confirm/cancel buttons, omitted once the trade is closed. The
+/// confirm button carries the version it confirms.
+pub fn trade_buttons(
+	view: &TradeView, lang_id: &LanguageIdentifier,
+) -> Vec<CreateComponent<'static>> {
+	if view.status != TradeStatus::Open {
+		return Vec::new();
+	}
+
+	let id = &view.trade.id;
+	let offer = CreateButton::new(format!("{}offer_{}", TRADE_PREFIX, id))
+		.label(USABLE_LOCALES.lookup(lang_id, "minigame_trade-change_offer"));
+	let confirm = CreateButton::new(format!(
+		"{}confirm_{}_{}",
+		TRADE_PREFIX, id, view.trade.version
+	))
+	.label(USABLE_LOCALES.lookup(lang_id, "minigame_trade-confirm"))
+	.style(ButtonStyle::Success);
+	let cancel = CreateButton::new(format!("{}cancel_{}", TRADE_PREFIX, id))
+		.label(USABLE_LOCALES.lookup(lang_id, "minigame_trade-cancel"))
+		.style(ButtonStyle::Danger);
+
+	vec![CreateComponent::ActionRow(CreateActionRow::Buttons(
+		Cow::Owned(vec![offer, confirm, cancel]),
+	))]
+}
+
+fn trade_embed(
+	view: &TradeView, notice: Option<&str>, lang_id: &LanguageIdentifier,
+) -> CreateEmbed<'static> {
+	let mut description = trade_description(view, lang_id);
+	if let Some(notice) = notice {
+		description = format!(
+			"{}\n\n{}",
+			USABLE_LOCALES.lookup(lang_id, notice),
+			description
+		);
+	}
+
+	CreateEmbed::new()
+		.timestamp(Timestamp::now())
+		.color(COLOR)
+		.title(USABLE_LOCALES.lookup(lang_id, "minigame_trade-title"))
+		.description(description)
+}
+
+/// The private panel a participant edits their offer from.
+async fn offer_panel(
+	db_connection: &DatabaseConnection, view: &TradeView, side: Side, notice: Option<String>,
+	lang_id: &LanguageIdentifier,
+) -> Result<CreateInteractionResponseMessage<'static>> {
+	let offer = view.offer(side);
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(Cow::Borrowed("coins"), FluentValue::from(offer.coins));
+	let mut description =
+		USABLE_LOCALES.lookup_with_args(lang_id, "minigame_trade-offer_desc", &args);
+	if let Some(notice) = notice {
+		description = format!("{}\n\n{}", notice, description);
+	}
+
+	let embed = CreateEmbed::new()
+		.timestamp(Timestamp::now())
+		.color(COLOR)
+		.title(USABLE_LOCALES.lookup(lang_id, "minigame_trade-offer_title"))
+		.description(description);
+
+	let mut components = Vec::new();
+
+	let entries = offerable_entries(db_connection, &view.trade, &offer.user_id).await?;
+	if !entries.is_empty() {
+		let options: Vec<CreateSelectMenuOption> = entries
+			.iter()
+			.map(|(entry, item)| {
+				let size = if entry.size > 0 {
+					format!(" {} cm", entry.size)
+				} else {
+					String::new()
+				};
+				let offered = offer
+					.items
+					.iter()
+					.any(|offered| offered.entry_id == entry.id);
+
+				CreateSelectMenuOption::new(
+					format!("{} {}{}", item.name, rarity_stars(entry.rarity), size),
+					entry.id.clone(),
+				)
+				.default_selection(offered)
+			})
+			.collect();
+		let max_values = options.len().min(MAX_TRADE_ITEMS) as u8;
+
+		let menu = CreateSelectMenu::new(
+			format!("{}pick_{}", TRADE_PREFIX, view.trade.id),
+			CreateSelectMenuKind::String {
+				options: Cow::Owned(options),
+			},
+		)
+		.placeholder(USABLE_LOCALES.lookup(lang_id, "minigame_trade-pick_items"))
+		.min_values(0)
+		.max_values(max_values);
+		components.push(CreateComponent::ActionRow(CreateActionRow::SelectMenu(
+			menu,
+		)));
+	}
+
+	let cards = offerable_cards(
+		db_connection,
+		&view.trade.guild_id,
+		&offer.user_id,
+		Some(&view.trade.id),
+		MAX_TRADE_CARDS as u64,
+	)
+	.await?;
+	if !cards.is_empty() {
+		let options: Vec<CreateSelectMenuOption> = cards
+			.iter()
+			.map(|(card, character)| {
+				let offered = offer.cards.iter().any(|offered| offered.card_id == card.id);
+
+				CreateSelectMenuOption::new(
+					format!("{} {}", character.name, rarity_stars(character.rarity)),
+					card.id.clone(),
+				)
+				.default_selection(offered)
+			})
+			.collect();
+		let max_values = options.len().min(MAX_TRADE_CARDS) as u8;
+
+		let menu = CreateSelectMenu::new(
+			format!("{}cards_{}", TRADE_PREFIX, view.trade.id),
+			CreateSelectMenuKind::String {
+				options: Cow::Owned(options),
+			},
+		)
+		.placeholder(USABLE_LOCALES.lookup(lang_id, "minigame_trade-pick_cards"))
+		.min_values(0)
+		.max_values(max_values);
+		components.push(CreateComponent::ActionRow(CreateActionRow::SelectMenu(
+			menu,
+		)));
+	}
+
+	let coin_buttons = COIN_STEPS
+		.iter()
+		.map(|step| {
+			let label = if *step == 0 {
+				USABLE_LOCALES.lookup(lang_id, "minigame_trade-reset_coins")
+			} else {
+				format!("+{}", step)
+			};
+
+			CreateButton::new(format!("{}coins_{}_{}", TRADE_PREFIX, view.trade.id, step))
+				.label(label)
+				.style(ButtonStyle::Secondary)
+		})
+		.collect();
+	components.push(CreateComponent::ActionRow(CreateActionRow::Buttons(
+		Cow::Owned(coin_buttons),
+	)));
+
+	Ok(CreateInteractionResponseMessage::new()
+		.embed(embed)
+		.components(components)
+		.ephemeral(true))
+}
+
+/// Redraw the trade window after an offer changed from the offer panel.
+async fn refresh_window(ctx: &SerenityContext, view: &TradeView, lang_id: &LanguageIdentifier) {
+	let (Some(channel_id), Some(message_id)) = (
+		view.trade
+			.channel_id
+			.as_ref()
+			.and_then(|id| id.parse().ok()),
+		view.trade
+			.message_id
+			.as_ref()
+			.and_then(|id| id.parse().ok()),
+	) else {
+		return;
+	};
+
+	let message = EditMessage::new()
+		.embed(trade_embed(view, None, lang_id))
+		.components(trade_buttons(view, lang_id));
+
+	if let Err(e) = GenericChannelId::new(channel_id)
+		.edit_message(&ctx.http, MessageId::new(message_id), message)
+		.await
+	{
+		warn!(trade_id = %view.trade.id, ?e, "Failed to redraw the trade window");
+	}
+}
+
+async fn respond_ephemeral(
+	ctx: &SerenityContext, interaction: &ComponentInteraction, key: &str,
+	lang_id: &LanguageIdentifier,
+) -> Result<()> {
+	let message = CreateInteractionResponseMessage::new()
+		.content(USABLE_LOCALES.lookup(lang_id, key))
+		.ephemeral(true);
+
+	interaction
+		.create_response(&ctx.http, CreateInteractionResponse::Message(message))
+		.await?;
+
+	Ok(())
+}
+
+async fn update_window(
+	ctx: &SerenityContext, interaction: &ComponentInteraction, view: &TradeView,
+	notice: Option<&str>, lang_id: &LanguageIdentifier,
+) -> Result<()> {
+	let message = CreateInteractionResponseMessage::new()
+		.embed(trade_embed(view, notice, lang_id))
+		.components(trade_buttons(view, lang_id));
+
+	interaction
+		.create_response(&ctx.http, CreateInteractionResponse::UpdateMessage(message))
+		.await?;
+
+	Ok(())
+}
+
+async fn update_panel(
+	ctx: &SerenityContext, interaction: &ComponentInteraction, db_connection: &DatabaseConnection,
+	trade_id: &str, side: Side, notice: Option<String>, lang_id: &LanguageIdentifier,
+) -> Result<()> {
+	let view = load_trade(db_connection, trade_id)
+		.await?
+		.ok_or(anyhow!("Trade {} not found", trade_id))?;
+
+	if view.status != TradeStatus::Open {
+		let message = CreateInteractionResponseMessage::new()
+			.content(USABLE_LOCALES.lookup(lang_id, "minigame_trade-closed"))
+			.embeds(vec![])
+			.components(vec![]);
+		interaction
+			.create_response(&ctx.http, CreateInteractionResponse::UpdateMessage(message))
+			.await?;
+
+		return Ok(());
+	}
+
+	let panel = offer_panel(db_connection, &view, side, notice, lang_id).await?;
+	interaction
+		.create_response(&ctx.http, CreateInteractionResponse::UpdateMessage(panel))
+		.await?;
+
+	refresh_window(ctx, &view, lang_id).await;
+
+	Ok(())
+}
+
+pub async fn update(
+	ctx: &SerenityContext, interaction: &ComponentInteraction,
+	db_connection: Arc<DatabaseConnection>,
+) -> Result<()> {
+	let guild_id = interaction
+		.guild_id
+		.ok_or(anyhow!("Guild ID not found"))?
+		.to_string();
+	let lang_id = get_language_identifier(guild_id, db_connection.clone()).await;
+
+	let (action, rest) = interaction.data.custom_id[TRADE_PREFIX.len()..]
+		.split_once('_')
+		.ok_or(anyhow!("Malformed trade custom id"))?;
+	// Trade ids are UUIDs, which contain no underscore
+	let (trade_id, argument) = match rest.split_once('_') {
+		Some((trade_id, argument)) => (trade_id, Some(argument)),
+		None => (rest, None),
+	};
+
+	let view = load_trade(&*db_connection, trade_id)
+		.await?
+		.ok_or(anyhow!("Trade {} not found", trade_id))?;
+
+	let user_id = interaction.user.id.to_string();
+	let Some(side) = Side::of(&view.trade, &user_id) else {
+		return respond_ephemeral(ctx, interaction, "minigame_trade-not_participant", &lang_id)
+			.await;
+	};
+
+	match action {
+		"offer" => {
+			if view.status != TradeStatus::Open {
+				return update_window(ctx, interaction, &view, None, &lang_id).await;
+			}
+
+			set_trade_message(
+				&db_connection,
+				trade_id,
+				interaction.channel_id.to_string(),
+				interaction.message.id.to_string(),
+			)
+			.await?;
+
+			let panel = offer_panel(&db_connection, &view, side, None, &lang_id).await?;
+			interaction
+				.create_response(&ctx.http, CreateInteractionResponse::Message(panel))
+				.await?;
+
+			Ok(())
+		},
+		"pick" | "cards" => {
+			let ids: Vec<String> = match &interaction.data.kind {
+				ComponentInteractionDataKind::StringSelect { values } => {
+					values.iter().map(|value| value.to_string()).collect()
+				},
+				_ => return Err(anyhow!("Trade picker is not a string select")),
+			};
+
+			let change = if action == "pick" {
+				set_offered_items(&db_connection, &view.trade, &user_id, ids).await?
+			} else {
+				set_offered_cards(&db_connection, &view.trade, &user_id, ids).await?
+			};
+			let notice = match change {
+				OfferChange::Closed => None,
+				_ => Some(USABLE_LOCALES.lookup(&lang_id, "minigame_trade-offer_updated")),
+			};
+
+			update_panel(
+				ctx,
+				interaction,
+				&db_connection,
+				trade_id,
+				side,
+				notice,
+				&lang_id,
+			)
+			.await
+		},
+		"coins" => {
+			let delta: i64 = argument.ok_or(anyhow!("Missing coin amount"))?.parse()?;
+
+			let notice =
+				match change_offered_coins(&db_connection, &view.trade, &user_id, delta).await? {
+					OfferChange::InsufficientFunds { balance } => {
+						let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+						args.insert(Cow::Borrowed("balance"), FluentValue::from(balance));
+						Some(USABLE_LOCALES.lookup_with_args(
+							&lang_id,
+							"minigame_trade-insufficient_funds",
+							&args,
+						))
+					},
+					OfferChange::Closed => None,
+					OfferChange::Updated => {
+						Some(USABLE_LOCALES.lookup(&lang_id, "minigame_trade-offer_updated"))
+					},
+				};
+
+			update_panel(
+				ctx,
+				interaction,
+				&db_connection,
+				trade_id,
+				side,
+				notice,
+				&lang_id,
+			)
+			.await
+		},
+		"confirm" => {
+			let version: i32 = argument.ok_or(anyhow!("Missing trade version"))?.parse()?;
+
+			let outcome = confirm_trade(&db_connection, &view.trade, &user_id, version).await?;
+			if outcome == ConfirmOutcome::Stale {
+				return respond_ephemeral(ctx, interaction, "minigame_trade-stale", &lang_id).await;
+			}
+
+			let view = load_trade(&*db_connection, trade_id)
+				.await?
+				.ok_or(anyhow!("Trade {} not found", trade_id))?;
+			let notice = (outcome == ConfirmOutcome::Failed).then_some("minigame_trade-failed");
+
+			update_window(ctx, interaction, &view, notice, &lang_id).await
+		},
+		"cancel" => {
+			cancel_trade(&db_connection, trade_id).await?;
+
+			let view = load_trade(&*db_connection, trade_id)
+				.await?
+				.ok_or(anyhow!("Trade {} not found", trade_id))?;
+
+			update_window(ctx, interaction, &view, None, &lang_id).await
+		},
+		_ => Err(anyhow!("Unknown trade action {}", action)),
+	}
+}
+
+pub struct MinigameTradeHandler;
+
+impl ComponentHandler for MinigameTradeHandler {
+	fn prefix(&self) -> &'static str {
+		TRADE_PREFIX
+	}
+
+	fn handle<'a>(
+		&'a self, ctx: &'a SerenityContext, interaction: &'a ComponentInteraction,
+		db: Arc<DatabaseConnection>,
+	) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+		Box::pin(async move { update(ctx, interaction, db).await })
+	}
+}
+
+inventory::submit! { &MinigameTradeHandler as &dyn ComponentHandler }
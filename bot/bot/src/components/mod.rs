@@ -1,3 +1,6 @@
 pub mod anilist;
 pub mod components_dispatch;
 pub mod handler;
+pub mod levels;
+pub mod minigame;
+pub mod pagination;
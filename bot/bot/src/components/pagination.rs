@@ -0,0 +1,67 @@
+//! Previous/next buttons of the paged lists.
+//!
+//! The custom id of a page button is the prefix of the list's handler, the
+//! encoded view when the list has one, then the 0-based page:
+//! `{prefix}{view}_{page}`, or `{prefix}{page}` without a view.
+use serenity::all::{CreateActionRow, CreateButton, CreateComponent};
+use std::borrow::Cow;
+
+pub fn page_custom_id(prefix: &str, view: Option<&str>, page: u64) -> String {
+	match view {
+		Some(view) => format!("{}{}_{}", prefix, view, page),
+		None => format!("{}{}", prefix, page),
+	}
+}
+
+/// Previous/next buttons, omitted entirely when everything fits on one page.
+/// The labels are already localized.
+pub fn page_buttons(
+	prefix: &str, view: Option<&str>, page: u64, total_pages: u64, previous_label: String,
+	next_label: String,
+) -> Vec<CreateComponent<'static>> {
+	if total_pages <= 1 {
+		return Vec::new();
+	}
+
+	let previous = CreateButton::new(page_custom_id(prefix, view, page.saturating_sub(1)))
+		.label(previous_label)
+		.disabled(page == 0);
+	let next = CreateButton::new(page_custom_id(prefix, view, page + 1))
+		.label(next_label)
+		.disabled(page + 1 >= total_pages);
+
+	vec![CreateComponent::ActionRow(CreateActionRow::Buttons(
+		Cow::Owned(vec![previous, next]),
+	))]
+}
+
+/// The 0-based page asked for with a `page` option. Pages are shown 1-based
+/// to users, anything below 1 is the first page.
+pub fn page_index(page: Option<i64>) -> u64 {
+	page.map(|page| page.max(1) as u64 - 1).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_page_ids_and_indexes() {
+		assert_eq!(page_custom_id("levels_lb_", None, 3), "levels_lb_3");
+		assert_eq!(
+			page_custom_id("minigame_lb_", Some("all:server:weekly"), 0),
+			"minigame_lb_all:server:weekly_0"
+		);
+
+		assert_eq!(page_index(None), 0);
+		assert_eq!(page_index(Some(-4)), 0);
+		assert_eq!(page_index(Some(1)), 0);
+		assert_eq!(page_index(Some(3)), 2);
+
+		let labels = || (String::from("<"), String::from(">"));
+		let (previous, next) = labels();
+		assert!(page_buttons("p_", None, 0, 1, previous, next).is_empty());
+		let (previous, next) = labels();
+		assert_eq!(page_buttons("p_", None, 0, 2, previous, next).len(), 1);
+	}
+}
@@ -61,4 +61,3 @@ pub const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const LIBRARY: &str = "serenity";
 
 pub const ACTIVITY_LIST_LIMIT: u64 = 10;
-pub const MEMBER_LIST_LIMIT: u16 = 10;
@@ -1,14 +1,15 @@
 use crate::event_handler::{BotData, Handler};
-use crate::handlers::user_db::add_user_data_to_db;
+use crate::handlers::user_db::{add_server_user_relation, add_user_data_to_db};
 use crate::server_image::calculate_user_color::{enqueue_user_color, get_member};
 use crate::server_image::generate_server_image::{
 	enqueue_global_server_image, enqueue_local_server_image, server_image_management,
 };
+use crate::voice_tracking::reconcile_guild;
 use sea_orm::ActiveValue::Set;
 use sea_orm::EntityTrait;
 use serenity::all::{Guild, GuildMembersChunkEvent, Member};
 use serenity::prelude::Context as SerenityContext;
-use shared::database::prelude::{GuildData, ServerUserRelation};
+use shared::database::prelude::GuildData;
 use std::sync::atomic::Ordering;
 use tracing::{info, trace, warn};
 
@@ -86,6 +87,10 @@ impl Handler {
 				"Failed to insert or update guild data in database"
 			);
 		}
+
+		if let Err(e) = reconcile_guild(&ctx, &guild).await {
+			warn!(guild_id = %guild.id, error = ?e, "Failed to reconcile vocal sessions");
+		}
 	}
 
 	pub(crate) async fn guild_member_addition(&self, ctx: SerenityContext, member: Member) {
@@ -135,6 +140,21 @@ impl Handler {
 				"Failed to insert user data into database"
 			);
 		}
+
+		if let Err(e) = add_server_user_relation(
+			guild_id.clone(),
+			member.user.id.to_string(),
+			bot_data.db_connection.clone(),
+		)
+		.await
+		{
+			warn!(
+				user_id = %member.user.id,
+				guild_id = %guild_id,
+				error = %e,
+				"Failed to insert server-user relation into database"
+			);
+		}
 	}
 
 	pub(crate) async fn guild_members_chunk(
@@ -164,34 +184,19 @@ impl Handler {
 				);
 			}
 
-			let active_relation = shared::database::server_user_relation::ActiveModel {
-				guild_id: Set(chunk.guild_id.to_string()),
-				user_id: Set(user.id.to_string()),
-			};
-
-			if let Err(e) = ServerUserRelation::insert(active_relation)
-				.on_conflict(
-					sea_orm::sea_query::OnConflict::columns([
-						shared::database::server_user_relation::Column::GuildId,
-						shared::database::server_user_relation::Column::UserId,
-					])
-					.do_nothing()
-					.to_owned(),
-				)
-				.exec(&*db_connection.clone())
-				.await
+			if let Err(e) = add_server_user_relation(
+				chunk.guild_id.to_string(),
+				user.id.to_string(),
+				db_connection.clone(),
+			)
+			.await
 			{
-				match e {
-					sea_orm::DbErr::RecordNotInserted => {},
-					_ => {
-						warn!(
-							user_id = %user.id,
-							guild_id = %chunk.guild_id,
-							error = %e,
-							"Failed to insert server-user relation from chunk into database"
-						);
-					},
-				}
+				warn!(
+					user_id = %user.id,
+					guild_id = %chunk.guild_id,
+					error = %e,
+					"Failed to insert server-user relation from chunk into database"
+				);
 			}
 		}
 	}
@@ -1,6 +1,6 @@
 use crate::autocomplete::autocomplete_dispatch::autocomplete_dispatching;
 use crate::command::command_dispatch::{dispatch_command, dispatch_user_command};
-use crate::components::components_dispatch::components_dispatching;
+use crate::components::components_dispatch::{components_dispatching, modals_dispatching};
 use crate::error_management::error_dispatch;
 use crate::event_handler::{BotData, Handler};
 use crate::handlers::user_db::add_user_data_to_db;
@@ -53,6 +53,13 @@ impl Handler {
 					warn!(error = ?e, "Failed to dispatch component interaction");
 				}
 			},
+			Interaction::Modal(modal_interaction) => {
+				user = Some(modal_interaction.user.clone());
+				let db_connection = bot_data.db_connection.clone();
+				if let Err(e) = modals_dispatching(ctx, modal_interaction, db_connection).await {
+					warn!(error = ?e, "Failed to dispatch modal interaction");
+				}
+			},
 			_ => {},
 		}
 
@@ -1,10 +1,12 @@
 use crate::event_handler::{BotData, Handler};
 use crate::handlers::user_db::add_user_data_to_db;
+use crate::leveling::handle_message_xp;
 use sea_orm::ActiveValue::Set;
 use sea_orm::EntityTrait;
 use serenity::all::Message;
 use serenity::prelude::Context as SerenityContext;
 use shared::database::prelude::Message as DatabaseMessage;
+use shared::helper::message_tracking::should_store_content;
 use tracing::{trace, warn};
 
 impl Handler {
@@ -35,9 +37,17 @@ impl Handler {
 		}
 
 		let message_id = message.id.to_string();
-		let data = message.content.to_string();
-		let length = data.len();
+		let length = message.content.len();
 		let channel_id = message.channel_id.to_string();
+		let guild_id = message.guild_id.map(|id| id.to_string());
+
+		// Only metadata is kept unless both the guild and the author opted in
+		let data =
+			if should_store_content(guild_id.clone(), user_id.to_string(), &db_connection).await {
+				message.content.to_string()
+			} else {
+				String::new()
+			};
 
 		let active_message = shared::database::message::ActiveModel {
 			id: Set(message_id),
@@ -45,6 +55,8 @@ impl Handler {
 			data: Set(data),
 			chat_length: Set(length as i32),
 			channel_id: Set(channel_id),
+			guild_id: Set(guild_id),
+			created_at: Set(message.timestamp.naive_utc()),
 		};
 
 		if let Err(e) = DatabaseMessage::insert(active_message)
@@ -58,5 +70,14 @@ impl Handler {
 				"Failed to insert message into database"
 			);
 		}
+
+		if let Err(e) = handle_message_xp(&ctx, &message).await {
+			warn!(
+				message_id = %message.id,
+				user_id = %user_id,
+				error = ?e,
+				"Failed to credit message XP"
+			);
+		}
 	}
 }
@@ -5,6 +5,7 @@ use crate::launch_task::thread_management_launcher;
 use crate::music_events;
 use crate::register::registration_dispatcher::command_registration;
 use crate::server_image::generate_server_image::server_image_management;
+use crate::voice_tracking::reconcile_on_ready;
 use lavalink_rs::model::events;
 use lavalink_rs::node::NodeBuilder;
 use lavalink_rs::prelude::NodeDistributionStrategy;
@@ -126,5 +127,9 @@ impl Handler {
 		if let Err(e) = load_items_from_json(&db).await {
 			warn!(error = %e, "Failed to load items from JSON");
 		}
+
+		if let Err(e) = reconcile_on_ready(&ctx, &ready).await {
+			warn!(error = ?e, "Failed to reconcile vocal sessions");
+		}
 	}
 }
@@ -1,9 +1,9 @@
 use anyhow::Result;
 use chrono::Utc;
 use sea_orm::ActiveValue::Set;
-use sea_orm::{DatabaseConnection, EntityTrait};
+use sea_orm::{DatabaseConnection, DbErr, EntityTrait};
 use serenity::all::User;
-use shared::database::prelude::UserData;
+use shared::database::prelude::{ServerUserRelation, UserData};
 use std::sync::Arc;
 use tracing::{trace, warn};
 
@@ -37,3 +37,30 @@ pub async fn add_user_data_to_db(user: User, connection: Arc<DatabaseConnection>
 		Ok(())
 	}
 }
+
+/// Records that the user is a member of the guild, which the server leaderboards and the
+/// registered user list read instead of asking Discord for the members.
+pub async fn add_server_user_relation(
+	guild_id: String, user_id: String, connection: Arc<DatabaseConnection>,
+) -> Result<()> {
+	let active_relation = shared::database::server_user_relation::ActiveModel {
+		guild_id: Set(guild_id),
+		user_id: Set(user_id),
+	};
+
+	match ServerUserRelation::insert(active_relation)
+		.on_conflict(
+			sea_orm::sea_query::OnConflict::columns([
+				shared::database::server_user_relation::Column::GuildId,
+				shared::database::server_user_relation::Column::UserId,
+			])
+			.do_nothing()
+			.to_owned(),
+		)
+		.exec(&*connection)
+		.await
+	{
+		Ok(_) | Err(DbErr::RecordNotInserted) => Ok(()),
+		Err(e) => Err(e.into()),
+	}
+}
@@ -50,6 +50,31 @@ pub fn get_option_map_integer_subcommand(interaction: &CommandInteraction) -> Ha
 	map
 }
 
+pub fn get_option_map_boolean_subcommand(
+	interaction: &CommandInteraction,
+) -> HashMap<String, bool> {
+	let mut map = HashMap::new();
+
+	let binding = interaction.data.options();
+
+	let subcommand = &binding.first().unwrap().value;
+
+	if let ResolvedValue::SubCommand(op) = subcommand {
+		for option in op {
+			let name = option.name.to_string();
+
+			let value = match option.value {
+				ResolvedValue::Boolean(a) => a,
+				_ => false,
+			};
+
+			map.insert(name, value);
+		}
+	}
+
+	map
+}
+
 pub fn get_option_map_user_subcommand(interaction: &CommandInteraction) -> HashMap<String, UserId> {
 	let mut map = HashMap::new();
 
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use serenity::all::{CommandInteraction, ResolvedOption, ResolvedValue};
+use serenity::all::{ChannelId, CommandInteraction, ResolvedOption, ResolvedValue, RoleId};
 
 pub fn get_subcommand(interaction: &CommandInteraction) -> Option<ResolvedOption<'_>> {
 	let subcommand_group_value = interaction.data.options().first()?.clone();
@@ -70,6 +70,122 @@ pub fn get_option_map_boolean_subcommand_group(
 	map
 }
 
+pub fn get_option_map_integer_subcommand_group(
+	interaction: &CommandInteraction,
+) -> HashMap<String, i64> {
+	let mut map = HashMap::new();
+
+	let binding = interaction.data.options();
+
+	let subcommand_group = &binding.first().unwrap().value;
+
+	if let ResolvedValue::SubCommandGroup(subcommand_group_options) = subcommand_group {
+		for option in subcommand_group_options {
+			if let ResolvedValue::SubCommand(subcommand_options) = &option.value {
+				for option2 in subcommand_options {
+					let name = option2.name.to_string();
+
+					let value = match option2.value {
+						ResolvedValue::Integer(a) => a,
+						_ => 0,
+					};
+
+					map.insert(name, value);
+				}
+			}
+		}
+	}
+
+	map
+}
+
+pub fn get_option_map_number_subcommand_group(
+	interaction: &CommandInteraction,
+) -> HashMap<String, f64> {
+	let mut map = HashMap::new();
+
+	let binding = interaction.data.options();
+
+	let subcommand_group = &binding.first().unwrap().value;
+
+	if let ResolvedValue::SubCommandGroup(subcommand_group_options) = subcommand_group {
+		for option in subcommand_group_options {
+			if let ResolvedValue::SubCommand(subcommand_options) = &option.value {
+				for option2 in subcommand_options {
+					let name = option2.name.to_string();
+
+					let value = match option2.value {
+						ResolvedValue::Number(a) => a,
+						_ => 0.0,
+					};
+
+					map.insert(name, value);
+				}
+			}
+		}
+	}
+
+	map
+}
+
+pub fn get_option_map_role_subcommand_group(
+	interaction: &CommandInteraction,
+) -> HashMap<String, RoleId> {
+	let mut map = HashMap::new();
+
+	let binding = interaction.data.options();
+
+	let subcommand_group = &binding.first().unwrap().value;
+
+	if let ResolvedValue::SubCommandGroup(subcommand_group_options) = subcommand_group {
+		for option in subcommand_group_options {
+			if let ResolvedValue::SubCommand(subcommand_options) = &option.value {
+				for option2 in subcommand_options {
+					let name = option2.name.to_string();
+
+					let value = match option2.value {
+						ResolvedValue::Role(a) => a.id,
+						_ => RoleId::new(1),
+					};
+
+					map.insert(name, value);
+				}
+			}
+		}
+	}
+
+	map
+}
+
+pub fn get_option_map_channel_subcommand_group(
+	interaction: &CommandInteraction,
+) -> HashMap<String, ChannelId> {
+	let mut map = HashMap::new();
+
+	let binding = interaction.data.options();
+
+	let subcommand_group = &binding.first().unwrap().value;
+
+	if let ResolvedValue::SubCommandGroup(subcommand_group_options) = subcommand_group {
+		for option in subcommand_group_options {
+			if let ResolvedValue::SubCommand(subcommand_options) = &option.value {
+				for option2 in subcommand_options {
+					let name = option2.name.to_string();
+
+					let value = match option2.value {
+						ResolvedValue::Channel(a) => a.id,
+						_ => ChannelId::new(1),
+					};
+
+					map.insert(name, value);
+				}
+			}
+		}
+	}
+
+	map
+}
+
 pub fn get_option_map_string_autocomplete_subcommand_group(
 	interaction: &CommandInteraction,
 ) -> HashMap<String, String> {
@@ -1,13 +1,39 @@
-use anyhow::{Context, Result};
-use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, Set};
+//! Sync of the item catalog in `json/items/items.json` to the `item` table.
+//!
+//! The catalog is validated as a whole before anything is written, then
+//! compared to the table: new items are inserted, edited items updated and
+//! items removed from the file are retired rather than deleted, so
+//! inventories holding them keep working. Every applied sync is recorded in
+//! `item_catalog_sync` with its diff. The catalog carries a `version` that
+//! must never go down; editing items without bumping it only warns.
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+	ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
 use serde::{Deserialize, Serialize};
-use tracing::{debug, info};
+use std::collections::HashSet;
+use std::fmt;
+use tracing::{debug, info, warn};
 
-use shared::database::item::{ActiveModel as ItemActiveModel, Entity as Item};
+use crate::minigame::fishing::ItemProperties;
+use shared::database::item::{self, ActiveModel as ItemActiveModel, Entity as Item};
+use shared::database::item_catalog_sync;
+use shared::database::prelude::ItemCatalogSync;
 use shared::helper::read_file::read_file_as_string;
 
+/// Item types the bot knows how to handle.
+pub const ITEM_TYPES: &[&str] = &["bait", "event", "fish", "minigame", "quest", "rod", "shop"];
+pub const MIN_RARITY: i32 = 1;
+pub const MAX_RARITY: i32 = 5;
+
+const CATALOG_PATH: &str = "./json/items/items.json";
+
 #[derive(Debug, Serialize, Deserialize)]
 struct ItemsJson {
+	#[serde(default)]
+	version: i32,
 	items: Vec<ItemJson>,
 }
 
@@ -22,9 +48,242 @@ struct ItemJson {
 	r#type: String,
 	base_xp_boost: f32,
 	weight: i32,
+	#[serde(default)]
+	properties: Option<serde_json::Value>,
+}
+
+impl ItemJson {
+	fn properties(&self) -> Option<String> {
+		self.properties.as_ref().map(|value| value.to_string())
+	}
+
+	/// Columns of `existing` this catalog entry changes.
+	fn changed_fields(&self, existing: &item::Model) -> Vec<&'static str> {
+		let mut fields = Vec::new();
+		if self.name != existing.name {
+			fields.push("name");
+		}
+		if self.description != existing.description {
+			fields.push("description");
+		}
+		if self.price != existing.price {
+			fields.push("price");
+		}
+		if self.minimum_rarity != existing.minimum_rarity {
+			fields.push("minimum_rarity");
+		}
+		if self.maximum_rarity != existing.maximum_rarity {
+			fields.push("maximum_rarity");
+		}
+		if self.r#type != existing.r#type {
+			fields.push("type");
+		}
+		if self.base_xp_boost != existing.base_xp_boost {
+			fields.push("base_xp_boost");
+		}
+		if self.weight != existing.weight {
+			fields.push("weight");
+		}
+		if self.properties() != existing.properties {
+			fields.push("properties");
+		}
+
+		fields
+	}
+
+	fn to_active_model(&self) -> ItemActiveModel {
+		ItemActiveModel {
+			item_id: Set(self.item_id.clone()),
+			name: Set(self.name.clone()),
+			description: Set(self.description.clone()),
+			price: Set(self.price),
+			minimum_rarity: Set(self.minimum_rarity),
+			maximum_rarity: Set(self.maximum_rarity),
+			r#type: Set(self.r#type.clone()),
+			base_xp_boost: Set(self.base_xp_boost),
+			weight: Set(self.weight),
+			properties: Set(self.properties()),
+			retired_at: Set(None),
+		}
+	}
+}
+
+/// Every problem with the catalog, so a single reload reports them all.
+fn validate(catalog: &ItemsJson) -> Vec<String> {
+	let mut errors = Vec::new();
+	let mut seen = HashSet::new();
+
+	if catalog.version < 1 {
+		errors.push(String::from("catalog: version must be at least 1"));
+	}
+
+	for item in &catalog.items {
+		let id = &item.item_id;
+
+		if id.trim().is_empty() {
+			errors.push(String::from("an item has an empty item_id"));
+			continue;
+		}
+		if !seen.insert(id.as_str()) {
+			errors.push(format!("{}: duplicate item_id", id));
+		}
+		if item.name.trim().is_empty() {
+			errors.push(format!("{}: empty name", id));
+		}
+		if !ITEM_TYPES.contains(&item.r#type.as_str()) {
+			errors.push(format!("{}: unknown type {}", id, item.r#type));
+		}
+		if !(MIN_RARITY..=MAX_RARITY).contains(&item.minimum_rarity)
+			|| !(MIN_RARITY..=MAX_RARITY).contains(&item.maximum_rarity)
+		{
+			errors.push(format!(
+				"{}: rarities must be between {} and {}",
+				id, MIN_RARITY, MAX_RARITY
+			));
+		} else if item.minimum_rarity > item.maximum_rarity {
+			errors.push(format!("{}: minimum_rarity above maximum_rarity", id));
+		}
+		if item.weight <= 0 {
+			errors.push(format!("{}: weight must be positive", id));
+		}
+		if item.price < 0 {
+			errors.push(format!("{}: negative price", id));
+		}
+		if !item.base_xp_boost.is_finite() || item.base_xp_boost < 0.0 {
+			errors.push(format!(
+				"{}: base_xp_boost must be a non-negative number",
+				id
+			));
+		}
+
+		let Some(value) = &item.properties else {
+			continue;
+		};
+		match serde_json::from_value::<ItemProperties>(value.clone()) {
+			Ok(properties) => {
+				if properties.catch.is_some() && item.r#type != "fish" {
+					errors.push(format!("{}: only fish have a catch table", id));
+				}
+				if let Some(gear) = properties.gear {
+					if gear.slot.as_str() != item.r#type {
+						errors.push(format!("{}: gear slot does not match the type", id));
+					}
+					if gear.durability <= 0 {
+						errors.push(format!("{}: gear durability must be positive", id));
+					}
+				}
+			},
+			Err(e) => errors.push(format!("{}: invalid properties: {}", id, e)),
+		}
+	}
+
+	errors
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ItemChange {
+	Added(String),
+	Updated {
+		item_id: String,
+		fields: Vec<&'static str>,
+	},
+	/// Removed from the catalog.
+	Retired(String),
+	/// Back in the catalog after being retired.
+	Restored(String),
+}
+
+impl fmt::Display for ItemChange {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ItemChange::Added(item_id) => write!(f, "+ {}", item_id),
+			ItemChange::Updated { item_id, fields } => {
+				write!(f, "~ {} ({})", item_id, fields.join(", "))
+			},
+			ItemChange::Retired(item_id) => write!(f, "- {}", item_id),
+			ItemChange::Restored(item_id) => write!(f, "^ {}", item_id),
+		}
+	}
+}
+
+/// Changes needed to bring `existing` in line with `catalog`, in catalog
+/// order followed by retirements.
+fn diff(existing: &[item::Model], catalog: &[ItemJson]) -> Vec<ItemChange> {
+	let mut changes = Vec::new();
+
+	for entry in catalog {
+		let Some(current) = existing.iter().find(|item| item.item_id == entry.item_id) else {
+			changes.push(ItemChange::Added(entry.item_id.clone()));
+			continue;
+		};
+
+		if current.retired_at.is_some() {
+			changes.push(ItemChange::Restored(entry.item_id.clone()));
+		}
+
+		let fields = entry.changed_fields(current);
+		if !fields.is_empty() {
+			changes.push(ItemChange::Updated {
+				item_id: entry.item_id.clone(),
+				fields,
+			});
+		}
+	}
+
+	for current in existing {
+		if current.retired_at.is_none()
+			&& !catalog.iter().any(|entry| entry.item_id == current.item_id)
+		{
+			changes.push(ItemChange::Retired(current.item_id.clone()));
+		}
+	}
+
+	changes
 }
 
-/// Loads items from a JSON file and inserts them into the database.
+/// Outcome of a catalog sync. `changes` is empty when the table already
+/// matched the catalog.
+#[derive(Debug, Clone)]
+pub struct CatalogSync {
+	pub version: i32,
+	pub changes: Vec<ItemChange>,
+}
+
+impl CatalogSync {
+	fn count(&self, matches: impl Fn(&ItemChange) -> bool) -> usize {
+		self.changes.iter().filter(|change| matches(change)).count()
+	}
+
+	pub fn added(&self) -> usize {
+		self.count(|change| matches!(change, ItemChange::Added(_)))
+	}
+
+	pub fn updated(&self) -> usize {
+		self.count(|change| matches!(change, ItemChange::Updated { .. }))
+	}
+
+	pub fn retired(&self) -> usize {
+		self.count(|change| matches!(change, ItemChange::Retired(_)))
+	}
+
+	pub fn restored(&self) -> usize {
+		self.count(|change| matches!(change, ItemChange::Restored(_)))
+	}
+
+	/// One line per change, in the style of a diff.
+	pub fn diff_lines(&self) -> String {
+		self.changes
+			.iter()
+			.map(ItemChange::to_string)
+			.collect::<Vec<_>>()
+			.join("\n")
+	}
+}
+
+/// Validates the item catalog JSON file and syncs it to the database.
+///
+/// Runs on startup and from the `reload_items` owner command. Nothing is
+/// written when the catalog is invalid or older than the last applied one.
 ///
 /// # Arguments
 ///
@@ -32,68 +291,186 @@ struct ItemJson {
 ///
 /// # Returns
 ///
-/// A Result indicating success or failure
-pub async fn load_items_from_json(db: &DatabaseConnection) -> Result<()> {
+/// The applied changes, or an error listing every validation problem
+pub async fn load_items_from_json(db: &DatabaseConnection) -> Result<CatalogSync> {
 	info!("Loading items from JSON file");
 
 	// Read the JSON file
 	let json_content =
-		read_file_as_string("./json/items/items.json").context("Failed to read items JSON file")?;
+		read_file_as_string(CATALOG_PATH).context("Failed to read items JSON file")?;
 
 	// Parse the JSON
-	let items_json: ItemsJson =
+	let catalog: ItemsJson =
 		serde_json::from_str(&json_content).context("Failed to parse items JSON")?;
 
-	debug!("Found {} items in JSON file", items_json.items.len());
+	debug!(
+		"Found {} items in JSON file, version {}",
+		catalog.items.len(),
+		catalog.version
+	);
 
-	// Insert each item into the database
-	for item_json in items_json.items {
-		debug!("Processing item: {}", item_json.item_id);
-
-		// Check if the item already exists
-		let existing_item = Item::find_by_id(item_json.item_id.clone())
-			.one(db)
-			.await
-			.context("Failed to check if item exists")?;
+	let errors = validate(&catalog);
+	if !errors.is_empty() {
+		bail!("Invalid item catalog:\n{}", errors.join("\n"));
+	}
 
-		if existing_item.is_some() {
-			debug!("Item already exists: {}", item_json.item_id);
-			continue;
+	let last_sync = ItemCatalogSync::find()
+		.order_by_desc(item_catalog_sync::Column::Version)
+		.one(db)
+		.await
+		.context("Failed to get the last catalog sync")?;
+	if let Some(last_sync) = &last_sync {
+		if catalog.version < last_sync.version {
+			bail!(
+				"Item catalog version {} is older than the applied version {}",
+				catalog.version,
+				last_sync.version
+			);
 		}
+	}
 
-		// Create a new item
-		let item = ItemActiveModel {
-			item_id: Set(item_json.item_id.clone()),
-			name: Set(item_json.name),
-			description: Set(item_json.description),
-			price: Set(item_json.price),
-			minimum_rarity: Set(item_json.minimum_rarity),
-			maximum_rarity: Set(item_json.maximum_rarity),
-			r#type: Set(item_json.r#type),
-			base_xp_boost: Set(item_json.base_xp_boost),
-			weight: Set(item_json.weight),
-		};
+	let existing = Item::find()
+		.all(db)
+		.await
+		.context("Failed to get existing items")?;
+
+	let sync = CatalogSync {
+		version: catalog.version,
+		changes: diff(&existing, &catalog.items),
+	};
+
+	if sync.changes.is_empty() {
+		info!("Item catalog version {} is up to date", catalog.version);
+		return Ok(sync);
+	}
+
+	if last_sync.is_some_and(|last_sync| last_sync.version == catalog.version) {
+		warn!(
+			"Item catalog changed without a version bump, still at {}",
+			catalog.version
+		);
+	}
+
+	let txn = db.begin().await?;
+
+	for change in &sync.changes {
+		match change {
+			ItemChange::Added(item_id) => {
+				let entry = catalog
+					.items
+					.iter()
+					.find(|entry| &entry.item_id == item_id)
+					.context("Added item missing from the catalog")?;
+				Item::insert(entry.to_active_model())
+					.exec_without_returning(&txn)
+					.await
+					.context(format!("Failed to insert item: {}", item_id))?;
+			},
+			ItemChange::Updated { item_id, .. } | ItemChange::Restored(item_id) => {
+				let entry = catalog
+					.items
+					.iter()
+					.find(|entry| &entry.item_id == item_id)
+					.context("Updated item missing from the catalog")?;
+				Item::update(entry.to_active_model())
+					.exec(&txn)
+					.await
+					.context(format!("Failed to update item: {}", item_id))?;
+			},
+			ItemChange::Retired(_) => {},
+		}
+	}
 
-		// Insert the item into the database
-		item.insert(db)
+	let retired: Vec<String> = sync
+		.changes
+		.iter()
+		.filter_map(|change| match change {
+			ItemChange::Retired(item_id) => Some(item_id.clone()),
+			_ => None,
+		})
+		.collect();
+	if !retired.is_empty() {
+		Item::update_many()
+			.col_expr(
+				item::Column::RetiredAt,
+				Expr::value(Some(Utc::now().naive_utc())),
+			)
+			.filter(item::Column::ItemId.is_in(retired))
+			.exec(&txn)
 			.await
-			.context(format!("Failed to insert item: {}", item_json.item_id))?;
+			.context("Failed to retire items")?;
+	}
+
+	ItemCatalogSync::insert(item_catalog_sync::ActiveModel {
+		id: Set(uuid::Uuid::new_v4().to_string()),
+		version: Set(sync.version),
+		added: Set(sync.added() as i32),
+		updated: Set(sync.updated() as i32),
+		retired: Set(sync.retired() as i32),
+		restored: Set(sync.restored() as i32),
+		diff: Set(sync.diff_lines()),
+		applied_at: Set(Utc::now().naive_utc()),
+	})
+	.exec_without_returning(&txn)
+	.await
+	.context("Failed to record the catalog sync")?;
 
-		info!("Inserted item: {}", item_json.item_id);
+	txn.commit().await?;
+
+	for change in &sync.changes {
+		info!("Item catalog: {}", change);
 	}
+	info!(
+		version = sync.version,
+		added = sync.added(),
+		updated = sync.updated(),
+		retired = sync.retired(),
+		restored = sync.restored(),
+		"Synced item catalog"
+	);
 
-	info!("Finished loading items from JSON file");
-	Ok(())
+	Ok(sync)
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
 
+	fn entry(item_id: &str) -> ItemJson {
+		ItemJson {
+			item_id: item_id.to_string(),
+			name: item_id.to_string(),
+			description: String::new(),
+			price: 10,
+			minimum_rarity: 1,
+			maximum_rarity: 2,
+			r#type: "fish".to_string(),
+			base_xp_boost: 0.1,
+			weight: 5,
+			properties: None,
+		}
+	}
+
+	fn existing(entry: &ItemJson) -> item::Model {
+		item::Model {
+			item_id: entry.item_id.clone(),
+			name: entry.name.clone(),
+			description: entry.description.clone(),
+			price: entry.price,
+			minimum_rarity: entry.minimum_rarity,
+			maximum_rarity: entry.maximum_rarity,
+			r#type: entry.r#type.clone(),
+			base_xp_boost: entry.base_xp_boost,
+			weight: entry.weight,
+			properties: entry.properties(),
+			retired_at: None,
+		}
+	}
+
 	#[test]
 	fn test_parse_items_json() {
 		// This test verifies that the JSON file can be parsed correctly
-		let json_content = read_file_as_string("./json/items/items.json").unwrap();
+		let json_content = read_file_as_string(CATALOG_PATH).unwrap();
 		let items_json: ItemsJson = serde_json::from_str(&json_content).unwrap();
 
 		assert!(!items_json.items.is_empty());
@@ -104,4 +481,81 @@ mod tests {
 		assert_eq!(first_item.name, "Minigame Coin");
 		assert_eq!(first_item.r#type, "minigame");
 	}
+
+	#[test]
+	fn test_shipped_catalog_is_valid() {
+		let json_content = read_file_as_string(CATALOG_PATH).unwrap();
+		let items_json: ItemsJson = serde_json::from_str(&json_content).unwrap();
+
+		assert_eq!(validate(&items_json), Vec::<String>::new());
+	}
+
+	#[test]
+	fn test_validate_reports_every_problem() {
+		let mut bad_rarity = entry("bad_rarity");
+		bad_rarity.minimum_rarity = 4;
+		bad_rarity.maximum_rarity = 2;
+		let mut bad_weight = entry("bad_weight");
+		bad_weight.weight = 0;
+		let mut bad_type = entry("bad_type");
+		bad_type.r#type = "potion".to_string();
+		let mut bad_gear = entry("bad_gear");
+		bad_gear.r#type = "rod".to_string();
+		bad_gear.properties = Some(serde_json::json!({"gear": {"slot": "bait", "durability": 5}}));
+
+		let catalog = ItemsJson {
+			version: 1,
+			items: vec![
+				bad_rarity,
+				bad_weight,
+				bad_type,
+				bad_gear,
+				entry("bad_weight"),
+			],
+		};
+
+		let errors = validate(&catalog);
+		// The duplicate also has a bad weight
+		assert_eq!(errors.len(), 6, "{:?}", errors);
+	}
+
+	#[test]
+	fn test_diff_adds_updates_retires_and_restores() {
+		let kept = entry("kept");
+		let mut edited = entry("edited");
+		let mut retired = existing(&entry("back"));
+		retired.retired_at = Some(Utc::now().naive_utc());
+
+		let current = vec![
+			existing(&kept),
+			existing(&edited),
+			existing(&entry("removed")),
+			retired,
+		];
+		edited.price = 20;
+		edited.weight = 1;
+
+		let changes = diff(&current, &[kept, edited, entry("back"), entry("new")]);
+
+		assert_eq!(
+			changes,
+			vec![
+				ItemChange::Updated {
+					item_id: "edited".to_string(),
+					fields: vec!["price", "weight"],
+				},
+				ItemChange::Restored("back".to_string()),
+				ItemChange::Added("new".to_string()),
+				ItemChange::Retired("removed".to_string()),
+			]
+		);
+	}
+
+	#[test]
+	fn test_diff_ignores_already_retired_items() {
+		let mut retired = existing(&entry("gone"));
+		retired.retired_at = Some(Utc::now().naive_utc());
+
+		assert!(diff(&[retired], &[]).is_empty());
+	}
 }
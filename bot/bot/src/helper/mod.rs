@@ -5,5 +5,6 @@ pub mod general_channel_info;
 pub mod get_option;
 pub mod load_items;
 pub mod make_graphql_cached;
+pub mod pixel_font;
 pub mod progress_bar_generator;
 pub mod trimer;
@@ -0,0 +1,110 @@
+use image::{Rgba, RgbaImage};
+
+/// Glyph size in font pixels, before scaling.
+pub const GLYPH_WIDTH: u32 = 5;
+pub const GLYPH_HEIGHT: u32 = 7;
+/// Blank font pixels between two glyphs.
+const GLYPH_SPACING: u32 = 1;
+
+/// 5x7 bitmap glyphs, one byte per row with the leftmost pixel in bit 4.
+///
+/// The image crates in the workspace have no text rendering, so generated
+/// cards draw their numbers and short labels with this table instead.
+fn glyph(c: char) -> [u8; 7] {
+	match c.to_ascii_uppercase() {
+		'0' => [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E],
+		'1' => [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E],
+		'2' => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F],
+		'3' => [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E],
+		'4' => [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02],
+		'5' => [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E],
+		'6' => [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E],
+		'7' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+		'8' => [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E],
+		'9' => [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C],
+		'#' => [0x0A, 0x0A, 0x1F, 0x0A, 0x1F, 0x0A, 0x0A],
+		'/' => [0x01, 0x02, 0x02, 0x04, 0x08, 0x08, 0x10],
+		'.' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C],
+		':' => [0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x0C, 0x00],
+		'-' => [0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00],
+		'%' => [0x18, 0x19, 0x02, 0x04, 0x08, 0x13, 0x03],
+		'A' => [0x0E, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+		'B' => [0x1E, 0x11, 0x11, 0x1E, 0x11, 0x11, 0x1E],
+		'C' => [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E],
+		'D' => [0x1C, 0x12, 0x11, 0x11, 0x11, 0x12, 0x1C],
+		'E' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F],
+		'F' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10],
+		'G' => [0x0E, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0F],
+		'H' => [0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+		'I' => [0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E],
+		'J' => [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0C],
+		'K' => [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+		'L' => [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1F],
+		'M' => [0x11, 0x1B, 0x15, 0x15, 0x11, 0x11, 0x11],
+		'N' => [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11],
+		'O' => [0x0E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+		'P' => [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10],
+		'Q' => [0x0E, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0D],
+		'R' => [0x1E, 0x11, 0x11, 0x1E, 0x14, 0x12, 0x11],
+		'S' => [0x0F, 0x10, 0x10, 0x0E, 0x01, 0x01, 0x1E],
+		'T' => [0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04],
+		'U' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+		'V' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x0A, 0x04],
+		'W' => [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0A],
+		'X' => [0x11, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x11],
+		'Y' => [0x11, 0x11, 0x0A, 0x04, 0x04, 0x04, 0x04],
+		'Z' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1F],
+		_ => [0x00; 7],
+	}
+}
+
+/// Width in pixels of `text` drawn at `scale`.
+pub fn text_width(text: &str, scale: u32) -> u32 {
+	let count = text.chars().count() as u32;
+	if count == 0 {
+		return 0;
+	}
+
+	(count * (GLYPH_WIDTH + GLYPH_SPACING) - GLYPH_SPACING) * scale
+}
+
+/// Draw `text` with its top-left corner at `(x, y)`. Pixels falling outside
+/// the image are clipped; unsupported characters render as blanks.
+pub fn draw_text(img: &mut RgbaImage, text: &str, x: u32, y: u32, scale: u32, color: Rgba<u8>) {
+	let scale = scale.max(1);
+	let mut cursor = x;
+
+	for c in text.chars() {
+		for (row, bits) in glyph(c).iter().enumerate() {
+			for col in 0..GLYPH_WIDTH {
+				if bits & (0x10 >> col) == 0 {
+					continue;
+				}
+
+				let px = cursor + col * scale;
+				let py = y + row as u32 * scale;
+				for dx in 0..scale {
+					for dy in 0..scale {
+						if px + dx < img.width() && py + dy < img.height() {
+							img.put_pixel(px + dx, py + dy, color);
+						}
+					}
+				}
+			}
+		}
+
+		cursor += (GLYPH_WIDTH + GLYPH_SPACING) * scale;
+	}
+}
+
+/// Shorten large counts so they fit on a card: `12345` becomes `12.3K`.
+pub fn compact_number(value: i64) -> String {
+	let abs = value.unsigned_abs();
+	if abs < 10_000 {
+		value.to_string()
+	} else if abs < 1_000_000 {
+		format!("{:.1}K", value as f64 / 1_000.0)
+	} else {
+		format!("{:.1}M", value as f64 / 1_000_000.0)
+	}
+}
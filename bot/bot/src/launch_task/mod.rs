@@ -3,6 +3,7 @@ pub mod game_management;
 pub mod ping_manager;
 pub mod queue_publisher;
 pub mod user_blacklist;
+pub mod voice_xp;
 
 use crate::event_handler::BotData;
 use serenity::all::Context as SerenityContext;
@@ -15,6 +16,7 @@ use self::bot_info_update::update_bot_info;
 use self::game_management::launch_game_management_thread;
 use self::ping_manager::ping_manager_thread;
 use self::user_blacklist::update_user_blacklist;
+use self::voice_xp::voice_xp_thread;
 
 /// Main function responsible for launching and managing all background tasks.
 #[tracing::instrument(skip(ctx, bot_data), level = "info")]
@@ -61,6 +63,26 @@ pub async fn thread_management_launcher(ctx: SerenityContext, bot_data: Arc<BotD
 	});
 	shutdown_receivers.push(game_task);
 
+	debug!(
+		"Spawning voice XP task (interval: {}s)",
+		task_intervals.voice_xp_update
+	);
+	let task_intervals_c = task_intervals.clone();
+	let ctx_c = ctx.clone();
+	let db_connection_c = db_connection.clone();
+	let mut voice_xp_shutdown_rx = shutdown_signal.subscribe();
+	let voice_xp_task = tokio::spawn(async move {
+		tokio::select! {
+			_ = voice_xp_thread(ctx_c, db_connection_c, task_intervals_c) => {
+				info!("Voice XP task completed");
+			},
+			_ = voice_xp_shutdown_rx.recv() => {
+				info!("Received shutdown signal, terminating voice XP task gracefully");
+			}
+		}
+	});
+	shutdown_receivers.push(voice_xp_task);
+
 	// === BOT STATUS TASKS ===
 	info!("Launching bot status monitoring background tasks");
 
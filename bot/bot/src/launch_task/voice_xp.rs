@@ -0,0 +1,154 @@
+use crate::handlers::user_db::add_user_data_to_db;
+use crate::leveling::curve::LevelCurve;
+use crate::leveling::{add_xp, get_level_config, handle_level_up, XpSource};
+use crate::voice_tracking::heartbeat;
+use anyhow::Result;
+use sea_orm::DatabaseConnection;
+use serenity::all::{ChannelId, Context as SerenityContext, GuildId, User};
+use shared::config::TaskIntervalConfig;
+use shared::helper::get_module_state::{is_module_enabled, GuildModule};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+/// Periodically credits voice XP to members sitting in voice channels and
+/// refreshes the heartbeat of open vocal sessions.
+#[tracing::instrument(skip(ctx, db_connection, task_intervals), level = "info")]
+pub async fn voice_xp_thread(
+	ctx: SerenityContext, db_connection: Arc<DatabaseConnection>,
+	task_intervals: TaskIntervalConfig,
+) {
+	info!("Launching the voice XP thread!");
+
+	let tick_secs = task_intervals.voice_xp_update.max(1);
+	let mut interval = tokio::time::interval(Duration::from_secs(tick_secs));
+
+	loop {
+		interval.tick().await;
+
+		if let Err(e) = heartbeat(&db_connection).await {
+			warn!(error = ?e, "Failed to refresh open vocal sessions");
+		}
+
+		for guild_id in ctx.cache.guilds() {
+			if let Err(e) = credit_guild(&ctx, &db_connection, guild_id, tick_secs).await {
+				warn!(guild_id = %guild_id, error = ?e, "Failed to credit voice XP");
+			}
+		}
+	}
+}
+
+async fn credit_guild(
+	ctx: &SerenityContext, db_connection: &Arc<DatabaseConnection>, guild_id: GuildId,
+	tick_secs: u64,
+) -> Result<()> {
+	let members = eligible_voice_members(ctx, guild_id);
+	if members.is_empty() {
+		return Ok(());
+	}
+
+	if !is_module_enabled(
+		guild_id.to_string(),
+		GuildModule::Level,
+		db_connection.clone(),
+	)
+	.await
+	{
+		return Ok(());
+	}
+
+	let config = get_level_config(guild_id.to_string(), db_connection).await?;
+	let curve = LevelCurve::from(&config);
+	let amount = config.voice_xp_per_minute as i64 * tick_secs as i64 / 60;
+	debug!(
+		guild_id = %guild_id,
+		members = members.len(),
+		amount,
+		"Crediting voice XP"
+	);
+
+	for user in members {
+		let user_id = user.id;
+		// user_level references user_data, voice-only members may not have a row yet
+		if add_user_data_to_db(user, db_connection.clone())
+			.await
+			.is_err()
+		{
+			continue;
+		}
+
+		// One member failing must not cost the rest of the guild their XP
+		let level_up = match add_xp(
+			db_connection,
+			&guild_id.to_string(),
+			&user_id.to_string(),
+			XpSource::Voice,
+			amount,
+			None,
+			&curve,
+		)
+		.await
+		{
+			Ok(level_up) => level_up,
+			Err(e) => {
+				warn!(guild_id = %guild_id, user_id = %user_id, error = ?e, "Failed to credit voice XP");
+				continue;
+			},
+		};
+
+		if let Some(level_up) = level_up {
+			if let Err(e) = handle_level_up(ctx, guild_id, user_id, &config, level_up, None).await {
+				warn!(guild_id = %guild_id, user_id = %user_id, error = ?e, "Failed to handle a voice level-up");
+			}
+		}
+	}
+
+	Ok(())
+}
+
+/// Members that should earn voice XP right now: not in the AFK channel, not
+/// muted or deafened, not a bot, and sharing their channel with at least one
+/// other eligible member.
+fn eligible_voice_members(ctx: &SerenityContext, guild_id: GuildId) -> Vec<User> {
+	let Some(guild) = guild_id.to_guild_cached(&ctx.cache) else {
+		return Vec::new();
+	};
+
+	let afk_channel = guild.afk_metadata.as_ref().map(|afk| afk.afk_channel_id);
+
+	let mut by_channel: HashMap<ChannelId, Vec<User>> = HashMap::new();
+	for voice_state in guild.voice_states.iter() {
+		let Some(channel_id) = voice_state.channel_id else {
+			continue;
+		};
+
+		if Some(channel_id) == afk_channel
+			|| voice_state.self_mute()
+			|| voice_state.self_deaf()
+			|| voice_state.mute()
+			|| voice_state.deaf()
+		{
+			continue;
+		}
+
+		let Some(member) = guild.members.get(&voice_state.user_id) else {
+			continue;
+		};
+
+		if member.user.bot() {
+			continue;
+		}
+
+		by_channel
+			.entry(channel_id)
+			.or_default()
+			.push(member.user.clone());
+	}
+
+	by_channel
+		.into_values()
+		.filter(|members| members.len() >= 2)
+		.flatten()
+		.collect()
+}
@@ -0,0 +1,283 @@
+//! Message and voice activity totals for a member.
+//!
+//! The worker folds raw `message` and `vocal` rows into per-day rollups. The
+//! last rolled-up day can still be partial, so totals read the rollups for the
+//! days before it and aggregate the raw rows from that day on. Vocal rows are
+//! split on when they were recorded, like the rollup does, since a session
+//! only gets its row once it closes. Nothing is loaded row by row, every
+//! figure is a single SQL aggregate.
+use anyhow::Result;
+use chrono::NaiveDate;
+use sea_orm::prelude::Expr;
+use sea_orm::{
+	ColumnTrait, DatabaseConnection, EntityTrait, FromQueryResult, QueryFilter, QueryOrder,
+	QuerySelect,
+};
+use shared::database::prelude::{Message, MessageDailyStats, Vocal, VocalDailyStats};
+use shared::database::{message, message_daily_stats, vocal, vocal_daily_stats};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ActivityTotals {
+	pub messages: i64,
+	pub vocal_sessions: i64,
+	pub vocal_secs: i64,
+	/// Muted, deafened and AFK time, never more than `vocal_secs`.
+	pub vocal_idle_secs: i64,
+}
+
+#[derive(Debug, Default, FromQueryResult)]
+struct MessageSums {
+	messages: i64,
+}
+
+#[derive(Debug, Default, FromQueryResult)]
+struct VocalSums {
+	sessions: i64,
+	duration_secs: i64,
+	idle_secs: i64,
+}
+
+/// Activity of `user_id` in the given channels, over all recorded history.
+pub async fn user_activity(
+	db_connection: &DatabaseConnection, user_id: &str, channel_ids: Vec<String>,
+) -> Result<ActivityTotals> {
+	let messages = message_totals(db_connection, user_id, &channel_ids).await?;
+	let vocal = vocal_totals(db_connection, user_id, &channel_ids).await?;
+
+	Ok(ActivityTotals {
+		messages: messages.messages,
+		vocal_sessions: vocal.sessions,
+		vocal_secs: vocal.duration_secs,
+		vocal_idle_secs: vocal.idle_secs.min(vocal.duration_secs),
+	})
+}
+
+async fn message_totals(
+	db_connection: &DatabaseConnection, user_id: &str, channel_ids: &[String],
+) -> Result<MessageSums> {
+	let boundary = MessageDailyStats::find()
+		.order_by_desc(message_daily_stats::Column::Day)
+		.one(db_connection)
+		.await?
+		.map(|row| row.day);
+
+	let mut rolled = MessageSums::default();
+	let mut live = Message::find()
+		.select_only()
+		.column_as(message::Column::Id.count(), "messages")
+		.filter(message::Column::UserId.eq(user_id))
+		.filter(message::Column::ChannelId.is_in(channel_ids.to_vec()));
+
+	if let Some(boundary) = boundary {
+		rolled = MessageDailyStats::find()
+			.select_only()
+			.column_as(
+				Expr::cust("CAST(COALESCE(SUM(message_count), 0) AS BIGINT)"),
+				"messages",
+			)
+			.filter(message_daily_stats::Column::UserId.eq(user_id))
+			.filter(message_daily_stats::Column::ChannelId.is_in(channel_ids.to_vec()))
+			.filter(message_daily_stats::Column::Day.lt(boundary))
+			.into_model::<MessageSums>()
+			.one(db_connection)
+			.await?
+			.unwrap_or_default();
+
+		live = live.filter(message::Column::CreatedAt.gte(day_start(boundary)));
+	}
+
+	let live = live
+		.into_model::<MessageSums>()
+		.one(db_connection)
+		.await?
+		.unwrap_or_default();
+
+	Ok(MessageSums {
+		messages: rolled.messages + live.messages,
+	})
+}
+
+async fn vocal_totals(
+	db_connection: &DatabaseConnection, user_id: &str, channel_ids: &[String],
+) -> Result<VocalSums> {
+	let boundary = VocalDailyStats::find()
+		.order_by_desc(vocal_daily_stats::Column::Day)
+		.one(db_connection)
+		.await?
+		.map(|row| row.day);
+
+	let mut rolled = VocalSums::default();
+	let mut live = Vocal::find()
+		.select_only()
+		.column_as(vocal::Column::Id.count(), "sessions")
+		.column_as(
+			Expr::cust("CAST(COALESCE(SUM(duration), 0) AS BIGINT)"),
+			"duration_secs",
+		)
+		.column_as(
+			Expr::cust("CAST(COALESCE(SUM(idle_secs), 0) AS BIGINT)"),
+			"idle_secs",
+		)
+		.filter(vocal::Column::UserId.eq(user_id))
+		.filter(vocal::Column::ChannelId.is_in(channel_ids.to_vec()));
+
+	if let Some(boundary) = boundary {
+		rolled = VocalDailyStats::find()
+			.select_only()
+			.column_as(
+				Expr::cust("CAST(COALESCE(SUM(session_count), 0) AS BIGINT)"),
+				"sessions",
+			)
+			.column_as(
+				Expr::cust("CAST(COALESCE(SUM(duration_secs), 0) AS BIGINT)"),
+				"duration_secs",
+			)
+			.column_as(
+				Expr::cust("CAST(COALESCE(SUM(idle_secs), 0) AS BIGINT)"),
+				"idle_secs",
+			)
+			.filter(vocal_daily_stats::Column::UserId.eq(user_id))
+			.filter(vocal_daily_stats::Column::ChannelId.is_in(channel_ids.to_vec()))
+			.filter(vocal_daily_stats::Column::Day.lt(boundary))
+			.into_model::<VocalSums>()
+			.one(db_connection)
+			.await?
+			.unwrap_or_default();
+
+		live = live.filter(vocal::Column::RecordedAt.gte(day_start(boundary)));
+	}
+
+	let live = live
+		.into_model::<VocalSums>()
+		.one(db_connection)
+		.await?
+		.unwrap_or_default();
+
+	Ok(VocalSums {
+		sessions: rolled.sessions + live.sessions,
+		duration_secs: rolled.duration_secs + live.duration_secs,
+		idle_secs: rolled.idle_secs + live.idle_secs,
+	})
+}
+
+fn day_start(day: NaiveDate) -> chrono::NaiveDateTime {
+	day.and_time(Default::default())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use chrono::NaiveDateTime;
+	use sea_orm::ActiveValue::Set;
+	use sea_orm::{ConnectOptions, ConnectionTrait, Database};
+
+	fn at(value: &str) -> NaiveDateTime {
+		NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S").unwrap()
+	}
+
+	async fn database() -> DatabaseConnection {
+		let db = Database::connect(ConnectOptions::new("sqlite::memory:").max_connections(1))
+			.await
+			.unwrap();
+		db.execute_unprepared(
+			"CREATE TABLE vocal (id TEXT PRIMARY KEY, user_id TEXT NOT NULL, start TEXT NOT \
+			 NULL, \"end\" TEXT NOT NULL, duration INTEGER NOT NULL, channel_id TEXT NOT NULL, \
+			 guild_id TEXT, muted_secs INTEGER NOT NULL, deafened_secs INTEGER NOT NULL, \
+			 afk_secs INTEGER NOT NULL, idle_secs INTEGER NOT NULL, recorded_at TEXT NOT NULL)",
+		)
+		.await
+		.unwrap();
+		db.execute_unprepared(
+			"CREATE TABLE vocal_daily_stats (guild_id TEXT NOT NULL, user_id TEXT NOT NULL, \
+			 channel_id TEXT NOT NULL, day TEXT NOT NULL, session_count INTEGER NOT NULL, \
+			 duration_secs INTEGER NOT NULL, idle_secs INTEGER NOT NULL, PRIMARY KEY (guild_id, \
+			 user_id, channel_id, day))",
+		)
+		.await
+		.unwrap();
+
+		db
+	}
+
+	async fn insert_session(
+		db: &DatabaseConnection, id: &str, start: &str, end: &str, duration: i32, recorded_at: &str,
+	) {
+		Vocal::insert(vocal::ActiveModel {
+			id: Set(id.to_string()),
+			user_id: Set(String::from("1")),
+			start: Set(at(start)),
+			end: Set(at(end)),
+			duration: Set(duration),
+			channel_id: Set(String::from("10")),
+			guild_id: Set(Some(String::from("100"))),
+			muted_secs: Set(0),
+			deafened_secs: Set(0),
+			afk_secs: Set(0),
+			idle_secs: Set(0),
+			recorded_at: Set(at(recorded_at)),
+		})
+		.exec(db)
+		.await
+		.unwrap();
+	}
+
+	async fn insert_rollup(db: &DatabaseConnection, day: NaiveDate, duration_secs: i64) {
+		VocalDailyStats::insert(vocal_daily_stats::ActiveModel {
+			guild_id: Set(String::from("100")),
+			user_id: Set(String::from("1")),
+			channel_id: Set(String::from("10")),
+			day: Set(day),
+			session_count: Set(1),
+			duration_secs: Set(duration_secs),
+			idle_secs: Set(0),
+		})
+		.exec(db)
+		.await
+		.unwrap();
+	}
+
+	#[tokio::test]
+	async fn test_session_crossing_the_rollup_boundary_is_counted() {
+		let db = database().await;
+		let first = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+		let second = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+
+		// The worker last ran on the second day, both days are rolled up
+		insert_session(
+			&db,
+			"a",
+			"2026-03-01 10:00:00",
+			"2026-03-01 10:10:00",
+			600,
+			"2026-03-01 10:10:00",
+		)
+		.await;
+		insert_rollup(&db, first, 600).await;
+		insert_session(
+			&db,
+			"b",
+			"2026-03-02 08:00:00",
+			"2026-03-02 08:05:00",
+			300,
+			"2026-03-02 08:05:00",
+		)
+		.await;
+		insert_rollup(&db, second, 300).await;
+
+		// Started before the boundary, closed by a restart after that run
+		insert_session(
+			&db,
+			"c",
+			"2026-03-01 23:30:00",
+			"2026-03-02 00:30:00",
+			3600,
+			"2026-03-03 09:00:00",
+		)
+		.await;
+
+		let totals = vocal_totals(&db, "1", &[String::from("10")]).await.unwrap();
+
+		assert_eq!(totals.sessions, 3);
+		assert_eq!(totals.duration_secs, 600 + 300 + 3600);
+	}
+}
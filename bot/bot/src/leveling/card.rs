@@ -0,0 +1,428 @@
+//! Rank card, leaderboard and activity heatmap rendering.
+//!
+//! Rendering is CPU bound; callers run these functions through
+//! `tokio::task::spawn_blocking` so the gateway tasks are never stalled.
+use crate::helper::pixel_font::{compact_number, draw_text, text_width, GLYPH_HEIGHT};
+use crate::leveling::heatmap::{Heatmap, HOURS, WEEKDAYS};
+use anyhow::Result;
+use image::codecs::png;
+use image::codecs::png::{CompressionType, PngEncoder};
+use image::imageops::FilterType;
+use image::{ExtendedColorType, ImageEncoder, Rgba, RgbaImage};
+
+const BACKGROUND: Rgba<u8> = Rgba([30, 30, 36, 255]);
+const ROW_BACKGROUND: Rgba<u8> = Rgba([40, 40, 48, 255]);
+const BAR_BACKGROUND: Rgba<u8> = Rgba([60, 60, 70, 255]);
+const TEXT: Rgba<u8> = Rgba([235, 235, 240, 255]);
+const MUTED_TEXT: Rgba<u8> = Rgba([160, 160, 170, 255]);
+
+pub const RANK_CARD_WIDTH: u32 = 800;
+pub const RANK_CARD_HEIGHT: u32 = 200;
+pub const LEADERBOARD_WIDTH: u32 = 800;
+pub const LEADERBOARD_ROW_HEIGHT: u32 = 72;
+const LEADERBOARD_PADDING: u32 = 8;
+
+const HEATMAP_EMPTY: Rgba<u8> = Rgba([48, 48, 58, 255]);
+const HEATMAP_PADDING: u32 = 16;
+const HEATMAP_CELL: u32 = 24;
+const HEATMAP_GAP: u32 = 3;
+const HEATMAP_GRID_LEFT: u32 = 64;
+const HEATMAP_LEGEND_WIDTH: u32 = 240;
+/// Title, hour labels, 7 rows of cells and the legend.
+const HEATMAP_PANEL_HEIGHT: u32 =
+	22 + 20 + WEEKDAYS as u32 * (HEATMAP_CELL + HEATMAP_GAP) + 10 + 14 + 20;
+pub const HEATMAP_WIDTH: u32 =
+	HEATMAP_GRID_LEFT + HOURS as u32 * (HEATMAP_CELL + HEATMAP_GAP) - HEATMAP_GAP + HEATMAP_PADDING;
+pub const HEATMAP_HEIGHT: u32 = HEATMAP_PADDING + 2 * HEATMAP_PANEL_HEIGHT;
+const WEEKDAY_LABELS: [&str; WEEKDAYS] = ["MON", "TUE", "WED", "THU", "FRI", "SAT", "SUN"];
+
+pub struct RankCard {
+	pub avatar: Option<Vec<u8>>,
+	pub accent: [u8; 4],
+	pub level: i32,
+	pub xp_progress: i64,
+	pub xp_needed: i64,
+	pub rank: u64,
+}
+
+pub struct LeaderboardRow {
+	pub rank: u64,
+	pub avatar: Option<Vec<u8>>,
+	pub accent: [u8; 4],
+	pub level: i32,
+	pub xp: i64,
+	pub highlighted: bool,
+}
+
+pub fn render_rank_card(card: &RankCard) -> Result<Vec<u8>> {
+	let mut img = RgbaImage::from_pixel(RANK_CARD_WIDTH, RANK_CARD_HEIGHT, BACKGROUND);
+	let accent = Rgba(card.accent);
+
+	fill_rect(&mut img, 0, 0, 8, RANK_CARD_HEIGHT, accent);
+	draw_avatar(&mut img, card.avatar.as_deref(), 28, 20, 160, accent);
+
+	let level = format!("LVL {}", card.level);
+	draw_text(&mut img, &level, 216, 28, 6, TEXT);
+
+	let rank = format!("#{}", card.rank);
+	let rank_width = text_width(&rank, 6);
+	draw_text(
+		&mut img,
+		&rank,
+		RANK_CARD_WIDTH - 28 - rank_width,
+		28,
+		6,
+		accent,
+	);
+
+	let xp = format!(
+		"{}/{} XP",
+		compact_number(card.xp_progress),
+		compact_number(card.xp_needed)
+	);
+	draw_text(&mut img, &xp, 216, 100, 3, MUTED_TEXT);
+
+	draw_bar(
+		&mut img,
+		216,
+		136,
+		RANK_CARD_WIDTH - 216 - 28,
+		32,
+		card.xp_progress,
+		card.xp_needed,
+		accent,
+	);
+
+	encode_png(img)
+}
+
+/// Render one row per entry; bars are scaled against `top_xp` so the first
+/// row of the whole leaderboard is always full.
+pub fn render_leaderboard(rows: &[LeaderboardRow], top_xp: i64) -> Result<Vec<u8>> {
+	let height = LEADERBOARD_PADDING + rows.len().max(1) as u32 * LEADERBOARD_ROW_HEIGHT;
+	let mut img = RgbaImage::from_pixel(LEADERBOARD_WIDTH, height, BACKGROUND);
+
+	for (index, row) in rows.iter().enumerate() {
+		let top = LEADERBOARD_PADDING + index as u32 * LEADERBOARD_ROW_HEIGHT;
+		let inner = LEADERBOARD_ROW_HEIGHT - LEADERBOARD_PADDING;
+		let accent = Rgba(row.accent);
+
+		let background = if row.highlighted {
+			Rgba([
+				row.accent[0] / 4 + 30,
+				row.accent[1] / 4 + 30,
+				row.accent[2] / 4 + 30,
+				255,
+			])
+		} else {
+			ROW_BACKGROUND
+		};
+		fill_rect(
+			&mut img,
+			LEADERBOARD_PADDING,
+			top,
+			LEADERBOARD_WIDTH - 2 * LEADERBOARD_PADDING,
+			inner,
+			background,
+		);
+
+		let rank = format!("#{}", row.rank);
+		let text_top = top + (inner - GLYPH_HEIGHT * 4) / 2;
+		draw_text(&mut img, &rank, 20, text_top, 4, TEXT);
+
+		draw_avatar(
+			&mut img,
+			row.avatar.as_deref(),
+			116,
+			top + 4,
+			inner - 8,
+			accent,
+		);
+
+		let level = format!("LVL {}", row.level);
+		draw_text(&mut img, &level, 188, top + 10, 3, TEXT);
+
+		let xp = format!("{} XP", compact_number(row.xp));
+		let xp_width = text_width(&xp, 3);
+		draw_text(
+			&mut img,
+			&xp,
+			LEADERBOARD_WIDTH - 24 - xp_width,
+			top + 10,
+			3,
+			MUTED_TEXT,
+		);
+
+		draw_bar(
+			&mut img,
+			188,
+			top + inner - 22,
+			LEADERBOARD_WIDTH - 188 - 24,
+			12,
+			row.xp,
+			top_xp,
+			accent,
+		);
+	}
+
+	encode_png(img)
+}
+
+/// Render the message panel above the voice panel, each with its own legend.
+/// Voice cells are drawn in minutes.
+pub fn render_heatmap(heatmap: &Heatmap, accent: [u8; 4]) -> Result<Vec<u8>> {
+	let mut img = RgbaImage::from_pixel(HEATMAP_WIDTH, HEATMAP_HEIGHT, BACKGROUND);
+	let accent = Rgba(accent);
+
+	let mut voice_minutes = [[0i64; HOURS]; WEEKDAYS];
+	for (day, hours) in heatmap.voice_secs.iter().enumerate() {
+		for (hour, secs) in hours.iter().enumerate() {
+			voice_minutes[day][hour] = secs / 60;
+		}
+	}
+
+	draw_heatmap_panel(
+		&mut img,
+		HEATMAP_PADDING,
+		"MESSAGES",
+		&heatmap.messages,
+		accent,
+	);
+	draw_heatmap_panel(
+		&mut img,
+		HEATMAP_PADDING + HEATMAP_PANEL_HEIGHT,
+		"VOICE MINUTES",
+		&voice_minutes,
+		accent,
+	);
+
+	encode_png(img)
+}
+
+fn draw_heatmap_panel(
+	img: &mut RgbaImage, top: u32, title: &str, cells: &[[i64; HOURS]; WEEKDAYS], accent: Rgba<u8>,
+) {
+	let max = cells.iter().flatten().copied().max().unwrap_or(0);
+
+	draw_text(img, title, HEATMAP_PADDING, top, 2, TEXT);
+
+	let labels_top = top + 22;
+	for hour in (0..HOURS).step_by(3) {
+		let x = HEATMAP_GRID_LEFT + hour as u32 * (HEATMAP_CELL + HEATMAP_GAP);
+		draw_text(img, &hour.to_string(), x, labels_top, 2, MUTED_TEXT);
+	}
+
+	let grid_top = labels_top + 20;
+	for (day, hours) in cells.iter().enumerate() {
+		let y = grid_top + day as u32 * (HEATMAP_CELL + HEATMAP_GAP);
+		draw_text(
+			img,
+			WEEKDAY_LABELS[day],
+			HEATMAP_PADDING,
+			y + (HEATMAP_CELL - GLYPH_HEIGHT * 2) / 2,
+			2,
+			MUTED_TEXT,
+		);
+
+		for (hour, value) in hours.iter().enumerate() {
+			let x = HEATMAP_GRID_LEFT + hour as u32 * (HEATMAP_CELL + HEATMAP_GAP);
+			fill_rect(
+				img,
+				x,
+				y,
+				HEATMAP_CELL,
+				HEATMAP_CELL,
+				heat_color(*value, max, accent),
+			);
+		}
+	}
+
+	// Legend: a gradient from empty to the busiest cell of the panel
+	let legend_top = grid_top + WEEKDAYS as u32 * (HEATMAP_CELL + HEATMAP_GAP) + 10;
+	draw_text(img, "0", HEATMAP_GRID_LEFT, legend_top, 2, MUTED_TEXT);
+	let legend_left = HEATMAP_GRID_LEFT + text_width("0", 2) + 8;
+	for dx in 0..HEATMAP_LEGEND_WIDTH {
+		let value = (max as f64 * dx as f64 / (HEATMAP_LEGEND_WIDTH - 1) as f64).round() as i64;
+		let color = if max > 0 {
+			heat_color(value.max(1), max, accent)
+		} else {
+			HEATMAP_EMPTY
+		};
+		fill_rect(
+			img,
+			legend_left + dx,
+			legend_top,
+			1,
+			GLYPH_HEIGHT * 2,
+			color,
+		);
+	}
+	draw_text(
+		img,
+		&compact_number(max),
+		legend_left + HEATMAP_LEGEND_WIDTH + 8,
+		legend_top,
+		2,
+		MUTED_TEXT,
+	);
+}
+
+/// Blend from the empty cell colour to `accent`. Activity is heavily skewed
+/// towards a few hours, so the square root keeps quiet hours visible.
+fn heat_color(value: i64, max: i64, accent: Rgba<u8>) -> Rgba<u8> {
+	if value <= 0 || max <= 0 {
+		return HEATMAP_EMPTY;
+	}
+
+	let t = (value as f64 / max as f64).clamp(0.0, 1.0).sqrt();
+	let blend = |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * t).round() as u8;
+
+	Rgba([
+		blend(HEATMAP_EMPTY[0], accent[0]),
+		blend(HEATMAP_EMPTY[1], accent[1]),
+		blend(HEATMAP_EMPTY[2], accent[2]),
+		255,
+	])
+}
+
+fn fill_rect(img: &mut RgbaImage, x: u32, y: u32, width: u32, height: u32, color: Rgba<u8>) {
+	for px in x..(x + width).min(img.width()) {
+		for py in y..(y + height).min(img.height()) {
+			img.put_pixel(px, py, color);
+		}
+	}
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_bar(
+	img: &mut RgbaImage, x: u32, y: u32, width: u32, height: u32, current: i64, max: i64,
+	color: Rgba<u8>,
+) {
+	fill_rect(img, x, y, width, height, BAR_BACKGROUND);
+
+	let ratio = if max > 0 {
+		(current as f64 / max as f64).clamp(0.0, 1.0)
+	} else {
+		0.0
+	};
+	fill_rect(img, x, y, (width as f64 * ratio) as u32, height, color);
+}
+
+/// Draw a circular avatar with an accent ring. Falls back to a solid disc in
+/// the accent colour when the avatar is missing or cannot be decoded.
+fn draw_avatar(
+	img: &mut RgbaImage, avatar: Option<&[u8]>, x: u32, y: u32, size: u32, accent: Rgba<u8>,
+) {
+	let decoded = avatar
+		.and_then(|bytes| image::load_from_memory(bytes).ok())
+		.map(|avatar| {
+			avatar
+				.resize_exact(size, size, FilterType::Triangle)
+				.to_rgba8()
+		});
+
+	let radius = size as f64 / 2.0;
+	let ring = 4.0;
+
+	for dx in 0..size {
+		for dy in 0..size {
+			let distance =
+				((dx as f64 + 0.5 - radius).powi(2) + (dy as f64 + 0.5 - radius).powi(2)).sqrt();
+			if distance > radius {
+				continue;
+			}
+
+			let color = if distance > radius - ring {
+				accent
+			} else {
+				match &decoded {
+					Some(avatar) => *avatar.get_pixel(dx, dy),
+					None => Rgba([accent[0] / 2, accent[1] / 2, accent[2] / 2, 255]),
+				}
+			};
+
+			if x + dx < img.width() && y + dy < img.height() {
+				img.put_pixel(x + dx, y + dy, color);
+			}
+		}
+	}
+}
+
+fn encode_png(img: RgbaImage) -> Result<Vec<u8>> {
+	let mut image_data: Vec<u8> = Vec::new();
+	PngEncoder::new_with_quality(
+		&mut image_data,
+		CompressionType::Fast,
+		png::FilterType::Adaptive,
+	)
+	.write_image(
+		img.as_raw(),
+		img.width(),
+		img.height(),
+		ExtendedColorType::Rgba8,
+	)?;
+
+	Ok(image_data)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_rank_card_dimensions() {
+		let png = render_rank_card(&RankCard {
+			avatar: None,
+			accent: [200, 80, 120, 255],
+			level: 12,
+			xp_progress: 340,
+			xp_needed: 1000,
+			rank: 3,
+		})
+		.unwrap();
+
+		let img = image::load_from_memory(&png).unwrap();
+		assert_eq!(img.width(), RANK_CARD_WIDTH);
+		assert_eq!(img.height(), RANK_CARD_HEIGHT);
+	}
+
+	#[test]
+	fn test_leaderboard_height_follows_rows() {
+		let rows: Vec<LeaderboardRow> = (1..=4)
+			.map(|rank| LeaderboardRow {
+				rank,
+				avatar: Some(vec![0, 1, 2]),
+				accent: [80, 120, 200, 255],
+				level: 5,
+				xp: 1000 / rank as i64,
+				highlighted: rank == 2,
+			})
+			.collect();
+
+		let png = render_leaderboard(&rows, 1000).unwrap();
+
+		let img = image::load_from_memory(&png).unwrap();
+		assert_eq!(
+			img.height(),
+			LEADERBOARD_PADDING + 4 * LEADERBOARD_ROW_HEIGHT
+		);
+	}
+
+	#[test]
+	fn test_heatmap_dimensions_and_busiest_cell() {
+		let accent = [200, 80, 120, 255];
+		let mut heatmap = Heatmap::default();
+		heatmap.messages[2][14] = 40;
+		heatmap.messages[2][15] = 10;
+
+		let png = render_heatmap(&heatmap, accent).unwrap();
+
+		let img = image::load_from_memory(&png).unwrap().to_rgba8();
+		assert_eq!(img.width(), HEATMAP_WIDTH);
+		assert_eq!(img.height(), HEATMAP_HEIGHT);
+
+		let x = HEATMAP_GRID_LEFT + 14 * (HEATMAP_CELL + HEATMAP_GAP) + 1;
+		let y = HEATMAP_PADDING + 42 + 2 * (HEATMAP_CELL + HEATMAP_GAP) + 1;
+		assert_eq!(*img.get_pixel(x, y), Rgba(accent));
+	}
+}
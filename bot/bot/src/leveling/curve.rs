@@ -0,0 +1,95 @@
+use shared::database::guild_level_config;
+
+/// Hard ceiling so a misconfigured curve can never make `level_for_xp` spin forever.
+pub const MAX_LEVEL: i32 = 1000;
+
+/// Total XP required to reach a level: `base * level^exponent`, rounded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LevelCurve {
+	pub base: i32,
+	pub exponent: f64,
+}
+
+impl LevelCurve {
+	pub fn new(base: i32, exponent: f64) -> Self {
+		Self {
+			base: base.max(1),
+			exponent: exponent.clamp(1.0, 3.0),
+		}
+	}
+
+	/// Cumulative XP needed to reach `level`. Level 0 is free.
+	pub fn xp_for_level(&self, level: i32) -> i64 {
+		if level <= 0 {
+			return 0;
+		}
+
+		(self.base as f64 * (level as f64).powf(self.exponent)).round() as i64
+	}
+
+	/// Highest level whose threshold `xp` has reached.
+	pub fn level_for_xp(&self, xp: i64) -> i32 {
+		let mut level = 0;
+		while level < MAX_LEVEL && self.xp_for_level(level + 1) <= xp {
+			level += 1;
+		}
+
+		level
+	}
+
+	/// `(xp into the current level, xp span of the current level)` for progress bars.
+	pub fn progress(&self, xp: i64) -> (i64, i64) {
+		let level = self.level_for_xp(xp);
+		let current = self.xp_for_level(level);
+		let next = self.xp_for_level(level + 1);
+
+		(xp - current, (next - current).max(1))
+	}
+}
+
+impl From<&guild_level_config::Model> for LevelCurve {
+	fn from(config: &guild_level_config::Model) -> Self {
+		Self::new(config.curve_base, config.curve_exponent)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_xp_for_level_follows_curve() {
+		let curve = LevelCurve::new(100, 1.5);
+
+		assert_eq!(curve.xp_for_level(0), 0);
+		assert_eq!(curve.xp_for_level(1), 100);
+		assert_eq!(curve.xp_for_level(4), 800);
+	}
+
+	#[test]
+	fn test_level_for_xp_is_inverse_of_threshold() {
+		let curve = LevelCurve::new(100, 1.5);
+
+		assert_eq!(curve.level_for_xp(0), 0);
+		assert_eq!(curve.level_for_xp(99), 0);
+		assert_eq!(curve.level_for_xp(100), 1);
+		assert_eq!(curve.level_for_xp(799), 3);
+		assert_eq!(curve.level_for_xp(800), 4);
+	}
+
+	#[test]
+	fn test_progress_within_level() {
+		let curve = LevelCurve::new(100, 1.0);
+
+		assert_eq!(curve.progress(250), (50, 100));
+	}
+
+	#[test]
+	fn test_curve_clamps_bad_config() {
+		let curve = LevelCurve::new(0, 10.0);
+
+		assert_eq!(curve.base, 1);
+		assert_eq!(curve.exponent, 3.0);
+		assert_eq!(curve.level_for_xp(i64::MAX), MAX_LEVEL);
+	}
+}
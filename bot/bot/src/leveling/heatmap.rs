@@ -0,0 +1,266 @@
+//! Weekday × hour activity grids for the `levels heatmap` command.
+//!
+//! Rows are bucketed per UTC hour in SQL, so only a few thousand buckets
+//! leave the database even for a whole guild over a year. Each bucket is then
+//! shifted into the guild timezone, which keeps daylight saving transitions
+//! correct. Zones with a non-whole-hour offset land on the hour they start in.
+use anyhow::Result;
+use chrono::{Datelike, Duration, NaiveDateTime, TimeZone, Timelike, Utc};
+use chrono_tz::Tz;
+use sea_orm::prelude::Expr;
+use sea_orm::{
+	ColumnTrait, ConnectionTrait, DatabaseBackend, DatabaseConnection, EntityTrait,
+	FromQueryResult, QueryFilter, QuerySelect,
+};
+use shared::database::prelude::{Message, Vocal};
+use shared::database::{message, vocal};
+use tracing::warn;
+
+pub const WEEKDAYS: usize = 7;
+pub const HOURS: usize = 24;
+
+/// A single voice session never spreads over more than a week of cells.
+const MAX_SPREAD_HOURS: i64 = 24 * 7;
+
+#[derive(Debug, Clone, Copy)]
+pub enum HeatmapPeriod {
+	Week,
+	Month,
+	Quarter,
+	Year,
+}
+
+impl HeatmapPeriod {
+	pub fn from_choice(choice: &str) -> Self {
+		match choice {
+			"week" => HeatmapPeriod::Week,
+			"quarter" => HeatmapPeriod::Quarter,
+			"year" => HeatmapPeriod::Year,
+			_ => HeatmapPeriod::Month,
+		}
+	}
+
+	pub fn days(self) -> i64 {
+		match self {
+			HeatmapPeriod::Week => 7,
+			HeatmapPeriod::Month => 30,
+			HeatmapPeriod::Quarter => 90,
+			HeatmapPeriod::Year => 365,
+		}
+	}
+
+	/// Days of messages the period can show. The worker deletes messages
+	/// older than `retention_days`, 0 keeping them forever.
+	pub fn message_days(self, retention_days: u32) -> i64 {
+		match retention_days {
+			0 => self.days(),
+			retention_days => self.days().min(i64::from(retention_days)),
+		}
+	}
+}
+
+/// Cells are indexed `[weekday from Monday][local hour]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Heatmap {
+	pub messages: [[i64; HOURS]; WEEKDAYS],
+	pub voice_secs: [[i64; HOURS]; WEEKDAYS],
+}
+
+impl Heatmap {
+	fn cell(hour_utc: NaiveDateTime, timezone: &Tz) -> (usize, usize) {
+		let local = timezone.from_utc_datetime(&hour_utc);
+
+		(
+			local.weekday().num_days_from_monday() as usize,
+			local.hour() as usize,
+		)
+	}
+
+	pub fn add_messages(&mut self, hour_utc: NaiveDateTime, count: i64, timezone: &Tz) {
+		let (day, hour) = Self::cell(hour_utc, timezone);
+		self.messages[day][hour] += count;
+	}
+
+	/// Spread `sessions` voice sessions starting in the hour `hour_utc` and
+	/// lasting `total_secs` together over the hours they cover, assuming
+	/// they share the average length and start on the hour.
+	pub fn add_voice(
+		&mut self, hour_utc: NaiveDateTime, sessions: i64, total_secs: i64, timezone: &Tz,
+	) {
+		if sessions <= 0 || total_secs <= 0 {
+			return;
+		}
+
+		let per_session = total_secs / sessions;
+		let remainder = total_secs % sessions;
+
+		for offset in 0..MAX_SPREAD_HOURS {
+			let in_hour = (per_session - offset * 3600).clamp(0, 3600);
+			let mut secs = in_hour * sessions;
+			if offset == 0 {
+				secs += remainder;
+			}
+			if secs == 0 {
+				break;
+			}
+
+			let (day, hour) = Self::cell(hour_utc + Duration::hours(offset), timezone);
+			self.voice_secs[day][hour] += secs;
+		}
+	}
+
+	pub fn total_messages(&self) -> i64 {
+		self.messages.iter().flatten().sum()
+	}
+
+	pub fn total_voice_secs(&self) -> i64 {
+		self.voice_secs.iter().flatten().sum()
+	}
+}
+
+#[derive(Debug, FromQueryResult)]
+struct MessageBucket {
+	bucket: String,
+	messages: i64,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct VocalBucket {
+	bucket: String,
+	sessions: i64,
+	duration_secs: i64,
+}
+
+/// SQL expression truncating `column` to its UTC hour as `YYYY-MM-DD HH`.
+fn hour_bucket_expr(backend: DatabaseBackend, column: &str) -> String {
+	match backend {
+		DatabaseBackend::Postgres => format!("to_char({}, 'YYYY-MM-DD HH24')", column),
+		_ => format!("strftime('%Y-%m-%d %H', {})", column),
+	}
+}
+
+fn parse_bucket(bucket: &str) -> Option<NaiveDateTime> {
+	match NaiveDateTime::parse_from_str(&format!("{}:00", bucket), "%Y-%m-%d %H:%M") {
+		Ok(hour) => Some(hour),
+		Err(e) => {
+			warn!("Skipping heatmap bucket {:?}: {}", bucket, e);
+			None
+		},
+	}
+}
+
+/// Build the heatmap of `user_id`, or of everyone when `None`, over the
+/// given channels for the last `period`. Messages only go back
+/// [`HeatmapPeriod::message_days`], voice sessions are never purged.
+pub async fn load_heatmap(
+	db_connection: &DatabaseConnection, user_id: Option<&str>, channel_ids: Vec<String>,
+	period: HeatmapPeriod, retention_days: u32, timezone: &Tz,
+) -> Result<Heatmap> {
+	let now = Utc::now().naive_utc();
+	let since = now - Duration::days(period.days());
+	let messages_since = now - Duration::days(period.message_days(retention_days));
+	let backend = db_connection.get_database_backend();
+	let mut heatmap = Heatmap::default();
+
+	let message_bucket = hour_bucket_expr(backend, "created_at");
+	let mut messages = Message::find()
+		.select_only()
+		.column_as(Expr::cust(message_bucket.clone()), "bucket")
+		.column_as(message::Column::Id.count(), "messages")
+		.filter(message::Column::ChannelId.is_in(channel_ids.clone()))
+		.filter(message::Column::CreatedAt.gte(messages_since));
+	if let Some(user_id) = user_id {
+		messages = messages.filter(message::Column::UserId.eq(user_id));
+	}
+
+	let messages = messages
+		.group_by(Expr::cust(message_bucket))
+		.into_model::<MessageBucket>()
+		.all(db_connection)
+		.await?;
+
+	for row in messages {
+		if let Some(hour) = parse_bucket(&row.bucket) {
+			heatmap.add_messages(hour, row.messages, timezone);
+		}
+	}
+
+	let vocal_bucket = hour_bucket_expr(backend, "start");
+	let mut vocals = Vocal::find()
+		.select_only()
+		.column_as(Expr::cust(vocal_bucket.clone()), "bucket")
+		.column_as(vocal::Column::Id.count(), "sessions")
+		.column_as(
+			Expr::cust("CAST(COALESCE(SUM(duration), 0) AS BIGINT)"),
+			"duration_secs",
+		)
+		.filter(vocal::Column::ChannelId.is_in(channel_ids))
+		.filter(vocal::Column::Start.gte(since));
+	if let Some(user_id) = user_id {
+		vocals = vocals.filter(vocal::Column::UserId.eq(user_id));
+	}
+
+	let vocals = vocals
+		.group_by(Expr::cust(vocal_bucket))
+		.into_model::<VocalBucket>()
+		.all(db_connection)
+		.await?;
+
+	for row in vocals {
+		if let Some(hour) = parse_bucket(&row.bucket) {
+			heatmap.add_voice(hour, row.sessions, row.duration_secs, timezone);
+		}
+	}
+
+	Ok(heatmap)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use chrono::NaiveDate;
+
+	fn hour(day: u32, hour: u32) -> NaiveDateTime {
+		NaiveDate::from_ymd_opt(2026, 1, day)
+			.unwrap()
+			.and_hms_opt(hour, 0, 0)
+			.unwrap()
+	}
+
+	#[test]
+	fn test_messages_shift_into_timezone() {
+		// 2026-01-05 is a Monday; 23:00 UTC is already Tuesday in Paris
+		let mut heatmap = Heatmap::default();
+		heatmap.add_messages(hour(5, 23), 4, &chrono_tz::Europe::Paris);
+
+		assert_eq!(heatmap.messages[1][0], 4);
+		assert_eq!(heatmap.total_messages(), 4);
+	}
+
+	#[test]
+	fn test_voice_spreads_over_following_hours() {
+		// Two sessions of 90 minutes each starting at 10:00 UTC on Monday
+		let mut heatmap = Heatmap::default();
+		heatmap.add_voice(hour(5, 10), 2, 2 * 5400, &Tz::UTC);
+
+		assert_eq!(heatmap.voice_secs[0][10], 7200);
+		assert_eq!(heatmap.voice_secs[0][11], 3600);
+		assert_eq!(heatmap.total_voice_secs(), 10800);
+	}
+
+	#[test]
+	fn test_messages_stop_at_the_retention() {
+		assert_eq!(HeatmapPeriod::Year.message_days(90), 90);
+		assert_eq!(HeatmapPeriod::Week.message_days(90), 7);
+		assert_eq!(HeatmapPeriod::Year.message_days(0), 365);
+	}
+
+	#[test]
+	fn test_voice_keeps_uneven_totals() {
+		let mut heatmap = Heatmap::default();
+		heatmap.add_voice(hour(5, 22), 3, 7201, &Tz::UTC);
+
+		assert_eq!(heatmap.total_voice_secs(), 7201);
+		assert_eq!(heatmap.voice_secs[0][22], 7201);
+	}
+}
@@ -0,0 +1,313 @@
+//! Data loading and caching for the rank card and leaderboard images.
+//!
+//! Cache keys embed the XP values being drawn, so any XP change produces a
+//! new key and a stale image is simply never looked up again.
+use crate::constant::COLOR;
+use crate::event_handler::BotData;
+use crate::leveling::card::{render_leaderboard, render_rank_card, LeaderboardRow, RankCard};
+use crate::leveling::curve::LevelCurve;
+use anyhow::{anyhow, Result};
+use sea_orm::{ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect};
+use serenity::all::User;
+use shared::database::prelude::{UserColor, UserLevel};
+use shared::database::{user_color, user_level};
+use shared::image_saver::storage::ImageStore;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use tracing::{debug, trace};
+
+pub const LEADERBOARD_PAGE_SIZE: u64 = 10;
+
+pub struct LeaderboardEntry {
+	pub rank: u64,
+	pub user_id: String,
+	pub level: i32,
+	pub xp: i64,
+}
+
+pub struct LeaderboardPage {
+	pub image: Arc<Vec<u8>>,
+	pub entries: Vec<LeaderboardEntry>,
+	pub page: u64,
+	pub total_pages: u64,
+}
+
+pub struct RankCardResult {
+	pub image: Arc<Vec<u8>>,
+	pub level: i32,
+	pub xp: i64,
+	pub rank: u64,
+}
+
+/// Parse the `#rrggbb` colours stored in `user_color`.
+pub fn parse_hex_color(hex: &str) -> Option<[u8; 4]> {
+	let hex = hex.trim_start_matches('#');
+	if hex.len() != 6 {
+		return None;
+	}
+
+	let value = u32::from_str_radix(hex, 16).ok()?;
+
+	Some([(value >> 16) as u8, (value >> 8) as u8, value as u8, 255])
+}
+
+fn default_accent() -> [u8; 4] {
+	[COLOR.r(), COLOR.g(), COLOR.b(), 255]
+}
+
+/// Colour stored for `user_id` by the profile colour feature, or the bot
+/// colour when none is stored yet.
+pub async fn user_accent(bot_data: &BotData, user_id: &str) -> Result<[u8; 4]> {
+	let stored_color = UserColor::find_by_id(user_id.to_string())
+		.one(&*bot_data.db_connection)
+		.await?;
+
+	Ok(stored_color
+		.and_then(|color| parse_hex_color(&color.color))
+		.unwrap_or_else(default_accent))
+}
+
+/// Accent used for images that are not about a single member.
+pub fn guild_accent() -> [u8; 4] {
+	default_accent()
+}
+
+/// 1-based position of `xp` in the guild, ties sharing the better rank.
+async fn guild_rank(bot_data: &BotData, guild_id: &str, xp: i64) -> Result<u64> {
+	let above = UserLevel::find()
+		.filter(user_level::Column::GuildId.eq(guild_id))
+		.filter(user_level::Column::Xp.gt(xp))
+		.count(&*bot_data.db_connection)
+		.await?;
+
+	Ok(above + 1)
+}
+
+/// Ranks of rows sorted by XP, starting `offset` rows into the guild with
+/// `first_rank` as the rank of the first one. Tied rows share the better
+/// rank, like [`guild_rank`]. The minigame leaderboards rank points the same way.
+pub(crate) fn shared_ranks(xps: &[i64], offset: u64, first_rank: u64) -> Vec<u64> {
+	let mut ranks: Vec<u64> = Vec::with_capacity(xps.len());
+	for (index, xp) in xps.iter().enumerate() {
+		let rank = match (index, ranks.last()) {
+			(0, _) => first_rank,
+			(_, Some(previous)) if xps[index - 1] == *xp => *previous,
+			_ => offset + index as u64 + 1,
+		};
+		ranks.push(rank);
+	}
+
+	ranks
+}
+
+pub async fn rank_card(
+	bot_data: &BotData, guild_id: &str, user: &User, curve: &LevelCurve,
+) -> Result<RankCardResult> {
+	let db_connection = bot_data.db_connection.clone();
+	let user_id = user.id.to_string();
+
+	let xp = UserLevel::find_by_id((guild_id.to_string(), user_id.clone()))
+		.one(&*db_connection)
+		.await?
+		.map(|row| row.xp)
+		.unwrap_or_default();
+	let level = curve.level_for_xp(xp);
+	let (xp_progress, xp_needed) = curve.progress(xp);
+	let rank = guild_rank(bot_data, guild_id, xp).await?;
+
+	let stored_color = UserColor::find_by_id(user_id.clone())
+		.one(&*db_connection)
+		.await?;
+	let accent = stored_color
+		.as_ref()
+		.and_then(|color| parse_hex_color(&color.color))
+		.unwrap_or_else(default_accent);
+	let avatar_url = user.face();
+
+	let key = format!(
+		"rank:{}:{}:{}:{}:{}:{:?}:{}:{}",
+		guild_id, user_id, xp, rank, avatar_url, accent, curve.base, curve.exponent
+	);
+	if let Some(image) = bot_data.level_image_cache.get(&key).await {
+		trace!(key = %key, "Rank card cache hit");
+		return Ok(RankCardResult {
+			image,
+			level,
+			xp,
+			rank,
+		});
+	}
+
+	let avatar = match bot_data.http_client.get(&avatar_url).send().await {
+		Ok(response) => response.bytes().await.ok().map(|bytes| bytes.to_vec()),
+		Err(e) => {
+			debug!(user_id = %user_id, error = %e, "Failed to download avatar for rank card");
+			None
+		},
+	};
+	let avatar = match (avatar, stored_color) {
+		(Some(avatar), _) => Some(avatar),
+		(None, Some(color)) => bot_data.image_store.load(&color.images).await.ok(),
+		(None, None) => None,
+	};
+
+	let card = RankCard {
+		avatar,
+		accent,
+		level,
+		xp_progress,
+		xp_needed,
+		rank,
+	};
+	let image = tokio::task::spawn_blocking(move || render_rank_card(&card))
+		.await
+		.map_err(|e| anyhow!("Rank card render task failed: {}", e))??;
+	let image = Arc::new(image);
+	bot_data.level_image_cache.insert(key, image.clone()).await;
+
+	Ok(RankCardResult {
+		image,
+		level,
+		xp,
+		rank,
+	})
+}
+
+pub async fn leaderboard_page(
+	bot_data: &BotData, guild_id: &str, viewer_id: &str, page: u64, curve: &LevelCurve,
+) -> Result<LeaderboardPage> {
+	let db_connection = bot_data.db_connection.clone();
+
+	let total = UserLevel::find()
+		.filter(user_level::Column::GuildId.eq(guild_id))
+		.count(&*db_connection)
+		.await?;
+	let total_pages = total.div_ceil(LEADERBOARD_PAGE_SIZE).max(1);
+	let page = page.min(total_pages - 1);
+
+	let rows = UserLevel::find()
+		.filter(user_level::Column::GuildId.eq(guild_id))
+		.order_by_desc(user_level::Column::Xp)
+		.order_by_asc(user_level::Column::UserId)
+		.offset(page * LEADERBOARD_PAGE_SIZE)
+		.limit(LEADERBOARD_PAGE_SIZE)
+		.all(&*db_connection)
+		.await?;
+
+	let top_xp = UserLevel::find()
+		.filter(user_level::Column::GuildId.eq(guild_id))
+		.order_by_desc(user_level::Column::Xp)
+		.one(&*db_connection)
+		.await?
+		.map(|row| row.xp)
+		.unwrap_or_default();
+
+	// Same rule as the rank card, so a member has one rank everywhere
+	let first_rank = match rows.first() {
+		Some(row) => guild_rank(bot_data, guild_id, row.xp).await?,
+		None => 1,
+	};
+	let xps: Vec<i64> = rows.iter().map(|row| row.xp).collect();
+	let ranks = shared_ranks(&xps, page * LEADERBOARD_PAGE_SIZE, first_rank);
+
+	let entries: Vec<LeaderboardEntry> = rows
+		.into_iter()
+		.zip(ranks)
+		.map(|(row, rank)| LeaderboardEntry {
+			rank,
+			level: curve.level_for_xp(row.xp),
+			user_id: row.user_id,
+			xp: row.xp,
+		})
+		.collect();
+
+	let mut hasher = DefaultHasher::new();
+	for entry in &entries {
+		entry.user_id.hash(&mut hasher);
+		entry.xp.hash(&mut hasher);
+		entry.rank.hash(&mut hasher);
+	}
+	let key = format!(
+		"leaderboard:{}:{}:{}:{}:{}:{}:{:x}",
+		guild_id,
+		page,
+		viewer_id,
+		top_xp,
+		curve.base,
+		curve.exponent,
+		hasher.finish()
+	);
+
+	if let Some(image) = bot_data.level_image_cache.get(&key).await {
+		trace!(key = %key, "Leaderboard cache hit");
+		return Ok(LeaderboardPage {
+			image,
+			entries,
+			page,
+			total_pages,
+		});
+	}
+
+	let user_ids: Vec<String> = entries.iter().map(|entry| entry.user_id.clone()).collect();
+	let colors: HashMap<String, user_color::Model> = UserColor::find()
+		.filter(user_color::Column::UserId.is_in(user_ids))
+		.all(&*db_connection)
+		.await?
+		.into_iter()
+		.map(|color| (color.user_id.clone(), color))
+		.collect();
+
+	let mut rows = Vec::with_capacity(entries.len());
+	for entry in &entries {
+		let stored = colors.get(&entry.user_id);
+		let avatar = match stored {
+			Some(color) => bot_data.image_store.load(&color.images).await.ok(),
+			None => None,
+		};
+		rows.push(LeaderboardRow {
+			rank: entry.rank,
+			avatar,
+			accent: stored
+				.and_then(|color| parse_hex_color(&color.color))
+				.unwrap_or_else(default_accent),
+			level: entry.level,
+			xp: entry.xp,
+			highlighted: entry.user_id == viewer_id,
+		});
+	}
+
+	let image = tokio::task::spawn_blocking(move || render_leaderboard(&rows, top_xp))
+		.await
+		.map_err(|e| anyhow!("Leaderboard render task failed: {}", e))??;
+	let image = Arc::new(image);
+	bot_data.level_image_cache.insert(key, image.clone()).await;
+
+	Ok(LeaderboardPage {
+		image,
+		entries,
+		page,
+		total_pages,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_hex_color() {
+		assert_eq!(parse_hex_color("#ff8000"), Some([255, 128, 0, 255]));
+		assert_eq!(parse_hex_color("00ff00"), Some([0, 255, 0, 255]));
+		assert_eq!(parse_hex_color("#fff"), None);
+		assert_eq!(parse_hex_color("#zzzzzz"), None);
+	}
+
+	#[test]
+	fn test_tied_members_share_a_rank() {
+		assert_eq!(shared_ranks(&[50, 40, 40, 30], 0, 1), vec![1, 2, 2, 4]);
+		// The page starts in the middle of a tie that began on the page before
+		assert_eq!(shared_ranks(&[40, 40, 10], 10, 9), vec![9, 9, 13]);
+	}
+}
@@ -0,0 +1,352 @@
+//! Guild leveling engine.
+//!
+//! XP is credited incrementally into `user_level` from three sources: messages
+//! (rate limited per user by the guild cooldown), time spent in voice
+//! (credited by the `voice_xp` background task) and minigame catches.
+//! Everything here is gated on the LEVEL module being enabled for the guild.
+pub mod activity;
+pub mod card;
+pub mod curve;
+pub mod heatmap;
+pub mod leaderboard;
+
+use crate::achievement::{track, Stat};
+use crate::constant::COLOR;
+use crate::event_handler::BotData;
+use crate::leveling::curve::LevelCurve;
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use fluent_templates::fluent_bundle::FluentValue;
+use sea_orm::sea_query::{Expr, ExprTrait, OnConflict};
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter};
+use serenity::all::{
+	Context as SerenityContext, CreateEmbed, CreateMessage, GenericChannelId, GuildId, Message,
+	RoleId, UserId,
+};
+use shared::database::prelude::{GuildLevelConfig, LevelReward, UserLevel};
+use shared::database::{guild_level_config, level_reward, user_level};
+use shared::helper::get_module_state::{is_module_enabled, GuildModule};
+use shared::localization::{get_language_identifier, Loader, USABLE_LOCALES};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{debug, info, trace, warn};
+
+pub const DEFAULT_MESSAGE_XP: i32 = 15;
+pub const DEFAULT_VOICE_XP_PER_MINUTE: i32 = 10;
+pub const DEFAULT_MESSAGE_COOLDOWN_SECS: i32 = 60;
+pub const DEFAULT_CURVE_BASE: i32 = 100;
+pub const DEFAULT_CURVE_EXPONENT: f64 = 1.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XpSource {
+	Message,
+	Voice,
+	Minigame,
+}
+
+impl XpSource {
+	fn column(self) -> user_level::Column {
+		match self {
+			XpSource::Message => user_level::Column::MessageXp,
+			XpSource::Voice => user_level::Column::VoiceXp,
+			XpSource::Minigame => user_level::Column::MinigameXp,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelUp {
+	pub old_level: i32,
+	pub new_level: i32,
+}
+
+/// Settings used for guilds that never ran `admin level settings`.
+pub fn default_level_config(guild_id: String) -> guild_level_config::Model {
+	guild_level_config::Model {
+		guild_id,
+		message_xp: DEFAULT_MESSAGE_XP,
+		voice_xp_per_minute: DEFAULT_VOICE_XP_PER_MINUTE,
+		message_cooldown_secs: DEFAULT_MESSAGE_COOLDOWN_SECS,
+		curve_base: DEFAULT_CURVE_BASE,
+		curve_exponent: DEFAULT_CURVE_EXPONENT,
+		announce_level_up: true,
+		level_up_channel_id: None,
+		updated_at: Utc::now().naive_utc(),
+	}
+}
+
+pub async fn get_level_config(
+	guild_id: String, db_connection: &DatabaseConnection,
+) -> Result<guild_level_config::Model> {
+	let config = GuildLevelConfig::find_by_id(guild_id.clone())
+		.one(db_connection)
+		.await?;
+
+	Ok(config.unwrap_or_else(|| default_level_config(guild_id)))
+}
+
+/// Credit `amount` XP to a member and persist any resulting level change.
+///
+/// When `cooldown_secs` is set the credit is skipped (returning `Ok(None)`)
+/// if the member already earned message XP inside that window. The level
+/// column is only raised with a conditional update, so concurrent credits
+/// report a given level-up exactly once.
+pub async fn add_xp(
+	db_connection: &DatabaseConnection, guild_id: &str, user_id: &str, source: XpSource,
+	amount: i64, cooldown_secs: Option<i64>, curve: &LevelCurve,
+) -> Result<Option<LevelUp>> {
+	if amount <= 0 {
+		return Ok(None);
+	}
+
+	let now = Utc::now().naive_utc();
+	let source_column = source.column();
+
+	let mut update = UserLevel::update_many()
+		.col_expr(
+			user_level::Column::Xp,
+			Expr::col(user_level::Column::Xp).add(amount),
+		)
+		.col_expr(source_column, Expr::col(source_column).add(amount))
+		.col_expr(user_level::Column::UpdatedAt, Expr::value(now))
+		.filter(user_level::Column::GuildId.eq(guild_id))
+		.filter(user_level::Column::UserId.eq(user_id));
+
+	if source == XpSource::Message {
+		update = update.col_expr(user_level::Column::LastMessageXpAt, Expr::value(Some(now)));
+	}
+
+	if let Some(cooldown) = cooldown_secs {
+		let cutoff = now - Duration::seconds(cooldown);
+		update = update.filter(
+			Condition::any()
+				.add(user_level::Column::LastMessageXpAt.is_null())
+				.add(user_level::Column::LastMessageXpAt.lte(cutoff)),
+		);
+	}
+
+	if update.exec(db_connection).await?.rows_affected == 0 {
+		let (message_xp, voice_xp, minigame_xp) = match source {
+			XpSource::Message => (amount, 0, 0),
+			XpSource::Voice => (0, amount, 0),
+			XpSource::Minigame => (0, 0, amount),
+		};
+		let inserted = UserLevel::insert(user_level::ActiveModel {
+			guild_id: Set(guild_id.to_string()),
+			user_id: Set(user_id.to_string()),
+			xp: Set(amount),
+			level: Set(0),
+			message_xp: Set(message_xp),
+			voice_xp: Set(voice_xp),
+			minigame_xp: Set(minigame_xp),
+			last_message_xp_at: Set((source == XpSource::Message).then_some(now)),
+			updated_at: Set(now),
+		})
+		.on_conflict(
+			OnConflict::columns([user_level::Column::GuildId, user_level::Column::UserId])
+				.do_nothing()
+				.to_owned(),
+		)
+		.exec_without_returning(db_connection)
+		.await?;
+
+		if inserted == 0 {
+			trace!(
+				guild_id,
+				user_id,
+				"XP credit skipped, member is on cooldown"
+			);
+			return Ok(None);
+		}
+	}
+
+	let Some(row) = UserLevel::find_by_id((guild_id.to_string(), user_id.to_string()))
+		.one(db_connection)
+		.await?
+	else {
+		return Ok(None);
+	};
+
+	let new_level = curve.level_for_xp(row.xp);
+	if new_level <= row.level {
+		return Ok(None);
+	}
+
+	let raised = UserLevel::update_many()
+		.col_expr(user_level::Column::Level, Expr::value(new_level))
+		.filter(user_level::Column::GuildId.eq(guild_id))
+		.filter(user_level::Column::UserId.eq(user_id))
+		.filter(user_level::Column::Level.lt(new_level))
+		.exec(db_connection)
+		.await?;
+
+	if raised.rows_affected == 0 {
+		return Ok(None);
+	}
+
+	debug!(
+		guild_id,
+		user_id,
+		old_level = row.level,
+		new_level,
+		"Member levelled up"
+	);
+
+	Ok(Some(LevelUp {
+		old_level: row.level,
+		new_level,
+	}))
+}
+
+/// Grant the reward roles unlocked by a level-up and post the announcement.
+///
+/// Announcements go to the configured level-up channel, falling back to
+/// `fallback_channel` (the channel of the triggering message) when unset.
+pub async fn handle_level_up(
+	ctx: &SerenityContext, guild_id: GuildId, user_id: UserId, config: &guild_level_config::Model,
+	level_up: LevelUp, fallback_channel: Option<GenericChannelId>,
+) -> Result<()> {
+	let bot_data = ctx.data::<BotData>().clone();
+	let db_connection = bot_data.db_connection.clone();
+
+	let rewards = LevelReward::find()
+		.filter(level_reward::Column::GuildId.eq(guild_id.to_string()))
+		.filter(level_reward::Column::Level.gt(level_up.old_level))
+		.filter(level_reward::Column::Level.lte(level_up.new_level))
+		.all(&*db_connection)
+		.await?;
+
+	for reward in rewards {
+		let Ok(role_id) = reward.role_id.parse::<u64>() else {
+			warn!(role_id = %reward.role_id, "Invalid level reward role id");
+			continue;
+		};
+
+		if let Err(e) = ctx
+			.http
+			.add_member_role(
+				guild_id,
+				user_id,
+				RoleId::new(role_id),
+				Some("Level reward"),
+			)
+			.await
+		{
+			warn!(
+				guild_id = %guild_id,
+				user_id = %user_id,
+				role_id,
+				error = %e,
+				"Failed to grant level reward role"
+			);
+		}
+	}
+
+	let channel = config
+		.level_up_channel_id
+		.as_deref()
+		.and_then(|id| id.parse::<u64>().ok())
+		.map(GenericChannelId::new)
+		.or(fallback_channel);
+
+	track(
+		ctx,
+		user_id,
+		Some(guild_id),
+		channel,
+		Stat::LevelReached,
+		i64::from(level_up.new_level),
+	)
+	.await;
+
+	if !config.announce_level_up {
+		return Ok(());
+	}
+
+	let Some(channel) = channel else {
+		return Ok(());
+	};
+
+	let lang_id = get_language_identifier(guild_id.to_string(), db_connection).await;
+
+	let mut args: HashMap<Cow<'static, str>, FluentValue> = HashMap::new();
+	args.insert(
+		Cow::Borrowed("user"),
+		FluentValue::from(format!("<@{}>", user_id)),
+	);
+	args.insert(
+		Cow::Borrowed("level"),
+		FluentValue::from(level_up.new_level),
+	);
+
+	let embed = CreateEmbed::new()
+		.title(USABLE_LOCALES.lookup(&lang_id, "level_up-title"))
+		.description(USABLE_LOCALES.lookup_with_args(&lang_id, "level_up-desc", &args))
+		.colour(COLOR);
+
+	channel
+		.send_message(&ctx.http, CreateMessage::new().embed(embed))
+		.await?;
+
+	Ok(())
+}
+
+/// Credit message XP for a guild message, respecting the guild cooldown.
+pub async fn handle_message_xp(ctx: &SerenityContext, message: &Message) -> Result<()> {
+	let Some(guild_id) = message.guild_id else {
+		return Ok(());
+	};
+
+	if message.author.bot() {
+		return Ok(());
+	}
+
+	let bot_data = ctx.data::<BotData>().clone();
+	let db_connection: Arc<DatabaseConnection> = bot_data.db_connection.clone();
+
+	if !is_module_enabled(
+		guild_id.to_string(),
+		GuildModule::Level,
+		db_connection.clone(),
+	)
+	.await
+	{
+		return Ok(());
+	}
+
+	let config = get_level_config(guild_id.to_string(), &db_connection).await?;
+	let curve = LevelCurve::from(&config);
+
+	let level_up = add_xp(
+		&db_connection,
+		&guild_id.to_string(),
+		&message.author.id.to_string(),
+		XpSource::Message,
+		config.message_xp as i64,
+		Some(config.message_cooldown_secs as i64),
+		&curve,
+	)
+	.await?;
+
+	if let Some(level_up) = level_up {
+		info!(
+			guild_id = %guild_id,
+			user_id = %message.author.id,
+			level = level_up.new_level,
+			"Member reached a new level from messages"
+		);
+		handle_level_up(
+			ctx,
+			guild_id,
+			message.author.id,
+			&config,
+			level_up,
+			Some(message.channel_id),
+		)
+		.await?;
+	}
+
+	Ok(())
+}
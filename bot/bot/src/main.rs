@@ -16,6 +16,8 @@ use std::time::Duration;
 use tokio::sync::{broadcast, RwLock};
 use tracing::{error, info, warn};
 
+mod achievement;
+mod anilist;
 pub mod autocomplete;
 pub mod bot_data;
 mod command;
@@ -27,11 +29,14 @@ mod event_handler;
 mod handlers;
 mod helper;
 pub mod launch_task;
+mod leveling;
 mod logger;
+mod minigame;
 mod music_events;
 mod register;
 mod server_image;
 mod structure;
+mod voice_tracking;
 
 #[tokio::main]
 async fn main() {
@@ -279,13 +284,19 @@ async fn main() {
 		shard_manager: Default::default(),
 		lavalink: Arc::new(Default::default()),
 		shutdown_signal: Arc::new(shutdown_tx),
-		vocal_session: Arc::new(Default::default()),
+		voice_session_locks: moka::future::Cache::builder()
+			.time_to_idle(std::time::Duration::from_secs(600))
+			.build(),
 		user_color_update_count: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
 		server_image_running: Arc::new(std::sync::atomic::AtomicBool::new(false)),
 		redis_connection,
 		user_color_task_tx: user_color_tx,
 		server_image_task_tx: server_image_tx,
 		image_store,
+		level_image_cache: moka::future::Cache::builder()
+			.max_capacity(512)
+			.time_to_idle(std::time::Duration::from_secs(3600))
+			.build(),
 	});
 	info!("Bot data structure initialized successfully");
 
@@ -0,0 +1,447 @@
+//! Anime song quiz.
+//!
+//! A quiz plays short clips of songs from `anime_song` in the voice channel
+//! and players name the anime through the answer modal, matched with
+//! [`is_correct`] against its English, Japanese and alternative names. The
+//! round being played lives on the `anisong_quiz` row, so an answer and the
+//! end of the round can race safely; `anisong_quiz_player` keeps the score of
+//! the quiz while [`record_score`] feeds the leaderboards.
+use crate::minigame::guess_anime::is_correct;
+use crate::minigame::leaderboard::{record_score, MinigameType};
+use anyhow::Result;
+use chrono::{Duration, NaiveDateTime, Utc};
+use rand::RngExt;
+use sea_orm::sea_query::{Expr, ExprTrait, OnConflict};
+use sea_orm::ActiveValue::Set;
+use sea_orm::{
+	ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+	QueryOrder, QuerySelect, TransactionTrait,
+};
+use shared::database::prelude::{AnimeSong, AnisongQuiz, AnisongQuizPlayer};
+use shared::database::{anime_song, anisong_quiz, anisong_quiz_player};
+use tracing::info;
+
+pub const DEFAULT_ROUNDS: i32 = 5;
+pub const MAX_ROUNDS: i32 = 10;
+/// How long a round accepts answers.
+pub const ROUND_SECS: i64 = 30;
+/// How long the clip plays, the rest of the round is silent.
+pub const CLIP_SECS: u64 = 20;
+/// Latest point of a song a clip starts at, most of the recognisable part
+/// of an opening or ending is in its first minute and a half.
+const MAX_CLIP_START_SECS: u64 = 60;
+/// A running quiz older than this was cut short by a restart.
+const QUIZ_TIMEOUT_SECS: i64 = 15 * 60;
+/// Attempts at finding a song with audio before giving up.
+pub const PICK_ATTEMPTS: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuizStatus {
+	Running,
+	Finished,
+	Stopped,
+}
+
+impl QuizStatus {
+	pub fn as_str(self) -> &'static str {
+		match self {
+			QuizStatus::Running => "running",
+			QuizStatus::Finished => "finished",
+			QuizStatus::Stopped => "stopped",
+		}
+	}
+
+	pub fn parse(status: &str) -> Self {
+		match status {
+			"running" => QuizStatus::Running,
+			"stopped" => QuizStatus::Stopped,
+			_ => QuizStatus::Finished,
+		}
+	}
+}
+
+/// Points for a right answer `elapsed_secs` into the round, quicker answers
+/// are worth more.
+pub fn answer_points(elapsed_secs: i64) -> i32 {
+	let remaining = (ROUND_SECS - elapsed_secs).clamp(0, ROUND_SECS);
+
+	5 + (10 * remaining / ROUND_SECS) as i32
+}
+
+/// Where the clip starts in a song `length_ms` long.
+pub fn clip_start_secs(length_ms: u64) -> u64 {
+	let latest = (length_ms / 1000)
+		.saturating_sub(CLIP_SECS)
+		.min(MAX_CLIP_START_SECS);
+
+	rand::rng().random_range(0..=latest)
+}
+
+/// Names a guess is checked against. The worker joins alternative names
+/// with ", ".
+pub fn song_answers(song: &anime_song::Model) -> Vec<String> {
+	let mut answers: Vec<String> = Vec::new();
+	let names = [song.anime_en_name.as_str(), song.anime_jp_name.as_str()]
+		.into_iter()
+		.chain(song.anime_alt_name.split(", "));
+
+	for name in names {
+		let name = name.trim();
+		if !name.is_empty() && !answers.iter().any(|answer| answer == name) {
+			answers.push(name.to_string());
+		}
+	}
+
+	answers
+}
+
+/// A random song with a clip to play.
+pub async fn random_song<C: ConnectionTrait>(db: &C) -> Result<Option<anime_song::Model>> {
+	let query = AnimeSong::find().filter(anime_song::Column::Audio.ne(""));
+
+	let count = query.clone().count(db).await?;
+	if count == 0 {
+		return Ok(None);
+	}
+
+	let offset = rand::rng().random_range(0..count);
+	Ok(query
+		.order_by_asc(anime_song::Column::AnnSongId)
+		.offset(offset)
+		.limit(1)
+		.one(db)
+		.await?)
+}
+
+fn quiz_cutoff() -> NaiveDateTime {
+	Utc::now().naive_utc() - Duration::seconds(QUIZ_TIMEOUT_SECS)
+}
+
+pub async fn load_quiz<C: ConnectionTrait>(
+	db: &C, quiz_id: &str,
+) -> Result<Option<anisong_quiz::Model>> {
+	Ok(AnisongQuiz::find_by_id(quiz_id.to_string()).one(db).await?)
+}
+
+/// The guild's running quiz, the bot has a single voice connection per guild.
+pub async fn running_quiz<C: ConnectionTrait>(
+	db: &C, guild_id: &str,
+) -> Result<Option<anisong_quiz::Model>> {
+	Ok(AnisongQuiz::find()
+		.filter(anisong_quiz::Column::GuildId.eq(guild_id))
+		.filter(anisong_quiz::Column::Status.eq(QuizStatus::Running.as_str()))
+		.filter(anisong_quiz::Column::CreatedAt.gt(quiz_cutoff()))
+		.one(db)
+		.await?)
+}
+
+pub async fn start_quiz<C: ConnectionTrait>(
+	db: &C, guild_id: &str, channel_id: &str, started_by: &str, rounds: i32,
+) -> Result<anisong_quiz::Model> {
+	// Quizzes cut short by a restart never finished
+	AnisongQuiz::update_many()
+		.col_expr(
+			anisong_quiz::Column::Status,
+			Expr::value(QuizStatus::Stopped.as_str()),
+		)
+		.col_expr(
+			anisong_quiz::Column::FinishedAt,
+			Expr::value(Some(Utc::now().naive_utc())),
+		)
+		.filter(anisong_quiz::Column::GuildId.eq(guild_id))
+		.filter(anisong_quiz::Column::Status.eq(QuizStatus::Running.as_str()))
+		.filter(anisong_quiz::Column::CreatedAt.lte(quiz_cutoff()))
+		.exec(db)
+		.await?;
+
+	let quiz = anisong_quiz::Model {
+		id: uuid::Uuid::new_v4().to_string(),
+		guild_id: guild_id.to_string(),
+		channel_id: channel_id.to_string(),
+		started_by: started_by.to_string(),
+		status: QuizStatus::Running.as_str().to_string(),
+		rounds: rounds.clamp(1, MAX_ROUNDS),
+		round: 0,
+		song_name: None,
+		song_type: None,
+		anime_name: None,
+		answers: None,
+		round_started_at: None,
+		solved_by: None,
+		created_at: Utc::now().naive_utc(),
+		finished_at: None,
+	};
+
+	AnisongQuiz::insert(anisong_quiz::ActiveModel {
+		id: Set(quiz.id.clone()),
+		guild_id: Set(quiz.guild_id.clone()),
+		channel_id: Set(quiz.channel_id.clone()),
+		started_by: Set(quiz.started_by.clone()),
+		status: Set(quiz.status.clone()),
+		rounds: Set(quiz.rounds),
+		round: Set(quiz.round),
+		song_name: Set(None),
+		song_type: Set(None),
+		anime_name: Set(None),
+		answers: Set(None),
+		round_started_at: Set(None),
+		solved_by: Set(None),
+		created_at: Set(quiz.created_at),
+		finished_at: Set(None),
+	})
+	.exec_without_returning(db)
+	.await?;
+
+	info!(
+		guild_id,
+		channel_id,
+		rounds = quiz.rounds,
+		"Anisong quiz started"
+	);
+
+	Ok(quiz)
+}
+
+/// Open `round` with `song`, answers from earlier rounds no longer count.
+pub async fn begin_round<C: ConnectionTrait>(
+	db: &C, quiz: &anisong_quiz::Model, round: i32, song: &anime_song::Model,
+) -> Result<anisong_quiz::Model> {
+	let now = Utc::now().naive_utc();
+	let answers = song_answers(song).join("\n");
+	let anime_name = if song.anime_en_name.is_empty() {
+		song.anime_jp_name.clone()
+	} else {
+		song.anime_en_name.clone()
+	};
+
+	AnisongQuiz::update_many()
+		.col_expr(anisong_quiz::Column::Round, Expr::value(round))
+		.col_expr(
+			anisong_quiz::Column::SongName,
+			Expr::value(Some(song.song_name.clone())),
+		)
+		.col_expr(
+			anisong_quiz::Column::SongType,
+			Expr::value(Some(song.song_type.clone())),
+		)
+		.col_expr(
+			anisong_quiz::Column::AnimeName,
+			Expr::value(Some(anime_name.clone())),
+		)
+		.col_expr(
+			anisong_quiz::Column::Answers,
+			Expr::value(Some(answers.clone())),
+		)
+		.col_expr(anisong_quiz::Column::RoundStartedAt, Expr::value(Some(now)))
+		.col_expr(
+			anisong_quiz::Column::SolvedBy,
+			Expr::value(Option::<String>::None),
+		)
+		.filter(anisong_quiz::Column::Id.eq(&quiz.id))
+		.exec(db)
+		.await?;
+
+	Ok(anisong_quiz::Model {
+		round,
+		song_name: Some(song.song_name.clone()),
+		song_type: Some(song.song_type.clone()),
+		anime_name: Some(anime_name),
+		answers: Some(answers),
+		round_started_at: Some(now),
+		solved_by: None,
+		..quiz.clone()
+	})
+}
+
+pub enum AnswerOutcome {
+	Correct {
+		points: i32,
+	},
+	Wrong,
+	/// The round was answered, timed out or the quiz ended.
+	Closed,
+}
+
+/// Check an answer for `round`, and on the first right one close the round
+/// and score it. `user_id` must already exist in `user_data`.
+pub async fn submit_answer(
+	db_connection: &DatabaseConnection, quiz_id: &str, round: i32, user_id: &str, guess: &str,
+) -> Result<AnswerOutcome> {
+	let Some(quiz) = load_quiz(db_connection, quiz_id).await? else {
+		return Ok(AnswerOutcome::Closed);
+	};
+	let now = Utc::now().naive_utc();
+	let Some(round_started_at) = quiz.round_started_at else {
+		return Ok(AnswerOutcome::Closed);
+	};
+	let elapsed = (now - round_started_at).num_seconds();
+	if QuizStatus::parse(&quiz.status) != QuizStatus::Running
+		|| quiz.round != round
+		|| quiz.solved_by.is_some()
+		|| elapsed > ROUND_SECS
+	{
+		return Ok(AnswerOutcome::Closed);
+	}
+
+	let answers = quiz.answers.clone().unwrap_or_default();
+	if !is_correct(guess, &answers.lines().collect::<Vec<_>>())? {
+		return Ok(AnswerOutcome::Wrong);
+	}
+
+	let txn = db_connection.begin().await?;
+
+	let solved = AnisongQuiz::update_many()
+		.col_expr(
+			anisong_quiz::Column::SolvedBy,
+			Expr::value(Some(user_id.to_string())),
+		)
+		.filter(anisong_quiz::Column::Id.eq(quiz_id))
+		.filter(anisong_quiz::Column::Round.eq(round))
+		.filter(anisong_quiz::Column::SolvedBy.is_null())
+		.filter(anisong_quiz::Column::Status.eq(QuizStatus::Running.as_str()))
+		.exec(&txn)
+		.await?;
+	if solved.rows_affected == 0 {
+		txn.rollback().await?;
+		return Ok(AnswerOutcome::Closed);
+	}
+
+	let points = answer_points(elapsed);
+
+	AnisongQuizPlayer::insert(anisong_quiz_player::ActiveModel {
+		quiz_id: Set(quiz_id.to_string()),
+		user_id: Set(user_id.to_string()),
+		points: Set(0),
+	})
+	.on_conflict(
+		OnConflict::columns([
+			anisong_quiz_player::Column::QuizId,
+			anisong_quiz_player::Column::UserId,
+		])
+		.do_nothing()
+		.to_owned(),
+	)
+	.exec_without_returning(&txn)
+	.await?;
+
+	AnisongQuizPlayer::update_many()
+		.col_expr(
+			anisong_quiz_player::Column::Points,
+			Expr::col(anisong_quiz_player::Column::Points).add(points),
+		)
+		.filter(anisong_quiz_player::Column::QuizId.eq(quiz_id))
+		.filter(anisong_quiz_player::Column::UserId.eq(user_id))
+		.exec(&txn)
+		.await?;
+
+	record_score(
+		&txn,
+		&quiz.guild_id,
+		user_id,
+		MinigameType::AnisongQuiz,
+		points,
+	)
+	.await?;
+
+	txn.commit().await?;
+
+	info!(
+		guild_id = %quiz.guild_id,
+		user_id, round, points, "Anisong quiz round solved"
+	);
+
+	Ok(AnswerOutcome::Correct { points })
+}
+
+/// Stop the quiz early. Only whoever started it can.
+pub async fn stop_quiz<C: ConnectionTrait>(
+	db: &C, quiz: &anisong_quiz::Model, user_id: &str,
+) -> Result<bool> {
+	if quiz.started_by != user_id {
+		return Ok(false);
+	}
+
+	close_quiz(db, &quiz.id, QuizStatus::Stopped).await
+}
+
+/// Mark the quiz over. Returns whether it was still running.
+pub async fn close_quiz<C: ConnectionTrait>(
+	db: &C, quiz_id: &str, status: QuizStatus,
+) -> Result<bool> {
+	let closed = AnisongQuiz::update_many()
+		.col_expr(anisong_quiz::Column::Status, Expr::value(status.as_str()))
+		.col_expr(
+			anisong_quiz::Column::FinishedAt,
+			Expr::value(Some(Utc::now().naive_utc())),
+		)
+		.filter(anisong_quiz::Column::Id.eq(quiz_id))
+		.filter(anisong_quiz::Column::Status.eq(QuizStatus::Running.as_str()))
+		.exec(db)
+		.await?;
+
+	Ok(closed.rows_affected > 0)
+}
+
+/// Players of the quiz, best first.
+pub async fn standings<C: ConnectionTrait>(
+	db: &C, quiz_id: &str,
+) -> Result<Vec<anisong_quiz_player::Model>> {
+	Ok(AnisongQuizPlayer::find()
+		.filter(anisong_quiz_player::Column::QuizId.eq(quiz_id))
+		.order_by_desc(anisong_quiz_player::Column::Points)
+		.all(db)
+		.await?)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn song(en: &str, jp: &str, alt: &str) -> anime_song::Model {
+		anime_song::Model {
+			anilist_id: "1".to_string(),
+			ann_id: "1".to_string(),
+			ann_song_id: "1".to_string(),
+			anime_en_name: en.to_string(),
+			anime_jp_name: jp.to_string(),
+			anime_alt_name: alt.to_string(),
+			song_type: "Opening 1".to_string(),
+			song_name: "Song".to_string(),
+			hq: String::new(),
+			mq: String::new(),
+			audio: "https://example.com/song.mp3".to_string(),
+		}
+	}
+
+	#[test]
+	fn test_song_answers_split_alternative_names() {
+		let answers = song_answers(&song(
+			"Attack on Titan",
+			"Shingeki no Kyojin",
+			"AoT, SnK, Attack on Titan",
+		));
+
+		assert_eq!(
+			answers,
+			vec!["Attack on Titan", "Shingeki no Kyojin", "AoT", "SnK"]
+		);
+		assert_eq!(song_answers(&song("", "Naruto", "")), vec!["Naruto"]);
+	}
+
+	#[test]
+	fn test_quicker_answers_score_more() {
+		assert_eq!(answer_points(0), 15);
+		assert!(answer_points(10) > answer_points(20));
+		assert_eq!(answer_points(ROUND_SECS), 5);
+		assert_eq!(answer_points(ROUND_SECS + 10), 5);
+	}
+
+	#[test]
+	fn test_clip_fits_in_the_song() {
+		for _ in 0..100 {
+			assert!(clip_start_secs(90_000) <= 70);
+			assert!(clip_start_secs(300_000) <= MAX_CLIP_START_SECS);
+		}
+		assert_eq!(clip_start_secs(10_000), 0);
+	}
+}
@@ -0,0 +1,586 @@
+//! Daily rewards and claim streaks.
+//!
+//! `minigame daily` can be claimed once per day of the guild timezone, so
+//! the reset follows the guild's midnight rather than UTC. Claiming on the
+//! next day extends the streak, and a claim within the grace window after a
+//! missed day still counts. What each streak length pays (coins, items and
+//! a rarity bonus) comes from `json/daily/rewards.json`. Streaks live per
+//! guild in `daily_streak`, next to the wallet they pay into.
+use crate::minigame::economy::{apply_change, LedgerReason};
+use crate::minigame::fishing::ItemProperties;
+use crate::minigame::leaderboard::{record_score, MinigameType};
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use rand::distr::weighted::WeightedIndex;
+use rand::distr::Distribution;
+use rand::RngExt;
+use sea_orm::sea_query::{Expr, ExprTrait, OnConflict};
+use sea_orm::ActiveValue::Set;
+use sea_orm::{
+	ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, TransactionTrait,
+};
+use serde::Deserialize;
+use shared::database::prelude::{DailyStreak, Item, UserInventory};
+use shared::database::{daily_streak, item, user_inventory};
+use shared::helper::read_file::read_file_as_string;
+use std::ops::RangeInclusive;
+use std::sync::LazyLock;
+use tracing::{error, info, warn};
+
+const REWARDS_PATH: &str = "./json/daily/rewards.json";
+/// The grace window can extend a day by at most another day.
+const MAX_GRACE_HOURS: i64 = 24;
+
+static REWARDS: LazyLock<Option<RewardTable>> = LazyLock::new(|| match load_rewards() {
+	Ok(table) => {
+		info!(tiers = table.tiers.len(), "Loaded the daily reward table");
+		Some(table)
+	},
+	Err(e) => {
+		error!(error = ?e, "Invalid daily reward table, daily rewards are disabled");
+		None
+	},
+});
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RewardTable {
+	/// Hours into the day after a missed one during which a claim still
+	/// keeps the streak.
+	pub grace_hours: i64,
+	/// Item types the reward items are drawn from.
+	pub item_types: Vec<String>,
+	/// Sorted by streak, the first tier starting at 1.
+	pub tiers: Vec<RewardTier>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct RewardTier {
+	/// Streak length the tier applies from.
+	pub streak: i32,
+	pub coins: i64,
+	/// Items drawn from the catalog, weighted by their `weight`.
+	pub items: u32,
+	/// Raises the lowest rarity the items can roll, never past their maximum.
+	#[serde(default)]
+	pub rarity_bonus: i32,
+}
+
+impl RewardTable {
+	/// Every problem with the table, so a broken file reports them all.
+	fn validate(&self) -> Vec<String> {
+		let mut errors = Vec::new();
+
+		if !(0..=MAX_GRACE_HOURS).contains(&self.grace_hours) {
+			errors.push(format!(
+				"grace_hours must be between 0 and {}",
+				MAX_GRACE_HOURS
+			));
+		}
+		if self.item_types.is_empty() {
+			errors.push(String::from("item_types is empty"));
+		}
+		match self.tiers.first() {
+			None => errors.push(String::from("there are no tiers")),
+			Some(first) if first.streak != 1 => {
+				errors.push(String::from("the first tier must start at streak 1"))
+			},
+			Some(_) => {},
+		}
+		for pair in self.tiers.windows(2) {
+			if pair[1].streak <= pair[0].streak {
+				errors.push(format!(
+					"tier {} must come after tier {}",
+					pair[1].streak, pair[0].streak
+				));
+			}
+		}
+		for tier in &self.tiers {
+			if tier.coins <= 0 {
+				errors.push(format!("tier {}: coins must be positive", tier.streak));
+			}
+			if tier.rarity_bonus < 0 {
+				errors.push(format!(
+					"tier {}: rarity_bonus cannot be negative",
+					tier.streak
+				));
+			}
+		}
+
+		errors
+	}
+
+	/// The tier paid at `streak`.
+	pub fn tier(&self, streak: i32) -> RewardTier {
+		*self
+			.tiers
+			.iter()
+			.rev()
+			.find(|tier| tier.streak <= streak)
+			.unwrap_or(&self.tiers[0])
+	}
+
+	/// The first tier a longer streak would reach.
+	pub fn next_tier(&self, streak: i32) -> Option<RewardTier> {
+		self.tiers.iter().find(|tier| tier.streak > streak).copied()
+	}
+}
+
+fn parse_rewards(json: &str) -> Result<RewardTable> {
+	let table: RewardTable =
+		serde_json::from_str(json).context("Failed to parse the daily reward table")?;
+
+	let errors = table.validate();
+	if !errors.is_empty() {
+		bail!("{}", errors.join("; "));
+	}
+
+	Ok(table)
+}
+
+fn load_rewards() -> Result<RewardTable> {
+	parse_rewards(&read_file_as_string(REWARDS_PATH)?)
+}
+
+pub fn reward_table() -> Option<&'static RewardTable> {
+	REWARDS.as_ref()
+}
+
+/// Leaderboard points for a claim, growing with the streak for a month.
+pub fn daily_points(streak: i32) -> i32 {
+	10 + 2 * streak.clamp(1, 30)
+}
+
+/// When `day` starts in `timezone`. A day starting in a DST gap starts when
+/// the gap ends.
+pub fn day_start(day: NaiveDate, timezone: &Tz) -> DateTime<Utc> {
+	let midnight = day.and_time(NaiveTime::MIN);
+
+	(0..=2)
+		.find_map(|hours| {
+			timezone
+				.from_local_datetime(&(midnight + Duration::hours(hours)))
+				.earliest()
+		})
+		.map(|start| start.with_timezone(&Utc))
+		.unwrap_or_else(|| midnight.and_utc())
+}
+
+/// Last moment a claim made on `day` can be followed up without losing the
+/// streak: the end of the next day plus the grace window.
+pub fn streak_deadline(day: NaiveDate, timezone: &Tz, grace_hours: i64) -> DateTime<Utc> {
+	day_start(day + Duration::days(2), timezone) + Duration::hours(grace_hours)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreakKind {
+	/// First claim in this guild.
+	Started,
+	Continued,
+	/// A day was missed but the claim came within the grace window.
+	Grace,
+	/// The previous streak lapsed, this claim starts a new one.
+	Broken {
+		previous: i32,
+	},
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreakCheck {
+	/// Today's reward was already claimed.
+	Claimed {
+		next_reset: DateTime<Utc>,
+	},
+	Ready {
+		streak: i32,
+		kind: StreakKind,
+	},
+}
+
+/// What a claim at `now` does to a streak of `current` days last claimed on
+/// `last_day`, both days being in `timezone`.
+pub fn check_streak(
+	last_day: Option<NaiveDate>, current: i32, now: DateTime<Utc>, timezone: &Tz, grace_hours: i64,
+) -> StreakCheck {
+	let today = now.with_timezone(timezone).date_naive();
+
+	let Some(last_day) = last_day else {
+		return StreakCheck::Ready {
+			streak: 1,
+			kind: StreakKind::Started,
+		};
+	};
+
+	// Also covers a guild moving to a timezone that is behind
+	if last_day >= today {
+		return StreakCheck::Claimed {
+			next_reset: day_start(today + Duration::days(1), timezone),
+		};
+	}
+
+	let kind = if last_day + Duration::days(1) == today {
+		StreakKind::Continued
+	} else if now <= streak_deadline(last_day, timezone, grace_hours) {
+		StreakKind::Grace
+	} else {
+		StreakKind::Broken { previous: current }
+	};
+
+	let streak = match kind {
+		StreakKind::Continued | StreakKind::Grace => current.saturating_add(1).max(1),
+		_ => 1,
+	};
+
+	StreakCheck::Ready { streak, kind }
+}
+
+/// Rarities a reward item can roll with the tier rarity bonus.
+pub fn reward_rarity_range(item: &item::Model, rarity_bonus: i32) -> RangeInclusive<i32> {
+	let maximum = item.maximum_rarity.max(item.minimum_rarity);
+
+	(item.minimum_rarity + rarity_bonus.max(0)).min(maximum)..=maximum
+}
+
+#[derive(Debug, Clone)]
+pub struct RewardItem {
+	pub item: item::Model,
+	pub rarity: i32,
+}
+
+/// A successful claim.
+pub struct DailyClaim {
+	pub streak: i32,
+	pub best_streak: i32,
+	pub kind: StreakKind,
+	pub tier: RewardTier,
+	pub next_tier: Option<RewardTier>,
+	pub balance: i64,
+	pub items: Vec<RewardItem>,
+	pub next_reset: DateTime<Utc>,
+	/// Claim again before this to keep the streak.
+	pub deadline: DateTime<Utc>,
+}
+
+pub enum DailyOutcome {
+	Claimed(DailyClaim),
+	AlreadyClaimed {
+		streak: i32,
+		next_reset: DateTime<Utc>,
+	},
+}
+
+pub async fn get_streak<C: ConnectionTrait>(
+	db: &C, guild_id: &str, user_id: &str,
+) -> Result<Option<daily_streak::Model>> {
+	Ok(
+		DailyStreak::find_by_id((guild_id.to_string(), user_id.to_string()))
+			.one(db)
+			.await?,
+	)
+}
+
+async fn ensure_state<C: ConnectionTrait>(db: &C, guild_id: &str, user_id: &str) -> Result<()> {
+	DailyStreak::insert(daily_streak::ActiveModel {
+		guild_id: Set(guild_id.to_string()),
+		user_id: Set(user_id.to_string()),
+		current_streak: Set(0),
+		best_streak: Set(0),
+		total_claims: Set(0),
+		last_claim_day: Set(None),
+		last_claim_at: Set(None),
+		updated_at: Set(Utc::now().naive_utc()),
+	})
+	.on_conflict(
+		OnConflict::columns([daily_streak::Column::GuildId, daily_streak::Column::UserId])
+			.do_nothing()
+			.to_owned(),
+	)
+	.exec_without_returning(db)
+	.await?;
+
+	Ok(())
+}
+
+/// Draw the tier's items from the catalog, weighted by `weight`. Nothing is
+/// drawn when no item of the configured types is available.
+async fn draw_items<C: ConnectionTrait>(
+	db: &C, table: &RewardTable, tier: RewardTier,
+) -> Result<Vec<RewardItem>> {
+	if tier.items == 0 {
+		return Ok(Vec::new());
+	}
+
+	let items = Item::find()
+		.filter(item::Column::Type.is_in(table.item_types.clone()))
+		.filter(item::Column::RetiredAt.is_null())
+		.filter(item::Column::Weight.gt(0))
+		.all(db)
+		.await?;
+	if items.is_empty() {
+		warn!(types = ?table.item_types, "No catalog item can be a daily reward");
+		return Ok(Vec::new());
+	}
+
+	let dist = WeightedIndex::new(items.iter().map(|item| item.weight))
+		.context("Failed to create weighted distribution")?;
+	let mut rng = rand::rng();
+
+	Ok((0..tier.items)
+		.map(|_| {
+			let item = items[dist.sample(&mut rng)].clone();
+			let rarity = rng.random_range(reward_rarity_range(&item, tier.rarity_bonus));
+
+			RewardItem { item, rarity }
+		})
+		.collect())
+}
+
+/// Claim today's reward for the member, who must already exist in
+/// `user_data`. The streak, the coins, the items and the leaderboard score
+/// land together, and only one of two concurrent claims goes through.
+pub async fn claim_daily(
+	db_connection: &DatabaseConnection, guild_id: &str, user_id: &str, timezone: &Tz,
+) -> Result<DailyOutcome> {
+	let table = reward_table().ok_or(anyhow!("The daily reward table is unavailable"))?;
+	let now = Utc::now();
+	let today = now.with_timezone(timezone).date_naive();
+	let next_reset = day_start(today + Duration::days(1), timezone);
+
+	let txn = db_connection.begin().await?;
+
+	ensure_state(&txn, guild_id, user_id).await?;
+	let state = get_streak(&txn, guild_id, user_id)
+		.await?
+		.ok_or(anyhow!("Daily streak missing right after its insert"))?;
+
+	let (streak, kind) = match check_streak(
+		state.last_claim_day,
+		state.current_streak,
+		now,
+		timezone,
+		table.grace_hours,
+	) {
+		StreakCheck::Claimed { next_reset } => {
+			txn.rollback().await?;
+			return Ok(DailyOutcome::AlreadyClaimed {
+				streak: state.current_streak,
+				next_reset,
+			});
+		},
+		StreakCheck::Ready { streak, kind } => (streak, kind),
+	};
+	let best_streak = state.best_streak.max(streak);
+
+	// Only a claim that still sees the previous claim day goes through
+	let mut update = DailyStreak::update_many()
+		.col_expr(daily_streak::Column::CurrentStreak, Expr::value(streak))
+		.col_expr(daily_streak::Column::BestStreak, Expr::value(best_streak))
+		.col_expr(
+			daily_streak::Column::TotalClaims,
+			Expr::col(daily_streak::Column::TotalClaims).add(1),
+		)
+		.col_expr(daily_streak::Column::LastClaimDay, Expr::value(Some(today)))
+		.col_expr(
+			daily_streak::Column::LastClaimAt,
+			Expr::value(Some(now.naive_utc())),
+		)
+		.col_expr(
+			daily_streak::Column::UpdatedAt,
+			Expr::value(now.naive_utc()),
+		)
+		.filter(daily_streak::Column::GuildId.eq(guild_id))
+		.filter(daily_streak::Column::UserId.eq(user_id));
+	update = match state.last_claim_day {
+		Some(day) => update.filter(daily_streak::Column::LastClaimDay.eq(day)),
+		None => update.filter(daily_streak::Column::LastClaimDay.is_null()),
+	};
+
+	if update.exec(&txn).await?.rows_affected == 0 {
+		txn.rollback().await?;
+		return Ok(DailyOutcome::AlreadyClaimed { streak, next_reset });
+	}
+
+	let tier = table.tier(streak);
+	let balance = apply_change(
+		&txn,
+		guild_id,
+		user_id,
+		tier.coins,
+		LedgerReason::DailyReward,
+		Some(today.to_string()),
+	)
+	.await?
+	.ok_or(anyhow!("Crediting a daily reward cannot overdraw a wallet"))?;
+
+	let items = draw_items(&txn, table, tier).await?;
+	if !items.is_empty() {
+		let entries: Vec<user_inventory::ActiveModel> = items
+			.iter()
+			.map(|reward| user_inventory::ActiveModel {
+				id: Set(uuid::Uuid::new_v4().to_string()),
+				item_id: Set(reward.item.item_id.clone()),
+				user_id: Set(user_id.to_string()),
+				server_id: Set(guild_id.to_string()),
+				size: Set(0),
+				rarity: Set(reward.rarity),
+				item_xp_boost: Set(reward.item.base_xp_boost),
+				durability: Set(ItemProperties::of(&reward.item)
+					.gear
+					.map(|gear| gear.durability)),
+			})
+			.collect();
+		UserInventory::insert_many(entries)
+			.exec_without_returning(&txn)
+			.await?;
+	}
+
+	record_score(
+		&txn,
+		guild_id,
+		user_id,
+		MinigameType::Daily,
+		daily_points(streak),
+	)
+	.await?;
+
+	txn.commit().await?;
+
+	info!(
+		guild_id,
+		user_id,
+		streak,
+		?kind,
+		coins = tier.coins,
+		items = items.len(),
+		"Daily reward claimed"
+	);
+
+	Ok(DailyOutcome::Claimed(DailyClaim {
+		streak,
+		best_streak,
+		kind,
+		tier,
+		next_tier: table.next_tier(streak),
+		balance,
+		items,
+		next_reset,
+		deadline: streak_deadline(today, timezone, table.grace_hours),
+	}))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn table() -> RewardTable {
+		parse_rewards(
+			r#"{"grace_hours": 6, "item_types": ["minigame"], "tiers": [
+			{"streak": 1, "coins": 100, "items": 1},
+			{"streak": 3, "coins": 150, "items": 1},
+			{"streak": 7, "coins": 250, "items": 2, "rarity_bonus": 1}]}"#,
+		)
+		.unwrap()
+	}
+
+	fn at(timezone: &Tz, year: i32, month: u32, day: u32, hour: u32) -> DateTime<Utc> {
+		timezone
+			.with_ymd_and_hms(year, month, day, hour, 0, 0)
+			.unwrap()
+			.with_timezone(&Utc)
+	}
+
+	fn date(month: u32, day: u32) -> NaiveDate {
+		NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+	}
+
+	#[test]
+	fn test_reward_file_is_valid() {
+		let json = std::fs::read_to_string(concat!(
+			env!("CARGO_MANIFEST_DIR"),
+			"/../json/daily/rewards.json"
+		))
+		.unwrap();
+
+		assert!(parse_rewards(&json).is_ok());
+	}
+
+	#[test]
+	fn test_invalid_tables_are_rejected() {
+		assert!(
+			parse_rewards(r#"{"grace_hours": 6, "item_types": ["minigame"], "tiers": []}"#)
+				.is_err()
+		);
+		assert!(parse_rewards(
+			r#"{"grace_hours": 6, "item_types": ["minigame"], "tiers": [{"streak": 2, "coins": 100, "items": 1}]}"#
+		)
+		.is_err());
+		assert!(parse_rewards(
+			r#"{"grace_hours": 48, "item_types": ["minigame"], "tiers": [{"streak": 1, "coins": 100, "items": 1}]}"#
+		)
+		.is_err());
+	}
+
+	#[test]
+	fn test_tiers_escalate_with_the_streak() {
+		let table = table();
+
+		assert_eq!(table.tier(1).coins, 100);
+		assert_eq!(table.tier(2).coins, 100);
+		assert_eq!(table.tier(3).coins, 150);
+		assert_eq!(table.tier(40).coins, 250);
+		assert_eq!(table.next_tier(3).map(|tier| tier.streak), Some(7));
+		assert_eq!(table.next_tier(7), None);
+	}
+
+	#[test]
+	fn test_streak_follows_the_guild_day() {
+		let tokyo = chrono_tz::Asia::Tokyo;
+		// 23:00 UTC on the 1st is already the 2nd in Tokyo
+		let now = at(&Tz::UTC, 2026, 3, 1, 23);
+
+		assert_eq!(
+			check_streak(Some(date(3, 1)), 4, now, &tokyo, 6),
+			StreakCheck::Ready {
+				streak: 5,
+				kind: StreakKind::Continued
+			}
+		);
+		assert!(matches!(
+			check_streak(Some(date(3, 1)), 4, now, &Tz::UTC, 6),
+			StreakCheck::Claimed { .. }
+		));
+	}
+
+	#[test]
+	fn test_grace_window_keeps_the_streak() {
+		let paris = chrono_tz::Europe::Paris;
+
+		assert_eq!(
+			check_streak(Some(date(3, 1)), 4, at(&paris, 2026, 3, 3, 5), &paris, 6),
+			StreakCheck::Ready {
+				streak: 5,
+				kind: StreakKind::Grace
+			}
+		);
+		assert_eq!(
+			check_streak(Some(date(3, 1)), 4, at(&paris, 2026, 3, 3, 7), &paris, 6),
+			StreakCheck::Ready {
+				streak: 1,
+				kind: StreakKind::Broken { previous: 4 }
+			}
+		);
+	}
+
+	#[test]
+	fn test_next_reset_is_the_local_midnight() {
+		let new_york = chrono_tz::America::New_York;
+		let now = at(&new_york, 2026, 3, 7, 20);
+
+		assert_eq!(
+			check_streak(Some(date(3, 7)), 1, now, &new_york, 0),
+			StreakCheck::Claimed {
+				next_reset: at(&new_york, 2026, 3, 8, 0)
+			}
+		);
+	}
+}
@@ -0,0 +1,432 @@
+//! Per-guild wallets and the shop.
+//!
+//! Every balance change goes through [`apply_change`], which must run inside
+//! the same transaction as the inventory change it pays for, and writes one
+//! `wallet_ledger` row recording the amount, the resulting balance and why.
+//! Debits are conditional updates, so a balance can never go negative even
+//! when two purchases race.
+use crate::minigame::fishing::ItemProperties;
+use crate::minigame::trade::held_entry_ids;
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use sea_orm::sea_query::{Expr, ExprTrait, OnConflict};
+use sea_orm::ActiveValue::Set;
+use sea_orm::{
+	ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+	QuerySelect, TransactionTrait,
+};
+use shared::database::prelude::{Item, UserInventory, Wallet, WalletLedger};
+use shared::database::{item, user_inventory, wallet, wallet_ledger};
+use tracing::{debug, info};
+
+/// Item types sold by `minigame shop`.
+pub const PURCHASABLE_TYPES: &[&str] = &["shop", "rod", "bait"];
+/// Most copies of an item bought with a single command.
+pub const MAX_PURCHASE_QUANTITY: i64 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LedgerReason {
+	ShopPurchase,
+	Sale,
+	Trade,
+	Gift,
+	GachaRoll,
+	DailyReward,
+}
+
+impl LedgerReason {
+	pub fn as_str(self) -> &'static str {
+		match self {
+			LedgerReason::ShopPurchase => "shop_purchase",
+			LedgerReason::Sale => "sale",
+			LedgerReason::Trade => "trade",
+			LedgerReason::Gift => "gift",
+			LedgerReason::GachaRoll => "gacha_roll",
+			LedgerReason::DailyReward => "daily_reward",
+		}
+	}
+}
+
+pub fn is_purchasable(item: &item::Model) -> bool {
+	PURCHASABLE_TYPES.contains(&item.r#type.as_str()) && item.price > 0
+}
+
+/// Whether the shop still sells the item. Retired items keep their sell
+/// value, they just cannot be bought anymore.
+pub fn is_for_sale(item: &item::Model) -> bool {
+	is_purchasable(item) && item.retired_at.is_none()
+}
+
+/// Coins paid for one inventory entry.
+///
+/// Shop items sell back at half their price so buying and selling never
+/// makes money, less what a worn piece of gear already used up. Anything
+/// else is worth its price scaled by rarity (+25% per tier above common)
+/// and, when it has one, by size (x0.5 to x1.5).
+pub fn sell_value(item: &item::Model, entry: &user_inventory::Model) -> i64 {
+	if item.price <= 0 {
+		return 0;
+	}
+
+	if is_purchasable(item) {
+		let half = item.price as i64 / 2;
+		return match (ItemProperties::of(item).gear, entry.durability) {
+			(Some(gear), Some(left)) if gear.durability > 0 => {
+				half * left.clamp(0, gear.durability) as i64 / gear.durability as i64
+			},
+			_ => half,
+		};
+	}
+
+	let rarity = 1.0 + 0.25 * (entry.rarity.clamp(1, 5) - 1) as f64;
+	let size = if entry.size > 0 {
+		0.5 + entry.size.min(100) as f64 / 100.0
+	} else {
+		1.0
+	};
+
+	(item.price as f64 * rarity * size).round() as i64
+}
+
+pub async fn get_balance<C: ConnectionTrait>(db: &C, guild_id: &str, user_id: &str) -> Result<i64> {
+	let wallet = Wallet::find_by_id((guild_id.to_string(), user_id.to_string()))
+		.one(db)
+		.await?;
+
+	Ok(wallet.map(|wallet| wallet.balance).unwrap_or(0))
+}
+
+pub async fn recent_ledger<C: ConnectionTrait>(
+	db: &C, guild_id: &str, user_id: &str, limit: u64,
+) -> Result<Vec<wallet_ledger::Model>> {
+	Ok(WalletLedger::find()
+		.filter(wallet_ledger::Column::GuildId.eq(guild_id))
+		.filter(wallet_ledger::Column::UserId.eq(user_id))
+		.order_by_desc(wallet_ledger::Column::CreatedAt)
+		.limit(limit)
+		.all(db)
+		.await?)
+}
+
+/// Add `amount` coins (negative to spend) and record it in the ledger.
+///
+/// Returns the new balance, or `Ok(None)` when a debit would overdraw the
+/// wallet, in which case nothing was written. The member must already exist
+/// in `user_data`.
+pub async fn apply_change<C: ConnectionTrait>(
+	db: &C, guild_id: &str, user_id: &str, amount: i64, reason: LedgerReason,
+	reference: Option<String>,
+) -> Result<Option<i64>> {
+	let now = Utc::now().naive_utc();
+
+	Wallet::insert(wallet::ActiveModel {
+		guild_id: Set(guild_id.to_string()),
+		user_id: Set(user_id.to_string()),
+		balance: Set(0),
+		updated_at: Set(now),
+	})
+	.on_conflict(
+		OnConflict::columns([wallet::Column::GuildId, wallet::Column::UserId])
+			.do_nothing()
+			.to_owned(),
+	)
+	.exec_without_returning(db)
+	.await?;
+
+	let mut update = Wallet::update_many()
+		.col_expr(
+			wallet::Column::Balance,
+			Expr::col(wallet::Column::Balance).add(amount),
+		)
+		.col_expr(wallet::Column::UpdatedAt, Expr::value(now))
+		.filter(wallet::Column::GuildId.eq(guild_id))
+		.filter(wallet::Column::UserId.eq(user_id));
+	if amount < 0 {
+		update = update.filter(wallet::Column::Balance.gte(-amount));
+	}
+
+	if update.exec(db).await?.rows_affected == 0 {
+		debug!(guild_id, user_id, amount, "Wallet debit refused");
+		return Ok(None);
+	}
+
+	let balance = get_balance(db, guild_id, user_id).await?;
+
+	WalletLedger::insert(wallet_ledger::ActiveModel {
+		id: Set(uuid::Uuid::new_v4().to_string()),
+		guild_id: Set(guild_id.to_string()),
+		user_id: Set(user_id.to_string()),
+		amount: Set(amount),
+		balance_after: Set(balance),
+		reason: Set(reason.as_str().to_string()),
+		reference: Set(reference),
+		created_at: Set(now),
+	})
+	.exec_without_returning(db)
+	.await?;
+
+	Ok(Some(balance))
+}
+
+pub enum PurchaseOutcome {
+	Bought { total: i64, balance: i64 },
+	InsufficientFunds { total: i64, balance: i64 },
+}
+
+/// Buy `quantity` copies of a shop item, paying and adding them to the
+/// inventory in one transaction.
+pub async fn buy_item(
+	db_connection: &DatabaseConnection, guild_id: &str, user_id: &str, item: &item::Model,
+	quantity: i64,
+) -> Result<PurchaseOutcome> {
+	if !is_for_sale(item) {
+		return Err(anyhow!("{} is not sold in the shop", item.item_id));
+	}
+
+	let quantity = quantity.clamp(1, MAX_PURCHASE_QUANTITY);
+	let total = item.price as i64 * quantity;
+
+	let txn = db_connection.begin().await?;
+
+	let Some(balance) = apply_change(
+		&txn,
+		guild_id,
+		user_id,
+		-total,
+		LedgerReason::ShopPurchase,
+		Some(item.item_id.clone()),
+	)
+	.await?
+	else {
+		txn.rollback().await?;
+		let balance = get_balance(db_connection, guild_id, user_id).await?;
+		return Ok(PurchaseOutcome::InsufficientFunds { total, balance });
+	};
+
+	let durability = ItemProperties::of(item).gear.map(|gear| gear.durability);
+	let entries: Vec<user_inventory::ActiveModel> = (0..quantity)
+		.map(|_| user_inventory::ActiveModel {
+			id: Set(uuid::Uuid::new_v4().to_string()),
+			item_id: Set(item.item_id.clone()),
+			user_id: Set(user_id.to_string()),
+			server_id: Set(guild_id.to_string()),
+			size: Set(0),
+			rarity: Set(item.minimum_rarity),
+			item_xp_boost: Set(item.base_xp_boost),
+			durability: Set(durability),
+		})
+		.collect();
+	UserInventory::insert_many(entries)
+		.exec_without_returning(&txn)
+		.await?;
+
+	txn.commit().await?;
+
+	info!(
+		guild_id,
+		user_id,
+		item_id = %item.item_id,
+		quantity,
+		total,
+		"Shop purchase"
+	);
+
+	Ok(PurchaseOutcome::Bought { total, balance })
+}
+
+/// What `minigame sell` was asked to sell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaleSelection {
+	/// A single inventory entry by id.
+	Entry(String),
+	/// Every entry of an item.
+	AllOf(String),
+}
+
+impl SaleSelection {
+	const ALL_PREFIX: &'static str = "all:";
+
+	/// Parse the value sent by the sell autocomplete.
+	pub fn parse(value: &str) -> Self {
+		match value.strip_prefix(Self::ALL_PREFIX) {
+			Some(item_id) => SaleSelection::AllOf(item_id.to_string()),
+			None => SaleSelection::Entry(value.to_string()),
+		}
+	}
+
+	pub fn all_of(item_id: &str) -> String {
+		format!("{}{}", Self::ALL_PREFIX, item_id)
+	}
+}
+
+pub enum SaleOutcome {
+	Sold {
+		item_name: String,
+		count: usize,
+		earned: i64,
+		balance: i64,
+	},
+	/// Nothing matched, everything matched is worthless or held by a trade,
+	/// or the entries were sold or traded away concurrently.
+	NothingSold,
+}
+
+/// Sell inventory entries, removing them and paying their value in one
+/// transaction. Only entries still owned when the delete runs are paid, and
+/// entries on offer in an open trade are never sold.
+pub async fn sell_items(
+	db_connection: &DatabaseConnection, guild_id: &str, user_id: &str, selection: SaleSelection,
+) -> Result<SaleOutcome> {
+	let txn = db_connection.begin().await?;
+
+	let held = held_entry_ids(&txn, guild_id, user_id, None).await?;
+	let mut query = UserInventory::find()
+		.find_also_related(Item)
+		.filter(user_inventory::Column::UserId.eq(user_id))
+		.filter(user_inventory::Column::ServerId.eq(guild_id))
+		.filter(user_inventory::Column::Id.is_not_in(held));
+	query = match &selection {
+		SaleSelection::Entry(id) => query.filter(user_inventory::Column::Id.eq(id.clone())),
+		SaleSelection::AllOf(item_id) => {
+			query.filter(user_inventory::Column::ItemId.eq(item_id.clone()))
+		},
+	};
+
+	let mut item_name = None;
+	let mut item_id = None;
+	let mut entry_ids = Vec::new();
+	let mut earned = 0;
+	for (entry, item) in query.all(&txn).await? {
+		let Some(item) = item else {
+			continue;
+		};
+
+		let value = sell_value(&item, &entry);
+		if value <= 0 {
+			continue;
+		}
+
+		earned += value;
+		entry_ids.push(entry.id);
+		item_name.get_or_insert(item.name);
+		item_id.get_or_insert(item.item_id);
+	}
+
+	let Some(item_name) = item_name else {
+		txn.rollback().await?;
+		return Ok(SaleOutcome::NothingSold);
+	};
+
+	let deleted = UserInventory::delete_many()
+		.filter(user_inventory::Column::Id.is_in(entry_ids.clone()))
+		.filter(user_inventory::Column::UserId.eq(user_id))
+		.filter(user_inventory::Column::ServerId.eq(guild_id))
+		.exec(&txn)
+		.await?;
+
+	// Another command took some of the entries first, sell nothing
+	if deleted.rows_affected != entry_ids.len() as u64 {
+		txn.rollback().await?;
+		return Ok(SaleOutcome::NothingSold);
+	}
+
+	let balance = apply_change(&txn, guild_id, user_id, earned, LedgerReason::Sale, item_id)
+		.await?
+		.ok_or(anyhow!("Crediting a sale cannot overdraw a wallet"))?;
+
+	txn.commit().await?;
+
+	info!(
+		guild_id,
+		user_id,
+		count = entry_ids.len(),
+		earned,
+		"Inventory sale"
+	);
+
+	Ok(SaleOutcome::Sold {
+		item_name,
+		count: entry_ids.len(),
+		earned,
+		balance,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn item(r#type: &str, price: i32) -> item::Model {
+		item::Model {
+			item_id: "test".to_string(),
+			name: "Test".to_string(),
+			description: String::new(),
+			price,
+			minimum_rarity: 1,
+			maximum_rarity: 5,
+			r#type: r#type.to_string(),
+			base_xp_boost: 0.0,
+			weight: 1,
+			properties: None,
+			retired_at: None,
+		}
+	}
+
+	fn entry(size: i32, rarity: i32) -> user_inventory::Model {
+		user_inventory::Model {
+			item_id: "test".to_string(),
+			user_id: "1".to_string(),
+			server_id: "2".to_string(),
+			size,
+			rarity,
+			item_xp_boost: 0.0,
+			id: "entry".to_string(),
+			durability: None,
+		}
+	}
+
+	#[test]
+	fn test_sell_value_scales_with_rarity_and_size() {
+		let fish = item("fish", 40);
+
+		assert_eq!(sell_value(&fish, &entry(50, 1)), 40);
+		assert_eq!(sell_value(&fish, &entry(100, 1)), 60);
+		assert_eq!(sell_value(&fish, &entry(50, 5)), 80);
+		assert_eq!(sell_value(&fish, &entry(0, 3)), 60);
+	}
+
+	#[test]
+	fn test_shop_items_never_sell_above_price() {
+		let amulet = item("shop", 150);
+
+		assert_eq!(sell_value(&amulet, &entry(0, 5)), 75);
+	}
+
+	#[test]
+	fn test_worn_gear_sells_for_less() {
+		let mut rod = item("rod", 100);
+		rod.properties = Some(r#"{"gear": {"slot": "rod", "durability": 40}}"#.to_string());
+		let mut worn = entry(0, 1);
+		worn.durability = Some(10);
+
+		assert_eq!(sell_value(&rod, &entry(0, 1)), 50);
+		assert_eq!(sell_value(&rod, &worn), 12);
+	}
+
+	#[test]
+	fn test_free_items_are_worthless() {
+		assert_eq!(sell_value(&item("quest", 0), &entry(80, 5)), 0);
+	}
+
+	#[test]
+	fn test_sale_selection_round_trip() {
+		assert_eq!(
+			SaleSelection::parse(&SaleSelection::all_of("salmon")),
+			SaleSelection::AllOf("salmon".to_string())
+		);
+		assert_eq!(
+			SaleSelection::parse("0b5c"),
+			SaleSelection::Entry("0b5c".to_string())
+		);
+	}
+}
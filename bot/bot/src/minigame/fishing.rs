@@ -0,0 +1,609 @@
+//! Fishing progression: cooldowns, gear and catch tables.
+//!
+//! Everything tunable lives in the `properties` JSON of catalog items. Fish
+//! carry a `catch` table scaling their weight by time of day and season (in
+//! the guild timezone), while rods and baits carry a `gear` block that shifts
+//! catch weights and rarity ranges and wears down by one use per cast.
+//! Gear is equipped by item id, so once an entry breaks the next one of the
+//! same item takes over.
+use crate::minigame::trade::held_entry_ids;
+use anyhow::Result;
+use chrono::{DateTime, Datelike, Duration, NaiveDateTime, Timelike, Utc};
+use chrono_tz::Tz;
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ColumnTrait, Condition, ConnectionTrait, EntityTrait, QueryFilter};
+use serde::{Deserialize, Serialize};
+use shared::database::prelude::{FishingState, Item, UserInventory};
+use shared::database::{fishing_state, item, user_inventory};
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use tracing::warn;
+
+/// Time between two casts without a rod shortening it.
+pub const FISHING_COOLDOWN_SECS: i64 = 120;
+/// Fish whose rarity can reach this tier count as rare for gear bonuses.
+pub const RARE_TIER: i32 = 4;
+/// Rods never shorten the cooldown below this fraction.
+const MIN_COOLDOWN_MULTIPLIER: f64 = 0.25;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeOfDay {
+	Dawn,
+	Day,
+	Dusk,
+	Night,
+}
+
+impl TimeOfDay {
+	pub fn from_hour(hour: u32) -> Self {
+		match hour {
+			5..=7 => TimeOfDay::Dawn,
+			8..=16 => TimeOfDay::Day,
+			17..=19 => TimeOfDay::Dusk,
+			_ => TimeOfDay::Night,
+		}
+	}
+
+	pub fn as_str(self) -> &'static str {
+		match self {
+			TimeOfDay::Dawn => "dawn",
+			TimeOfDay::Day => "day",
+			TimeOfDay::Dusk => "dusk",
+			TimeOfDay::Night => "night",
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Season {
+	Spring,
+	Summer,
+	Autumn,
+	Winter,
+}
+
+impl Season {
+	/// Meteorological seasons of the northern hemisphere.
+	pub fn from_month(month: u32) -> Self {
+		match month {
+			3..=5 => Season::Spring,
+			6..=8 => Season::Summer,
+			9..=11 => Season::Autumn,
+			_ => Season::Winter,
+		}
+	}
+
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Season::Spring => "spring",
+			Season::Summer => "summer",
+			Season::Autumn => "autumn",
+			Season::Winter => "winter",
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CastConditions {
+	pub time_of_day: TimeOfDay,
+	pub season: Season,
+}
+
+impl CastConditions {
+	pub fn at(now: DateTime<Utc>, timezone: &Tz) -> Self {
+		let local = now.with_timezone(timezone);
+
+		CastConditions {
+			time_of_day: TimeOfDay::from_hour(local.hour()),
+			season: Season::from_month(local.month()),
+		}
+	}
+}
+
+/// Weight multipliers of a fish. Missing entries count as 1, a 0 means the
+/// fish cannot be caught then.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CatchTable {
+	pub time_of_day: HashMap<TimeOfDay, f64>,
+	pub season: HashMap<Season, f64>,
+}
+
+impl CatchTable {
+	pub fn multiplier(&self, conditions: CastConditions) -> f64 {
+		let time = self
+			.time_of_day
+			.get(&conditions.time_of_day)
+			.copied()
+			.unwrap_or(1.0);
+		let season = self.season.get(&conditions.season).copied().unwrap_or(1.0);
+
+		(time * season).max(0.0)
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GearSlot {
+	Rod,
+	Bait,
+}
+
+impl GearSlot {
+	pub fn as_str(self) -> &'static str {
+		match self {
+			GearSlot::Rod => "rod",
+			GearSlot::Bait => "bait",
+		}
+	}
+}
+
+fn one() -> f64 {
+	1.0
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Gear {
+	pub slot: GearSlot,
+	/// Casts a single inventory entry lasts.
+	pub durability: i32,
+	/// Raises the lowest rarity a catch can roll, never past its maximum.
+	#[serde(default)]
+	pub rarity_bonus: i32,
+	/// Weight multiplier for fish reaching [`RARE_TIER`].
+	#[serde(default = "one")]
+	pub rare_multiplier: f64,
+	/// Weight multipliers for specific fish, by item id.
+	#[serde(default)]
+	pub attracts: HashMap<String, f64>,
+	/// Only used on rods.
+	#[serde(default = "one")]
+	pub cooldown_multiplier: f64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ItemProperties {
+	pub catch: Option<CatchTable>,
+	pub gear: Option<Gear>,
+}
+
+impl ItemProperties {
+	/// Parsed `properties` of a catalog item. Invalid JSON is logged and
+	/// treated as no properties so one bad item cannot break fishing.
+	pub fn of(item: &item::Model) -> Self {
+		let Some(properties) = item.properties.as_deref() else {
+			return ItemProperties::default();
+		};
+
+		match serde_json::from_str(properties) {
+			Ok(properties) => properties,
+			Err(e) => {
+				warn!(item_id = %item.item_id, error = %e, "Invalid item properties");
+				ItemProperties::default()
+			},
+		}
+	}
+}
+
+/// Catch weight of every fish under the given conditions and gear.
+pub fn catch_weights(fish: &[item::Model], conditions: CastConditions, gear: &[&Gear]) -> Vec<f64> {
+	fish.iter()
+		.map(|fish| {
+			let mut weight = fish.weight.max(0) as f64;
+			if let Some(table) = ItemProperties::of(fish).catch {
+				weight *= table.multiplier(conditions);
+			}
+
+			for gear in gear {
+				if fish.maximum_rarity >= RARE_TIER {
+					weight *= gear.rare_multiplier.max(0.0);
+				}
+				if let Some(multiplier) = gear.attracts.get(&fish.item_id) {
+					weight *= multiplier.max(0.0);
+				}
+			}
+
+			weight
+		})
+		.collect()
+}
+
+/// Rarities a fish can roll with the given gear.
+pub fn rarity_range(fish: &item::Model, gear: &[&Gear]) -> RangeInclusive<i32> {
+	let bonus: i32 = gear.iter().map(|gear| gear.rarity_bonus.max(0)).sum();
+	let maximum = fish.maximum_rarity.max(fish.minimum_rarity);
+
+	(fish.minimum_rarity + bonus).min(maximum)..=maximum
+}
+
+pub fn cooldown_secs(rod: Option<&Gear>) -> i64 {
+	let multiplier = rod
+		.map(|rod| rod.cooldown_multiplier.clamp(MIN_COOLDOWN_MULTIPLIER, 1.0))
+		.unwrap_or(1.0);
+
+	(FISHING_COOLDOWN_SECS as f64 * multiplier).round() as i64
+}
+
+/// Leveling XP for a catch: the guild message XP, +50% per rarity tier above
+/// common, boosted by the fish `base_xp_boost`.
+pub fn catch_xp(message_xp: i32, rarity: i32, xp_boost: f32) -> i64 {
+	let rarity = 1.0 + 0.5 * (rarity.max(1) - 1) as f64;
+
+	(message_xp.max(0) as f64 * rarity * (1.0 + xp_boost.max(0.0) as f64)).round() as i64
+}
+
+pub async fn get_state<C: ConnectionTrait>(
+	db: &C, guild_id: &str, user_id: &str,
+) -> Result<Option<fishing_state::Model>> {
+	Ok(
+		FishingState::find_by_id((guild_id.to_string(), user_id.to_string()))
+			.one(db)
+			.await?,
+	)
+}
+
+async fn ensure_state<C: ConnectionTrait>(db: &C, guild_id: &str, user_id: &str) -> Result<()> {
+	FishingState::insert(fishing_state::ActiveModel {
+		guild_id: Set(guild_id.to_string()),
+		user_id: Set(user_id.to_string()),
+		last_cast_at: Set(None),
+		rod_item_id: Set(None),
+		bait_item_id: Set(None),
+		updated_at: Set(Utc::now().naive_utc()),
+	})
+	.on_conflict(
+		OnConflict::columns([
+			fishing_state::Column::GuildId,
+			fishing_state::Column::UserId,
+		])
+		.do_nothing()
+		.to_owned(),
+	)
+	.exec_without_returning(db)
+	.await?;
+
+	Ok(())
+}
+
+/// Start a cast if the member is off cooldown.
+///
+/// Returns `Ok(None)` when the cast was claimed, or when the next cast is
+/// allowed otherwise. The claim is a conditional update, so two casts
+/// racing each other cannot both get through. The member must already exist
+/// in `user_data`.
+pub async fn claim_cast<C: ConnectionTrait>(
+	db: &C, guild_id: &str, user_id: &str, cooldown_secs: i64,
+) -> Result<Option<NaiveDateTime>> {
+	ensure_state(db, guild_id, user_id).await?;
+
+	let now = Utc::now().naive_utc();
+	let cutoff = now - Duration::seconds(cooldown_secs);
+
+	let claimed = FishingState::update_many()
+		.col_expr(fishing_state::Column::LastCastAt, Expr::value(Some(now)))
+		.col_expr(fishing_state::Column::UpdatedAt, Expr::value(now))
+		.filter(fishing_state::Column::GuildId.eq(guild_id))
+		.filter(fishing_state::Column::UserId.eq(user_id))
+		.filter(
+			Condition::any()
+				.add(fishing_state::Column::LastCastAt.is_null())
+				.add(fishing_state::Column::LastCastAt.lte(cutoff)),
+		)
+		.exec(db)
+		.await?;
+
+	if claimed.rows_affected > 0 {
+		return Ok(None);
+	}
+
+	let last_cast = get_state(db, guild_id, user_id)
+		.await?
+		.and_then(|state| state.last_cast_at)
+		.unwrap_or(now);
+
+	Ok(Some(last_cast + Duration::seconds(cooldown_secs)))
+}
+
+/// Equip `item_id` in `slot`, or empty the slot when `None`.
+pub async fn set_equipped<C: ConnectionTrait>(
+	db: &C, guild_id: &str, user_id: &str, slot: GearSlot, item_id: Option<String>,
+) -> Result<()> {
+	ensure_state(db, guild_id, user_id).await?;
+
+	let column = match slot {
+		GearSlot::Rod => fishing_state::Column::RodItemId,
+		GearSlot::Bait => fishing_state::Column::BaitItemId,
+	};
+
+	FishingState::update_many()
+		.col_expr(column, Expr::value(item_id))
+		.col_expr(
+			fishing_state::Column::UpdatedAt,
+			Expr::value(Utc::now().naive_utc()),
+		)
+		.filter(fishing_state::Column::GuildId.eq(guild_id))
+		.filter(fishing_state::Column::UserId.eq(user_id))
+		.exec(db)
+		.await?;
+
+	Ok(())
+}
+
+/// An equipped gear item and the inventory entry currently in use.
+#[derive(Debug, Clone)]
+pub struct EquippedGear {
+	pub item: item::Model,
+	pub gear: Gear,
+	pub entry: user_inventory::Model,
+	/// Entries of this item owned and not on offer, including the one in use.
+	pub owned: usize,
+}
+
+impl EquippedGear {
+	pub fn uses_left(&self) -> i32 {
+		self.entry.durability.unwrap_or(self.gear.durability)
+	}
+}
+
+/// The gear of `item_id` a member can use in `slot`, the most worn entry
+/// first so partly used entries are finished before new ones. Entries on
+/// offer in an open trade are never used.
+pub async fn load_equipped<C: ConnectionTrait>(
+	db: &C, guild_id: &str, user_id: &str, slot: GearSlot, item_id: &str,
+) -> Result<Option<EquippedGear>> {
+	let Some(item) = Item::find_by_id(item_id.to_string()).one(db).await? else {
+		return Ok(None);
+	};
+
+	let Some(gear) = ItemProperties::of(&item)
+		.gear
+		.filter(|gear| gear.slot == slot)
+	else {
+		return Ok(None);
+	};
+
+	let held = held_entry_ids(db, guild_id, user_id, None).await?;
+	let entries = UserInventory::find()
+		.filter(user_inventory::Column::ItemId.eq(item_id))
+		.filter(user_inventory::Column::UserId.eq(user_id))
+		.filter(user_inventory::Column::ServerId.eq(guild_id))
+		.filter(user_inventory::Column::Id.is_not_in(held))
+		.all(db)
+		.await?;
+
+	let owned = entries.len();
+	let Some(entry) = entries
+		.into_iter()
+		.min_by_key(|entry| entry.durability.unwrap_or(gear.durability))
+	else {
+		return Ok(None);
+	};
+
+	Ok(Some(EquippedGear {
+		item,
+		gear,
+		entry,
+		owned,
+	}))
+}
+
+/// What `minigame equip` was asked to do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EquipSelection {
+	/// Equip a gear item by id.
+	Equip(String),
+	/// Empty a slot.
+	Unequip(GearSlot),
+}
+
+impl EquipSelection {
+	const UNEQUIP_PREFIX: &'static str = "unequip:";
+
+	/// Parse the value sent by the equip autocomplete.
+	pub fn parse(value: &str) -> Self {
+		match value.strip_prefix(Self::UNEQUIP_PREFIX) {
+			Some("rod") => EquipSelection::Unequip(GearSlot::Rod),
+			Some("bait") => EquipSelection::Unequip(GearSlot::Bait),
+			_ => EquipSelection::Equip(value.to_string()),
+		}
+	}
+
+	pub fn unequip(slot: GearSlot) -> String {
+		format!("{}{}", Self::UNEQUIP_PREFIX, slot.as_str())
+	}
+}
+
+/// What a member fishes with. A slot is empty when nothing is equipped in
+/// it or when the equipped item ran out.
+#[derive(Debug, Clone, Default)]
+pub struct Loadout {
+	pub rod: Option<EquippedGear>,
+	pub bait: Option<EquippedGear>,
+}
+
+impl Loadout {
+	pub fn equipped(&self) -> Vec<&EquippedGear> {
+		self.rod.iter().chain(self.bait.iter()).collect()
+	}
+}
+
+pub async fn load_loadout<C: ConnectionTrait>(
+	db: &C, guild_id: &str, user_id: &str,
+) -> Result<Loadout> {
+	let Some(state) = get_state(db, guild_id, user_id).await? else {
+		return Ok(Loadout::default());
+	};
+
+	let mut loadout = Loadout::default();
+	if let Some(item_id) = state.rod_item_id.as_deref() {
+		loadout.rod = load_equipped(db, guild_id, user_id, GearSlot::Rod, item_id).await?;
+	}
+	if let Some(item_id) = state.bait_item_id.as_deref() {
+		loadout.bait = load_equipped(db, guild_id, user_id, GearSlot::Bait, item_id).await?;
+	}
+
+	Ok(loadout)
+}
+
+/// Use up one cast of a gear entry. Returns `true` when the entry broke and
+/// was removed from the inventory. The entry is only touched while the
+/// member still owns it, a traded entry belongs to someone else now, and
+/// while it is not on offer in a trade opened since the gear was loaded.
+pub async fn wear<C: ConnectionTrait>(db: &C, equipped: &EquippedGear) -> Result<bool> {
+	let remaining = equipped.uses_left() - 1;
+	let held = held_entry_ids(db, &equipped.entry.server_id, &equipped.entry.user_id, None).await?;
+	if held.contains(&equipped.entry.id) {
+		return Ok(false);
+	}
+
+	if remaining > 0 {
+		UserInventory::update_many()
+			.col_expr(
+				user_inventory::Column::Durability,
+				Expr::value(Some(remaining)),
+			)
+			.filter(user_inventory::Column::Id.eq(equipped.entry.id.clone()))
+			.filter(user_inventory::Column::UserId.eq(equipped.entry.user_id.clone()))
+			.filter(user_inventory::Column::ServerId.eq(equipped.entry.server_id.clone()))
+			.exec(db)
+			.await?;
+
+		return Ok(false);
+	}
+
+	UserInventory::delete_many()
+		.filter(user_inventory::Column::Id.eq(equipped.entry.id.clone()))
+		.filter(user_inventory::Column::UserId.eq(equipped.entry.user_id.clone()))
+		.filter(user_inventory::Column::ServerId.eq(equipped.entry.server_id.clone()))
+		.exec(db)
+		.await?;
+
+	Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn fish(
+		item_id: &str, weight: i32, maximum_rarity: i32, properties: Option<&str>,
+	) -> item::Model {
+		item::Model {
+			item_id: item_id.to_string(),
+			name: item_id.to_string(),
+			description: String::new(),
+			price: 10,
+			minimum_rarity: 1,
+			maximum_rarity,
+			r#type: "fish".to_string(),
+			base_xp_boost: 0.1,
+			weight,
+			properties: properties.map(str::to_string),
+			retired_at: None,
+		}
+	}
+
+	fn gear(json: &str) -> Gear {
+		serde_json::from_str(json).unwrap()
+	}
+
+	const NIGHT_WINTER: CastConditions = CastConditions {
+		time_of_day: TimeOfDay::Night,
+		season: Season::Winter,
+	};
+
+	#[test]
+	fn test_conditions_use_the_guild_timezone() {
+		let now = DateTime::parse_from_rfc3339("2026-06-01T02:30:00Z")
+			.unwrap()
+			.with_timezone(&Utc);
+
+		assert_eq!(
+			CastConditions::at(now, &chrono_tz::Asia::Tokyo),
+			CastConditions {
+				time_of_day: TimeOfDay::Day,
+				season: Season::Summer,
+			}
+		);
+		assert_eq!(
+			CastConditions::at(now, &chrono_tz::America::New_York).time_of_day,
+			TimeOfDay::Night
+		);
+	}
+
+	#[test]
+	fn test_catch_table_scales_weights() {
+		let fish = vec![
+			fish("perch", 100, 2, None),
+			fish(
+				"oarfish",
+				10,
+				5,
+				Some(r#"{"catch": {"time_of_day": {"night": 3.0}, "season": {"summer": 0}}}"#),
+			),
+		];
+
+		assert_eq!(catch_weights(&fish, NIGHT_WINTER, &[]), vec![100.0, 30.0]);
+
+		let summer = CastConditions {
+			season: Season::Summer,
+			..NIGHT_WINTER
+		};
+		assert_eq!(catch_weights(&fish, summer, &[]), vec![100.0, 0.0]);
+	}
+
+	#[test]
+	fn test_gear_shifts_weights_and_rarity() {
+		let fish = vec![fish("perch", 100, 2, None), fish("tuna", 10, 5, None)];
+		let rod =
+			gear(r#"{"slot": "rod", "durability": 50, "rarity_bonus": 1, "rare_multiplier": 2.0}"#);
+		let bait = gear(r#"{"slot": "bait", "durability": 5, "attracts": {"perch": 0.5}}"#);
+
+		assert_eq!(
+			catch_weights(&fish, NIGHT_WINTER, &[&rod, &bait]),
+			vec![50.0, 20.0]
+		);
+		assert_eq!(rarity_range(&fish[1], &[&rod]), 2..=5);
+		assert_eq!(rarity_range(&fish[0], &[&rod, &rod, &rod]), 2..=2);
+	}
+
+	#[test]
+	fn test_cooldown_is_bounded() {
+		let fast = gear(r#"{"slot": "rod", "durability": 1, "cooldown_multiplier": 0.5}"#);
+		let broken = gear(r#"{"slot": "rod", "durability": 1, "cooldown_multiplier": 0.0}"#);
+
+		assert_eq!(cooldown_secs(None), FISHING_COOLDOWN_SECS);
+		assert_eq!(cooldown_secs(Some(&fast)), FISHING_COOLDOWN_SECS / 2);
+		assert_eq!(cooldown_secs(Some(&broken)), FISHING_COOLDOWN_SECS / 4);
+	}
+
+	#[test]
+	fn test_catch_xp_uses_rarity_and_boost() {
+		assert_eq!(catch_xp(20, 1, 0.0), 20);
+		assert_eq!(catch_xp(20, 3, 0.5), 60);
+	}
+
+	#[test]
+	fn test_equip_selection_round_trip() {
+		assert_eq!(
+			EquipSelection::parse(&EquipSelection::unequip(GearSlot::Bait)),
+			EquipSelection::Unequip(GearSlot::Bait)
+		);
+		assert_eq!(
+			EquipSelection::parse("rod_bamboo"),
+			EquipSelection::Equip("rod_bamboo".to_string())
+		);
+	}
+
+	#[test]
+	fn test_invalid_properties_are_ignored() {
+		assert_eq!(
+			ItemProperties::of(&fish("perch", 1, 1, Some("not json"))),
+			ItemProperties::default()
+		);
+	}
+}
@@ -0,0 +1,359 @@
+//! Character collection gacha.
+//!
+//! Rolls draw from `character_pool`, which the worker fills with the most
+//! favourited AniList characters and their rarity, so a roll never waits on
+//! the API. Each member gets a free roll every [`FREE_ROLL_COOLDOWN_SECS`];
+//! rolling again before that costs [`ROLL_COST`] coins. Cards live in
+//! `user_character` per guild and can be traded like inventory entries.
+use crate::minigame::economy::{apply_change, get_balance, LedgerReason};
+use crate::minigame::leaderboard::{record_score, MinigameType};
+use crate::minigame::trade::held_card_ids;
+use anyhow::{anyhow, Result};
+use chrono::{Duration, NaiveDateTime, Utc};
+use rand::RngExt;
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::ActiveValue::Set;
+use sea_orm::{
+	ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, EntityTrait, PaginatorTrait,
+	QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+};
+use shared::database::prelude::{CharacterPool, GachaState, UserCharacter};
+use shared::database::{character_pool, gacha_state, user_character};
+use tracing::info;
+
+pub const FREE_ROLL_COOLDOWN_SECS: i64 = 3 * 60 * 60;
+/// Coins paid for a roll while the free roll is on cooldown.
+pub const ROLL_COST: i64 = 150;
+pub const COLLECTION_PAGE_SIZE: u64 = 10;
+
+/// Chance of each rarity, in percent. The pool is skewed towards popular
+/// characters, weighting the rarity first keeps them rare.
+const RARITY_WEIGHTS: [(i32, u32); 5] = [(1, 55), (2, 27), (3, 12), (4, 5), (5, 1)];
+
+/// The rarity for `roll`, a number below the total of [`RARITY_WEIGHTS`].
+pub fn rarity_for_roll(roll: u32) -> i32 {
+	let mut remaining = roll;
+	for (rarity, weight) in RARITY_WEIGHTS {
+		if remaining < weight {
+			return rarity;
+		}
+		remaining -= weight;
+	}
+
+	1
+}
+
+/// Leaderboard points for a character new to the collection, duplicates
+/// score nothing.
+pub fn gacha_points(rarity: i32) -> i32 {
+	let rarity = rarity.clamp(1, 5);
+
+	rarity * rarity * 5
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RollPayment {
+	Free,
+	Paid(i64),
+}
+
+pub enum RollOutcome {
+	Rolled {
+		card: user_character::Model,
+		character: character_pool::Model,
+		payment: RollPayment,
+		/// The member already had this character.
+		duplicate: bool,
+	},
+	/// The worker has not filled the pool yet.
+	PoolEmpty,
+	InsufficientFunds {
+		balance: i64,
+		next_free: NaiveDateTime,
+	},
+}
+
+async fn ensure_state<C: ConnectionTrait>(db: &C, guild_id: &str, user_id: &str) -> Result<()> {
+	GachaState::insert(gacha_state::ActiveModel {
+		guild_id: Set(guild_id.to_string()),
+		user_id: Set(user_id.to_string()),
+		last_free_roll_at: Set(None),
+		updated_at: Set(Utc::now().naive_utc()),
+	})
+	.on_conflict(
+		OnConflict::columns([gacha_state::Column::GuildId, gacha_state::Column::UserId])
+			.do_nothing()
+			.to_owned(),
+	)
+	.exec_without_returning(db)
+	.await?;
+
+	Ok(())
+}
+
+/// Take the free roll if it is available. Returns when the next free roll
+/// becomes available if it is not.
+async fn claim_free_roll<C: ConnectionTrait>(
+	db: &C, guild_id: &str, user_id: &str,
+) -> Result<Option<NaiveDateTime>> {
+	ensure_state(db, guild_id, user_id).await?;
+
+	let now = Utc::now().naive_utc();
+	let cutoff = now - Duration::seconds(FREE_ROLL_COOLDOWN_SECS);
+
+	let claimed = GachaState::update_many()
+		.col_expr(gacha_state::Column::LastFreeRollAt, Expr::value(Some(now)))
+		.col_expr(gacha_state::Column::UpdatedAt, Expr::value(now))
+		.filter(gacha_state::Column::GuildId.eq(guild_id))
+		.filter(gacha_state::Column::UserId.eq(user_id))
+		.filter(
+			Condition::any()
+				.add(gacha_state::Column::LastFreeRollAt.is_null())
+				.add(gacha_state::Column::LastFreeRollAt.lte(cutoff)),
+		)
+		.exec(db)
+		.await?;
+
+	if claimed.rows_affected > 0 {
+		return Ok(None);
+	}
+
+	let last_roll = GachaState::find_by_id((guild_id.to_string(), user_id.to_string()))
+		.one(db)
+		.await?
+		.and_then(|state| state.last_free_roll_at)
+		.unwrap_or(now);
+
+	Ok(Some(last_roll + Duration::seconds(FREE_ROLL_COOLDOWN_SECS)))
+}
+
+/// The rarity to draw from when `rarity` was rolled and only the
+/// `populated` rarities have characters. The pool only keeps the most
+/// favourited characters, so the common buckets can be empty; the nearest
+/// rarity is used instead, the more common one on a tie.
+pub fn nearest_populated_rarity(rarity: i32, populated: &[i32]) -> Option<i32> {
+	populated
+		.iter()
+		.copied()
+		.min_by_key(|candidate| ((candidate - rarity).abs(), *candidate))
+}
+
+/// A random character of `rarity`, or of the nearest rarity the pool has.
+async fn draw_character<C: ConnectionTrait>(
+	db: &C, rarity: i32,
+) -> Result<Option<character_pool::Model>> {
+	let populated = CharacterPool::find()
+		.select_only()
+		.column(character_pool::Column::Rarity)
+		.distinct()
+		.into_tuple::<i32>()
+		.all(db)
+		.await?;
+	let Some(rarity) = nearest_populated_rarity(rarity, &populated) else {
+		return Ok(None);
+	};
+
+	let query = CharacterPool::find().filter(character_pool::Column::Rarity.eq(rarity));
+	let count = query.clone().count(db).await?;
+	if count == 0 {
+		return Ok(None);
+	}
+
+	let offset = rand::rng().random_range(0..count);
+	Ok(query
+		.order_by_asc(character_pool::Column::CharacterId)
+		.offset(offset)
+		.limit(1)
+		.one(db)
+		.await?)
+}
+
+/// Roll a character for the member, who must already exist in `user_data`.
+/// The payment, the card and its leaderboard score land together.
+pub async fn roll(
+	db_connection: &DatabaseConnection, guild_id: &str, user_id: &str,
+) -> Result<RollOutcome> {
+	if CharacterPool::find().count(db_connection).await? == 0 {
+		return Ok(RollOutcome::PoolEmpty);
+	}
+
+	let txn = db_connection.begin().await?;
+
+	let payment = match claim_free_roll(&txn, guild_id, user_id).await? {
+		None => RollPayment::Free,
+		Some(next_free) => {
+			let paid = apply_change(
+				&txn,
+				guild_id,
+				user_id,
+				-ROLL_COST,
+				LedgerReason::GachaRoll,
+				None,
+			)
+			.await?;
+			if paid.is_none() {
+				txn.rollback().await?;
+				let balance = get_balance(db_connection, guild_id, user_id).await?;
+				return Ok(RollOutcome::InsufficientFunds { balance, next_free });
+			}
+
+			RollPayment::Paid(ROLL_COST)
+		},
+	};
+
+	let rarity = rarity_for_roll(rand::rng().random_range(0..100));
+	let character = draw_character(&txn, rarity)
+		.await?
+		.ok_or(anyhow!("Character pool emptied during a roll"))?;
+
+	let duplicate = UserCharacter::find()
+		.filter(user_character::Column::UserId.eq(user_id))
+		.filter(user_character::Column::ServerId.eq(guild_id))
+		.filter(user_character::Column::CharacterId.eq(character.character_id))
+		.count(&txn)
+		.await?
+		> 0;
+
+	let card = user_character::Model {
+		id: uuid::Uuid::new_v4().to_string(),
+		user_id: user_id.to_string(),
+		server_id: guild_id.to_string(),
+		character_id: character.character_id,
+		obtained_at: Utc::now().naive_utc(),
+	};
+	UserCharacter::insert(user_character::ActiveModel {
+		id: Set(card.id.clone()),
+		user_id: Set(card.user_id.clone()),
+		server_id: Set(card.server_id.clone()),
+		character_id: Set(card.character_id),
+		obtained_at: Set(card.obtained_at),
+	})
+	.exec_without_returning(&txn)
+	.await?;
+
+	if !duplicate {
+		record_score(
+			&txn,
+			guild_id,
+			user_id,
+			MinigameType::Gacha,
+			gacha_points(character.rarity),
+		)
+		.await?;
+	}
+
+	txn.commit().await?;
+
+	info!(
+		guild_id,
+		user_id,
+		character_id = character.character_id,
+		rarity = character.rarity,
+		?payment,
+		"Gacha roll"
+	);
+
+	Ok(RollOutcome::Rolled {
+		card,
+		character,
+		payment,
+		duplicate,
+	})
+}
+
+pub struct CollectionPage {
+	pub cards: Vec<(user_character::Model, character_pool::Model)>,
+	pub page: u64,
+	pub total_pages: u64,
+	pub total: u64,
+}
+
+/// One page of the member's cards, rarest first.
+pub async fn collection_page<C: ConnectionTrait>(
+	db: &C, guild_id: &str, user_id: &str, page: u64,
+) -> Result<CollectionPage> {
+	let query = UserCharacter::find()
+		.filter(user_character::Column::UserId.eq(user_id))
+		.filter(user_character::Column::ServerId.eq(guild_id));
+
+	let total = query.clone().count(db).await?;
+	let total_pages = total.div_ceil(COLLECTION_PAGE_SIZE).max(1);
+	let page = page.min(total_pages - 1);
+
+	let cards = query
+		.find_also_related(CharacterPool)
+		.order_by_desc(character_pool::Column::Rarity)
+		.order_by_asc(character_pool::Column::Name)
+		.order_by_asc(user_character::Column::ObtainedAt)
+		.offset(page * COLLECTION_PAGE_SIZE)
+		.limit(COLLECTION_PAGE_SIZE)
+		.all(db)
+		.await?
+		.into_iter()
+		.filter_map(|(card, character)| character.map(|character| (card, character)))
+		.collect();
+
+	Ok(CollectionPage {
+		cards,
+		page,
+		total_pages,
+		total,
+	})
+}
+
+/// Cards `user_id` can put on offer in a trade: the ones not held by another
+/// open trade, rarest first, at most `limit`.
+pub async fn offerable_cards<C: ConnectionTrait>(
+	db: &C, guild_id: &str, user_id: &str, except_trade: Option<&str>, limit: u64,
+) -> Result<Vec<(user_character::Model, character_pool::Model)>> {
+	let held = held_card_ids(db, guild_id, user_id, except_trade).await?;
+
+	Ok(UserCharacter::find()
+		.find_also_related(CharacterPool)
+		.filter(user_character::Column::UserId.eq(user_id))
+		.filter(user_character::Column::ServerId.eq(guild_id))
+		.filter(user_character::Column::Id.is_not_in(held))
+		.order_by_desc(character_pool::Column::Rarity)
+		.order_by_asc(character_pool::Column::Name)
+		.limit(limit)
+		.all(db)
+		.await?
+		.into_iter()
+		.filter_map(|(card, character)| character.map(|character| (card, character)))
+		.collect())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_rarity_weights_cover_every_roll() {
+		let total: u32 = RARITY_WEIGHTS.iter().map(|(_, weight)| weight).sum();
+		assert_eq!(total, 100);
+
+		assert_eq!(rarity_for_roll(0), 1);
+		assert_eq!(rarity_for_roll(54), 1);
+		assert_eq!(rarity_for_roll(55), 2);
+		assert_eq!(rarity_for_roll(98), 4);
+		assert_eq!(rarity_for_roll(99), 5);
+	}
+
+	#[test]
+	fn test_empty_rarity_falls_back_to_the_nearest() {
+		// Only popular characters in the pool, no 1★ or 2★
+		let populated = [3, 4, 5];
+		assert_eq!(nearest_populated_rarity(1, &populated), Some(3));
+		assert_eq!(nearest_populated_rarity(4, &populated), Some(4));
+		assert_eq!(nearest_populated_rarity(3, &[1, 5]), Some(1));
+		assert_eq!(nearest_populated_rarity(5, &[1, 2]), Some(2));
+		assert_eq!(nearest_populated_rarity(2, &[]), None);
+	}
+
+	#[test]
+	fn test_rarer_characters_score_more() {
+		for rarity in 1..5 {
+			assert!(gacha_points(rarity + 1) > gacha_points(rarity));
+		}
+	}
+}
@@ -0,0 +1,521 @@
+//! Guess-the-anime rounds.
+//!
+//! A round shows a pixelated crop of an AniList cover or banner. Every reveal
+//! widens the crop and sharpens it, and lowers what the round is worth.
+//! Answers are matched with [`distance_top_n`] against the romaji and English
+//! titles and the synonyms, stored with the round so the answer modal never
+//! waits on AniList. Rounds live in `guess_round`, one open round per
+//! channel, and expire after [`ROUND_TIMEOUT_SECS`].
+use crate::helper::fuzzy_search::distance_top_n;
+use crate::helper::make_graphql_cached::make_request_anilist;
+use crate::minigame::leaderboard::{record_score, MinigameType};
+use crate::structure::run::anilist::guess_anime::{GuessAnime, GuessAnimeVariables, Media};
+use anyhow::{anyhow, Result};
+use chrono::{Duration, NaiveDateTime, Utc};
+use cynic::{GraphQlResponse, QueryBuilder};
+use image::imageops::FilterType;
+use image::ImageFormat;
+use rand::RngExt;
+use reqwest::Client;
+use sea_orm::sea_query::Expr;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{
+	ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, TransactionTrait,
+};
+use shared::cache::CacheInterface;
+use shared::database::guess_round;
+use shared::database::prelude::GuessRound;
+use std::io::Cursor;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::info;
+
+pub const ROUND_TIMEOUT_SECS: i64 = 15 * 60;
+/// Similarity, out of 100, a guess needs with one of the titles.
+const ANSWER_SIMILARITY: usize = 88;
+/// Width of the rendered crops, whatever the source size.
+const IMAGE_WIDTH: u32 = 512;
+/// Share of the image shown and size of the pixel blocks, per stage.
+const STAGES: [(f32, u32); 4] = [(0.3, 24), (0.5, 14), (0.75, 7), (1.0, 3)];
+pub const REVEAL_STAGES: i32 = STAGES.len() as i32;
+/// Attempts at finding an anime with an image before giving up.
+const PICK_ATTEMPTS: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Difficulty {
+	Easy,
+	Normal,
+	Hard,
+}
+
+impl Difficulty {
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Difficulty::Easy => "easy",
+			Difficulty::Normal => "normal",
+			Difficulty::Hard => "hard",
+		}
+	}
+
+	pub fn parse(value: &str) -> Option<Self> {
+		match value {
+			"easy" => Some(Difficulty::Easy),
+			"normal" => Some(Difficulty::Normal),
+			"hard" => Some(Difficulty::Hard),
+			_ => None,
+		}
+	}
+
+	/// Popularity ranks the anime is drawn from, less known shows are harder.
+	pub fn rank_range(self) -> RangeInclusive<i32> {
+		match self {
+			Difficulty::Easy => 1..=150,
+			Difficulty::Normal => 151..=600,
+			Difficulty::Hard => 601..=1500,
+		}
+	}
+
+	fn base_points(self) -> i32 {
+		match self {
+			Difficulty::Easy => 10,
+			Difficulty::Normal => 20,
+			Difficulty::Hard => 35,
+		}
+	}
+}
+
+/// Points for solving a round at `stage`, each reveal takes a share away.
+pub fn round_points(difficulty: Difficulty, stage: i32) -> i32 {
+	let remaining = REVEAL_STAGES - stage.clamp(0, REVEAL_STAGES - 1);
+
+	(difficulty.base_points() * remaining / REVEAL_STAGES).max(1)
+}
+
+/// Lowercase and keep only letters and digits, so punctuation and spacing
+/// never cost a correct answer.
+fn normalize(title: &str) -> String {
+	title
+		.chars()
+		.map(|c| if c.is_alphanumeric() { c } else { ' ' })
+		.collect::<String>()
+		.split_whitespace()
+		.collect::<Vec<_>>()
+		.join(" ")
+		.to_lowercase()
+}
+
+pub fn is_correct(guess: &str, answers: &[&str]) -> Result<bool> {
+	let guess = normalize(guess);
+	if guess.is_empty() {
+		return Ok(false);
+	}
+
+	let answers: Vec<String> = answers.iter().map(|answer| normalize(answer)).collect();
+	let best = distance_top_n(&guess, answers.iter().map(String::as_str).collect(), 1)?;
+
+	Ok(best
+		.first()
+		.is_some_and(|(_, score)| *score >= ANSWER_SIMILARITY))
+}
+
+/// Every title a guess is checked against, without duplicates.
+fn accepted_titles(media: &Media) -> Vec<String> {
+	let mut titles: Vec<String> = Vec::new();
+	let title = media.title.as_ref();
+	let candidates = [
+		title.and_then(|title| title.romaji.clone()),
+		title.and_then(|title| title.english.clone()),
+		title.and_then(|title| title.user_preferred.clone()),
+	]
+	.into_iter()
+	.flatten()
+	.chain(
+		media
+			.synonyms
+			.clone()
+			.unwrap_or_default()
+			.into_iter()
+			.flatten(),
+	);
+
+	for candidate in candidates {
+		let candidate = candidate.trim().to_string();
+		if !normalize(&candidate).is_empty()
+			&& !titles
+				.iter()
+				.any(|title| normalize(title) == normalize(&candidate))
+		{
+			titles.push(candidate);
+		}
+	}
+
+	titles
+}
+
+/// A stable seed per round, so every stage crops around the same spot.
+fn round_seed(round_id: &str) -> u64 {
+	round_id.bytes().fold(0u64, |acc, byte| {
+		acc.wrapping_mul(31).wrapping_add(byte as u64)
+	})
+}
+
+/// Render `stage` of a round from the source image as a PNG.
+pub fn render_stage(source: &[u8], stage: i32, round_id: &str) -> Result<Vec<u8>> {
+	let image = image::load_from_memory(source)?;
+	let (width, height) = (image.width(), image.height());
+	let (share, block) = STAGES[stage.clamp(0, REVEAL_STAGES - 1) as usize];
+
+	let crop_width = ((width as f32 * share) as u32).clamp(1, width);
+	let crop_height = ((height as f32 * share) as u32).clamp(1, height);
+	let seed = round_seed(round_id);
+	let focus_x = (seed % 1000) as f32 / 1000.0;
+	let focus_y = ((seed / 1000) % 1000) as f32 / 1000.0;
+	let x = ((width - crop_width) as f32 * focus_x) as u32;
+	let y = ((height - crop_height) as f32 * focus_y) as u32;
+
+	let out_height = (crop_height * IMAGE_WIDTH / crop_width).max(1);
+	let crop = image.crop_imm(x, y, crop_width, crop_height).resize_exact(
+		IMAGE_WIDTH,
+		out_height,
+		FilterType::Triangle,
+	);
+	let pixelated = crop
+		.resize_exact(
+			(IMAGE_WIDTH / block).max(1),
+			(out_height / block).max(1),
+			FilterType::Triangle,
+		)
+		.resize_exact(IMAGE_WIDTH, out_height, FilterType::Nearest);
+
+	let mut bytes: Vec<u8> = Vec::new();
+	pixelated
+		.to_rgba8()
+		.write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)?;
+
+	Ok(bytes)
+}
+
+pub async fn fetch_image(http_client: &Client, url: &str) -> Result<Vec<u8>> {
+	let bytes = http_client
+		.get(url)
+		.send()
+		.await?
+		.error_for_status()?
+		.bytes()
+		.await?;
+
+	Ok(bytes.to_vec())
+}
+
+/// Draw an anime of the difficulty's popularity range that has an image.
+pub async fn pick_media(
+	difficulty: Difficulty, anilist_cache: Arc<RwLock<CacheInterface>>,
+) -> Result<Option<Media>> {
+	for _ in 0..PICK_ATTEMPTS {
+		let rank = rand::rng().random_range(difficulty.rank_range());
+		let operation = GuessAnime::build(GuessAnimeVariables { page: Some(rank) });
+		let data: GraphQlResponse<GuessAnime> =
+			make_request_anilist(operation, true, anilist_cache.clone()).await?;
+
+		let media = data
+			.data
+			.and_then(|data| data.page)
+			.and_then(|page| page.media)
+			.and_then(|media| media.into_iter().flatten().next());
+		if let Some(media) = media {
+			if !accepted_titles(&media).is_empty() && image_for(&media, false).is_some() {
+				return Ok(Some(media));
+			}
+		}
+	}
+
+	Ok(None)
+}
+
+/// The cover, or the banner when there is one and `prefer_banner` is set.
+fn image_for(media: &Media, prefer_banner: bool) -> Option<String> {
+	let cover = media
+		.cover_image
+		.as_ref()
+		.and_then(|cover| cover.extra_large.clone());
+
+	match (&media.banner_image, prefer_banner) {
+		(Some(banner), true) => Some(banner.clone()),
+		_ => cover.or_else(|| media.banner_image.clone()),
+	}
+}
+
+fn round_cutoff() -> NaiveDateTime {
+	Utc::now().naive_utc() - Duration::seconds(ROUND_TIMEOUT_SECS)
+}
+
+pub fn is_open(round: &guess_round::Model) -> bool {
+	round.finished_at.is_none() && round.created_at > round_cutoff()
+}
+
+pub fn answers(round: &guess_round::Model) -> Vec<&str> {
+	round.answers.lines().collect()
+}
+
+pub fn difficulty(round: &guess_round::Model) -> Difficulty {
+	Difficulty::parse(&round.difficulty).unwrap_or(Difficulty::Normal)
+}
+
+pub async fn open_round<C: ConnectionTrait>(
+	db: &C, channel_id: &str,
+) -> Result<Option<guess_round::Model>> {
+	Ok(GuessRound::find()
+		.filter(guess_round::Column::ChannelId.eq(channel_id))
+		.filter(guess_round::Column::FinishedAt.is_null())
+		.filter(guess_round::Column::CreatedAt.gt(round_cutoff()))
+		.one(db)
+		.await?)
+}
+
+pub async fn start_round<C: ConnectionTrait>(
+	db: &C, guild_id: &str, channel_id: &str, started_by: &str, difficulty: Difficulty,
+	media: &Media,
+) -> Result<guess_round::Model> {
+	// Expired rounds of the channel are closed unsolved
+	GuessRound::update_many()
+		.col_expr(
+			guess_round::Column::FinishedAt,
+			Expr::value(Some(Utc::now().naive_utc())),
+		)
+		.filter(guess_round::Column::ChannelId.eq(channel_id))
+		.filter(guess_round::Column::FinishedAt.is_null())
+		.filter(guess_round::Column::CreatedAt.lte(round_cutoff()))
+		.exec(db)
+		.await?;
+
+	let titles = accepted_titles(media);
+	let image_url = image_for(media, rand::rng().random_bool(0.5))
+		.ok_or(anyhow!("Anime {} has no image", media.id))?;
+	let title = media
+		.title
+		.as_ref()
+		.and_then(|title| title.user_preferred.clone())
+		.or_else(|| titles.first().cloned())
+		.ok_or(anyhow!("Anime {} has no title", media.id))?;
+
+	let round = guess_round::Model {
+		id: uuid::Uuid::new_v4().to_string(),
+		guild_id: guild_id.to_string(),
+		channel_id: channel_id.to_string(),
+		media_id: media.id,
+		title,
+		answers: titles.join("\n"),
+		image_url,
+		site_url: media
+			.site_url
+			.clone()
+			.unwrap_or_else(|| format!("https://anilist.co/anime/{}", media.id)),
+		difficulty: difficulty.as_str().to_string(),
+		stage: 0,
+		started_by: started_by.to_string(),
+		solved_by: None,
+		created_at: Utc::now().naive_utc(),
+		finished_at: None,
+	};
+
+	GuessRound::insert(guess_round::ActiveModel {
+		id: Set(round.id.clone()),
+		guild_id: Set(round.guild_id.clone()),
+		channel_id: Set(round.channel_id.clone()),
+		media_id: Set(round.media_id),
+		title: Set(round.title.clone()),
+		answers: Set(round.answers.clone()),
+		image_url: Set(round.image_url.clone()),
+		site_url: Set(round.site_url.clone()),
+		difficulty: Set(round.difficulty.clone()),
+		stage: Set(round.stage),
+		started_by: Set(round.started_by.clone()),
+		solved_by: Set(None),
+		created_at: Set(round.created_at),
+		finished_at: Set(None),
+	})
+	.exec_without_returning(db)
+	.await?;
+
+	info!(
+		guild_id,
+		channel_id,
+		media_id = media.id,
+		difficulty = difficulty.as_str(),
+		"Guess the anime round started"
+	);
+
+	Ok(round)
+}
+
+/// Move the round to its next stage. Returns the updated round, or `None` if
+/// it is over, at its last stage, or someone revealed it first.
+pub async fn reveal<C: ConnectionTrait>(
+	db: &C, round: &guess_round::Model,
+) -> Result<Option<guess_round::Model>> {
+	if !is_open(round) || round.stage + 1 >= REVEAL_STAGES {
+		return Ok(None);
+	}
+
+	let revealed = GuessRound::update_many()
+		.col_expr(guess_round::Column::Stage, Expr::value(round.stage + 1))
+		.filter(guess_round::Column::Id.eq(&round.id))
+		.filter(guess_round::Column::Stage.eq(round.stage))
+		.filter(guess_round::Column::FinishedAt.is_null())
+		.exec(db)
+		.await?;
+	if revealed.rows_affected == 0 {
+		return Ok(None);
+	}
+
+	Ok(Some(guess_round::Model {
+		stage: round.stage + 1,
+		..round.clone()
+	}))
+}
+
+pub enum GuessOutcome {
+	Correct {
+		round: guess_round::Model,
+		points: i32,
+	},
+	Wrong,
+	/// Solved, given up or expired before the guess landed.
+	Over,
+}
+
+/// Check a guess, and on the first right answer close the round and score
+/// it. `user_id` must already exist in `user_data`.
+pub async fn submit_guess(
+	db_connection: &DatabaseConnection, round_id: &str, user_id: &str, guess: &str,
+) -> Result<GuessOutcome> {
+	let Some(round) = GuessRound::find_by_id(round_id.to_string())
+		.one(db_connection)
+		.await?
+	else {
+		return Ok(GuessOutcome::Over);
+	};
+	if !is_open(&round) {
+		return Ok(GuessOutcome::Over);
+	}
+	if !is_correct(guess, &answers(&round))? {
+		return Ok(GuessOutcome::Wrong);
+	}
+
+	let txn = db_connection.begin().await?;
+	let now = Utc::now().naive_utc();
+
+	let solved = GuessRound::update_many()
+		.col_expr(
+			guess_round::Column::SolvedBy,
+			Expr::value(Some(user_id.to_string())),
+		)
+		.col_expr(guess_round::Column::FinishedAt, Expr::value(Some(now)))
+		.filter(guess_round::Column::Id.eq(round_id))
+		.filter(guess_round::Column::FinishedAt.is_null())
+		.exec(&txn)
+		.await?;
+	if solved.rows_affected == 0 {
+		txn.rollback().await?;
+		return Ok(GuessOutcome::Over);
+	}
+
+	let points = round_points(difficulty(&round), round.stage);
+	record_score(
+		&txn,
+		&round.guild_id,
+		user_id,
+		MinigameType::GuessAnime,
+		points,
+	)
+	.await?;
+
+	txn.commit().await?;
+
+	info!(
+		guild_id = round.guild_id,
+		user_id,
+		media_id = round.media_id,
+		stage = round.stage,
+		points,
+		"Guess the anime round solved"
+	);
+
+	Ok(GuessOutcome::Correct {
+		round: guess_round::Model {
+			solved_by: Some(user_id.to_string()),
+			finished_at: Some(now),
+			..round
+		},
+		points,
+	})
+}
+
+/// Close the round unsolved. Only whoever started it can give up.
+pub async fn give_up<C: ConnectionTrait>(
+	db: &C, round: &guess_round::Model, user_id: &str,
+) -> Result<Option<guess_round::Model>> {
+	if round.started_by != user_id {
+		return Ok(None);
+	}
+
+	let now = Utc::now().naive_utc();
+	let closed = GuessRound::update_many()
+		.col_expr(guess_round::Column::FinishedAt, Expr::value(Some(now)))
+		.filter(guess_round::Column::Id.eq(&round.id))
+		.filter(guess_round::Column::FinishedAt.is_null())
+		.exec(db)
+		.await?;
+	if closed.rows_affected == 0 {
+		return Ok(None);
+	}
+
+	Ok(Some(guess_round::Model {
+		finished_at: Some(now),
+		..round.clone()
+	}))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use image::{ImageBuffer, Rgba};
+
+	#[test]
+	fn test_answers_are_matched_loosely() {
+		let answers = ["Shingeki no Kyojin", "Attack on Titan"];
+
+		assert!(is_correct("attack on titan", &answers).unwrap());
+		assert!(is_correct("Shingeki no Kyojin!", &answers).unwrap());
+		assert!(is_correct("atack on titan", &answers).unwrap());
+		assert!(!is_correct("Naruto", &answers).unwrap());
+		assert!(!is_correct("  ", &answers).unwrap());
+	}
+
+	#[test]
+	fn test_reveals_lower_points() {
+		for difficulty in [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard] {
+			for stage in 1..REVEAL_STAGES {
+				assert!(round_points(difficulty, stage) < round_points(difficulty, stage - 1));
+			}
+			assert!(round_points(difficulty, REVEAL_STAGES - 1) >= 1);
+		}
+		assert!(round_points(Difficulty::Hard, 0) > round_points(Difficulty::Easy, 0));
+	}
+
+	#[test]
+	fn test_render_keeps_the_output_width() {
+		let source = ImageBuffer::from_pixel(300, 420, Rgba([120u8, 40, 200, 255]));
+		let mut bytes: Vec<u8> = Vec::new();
+		source
+			.write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+			.unwrap();
+
+		for stage in 0..REVEAL_STAGES {
+			let rendered = render_stage(&bytes, stage, "round").unwrap();
+			let image = image::load_from_memory(&rendered).unwrap();
+			assert_eq!(image.width(), IMAGE_WIDTH);
+		}
+	}
+}
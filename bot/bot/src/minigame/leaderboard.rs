@@ -0,0 +1,369 @@
+//! Minigame scores and the leaderboards built from them.
+//!
+//! A game calls [`record_score`] inside the transaction that grants what was
+//! scored. The points are added to the member's all-time total in
+//! `leader_board` and logged in `minigame_score`, which the weekly
+//! leaderboards sum over. Both tables share their column names so one query
+//! builder serves either window.
+use crate::leveling::leaderboard::shared_ranks;
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use sea_orm::sea_query::{Alias, Expr, ExprTrait, OnConflict, Order, Query, SelectStatement};
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, FromQueryResult, QueryFilter};
+use shared::database::prelude::{LeaderBoard, MinigameScore};
+use shared::database::{leader_board, minigame_score};
+
+pub const LEADERBOARD_PAGE_SIZE: u64 = 10;
+/// Length of the weekly window, a rolling week so it reads the same in
+/// every timezone.
+pub const WEEK_DAYS: i64 = 7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MinigameType {
+	AnisongQuiz,
+	Daily,
+	Fishing,
+	Gacha,
+	GuessAnime,
+}
+
+impl MinigameType {
+	pub const ALL: &'static [MinigameType] = &[
+		MinigameType::AnisongQuiz,
+		MinigameType::Daily,
+		MinigameType::Fishing,
+		MinigameType::Gacha,
+		MinigameType::GuessAnime,
+	];
+
+	pub fn as_str(self) -> &'static str {
+		match self {
+			MinigameType::AnisongQuiz => "anisong_quiz",
+			MinigameType::Daily => "daily",
+			MinigameType::Fishing => "fishing",
+			MinigameType::Gacha => "gacha",
+			MinigameType::GuessAnime => "guess_anime",
+		}
+	}
+
+	pub fn parse(value: &str) -> Option<Self> {
+		Self::ALL
+			.iter()
+			.copied()
+			.find(|game| game.as_str() == value)
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+	Server,
+	Global,
+}
+
+impl Scope {
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Scope::Server => "server",
+			Scope::Global => "global",
+		}
+	}
+
+	pub fn parse(value: &str) -> Option<Self> {
+		match value {
+			"server" => Some(Scope::Server),
+			"global" => Some(Scope::Global),
+			_ => None,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Window {
+	Weekly,
+	AllTime,
+}
+
+impl Window {
+	pub fn as_str(self) -> &'static str {
+		match self {
+			Window::Weekly => "weekly",
+			Window::AllTime => "all_time",
+		}
+	}
+
+	pub fn parse(value: &str) -> Option<Self> {
+		match value {
+			"weekly" => Some(Window::Weekly),
+			"all_time" => Some(Window::AllTime),
+			_ => None,
+		}
+	}
+}
+
+/// Which leaderboard to show. `game` is `None` for the combined one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeaderboardView {
+	pub game: Option<MinigameType>,
+	pub scope: Scope,
+	pub window: Window,
+}
+
+impl LeaderboardView {
+	/// `game:scope:window`, `all` standing for the combined leaderboard.
+	pub fn encode(&self) -> String {
+		format!(
+			"{}:{}:{}",
+			self.game.map(MinigameType::as_str).unwrap_or("all"),
+			self.scope.as_str(),
+			self.window.as_str()
+		)
+	}
+
+	pub fn decode(value: &str) -> Option<Self> {
+		let mut parts = value.split(':');
+		let game = match parts.next()? {
+			"all" => None,
+			game => Some(MinigameType::parse(game)?),
+		};
+
+		Some(Self {
+			game,
+			scope: Scope::parse(parts.next()?)?,
+			window: Window::parse(parts.next()?)?,
+		})
+	}
+}
+
+/// Leaderboard points for a catch. Rarity dominates, size only separates
+/// catches of the same rarity.
+pub fn fishing_points(rarity: i32, size: i32) -> i32 {
+	let rarity = rarity.clamp(1, 5);
+
+	rarity * rarity * 10 + size.clamp(0, 100) / 10
+}
+
+/// Add `points` to the member's score in `game`. The member must already
+/// exist in `user_data`.
+pub async fn record_score<C: ConnectionTrait>(
+	db: &C, guild_id: &str, user_id: &str, game: MinigameType, points: i32,
+) -> Result<()> {
+	if points <= 0 {
+		return Ok(());
+	}
+
+	LeaderBoard::insert(leader_board::ActiveModel {
+		user_id: Set(user_id.to_string()),
+		minigame_type: Set(game.as_str().to_string()),
+		server_id: Set(guild_id.to_string()),
+		points: Set(0),
+	})
+	.on_conflict(
+		OnConflict::columns([
+			leader_board::Column::MinigameType,
+			leader_board::Column::ServerId,
+			leader_board::Column::UserId,
+		])
+		.do_nothing()
+		.to_owned(),
+	)
+	.exec_without_returning(db)
+	.await?;
+
+	LeaderBoard::update_many()
+		.col_expr(
+			leader_board::Column::Points,
+			Expr::col(leader_board::Column::Points).add(points),
+		)
+		.filter(leader_board::Column::MinigameType.eq(game.as_str()))
+		.filter(leader_board::Column::ServerId.eq(guild_id))
+		.filter(leader_board::Column::UserId.eq(user_id))
+		.exec(db)
+		.await?;
+
+	MinigameScore::insert(minigame_score::ActiveModel {
+		id: Set(uuid::Uuid::new_v4().to_string()),
+		user_id: Set(user_id.to_string()),
+		minigame_type: Set(game.as_str().to_string()),
+		server_id: Set(guild_id.to_string()),
+		points: Set(points),
+		created_at: Set(Utc::now().naive_utc()),
+	})
+	.exec_without_returning(db)
+	.await?;
+
+	Ok(())
+}
+
+#[derive(Debug, FromQueryResult)]
+struct TotalRow {
+	user_id: String,
+	total: i64,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct CountRow {
+	count: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ScoreEntry {
+	pub rank: u64,
+	pub user_id: String,
+	pub points: i64,
+}
+
+pub struct ScorePage {
+	pub entries: Vec<ScoreEntry>,
+	pub page: u64,
+	pub total_pages: u64,
+	/// The viewer's own position, `None` until they score.
+	pub viewer: Option<ScoreEntry>,
+}
+
+/// `user_id` and `total` points of every member on the leaderboard.
+fn totals_query(view: LeaderboardView, guild_id: &str) -> SelectStatement {
+	let mut query = Query::select();
+	query
+		.column(Alias::new("user_id"))
+		.expr_as(
+			Expr::cust("CAST(SUM(points) AS BIGINT)"),
+			Alias::new("total"),
+		)
+		.group_by_col(Alias::new("user_id"));
+
+	match view.window {
+		Window::AllTime => {
+			query.from(LeaderBoard);
+		},
+		Window::Weekly => {
+			let since = Utc::now().naive_utc() - Duration::days(WEEK_DAYS);
+			query
+				.from(MinigameScore)
+				.and_where(Expr::col(Alias::new("created_at")).gte(since));
+		},
+	}
+
+	if let Some(game) = view.game {
+		query.and_where(Expr::col(Alias::new("minigame_type")).eq(game.as_str()));
+	}
+	if view.scope == Scope::Server {
+		query.and_where(Expr::col(Alias::new("server_id")).eq(guild_id));
+	}
+
+	query
+}
+
+/// Members with more than `points` points, or all of them when `None`.
+async fn count_members<C: ConnectionTrait>(
+	db: &C, view: LeaderboardView, guild_id: &str, above: Option<i64>,
+) -> Result<u64> {
+	let mut query = Query::select();
+	query
+		.expr_as(Expr::cust("COUNT(*)"), Alias::new("count"))
+		.from_subquery(totals_query(view, guild_id), Alias::new("totals"));
+	if let Some(points) = above {
+		query.and_where(Expr::col(Alias::new("total")).gt(points));
+	}
+
+	let backend = db.get_database_backend();
+	let count = CountRow::find_by_statement(backend.build(&query))
+		.one(db)
+		.await?
+		.map(|row| row.count)
+		.unwrap_or_default();
+
+	Ok(count.max(0) as u64)
+}
+
+pub async fn score_page<C: ConnectionTrait>(
+	db: &C, view: LeaderboardView, guild_id: &str, viewer_id: &str, page: u64,
+) -> Result<ScorePage> {
+	let backend = db.get_database_backend();
+
+	let total = count_members(db, view, guild_id, None).await?;
+	let total_pages = total.div_ceil(LEADERBOARD_PAGE_SIZE).max(1);
+	let page = page.min(total_pages - 1);
+
+	let mut query = totals_query(view, guild_id);
+	query
+		.order_by_expr(Expr::cust("total"), Order::Desc)
+		.order_by(Alias::new("user_id"), Order::Asc)
+		.offset(page * LEADERBOARD_PAGE_SIZE)
+		.limit(LEADERBOARD_PAGE_SIZE);
+	let rows = TotalRow::find_by_statement(backend.build(&query))
+		.all(db)
+		.await?;
+
+	// Same tie rule as the viewer line below
+	let first_rank = match rows.first() {
+		Some(row) => count_members(db, view, guild_id, Some(row.total)).await? + 1,
+		None => 1,
+	};
+	let totals: Vec<i64> = rows.iter().map(|row| row.total).collect();
+	let ranks = shared_ranks(&totals, page * LEADERBOARD_PAGE_SIZE, first_rank);
+	let entries = rows
+		.into_iter()
+		.zip(ranks)
+		.map(|(row, rank)| ScoreEntry {
+			rank,
+			user_id: row.user_id,
+			points: row.total,
+		})
+		.collect();
+
+	let mut query = totals_query(view, guild_id);
+	query.and_where(Expr::col(Alias::new("user_id")).eq(viewer_id));
+	let viewer = match TotalRow::find_by_statement(backend.build(&query))
+		.one(db)
+		.await?
+	{
+		// Ties share the better rank
+		Some(row) => Some(ScoreEntry {
+			rank: count_members(db, view, guild_id, Some(row.total)).await? + 1,
+			user_id: row.user_id,
+			points: row.total,
+		}),
+		None => None,
+	};
+
+	Ok(ScorePage {
+		entries,
+		page,
+		total_pages,
+		viewer,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_rarity_outweighs_size() {
+		assert!(fishing_points(2, 1) > fishing_points(1, 100));
+		assert!(fishing_points(5, 1) > fishing_points(4, 100));
+		assert!(fishing_points(3, 90) > fishing_points(3, 10));
+	}
+
+	#[test]
+	fn test_view_round_trip() {
+		let views = [
+			LeaderboardView {
+				game: None,
+				scope: Scope::Global,
+				window: Window::Weekly,
+			},
+			LeaderboardView {
+				game: Some(MinigameType::Fishing),
+				scope: Scope::Server,
+				window: Window::AllTime,
+			},
+		];
+
+		for view in views {
+			assert_eq!(LeaderboardView::decode(&view.encode()), Some(view));
+		}
+		assert_eq!(LeaderboardView::decode("darts:server:weekly"), None);
+	}
+}
@@ -0,0 +1,37 @@
+//! Minigame features shared by several commands.
+//!
+//! Everything in here is gated on the MINIGAME module; commands check
+//! [`minigame_enabled`] first and answer with [`module_disabled_embed`] when
+//! the guild turned the module off.
+pub mod anisong_quiz;
+pub mod daily;
+pub mod economy;
+pub mod fishing;
+pub mod gacha;
+pub mod guess_anime;
+pub mod leaderboard;
+pub mod trade;
+
+use crate::command::embed_content::{EmbedContent, EmbedsContents};
+use sea_orm::DatabaseConnection;
+use shared::helper::get_module_state::{is_module_enabled, GuildModule};
+use shared::localization::{Loader, USABLE_LOCALES};
+use std::sync::Arc;
+use unic_langid::LanguageIdentifier;
+
+pub async fn minigame_enabled(guild_id: &str, db_connection: Arc<DatabaseConnection>) -> bool {
+	is_module_enabled(guild_id.to_string(), GuildModule::MiniGame, db_connection).await
+}
+
+pub fn module_disabled_embed<'a>(lang_id: &LanguageIdentifier) -> EmbedsContents<'a> {
+	let embed_content =
+		EmbedContent::new(USABLE_LOCALES.lookup(lang_id, "minigame-disabled_title"))
+			.description(USABLE_LOCALES.lookup(lang_id, "minigame-disabled_desc"));
+
+	EmbedsContents::new(vec![embed_content])
+}
+
+/// Rarity as stars, used where a localized name does not fit (autocomplete).
+pub fn rarity_stars(rarity: i32) -> String {
+	"★".repeat(rarity.clamp(1, 5) as usize)
+}
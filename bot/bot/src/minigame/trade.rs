@@ -0,0 +1,953 @@
+//! Trades and gifts between members of a guild.
+//!
+//! A trade is a `trade` row plus one `trade_item` row per inventory entry
+//! and one `trade_card` row per gacha card offered. Every change to an offer bumps the trade `version` and clears both
+//! confirmations, and a confirmation only counts for the version it was given
+//! on, so nobody can be tricked into accepting an offer they never saw.
+//!
+//! Entries and cards offered in an open trade are held: they cannot be sold,
+//! gifted or offered in another trade. The exchange itself moves them with
+//! conditional updates and pays coins through [`apply_change`] in a single
+//! transaction, rolling everything back if anything moved in the meantime.
+//! Closed trades and their items stay in the tables as the trade log.
+use crate::minigame::economy::{apply_change, get_balance, LedgerReason, SaleSelection};
+use anyhow::Result;
+use chrono::{Duration, NaiveDateTime, Utc};
+use sea_orm::sea_query::{Expr, ExprTrait};
+use sea_orm::ActiveValue::Set;
+use sea_orm::{
+	ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, JoinType, QueryFilter,
+	QueryOrder, QuerySelect, RelationTrait, TransactionTrait,
+};
+use shared::database::prelude::{
+	CharacterPool, Item, Trade, TradeCard, TradeItem, UserCharacter, UserInventory,
+};
+use shared::database::{item, trade, trade_card, trade_item, user_character, user_inventory};
+use tracing::info;
+
+/// Open trades nobody touched for this long expire.
+pub const TRADE_TTL_SECS: i64 = 15 * 60;
+/// Entries one side can offer, the size of a select menu.
+pub const MAX_TRADE_ITEMS: usize = 25;
+/// Cards one side can offer, the size of a select menu.
+pub const MAX_TRADE_CARDS: usize = 25;
+/// Coins one side can offer.
+pub const MAX_TRADE_COINS: i64 = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TradeStatus {
+	Open,
+	Completed,
+	Cancelled,
+	Expired,
+}
+
+impl TradeStatus {
+	pub fn as_str(self) -> &'static str {
+		match self {
+			TradeStatus::Open => "open",
+			TradeStatus::Completed => "completed",
+			TradeStatus::Cancelled => "cancelled",
+			TradeStatus::Expired => "expired",
+		}
+	}
+
+	pub fn parse(status: &str) -> Self {
+		match status {
+			"open" => TradeStatus::Open,
+			"completed" => TradeStatus::Completed,
+			"cancelled" => TradeStatus::Cancelled,
+			_ => TradeStatus::Expired,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+	Initiator,
+	Partner,
+}
+
+impl Side {
+	pub fn of(trade: &trade::Model, user_id: &str) -> Option<Self> {
+		if trade.initiator_id == user_id {
+			Some(Side::Initiator)
+		} else if trade.partner_id == user_id {
+			Some(Side::Partner)
+		} else {
+			None
+		}
+	}
+
+	fn confirmed_column(self) -> trade::Column {
+		match self {
+			Side::Initiator => trade::Column::InitiatorConfirmed,
+			Side::Partner => trade::Column::PartnerConfirmed,
+		}
+	}
+
+	fn coins_column(self) -> trade::Column {
+		match self {
+			Side::Initiator => trade::Column::InitiatorCoins,
+			Side::Partner => trade::Column::PartnerCoins,
+		}
+	}
+}
+
+/// An entry on offer. `rarity` is `None` once the entry left the inventory.
+#[derive(Debug, Clone)]
+pub struct OfferedItem {
+	pub entry_id: String,
+	pub name: String,
+	pub rarity: Option<i32>,
+}
+
+/// A gacha card on offer. `rarity` is `None` once the card left the
+/// collection.
+#[derive(Debug, Clone)]
+pub struct OfferedCard {
+	pub card_id: String,
+	pub name: String,
+	pub rarity: Option<i32>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Offer {
+	pub user_id: String,
+	pub coins: i64,
+	pub items: Vec<OfferedItem>,
+	pub cards: Vec<OfferedCard>,
+	pub confirmed: bool,
+}
+
+impl Offer {
+	pub fn is_empty(&self) -> bool {
+		self.items.is_empty() && self.cards.is_empty() && self.coins == 0
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct TradeView {
+	pub trade: trade::Model,
+	pub status: TradeStatus,
+	pub initiator: Offer,
+	pub partner: Offer,
+}
+
+impl TradeView {
+	pub fn offer(&self, side: Side) -> &Offer {
+		match side {
+			Side::Initiator => &self.initiator,
+			Side::Partner => &self.partner,
+		}
+	}
+
+	pub fn expires_at(&self) -> NaiveDateTime {
+		self.trade.updated_at + Duration::seconds(TRADE_TTL_SECS)
+	}
+}
+
+fn cutoff() -> NaiveDateTime {
+	Utc::now().naive_utc() - Duration::seconds(TRADE_TTL_SECS)
+}
+
+/// Start a trade. Both members must already exist in `user_data`.
+pub async fn open_trade(
+	db_connection: &DatabaseConnection, guild_id: &str, initiator_id: &str, partner_id: &str,
+) -> Result<trade::Model> {
+	let now = Utc::now().naive_utc();
+
+	let trade = trade::ActiveModel {
+		id: Set(uuid::Uuid::new_v4().to_string()),
+		guild_id: Set(guild_id.to_string()),
+		initiator_id: Set(initiator_id.to_string()),
+		partner_id: Set(partner_id.to_string()),
+		status: Set(TradeStatus::Open.as_str().to_string()),
+		initiator_coins: Set(0),
+		partner_coins: Set(0),
+		initiator_confirmed: Set(false),
+		partner_confirmed: Set(false),
+		version: Set(0),
+		channel_id: Set(None),
+		message_id: Set(None),
+		created_at: Set(now),
+		updated_at: Set(now),
+		closed_at: Set(None),
+	};
+
+	let trade = Trade::insert(trade)
+		.exec_with_returning(db_connection)
+		.await?;
+
+	info!(
+		trade_id = %trade.id,
+		guild_id,
+		initiator_id,
+		partner_id,
+		"Trade opened"
+	);
+
+	Ok(trade)
+}
+
+/// Load a trade with both offers, expiring it first if it went stale.
+pub async fn load_trade<C: ConnectionTrait>(db: &C, trade_id: &str) -> Result<Option<TradeView>> {
+	let Some(mut trade) = Trade::find_by_id(trade_id.to_string()).one(db).await? else {
+		return Ok(None);
+	};
+
+	if trade.status == TradeStatus::Open.as_str() && trade.updated_at < cutoff() {
+		Trade::update_many()
+			.col_expr(
+				trade::Column::Status,
+				Expr::value(TradeStatus::Expired.as_str()),
+			)
+			.col_expr(
+				trade::Column::ClosedAt,
+				Expr::value(Some(Utc::now().naive_utc())),
+			)
+			.filter(trade::Column::Id.eq(trade_id))
+			.filter(trade::Column::Status.eq(TradeStatus::Open.as_str()))
+			.exec(db)
+			.await?;
+		trade.status = TradeStatus::Expired.as_str().to_string();
+	}
+
+	let offered = TradeItem::find()
+		.filter(trade_item::Column::TradeId.eq(trade_id))
+		.find_also_related(Item)
+		.all(db)
+		.await?;
+
+	let entry_ids: Vec<String> = offered
+		.iter()
+		.map(|(offered, _)| offered.entry_id.clone())
+		.collect();
+	let entries = UserInventory::find()
+		.filter(user_inventory::Column::Id.is_in(entry_ids))
+		.all(db)
+		.await?;
+
+	let mut initiator = Offer {
+		user_id: trade.initiator_id.clone(),
+		coins: trade.initiator_coins,
+		items: Vec::new(),
+		cards: Vec::new(),
+		confirmed: trade.initiator_confirmed,
+	};
+	let mut partner = Offer {
+		user_id: trade.partner_id.clone(),
+		coins: trade.partner_coins,
+		items: Vec::new(),
+		cards: Vec::new(),
+		confirmed: trade.partner_confirmed,
+	};
+
+	for (offered, item) in offered {
+		// Still there and still owned by whoever offered it
+		let rarity = entries
+			.iter()
+			.find(|entry| entry.id == offered.entry_id && entry.user_id == offered.user_id)
+			.map(|entry| entry.rarity);
+
+		let item = OfferedItem {
+			entry_id: offered.entry_id,
+			name: item.map(|item| item.name).unwrap_or(offered.item_id),
+			rarity,
+		};
+
+		if offered.user_id == trade.initiator_id {
+			initiator.items.push(item);
+		} else {
+			partner.items.push(item);
+		}
+	}
+
+	let offered = TradeCard::find()
+		.filter(trade_card::Column::TradeId.eq(trade_id))
+		.find_also_related(CharacterPool)
+		.all(db)
+		.await?;
+
+	let card_ids: Vec<String> = offered
+		.iter()
+		.map(|(offered, _)| offered.card_id.clone())
+		.collect();
+	let cards = UserCharacter::find()
+		.filter(user_character::Column::Id.is_in(card_ids))
+		.all(db)
+		.await?;
+
+	for (offered, character) in offered {
+		let owned = cards
+			.iter()
+			.any(|card| card.id == offered.card_id && card.user_id == offered.user_id);
+
+		let card = OfferedCard {
+			card_id: offered.card_id,
+			name: character
+				.as_ref()
+				.map(|character| character.name.clone())
+				.unwrap_or_else(|| offered.character_id.to_string()),
+			rarity: character
+				.map(|character| character.rarity)
+				.filter(|_| owned),
+		};
+
+		if offered.user_id == trade.initiator_id {
+			initiator.cards.push(card);
+		} else {
+			partner.cards.push(card);
+		}
+	}
+
+	Ok(Some(TradeView {
+		status: TradeStatus::parse(&trade.status),
+		trade,
+		initiator,
+		partner,
+	}))
+}
+
+/// Ids of the entries `user_id` has on offer in open trades of the guild,
+/// leaving out `except_trade`.
+pub async fn held_entry_ids<C: ConnectionTrait>(
+	db: &C, guild_id: &str, user_id: &str, except_trade: Option<&str>,
+) -> Result<Vec<String>> {
+	let mut query = TradeItem::find()
+		.select_only()
+		.column(trade_item::Column::EntryId)
+		.join(JoinType::InnerJoin, trade_item::Relation::Trade.def())
+		.filter(trade_item::Column::UserId.eq(user_id))
+		.filter(trade::Column::GuildId.eq(guild_id))
+		.filter(trade::Column::Status.eq(TradeStatus::Open.as_str()))
+		.filter(trade::Column::UpdatedAt.gte(cutoff()));
+	if let Some(trade_id) = except_trade {
+		query = query.filter(trade::Column::Id.ne(trade_id));
+	}
+
+	Ok(query.into_tuple::<String>().all(db).await?)
+}
+
+/// Ids of the gacha cards `user_id` has on offer in open trades of the
+/// guild, leaving out `except_trade`.
+pub async fn held_card_ids<C: ConnectionTrait>(
+	db: &C, guild_id: &str, user_id: &str, except_trade: Option<&str>,
+) -> Result<Vec<String>> {
+	let mut query = TradeCard::find()
+		.select_only()
+		.column(trade_card::Column::CardId)
+		.join(JoinType::InnerJoin, trade_card::Relation::Trade.def())
+		.filter(trade_card::Column::UserId.eq(user_id))
+		.filter(trade::Column::GuildId.eq(guild_id))
+		.filter(trade::Column::Status.eq(TradeStatus::Open.as_str()))
+		.filter(trade::Column::UpdatedAt.gte(cutoff()));
+	if let Some(trade_id) = except_trade {
+		query = query.filter(trade::Column::Id.ne(trade_id));
+	}
+
+	Ok(query.into_tuple::<String>().all(db).await?)
+}
+
+/// Entries `user_id` can put on offer in `trade`: everything they own in the
+/// guild that is not held by another trade, at most [`MAX_TRADE_ITEMS`].
+pub async fn offerable_entries<C: ConnectionTrait>(
+	db: &C, trade: &trade::Model, user_id: &str,
+) -> Result<Vec<(user_inventory::Model, item::Model)>> {
+	let held = held_entry_ids(db, &trade.guild_id, user_id, Some(&trade.id)).await?;
+
+	let entries = UserInventory::find()
+		.find_also_related(Item)
+		.filter(user_inventory::Column::UserId.eq(user_id))
+		.filter(user_inventory::Column::ServerId.eq(trade.guild_id.clone()))
+		.filter(user_inventory::Column::Id.is_not_in(held))
+		.order_by_desc(user_inventory::Column::Rarity)
+		.limit(MAX_TRADE_ITEMS as u64)
+		.all(db)
+		.await?;
+
+	Ok(entries
+		.into_iter()
+		.filter_map(|(entry, item)| item.map(|item| (entry, item)))
+		.collect())
+}
+
+pub enum OfferChange {
+	Updated,
+	/// The trade was completed, cancelled or expired.
+	Closed,
+	InsufficientFunds {
+		balance: i64,
+	},
+}
+
+/// Record an offer change: bump the version, clear both confirmations and
+/// keep the trade alive. Returns `false` if the trade is no longer open.
+async fn touch<C: ConnectionTrait>(db: &C, trade_id: &str) -> Result<bool> {
+	let touched = Trade::update_many()
+		.col_expr(
+			trade::Column::Version,
+			Expr::col(trade::Column::Version).add(1),
+		)
+		.col_expr(trade::Column::InitiatorConfirmed, Expr::value(false))
+		.col_expr(trade::Column::PartnerConfirmed, Expr::value(false))
+		.col_expr(
+			trade::Column::UpdatedAt,
+			Expr::value(Utc::now().naive_utc()),
+		)
+		.filter(trade::Column::Id.eq(trade_id))
+		.filter(trade::Column::Status.eq(TradeStatus::Open.as_str()))
+		.filter(trade::Column::UpdatedAt.gte(cutoff()))
+		.exec(db)
+		.await?;
+
+	Ok(touched.rows_affected > 0)
+}
+
+/// Replace the entries `user_id` offers. Entries the member does not own or
+/// has on offer elsewhere are skipped.
+pub async fn set_offered_items(
+	db_connection: &DatabaseConnection, trade: &trade::Model, user_id: &str, entry_ids: Vec<String>,
+) -> Result<OfferChange> {
+	let txn = db_connection.begin().await?;
+
+	if !touch(&txn, &trade.id).await? {
+		txn.rollback().await?;
+		return Ok(OfferChange::Closed);
+	}
+
+	let held = held_entry_ids(&txn, &trade.guild_id, user_id, Some(&trade.id)).await?;
+	let entries = UserInventory::find()
+		.filter(user_inventory::Column::Id.is_in(entry_ids))
+		.filter(user_inventory::Column::Id.is_not_in(held))
+		.filter(user_inventory::Column::UserId.eq(user_id))
+		.filter(user_inventory::Column::ServerId.eq(trade.guild_id.clone()))
+		.all(&txn)
+		.await?;
+
+	TradeItem::delete_many()
+		.filter(trade_item::Column::TradeId.eq(trade.id.clone()))
+		.filter(trade_item::Column::UserId.eq(user_id))
+		.exec(&txn)
+		.await?;
+
+	let offered: Vec<trade_item::ActiveModel> = entries
+		.into_iter()
+		.take(MAX_TRADE_ITEMS)
+		.map(|entry| trade_item::ActiveModel {
+			trade_id: Set(trade.id.clone()),
+			entry_id: Set(entry.id),
+			user_id: Set(user_id.to_string()),
+			item_id: Set(entry.item_id),
+		})
+		.collect();
+	if !offered.is_empty() {
+		TradeItem::insert_many(offered)
+			.exec_without_returning(&txn)
+			.await?;
+	}
+
+	txn.commit().await?;
+
+	Ok(OfferChange::Updated)
+}
+
+/// Replace the gacha cards `user_id` offers. Cards the member does not own or
+/// has on offer elsewhere are skipped.
+pub async fn set_offered_cards(
+	db_connection: &DatabaseConnection, trade: &trade::Model, user_id: &str, card_ids: Vec<String>,
+) -> Result<OfferChange> {
+	let txn = db_connection.begin().await?;
+
+	if !touch(&txn, &trade.id).await? {
+		txn.rollback().await?;
+		return Ok(OfferChange::Closed);
+	}
+
+	let held = held_card_ids(&txn, &trade.guild_id, user_id, Some(&trade.id)).await?;
+	let cards = UserCharacter::find()
+		.filter(user_character::Column::Id.is_in(card_ids))
+		.filter(user_character::Column::Id.is_not_in(held))
+		.filter(user_character::Column::UserId.eq(user_id))
+		.filter(user_character::Column::ServerId.eq(trade.guild_id.clone()))
+		.all(&txn)
+		.await?;
+
+	TradeCard::delete_many()
+		.filter(trade_card::Column::TradeId.eq(trade.id.clone()))
+		.filter(trade_card::Column::UserId.eq(user_id))
+		.exec(&txn)
+		.await?;
+
+	let offered: Vec<trade_card::ActiveModel> = cards
+		.into_iter()
+		.take(MAX_TRADE_CARDS)
+		.map(|card| trade_card::ActiveModel {
+			trade_id: Set(trade.id.clone()),
+			card_id: Set(card.id),
+			user_id: Set(user_id.to_string()),
+			character_id: Set(card.character_id),
+		})
+		.collect();
+	if !offered.is_empty() {
+		TradeCard::insert_many(offered)
+			.exec_without_returning(&txn)
+			.await?;
+	}
+
+	txn.commit().await?;
+
+	Ok(OfferChange::Updated)
+}
+
+/// Change the coins `user_id` offers by `delta`, or reset them to zero when
+/// `delta` is 0. Offers above the current balance are refused; the balance is
+/// checked again when the trade completes.
+pub async fn change_offered_coins(
+	db_connection: &DatabaseConnection, trade: &trade::Model, user_id: &str, delta: i64,
+) -> Result<OfferChange> {
+	let Some(side) = Side::of(trade, user_id) else {
+		return Ok(OfferChange::Closed);
+	};
+
+	let current = match side {
+		Side::Initiator => trade.initiator_coins,
+		Side::Partner => trade.partner_coins,
+	};
+	let coins = if delta == 0 {
+		0
+	} else {
+		(current + delta).clamp(0, MAX_TRADE_COINS)
+	};
+
+	let balance = get_balance(db_connection, &trade.guild_id, user_id).await?;
+	if coins > balance {
+		return Ok(OfferChange::InsufficientFunds { balance });
+	}
+
+	let txn = db_connection.begin().await?;
+
+	if !touch(&txn, &trade.id).await? {
+		txn.rollback().await?;
+		return Ok(OfferChange::Closed);
+	}
+
+	Trade::update_many()
+		.col_expr(side.coins_column(), Expr::value(coins))
+		.filter(trade::Column::Id.eq(trade.id.clone()))
+		.exec(&txn)
+		.await?;
+
+	txn.commit().await?;
+
+	Ok(OfferChange::Updated)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmOutcome {
+	/// Confirmed, the other side has not yet.
+	Waiting,
+	Completed,
+	/// The offer changed since the window was drawn.
+	Stale,
+	Closed,
+	/// Something on offer moved or a wallet ran short; confirmations were
+	/// cleared so both sides can review the trade again.
+	Failed,
+}
+
+/// Confirm the trade as it was at `version`, completing it once both sides
+/// have confirmed.
+pub async fn confirm_trade(
+	db_connection: &DatabaseConnection, trade: &trade::Model, user_id: &str, version: i32,
+) -> Result<ConfirmOutcome> {
+	let Some(side) = Side::of(trade, user_id) else {
+		return Ok(ConfirmOutcome::Closed);
+	};
+
+	let confirmed = Trade::update_many()
+		.col_expr(side.confirmed_column(), Expr::value(true))
+		.col_expr(
+			trade::Column::UpdatedAt,
+			Expr::value(Utc::now().naive_utc()),
+		)
+		.filter(trade::Column::Id.eq(trade.id.clone()))
+		.filter(trade::Column::Status.eq(TradeStatus::Open.as_str()))
+		.filter(trade::Column::Version.eq(version))
+		.filter(trade::Column::UpdatedAt.gte(cutoff()))
+		.exec(db_connection)
+		.await?;
+
+	let Some(trade) = Trade::find_by_id(trade.id.clone())
+		.one(db_connection)
+		.await?
+	else {
+		return Ok(ConfirmOutcome::Closed);
+	};
+
+	if confirmed.rows_affected == 0 {
+		return Ok(if trade.status == TradeStatus::Open.as_str() {
+			ConfirmOutcome::Stale
+		} else {
+			ConfirmOutcome::Closed
+		});
+	}
+
+	if !(trade.initiator_confirmed && trade.partner_confirmed) {
+		return Ok(ConfirmOutcome::Waiting);
+	}
+
+	complete_trade(db_connection, &trade).await
+}
+
+async fn complete_trade(
+	db_connection: &DatabaseConnection, trade: &trade::Model,
+) -> Result<ConfirmOutcome> {
+	let txn = db_connection.begin().await?;
+
+	// Only one of two simultaneous final confirmations gets to run the exchange
+	let claimed = Trade::update_many()
+		.col_expr(
+			trade::Column::Status,
+			Expr::value(TradeStatus::Completed.as_str()),
+		)
+		.col_expr(
+			trade::Column::ClosedAt,
+			Expr::value(Some(Utc::now().naive_utc())),
+		)
+		.filter(trade::Column::Id.eq(trade.id.clone()))
+		.filter(trade::Column::Status.eq(TradeStatus::Open.as_str()))
+		.filter(trade::Column::Version.eq(trade.version))
+		.filter(trade::Column::InitiatorConfirmed.eq(true))
+		.filter(trade::Column::PartnerConfirmed.eq(true))
+		.exec(&txn)
+		.await?;
+	if claimed.rows_affected == 0 {
+		txn.rollback().await?;
+		return Ok(ConfirmOutcome::Closed);
+	}
+
+	let offered = TradeItem::find()
+		.filter(trade_item::Column::TradeId.eq(trade.id.clone()))
+		.all(&txn)
+		.await?;
+	let offered_cards = TradeCard::find()
+		.filter(trade_card::Column::TradeId.eq(trade.id.clone()))
+		.all(&txn)
+		.await?;
+
+	let sides = [
+		(
+			&trade.initiator_id,
+			&trade.partner_id,
+			trade.initiator_coins,
+		),
+		(&trade.partner_id, &trade.initiator_id, trade.partner_coins),
+	];
+
+	let mut complete = true;
+	for (from, to, coins) in sides {
+		let entry_ids: Vec<String> = offered
+			.iter()
+			.filter(|offered| &offered.user_id == from)
+			.map(|offered| offered.entry_id.clone())
+			.collect();
+
+		if !entry_ids.is_empty()
+			&& !transfer_entries(&txn, &trade.guild_id, from, to, &entry_ids).await?
+		{
+			complete = false;
+			break;
+		}
+
+		let card_ids: Vec<String> = offered_cards
+			.iter()
+			.filter(|offered| &offered.user_id == from)
+			.map(|offered| offered.card_id.clone())
+			.collect();
+
+		if !card_ids.is_empty()
+			&& !transfer_cards(&txn, &trade.guild_id, from, to, &card_ids).await?
+		{
+			complete = false;
+			break;
+		}
+
+		if coins > 0 && !transfer_coins(&txn, &trade.guild_id, from, to, coins, &trade.id).await? {
+			complete = false;
+			break;
+		}
+	}
+
+	if !complete {
+		txn.rollback().await?;
+		touch(db_connection, &trade.id).await?;
+		info!(trade_id = %trade.id, "Trade failed, an offer changed underneath it");
+		return Ok(ConfirmOutcome::Failed);
+	}
+
+	txn.commit().await?;
+
+	info!(
+		trade_id = %trade.id,
+		guild_id = %trade.guild_id,
+		initiator_id = %trade.initiator_id,
+		partner_id = %trade.partner_id,
+		items = offered.len(),
+		cards = offered_cards.len(),
+		initiator_coins = trade.initiator_coins,
+		partner_coins = trade.partner_coins,
+		"Trade completed"
+	);
+
+	Ok(ConfirmOutcome::Completed)
+}
+
+/// Cancel an open trade. Returns `false` if it was already closed.
+pub async fn cancel_trade(db_connection: &DatabaseConnection, trade_id: &str) -> Result<bool> {
+	let cancelled = Trade::update_many()
+		.col_expr(
+			trade::Column::Status,
+			Expr::value(TradeStatus::Cancelled.as_str()),
+		)
+		.col_expr(
+			trade::Column::ClosedAt,
+			Expr::value(Some(Utc::now().naive_utc())),
+		)
+		.filter(trade::Column::Id.eq(trade_id))
+		.filter(trade::Column::Status.eq(TradeStatus::Open.as_str()))
+		.exec(db_connection)
+		.await?;
+
+	if cancelled.rows_affected > 0 {
+		info!(trade_id, "Trade cancelled");
+	}
+
+	Ok(cancelled.rows_affected > 0)
+}
+
+/// Remember where the trade window lives so offers changed from the private
+/// offer panel can redraw it.
+pub async fn set_trade_message(
+	db_connection: &DatabaseConnection, trade_id: &str, channel_id: String, message_id: String,
+) -> Result<()> {
+	Trade::update_many()
+		.col_expr(trade::Column::ChannelId, Expr::value(Some(channel_id)))
+		.col_expr(trade::Column::MessageId, Expr::value(Some(message_id)))
+		.filter(trade::Column::Id.eq(trade_id))
+		.exec(db_connection)
+		.await?;
+
+	Ok(())
+}
+
+/// Move entries from one member to another. Returns `false`, having moved
+/// nothing the caller should keep, unless every entry still belonged to
+/// `from`.
+async fn transfer_entries<C: ConnectionTrait>(
+	db: &C, guild_id: &str, from: &str, to: &str, entry_ids: &[String],
+) -> Result<bool> {
+	let moved = UserInventory::update_many()
+		.col_expr(user_inventory::Column::UserId, Expr::value(to))
+		.filter(user_inventory::Column::Id.is_in(entry_ids.to_vec()))
+		.filter(user_inventory::Column::UserId.eq(from))
+		.filter(user_inventory::Column::ServerId.eq(guild_id))
+		.exec(db)
+		.await?;
+
+	Ok(moved.rows_affected == entry_ids.len() as u64)
+}
+
+/// Move gacha cards from one member to another, with the same guarantee as
+/// [`transfer_entries`].
+async fn transfer_cards<C: ConnectionTrait>(
+	db: &C, guild_id: &str, from: &str, to: &str, card_ids: &[String],
+) -> Result<bool> {
+	let moved = UserCharacter::update_many()
+		.col_expr(user_character::Column::UserId, Expr::value(to))
+		.filter(user_character::Column::Id.is_in(card_ids.to_vec()))
+		.filter(user_character::Column::UserId.eq(from))
+		.filter(user_character::Column::ServerId.eq(guild_id))
+		.exec(db)
+		.await?;
+
+	Ok(moved.rows_affected == card_ids.len() as u64)
+}
+
+/// Move traded coins between two wallets, both ledger rows referencing the
+/// trade. Returns `false` when `from` cannot pay.
+async fn transfer_coins<C: ConnectionTrait>(
+	db: &C, guild_id: &str, from: &str, to: &str, coins: i64, trade_id: &str,
+) -> Result<bool> {
+	let paid = apply_change(
+		db,
+		guild_id,
+		from,
+		-coins,
+		LedgerReason::Trade,
+		Some(trade_id.to_string()),
+	)
+	.await?;
+	if paid.is_none() {
+		return Ok(false);
+	}
+
+	apply_change(
+		db,
+		guild_id,
+		to,
+		coins,
+		LedgerReason::Trade,
+		Some(trade_id.to_string()),
+	)
+	.await?;
+
+	Ok(true)
+}
+
+pub enum GiftOutcome {
+	Given {
+		item_name: Option<String>,
+		count: usize,
+		coins: i64,
+	},
+	/// No entry matched (or every match is held by a trade) and no coins
+	/// were given.
+	NothingGiven,
+	InsufficientFunds {
+		balance: i64,
+	},
+}
+
+/// Give entries and/or coins to another member in one transaction. Both
+/// members must already exist in `user_data`.
+pub async fn gift(
+	db_connection: &DatabaseConnection, guild_id: &str, from: &str, to: &str,
+	selection: Option<SaleSelection>, coins: i64,
+) -> Result<GiftOutcome> {
+	let txn = db_connection.begin().await?;
+
+	let mut item_name = None;
+	let mut entry_ids = Vec::new();
+	if let Some(selection) = selection {
+		let held = held_entry_ids(&txn, guild_id, from, None).await?;
+		let mut query = UserInventory::find()
+			.find_also_related(Item)
+			.filter(user_inventory::Column::UserId.eq(from))
+			.filter(user_inventory::Column::ServerId.eq(guild_id))
+			.filter(user_inventory::Column::Id.is_not_in(held));
+		query = match selection {
+			SaleSelection::Entry(id) => query.filter(user_inventory::Column::Id.eq(id)),
+			SaleSelection::AllOf(item_id) => {
+				query.filter(user_inventory::Column::ItemId.eq(item_id))
+			},
+		};
+
+		for (entry, item) in query.all(&txn).await? {
+			item_name.get_or_insert(item.map(|item: item::Model| item.name).unwrap_or_default());
+			entry_ids.push(entry.id);
+		}
+	}
+
+	if entry_ids.is_empty() && coins <= 0 {
+		txn.rollback().await?;
+		return Ok(GiftOutcome::NothingGiven);
+	}
+
+	if !entry_ids.is_empty() && !transfer_entries(&txn, guild_id, from, to, &entry_ids).await? {
+		txn.rollback().await?;
+		return Ok(GiftOutcome::NothingGiven);
+	}
+
+	// Each ledger row points at the other member
+	if coins > 0 {
+		let paid = apply_change(
+			&txn,
+			guild_id,
+			from,
+			-coins,
+			LedgerReason::Gift,
+			Some(to.to_string()),
+		)
+		.await?;
+		let Some(_) = paid else {
+			txn.rollback().await?;
+			let balance = get_balance(db_connection, guild_id, from).await?;
+			return Ok(GiftOutcome::InsufficientFunds { balance });
+		};
+
+		apply_change(
+			&txn,
+			guild_id,
+			to,
+			coins,
+			LedgerReason::Gift,
+			Some(from.to_string()),
+		)
+		.await?;
+	}
+
+	txn.commit().await?;
+
+	info!(
+		guild_id,
+		from,
+		to,
+		items = entry_ids.len(),
+		coins,
+		"Gift given"
+	);
+
+	Ok(GiftOutcome::Given {
+		item_name: item_name.filter(|_| !entry_ids.is_empty()),
+		count: entry_ids.len(),
+		coins: coins.max(0),
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn trade() -> trade::Model {
+		let now = Utc::now().naive_utc();
+		trade::Model {
+			id: "trade".to_string(),
+			guild_id: "1".to_string(),
+			initiator_id: "10".to_string(),
+			partner_id: "20".to_string(),
+			status: "open".to_string(),
+			initiator_coins: 0,
+			partner_coins: 0,
+			initiator_confirmed: false,
+			partner_confirmed: false,
+			version: 0,
+			channel_id: None,
+			message_id: None,
+			created_at: now,
+			updated_at: now,
+			closed_at: None,
+		}
+	}
+
+	#[test]
+	fn test_side_of_participants_only() {
+		let trade = trade();
+
+		assert_eq!(Side::of(&trade, "10"), Some(Side::Initiator));
+		assert_eq!(Side::of(&trade, "20"), Some(Side::Partner));
+		assert_eq!(Side::of(&trade, "30"), None);
+	}
+
+	#[test]
+	fn test_status_round_trip() {
+		for status in [
+			TradeStatus::Open,
+			TradeStatus::Completed,
+			TradeStatus::Cancelled,
+			TradeStatus::Expired,
+		] {
+			assert_eq!(TradeStatus::parse(status.as_str()), status);
+		}
+	}
+}
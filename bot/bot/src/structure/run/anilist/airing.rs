@@ -0,0 +1,225 @@
+//! The anime of a season and the episodes airing in a time window.
+#[cynic::schema("anilist")]
+
+mod schema {}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+
+pub enum MediaSeason {
+	Winter,
+	Spring,
+	Summer,
+	Fall,
+}
+
+impl MediaSeason {
+	pub const ALL: &'static [MediaSeason] = &[
+		MediaSeason::Winter,
+		MediaSeason::Spring,
+		MediaSeason::Summer,
+		MediaSeason::Fall,
+	];
+
+	/// Name used by the command choices, the button ids and the translation keys.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			MediaSeason::Winter => "winter",
+			MediaSeason::Spring => "spring",
+			MediaSeason::Summer => "summer",
+			MediaSeason::Fall => "fall",
+		}
+	}
+
+	pub fn parse(value: &str) -> Option<Self> {
+		Self::ALL
+			.iter()
+			.copied()
+			.find(|season| season.as_str() == value)
+	}
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+
+pub enum MediaFormat {
+	Tv,
+	TvShort,
+	Movie,
+	Special,
+	Ova,
+	Ona,
+	Music,
+	Manga,
+	Novel,
+	OneShot,
+}
+
+impl MediaFormat {
+	/// The formats an anime can have.
+	pub const ANIME: &'static [MediaFormat] = &[
+		MediaFormat::Tv,
+		MediaFormat::TvShort,
+		MediaFormat::Movie,
+		MediaFormat::Special,
+		MediaFormat::Ova,
+		MediaFormat::Ona,
+		MediaFormat::Music,
+	];
+
+	/// Name used by the command choices, the button ids and the translation keys.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			MediaFormat::Tv => "tv",
+			MediaFormat::TvShort => "tv_short",
+			MediaFormat::Movie => "movie",
+			MediaFormat::Special => "special",
+			MediaFormat::Ova => "ova",
+			MediaFormat::Ona => "ona",
+			MediaFormat::Music => "music",
+			MediaFormat::Manga => "manga",
+			MediaFormat::Novel => "novel",
+			MediaFormat::OneShot => "one_shot",
+		}
+	}
+
+	pub fn parse(value: &str) -> Option<Self> {
+		Self::ANIME
+			.iter()
+			.copied()
+			.find(|format| format.as_str() == value)
+	}
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+
+pub enum MediaStatus {
+	Finished,
+	Releasing,
+	NotYetReleased,
+	Cancelled,
+	Hiatus,
+}
+
+#[derive(cynic::QueryVariables, Debug, Clone)]
+
+pub struct SeasonMediaVariables {
+	pub season: Option<MediaSeason>,
+	pub season_year: Option<i32>,
+	pub format_in: Option<Vec<Option<MediaFormat>>>,
+	pub genre: Option<String>,
+	pub page: Option<i32>,
+	pub per_page: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Query", variables = "SeasonMediaVariables")]
+
+pub struct SeasonMedia {
+	#[arguments(page: $ page, perPage: $ per_page)]
+	#[cynic(rename = "Page")]
+	pub page: Option<SeasonMediaPage>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Page", variables = "SeasonMediaVariables")]
+
+pub struct SeasonMediaPage {
+	pub page_info: Option<PageInfo>,
+	#[arguments(season: $ season, seasonYear: $ season_year, type: "ANIME", format_in: $ format_in, genre: $ genre, isAdult: false, sort: "POPULARITY_DESC")]
+	pub media: Option<Vec<Option<Media>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct PageInfo {
+	pub has_next_page: Option<bool>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct Media {
+	pub id: i32,
+	pub title: Option<MediaTitle>,
+	pub site_url: Option<String>,
+	pub format: Option<MediaFormat>,
+	pub status: Option<MediaStatus>,
+	pub episodes: Option<i32>,
+	pub genres: Option<Vec<Option<String>>>,
+	pub mean_score: Option<i32>,
+	#[arguments(isMain: true)]
+	pub studios: Option<StudioConnection>,
+	pub next_airing_episode: Option<NextAiringEpisode>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct MediaTitle {
+	pub user_preferred: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct StudioConnection {
+	pub nodes: Option<Vec<Option<Studio>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct Studio {
+	pub name: String,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "AiringSchedule")]
+
+pub struct NextAiringEpisode {
+	pub airing_at: i32,
+	pub episode: i32,
+}
+
+#[derive(cynic::QueryVariables, Debug, Clone)]
+
+pub struct AiringScheduleVariables {
+	pub airing_at_greater: Option<i32>,
+	pub airing_at_lesser: Option<i32>,
+	pub media_ids: Option<Vec<i32>>,
+	pub page: Option<i32>,
+	pub per_page: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Query", variables = "AiringScheduleVariables")]
+
+pub struct AiringSchedules {
+	#[arguments(page: $ page, perPage: $ per_page)]
+	#[cynic(rename = "Page")]
+	pub page: Option<AiringSchedulePage>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Page", variables = "AiringScheduleVariables")]
+
+pub struct AiringSchedulePage {
+	pub page_info: Option<PageInfo>,
+	#[arguments(airingAt_greater: $ airing_at_greater, airingAt_lesser: $ airing_at_lesser, mediaId_in: $ media_ids, sort: "TIME")]
+	pub airing_schedules: Option<Vec<Option<AiringSchedule>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct AiringSchedule {
+	pub airing_at: i32,
+	pub episode: i32,
+	pub media: Option<AiringMedia>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Media")]
+
+pub struct AiringMedia {
+	pub id: i32,
+	pub title: Option<MediaTitle>,
+	pub site_url: Option<String>,
+	pub format: Option<MediaFormat>,
+	pub episodes: Option<i32>,
+	pub is_adult: Option<bool>,
+}
@@ -0,0 +1,51 @@
+//! One anime by popularity rank, for the guess-the-anime minigame.
+#[cynic::schema("anilist")]
+
+mod schema {}
+
+#[derive(cynic::QueryVariables, Debug, Clone)]
+
+pub struct GuessAnimeVariables {
+	pub page: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Query", variables = "GuessAnimeVariables")]
+
+pub struct GuessAnime {
+	#[arguments(perPage: 1, page: $ page)]
+	#[cynic(rename = "Page")]
+	pub page: Option<Page>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct Page {
+	#[arguments(type: "ANIME", sort: "POPULARITY_DESC", isAdult: false)]
+	pub media: Option<Vec<Option<Media>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct Media {
+	pub id: i32,
+	pub title: Option<MediaTitle>,
+	pub synonyms: Option<Vec<Option<String>>>,
+	pub cover_image: Option<MediaCoverImage>,
+	pub banner_image: Option<String>,
+	pub site_url: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct MediaTitle {
+	pub romaji: Option<String>,
+	pub english: Option<String>,
+	pub user_preferred: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct MediaCoverImage {
+	pub extra_large: Option<String>,
+}
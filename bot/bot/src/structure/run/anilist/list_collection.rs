@@ -0,0 +1,77 @@
+//! A user's whole anime or manga list, loaded in chunks of up to 500 entries.
+#[cynic::schema("anilist")]
+
+mod schema {}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+
+pub enum MediaType {
+	Anime,
+	Manga,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+
+pub enum MediaListStatus {
+	Current,
+	Planning,
+	Completed,
+	Dropped,
+	Paused,
+	Repeating,
+}
+
+#[derive(cynic::QueryVariables, Debug, Clone)]
+
+pub struct ListCollectionVariables {
+	pub user_id: Option<i32>,
+	pub media_type: Option<MediaType>,
+	pub chunk: Option<i32>,
+	pub per_chunk: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Query", variables = "ListCollectionVariables")]
+
+pub struct ListCollection {
+	#[arguments(userId: $ user_id, type: $ media_type, chunk: $ chunk, perChunk: $ per_chunk)]
+	#[cynic(rename = "MediaListCollection")]
+	pub media_list_collection: Option<MediaListCollection>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct MediaListCollection {
+	pub lists: Option<Vec<Option<MediaListGroup>>>,
+	pub has_next_chunk: Option<bool>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct MediaListGroup {
+	pub entries: Option<Vec<Option<MediaList>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct MediaList {
+	pub status: Option<MediaListStatus>,
+	// Every user's score on the same scale, whatever their profile format
+	#[arguments(format: "POINT_100")]
+	pub score: Option<f64>,
+	pub media: Option<Media>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct Media {
+	pub id: i32,
+	pub title: Option<MediaTitle>,
+	pub site_url: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct MediaTitle {
+	pub user_preferred: Option<String>,
+}
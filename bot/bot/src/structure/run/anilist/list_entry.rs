@@ -0,0 +1,187 @@
+//! A member's own list entries, read and written with their AniList token.
+#[cynic::schema("anilist")]
+
+mod schema {}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+
+pub enum MediaListStatus {
+	Current,
+	Planning,
+	Completed,
+	Dropped,
+	Paused,
+	Repeating,
+}
+
+impl MediaListStatus {
+	pub const ALL: &'static [MediaListStatus] = &[
+		MediaListStatus::Current,
+		MediaListStatus::Planning,
+		MediaListStatus::Completed,
+		MediaListStatus::Dropped,
+		MediaListStatus::Paused,
+		MediaListStatus::Repeating,
+	];
+
+	/// Name used by the command choices and the translation keys.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			MediaListStatus::Current => "current",
+			MediaListStatus::Planning => "planning",
+			MediaListStatus::Completed => "completed",
+			MediaListStatus::Dropped => "dropped",
+			MediaListStatus::Paused => "paused",
+			MediaListStatus::Repeating => "repeating",
+		}
+	}
+
+	pub fn parse(value: &str) -> Option<Self> {
+		Self::ALL
+			.iter()
+			.copied()
+			.find(|status| status.as_str() == value)
+	}
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+
+pub enum ScoreFormat {
+	#[cynic(rename = "POINT_100")]
+	Point100,
+	#[cynic(rename = "POINT_10_DECIMAL")]
+	Point10Decimal,
+	#[cynic(rename = "POINT_10")]
+	Point10,
+	#[cynic(rename = "POINT_5")]
+	Point5,
+	#[cynic(rename = "POINT_3")]
+	Point3,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+
+pub enum MediaType {
+	Anime,
+	Manga,
+}
+
+#[derive(cynic::QueryVariables, Debug, Clone)]
+
+pub struct SaveMediaListEntryVariables {
+	pub media_id: Option<i32>,
+	#[cynic(skip_serializing_if = "Option::is_none")]
+	pub status: Option<MediaListStatus>,
+	#[cynic(skip_serializing_if = "Option::is_none")]
+	pub score: Option<f64>,
+	#[cynic(skip_serializing_if = "Option::is_none")]
+	pub progress: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Mutation", variables = "SaveMediaListEntryVariables")]
+
+pub struct SaveMediaListEntry {
+	#[arguments(mediaId: $ media_id, status: $ status, score: $ score, progress: $ progress)]
+	#[cynic(rename = "SaveMediaListEntry")]
+	pub save_media_list_entry: Option<MediaList>,
+}
+
+#[derive(cynic::QueryVariables, Debug, Clone)]
+
+pub struct ListEntryVariables {
+	pub user_id: Option<i32>,
+	pub media_id: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Query", variables = "ListEntryVariables")]
+
+pub struct ListEntry {
+	#[arguments(userId: $ user_id, mediaId: $ media_id)]
+	#[cynic(rename = "MediaList")]
+	pub media_list: Option<MediaList>,
+}
+
+#[derive(cynic::QueryVariables, Debug, Clone)]
+
+pub struct CurrentListVariables {
+	pub user_id: Option<i32>,
+	pub per_page: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Query", variables = "CurrentListVariables")]
+
+pub struct CurrentList {
+	#[arguments(perPage: $ per_page)]
+	#[cynic(rename = "Page")]
+	pub page: Option<CurrentListPage>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Page", variables = "CurrentListVariables")]
+
+pub struct CurrentListPage {
+	#[arguments(userId: $ user_id, status_in: ["CURRENT", "REPEATING"], sort: "UPDATED_TIME_DESC")]
+	pub media_list: Option<Vec<Option<MediaList>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Query")]
+
+pub struct ViewerOptions {
+	#[cynic(rename = "Viewer")]
+	pub viewer: Option<Viewer>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "User")]
+
+pub struct Viewer {
+	pub id: i32,
+	pub media_list_options: Option<MediaListOptions>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct MediaListOptions {
+	pub score_format: Option<ScoreFormat>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct MediaList {
+	pub id: i32,
+	pub status: Option<MediaListStatus>,
+	pub score: Option<f64>,
+	pub progress: Option<i32>,
+	pub media: Option<Media>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct Media {
+	pub id: i32,
+	#[cynic(rename = "type")]
+	pub media_type: Option<MediaType>,
+	pub title: Option<MediaTitle>,
+	pub episodes: Option<i32>,
+	pub chapters: Option<i32>,
+	pub site_url: Option<String>,
+	pub cover_image: Option<MediaCoverImage>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct MediaTitle {
+	pub romaji: Option<String>,
+	pub english: Option<String>,
+	pub user_preferred: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct MediaCoverImage {
+	pub large: Option<String>,
+}
@@ -0,0 +1,201 @@
+//! What AniList users recommend for several titles at once, and the tags a
+//! user scores best.
+#[cynic::schema("anilist")]
+
+mod schema {}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+
+pub enum MediaType {
+	Anime,
+	Manga,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+
+pub enum MediaFormat {
+	Tv,
+	TvShort,
+	Movie,
+	Special,
+	Ova,
+	Ona,
+	Music,
+	Manga,
+	Novel,
+	OneShot,
+}
+
+impl MediaFormat {
+	pub const ALL: &'static [MediaFormat] = &[
+		MediaFormat::Tv,
+		MediaFormat::TvShort,
+		MediaFormat::Movie,
+		MediaFormat::Special,
+		MediaFormat::Ova,
+		MediaFormat::Ona,
+		MediaFormat::Music,
+		MediaFormat::Manga,
+		MediaFormat::Novel,
+		MediaFormat::OneShot,
+	];
+
+	/// Name used by the command choices.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			MediaFormat::Tv => "tv",
+			MediaFormat::TvShort => "tv_short",
+			MediaFormat::Movie => "movie",
+			MediaFormat::Special => "special",
+			MediaFormat::Ova => "ova",
+			MediaFormat::Ona => "ona",
+			MediaFormat::Music => "music",
+			MediaFormat::Manga => "manga",
+			MediaFormat::Novel => "novel",
+			MediaFormat::OneShot => "one_shot",
+		}
+	}
+
+	pub fn parse(value: &str) -> Option<Self> {
+		Self::ALL
+			.iter()
+			.copied()
+			.find(|format| format.as_str() == value)
+	}
+}
+
+#[derive(cynic::QueryVariables, Debug, Clone)]
+
+pub struct MediaRecommendationsVariables {
+	pub ids: Option<Vec<i32>>,
+	pub per_page: Option<i32>,
+	pub per_media: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Query", variables = "MediaRecommendationsVariables")]
+
+pub struct MediaRecommendations {
+	#[arguments(perPage: $ per_page)]
+	#[cynic(rename = "Page")]
+	pub page: Option<MediaRecommendationsPage>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Page", variables = "MediaRecommendationsVariables")]
+
+pub struct MediaRecommendationsPage {
+	#[arguments(id_in: $ ids)]
+	pub media: Option<Vec<Option<SeedMedia>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Media", variables = "MediaRecommendationsVariables")]
+
+pub struct SeedMedia {
+	pub id: i32,
+	#[arguments(sort: "RATING_DESC", perPage: $ per_media)]
+	pub recommendations: Option<RecommendationConnection>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct RecommendationConnection {
+	pub nodes: Option<Vec<Option<Recommendation>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct Recommendation {
+	pub rating: Option<i32>,
+	pub media_recommendation: Option<Media>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct Media {
+	pub id: i32,
+	#[cynic(rename = "type")]
+	pub media_type: Option<MediaType>,
+	pub format: Option<MediaFormat>,
+	pub title: Option<MediaTitle>,
+	pub site_url: Option<String>,
+	pub genres: Option<Vec<Option<String>>>,
+	pub season_year: Option<i32>,
+	pub start_date: Option<FuzzyDate>,
+	pub mean_score: Option<i32>,
+	pub is_adult: Option<bool>,
+	pub tags: Option<Vec<Option<MediaTag>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct MediaTitle {
+	pub user_preferred: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct FuzzyDate {
+	pub year: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct MediaTag {
+	pub name: String,
+	pub rank: Option<i32>,
+}
+
+#[derive(cynic::QueryVariables, Debug, Clone)]
+
+pub struct UserTagStatisticsVariables {
+	pub id: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Query", variables = "UserTagStatisticsVariables")]
+
+pub struct UserTagStatistics {
+	#[arguments(id: $ id)]
+	#[cynic(rename = "User")]
+	pub user: Option<User>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct User {
+	pub id: i32,
+	pub name: String,
+	pub statistics: Option<UserStatisticTypes>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct UserStatisticTypes {
+	pub anime: Option<UserStatistics>,
+	pub manga: Option<UserStatistics>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct UserStatistics {
+	pub mean_score: f64,
+	#[arguments(limit: 50, sort: "COUNT_DESC")]
+	pub tags: Option<Vec<Option<UserTagStatistic>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct UserTagStatistic {
+	pub count: i32,
+	pub mean_score: f64,
+	pub tag: Option<UserStatisticTag>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "MediaTag")]
+
+pub struct UserStatisticTag {
+	pub name: String,
+}
@@ -0,0 +1,136 @@
+//! The relations of several titles at once, one layer of a franchise walk.
+#[cynic::schema("anilist")]
+
+mod schema {}
+
+#[derive(cynic::QueryVariables, Debug, Clone)]
+
+pub struct MediaRelationsVariables {
+	pub ids: Option<Vec<i32>>,
+	pub per_page: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Query", variables = "MediaRelationsVariables")]
+
+pub struct MediaRelations {
+	#[arguments(perPage: $ per_page)]
+	#[cynic(rename = "Page")]
+	pub page: Option<MediaRelationsPage>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Page", variables = "MediaRelationsVariables")]
+
+pub struct MediaRelationsPage {
+	#[arguments(id_in: $ ids)]
+	pub media: Option<Vec<Option<Media>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct Media {
+	pub id: i32,
+	#[cynic(rename = "type")]
+	pub media_type: Option<MediaType>,
+	pub format: Option<MediaFormat>,
+	pub title: Option<MediaTitle>,
+	pub start_date: Option<FuzzyDate>,
+	pub relations: Option<MediaConnection>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct MediaTitle {
+	pub english: Option<String>,
+	pub romaji: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct FuzzyDate {
+	pub year: Option<i32>,
+	pub month: Option<i32>,
+	pub day: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct MediaConnection {
+	pub edges: Option<Vec<Option<MediaEdge>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+
+pub struct MediaEdge {
+	#[arguments(version: 2)]
+	pub relation_type: Option<MediaRelation>,
+	pub node: Option<RelatedMedia>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Media")]
+
+pub struct RelatedMedia {
+	pub id: i32,
+	#[cynic(rename = "type")]
+	pub media_type: Option<MediaType>,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+
+pub enum MediaType {
+	Anime,
+	Manga,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+
+pub enum MediaFormat {
+	Tv,
+	TvShort,
+	Movie,
+	Special,
+	Ova,
+	Ona,
+	Music,
+	Manga,
+	Novel,
+	OneShot,
+}
+
+impl MediaFormat {
+	/// Short label drawn on the graph and shown next to the titles.
+	pub fn label(self) -> &'static str {
+		match self {
+			MediaFormat::Tv => "TV",
+			MediaFormat::TvShort => "TV SHORT",
+			MediaFormat::Movie => "MOVIE",
+			MediaFormat::Special => "SPECIAL",
+			MediaFormat::Ova => "OVA",
+			MediaFormat::Ona => "ONA",
+			MediaFormat::Music => "MUSIC",
+			MediaFormat::Manga => "MANGA",
+			MediaFormat::Novel => "NOVEL",
+			MediaFormat::OneShot => "ONE SHOT",
+		}
+	}
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+
+pub enum MediaRelation {
+	Adaptation,
+	Prequel,
+	Sequel,
+	Parent,
+	SideStory,
+	Character,
+	Summary,
+	Alternative,
+	SpinOff,
+	Other,
+	Source,
+	Compilation,
+	Contains,
+}
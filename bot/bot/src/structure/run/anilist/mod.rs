@@ -1,4 +1,10 @@
+pub mod airing;
 pub mod character;
+pub mod guess_anime;
+pub mod list_collection;
+pub mod media_recommendation;
+pub mod media_relation;
+pub mod list_entry;
 pub mod media;
 pub mod minimal_anime;
 pub mod random;
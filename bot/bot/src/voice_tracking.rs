@@ -0,0 +1,615 @@
+//! Voice session tracking.
+//!
+//! Open sessions live in the `voice_session` table rather than in memory, so
+//! they survive restarts. A session is closed into a `vocal` row when the
+//! member leaves or switches channels. Time spent muted, deafened or in the
+//! AFK channel is credited separately so stats can leave it out.
+use crate::achievement::{track, Stat};
+use crate::event_handler::BotData;
+use crate::handlers::user_db::add_user_data_to_db;
+use anyhow::{anyhow, Result};
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::prelude::Expr;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{
+	ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, TransactionTrait,
+};
+use serenity::all::{
+	ChannelId, Context as SerenityContext, Guild, GuildId, Ready, User, UserId, VoiceState,
+};
+use shared::database::prelude::{UserData, Vocal, VoiceSession};
+use shared::database::{vocal, voice_session};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::{debug, info, trace, warn};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VoiceFlags {
+	pub muted: bool,
+	pub deafened: bool,
+	pub afk: bool,
+}
+
+impl VoiceFlags {
+	pub fn from_state(voice_state: &VoiceState, afk_channel: Option<ChannelId>) -> Self {
+		Self {
+			muted: voice_state.self_mute() || voice_state.mute(),
+			deafened: voice_state.self_deaf() || voice_state.deaf(),
+			afk: voice_state.channel_id.is_some() && voice_state.channel_id == afk_channel,
+		}
+	}
+
+	fn from_session(session: &voice_session::Model) -> Self {
+		Self {
+			muted: session.muted,
+			deafened: session.deafened,
+			afk: session.afk,
+		}
+	}
+
+	fn is_idle(&self) -> bool {
+		self.muted || self.deafened || self.afk
+	}
+}
+
+/// Per-state totals of a session, including the segment still running.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StateTotals {
+	pub muted_secs: i32,
+	pub deafened_secs: i32,
+	pub afk_secs: i32,
+	pub idle_secs: i32,
+}
+
+/// Totals of `session` once the segment that started at `state_since` is
+/// credited up to `until`.
+pub fn credit_segment(session: &voice_session::Model, until: NaiveDateTime) -> StateTotals {
+	let elapsed = until
+		.signed_duration_since(session.state_since)
+		.num_seconds()
+		.clamp(0, i32::MAX as i64) as i32;
+	let flags = VoiceFlags::from_session(session);
+	let credit = |active: bool, total: i32| {
+		if active {
+			total.saturating_add(elapsed)
+		} else {
+			total
+		}
+	};
+
+	StateTotals {
+		muted_secs: credit(flags.muted, session.muted_secs),
+		deafened_secs: credit(flags.deafened, session.deafened_secs),
+		afk_secs: credit(flags.afk, session.afk_secs),
+		idle_secs: credit(flags.is_idle(), session.idle_secs),
+	}
+}
+
+fn afk_channel(ctx: &SerenityContext, guild_id: GuildId) -> Option<ChannelId> {
+	guild_id
+		.to_guild_cached(&ctx.cache)
+		.and_then(|guild| guild.afk_metadata.as_ref().map(|afk| afk.afk_channel_id))
+}
+
+/// The lock serializing updates of one member's stored session. It is only
+/// held around database work, never across a request to Discord, so events of
+/// other members and guilds are not held up.
+async fn session_lock(bot_data: &BotData, guild_id: GuildId, user_id: UserId) -> Arc<Mutex<()>> {
+	bot_data
+		.voice_session_locks
+		.get_with((guild_id, user_id), async { Arc::new(Mutex::new(())) })
+		.await
+}
+
+/// The member behind a voice state, from the event or the cache. Discord is
+/// never asked, a guild full of members in voice would cost a request each.
+fn voice_user(ctx: &SerenityContext, guild_id: GuildId, voice_state: &VoiceState) -> Option<User> {
+	if let Some(member) = &voice_state.member {
+		return Some(member.user.clone());
+	}
+
+	guild_id.to_guild_cached(&ctx.cache).and_then(|guild| {
+		guild
+			.members
+			.get(&voice_state.user_id)
+			.map(|member| member.user.clone())
+	})
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn open_session(
+	ctx: &SerenityContext, db_connection: &DatabaseConnection, guild_id: GuildId, user_id: UserId,
+	user: Option<User>, channel_id: ChannelId, flags: VoiceFlags, now: NaiveDateTime,
+) -> Result<()> {
+	// voice_session references user_data
+	match user {
+		Some(user) => {
+			let bot_data = ctx.data::<BotData>().clone();
+			add_user_data_to_db(user, bot_data.db_connection.clone()).await?;
+		},
+		None => {
+			if UserData::find_by_id(user_id.to_string())
+				.one(db_connection)
+				.await?
+				.is_none()
+			{
+				return Err(anyhow!("Member {} is neither cached nor stored", user_id));
+			}
+		},
+	}
+
+	let model = voice_session::ActiveModel {
+		guild_id: Set(guild_id.to_string()),
+		user_id: Set(user_id.to_string()),
+		channel_id: Set(channel_id.to_string()),
+		started_at: Set(now),
+		state_since: Set(now),
+		last_seen_at: Set(now),
+		muted: Set(flags.muted),
+		deafened: Set(flags.deafened),
+		afk: Set(flags.afk),
+		muted_secs: Set(0),
+		deafened_secs: Set(0),
+		afk_secs: Set(0),
+		idle_secs: Set(0),
+	};
+
+	VoiceSession::insert(model)
+		.on_conflict(
+			sea_orm::sea_query::OnConflict::columns([
+				voice_session::Column::GuildId,
+				voice_session::Column::UserId,
+			])
+			.update_columns([
+				voice_session::Column::ChannelId,
+				voice_session::Column::StartedAt,
+				voice_session::Column::StateSince,
+				voice_session::Column::LastSeenAt,
+				voice_session::Column::Muted,
+				voice_session::Column::Deafened,
+				voice_session::Column::Afk,
+				voice_session::Column::MutedSecs,
+				voice_session::Column::DeafenedSecs,
+				voice_session::Column::AfkSecs,
+				voice_session::Column::IdleSecs,
+			])
+			.to_owned(),
+		)
+		.exec(db_connection)
+		.await?;
+
+	info!(guild_id = %guild_id, user_id = %user_id, channel_id = %channel_id, "Started new vocal session");
+
+	Ok(())
+}
+
+/// Move the session into `vocal` and drop the open row in one transaction,
+/// then count its active time toward voice achievements.
+async fn close_session(
+	ctx: &SerenityContext, db_connection: &DatabaseConnection, session: voice_session::Model,
+	end: NaiveDateTime,
+) -> Result<()> {
+	// A state change after the last heartbeat must not produce negative time
+	let end = end.max(session.state_since);
+	let totals = credit_segment(&session, end);
+	let duration = end
+		.signed_duration_since(session.started_at)
+		.num_seconds()
+		.clamp(0, i32::MAX as i64) as i32;
+
+	let id = format!(
+		"{}-{}-{}",
+		session.user_id,
+		session.channel_id,
+		session.started_at.and_utc().timestamp_millis()
+	);
+
+	info!(
+		user_id = %session.user_id, channel_id = %session.channel_id,
+		start = %session.started_at, end = %end, duration = duration,
+		idle = totals.idle_secs, "Saving vocal session to database"
+	);
+
+	let txn = db_connection.begin().await?;
+
+	Vocal::insert(vocal::ActiveModel {
+		id: Set(id),
+		user_id: Set(session.user_id.clone()),
+		start: Set(session.started_at),
+		end: Set(end),
+		duration: Set(duration),
+		channel_id: Set(session.channel_id.clone()),
+		guild_id: Set(Some(session.guild_id.clone())),
+		muted_secs: Set(totals.muted_secs),
+		deafened_secs: Set(totals.deafened_secs),
+		afk_secs: Set(totals.afk_secs),
+		idle_secs: Set(totals.idle_secs),
+		recorded_at: Set(Utc::now().naive_utc()),
+	})
+	.exec(&txn)
+	.await?;
+
+	VoiceSession::delete_by_id((session.guild_id.clone(), session.user_id.clone()))
+		.exec(&txn)
+		.await?;
+
+	txn.commit().await?;
+
+	if let (Ok(user_id), Ok(guild_id)) = (
+		session.user_id.parse::<UserId>(),
+		session.guild_id.parse::<GuildId>(),
+	) {
+		// Unlocks are announced over HTTP, the caller holds the session lock
+		let ctx = ctx.clone();
+		let amount = i64::from(duration - totals.idle_secs);
+		tokio::spawn(async move {
+			track(
+				&ctx,
+				user_id,
+				Some(guild_id),
+				None,
+				Stat::VoiceHours,
+				amount,
+			)
+			.await;
+		});
+	}
+
+	Ok(())
+}
+
+/// Credit the running segment and start a new one with `flags`.
+async fn update_flags(
+	db_connection: &DatabaseConnection, session: voice_session::Model, flags: VoiceFlags,
+	now: NaiveDateTime,
+) -> Result<()> {
+	let totals = credit_segment(&session, now);
+
+	voice_session::ActiveModel {
+		guild_id: Set(session.guild_id),
+		user_id: Set(session.user_id),
+		state_since: Set(now),
+		last_seen_at: Set(now),
+		muted: Set(flags.muted),
+		deafened: Set(flags.deafened),
+		afk: Set(flags.afk),
+		muted_secs: Set(totals.muted_secs),
+		deafened_secs: Set(totals.deafened_secs),
+		afk_secs: Set(totals.afk_secs),
+		idle_secs: Set(totals.idle_secs),
+		..Default::default()
+	}
+	.update(db_connection)
+	.await?;
+
+	Ok(())
+}
+
+/// Bring the stored session of one member in line with `voice_state`.
+///
+/// The stored row is the source of truth, so the previous voice state is not
+/// needed: a join, a leave, a channel switch and a mute toggle all reduce to
+/// comparing the stored channel and flags with the new ones.
+#[allow(clippy::too_many_arguments)]
+async fn apply_voice_state(
+	ctx: &SerenityContext, db_connection: &DatabaseConnection, guild_id: GuildId,
+	session: Option<voice_session::Model>, voice_state: Option<&VoiceState>,
+	afk_channel: Option<ChannelId>, now: NaiveDateTime, closed_at: NaiveDateTime,
+) -> Result<()> {
+	let current = voice_state.and_then(|voice_state| {
+		voice_state
+			.channel_id
+			.map(|channel_id| (voice_state, channel_id))
+	});
+
+	match (session, current) {
+		(None, None) => {},
+		(Some(session), None) => {
+			close_session(ctx, db_connection, session, closed_at).await?;
+		},
+		(Some(session), Some((voice_state, channel_id)))
+			if session.channel_id == channel_id.to_string() =>
+		{
+			let flags = VoiceFlags::from_state(voice_state, afk_channel);
+			if flags != VoiceFlags::from_session(&session) {
+				trace!(guild_id = %guild_id, user_id = %session.user_id, ?flags, "Voice state flags changed");
+				update_flags(db_connection, session, flags, now).await?;
+			}
+		},
+		(session, Some((voice_state, channel_id))) => {
+			if let Some(session) = session {
+				debug!(guild_id = %guild_id, user_id = %voice_state.user_id, from = %session.channel_id, to = %channel_id, "User switched voice channels");
+				close_session(ctx, db_connection, session, closed_at).await?;
+			}
+			let flags = VoiceFlags::from_state(voice_state, afk_channel);
+			open_session(
+				ctx,
+				db_connection,
+				guild_id,
+				voice_state.user_id,
+				voice_user(ctx, guild_id, voice_state),
+				channel_id,
+				flags,
+				now,
+			)
+			.await?;
+		},
+	}
+
+	Ok(())
+}
+
+pub async fn handle_voice_state_update(
+	ctx: &SerenityContext, voice_state: &VoiceState,
+) -> Result<()> {
+	let Some(guild_id) = voice_state.guild_id else {
+		trace!(user_id = %voice_state.user_id, "Voice state outside of a guild, ignoring");
+		return Ok(());
+	};
+
+	let bot_data = ctx.data::<BotData>().clone();
+	let db_connection = bot_data.db_connection.clone();
+	let afk_channel = afk_channel(ctx, guild_id);
+
+	// Events for the same member may be dispatched concurrently
+	let lock = session_lock(&bot_data, guild_id, voice_state.user_id).await;
+	let _guard = lock.lock().await;
+
+	let session = VoiceSession::find_by_id((guild_id.to_string(), voice_state.user_id.to_string()))
+		.one(&*db_connection)
+		.await?;
+	let now = Utc::now().naive_utc();
+
+	apply_voice_state(
+		ctx,
+		&db_connection,
+		guild_id,
+		session,
+		Some(voice_state),
+		afk_channel,
+		now,
+		now,
+	)
+	.await
+}
+
+/// Reconcile the stored sessions of a guild with the voice states it was
+/// received with.
+///
+/// Sessions whose member is gone are closed at their last heartbeat, since
+/// nothing is known about the time the bot was offline. Members already in
+/// voice without a stored session get a new one.
+pub async fn reconcile_guild(ctx: &SerenityContext, guild: &Guild) -> Result<()> {
+	let bot_data = ctx.data::<BotData>().clone();
+	let db_connection = bot_data.db_connection.clone();
+	let afk_channel = guild.afk_metadata.as_ref().map(|afk| afk.afk_channel_id);
+	let voice_states: HashMap<UserId, VoiceState> = guild
+		.voice_states
+		.iter()
+		.filter(|voice_state| voice_state.channel_id.is_some())
+		.map(|voice_state| (voice_state.user_id, voice_state.clone()))
+		.collect();
+
+	let sessions = VoiceSession::find()
+		.filter(voice_session::Column::GuildId.eq(guild.id.to_string()))
+		.all(&*db_connection)
+		.await?;
+	let now = Utc::now().naive_utc();
+
+	let mut seen = HashSet::new();
+	for session in sessions {
+		let Ok(user_id) = session.user_id.parse::<UserId>() else {
+			continue;
+		};
+		seen.insert(user_id);
+
+		// Locked one member at a time, voice events of the others keep flowing
+		let lock = session_lock(&bot_data, guild.id, user_id).await;
+		let _guard = lock.lock().await;
+
+		// An event may have changed the session since the guild was listed
+		let session = match VoiceSession::find_by_id((session.guild_id, session.user_id))
+			.one(&*db_connection)
+			.await
+		{
+			Ok(session) => session,
+			Err(e) => {
+				warn!(guild_id = %guild.id, user_id = %user_id, error = ?e, "Failed to reload vocal session");
+				continue;
+			},
+		};
+		let closed_at = session
+			.as_ref()
+			.map(|session| session.last_seen_at)
+			.unwrap_or(now);
+
+		if let Err(e) = apply_voice_state(
+			ctx,
+			&db_connection,
+			guild.id,
+			session,
+			voice_states.get(&user_id),
+			afk_channel,
+			now,
+			closed_at,
+		)
+		.await
+		{
+			warn!(guild_id = %guild.id, user_id = %user_id, error = ?e, "Failed to reconcile vocal session");
+		}
+	}
+
+	for (user_id, voice_state) in &voice_states {
+		if seen.contains(user_id) {
+			continue;
+		}
+
+		let lock = session_lock(&bot_data, guild.id, *user_id).await;
+		let _guard = lock.lock().await;
+
+		// The member may have joined through an event since the guild was listed
+		let session = match VoiceSession::find_by_id((guild.id.to_string(), user_id.to_string()))
+			.one(&*db_connection)
+			.await
+		{
+			Ok(session) => session,
+			Err(e) => {
+				warn!(guild_id = %guild.id, user_id = %user_id, error = ?e, "Failed to reload vocal session");
+				continue;
+			},
+		};
+
+		if let Err(e) = apply_voice_state(
+			ctx,
+			&db_connection,
+			guild.id,
+			session,
+			Some(voice_state),
+			afk_channel,
+			now,
+			now,
+		)
+		.await
+		{
+			warn!(guild_id = %guild.id, user_id = %user_id, error = ?e, "Failed to open vocal session");
+		}
+	}
+
+	debug!(guild_id = %guild.id, in_voice = voice_states.len(), "Reconciled vocal sessions");
+
+	Ok(())
+}
+
+/// Close the sessions of guilds the bot is no longer part of. The remaining
+/// guilds are reconciled one by one as they are received.
+pub async fn reconcile_on_ready(ctx: &SerenityContext, ready: &Ready) -> Result<()> {
+	let bot_data = ctx.data::<BotData>().clone();
+	let db_connection = bot_data.db_connection.clone();
+	let guild_ids: Vec<String> = ready
+		.guilds
+		.iter()
+		.map(|guild| guild.id.to_string())
+		.collect();
+
+	let stale = VoiceSession::find()
+		.filter(voice_session::Column::GuildId.is_not_in(guild_ids))
+		.all(&*db_connection)
+		.await?;
+
+	for session in stale {
+		let Ok(guild_id) = session.guild_id.parse::<u64>() else {
+			continue;
+		};
+
+		// Guilds of other shards are missing from this ready payload
+		if let Some(shard) = ready.shard {
+			if (guild_id >> 22) % shard.total.get() as u64 != shard.id.0 as u64 {
+				continue;
+			}
+		}
+
+		let (Ok(user_id), Ok(locked_guild)) = (
+			session.user_id.parse::<UserId>(),
+			session.guild_id.parse::<GuildId>(),
+		) else {
+			continue;
+		};
+		let lock = session_lock(&bot_data, locked_guild, user_id).await;
+		let _guard = lock.lock().await;
+
+		// Skip sessions an event closed in the meantime
+		let session = match VoiceSession::find_by_id((session.guild_id, session.user_id))
+			.one(&*db_connection)
+			.await
+		{
+			Ok(Some(session)) => session,
+			Ok(None) => continue,
+			Err(e) => {
+				warn!(guild_id = %guild_id, error = ?e, "Failed to reload stale vocal session");
+				continue;
+			},
+		};
+
+		let closed_at = session.last_seen_at;
+		if let Err(e) = close_session(ctx, &db_connection, session, closed_at).await {
+			warn!(guild_id = %guild_id, error = ?e, "Failed to close stale vocal session");
+		}
+	}
+
+	Ok(())
+}
+
+/// Record that every open session was still running a moment ago, which
+/// bounds how much time a crash can add to a session.
+pub async fn heartbeat(db_connection: &DatabaseConnection) -> Result<()> {
+	VoiceSession::update_many()
+		.col_expr(
+			voice_session::Column::LastSeenAt,
+			Expr::value(Utc::now().naive_utc()),
+		)
+		.exec(db_connection)
+		.await?;
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use chrono::NaiveDate;
+
+	fn at(secs: u32) -> NaiveDateTime {
+		NaiveDate::from_ymd_opt(2026, 1, 1)
+			.unwrap()
+			.and_hms_opt(0, 0, 0)
+			.unwrap() + chrono::Duration::seconds(secs as i64)
+	}
+
+	fn session(muted: bool, deafened: bool, afk: bool) -> voice_session::Model {
+		voice_session::Model {
+			guild_id: "1".to_string(),
+			user_id: "2".to_string(),
+			channel_id: "3".to_string(),
+			started_at: at(0),
+			state_since: at(100),
+			last_seen_at: at(100),
+			muted,
+			deafened,
+			afk,
+			muted_secs: 10,
+			deafened_secs: 5,
+			afk_secs: 0,
+			idle_secs: 12,
+		}
+	}
+
+	#[test]
+	fn test_credit_segment_active_state() {
+		let totals = credit_segment(&session(false, false, false), at(160));
+		assert_eq!(
+			totals,
+			StateTotals {
+				muted_secs: 10,
+				deafened_secs: 5,
+				afk_secs: 0,
+				idle_secs: 12,
+			}
+		);
+	}
+
+	#[test]
+	fn test_credit_segment_idle_counted_once() {
+		let totals = credit_segment(&session(true, true, false), at(160));
+		assert_eq!(totals.muted_secs, 70);
+		assert_eq!(totals.deafened_secs, 65);
+		assert_eq!(totals.idle_secs, 72);
+	}
+
+	#[test]
+	fn test_credit_segment_never_negative() {
+		let totals = credit_segment(&session(false, false, true), at(50));
+		assert_eq!(totals.afk_secs, 0);
+		assert_eq!(totals.idle_secs, 12);
+	}
+}
@@ -0,0 +1,432 @@
+//! Personal airing notifications, the DMs the worker sends when an episode of
+//! an anime a member follows airs.
+//!
+//! The next episode of every followed anime is kept in `airing_episode`, the
+//! worker polls it like `activity_data` and moves it forward once the DMs are
+//! sent. Members with auto-follow get the airing anime of their CURRENT
+//! AniList list followed for them.
+#[cynic::schema("anilist")]
+mod schema {}
+
+#[derive(cynic::QueryVariables, Debug, Clone)]
+pub struct CurrentAnimeVariables {
+	pub user_id: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Query", variables = "CurrentAnimeVariables")]
+pub struct CurrentAnime {
+	#[arguments(userId: $ user_id, type: "ANIME", status: "CURRENT")]
+	#[cynic(rename = "MediaListCollection")]
+	pub media_list_collection: Option<MediaListCollection>,
+}
+
+#[derive(cynic::QueryVariables, Debug, Clone)]
+pub struct NextEpisodesVariables {
+	pub ids: Option<Vec<i32>>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Query", variables = "NextEpisodesVariables")]
+pub struct NextEpisodes {
+	#[arguments(perPage: 50)]
+	#[cynic(rename = "Page")]
+	pub page: Option<NextEpisodesPage>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(graphql_type = "Page", variables = "NextEpisodesVariables")]
+pub struct NextEpisodesPage {
+	#[arguments(id_in: $ ids, type: "ANIME")]
+	pub media: Option<Vec<Option<Media>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+pub struct MediaListCollection {
+	pub lists: Option<Vec<Option<MediaListGroup>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+pub struct MediaListGroup {
+	pub entries: Option<Vec<Option<MediaList>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+pub struct MediaList {
+	pub media: Option<Media>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+pub struct Media {
+	pub id: i32,
+	pub title: Option<MediaTitle>,
+	pub next_airing_episode: Option<AiringSchedule>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+pub struct MediaTitle {
+	pub english: Option<String>,
+	pub romaji: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+pub struct AiringSchedule {
+	pub airing_at: i32,
+	pub episode: i32,
+}
+
+use crate::anilist::make_request::make_request_anilist;
+use crate::cache::CacheInterface;
+use crate::database::prelude::{AiringEpisode, AiringSubscription};
+use crate::database::{airing_dm_setting, airing_episode, airing_subscription};
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use cynic::{GraphQlResponse, QueryBuilder};
+use sea_orm::sea_query::OnConflict;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+pub const AIRING_DM_BUTTON: &str = "airing_dm";
+
+/// How long the snooze button of a DM silences the notifications.
+pub const SNOOZE_BUTTON_HOURS: i64 = 24;
+
+/// Anime per request when moving episodes forward, AniList's page size.
+pub const NEXT_EPISODE_BATCH: usize = 50;
+
+/// DMs a member can get in [`DM_WINDOW_MINUTES`], the episodes past it are
+/// dropped rather than queued.
+pub const DM_LIMIT: i32 = 10;
+pub const DM_WINDOW_MINUTES: i64 = 60;
+
+/// The buttons under an airing DM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AiringDmAction {
+	Unfollow(i32),
+	Snooze,
+	Mute,
+}
+
+impl AiringDmAction {
+	pub fn custom_id(&self) -> String {
+		match self {
+			AiringDmAction::Unfollow(anime_id) => {
+				format!("{}_unfollow_{}", AIRING_DM_BUTTON, anime_id)
+			},
+			AiringDmAction::Snooze => format!("{}_snooze", AIRING_DM_BUTTON),
+			AiringDmAction::Mute => format!("{}_mute", AIRING_DM_BUTTON),
+		}
+	}
+
+	pub fn parse(custom_id: &str) -> Option<Self> {
+		let rest = custom_id
+			.strip_prefix(AIRING_DM_BUTTON)?
+			.strip_prefix('_')?;
+
+		match rest.split_once('_') {
+			Some(("unfollow", anime_id)) => Some(AiringDmAction::Unfollow(anime_id.parse().ok()?)),
+			None if rest == "snooze" => Some(AiringDmAction::Snooze),
+			None if rest == "mute" => Some(AiringDmAction::Mute),
+			_ => None,
+		}
+	}
+}
+
+/// The English title, the romaji one for anime without one.
+pub fn display_name(english: Option<String>, romaji: Option<String>, anime_id: i32) -> String {
+	english.or(romaji).unwrap_or_else(|| anime_id.to_string())
+}
+
+/// Whether the member muted or snoozed their notifications, members without
+/// settings get them.
+pub fn is_quiet(setting: Option<&airing_dm_setting::Model>, now: NaiveDateTime) -> bool {
+	setting.is_some_and(|setting| {
+		setting.muted
+			|| setting
+				.snoozed_until
+				.is_some_and(|snoozed_until| snoozed_until > now)
+	})
+}
+
+/// The rate limit window to store once one more DM is sent, `None` when the
+/// member already got [`DM_LIMIT`] DMs in the current window.
+pub fn take_dm_slot(
+	window_start: Option<NaiveDateTime>, window_count: i32, now: NaiveDateTime,
+) -> Option<(NaiveDateTime, i32)> {
+	match window_start {
+		Some(start) if now - start < Duration::minutes(DM_WINDOW_MINUTES) => {
+			(window_count < DM_LIMIT).then_some((start, window_count + 1))
+		},
+		_ => Some((now, 1)),
+	}
+}
+
+/// Keeps the next episode of an anime, or forgets the anime once nothing is
+/// scheduled anymore.
+pub async fn track_next_episode(
+	db: &DatabaseConnection, anime_id: i32, name: String, next_episode: Option<(i64, i32)>,
+) -> Result<()> {
+	let now = Utc::now().timestamp();
+	let Some((airing_at, episode)) = next_episode.filter(|(airing_at, _)| *airing_at > now) else {
+		AiringEpisode::delete_by_id(anime_id).exec(db).await?;
+		return Ok(());
+	};
+
+	let airing_at = DateTime::<Utc>::from_timestamp(airing_at, 0)
+		.ok_or(anyhow!("Invalid airing time {}", airing_at))?
+		.naive_utc();
+
+	AiringEpisode::insert(airing_episode::ActiveModel {
+		anime_id: Set(anime_id),
+		name: Set(name),
+		episode: Set(episode),
+		airing_at: Set(airing_at),
+	})
+	.on_conflict(
+		OnConflict::column(airing_episode::Column::AnimeId)
+			.update_columns([
+				airing_episode::Column::Name,
+				airing_episode::Column::Episode,
+				airing_episode::Column::AiringAt,
+			])
+			.to_owned(),
+	)
+	.exec(db)
+	.await?;
+
+	Ok(())
+}
+
+/// An anime and its next episode.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AiringAnime {
+	pub anime_id: i32,
+	pub name: String,
+	/// When the next episode airs and its number, for anime still airing.
+	pub next_episode: Option<(i64, i32)>,
+}
+
+impl From<Media> for AiringAnime {
+	fn from(media: Media) -> Self {
+		let (english, romaji) = media
+			.title
+			.map(|title| (title.english, title.romaji))
+			.unwrap_or_default();
+
+		Self {
+			anime_id: media.id,
+			name: display_name(english, romaji, media.id),
+			next_episode: media
+				.next_airing_episode
+				.map(|next| (next.airing_at as i64, next.episode)),
+		}
+	}
+}
+
+/// The CURRENT anime of an AniList user, always fresh from AniList so a
+/// show started today is followed on the next sync.
+pub async fn current_anime(
+	anilist_id: i32, anilist_cache: Arc<RwLock<CacheInterface>>,
+) -> Result<Vec<AiringAnime>> {
+	let operation = CurrentAnime::build(CurrentAnimeVariables {
+		user_id: Some(anilist_id),
+	});
+	let response: GraphQlResponse<CurrentAnime> =
+		make_request_anilist(operation, false, anilist_cache).await?;
+
+	let collection = response
+		.data
+		.and_then(|data| data.media_list_collection)
+		.ok_or(anyhow!("No list for the AniList user {}", anilist_id))?;
+
+	Ok(collection
+		.lists
+		.unwrap_or_default()
+		.into_iter()
+		.flatten()
+		.flat_map(|group| group.entries.unwrap_or_default())
+		.flatten()
+		.filter_map(|entry| entry.media)
+		.map(AiringAnime::from)
+		.collect())
+}
+
+/// Fetches the next episode of every anime in `anime_ids` and stores it,
+/// anime AniList no longer schedules anything for are forgotten. Always
+/// fresh from AniList, a cached answer would still list the episode that
+/// just aired.
+pub async fn refresh_next_episodes(
+	db: &DatabaseConnection, anime_ids: &[i32], anilist_cache: Arc<RwLock<CacheInterface>>,
+) -> Result<()> {
+	for ids in anime_ids.chunks(NEXT_EPISODE_BATCH) {
+		let operation = NextEpisodes::build(NextEpisodesVariables {
+			ids: Some(ids.to_vec()),
+		});
+		let response: GraphQlResponse<NextEpisodes> =
+			make_request_anilist(operation, false, anilist_cache.clone()).await?;
+
+		let mut missing: HashSet<i32> = ids.iter().copied().collect();
+		let media = response
+			.data
+			.and_then(|data| data.page)
+			.and_then(|page| page.media)
+			.ok_or(anyhow!("No media for the ids {:?}", ids))?;
+
+		for anime in media.into_iter().flatten().map(AiringAnime::from) {
+			missing.remove(&anime.anime_id);
+			track_next_episode(db, anime.anime_id, anime.name, anime.next_episode).await?;
+		}
+
+		if !missing.is_empty() {
+			AiringEpisode::delete_many()
+				.filter(airing_episode::Column::AnimeId.is_in(missing))
+				.exec(db)
+				.await?;
+		}
+	}
+
+	Ok(())
+}
+
+/// Follows the airing anime of the member's CURRENT list and unfollows the
+/// ones a previous sync added that left it. Anime the member followed
+/// themselves, or muted, are left as they are. Returns how many anime the
+/// list follows.
+pub async fn sync_list_follows(
+	db: &DatabaseConnection, user_id: &str, anilist_id: i32,
+	anilist_cache: Arc<RwLock<CacheInterface>>,
+) -> Result<usize> {
+	let airing: Vec<AiringAnime> = current_anime(anilist_id, anilist_cache)
+		.await?
+		.into_iter()
+		.filter(|entry| entry.next_episode.is_some())
+		.collect();
+	let airing_ids: HashSet<i32> = airing.iter().map(|entry| entry.anime_id).collect();
+
+	let from_list: Vec<i32> = AiringSubscription::find()
+		.filter(airing_subscription::Column::UserId.eq(user_id))
+		.filter(airing_subscription::Column::FromList.eq(true))
+		.all(db)
+		.await?
+		.into_iter()
+		.map(|subscription| subscription.anime_id)
+		.filter(|anime_id| !airing_ids.contains(anime_id))
+		.collect();
+	if !from_list.is_empty() {
+		AiringSubscription::delete_many()
+			.filter(airing_subscription::Column::UserId.eq(user_id))
+			.filter(airing_subscription::Column::AnimeId.is_in(from_list))
+			.exec(db)
+			.await?;
+	}
+
+	if airing.is_empty() {
+		return Ok(0);
+	}
+
+	let now = Utc::now().naive_utc();
+	let rows = airing.iter().map(|entry| airing_subscription::ActiveModel {
+		user_id: Set(user_id.to_string()),
+		anime_id: Set(entry.anime_id),
+		from_list: Set(true),
+		muted: Set(false),
+		created_at: Set(now),
+	});
+	AiringSubscription::insert_many(rows)
+		.on_conflict(
+			OnConflict::columns([
+				airing_subscription::Column::UserId,
+				airing_subscription::Column::AnimeId,
+			])
+			.do_nothing()
+			.to_owned(),
+		)
+		.exec_without_returning(db)
+		.await?;
+
+	for entry in &airing {
+		track_next_episode(db, entry.anime_id, entry.name.clone(), entry.next_episode).await?;
+	}
+
+	Ok(airing.len())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_action_round_trip() {
+		for action in [
+			AiringDmAction::Unfollow(21),
+			AiringDmAction::Snooze,
+			AiringDmAction::Mute,
+		] {
+			assert_eq!(AiringDmAction::parse(&action.custom_id()), Some(action));
+		}
+
+		assert_eq!(AiringDmAction::parse("airing_dm_unfollow_x"), None);
+		assert_eq!(AiringDmAction::parse("airing_dm_other"), None);
+		assert_eq!(AiringDmAction::parse("anilist_episode_21_3"), None);
+	}
+
+	#[test]
+	fn test_dm_slots_reset_with_the_window() {
+		let start = DateTime::<Utc>::from_timestamp(1_000_000, 0)
+			.unwrap()
+			.naive_utc();
+		let later = start + Duration::minutes(10);
+
+		assert_eq!(take_dm_slot(None, 0, start), Some((start, 1)));
+		assert_eq!(take_dm_slot(Some(start), 3, later), Some((start, 4)));
+		assert_eq!(take_dm_slot(Some(start), DM_LIMIT, later), None);
+
+		let next_window = start + Duration::minutes(DM_WINDOW_MINUTES);
+		assert_eq!(
+			take_dm_slot(Some(start), DM_LIMIT, next_window),
+			Some((next_window, 1))
+		);
+	}
+
+	#[test]
+	fn test_quiet_when_muted_or_snoozed() {
+		let now = DateTime::<Utc>::from_timestamp(1_000_000, 0)
+			.unwrap()
+			.naive_utc();
+		let setting = airing_dm_setting::Model {
+			user_id: String::from("1"),
+			auto_follow: false,
+			muted: false,
+			snoozed_until: None,
+			window_start: None,
+			window_count: 0,
+		};
+
+		assert!(!is_quiet(None, now));
+		assert!(!is_quiet(Some(&setting), now));
+		assert!(is_quiet(
+			Some(&airing_dm_setting::Model {
+				muted: true,
+				..setting.clone()
+			}),
+			now
+		));
+		assert!(is_quiet(
+			Some(&airing_dm_setting::Model {
+				snoozed_until: Some(now + Duration::hours(1)),
+				..setting.clone()
+			}),
+			now
+		));
+		assert!(!is_quiet(
+			Some(&airing_dm_setting::Model {
+				snoozed_until: Some(now - Duration::hours(1)),
+				..setting
+			}),
+			now
+		));
+	}
+}
@@ -0,0 +1,66 @@
+//! Characters by favourites, one page at a time, to fill the gacha pool.
+#[cynic::schema("anilist")]
+mod schema {}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CharacterPoolVariables {
+	pub page: Option<i32>,
+	pub per_page: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Query", variables = "CharacterPoolVariables")]
+pub struct CharacterPool {
+	#[arguments(page: $ page, perPage: $ per_page)]
+	#[cynic(rename = "Page")]
+	pub page: Option<Page>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+pub struct Page {
+	pub page_info: Option<PageInfo>,
+	#[arguments(sort: "FAVOURITES_DESC")]
+	pub characters: Option<Vec<Option<Character>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+pub struct PageInfo {
+	pub has_next_page: Option<bool>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+pub struct Character {
+	pub id: i32,
+	pub favourites: Option<i32>,
+	pub name: Option<CharacterName>,
+	pub image: Option<CharacterImage>,
+	pub site_url: Option<String>,
+	#[arguments(perPage: 1, sort: "POPULARITY_DESC")]
+	pub media: Option<MediaConnection>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+pub struct CharacterName {
+	pub user_preferred: Option<String>,
+	pub native: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+pub struct CharacterImage {
+	pub large: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+pub struct MediaConnection {
+	pub nodes: Option<Vec<Option<Media>>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+pub struct Media {
+	pub title: Option<MediaTitle>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+pub struct MediaTitle {
+	pub user_preferred: Option<String>,
+}
@@ -0,0 +1,31 @@
+//! Custom id of the "episode +1" button the worker puts on anime activity
+//! notifications and the bot handles.
+
+pub const EPISODE_BUTTON: &str = "anilist_episode";
+
+pub fn episode_button_id(anime_id: i32, episode: i32) -> String {
+	format!("{}_{}_{}", EPISODE_BUTTON, anime_id, episode)
+}
+
+/// The anime id and aired episode from a button custom id.
+pub fn parse_episode_button(custom_id: &str) -> Option<(i32, i32)> {
+	let rest = custom_id.strip_prefix(EPISODE_BUTTON)?.strip_prefix('_')?;
+	let (anime_id, episode) = rest.split_once('_')?;
+
+	Some((anime_id.parse().ok()?, episode.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_episode_button_round_trip() {
+		let custom_id = episode_button_id(21, 1100);
+
+		assert_eq!(custom_id, "anilist_episode_21_1100");
+		assert_eq!(parse_episode_button(&custom_id), Some((21, 1100)));
+		assert_eq!(parse_episode_button("anilist_episode_21"), None);
+		assert_eq!(parse_episode_button("anilist_verify"), None);
+	}
+}
@@ -3,11 +3,23 @@ use std::sync::Arc;
 use crate::cache::CacheInterface;
 use anyhow::{Context, Result};
 use cynic::{GraphQlResponse, Operation, QueryFragment, QueryVariables};
-use reqwest::Client;
+use reqwest::{Client, StatusCode};
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, trace, warn};
 
+/// AniList refused a member's access token, it expired or was revoked.
+#[derive(Debug)]
+pub struct InvalidAnilistToken;
+
+impl std::fmt::Display for InvalidAnilistToken {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "AniList rejected the access token")
+	}
+}
+
+impl std::error::Error for InvalidAnilistToken {}
+
 pub async fn make_request_anilist<
 	'a,
 	T: QueryFragment,
@@ -163,6 +175,131 @@ async fn do_request<
 	})
 }
 
+/// Like [`make_request_anilist`] with the cache, but `ttl` picks how many
+/// seconds the answer stays cached from what it contains, so data that
+/// changes when an episode airs can expire right then. Answers with errors
+/// and no data are not cached.
+pub async fn make_request_anilist_with_ttl<
+	T: QueryFragment,
+	S: QueryVariables + Serialize,
+	U: for<'de> Deserialize<'de>,
+>(
+	operation: Operation<T, S>, anilist_cache: Arc<RwLock<CacheInterface>>,
+	ttl: impl FnOnce(&U) -> u64,
+) -> Result<GraphQlResponse<U>> {
+	// Key must include variables so different queries don't collide in the cache
+	let key = format!(
+		"{}{}",
+		operation.query,
+		serde_json::to_string(&operation.variables).unwrap_or_default()
+	);
+
+	if let Some(data) = anilist_cache.read().await.read(&key).await? {
+		info!("Cache hit for GraphQL query");
+		return get_type(data).with_context(|| "Failed to deserialize cached GraphQL response");
+	}
+
+	info!("Cache miss for GraphQL query, making network request");
+	let response_text = Client::new()
+		.post("https://graphql.anilist.co/")
+		.header("Content-Type", "application/json")
+		.header("Accept", "application/json")
+		.json(&operation)
+		.send()
+		.await
+		.with_context(|| "Failed to send GraphQL request to Anilist API")?
+		.text()
+		.await
+		.with_context(|| "Failed to extract text from Anilist API response")?;
+
+	let response: GraphQlResponse<U> = get_type(response_text.clone()).with_context(|| {
+		format!(
+			"Failed to deserialize GraphQL response for query: {}",
+			operation.query
+		)
+	})?;
+
+	if let Some(data) = &response.data {
+		let ttl_secs = ttl(data);
+		debug!("Caching GraphQL response for {} seconds", ttl_secs);
+		anilist_cache
+			.write()
+			.await
+			.write_with_ttl(key, response_text, ttl_secs)
+			.await?;
+	}
+
+	Ok(response)
+}
+
+/// Send `operation` on behalf of the member owning `access_token`. The answer
+/// depends on who asks, so it is never cached.
+pub async fn make_authed_request_anilist<
+	T: QueryFragment,
+	S: QueryVariables + Serialize,
+	U: for<'de> Deserialize<'de>,
+>(
+	operation: Operation<T, S>, access_token: &str,
+) -> Result<GraphQlResponse<U>> {
+	info!("Making authenticated GraphQL request to Anilist API");
+
+	let resp = Client::new()
+		.post("https://graphql.anilist.co/")
+		.header("Content-Type", "application/json")
+		.header("Accept", "application/json")
+		.bearer_auth(access_token)
+		.json(&operation)
+		.send()
+		.await
+		.with_context(|| "Failed to send GraphQL request to Anilist API")?;
+
+	let status = resp.status();
+	let response_text = resp
+		.text()
+		.await
+		.with_context(|| "Failed to extract text from Anilist API response")?;
+
+	// AniList answers 401 for revoked tokens and 400 "Invalid token" for
+	// expired or malformed ones
+	if status == StatusCode::UNAUTHORIZED
+		|| (status == StatusCode::BAD_REQUEST && response_text.contains("Invalid token"))
+	{
+		warn!("Anilist rejected the access token with status {}", status);
+		return Err(InvalidAnilistToken.into());
+	}
+
+	get_type(response_text).with_context(|| {
+		format!(
+			"Failed to deserialize GraphQL response for query: {}",
+			operation.query
+		)
+	})
+}
+
+/// Send a query written by hand, for the aliased batches cynic cannot build.
+/// It is sent by the worker to refresh stored data, so it is never cached.
+pub async fn make_batch_request_anilist<U: for<'de> Deserialize<'de>>(
+	query: String,
+) -> Result<GraphQlResponse<U>> {
+	info!("Making batched GraphQL request to Anilist API");
+
+	let resp = Client::new()
+		.post("https://graphql.anilist.co/")
+		.header("Content-Type", "application/json")
+		.header("Accept", "application/json")
+		.json(&serde_json::json!({ "query": query }))
+		.send()
+		.await
+		.with_context(|| "Failed to send GraphQL request to Anilist API")?;
+
+	let response_text = resp
+		.text()
+		.await
+		.with_context(|| "Failed to extract text from Anilist API response")?;
+
+	get_type(response_text).with_context(|| "Failed to deserialize batched GraphQL response")
+}
+
 fn get_type<U: for<'de> Deserialize<'de>>(value: String) -> Result<GraphQlResponse<U>> {
 	trace!("Deserializing JSON response to GraphQL type");
 	debug!("Target type: {}", std::any::type_name::<U>());
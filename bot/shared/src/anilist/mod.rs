@@ -1,4 +1,9 @@
+pub mod airing_dm;
+pub mod character_pool;
+pub mod episode_button;
 pub mod make_request;
 pub mod minimal_anime;
+pub mod oauth;
 pub mod site_statistic_anime;
 pub mod site_statistic_manga;
+pub mod user_stats;
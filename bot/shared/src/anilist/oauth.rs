@@ -0,0 +1,200 @@
+//! Pieces of the verified AniList linking shared by the bot and the api
+//! server.
+//!
+//! The bot hands a member a short lived link token, a JWT signed with the api
+//! secret whose audience keeps it from passing as a dashboard session. The
+//! token alone proves nothing about who opened the link, so the api server
+//! has the browser log in with Discord and only sends it on to AniList when
+//! that account is the one the token was issued to. The AniList access token
+//! is stored encrypted with [`TokenCipher`].
+use crate::config::AnilistOAuthConfig;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use anyhow::{anyhow, Result};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine as _;
+use chrono::{Duration, Utc};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+
+pub const LINK_AUDIENCE: &str = "anilist_link";
+/// How long a member has to open the link once the bot sent it.
+pub const LINK_TOKEN_MINUTES: i64 = 15;
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LinkClaims {
+	sub: String,
+	aud: String,
+	exp: usize,
+}
+
+/// Sign a link token for the Discord user `user_id`.
+pub fn create_link_token(secret: &[u8], user_id: &str) -> Result<String> {
+	let claims = LinkClaims {
+		sub: user_id.to_string(),
+		aud: LINK_AUDIENCE.to_string(),
+		exp: (Utc::now() + Duration::minutes(LINK_TOKEN_MINUTES)).timestamp() as usize,
+	};
+
+	Ok(encode(
+		&Header::default(),
+		&claims,
+		&EncodingKey::from_secret(secret),
+	)?)
+}
+
+/// The Discord user id a link token was issued to.
+pub fn verify_link_token(secret: &[u8], token: &str) -> Result<String> {
+	let mut validation = Validation::new(jsonwebtoken::Algorithm::HS256);
+	validation.set_audience(&[LINK_AUDIENCE]);
+
+	let data = decode::<LinkClaims>(token, &DecodingKey::from_secret(secret), &validation)?;
+
+	Ok(data.claims.sub)
+}
+
+/// Address of the api server page that starts the AniList login.
+pub fn login_url(config: &AnilistOAuthConfig, token: &str) -> String {
+	let query = serde_urlencoded::to_string([("token", token)]).unwrap_or_default();
+
+	format!(
+		"{}/api/anilist/login?{}",
+		config.public_url.trim_end_matches('/'),
+		query
+	)
+}
+
+/// Where Discord sends the member back to once it confirmed who opened a
+/// link. It must be registered as a redirect of the Discord application.
+pub fn discord_redirect_uri(config: &AnilistOAuthConfig) -> String {
+	format!(
+		"{}/api/anilist/discord",
+		config.public_url.trim_end_matches('/')
+	)
+}
+
+/// AES-256-GCM over the stored AniList tokens. The output is the base64 of
+/// the nonce followed by the ciphertext.
+pub struct TokenCipher {
+	cipher: Aes256Gcm,
+}
+
+impl TokenCipher {
+	pub fn from_base64_key(key: &str) -> Result<Self> {
+		let key = STANDARD.decode(key.trim())?;
+		if key.len() != 32 {
+			return Err(anyhow!(
+				"AniList token key must be 32 bytes, got {}",
+				key.len()
+			));
+		}
+
+		Ok(Self {
+			cipher: Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key)),
+		})
+	}
+
+	pub fn encrypt(&self, plaintext: &str) -> Result<String> {
+		let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+		let ciphertext = self
+			.cipher
+			.encrypt(&nonce, plaintext.as_bytes())
+			.map_err(|_| anyhow!("Failed to encrypt the AniList token"))?;
+
+		let mut payload = nonce.to_vec();
+		payload.extend_from_slice(&ciphertext);
+
+		Ok(STANDARD.encode(payload))
+	}
+
+	pub fn decrypt(&self, encoded: &str) -> Result<String> {
+		let payload = STANDARD.decode(encoded)?;
+		if payload.len() <= NONCE_LEN {
+			return Err(anyhow!("Stored AniList token is too short"));
+		}
+
+		let (nonce, ciphertext) = payload.split_at(NONCE_LEN);
+		let plaintext = self
+			.cipher
+			.decrypt(Nonce::from_slice(nonce), ciphertext)
+			.map_err(|_| anyhow!("Failed to decrypt the AniList token"))?;
+
+		Ok(String::from_utf8(plaintext)?)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const KEY: &str = "MDEyMzQ1Njc4OWFiY2RlZjAxMjM0NTY3ODlhYmNkZWY=";
+
+	#[test]
+	fn test_cipher_round_trip() {
+		let cipher = TokenCipher::from_base64_key(KEY).unwrap();
+		let encrypted = cipher.encrypt("secret-token").unwrap();
+
+		assert!(!encrypted.contains("secret-token"));
+		assert_ne!(encrypted, cipher.encrypt("secret-token").unwrap());
+		assert_eq!(cipher.decrypt(&encrypted).unwrap(), "secret-token");
+	}
+
+	#[test]
+	fn test_cipher_rejects_bad_input() {
+		assert!(TokenCipher::from_base64_key("c2hvcnQ=").is_err());
+
+		let cipher = TokenCipher::from_base64_key(KEY).unwrap();
+		let mut payload = STANDARD
+			.decode(cipher.encrypt("secret-token").unwrap())
+			.unwrap();
+		let last = payload.len() - 1;
+		payload[last] ^= 1;
+		assert!(cipher.decrypt(&STANDARD.encode(payload)).is_err());
+	}
+
+	#[test]
+	fn test_link_urls() {
+		let config = AnilistOAuthConfig {
+			client_id: String::from("1"),
+			client_secret: String::from("secret"),
+			redirect_uri: String::from("https://api.example.com/api/anilist/callback"),
+			public_url: String::from("https://api.example.com/"),
+			token_key: String::from(KEY),
+		};
+
+		assert_eq!(
+			login_url(&config, "abc"),
+			"https://api.example.com/api/anilist/login?token=abc"
+		);
+		assert_eq!(
+			discord_redirect_uri(&config),
+			"https://api.example.com/api/anilist/discord"
+		);
+	}
+
+	#[test]
+	fn test_link_token_audience() {
+		let secret = b"link-secret";
+		let token = create_link_token(secret, "1234").unwrap();
+		assert_eq!(verify_link_token(secret, &token).unwrap(), "1234");
+		assert!(verify_link_token(b"other-secret", &token).is_err());
+
+		// A dashboard session token has no audience and must not start a link
+		#[derive(Serialize)]
+		struct Session {
+			sub: String,
+			exp: usize,
+		}
+		let session = encode(
+			&Header::default(),
+			&Session {
+				sub: "1234".to_string(),
+				exp: (Utc::now() + Duration::hours(1)).timestamp() as usize,
+			},
+			&EncodingKey::from_secret(secret),
+		)
+		.unwrap();
+		assert!(verify_link_token(secret, &session).is_err());
+	}
+}
@@ -0,0 +1,193 @@
+//! Profile statistics of many AniList users in one request.
+//!
+//! AniList has no `id_in` filter for users, so each user gets an aliased
+//! `User` field in the same query. The worker uses it to refresh the stats
+//! behind the server leaderboards without one request per member.
+use crate::anilist::make_request::make_batch_request_anilist;
+use anyhow::Result;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// Users per request, keeps the query well under AniList's complexity limit.
+pub const USER_BATCH: usize = 25;
+
+const USER_STATS_FRAGMENT: &str = "fragment stats on User { id name statistics { \
+	anime { count meanScore minutesWatched statuses { status count } } \
+	manga { count meanScore chaptersRead statuses { status count } } } }";
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchUser {
+	pub id: i32,
+	pub name: String,
+	pub statistics: Option<BatchStatistics>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BatchStatistics {
+	pub anime: Option<BatchTypeStatistics>,
+	pub manga: Option<BatchTypeStatistics>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BatchTypeStatistics {
+	pub count: i64,
+	pub mean_score: f64,
+	pub minutes_watched: i64,
+	pub chapters_read: i64,
+	pub statuses: Vec<BatchStatusStatistic>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct BatchStatusStatistic {
+	pub status: String,
+	pub count: i64,
+}
+
+impl BatchTypeStatistics {
+	fn completed(&self) -> i64 {
+		self.statuses
+			.iter()
+			.filter(|status| status.status == "COMPLETED")
+			.map(|status| status.count)
+			.sum()
+	}
+}
+
+/// What the leaderboards rank, for one user.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserStatsSummary {
+	pub anilist_id: i32,
+	pub name: String,
+	pub minutes_watched: i64,
+	pub chapters_read: i64,
+	/// Over anime and manga, 0 when nothing is scored.
+	pub mean_score: f64,
+	pub completed: i64,
+	pub xp: f64,
+}
+
+/// The experience of the `anilist_user level` command.
+pub fn anilist_xp(completed: i64, chapters_read: i64, minutes_watched: i64) -> f64 {
+	(8.0 * completed as f64) + (2.0 * chapters_read as f64) + (minutes_watched as f64 * 0.5)
+}
+
+impl From<BatchUser> for UserStatsSummary {
+	fn from(user: BatchUser) -> Self {
+		let statistics = user.statistics;
+		let anime = statistics
+			.as_ref()
+			.and_then(|statistics| statistics.anime.clone())
+			.unwrap_or_default();
+		let manga = statistics
+			.and_then(|statistics| statistics.manga)
+			.unwrap_or_default();
+
+		// AniList gives 0 as the mean of a list with no score
+		let scored: Vec<&BatchTypeStatistics> = [&anime, &manga]
+			.into_iter()
+			.filter(|stats| stats.mean_score > 0.0 && stats.count > 0)
+			.collect();
+		let scored_count: i64 = scored.iter().map(|stats| stats.count).sum();
+		let mean_score = if scored_count > 0 {
+			scored
+				.iter()
+				.map(|stats| stats.mean_score * stats.count as f64)
+				.sum::<f64>()
+				/ scored_count as f64
+		} else {
+			0.0
+		};
+
+		let completed = anime.completed() + manga.completed();
+
+		Self {
+			anilist_id: user.id,
+			name: user.name,
+			minutes_watched: anime.minutes_watched,
+			chapters_read: manga.chapters_read,
+			mean_score,
+			completed,
+			xp: anilist_xp(completed, manga.chapters_read, anime.minutes_watched),
+		}
+	}
+}
+
+/// One `User` field per id, aliased `u0`, `u1`... in the order of `ids`.
+pub fn batch_query(ids: &[i32]) -> String {
+	let fields = ids
+		.iter()
+		.enumerate()
+		.map(|(index, id)| format!("u{}: User(id: {}) {{ ...stats }}", index, id))
+		.collect::<Vec<_>>()
+		.join(" ");
+
+	format!("query {{ {} }} {}", fields, USER_STATS_FRAGMENT)
+}
+
+/// The statistics of every user in `ids`, at most [`USER_BATCH`] of them.
+/// Deleted accounts come back as `null` with an error and are left out.
+pub async fn fetch_user_stats(ids: &[i32]) -> Result<Vec<UserStatsSummary>> {
+	let response =
+		make_batch_request_anilist::<HashMap<String, Option<BatchUser>>>(batch_query(ids)).await?;
+
+	Ok(response
+		.data
+		.unwrap_or_default()
+		.into_values()
+		.flatten()
+		.map(UserStatsSummary::from)
+		.collect())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_batch_query_aliases_each_user() {
+		let query = batch_query(&[12, 34]);
+
+		assert!(query
+			.starts_with("query { u0: User(id: 12) { ...stats } u1: User(id: 34) { ...stats } }"));
+		assert!(query.ends_with(USER_STATS_FRAGMENT));
+	}
+
+	#[test]
+	fn test_summary_from_batch_user() {
+		let json = r#"{
+			"id": 5, "name": "someone",
+			"statistics": {
+				"anime": {"count": 30, "meanScore": 80.0, "minutesWatched": 1000,
+					"statuses": [{"status": "COMPLETED", "count": 20}, {"status": "CURRENT", "count": 10}]},
+				"manga": {"count": 10, "meanScore": 60.0, "chaptersRead": 200,
+					"statuses": [{"status": "COMPLETED", "count": 4}]}
+			}
+		}"#;
+		let user: BatchUser = serde_json::from_str(json).unwrap();
+
+		let summary = UserStatsSummary::from(user);
+
+		assert_eq!(summary.completed, 24);
+		assert_eq!(summary.minutes_watched, 1000);
+		assert_eq!(summary.chapters_read, 200);
+		assert!((summary.mean_score - 75.0).abs() < 1e-9);
+		assert_eq!(summary.xp, anilist_xp(24, 200, 1000));
+	}
+
+	#[test]
+	fn test_unscored_list_is_left_out_of_the_mean() {
+		let json = r#"{
+			"id": 6, "name": "other",
+			"statistics": {
+				"anime": {"count": 12, "meanScore": 70.0, "statuses": []},
+				"manga": {"count": 40, "meanScore": 0, "statuses": []}
+			}
+		}"#;
+		let user: BatchUser = serde_json::from_str(json).unwrap();
+
+		assert_eq!(UserStatsSummary::from(user).mean_score, 70.0);
+	}
+}
@@ -1,11 +1,34 @@
 use anyhow::{Context, Result};
 use moka::future::Cache;
+use moka::Expiry;
 use redis::AsyncCommands;
+use std::time::{Duration, Instant};
 
 use crate::config::CacheConfig;
 
+/// A cached value with how long it stays valid.
+#[derive(Clone)]
+struct CacheEntry {
+	value: String,
+	ttl: Duration,
+}
+
+/// Expires each memory entry after its own TTL.
+struct EntryTtl;
+
+impl Expiry<String, CacheEntry> for EntryTtl {
+	fn expire_after_create(
+		&self, _key: &String, value: &CacheEntry, _created_at: Instant,
+	) -> Option<Duration> {
+		Some(value.ttl)
+	}
+}
+
 enum CacheBackend {
-	Memory(Cache<String, String>),
+	Memory {
+		cache: Cache<String, CacheEntry>,
+		ttl_secs: u64,
+	},
 	Redis {
 		connection: redis::aio::MultiplexedConnection,
 		ttl_secs: u64,
@@ -25,10 +48,10 @@ impl CacheInterface {
 	pub fn new_memory(max_capacity: u64, ttl_secs: u64) -> Self {
 		let cache = Cache::builder()
 			.max_capacity(max_capacity)
-			.time_to_live(std::time::Duration::from_secs(ttl_secs))
+			.expire_after(EntryTtl)
 			.build();
 		Self {
-			backend: CacheBackend::Memory(cache),
+			backend: CacheBackend::Memory { cache, ttl_secs },
 		}
 	}
 
@@ -84,7 +107,7 @@ impl CacheInterface {
 
 	pub async fn read(&self, key: &String) -> Result<Option<String>> {
 		match &self.backend {
-			CacheBackend::Memory(cache) => Ok(cache.get(key).await),
+			CacheBackend::Memory { cache, .. } => Ok(cache.get(key).await.map(|entry| entry.value)),
 			CacheBackend::Redis { connection, .. } => {
 				let mut conn = connection.clone();
 				let value: Option<String> = conn.get(key).await?;
@@ -94,17 +117,30 @@ impl CacheInterface {
 	}
 
 	pub async fn write(&self, key: String, value: String) -> Result<()> {
+		let ttl_secs = match &self.backend {
+			CacheBackend::Memory { ttl_secs, .. } | CacheBackend::Redis { ttl_secs, .. } => {
+				*ttl_secs
+			},
+		};
+
+		self.write_with_ttl(key, value, ttl_secs).await
+	}
+
+	/// Like [`CacheInterface::write`], for data that goes stale sooner or
+	/// later than the configured TTL.
+	pub async fn write_with_ttl(&self, key: String, value: String, ttl_secs: u64) -> Result<()> {
 		match &self.backend {
-			CacheBackend::Memory(cache) => {
-				cache.insert(key, value).await;
+			CacheBackend::Memory { cache, .. } => {
+				let entry = CacheEntry {
+					value,
+					ttl: Duration::from_secs(ttl_secs),
+				};
+				cache.insert(key, entry).await;
 				Ok(())
 			},
-			CacheBackend::Redis {
-				connection,
-				ttl_secs,
-			} => {
+			CacheBackend::Redis { connection, .. } => {
 				let mut conn = connection.clone();
-				conn.set_ex::<_, _, ()>(&key, &value, *ttl_secs).await?;
+				conn.set_ex::<_, _, ()>(&key, &value, ttl_secs).await?;
 				Ok(())
 			},
 		}
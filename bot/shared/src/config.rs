@@ -317,6 +317,69 @@ pub struct TaskIntervalConfig {
 	pub random_stats_update: u64,
 	pub anisong_update: u64,
 	pub bot_info_update: u64,
+	/// How often connected voice members are credited leveling XP.
+	#[serde(default = "default_voice_xp_update")]
+	pub voice_xp_update: u64,
+	/// How often the worker deletes messages past their retention window.
+	#[serde(default = "default_message_purge")]
+	pub message_purge: u64,
+	/// How often the worker folds raw message and vocal rows into daily stats.
+	#[serde(default = "default_stats_rollup")]
+	pub stats_rollup: u64,
+	/// How often the worker refreshes the gacha character pool from AniList.
+	#[serde(default = "default_character_pool_update")]
+	pub character_pool_update: u64,
+	/// How often the worker refreshes the AniList stats of registered members.
+	#[serde(default = "default_anilist_stats_update")]
+	pub anilist_stats_update: u64,
+	/// How often the worker follows the CURRENT lists of members with airing
+	/// auto-follow.
+	#[serde(default = "default_airing_follow_sync")]
+	pub airing_follow_sync: u64,
+}
+
+fn default_voice_xp_update() -> u64 {
+	60
+}
+
+fn default_message_purge() -> u64 {
+	86400
+}
+
+fn default_stats_rollup() -> u64 {
+	3600
+}
+
+fn default_character_pool_update() -> u64 {
+	604800
+}
+
+fn default_anilist_stats_update() -> u64 {
+	21600
+}
+
+fn default_airing_follow_sync() -> u64 {
+	3600
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct MessageTrackingConfig {
+	/// Days a tracked message is kept before the worker deletes it, 0 keeps
+	/// messages forever.
+	#[serde(default = "default_message_retention_days")]
+	pub retention_days: u32,
+}
+
+fn default_message_retention_days() -> u32 {
+	90
+}
+
+impl Default for MessageTrackingConfig {
+	fn default() -> Self {
+		Self {
+			retention_days: default_message_retention_days(),
+		}
+	}
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -327,8 +390,12 @@ pub struct ApiConfig {
 	pub debug: bool,
 	pub allowed_domain: Option<String>,
 	pub oauth: OAuthConfig,
+	/// Verified AniList linking, disabled when the section is missing.
+	pub anilist: Option<AnilistOAuthConfig>,
 	#[serde(default = "default_rate_limit")]
 	pub rate_limit_per_minute: u32,
+	#[serde(default = "default_stats_rate_limit")]
+	pub stats_rate_limit_per_minute: u32,
 	#[serde(default)]
 	pub cache: ApiCacheConfig,
 }
@@ -347,11 +414,18 @@ pub struct ApiCacheConfig {
 	pub oauth_state_capacity: u64,
 	#[serde(default = "default_oauth_state_ttl")]
 	pub oauth_state_ttl_secs: u64,
+	#[serde(default = "default_stats_cache_capacity")]
+	pub stats_cache_capacity: u64,
+	#[serde(default = "default_stats_cache_ttl")]
+	pub stats_cache_ttl_secs: u64,
 }
 
 fn default_rate_limit() -> u32 {
 	10
 }
+fn default_stats_rate_limit() -> u32 {
+	60
+}
 fn default_user_cache_capacity() -> u64 {
 	10_000
 }
@@ -370,6 +444,12 @@ fn default_oauth_state_capacity() -> u64 {
 fn default_oauth_state_ttl() -> u64 {
 	600
 }
+fn default_stats_cache_capacity() -> u64 {
+	256
+}
+fn default_stats_cache_ttl() -> u64 {
+	300
+}
 
 impl Default for ApiCacheConfig {
 	fn default() -> Self {
@@ -380,6 +460,8 @@ impl Default for ApiCacheConfig {
 			auth_code_ttl_secs: default_auth_code_ttl(),
 			oauth_state_capacity: default_oauth_state_capacity(),
 			oauth_state_ttl_secs: default_oauth_state_ttl(),
+			stats_cache_capacity: default_stats_cache_capacity(),
+			stats_cache_ttl_secs: default_stats_cache_ttl(),
 		}
 	}
 }
@@ -393,6 +475,18 @@ pub struct OAuthConfig {
 	pub jwt_secret: String, // New field for JWT secret
 }
 
+#[derive(Debug, Deserialize, Clone)]
+pub struct AnilistOAuthConfig {
+	pub client_id: String,
+	pub client_secret: String,
+	pub redirect_uri: String,
+	/// Public address of the api server, used to build the login links the
+	/// bot hands out.
+	pub public_url: String,
+	/// Base64 encoded 32 byte key the AniList tokens are encrypted with.
+	pub token_key: String,
+}
+
 impl Config {
 	pub fn new() -> Result<Self> {
 		let config = std::fs::read_to_string("config.toml")?;
@@ -414,6 +508,8 @@ pub struct WorkerConfig {
 	pub db: DbConfig,
 	pub task_intervals: TaskIntervalConfig,
 	pub cache: CacheConfig,
+	#[serde(default)]
+	pub message_tracking: MessageTrackingConfig,
 	pub sentry_url: Option<String>,
 }
 
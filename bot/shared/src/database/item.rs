@@ -16,14 +16,25 @@ pub struct Model {
 	#[sea_orm(column_type = "Float")]
 	pub base_xp_boost: f32,
 	pub weight: i32,
+	#[sea_orm(column_type = "Text", nullable)]
+	pub properties: Option<String>,
+	pub retired_at: Option<DateTime>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+	#[sea_orm(has_many = "super::trade_item::Entity")]
+	TradeItem,
 	#[sea_orm(has_many = "super::user_inventory::Entity")]
 	UserInventory,
 }
 
+impl Related<super::trade_item::Entity> for Entity {
+	fn to() -> RelationDef {
+		Relation::TradeItem.def()
+	}
+}
+
 impl Related<super::user_inventory::Entity> for Entity {
 	fn to() -> RelationDef {
 		Relation::UserInventory.def()
@@ -2,17 +2,38 @@
 
 pub mod prelude;
 
+pub mod achievement_progress;
 pub mod activity_data;
+pub mod airing_dm_setting;
+pub mod airing_episode;
+pub mod airing_subscription;
+pub mod anilist_token;
+pub mod anilist_user_stats;
 pub mod anime_song;
+pub mod anisong_quiz;
+pub mod anisong_quiz_player;
+pub mod character_pool;
 pub mod command_list;
 pub mod command_usage;
+pub mod daily_streak;
+pub mod fishing_state;
+pub mod gacha_state;
+pub mod guess_round;
+pub mod guild_anilist_setting;
 pub mod guild_data;
 pub mod guild_lang;
+pub mod guild_level_config;
+pub mod guild_message_tracking;
 pub mod guild_subscription;
+pub mod guild_timezone;
 pub mod item;
+pub mod item_catalog_sync;
 pub mod kill_switch;
 pub mod leader_board;
+pub mod level_reward;
 pub mod message;
+pub mod message_daily_stats;
+pub mod minigame_score;
 pub mod module_activation;
 pub mod oauth_token;
 pub mod ping_history;
@@ -20,8 +41,19 @@ pub mod random_stats;
 pub mod registered_user;
 pub mod server_image;
 pub mod server_user_relation;
+pub mod trade;
+pub mod trade_card;
+pub mod trade_item;
+pub mod user_achievement;
+pub mod user_character;
 pub mod user_color;
 pub mod user_data;
 pub mod user_inventory;
+pub mod user_level;
+pub mod user_message_tracking;
 pub mod user_subscription;
 pub mod vocal;
+pub mod vocal_daily_stats;
+pub mod voice_session;
+pub mod wallet;
+pub mod wallet_ledger;
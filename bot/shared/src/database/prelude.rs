@@ -1,16 +1,37 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 2.0
 
+pub use super::achievement_progress::Entity as AchievementProgress;
 pub use super::activity_data::Entity as ActivityData;
+pub use super::airing_dm_setting::Entity as AiringDmSetting;
+pub use super::airing_episode::Entity as AiringEpisode;
+pub use super::airing_subscription::Entity as AiringSubscription;
+pub use super::anilist_token::Entity as AnilistToken;
+pub use super::anilist_user_stats::Entity as AnilistUserStats;
 pub use super::anime_song::Entity as AnimeSong;
+pub use super::anisong_quiz::Entity as AnisongQuiz;
+pub use super::anisong_quiz_player::Entity as AnisongQuizPlayer;
+pub use super::character_pool::Entity as CharacterPool;
 pub use super::command_list::Entity as CommandList;
 pub use super::command_usage::Entity as CommandUsage;
+pub use super::daily_streak::Entity as DailyStreak;
+pub use super::fishing_state::Entity as FishingState;
+pub use super::gacha_state::Entity as GachaState;
+pub use super::guess_round::Entity as GuessRound;
+pub use super::guild_anilist_setting::Entity as GuildAnilistSetting;
 pub use super::guild_data::Entity as GuildData;
 pub use super::guild_lang::Entity as GuildLang;
+pub use super::guild_level_config::Entity as GuildLevelConfig;
+pub use super::guild_message_tracking::Entity as GuildMessageTracking;
 pub use super::guild_subscription::Entity as GuildSubscription;
+pub use super::guild_timezone::Entity as GuildTimezone;
 pub use super::item::Entity as Item;
+pub use super::item_catalog_sync::Entity as ItemCatalogSync;
 pub use super::kill_switch::Entity as KillSwitch;
 pub use super::leader_board::Entity as LeaderBoard;
+pub use super::level_reward::Entity as LevelReward;
 pub use super::message::Entity as Message;
+pub use super::message_daily_stats::Entity as MessageDailyStats;
+pub use super::minigame_score::Entity as MinigameScore;
 pub use super::module_activation::Entity as ModuleActivation;
 pub use super::oauth_token::Entity as OAuthToken;
 pub use super::ping_history::Entity as PingHistory;
@@ -18,8 +39,19 @@ pub use super::random_stats::Entity as RandomStats;
 pub use super::registered_user::Entity as RegisteredUser;
 pub use super::server_image::Entity as ServerImage;
 pub use super::server_user_relation::Entity as ServerUserRelation;
+pub use super::trade::Entity as Trade;
+pub use super::trade_card::Entity as TradeCard;
+pub use super::trade_item::Entity as TradeItem;
+pub use super::user_achievement::Entity as UserAchievement;
+pub use super::user_character::Entity as UserCharacter;
 pub use super::user_color::Entity as UserColor;
 pub use super::user_data::Entity as UserData;
 pub use super::user_inventory::Entity as UserInventory;
+pub use super::user_level::Entity as UserLevel;
+pub use super::user_message_tracking::Entity as UserMessageTracking;
 pub use super::user_subscription::Entity as UserSubscription;
 pub use super::vocal::Entity as Vocal;
+pub use super::vocal_daily_stats::Entity as VocalDailyStats;
+pub use super::voice_session::Entity as VoiceSession;
+pub use super::wallet::Entity as Wallet;
+pub use super::wallet_ledger::Entity as WalletLedger;
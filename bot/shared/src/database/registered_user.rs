@@ -10,6 +10,7 @@ pub struct Model {
 	#[sea_orm(unique)]
 	pub anilist_id: i32,
 	pub registered_at: DateTime,
+	pub verified: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
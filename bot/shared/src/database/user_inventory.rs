@@ -17,6 +17,7 @@ pub struct Model {
 	pub item_xp_boost: f32,
 	#[sea_orm(primary_key, auto_increment = false)]
 	pub id: String,
+	pub durability: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
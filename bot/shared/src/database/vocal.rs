@@ -12,6 +12,12 @@ pub struct Model {
 	pub end: DateTime,
 	pub duration: i32,
 	pub channel_id: String,
+	pub guild_id: Option<String>,
+	pub muted_secs: i32,
+	pub deafened_secs: i32,
+	pub afk_secs: i32,
+	pub idle_secs: i32,
+	pub recorded_at: DateTime,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
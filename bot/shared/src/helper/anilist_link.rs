@@ -0,0 +1,12 @@
+use crate::database::prelude::GuildAnilistSetting;
+use sea_orm::{DatabaseConnection, EntityTrait};
+
+/// Whether the guild only lists AniList accounts linked through the AniList
+/// login. Off until an admin turns it on.
+pub async fn verified_links_only(guild_id: String, db_connection: &DatabaseConnection) -> bool {
+	GuildAnilistSetting::find_by_id(guild_id)
+		.one(db_connection)
+		.await
+		.unwrap_or(None)
+		.is_some_and(|row| row.verified_only)
+}
@@ -0,0 +1,26 @@
+use crate::database::prelude::GuildTimezone;
+use chrono_tz::Tz;
+use sea_orm::{DatabaseConnection, EntityTrait};
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::warn;
+
+/// IANA timezone set with `admin general timezone`, UTC when unset.
+pub async fn get_guild_timezone(guild_id: String, db_connection: Arc<DatabaseConnection>) -> Tz {
+	if guild_id == *"0" {
+		return Tz::UTC;
+	};
+
+	let guild_timezone = GuildTimezone::find_by_id(guild_id.clone())
+		.one(&*db_connection)
+		.await
+		.unwrap_or(None);
+
+	match guild_timezone {
+		Some(row) => Tz::from_str(&row.timezone).unwrap_or_else(|_| {
+			warn!(guild_id, timezone = %row.timezone, "Stored guild timezone is invalid");
+			Tz::UTC
+		}),
+		None => Tz::UTC,
+	}
+}
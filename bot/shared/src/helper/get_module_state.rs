@@ -0,0 +1,56 @@
+use crate::database::module_activation::{Column, Model};
+use crate::database::prelude::ModuleActivation;
+use sea_orm::EntityTrait;
+use sea_orm::QueryFilter;
+use sea_orm::{ColumnTrait, DatabaseConnection};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuildModule {
+	Ai,
+	Anilist,
+	Game,
+	Anime,
+	Vn,
+	Level,
+	MiniGame,
+}
+
+impl GuildModule {
+	/// State used when the guild has never touched `admin general module`.
+	/// Mirrors the row the module command creates on first use.
+	fn default_state(self) -> bool {
+		!matches!(self, GuildModule::Level)
+	}
+
+	fn state(self, row: &Model) -> bool {
+		match self {
+			GuildModule::Ai => row.ai_module,
+			GuildModule::Anilist => row.anilist_module,
+			GuildModule::Game => row.game_module,
+			GuildModule::Anime => row.anime_module,
+			GuildModule::Vn => row.vn_module,
+			GuildModule::Level => row.level_module,
+			GuildModule::MiniGame => row.mini_game_module,
+		}
+	}
+}
+
+pub async fn is_module_enabled(
+	guild_id: String, module: GuildModule, db_connection: Arc<DatabaseConnection>,
+) -> bool {
+	if guild_id == *"0" {
+		return module.default_state();
+	};
+
+	let row: Option<Model> = ModuleActivation::find()
+		.filter(Column::GuildId.eq(guild_id))
+		.one(&*db_connection)
+		.await
+		.unwrap_or(None);
+
+	match row {
+		Some(row) => module.state(&row),
+		None => module.default_state(),
+	}
+}
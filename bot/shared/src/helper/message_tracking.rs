@@ -0,0 +1,27 @@
+use crate::database::prelude::{GuildMessageTracking, UserMessageTracking};
+use sea_orm::{DatabaseConnection, EntityTrait};
+
+/// Message bodies are only kept when both the guild and the author opted in.
+/// Everything else, direct messages included, is stored as metadata only.
+pub async fn should_store_content(
+	guild_id: Option<String>, user_id: String, db_connection: &DatabaseConnection,
+) -> bool {
+	let Some(guild_id) = guild_id else {
+		return false;
+	};
+
+	let guild_opt_in = GuildMessageTracking::find_by_id(guild_id)
+		.one(db_connection)
+		.await
+		.unwrap_or(None)
+		.is_some_and(|row| row.store_content);
+	if !guild_opt_in {
+		return false;
+	}
+
+	UserMessageTracking::find_by_id(user_id)
+		.one(db_connection)
+		.await
+		.unwrap_or(None)
+		.is_some_and(|row| row.store_content)
+}
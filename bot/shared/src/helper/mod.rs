@@ -1,2 +1,6 @@
+pub mod anilist_link;
 pub mod get_guild_lang;
+pub mod get_guild_timezone;
+pub mod get_module_state;
+pub mod message_tracking;
 pub mod read_file;
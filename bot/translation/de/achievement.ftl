@@ -0,0 +1,3 @@
+achievement-unlocked = { $user } hat { $icon } **{ $name }** freigeschaltet
+    { $description }
+achievement-unlocked_title = Erfolg freigeschaltet!
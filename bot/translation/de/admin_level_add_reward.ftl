@@ -0,0 +1,2 @@
+admin_level_add_reward-desc = { $role } wird an Mitglieder vergeben, die Level { $level } erreichen.
+admin_level_add_reward-title = Levelbelohnung hinzugefügt
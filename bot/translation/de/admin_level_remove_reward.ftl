@@ -0,0 +1,3 @@
+admin_level_remove_reward-desc = { $role } ist keine Levelbelohnung mehr.
+admin_level_remove_reward-not_found = { $role } ist auf diesem Server keine Levelbelohnung.
+admin_level_remove_reward-title = Levelbelohnung entfernt
@@ -0,0 +1,16 @@
+admin_level_settings-announce = Level-Up-Ankündigungen
+admin_level_settings-channel = Ankündigungskanal
+admin_level_settings-channel_default = Kanal der Nachricht
+admin_level_settings-cooldown = Nachrichten-Abklingzeit
+admin_level_settings-cooldown_value = { $seconds }s
+admin_level_settings-curve = Levelkurve
+admin_level_settings-curve_value = { $base } × Level^{ $exponent }
+admin_level_settings-message_xp = XP pro Nachricht
+admin_level_settings-module_off = Das LEVEL-Modul ist auf diesem Server deaktiviert, es wird keine XP vergeben, bis es mit `/admin general module` aktiviert wird.
+admin_level_settings-no_rewards = Keine Belohnungsrollen konfiguriert.
+admin_level_settings-off = Deaktiviert
+admin_level_settings-on = Aktiviert
+admin_level_settings-reward = Level { $level }: { $role }
+admin_level_settings-rewards = Belohnungsrollen
+admin_level_settings-title = Level-Einstellungen
+admin_level_settings-voice_xp = XP pro Sprachminute
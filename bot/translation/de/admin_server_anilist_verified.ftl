@@ -0,0 +1,3 @@
+admin_server_anilist_verified-title = Verifizierte AniList-Konten
+admin_server_anilist_verified-on = Serverlisten zeigen jetzt nur noch mit `/verify` verifizierte AniList-Konten.
+admin_server_anilist_verified-off = Serverlisten zeigen alle registrierten AniList-Konten, verifizierte sind mit ✓ markiert.
@@ -0,0 +1,3 @@
+admin_server_message_content-off = Nachrichteninhalte werden auf diesem Server nicht mehr gespeichert. Bereits gespeicherte Inhalte wurden entfernt; nur Länge, Kanal und Zeitpunkt der Nachrichten bleiben erhalten.
+admin_server_message_content-on = Nachrichteninhalte werden für Mitglieder gespeichert, die mit `/user message_content` zugestimmt haben.
+admin_server_message_content-title = Nachrichteninhalt
@@ -0,0 +1,2 @@
+admin_server_timezone-desc = Die Zeitzone des Servers ist jetzt: { $timezone }
+admin_server_timezone-title = Zeitzone
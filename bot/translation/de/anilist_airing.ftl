@@ -0,0 +1,24 @@
+anilist_airing-dm_desc = Folge { $episode } von { $anime } wurde gerade ausgestrahlt.
+anilist_airing-unfollow_button = Entfolgen
+anilist_airing-snooze_button = { $hours } Std. pausieren
+anilist_airing-mute_button = Alle stummschalten
+anilist_airing-following = Du erhältst eine DM, wenn Folge { $episode } { $time } ausgestrahlt wird. Stelle sicher, dass der Bot dir Direktnachrichten senden kann.
+anilist_airing-not_airing = Dieser Anime hat keine kommende Folge zum Verfolgen.
+anilist_airing-unfollow_title = Ausstrahlungs-DMs
+anilist_airing-unfollowed = Du erhältst keine DMs mehr für diesen Anime.
+anilist_airing-not_following = Du folgst diesem Anime nicht.
+anilist_airing-snoozed = Die Ausstrahlungs-DMs sind pausiert, sie gehen { $time } weiter.
+anilist_airing-muted = Die Ausstrahlungs-DMs sind stummgeschaltet, aktiviere sie wieder mit `anilist notifications`.
+anilist_airing-settings_title = Ausstrahlungs-Benachrichtigungen
+anilist_airing-state = DMs
+anilist_airing-state_on = An
+anilist_airing-state_muted = Stumm
+anilist_airing-state_snoozed = Pausiert bis { $time }
+anilist_airing-auto_follow = Automatisch folgen
+anilist_airing-auto_follow_on = Schauliste verfolgt
+anilist_airing-auto_follow_off = Aus
+anilist_airing-none_followed = Du folgst keinem Anime. Nutze `anilist follow` oder aktiviere automatisches Folgen.
+anilist_airing-next = Folge { $episode } { $time }
+anilist_airing-no_next = keine Folge geplant
+anilist_airing-from_list = aus deiner Liste
+anilist_airing-more = …und { $count } weitere
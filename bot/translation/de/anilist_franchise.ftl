@@ -0,0 +1,6 @@
+anilist_franchise-title = Franchise
+anilist_franchise-title_media = Franchise { $title }, { $count } Titel
+anilist_franchise-not_found = Mit diesem Namen wurde kein Anime oder Manga gefunden.
+anilist_franchise-truncated = Das Franchise ist zu groß, nur die { $count } nächsten Titel werden gezeigt.
+anilist_franchise-cycles = Einige Beziehungen bilden Schleifen, diese Titel sind nach Erscheinungsdatum sortiert.
+anilist_franchise-more = …und { $count } weitere im Graphen
@@ -0,0 +1,19 @@
+anilist_leaderboard-title = AniList-Bestenliste: { $stat }
+anilist_leaderboard-stat_level = Level
+anilist_leaderboard-stat_minutes_watched = Geschaute Zeit
+anilist_leaderboard-stat_chapters_read = Gelesene Kapitel
+anilist_leaderboard-stat_mean_score = Durchschnittliche Bewertung
+anilist_leaderboard-stat_completed = Abgeschlossene Titel
+anilist_leaderboard-entry = { $rank }. { $user } [{ $name }](<{ $url }>) · { $value }
+anilist_leaderboard-value_level = Level { $level } ({ $xp } XP)
+anilist_leaderboard-value_minutes_watched = { $days } Tage
+anilist_leaderboard-value_chapters_read = { $value } Kapitel
+anilist_leaderboard-value_mean_score = { $value }/100
+anilist_leaderboard-value_completed = { $value } Titel
+anilist_leaderboard-empty = Noch kein Mitglied dieses Servers ist eingestuft. Registrierte Konten werden bei der nächsten Aktualisierung der Statistiken berücksichtigt, alle paar Stunden.
+anilist_leaderboard-updated = Statistiken aktualisiert { $time }.
+anilist_leaderboard-page = Seite { $page }/{ $total }
+anilist_leaderboard-position = Du bist Platz { $rank }
+anilist_leaderboard-unranked = Du bist nicht eingestuft
+anilist_leaderboard-previous = Zurück
+anilist_leaderboard-next = Weiter
@@ -0,0 +1,16 @@
+anilist_list-relink_title = AniList-Anmeldung erforderlich
+anilist_list-not_linked = Melde dich bei AniList an, um deine Liste von Discord aus zu aktualisieren. Über den Button unten erhältst du einen persönlichen Anmeldelink.
+anilist_list-expired = Deine AniList-Anmeldung ist abgelaufen oder wurde widerrufen. Melde dich über den Button unten erneut an, um deine Liste weiter zu aktualisieren.
+anilist_list-not_found_title = Nicht auf deiner Liste
+anilist_list-not_found = Nichts auf deiner aktuellen Liste passt zu „{ $media }“. Wähle einen Eintrag aus den Vorschlägen.
+anilist_list-progress = Fortschritt
+anilist_list-status = Status
+anilist_list-score = Bewertung
+anilist_list-status_current = Aktuell
+anilist_list-status_planning = Geplant
+anilist_list-status_completed = Abgeschlossen
+anilist_list-status_dropped = Abgebrochen
+anilist_list-status_paused = Pausiert
+anilist_list-status_repeating = Wiederholung
+anilist_list-episode_done = { $anime } steht auf deiner Liste bereits bei { $progress }.
+anilist_list-episode_saved = Als gesehen markiert, { $anime } steht auf deiner Liste jetzt bei { $progress }.
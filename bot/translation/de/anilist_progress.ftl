@@ -0,0 +1 @@
+anilist_progress-desc = Fortschritt von { $previous } auf { $progress } geändert.
@@ -0,0 +1,6 @@
+anilist_recommend-title = Empfehlungen
+anilist_recommend-title_user = Empfehlungen für { $user }
+anilist_recommend-no_user = Gib einen Benutzernamen an oder registriere dein AniList-Konto mit dem Befehl `register`.
+anilist_recommend-nothing = Nichts zu empfehlen. Empfehlungen kommen von abgeschlossenen Titeln mit 7/10 oder mehr, versuche es ohne Filter oder bewerte mehr Titel.
+anilist_recommend-because = Weil du { $title } mit { $score }/10 bewertet hast
+anilist_recommend-tags = {" "}und { $tags } magst
@@ -0,0 +1,12 @@
+anilist_schedule-title = Sendeplan vom { $date }
+anilist_schedule-scope_all = Alle Anime, die an diesem Tag in { $timezone } laufen.
+anilist_schedule-scope_server = Die vom Server verfolgten Anime, die an diesem Tag in { $timezone } laufen.
+anilist_schedule-scope_list = Die Anime deiner Liste, die an diesem Tag in { $timezone } laufen.
+anilist_schedule-empty = An diesem Tag läuft keine Folge.
+anilist_schedule-episode = Folge { $episode }
+anilist_schedule-episode_of = Folge { $episode }/{ $episodes }
+anilist_schedule-page = Seite { $page }/{ $total }
+anilist_schedule-no_user_title = Sendeplan
+anilist_schedule-no_user = Registriere dein AniList-Konto mit `register`, um die Folgen deiner Liste zu sehen.
+anilist_schedule-previous = Zurück
+anilist_schedule-next = Weiter
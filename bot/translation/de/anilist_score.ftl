@@ -0,0 +1,3 @@
+anilist_score-title = Ungültige Bewertung
+anilist_score-invalid = { $score } passt nicht zum Bewertungsformat deines AniList-Profils, verwende eine Bewertung zwischen 0 und { $max }.
+anilist_score-desc = Bewertung auf { $score } gesetzt.
@@ -0,0 +1,20 @@
+anilist_season-title = Anime { $season } { $year }
+anilist_season-season_winter = Winter
+anilist_season-season_spring = Frühling
+anilist_season-season_summer = Sommer
+anilist_season-season_fall = Herbst
+anilist_season-format_tv = TV
+anilist_season-format_tv_short = TV kurz
+anilist_season-format_movie = Film
+anilist_season-format_special = Special
+anilist_season-format_ova = OVA
+anilist_season-format_ona = ONA
+anilist_season-format_music = Musik
+anilist_season-filters = Filter: { $filters }
+anilist_season-empty = Kein Anime dieser Season passt zu diesen Filtern.
+anilist_season-episodes = { $count } Folgen
+anilist_season-next = Folge { $episode } { $time }
+anilist_season-upcoming = noch nicht ausgestrahlt
+anilist_season-page = Seite { $page }/{ $total } · { $count } Anime
+anilist_season-previous = Zurück
+anilist_season-next_page = Weiter
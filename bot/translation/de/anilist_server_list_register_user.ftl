@@ -1,3 +1,5 @@
 anilist_server_list_register_user-next = Nächste Seite
 anilist_server_list_register_user-previous = Vorherige Seite
 anilist_server_list_register_user-title = Liste der registrierten Benutzer.
+anilist_server_list_register_user-empty = Kein Mitglied dieses Servers hat ein AniList-Konto registriert.
+anilist_server_list_register_user-page = Seite { $page }/{ $total }
@@ -32,3 +32,27 @@ anilist_user_compare-tag_anime =
     { $var1 } bevorzugt das Tag { $var1a }, und { $var2 } bevorzugt das Tag { $var2a } für Anime
 anilist_user_compare-tag_manga =
     { $var1 } bevorzugt das Tag { $var1a }, und { $var2 } bevorzugt das Tag { $var2a } für Manga
+anilist_user_compare-correlation =
+    Korrelation der Bewertungen gemeinsamer Titel: { $pearson } (Pearson), { $spearman } (Spearman)
+anilist_user_compare-disagreements =
+    Größte Meinungsverschiedenheiten
+anilist_user_compare-disagreements_button =
+    Meinungsverschiedenheiten
+anilist_user_compare-for_first =
+    { $var2 } liebt es, { $var1 } hat es noch nicht gesehen
+anilist_user_compare-for_second =
+    { $var1 } liebt es, { $var2 } hat es noch nicht gesehen
+anilist_user_compare-list_title =
+    Listenvergleich
+anilist_user_compare-next =
+    Weiter
+anilist_user_compare-no_correlation =
+    Zu wenige von beiden bewertete Titel, um eine Korrelation zu berechnen
+anilist_user_compare-nothing =
+    Hier gibt es nichts anzuzeigen.
+anilist_user_compare-page =
+    Seite { $page } von { $total }
+anilist_user_compare-previous =
+    Zurück
+anilist_user_compare-shared =
+    { $var1 } und { $var2 } haben beide { $shared } Titel gesehen oder gelesen, { $scored } davon von beiden bewertet
@@ -1 +1,4 @@
 anilist_user_register-desc = Der Benutzer { $user } (mit der ID { $id }) wurde mit dem Anilist-Konto { $anilist } verknüpft
+anilist_user_register-unverified = Diese Verknüpfung ist nicht verifiziert, nutze `/verify`, um zu beweisen, dass das Konto dir gehört.
+anilist_user_register-taken = Ein anderes Mitglied hat dieses AniList-Konto bereits registriert. Wenn es dir gehört, nutze `/verify`, um es zu übernehmen.
+anilist_user_register-taken_verified = Dieses AniList-Konto wurde von einem anderen Mitglied verifiziert. Wenn es dir gehört, nutze `/verify`, um es zu übernehmen.
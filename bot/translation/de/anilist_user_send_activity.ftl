@@ -1,2 +1,3 @@
 anilist_user_send_activity-desc = Folge { $ep } von { $anime } wurde gerade veröffentlicht.
 anilist_user_send_activity-title = Neue Folge
+anilist_user_send_activity-episode_button = Folge +1
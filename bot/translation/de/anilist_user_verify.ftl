@@ -0,0 +1,7 @@
+anilist_user_verify-title = AniList-Konto verifizieren
+anilist_user_verify-desc = Jeder kann einen AniList-Benutzernamen registrieren. Melde dich bei AniList an, um zu beweisen, dass das Konto dir gehört; eine verifizierte Verknüpfung übernimmt das Konto von allen, die es zuvor registriert haben, und kann von Servern verlangt werden.
+anilist_user_verify-already = Dein Discord-Konto ist bereits mit [diesem AniList-Konto]({ $url }) verifiziert. Melde dich erneut an, um ein anderes zu verknüpfen.
+anilist_user_verify-button = Meinen Anmeldelink erhalten
+anilist_user_verify-link = Hier ist dein persönlicher Anmeldelink, er läuft in 15 Minuten ab. Du meldest dich zuerst mit Discord und dann bei AniList an.
+anilist_user_verify-open = Bei AniList anmelden
+anilist_user_verify-disabled = Die verifizierte AniList-Verknüpfung ist bei diesem Bot nicht aktiviert.
@@ -35,6 +35,9 @@ parent-levels-desc = Befehl zum Abrufen der Benutzerebene und der Statistik.
 parent-minigame-name = minispiel
 parent-minigame-desc = Befehle zum Spielen von Minispielen und zur Verwaltung Ihres Inventars.
 
+parent-anilist-name = anilist
+parent-anilist-desc = Befehle rund um deine AniList-Liste.
+
 parent-admin-name = admin
 parent-admin-desc = Bot-Konfiguration nur für Administratoren.
 
@@ -46,6 +49,9 @@ group-admin-anilist-desc = Befehle für das AniList-Modul, die Administratorbere
 group-admin-general-name = general
 group-admin-general-desc = Befehle für das allgemeine Modul, die Administratorberechtigungen erfordern.
 
+group-admin-level-name = level
+group-admin-level-desc = Admin-Befehle für das LEVEL-Modul.
+
 # ─── Commands ─────────────────────────────────────────────────────────────────
 
 # admin/anilist
@@ -59,9 +65,28 @@ cmd-delete_anime_activity-desc = Löschen Sie eine Anime-Aktivität.
 cmd-lang-name = lang
 cmd-lang-desc = Die Sprache, die Sie für die Antwort festlegen möchten.
 
+cmd-admin_message_content-name = nachrichteninhalt
+cmd-admin_message_content-desc = Speichern von Nachrichteninhalten zustimmender Mitglieder erlauben.
+
+cmd-admin_anilist_verified-name = anilist_verifiziert
+cmd-admin_anilist_verified-desc = Nur über die AniList-Anmeldung verifizierte AniList-Konten auflisten.
+
+cmd-admin_timezone-name = zeitzone
+cmd-admin_timezone-desc = Die Zeitzone des Servers festlegen.
+
 cmd-module-name = modul
 cmd-module-desc = Schalten Sie ein Modul ein oder aus.
 
+# admin/level
+cmd-admin_settings-name = einstellungen
+cmd-admin_settings-desc = Level-Einstellungen anzeigen oder ändern.
+
+cmd-admin_add_reward-name = belohnung_hinzufuegen
+cmd-admin_add_reward-desc = Mitgliedern beim Erreichen eines Levels eine Rolle geben.
+
+cmd-admin_remove_reward-name = belohnung_entfernen
+cmd-admin_remove_reward-desc = Eine Rolle nicht mehr als Level-Belohnung vergeben.
+
 # ai
 cmd-image-name = bild
 cmd-image-desc = Ein Bild generieren.
@@ -75,6 +100,37 @@ cmd-transcript-desc = Erstellt ein Transkript aus einem Video.
 cmd-translation-name = ubersetzung
 cmd-translation-desc = Erzeugen Sie eine Übersetzung.
 
+# anilist
+cmd-anilist_follow-name = folgen
+cmd-anilist_follow-desc = Erhalte eine DM, wenn eine Folge eines Anime ausgestrahlt wird.
+
+cmd-anilist_franchise-name = franchise
+cmd-anilist_franchise-desc = Erhalte die Reihenfolge und den Beziehungsgraphen eines Franchise.
+
+cmd-anilist_notifications-name = benachrichtigungen
+cmd-anilist_notifications-desc = Richte deine Ausstrahlungs-DMs ein und sieh die verfolgten Anime.
+
+cmd-anilist_progress-name = fortschritt
+cmd-anilist_progress-desc = Füge gesehene Folgen oder gelesene Kapitel zu deiner AniList-Liste hinzu.
+
+cmd-anilist_recommend-name = empfehlen
+cmd-anilist_recommend-desc = Anime oder Manga, ausgewählt nach den am besten bewerteten Titeln einer Liste.
+
+cmd-anilist_schedule-name = zeitplan
+cmd-anilist_schedule-desc = Zeigt die Anime-Folgen, die an einem Tag ausgestrahlt werden.
+
+cmd-anilist_score-name = bewertung
+cmd-anilist_score-desc = Bewerte einen Anime oder Manga auf deiner AniList-Liste.
+
+cmd-anilist_season-name = saison
+cmd-anilist_season-desc = Listet die Anime einer Season auf.
+
+cmd-anilist_status-name = status
+cmd-anilist_status-desc = Ändere den Status eines Animes oder Mangas auf deiner AniList-Liste.
+
+cmd-anilist_unfollow-name = entfolgen
+cmd-anilist_unfollow-desc = Beende die Ausstrahlungs-DMs eines Anime.
+
 # anilist_server
 cmd-list_activity-name = liste_aktivitaten
 cmd-list_activity-desc = Die Liste der registrierten Aktivitäten abrufen.
@@ -82,6 +138,9 @@ cmd-list_activity-desc = Die Liste der registrierten Aktivitäten abrufen.
 cmd-list_user-name = liste_benutzer
 cmd-list_user-desc = Die Liste der registrierten Benutzer abrufen.
 
+cmd-anilist_leaderboard-name = anilist_bestenliste
+cmd-anilist_leaderboard-desc = Die Mitglieder des Servers nach ihren AniList-Statistiken ordnen.
+
 # anilist_user
 cmd-anime-name = anime
 cmd-anime-desc = Informationen zu einem Anime.
@@ -107,6 +166,9 @@ cmd-random-desc = Einen zufälligen Anime oder Manga erhalten.
 cmd-register-name = registrieren
 cmd-register-desc = Registrieren Sie Ihren Benutzernamen auf AniList.
 
+cmd-verify-name = verifizieren
+cmd-verify-desc = Beweise per AniList-Anmeldung, dass dir dein AniList-Konto gehört.
+
 cmd-seiyuu-name = seiyuu
 cmd-seiyuu-desc = Informationen zu einem Seiyuu.
 
@@ -144,6 +206,15 @@ cmd-ping-desc = Holen Sie sich den Ping des Bots (und die Shard-ID).
 cmd-stats-name = statistiken
 cmd-stats-desc = Statistiken zum Level erhalten.
 
+cmd-levels_rank-name = rang
+cmd-levels_rank-desc = Die Rangkarte eines Mitglieds anzeigen.
+
+cmd-levels_leaderboard-name = rangliste
+cmd-levels_leaderboard-desc = Die aktivsten Mitglieder des Servers anzeigen.
+
+cmd-levels_heatmap-name = heatmap
+cmd-levels_heatmap-desc = Zeigen, wann ein Mitglied oder der Server aktiv ist.
+
 # management
 cmd-give_premium_sub-name = give_premium_sub
 cmd-give_premium_sub-desc = Einem Benutzer ein Premium-Abonnement geben.
@@ -151,19 +222,58 @@ cmd-give_premium_sub-desc = Einem Benutzer ein Premium-Abonnement geben.
 cmd-kill_switch-name = kill_switch
 cmd-kill_switch-desc = Ein Modul global ein- oder ausschalten
 
+cmd-reload_items-name = reload_items
+cmd-reload_items-desc = Den Gegenstandskatalog prüfen und synchronisieren.
+
 cmd-remove_test_sub-name = remove_test_sub
 cmd-remove_test_sub-desc = Premium-Abonnements eines Benutzers entfernen.
 
 # minigame
+cmd-minigame_balance-name = kontostand
+cmd-minigame_balance-desc = Zeige deine Münzen und letzten Transaktionen.
+
+cmd-minigame_collection-name = sammlung
+cmd-minigame_collection-desc = Zeigt die Charaktere, die ein Mitglied gezogen hat.
+
+cmd-minigame_daily-name = taeglich
+cmd-minigame_daily-desc = Hole deine tägliche Belohnung ab und halte deine Serie am Laufen.
+
+cmd-minigame_equip-name = ausruesten
+cmd-minigame_equip-desc = Lege eine Angel oder einen Köder an.
+
 cmd-fish_inventory-name = fisch_inventar
 cmd-fish_inventory-desc = Überprüfen Sie Ihr Fischinventar.
 
 cmd-fishing-name = angeln
 cmd-fishing-desc = Geh angeln!
 
+cmd-minigame_gift-name = geschenk
+cmd-minigame_gift-desc = Gib einem anderen Mitglied Gegenstände oder Münzen.
+
+cmd-minigame_guess_anime-name = anime_raten
+cmd-minigame_guess_anime-desc = Starte eine Runde „Errate den Anime“ anhand des Covers.
+
+cmd-minigame_anisong_quiz-name = anisong_quiz
+cmd-minigame_anisong_quiz-desc = Starte ein Quiz, in dem der Anime zu Songs in deinem Sprachkanal erraten wird.
+
 cmd-inventory-name = inventar
 cmd-inventory-desc = Überprüfen Sie Ihr Inventar.
 
+cmd-minigame_leaderboard-name = bestenliste
+cmd-minigame_leaderboard-desc = Zeige die besten Minispiel-Spieler.
+
+cmd-minigame_roll-name = ziehen
+cmd-minigame_roll-desc = Ziehe einen zufälligen AniList-Charakter für deine Sammlung.
+
+cmd-minigame_sell-name = verkaufen
+cmd-minigame_sell-desc = Verkaufe Gegenstände aus deinem Inventar.
+
+cmd-minigame_shop-name = laden
+cmd-minigame_shop-desc = Durchstöbere den Laden oder kaufe einen Gegenstand.
+
+cmd-minigame_trade-name = tausch
+cmd-minigame_trade-desc = Tausche Gegenstände und Münzen mit einem anderen Mitglied.
+
 # music
 cmd-clear-name = leeren
 cmd-clear-desc = Die aktuelle Warteschlange leeren.
@@ -225,6 +335,9 @@ cmd-banner-desc = Holen Sie das Banner.
 cmd-command_usage-name = befehlsverwendung
 cmd-command_usage-desc = Zeigen Sie die Verwendung jedes Befehls für einen Benutzer an.
 
+cmd-user_message_content-name = nachrichteninhalt
+cmd-user_message_content-desc = Wähle, ob der Inhalt deiner Nachrichten gespeichert werden darf.
+
 cmd-profile-name = profil
 cmd-profile-desc = Das Profil eines Benutzers anzeigen.
 
@@ -263,6 +376,46 @@ arg-module-name-desc = Das Modul, dessen Zustand Sie ändern möchten.
 arg-module-state-name = status
 arg-module-state-desc = Der Zustand den Sie anwenden möchten.
 
+# admin/general/message_content
+arg-admin_message_content-state-name = status
+arg-admin_message_content-state-desc = Ob Nachrichteninhalte gespeichert werden dürfen.
+
+# admin/general/anilist_verified
+arg-admin_anilist_verified-state-name = status
+arg-admin_anilist_verified-state-desc = Ob nur verifizierte Konten aufgelistet werden.
+
+# admin/general/timezone
+arg-admin_timezone-timezone-name = zeitzone
+arg-admin_timezone-timezone-desc = Die Zeitzone, zum Beispiel Europe/Berlin.
+
+# admin/level/settings
+arg-admin_settings-message_xp-name = nachrichten_xp
+arg-admin_settings-message_xp-desc = XP pro Nachricht (0-1000).
+arg-admin_settings-voice_xp-name = sprach_xp
+arg-admin_settings-voice_xp-desc = XP pro Minute im Sprachkanal (0-1000).
+arg-admin_settings-cooldown-name = abklingzeit
+arg-admin_settings-cooldown-desc = Sekunden zwischen zwei Nachrichten mit XP (0-3600).
+arg-admin_settings-curve_base-name = kurvenbasis
+arg-admin_settings-curve_base-desc = Benötigte XP für Level 1 (1-100000).
+arg-admin_settings-curve_exponent-name = kurvenexponent
+arg-admin_settings-curve_exponent-desc = Wie schnell Level schwerer werden (1.0-3.0).
+arg-admin_settings-announce-name = ankuendigen
+arg-admin_settings-announce-desc = Level-Aufstiege ankündigen.
+arg-admin_settings-channel-name = kanal
+arg-admin_settings-channel-desc = Kanal für Level-Ankündigungen.
+arg-admin_settings-reset_channel-name = kanal_zuruecksetzen
+arg-admin_settings-reset_channel-desc = Im Kanal der Nachricht ankündigen.
+
+# admin/level/add_reward
+arg-admin_add_reward-level-name = level
+arg-admin_add_reward-level-desc = Das Level, das die Rolle freischaltet.
+arg-admin_add_reward-role-name = rolle
+arg-admin_add_reward-role-desc = Die zu vergebende Rolle.
+
+# admin/level/remove_reward
+arg-admin_remove_reward-role-name = rolle
+arg-admin_remove_reward-role-desc = Die Rolle, die aus den Belohnungen entfernt wird.
+
 # ai/image
 arg-image-description-name = beschreibung
 arg-image-description-desc = Geben Sie eine Beschreibung des Bildes ein, das Sie generieren möchten.
@@ -287,6 +440,80 @@ arg-translation-video-desc = Video-Datei hochladen (max. 25MB).
 arg-translation-lang-name = lang
 arg-translation-lang-desc = Eingabesprache auswählen (ISO-639-1)
 
+# anilist/follow
+arg-anilist_follow-anime-name = anime
+arg-anilist_follow-anime-desc = Der Anime, dem du folgen willst.
+
+# anilist/franchise
+arg-anilist_franchise-media-name = medium
+arg-anilist_franchise-media-desc = Ein Anime oder Manga des Franchise.
+
+# anilist/notifications
+arg-anilist_notifications-auto_follow-name = auto_folgen
+arg-anilist_notifications-auto_follow-desc = Folge den laufenden Anime deiner AniList-Schauliste.
+arg-anilist_notifications-mute-name = stumm
+arg-anilist_notifications-mute-desc = Stoppt alle Ausstrahlungs-DMs, bis du sie wieder aktivierst.
+arg-anilist_notifications-snooze-name = pausieren
+arg-anilist_notifications-snooze-desc = Stunden ohne Ausstrahlungs-DMs, 0 beendet die Pause.
+
+# anilist/progress
+arg-anilist_progress-media-name = medium
+arg-anilist_progress-media-desc = Ein Anime oder Manga von deiner aktuellen Liste.
+arg-anilist_progress-amount-name = anzahl
+arg-anilist_progress-amount-desc = Wie viele Folgen oder Kapitel hinzugefügt werden, standardmäßig 1. Negativ, um zurückzugehen.
+
+# anilist/recommend
+arg-anilist_recommend-username-name = benutzername
+arg-anilist_recommend-username-desc = Benutzername, du selbst wenn leer.
+arg-anilist_recommend-type-name = typ
+arg-anilist_recommend-type-desc = Anime oder Manga, Anime wenn leer.
+arg-anilist_recommend-genre-name = genre
+arg-anilist_recommend-genre-desc = Nur Titel dieses Genres.
+arg-anilist_recommend-format-name = format
+arg-anilist_recommend-format-desc = Nur Titel dieses Formats.
+arg-anilist_recommend-year-name = jahr
+arg-anilist_recommend-year-desc = Nur Titel aus diesem Jahr.
+
+# anilist/schedule
+arg-anilist_schedule-day-name = tag
+arg-anilist_schedule-day-desc = Der Tag, heute wenn leer.
+arg-anilist_schedule-scope-name = umfang
+arg-anilist_schedule-scope-desc = Alle Anime, die vom Server verfolgten oder die auf deiner Liste.
+
+# anilist/score
+arg-anilist_score-media-name = medium
+arg-anilist_score-media-desc = Ein Anime oder Manga von deiner aktuellen Liste.
+arg-anilist_score-score-name = bewertung
+arg-anilist_score-score-desc = Die Bewertung im Format deines AniList-Profils.
+
+# anilist/season
+arg-anilist_season-year-name = jahr
+arg-anilist_season-year-desc = Jahr der Season, das aktuelle wenn leer.
+arg-anilist_season-season-name = saison
+arg-anilist_season-season-desc = Die Season, die aktuelle wenn leer.
+arg-anilist_season-format-name = format
+arg-anilist_season-format-desc = Nur Anime in diesem Format.
+arg-anilist_season-genre-name = genre
+arg-anilist_season-genre-desc = Nur Anime dieses Genres.
+arg-anilist_season-studio-name = studio
+arg-anilist_season-studio-desc = Nur Anime von diesem Studio.
+
+# anilist/status
+arg-anilist_status-media-name = medium
+arg-anilist_status-media-desc = Ein Anime oder Manga von deiner aktuellen Liste.
+arg-anilist_status-status-name = status
+arg-anilist_status-status-desc = Der neue Status.
+
+# anilist/unfollow
+arg-anilist_unfollow-anime-name = anime
+arg-anilist_unfollow-anime-desc = Ein Anime, dem du folgst.
+
+# anilist_server/anilist_leaderboard
+arg-anilist_leaderboard-stat-name = statistik
+arg-anilist_leaderboard-stat-desc = Wonach geordnet wird, standardmäßig nach dem Level.
+arg-anilist_leaderboard-page-name = seite
+arg-anilist_leaderboard-page-desc = Die Startseite.
+
 # anilist_user/anime
 arg-anime-anime_name-name = anime_name
 arg-anime-anime_name-desc = Name des Animes, den Sie überprüfen möchten.
@@ -345,6 +572,22 @@ arg-random_image-image_type-desc = Art des Bildes, das Sie möchten.
 arg-random_himage-image_type-name = bildtyp
 arg-random_himage-image_type-desc = Art des Bildes, das Sie möchten.
 
+# levels/rank
+arg-levels_rank-username-name = benutzername
+arg-levels_rank-username-desc = Das Mitglied, dessen Rangkarte angezeigt wird.
+
+# levels/leaderboard
+arg-levels_leaderboard-page-name = seite
+arg-levels_leaderboard-page-desc = Die Startseite.
+
+# levels/heatmap
+arg-levels_heatmap-username-name = benutzer
+arg-levels_heatmap-username-desc = Das Mitglied, dessen Aktivität angezeigt wird.
+arg-levels_heatmap-server-name = server
+arg-levels_heatmap-server-desc = Stattdessen die Aktivität des ganzen Servers anzeigen.
+arg-levels_heatmap-period-name = zeitraum
+arg-levels_heatmap-period-desc = Wie weit zurückgeschaut wird.
+
 # management/give_premium_sub
 arg-give_premium_sub-user-name = benutzer
 arg-give_premium_sub-user-desc = Der Benutzer, dem das Abonnement gegeben werden soll.
@@ -361,6 +604,56 @@ arg-kill_switch-state-desc = Der Zustand den Sie anwenden möchten.
 arg-remove_test_sub-user-name = benutzer
 arg-remove_test_sub-user-desc = Der Benutzer, dem das Abonnement entzogen werden soll.
 
+# minigame/collection
+arg-minigame_collection-user-name = benutzer
+arg-minigame_collection-user-desc = Das Mitglied, dessen Sammlung angezeigt wird, standardmäßig du.
+arg-minigame_collection-page-name = seite
+arg-minigame_collection-page-desc = Die Startseite.
+
+# minigame/equip
+arg-minigame_equip-gear-name = ausruestung
+arg-minigame_equip-gear-desc = Die Angel oder der Köder zum Anlegen.
+
+# minigame/gift
+arg-minigame_gift-user-name = benutzer
+arg-minigame_gift-user-desc = Das Mitglied, das beschenkt wird.
+arg-minigame_gift-item-name = gegenstand
+arg-minigame_gift-item-desc = Der zu verschenkende Gegenstand.
+arg-minigame_gift-coins-name = muenzen
+arg-minigame_gift-coins-desc = Wie viele Münzen verschenkt werden sollen.
+
+# minigame/guess_anime
+arg-minigame_guess_anime-difficulty-name = schwierigkeit
+arg-minigame_guess_anime-difficulty-desc = Wie bekannt der Anime ist, standardmäßig normal.
+
+# minigame/anisong_quiz
+arg-minigame_anisong_quiz-rounds-name = runden
+arg-minigame_anisong_quiz-rounds-desc = Anzahl der Songs, standardmäßig 5 und höchstens 10.
+
+# minigame/leaderboard
+arg-minigame_leaderboard-game-name = spiel
+arg-minigame_leaderboard-game-desc = Das zu wertende Minispiel, standardmäßig alle.
+arg-minigame_leaderboard-scope-name = bereich
+arg-minigame_leaderboard-scope-desc = Dieser Server oder alle Server.
+arg-minigame_leaderboard-window-name = zeitraum
+arg-minigame_leaderboard-window-desc = Diese Woche oder insgesamt.
+arg-minigame_leaderboard-page-name = seite
+arg-minigame_leaderboard-page-desc = Die Startseite.
+
+# minigame/sell
+arg-minigame_sell-item-name = gegenstand
+arg-minigame_sell-item-desc = Der zu verkaufende Gegenstand.
+
+# minigame/shop
+arg-minigame_shop-buy-name = kaufen
+arg-minigame_shop-buy-desc = Der zu kaufende Gegenstand.
+arg-minigame_shop-quantity-name = menge
+arg-minigame_shop-quantity-desc = Wie viele gekauft werden sollen (1-10).
+
+# minigame/trade
+arg-minigame_trade-user-name = benutzer
+arg-minigame_trade-user-desc = Das Mitglied, mit dem getauscht wird.
+
 # music/play
 arg-play-search-name = suche
 arg-play-search-desc = Nach einem Lied suchen.
@@ -395,6 +688,10 @@ arg-banner-username-desc = Benutzername des Benutzers, von dem Sie den Avatar m
 arg-command_usage-username-name = benutzername
 arg-command_usage-username-desc = Benutzername des Benutzers, dessen Verwendung Sie sehen möchten.
 
+# user/message_content
+arg-user_message_content-state-name = status
+arg-user_message_content-state-desc = Ob der Inhalt deiner Nachrichten gespeichert werden darf.
+
 # user/profile
 arg-profile-username-name = benutzername
 arg-profile-username-desc = Benutzername des Benutzers, dessen Avatar Sie wollen.
@@ -430,6 +727,94 @@ choice-lang-lang_choice-es-ES-name = Spanisch
 choice-lang-lang_choice-zh-CN-name = Chinesisch (Vereinfacht)
 choice-lang-lang_choice-ru-name = Russisch
 
+# anilist/status status choices
+choice-anilist_status-status-current-name = Aktuell
+choice-anilist_status-status-planning-name = Geplant
+choice-anilist_status-status-completed-name = Abgeschlossen
+choice-anilist_status-status-dropped-name = Abgebrochen
+choice-anilist_status-status-paused-name = Pausiert
+choice-anilist_status-status-repeating-name = Wiederholung
+
+# anilist/recommend type choices
+choice-anilist_recommend-type-anime-name = Anime
+choice-anilist_recommend-type-manga-name = Manga
+
+# anilist/recommend format choices
+choice-anilist_recommend-format-tv-name = TV
+choice-anilist_recommend-format-tv_short-name = TV kurz
+choice-anilist_recommend-format-movie-name = Film
+choice-anilist_recommend-format-special-name = Special
+choice-anilist_recommend-format-ova-name = OVA
+choice-anilist_recommend-format-ona-name = ONA
+choice-anilist_recommend-format-manga-name = Manga
+choice-anilist_recommend-format-novel-name = Light Novel
+choice-anilist_recommend-format-one_shot-name = One Shot
+
+# anilist/schedule day choices
+choice-anilist_schedule-day-today-name = Heute
+choice-anilist_schedule-day-tomorrow-name = Morgen
+choice-anilist_schedule-day-monday-name = Montag
+choice-anilist_schedule-day-tuesday-name = Dienstag
+choice-anilist_schedule-day-wednesday-name = Mittwoch
+choice-anilist_schedule-day-thursday-name = Donnerstag
+choice-anilist_schedule-day-friday-name = Freitag
+choice-anilist_schedule-day-saturday-name = Samstag
+choice-anilist_schedule-day-sunday-name = Sonntag
+
+# anilist/schedule scope choices
+choice-anilist_schedule-scope-all-name = Alle Anime
+choice-anilist_schedule-scope-server-name = Vom Server verfolgt
+choice-anilist_schedule-scope-list-name = Auf meiner Liste
+
+# anilist/season season choices
+choice-anilist_season-season-winter-name = Winter
+choice-anilist_season-season-spring-name = Frühling
+choice-anilist_season-season-summer-name = Sommer
+choice-anilist_season-season-fall-name = Herbst
+
+# anilist/season format choices
+choice-anilist_season-format-tv-name = TV
+choice-anilist_season-format-tv_short-name = TV kurz
+choice-anilist_season-format-movie-name = Film
+choice-anilist_season-format-special-name = Special
+choice-anilist_season-format-ova-name = OVA
+choice-anilist_season-format-ona-name = ONA
+choice-anilist_season-format-music-name = Musik
+
+# anilist_server/anilist_leaderboard stat choices
+choice-anilist_leaderboard-stat-level-name = Level
+choice-anilist_leaderboard-stat-minutes_watched-name = Geschaute Zeit
+choice-anilist_leaderboard-stat-chapters_read-name = Gelesene Kapitel
+choice-anilist_leaderboard-stat-mean_score-name = Durchschnittliche Bewertung
+choice-anilist_leaderboard-stat-completed-name = Abgeschlossene Titel
+
+# levels/heatmap period choices
+choice-levels_heatmap-period-week-name = Letzte 7 Tage
+choice-levels_heatmap-period-month-name = Letzte 30 Tage
+choice-levels_heatmap-period-quarter-name = Letzte 90 Tage
+choice-levels_heatmap-period-year-name = Letzte 365 Tage
+
+# minigame/guess_anime difficulty choices
+choice-minigame_guess_anime-difficulty-easy-name = Leicht
+choice-minigame_guess_anime-difficulty-normal-name = Normal
+choice-minigame_guess_anime-difficulty-hard-name = Schwer
+
+# minigame/leaderboard game choices
+choice-minigame_leaderboard-game-all-name = Alle Minispiele
+choice-minigame_leaderboard-game-fishing-name = Angeln
+choice-minigame_leaderboard-game-gacha-name = Charaktersammlung
+choice-minigame_leaderboard-game-guess_anime-name = Errate den Anime
+choice-minigame_leaderboard-game-anisong_quiz-name = Anisong-Quiz
+choice-minigame_leaderboard-game-daily-name = Tägliche Belohnungen
+
+# minigame/leaderboard scope choices
+choice-minigame_leaderboard-scope-server-name = Dieser Server
+choice-minigame_leaderboard-scope-global-name = Alle Server
+
+# minigame/leaderboard window choices
+choice-minigame_leaderboard-window-weekly-name = Diese Woche
+choice-minigame_leaderboard-window-all_time-name = Insgesamt
+
 # admin/general/module choices
 choice-module-name-AI-name = KI
 choice-module-name-ANILIST-name = ANILIST
@@ -0,0 +1,2 @@
+level_up-desc = Glückwunsch { $user }, du hast **Level { $level }** erreicht!
+level_up-title = Levelaufstieg!
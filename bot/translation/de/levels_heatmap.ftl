@@ -0,0 +1,5 @@
+levels_heatmap-desc = Aktivität der letzten { $days } Tage: { $messages } Nachrichten und { $hours }h { $minutes }m im Sprachkanal.
+levels_heatmap-footer = Uhrzeiten in { $timezone }.
+levels_heatmap-message_retention = Nachrichten werden nur { $message_days } Tage aufbewahrt, ältere werden nicht angezeigt.
+levels_heatmap-title_server = Serveraktivität
+levels_heatmap-title_user = Aktivität von { $user }
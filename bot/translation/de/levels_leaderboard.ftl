@@ -0,0 +1,6 @@
+levels_leaderboard-empty = Auf diesem Server hat noch niemand XP gesammelt.
+levels_leaderboard-entry = **#{ $rank }** { $user } — Level { $level } ({ $xp } XP)
+levels_leaderboard-next = Weiter
+levels_leaderboard-page = Seite { $page }/{ $total }
+levels_leaderboard-previous = Zurück
+levels_leaderboard-title = Server-Rangliste
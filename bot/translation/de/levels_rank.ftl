@@ -0,0 +1,4 @@
+levels_rank-desc = **Rang:** #{ $rank }
+    **Level:** { $level }
+    **Gesamt-XP:** { $xp }
+levels_rank-title = Rangkarte von { $user }
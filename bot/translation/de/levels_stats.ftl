@@ -4,10 +4,11 @@ levels_stats-message = **Gesendete Nachrichten:** { $message }
 levels_stats-message_title = Nachrichtenaktivität
 levels_stats-title = Server-Aktivitätsstatistik
 levels_stats-vocal = **Sitzungen:** { $session }
+levels_stats-vocal_active = **Aktive Zeit:** { $hours }h { $minutes }m { $seconds }s
 levels_stats-vocal_len = **Verbrachte Zeit:** { $hours }h { $minutes }m { $seconds }s
 levels_stats-vocal_title = Sprachaktivität
 levels_stats-xp_message = **Von Nachrichten:** { $xp } XP
+levels_stats-xp_minigame = **Aus Minispielen:** { $xp } XP
 levels_stats-xp_title = XP-Aufschlüsselung
 levels_stats-xp_total = **Gesamt-XP:** { $xp }
-levels_stats-xp_vocal = **Von Sprachsitzungen:** { $xp } XP
-levels_stats-xp_vocal_len = **Von Sprachdauer:** { $xp } XP
+levels_stats-xp_vocal = **Aus Sprachkanälen:** { $xp } XP
@@ -0,0 +1,4 @@
+management_reload_items-failed = Der Gegenstandskatalog wurde abgelehnt, nichts wurde geändert.
+management_reload_items-synced = Gegenstandskatalog Version { $version } synchronisiert: { $added } hinzugefügt, { $updated } geändert, { $retired } ausgemustert, { $restored } wiederhergestellt.
+management_reload_items-title = Gegenstandskatalog
+management_reload_items-up_to_date = Der Gegenstandskatalog (Version { $version }) ist bereits aktuell.
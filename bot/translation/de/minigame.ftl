@@ -0,0 +1,2 @@
+minigame-disabled_desc = Das Minispiel-Modul ist auf diesem Server deaktiviert.
+minigame-disabled_title = Minispiele deaktiviert
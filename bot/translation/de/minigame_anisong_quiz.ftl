@@ -0,0 +1,23 @@
+minigame_anisong_quiz-already_running = Auf diesem Server läuft bereits ein Quiz, warte bis es endet.
+minigame_anisong_quiz-anime = Anime
+minigame_anisong_quiz-answer = Antworten
+minigame_anisong_quiz-answer_label = Anime-Titel
+minigame_anisong_quiz-answer_placeholder = Romaji, Englisch oder ein anderer bekannter Titel
+minigame_anisong_quiz-closed = Diese Runde ist vorbei.
+minigame_anisong_quiz-correct = Richtig! Du erhältst { $points } Punkte.
+minigame_anisong_quiz-music_playing = Im Sprachkanal läuft Musik, stoppe sie bevor du ein Quiz startest.
+minigame_anisong_quiz-no_song = Es wurde kein Song zum Abspielen gefunden, das Quiz endet hier.
+minigame_anisong_quiz-not_starter = Nur wer das Quiz gestartet hat, kann es stoppen.
+minigame_anisong_quiz-round_description = Aus welchem Anime stammt dieser Song? Du hast { $seconds } Sekunden, schnelle Antworten bringen mehr Punkte.
+minigame_anisong_quiz-round_title = Anisong-Quiz · Runde { $round }/{ $rounds }
+minigame_anisong_quiz-solved = { $user } hat es erraten!
+minigame_anisong_quiz-song = Song
+minigame_anisong_quiz-standing = **#{ $rank }** { $user } — { $points } Punkte
+minigame_anisong_quiz-standings_empty = Diesmal hat niemand gepunktet.
+minigame_anisong_quiz-standings_title = Ergebnisse des Anisong-Quiz
+minigame_anisong_quiz-starting = Das Quiz beginnt mit { $rounds } Songs, hör genau hin!
+minigame_anisong_quiz-stop = Quiz stoppen
+minigame_anisong_quiz-stopped = Das Quiz wurde gestoppt.
+minigame_anisong_quiz-title = Anisong-Quiz
+minigame_anisong_quiz-unsolved = Diesmal hat es niemand erraten.
+minigame_anisong_quiz-wrong = „{ $guess }“ ist es nicht, versuch es weiter!
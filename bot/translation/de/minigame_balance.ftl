@@ -0,0 +1,9 @@
+minigame_balance-desc = Du hast **{ $balance }** Münzen.
+minigame_balance-history = Letzte Transaktionen
+minigame_balance-no_history = Noch keine Transaktionen.
+minigame_balance-reason_gacha_roll = Charakterziehung
+minigame_balance-reason_gift = Geschenk
+minigame_balance-reason_sale = Verkauf
+minigame_balance-reason_shop_purchase = Ladenkauf
+minigame_balance-reason_trade = Tausch
+minigame_balance-title = Kontostand
@@ -0,0 +1,11 @@
+minigame_daily-already_claimed = Du hast die heutige Belohnung bereits abgeholt, deine Serie liegt bei { $streak } Tagen. Die nächste ist { $next } verfügbar.
+minigame_daily-coins = Du hast **{ $coins }** Münzen erhalten, dein Kontostand beträgt jetzt { $balance }.
+minigame_daily-item = Du hast **{ $name }** { $rarity } gefunden
+minigame_daily-next = Nächste Belohnung { $next }. Hole sie vor { $deadline } ab, um deine Serie zu behalten.
+minigame_daily-next_field = Nächste Belohnung
+minigame_daily-next_tier = Ab Tag { $day } steigt die Belohnung auf { $coins } Münzen.
+minigame_daily-streak = **{ $streak }** Tage in Folge (Rekord: { $best })
+minigame_daily-streak_field = Serie
+minigame_daily-streak_grace = Du warst spät dran, aber die Kulanzzeit hat deine Serie gerettet.
+minigame_daily-streak_lost = Deine Serie von { $previous } Tagen ist verloren, eine neue beginnt.
+minigame_daily-title = Tägliche Belohnung
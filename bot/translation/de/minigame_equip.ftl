@@ -0,0 +1,6 @@
+minigame_equip-equipped = **{ $name }** als { $slot } angelegt. Noch { $uses } Einsätze beim aktuellen, { $owned } im Besitz.
+minigame_equip-not_owned = Du besitzt diese Ausrüstung nicht.
+minigame_equip-slot_bait = Köder
+minigame_equip-slot_rod = Angel
+minigame_equip-title = Angelausrüstung
+minigame_equip-unequipped = Dein Platz für { $slot } ist jetzt leer.
@@ -1,6 +1,9 @@
 minigame_fishing-average = durchschnittlich
 minigame_fishing-caught_fish = Du hast einen Fisch gefangen!
 minigame_fishing-common = Gewöhnlich
+minigame_fishing-conditions = { $time_of_day }, { $season }
+minigame_fishing-conditions_field = Bedingungen
+minigame_fishing-cooldown = Deine Schnur trocknet noch. Du kannst { $time } wieder auswerfen.
 minigame_fishing-description_field = Beschreibung
 minigame_fishing-epic = Episch
 minigame_fishing-fish_details = Fischdetails
@@ -10,14 +13,28 @@ minigame_fishing-fish_details_format =
     {"**"}Seltenheit:** { $rarity }
     {"**"}XP-Boost:** { $xp_boost }%
     {"**"}Wert:** { $price } Münzen
+minigame_fishing-gear_broken = **{ $name }** ist kaputtgegangen!
+minigame_fishing-gear_entry = **{ $name }**: noch { $uses } Einsätze
+minigame_fishing-gear_field = Ausrüstung
 minigame_fishing-huge = riesig
 minigame_fishing-large = groß
 minigame_fishing-legendary = Legendär
 minigame_fishing-massive = massiv
+minigame_fishing-no_gear = Keine Ausrüstung angelegt
+minigame_fishing-points = Bestenliste: **+{ $points } Punkte**.
 minigame_fishing-rare = Selten
+minigame_fishing-season_autumn = Herbst
+minigame_fishing-season_spring = Frühling
+minigame_fishing-season_summer = Sommer
+minigame_fishing-season_winter = Winter
 minigame_fishing-small = klein
+minigame_fishing-time_dawn = Morgengrauen
+minigame_fishing-time_day = Tag
+minigame_fishing-time_dusk = Abenddämmerung
+minigame_fishing-time_night = Nacht
 minigame_fishing-tiny = winzig
 minigame_fishing-title = Angelergebnisse
 minigame_fishing-uncommon = Ungewöhnlich
 minigame_fishing-unknown = Unbekannt
 minigame_fishing-unknown_size = unbekannte Größe
+minigame_fishing-xp_gained = Du hast **{ $xp } XP** erhalten.
\ No newline at end of file
@@ -0,0 +1,18 @@
+minigame_gacha-card_gone = Diese Karte ist nicht mehr in dieser Sammlung.
+minigame_gacha-collection_empty = Noch keine Charaktere, ziehe einen, um eine Sammlung zu beginnen.
+minigame_gacha-collection_page = Seite { $page }/{ $total } · { $count } Karten
+minigame_gacha-collection_title = Sammlung von { $user }
+minigame_gacha-duplicate = Bereits in deiner Sammlung, diesmal keine Punkte.
+minigame_gacha-favourites = Favoriten
+minigame_gacha-free_roll = Gratisziehung genutzt, die nächste ist { $next } verfügbar.
+minigame_gacha-insufficient_funds = Deine Gratisziehung ist { $next } wieder verfügbar. Eine weitere Ziehung kostet { $cost } Münzen und du hast { $balance }.
+minigame_gacha-new_card = Neuer Charakter zu deiner Sammlung hinzugefügt!
+minigame_gacha-next = Weiter
+minigame_gacha-owned_by = Gehört { $user } seit { $obtained }
+minigame_gacha-paid_roll = { $cost } Münzen für diese Ziehung bezahlt.
+minigame_gacha-pool_empty = Der Charakterpool wird noch gefüllt, versuche es später erneut.
+minigame_gacha-previous = Zurück
+minigame_gacha-rarity = Seltenheit
+minigame_gacha-roll_title = Charakterziehung
+minigame_gacha-series = Serie
+minigame_gacha-view_card = Charakter ansehen
@@ -0,0 +1,8 @@
+minigame_gift-coins = Du hast { $user } { $coins } Münzen geschenkt.
+minigame_gift-insufficient_funds = Du hast nur { $balance } Münzen.
+minigame_gift-invalid_coins = Du kannst keine negative Anzahl Münzen verschenken.
+minigame_gift-invalid_recipient = Du kannst nur einem anderen Mitglied etwas schenken, das kein Bot ist.
+minigame_gift-items = Du hast { $user } **{ $count }x { $name }** geschenkt.
+minigame_gift-nothing = Dieser Gegenstand ist nicht mehr in deinem Inventar oder wird in einem Tausch angeboten.
+minigame_gift-nothing_chosen = Wähle einen Gegenstand, Münzen oder beides.
+minigame_gift-title = Geschenk
@@ -0,0 +1,19 @@
+minigame_guess_anime-already_running = In diesem Kanal läuft bereits eine Runde, löse sie oder gib zuerst auf.
+minigame_guess_anime-answer = Raten
+minigame_guess_anime-answer_label = Anime-Titel
+minigame_guess_anime-answer_placeholder = Romaji, Englisch oder ein anderer bekannter Titel
+minigame_guess_anime-description = Welcher Anime ist das? Schwierigkeit: { $difficulty }
+    { $points } Punkte · Stufe { $stage }/{ $total }
+minigame_guess_anime-difficulty_easy = leicht
+minigame_guess_anime-difficulty_hard = schwer
+minigame_guess_anime-difficulty_normal = normal
+minigame_guess_anime-fully_revealed = Das Bild ist bereits vollständig aufgedeckt.
+minigame_guess_anime-give_up = Aufgeben
+minigame_guess_anime-no_anime = Es wurde kein Anime zum Raten gefunden, versuche es erneut.
+minigame_guess_anime-not_starter = Nur wer die Runde gestartet hat, kann aufgeben.
+minigame_guess_anime-over = Diese Runde ist vorbei.
+minigame_guess_anime-reveal = Mehr aufdecken
+minigame_guess_anime-solved = { $user } hat es erraten und { $points } Punkte verdient!
+minigame_guess_anime-title = Errate den Anime
+minigame_guess_anime-unsolved = Diesmal hat es niemand erraten.
+minigame_guess_anime-wrong = „{ $guess }“ ist es nicht, weiter so!
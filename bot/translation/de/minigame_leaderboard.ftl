@@ -0,0 +1,18 @@
+minigame_leaderboard-empty = Noch niemand hat Punkte erzielt.
+minigame_leaderboard-entry = **#{ $rank }** { $user } — { $points } Punkte
+minigame_leaderboard-game_all = Minispiel
+minigame_leaderboard-game_anisong_quiz = Anisong-Quiz
+minigame_leaderboard-game_daily = Tägliche Belohnungen
+minigame_leaderboard-game_fishing = Angel
+minigame_leaderboard-game_gacha = Charaktersammlung
+minigame_leaderboard-game_guess_anime = Errate den Anime
+minigame_leaderboard-next = Weiter
+minigame_leaderboard-page = Seite { $page }/{ $total }
+minigame_leaderboard-position = Du bist #{ $rank } mit { $points } Punkten
+minigame_leaderboard-previous = Zurück
+minigame_leaderboard-scope_global = global
+minigame_leaderboard-scope_server = Server
+minigame_leaderboard-title = { $game }-Bestenliste ({ $scope }, { $window })
+minigame_leaderboard-unranked = Du bist noch nicht platziert
+minigame_leaderboard-window_all_time = insgesamt
+minigame_leaderboard-window_weekly = diese Woche
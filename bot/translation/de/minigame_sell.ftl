@@ -0,0 +1,3 @@
+minigame_sell-nothing = Dieser Gegenstand ist nicht mehr in deinem Inventar.
+minigame_sell-sold = Du hast **{ $count }x { $name }** für { $earned } Münzen verkauft. Du hast jetzt { $balance } Münzen.
+minigame_sell-title = Verkauf
@@ -0,0 +1,7 @@
+minigame_shop-bought = Du hast **{ $quantity }x { $name }** für { $total } Münzen gekauft. Du hast jetzt { $balance } Münzen.
+minigame_shop-desc = Du hast **{ $balance }** Münzen. Nutze die Option `kaufen`, um einen Gegenstand zu kaufen.
+minigame_shop-empty = Der Laden hat gerade nichts im Angebot.
+minigame_shop-entry = **{ $name }** — { $price } Münzen
+minigame_shop-insufficient = **{ $name }** kostet { $total } Münzen, aber du hast nur { $balance }.
+minigame_shop-not_for_sale = Dieser Gegenstand ist nicht zu verkaufen.
+minigame_shop-title = Laden
@@ -0,0 +1,28 @@
+minigame_trade-cancel = Abbrechen
+minigame_trade-cancelled = Dieser Tausch wurde abgebrochen.
+minigame_trade-change_offer = Mein Angebot ändern
+minigame_trade-closed = Dieser Tausch ist abgeschlossen.
+minigame_trade-coins = { $coins } Münzen
+minigame_trade-completed = Tausch abgeschlossen! Alles Angebotene hat den Besitzer gewechselt.
+minigame_trade-confirm = Bestätigen
+minigame_trade-confirmed = bestätigt
+minigame_trade-empty_offer = Noch nichts angeboten.
+minigame_trade-expired = Dieser Tausch ist abgelaufen.
+minigame_trade-failed = Der Tausch konnte nicht abgeschlossen werden, weil etwas Angebotenes fehlt oder eine Geldbörse nicht reicht. Prüft die Angebote und bestätigt erneut.
+minigame_trade-insufficient_funds = Du hast nur { $balance } Münzen.
+minigame_trade-invalid_partner = Du kannst nur mit einem anderen Mitglied tauschen, das kein Bot ist.
+minigame_trade-missing = { $name } (nicht mehr verfügbar)
+minigame_trade-not_participant = Nur die beiden Mitglieder dieses Tauschs können ihn verwenden.
+minigame_trade-offer_desc =
+    Wähle unten die Gegenstände, die du anbietest, und lege deine Münzen mit den Knöpfen fest.
+    Angebotene Münzen: **{ $coins }**
+minigame_trade-offer_header = **{ $user }** — { $state }
+minigame_trade-offer_title = Dein Angebot
+minigame_trade-offer_updated = Angebot aktualisiert.
+minigame_trade-open = Beide Mitglieder fügen Gegenstände oder Münzen hinzu und bestätigen dann beide. Jede Änderung setzt die Bestätigungen zurück. Läuft ab { $expires }.
+minigame_trade-pending = nicht bestätigt
+minigame_trade-pick_cards = Anzubietende Charaktere
+minigame_trade-pick_items = Anzubietende Gegenstände
+minigame_trade-reset_coins = Münzen zurücksetzen
+minigame_trade-stale = Das Angebot hat sich geändert, seit du es angesehen hast. Prüfe es, bevor du bestätigst.
+minigame_trade-title = Tausch
@@ -0,0 +1,3 @@
+user_message_content-off = Der Inhalt deiner Nachrichten wird nicht gespeichert, und bisher gespeicherte Inhalte wurden entfernt. Nur Länge, Kanal und Zeitpunkt der Nachrichten bleiben erhalten.
+user_message_content-on = Der Inhalt deiner Nachrichten wird auf Servern gespeichert, die dies erlauben.
+user_message_content-title = Nachrichteninhalt
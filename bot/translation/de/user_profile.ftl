@@ -1,8 +1,10 @@
+user_profile-achievements = Erfolge ({ $count }/{ $total })
 user_profile-bot = Ist ein Bot
 user_profile-creation_date = Erstellungsdatum des Kontos
 user_profile-id = Benutzer ID
 user_profile-joined_date = Beitrittsdatum
 user_profile-nitro = Nitro Typ
+user_profile-no_achievements = Noch keine Erfolge freigeschaltet.
 user_profile-premium = Premium
 user_profile-public_flag = Öffentliche Flagge
 user_profile-system = Ist ein System
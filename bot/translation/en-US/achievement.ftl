@@ -0,0 +1,3 @@
+achievement-unlocked = { $user } unlocked { $icon } **{ $name }**
+    { $description }
+achievement-unlocked_title = Achievement unlocked!
@@ -0,0 +1,2 @@
+admin_level_add_reward-desc = { $role } will be given to members reaching level { $level }.
+admin_level_add_reward-title = Level Reward Added
@@ -0,0 +1,3 @@
+admin_level_remove_reward-desc = { $role } is no longer a level reward.
+admin_level_remove_reward-not_found = { $role } is not a level reward on this server.
+admin_level_remove_reward-title = Level Reward Removed
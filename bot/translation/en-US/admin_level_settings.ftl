@@ -0,0 +1,16 @@
+admin_level_settings-announce = Level-up announcements
+admin_level_settings-channel = Announcement channel
+admin_level_settings-channel_default = Channel of the message
+admin_level_settings-cooldown = Message cooldown
+admin_level_settings-cooldown_value = { $seconds }s
+admin_level_settings-curve = Level curve
+admin_level_settings-curve_value = { $base } × level^{ $exponent }
+admin_level_settings-message_xp = XP per message
+admin_level_settings-module_off = The LEVEL module is disabled on this server, no XP will be earned until it is enabled with `/admin general module`.
+admin_level_settings-no_rewards = No reward roles configured.
+admin_level_settings-off = Disabled
+admin_level_settings-on = Enabled
+admin_level_settings-reward = Level { $level }: { $role }
+admin_level_settings-rewards = Reward roles
+admin_level_settings-title = Leveling Settings
+admin_level_settings-voice_xp = XP per voice minute
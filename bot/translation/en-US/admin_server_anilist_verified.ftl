@@ -0,0 +1,3 @@
+admin_server_anilist_verified-title = Verified AniList accounts
+admin_server_anilist_verified-on = Server lists now only show AniList accounts verified with `/verify`.
+admin_server_anilist_verified-off = Server lists show every registered AniList account, verified ones are marked with ✓.
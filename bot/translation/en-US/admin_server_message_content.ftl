@@ -0,0 +1,3 @@
+admin_server_message_content-off = Message content will no longer be stored on this server. Content already stored here has been removed; only message length, channel and time are kept.
+admin_server_message_content-on = Message content will be stored for members who opted in with `/user message_content`.
+admin_server_message_content-title = Message content
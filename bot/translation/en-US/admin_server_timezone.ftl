@@ -0,0 +1,2 @@
+admin_server_timezone-desc = The server timezone was set to: { $timezone }
+admin_server_timezone-title = Timezone
@@ -0,0 +1,24 @@
+anilist_airing-dm_desc = Episode { $episode } of { $anime } just aired.
+anilist_airing-unfollow_button = Unfollow
+anilist_airing-snooze_button = Snooze { $hours }h
+anilist_airing-mute_button = Mute all
+anilist_airing-following = You will get a DM when episode { $episode } airs { $time }. Make sure the bot can send you direct messages.
+anilist_airing-not_airing = This anime has no upcoming episode to follow.
+anilist_airing-unfollow_title = Airing DMs
+anilist_airing-unfollowed = You will no longer get DMs for this anime.
+anilist_airing-not_following = You do not follow this anime.
+anilist_airing-snoozed = Airing DMs are snoozed, they resume { $time }.
+anilist_airing-muted = Airing DMs are muted, turn them back on with `anilist notifications`.
+anilist_airing-settings_title = Airing notifications
+anilist_airing-state = DMs
+anilist_airing-state_on = On
+anilist_airing-state_muted = Muted
+anilist_airing-state_snoozed = Snoozed until { $time }
+anilist_airing-auto_follow = Auto-follow
+anilist_airing-auto_follow_on = Watching list followed
+anilist_airing-auto_follow_off = Off
+anilist_airing-none_followed = You follow no anime. Use `anilist follow` or turn on auto-follow.
+anilist_airing-next = episode { $episode } { $time }
+anilist_airing-no_next = no episode scheduled
+anilist_airing-from_list = from your list
+anilist_airing-more = …and { $count } more
@@ -0,0 +1,6 @@
+anilist_franchise-title = Franchise
+anilist_franchise-title_media = { $title } franchise, { $count } titles
+anilist_franchise-not_found = No anime or manga was found with this name.
+anilist_franchise-truncated = The franchise is too large, only the { $count } closest titles are shown.
+anilist_franchise-cycles = Some relations loop, those titles are ordered by release date.
+anilist_franchise-more = …and { $count } more on the graph
@@ -0,0 +1,19 @@
+anilist_leaderboard-title = AniList leaderboard: { $stat }
+anilist_leaderboard-stat_level = Level
+anilist_leaderboard-stat_minutes_watched = Time watched
+anilist_leaderboard-stat_chapters_read = Chapters read
+anilist_leaderboard-stat_mean_score = Mean score
+anilist_leaderboard-stat_completed = Completed titles
+anilist_leaderboard-entry = { $rank }. { $user } [{ $name }](<{ $url }>) · { $value }
+anilist_leaderboard-value_level = level { $level } ({ $xp } XP)
+anilist_leaderboard-value_minutes_watched = { $days } days
+anilist_leaderboard-value_chapters_read = { $value } chapters
+anilist_leaderboard-value_mean_score = { $value }/100
+anilist_leaderboard-value_completed = { $value } titles
+anilist_leaderboard-empty = No member of this server is ranked yet. Registered accounts are picked up when the statistics are next refreshed, every few hours.
+anilist_leaderboard-updated = Statistics updated { $time }.
+anilist_leaderboard-page = Page { $page }/{ $total }
+anilist_leaderboard-position = You are #{ $rank }
+anilist_leaderboard-unranked = You are not ranked
+anilist_leaderboard-previous = Previous
+anilist_leaderboard-next = Next
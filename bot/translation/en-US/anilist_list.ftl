@@ -0,0 +1,16 @@
+anilist_list-relink_title = AniList login needed
+anilist_list-not_linked = Log in on AniList to update your list from Discord. The button below gives you a personal login link.
+anilist_list-expired = Your AniList login expired or was revoked. Log in again with the button below to keep updating your list.
+anilist_list-not_found_title = Not on your list
+anilist_list-not_found = Nothing on your current list matches "{ $media }". Pick an entry from the suggestions.
+anilist_list-progress = Progress
+anilist_list-status = Status
+anilist_list-score = Score
+anilist_list-status_current = Current
+anilist_list-status_planning = Planning
+anilist_list-status_completed = Completed
+anilist_list-status_dropped = Dropped
+anilist_list-status_paused = Paused
+anilist_list-status_repeating = Repeating
+anilist_list-episode_done = { $anime } is already at { $progress } on your list.
+anilist_list-episode_saved = Marked as watched, { $anime } is now at { $progress } on your list.
@@ -0,0 +1 @@
+anilist_progress-desc = Progress moved from { $previous } to { $progress }.
@@ -0,0 +1,6 @@
+anilist_recommend-title = Recommendations
+anilist_recommend-title_user = Recommendations for { $user }
+anilist_recommend-no_user = Give a username or register your AniList account with the `register` command.
+anilist_recommend-nothing = Nothing to recommend. Recommendations come from the completed titles scored 7/10 or more, try without filters or score more titles.
+anilist_recommend-because = Because you rated { $title } { $score }/10
+anilist_recommend-tags = {" "}and like { $tags }
@@ -0,0 +1,12 @@
+anilist_schedule-title = Airing schedule of { $date }
+anilist_schedule-scope_all = Every anime airing that day in { $timezone }.
+anilist_schedule-scope_server = The anime followed by this server airing that day in { $timezone }.
+anilist_schedule-scope_list = The anime on your list airing that day in { $timezone }.
+anilist_schedule-empty = No episode airs that day.
+anilist_schedule-episode = episode { $episode }
+anilist_schedule-episode_of = episode { $episode }/{ $episodes }
+anilist_schedule-page = Page { $page }/{ $total }
+anilist_schedule-no_user_title = Airing schedule
+anilist_schedule-no_user = Register your AniList account with `register` to see the episodes of your list.
+anilist_schedule-previous = Previous
+anilist_schedule-next = Next
@@ -0,0 +1,3 @@
+anilist_score-title = Invalid score
+anilist_score-invalid = { $score } does not fit the score format of your AniList profile, use a score between 0 and { $max }.
+anilist_score-desc = Score set to { $score }.
@@ -0,0 +1,20 @@
+anilist_season-title = { $season } { $year } anime
+anilist_season-season_winter = Winter
+anilist_season-season_spring = Spring
+anilist_season-season_summer = Summer
+anilist_season-season_fall = Fall
+anilist_season-format_tv = TV
+anilist_season-format_tv_short = TV short
+anilist_season-format_movie = Movie
+anilist_season-format_special = Special
+anilist_season-format_ova = OVA
+anilist_season-format_ona = ONA
+anilist_season-format_music = Music
+anilist_season-filters = Filters: { $filters }
+anilist_season-empty = No anime of this season matches these filters.
+anilist_season-episodes = { $count } episodes
+anilist_season-next = episode { $episode } { $time }
+anilist_season-upcoming = not yet aired
+anilist_season-page = Page { $page }/{ $total } · { $count } anime
+anilist_season-previous = Previous
+anilist_season-next_page = Next
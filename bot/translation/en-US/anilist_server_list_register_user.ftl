@@ -1,3 +1,5 @@
 anilist_server_list_register_user-next = Next page
 anilist_server_list_register_user-previous = Previous page
 anilist_server_list_register_user-title = List of registered users.
+anilist_server_list_register_user-empty = No member of this server has registered an AniList account.
+anilist_server_list_register_user-page = Page { $page }/{ $total }
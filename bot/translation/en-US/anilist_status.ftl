@@ -0,0 +1 @@
+anilist_status-desc = Status set to { $status }.
@@ -32,3 +32,27 @@ anilist_user_compare-tag_anime =
     { $var1 } prefers the { $var1a } tag, and { $var2 } prefers the { $var2a } tag for anime
 anilist_user_compare-tag_manga =
     { $var1 } prefers the { $var1a } tag, and { $var2 } prefers the { $var2a } tag for manga
+anilist_user_compare-correlation =
+    Score correlation on shared titles: { $pearson } (Pearson), { $spearman } (Spearman)
+anilist_user_compare-disagreements =
+    Biggest disagreements
+anilist_user_compare-disagreements_button =
+    Disagreements
+anilist_user_compare-for_first =
+    { $var2 } loved it, { $var1 } hasn't seen it
+anilist_user_compare-for_second =
+    { $var1 } loved it, { $var2 } hasn't seen it
+anilist_user_compare-list_title =
+    List comparison
+anilist_user_compare-next =
+    Next
+anilist_user_compare-no_correlation =
+    Not enough titles scored by both to compute a score correlation
+anilist_user_compare-nothing =
+    Nothing to show here.
+anilist_user_compare-page =
+    Page { $page } of { $total }
+anilist_user_compare-previous =
+    Previous
+anilist_user_compare-shared =
+    { $var1 } and { $var2 } both watched or read { $shared } titles, { $scored } of them scored by both
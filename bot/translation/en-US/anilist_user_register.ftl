@@ -1 +1,4 @@
 anilist_user_register-desc = The user { $user } (with the id { $id }) was linked with the Anilist account { $anilist }
+anilist_user_register-unverified = This link is unverified, use `/verify` to prove the account is yours.
+anilist_user_register-taken = Another member already registered this AniList account. If it is yours, use `/verify` to claim it.
+anilist_user_register-taken_verified = This AniList account is verified by another member. If it is yours, use `/verify` to claim it.
@@ -1,2 +1,3 @@
 anilist_user_send_activity-desc = Episode { $ep } of { $anime } just released.
 anilist_user_send_activity-title = New Episode
+anilist_user_send_activity-episode_button = Episode +1
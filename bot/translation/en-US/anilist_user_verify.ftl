@@ -0,0 +1,7 @@
+anilist_user_verify-title = Verify your AniList account
+anilist_user_verify-desc = Anyone can register an AniList username. Log in on AniList to prove the account is yours; verified links take over the account from anyone who registered it before and can be required by servers.
+anilist_user_verify-already = Your Discord account is already verified with [this AniList account]({ $url }). Log in again to link another one.
+anilist_user_verify-button = Get my login link
+anilist_user_verify-link = Here is your personal login link, it expires in 15 minutes. You will log in with Discord first, then on AniList.
+anilist_user_verify-open = Log in on AniList
+anilist_user_verify-disabled = Verified AniList linking is not enabled on this bot.
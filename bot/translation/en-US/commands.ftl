@@ -35,6 +35,9 @@ parent-levels-desc = Command to get level of user, and statistic.
 parent-minigame-name = minigame
 parent-minigame-desc = Commands for playing minigames and managing your inventory.
 
+parent-anilist-name = anilist
+parent-anilist-desc = Commands using your AniList list.
+
 parent-admin-name = admin
 parent-admin-desc = Bot configuration configuration for admin only.
 
@@ -46,6 +49,9 @@ group-admin-anilist-desc = Commands for the AniList module that need admin permi
 group-admin-general-name = general
 group-admin-general-desc = Commands for the general module that need admin permissions.
 
+group-admin-level-name = level
+group-admin-level-desc = Admin commands for the LEVEL module.
+
 # ─── Commands ─────────────────────────────────────────────────────────────────
 
 # admin/anilist
@@ -59,9 +65,28 @@ cmd-delete_anime_activity-desc = Delete an anime activity.
 cmd-lang-name = lang
 cmd-lang-desc = The language you want to set the response to.
 
+cmd-admin_message_content-name = message_content
+cmd-admin_message_content-desc = Allow storing the content of messages from members who opted in.
+
+cmd-admin_anilist_verified-name = anilist_verified
+cmd-admin_anilist_verified-desc = Only list AniList accounts verified through the AniList login.
+
+cmd-admin_timezone-name = timezone
+cmd-admin_timezone-desc = Set the timezone of the server.
+
 cmd-module-name = module
 cmd-module-desc = Turn on or off a module.
 
+# admin/level
+cmd-admin_settings-name = settings
+cmd-admin_settings-desc = View or change the leveling settings.
+
+cmd-admin_add_reward-name = add_reward
+cmd-admin_add_reward-desc = Give a role to members when they reach a level.
+
+cmd-admin_remove_reward-name = remove_reward
+cmd-admin_remove_reward-desc = Stop giving a role as a level reward.
+
 # ai
 cmd-image-name = image
 cmd-image-desc = Generate an image.
@@ -75,6 +100,37 @@ cmd-transcript-desc = Generate a transcript from a video.
 cmd-translation-name = translation
 cmd-translation-desc = Generate a translation.
 
+# anilist
+cmd-anilist_follow-name = follow
+cmd-anilist_follow-desc = Get a DM when each episode of an anime airs.
+
+cmd-anilist_franchise-name = franchise
+cmd-anilist_franchise-desc = Get the watch order and relation graph of a franchise.
+
+cmd-anilist_notifications-name = notifications
+cmd-anilist_notifications-desc = Set up your airing DMs and see the anime you follow.
+
+cmd-anilist_progress-name = progress
+cmd-anilist_progress-desc = Add watched episodes or read chapters to your AniList list.
+
+cmd-anilist_recommend-name = recommend
+cmd-anilist_recommend-desc = Get anime or manga picked from the best scored titles of a list.
+
+cmd-anilist_schedule-name = schedule
+cmd-anilist_schedule-desc = Show the anime episodes airing on a day.
+
+cmd-anilist_score-name = score
+cmd-anilist_score-desc = Score an anime or manga on your AniList list.
+
+cmd-anilist_season-name = season
+cmd-anilist_season-desc = List the anime of a season.
+
+cmd-anilist_status-name = status
+cmd-anilist_status-desc = Change the status of an anime or manga on your AniList list.
+
+cmd-anilist_unfollow-name = unfollow
+cmd-anilist_unfollow-desc = Stop the airing DMs of an anime.
+
 # anilist_server
 cmd-list_activity-name = list_activity
 cmd-list_activity-desc = Get the list of registered activity.
@@ -82,6 +138,9 @@ cmd-list_activity-desc = Get the list of registered activity.
 cmd-list_user-name = list_user
 cmd-list_user-desc = Get the list of registered user.
 
+cmd-anilist_leaderboard-name = anilist_leaderboard
+cmd-anilist_leaderboard-desc = Rank the server members by their AniList statistics.
+
 # anilist_user
 cmd-anime-name = anime
 cmd-anime-desc = Info of an anime.
@@ -107,6 +166,9 @@ cmd-random-desc = Get a random anime or manga.
 cmd-register-name = register
 cmd-register-desc = Register your username on AniList.
 
+cmd-verify-name = verify
+cmd-verify-desc = Prove you own your AniList account by logging in on AniList.
+
 cmd-seiyuu-name = seiyuu
 cmd-seiyuu-desc = Info of a seiyuu.
 
@@ -144,6 +206,15 @@ cmd-ping-desc = Get the ping of the bot (and the shard id).
 cmd-stats-name = stats
 cmd-stats-desc = Get stats for level.
 
+cmd-levels_rank-name = rank
+cmd-levels_rank-desc = Show the rank card of a member.
+
+cmd-levels_leaderboard-name = leaderboard
+cmd-levels_leaderboard-desc = Show the most active members of the server.
+
+cmd-levels_heatmap-name = heatmap
+cmd-levels_heatmap-desc = Show when a member or the server is active.
+
 # management
 cmd-give_premium_sub-name = give_premium_sub
 cmd-give_premium_sub-desc = Give a premium subscription to a user.
@@ -151,19 +222,58 @@ cmd-give_premium_sub-desc = Give a premium subscription to a user.
 cmd-kill_switch-name = kill_switch
 cmd-kill_switch-desc = Globally turn on or off a module
 
+cmd-reload_items-name = reload_items
+cmd-reload_items-desc = Validate and sync the item catalog.
+
 cmd-remove_test_sub-name = remove_test_sub
 cmd-remove_test_sub-desc = Remove premium subscriptions from a user.
 
 # minigame
+cmd-minigame_balance-name = balance
+cmd-minigame_balance-desc = Check your coins and recent transactions.
+
+cmd-minigame_collection-name = collection
+cmd-minigame_collection-desc = Show the characters a member has rolled.
+
+cmd-minigame_daily-name = daily
+cmd-minigame_daily-desc = Claim your daily reward and keep your streak going.
+
+cmd-minigame_equip-name = equip
+cmd-minigame_equip-desc = Equip a fishing rod or bait.
+
 cmd-fish_inventory-name = fish_inventory
 cmd-fish_inventory-desc = Check your fish inventory.
 
 cmd-fishing-name = fishing
 cmd-fishing-desc = Go fishing!
 
+cmd-minigame_gift-name = gift
+cmd-minigame_gift-desc = Give items or coins to another member.
+
+cmd-minigame_guess_anime-name = guess_anime
+cmd-minigame_guess_anime-desc = Start a round of guess the anime from its cover.
+
+cmd-minigame_anisong_quiz-name = anisong_quiz
+cmd-minigame_anisong_quiz-desc = Start a quiz naming the anime of songs played in your voice channel.
+
 cmd-inventory-name = inventory
 cmd-inventory-desc = Check your inventory.
 
+cmd-minigame_leaderboard-name = leaderboard
+cmd-minigame_leaderboard-desc = Show the best minigame players.
+
+cmd-minigame_roll-name = roll
+cmd-minigame_roll-desc = Roll a random AniList character for your collection.
+
+cmd-minigame_sell-name = sell
+cmd-minigame_sell-desc = Sell items from your inventory.
+
+cmd-minigame_shop-name = shop
+cmd-minigame_shop-desc = Browse the shop or buy an item.
+
+cmd-minigame_trade-name = trade
+cmd-minigame_trade-desc = Trade items and coins with another member.
+
 # music
 cmd-clear-name = clear
 cmd-clear-desc = Clear the current queue.
@@ -225,6 +335,9 @@ cmd-banner-desc = Get the banner.
 cmd-command_usage-name = command_usage
 cmd-command_usage-desc = Show the usage of each command for an user.
 
+cmd-user_message_content-name = message_content
+cmd-user_message_content-desc = Choose whether the content of your messages may be stored.
+
 cmd-profile-name = profile
 cmd-profile-desc = Show the profile of a user.
 
@@ -263,6 +376,46 @@ arg-module-name-desc = The module you want to change the state of.
 arg-module-state-name = module_state
 arg-module-state-desc = The state you want to to.
 
+# admin/general/message_content
+arg-admin_message_content-state-name = state
+arg-admin_message_content-state-desc = Whether message content may be stored.
+
+# admin/general/anilist_verified
+arg-admin_anilist_verified-state-name = state
+arg-admin_anilist_verified-state-desc = Whether only verified accounts are listed.
+
+# admin/general/timezone
+arg-admin_timezone-timezone-name = timezone
+arg-admin_timezone-timezone-desc = The timezone, for example Europe/Paris.
+
+# admin/level/settings
+arg-admin_settings-message_xp-name = message_xp
+arg-admin_settings-message_xp-desc = XP given per message (0-1000).
+arg-admin_settings-voice_xp-name = voice_xp
+arg-admin_settings-voice_xp-desc = XP given per minute in voice (0-1000).
+arg-admin_settings-cooldown-name = cooldown
+arg-admin_settings-cooldown-desc = Seconds between two messages earning XP (0-3600).
+arg-admin_settings-curve_base-name = curve_base
+arg-admin_settings-curve_base-desc = XP needed for level 1 (1-100000).
+arg-admin_settings-curve_exponent-name = curve_exponent
+arg-admin_settings-curve_exponent-desc = How steeply levels get harder (1.0-3.0).
+arg-admin_settings-announce-name = announce
+arg-admin_settings-announce-desc = Announce level-ups.
+arg-admin_settings-channel-name = channel
+arg-admin_settings-channel-desc = Channel for level-up announcements.
+arg-admin_settings-reset_channel-name = reset_channel
+arg-admin_settings-reset_channel-desc = Announce in the channel the message was sent in.
+
+# admin/level/add_reward
+arg-admin_add_reward-level-name = level
+arg-admin_add_reward-level-desc = The level that unlocks the role.
+arg-admin_add_reward-role-name = role
+arg-admin_add_reward-role-desc = The role to give.
+
+# admin/level/remove_reward
+arg-admin_remove_reward-role-name = role
+arg-admin_remove_reward-role-desc = The role to remove from the rewards.
+
 # ai/image
 arg-image-description-name = description
 arg-image-description-desc = Enter a description of the image you want to generate.
@@ -287,6 +440,80 @@ arg-translation-video-desc = Upload video file (max. 25MB).
 arg-translation-lang-name = lang
 arg-translation-lang-desc = Select input language (ISO-639-1)
 
+# anilist/follow
+arg-anilist_follow-anime-name = anime
+arg-anilist_follow-anime-desc = The anime to follow.
+
+# anilist/franchise
+arg-anilist_franchise-media-name = media
+arg-anilist_franchise-media-desc = An anime or manga of the franchise.
+
+# anilist/notifications
+arg-anilist_notifications-auto_follow-name = auto_follow
+arg-anilist_notifications-auto_follow-desc = Follow the airing anime of your AniList watching list.
+arg-anilist_notifications-mute-name = mute
+arg-anilist_notifications-mute-desc = Stop every airing DM until you unmute them.
+arg-anilist_notifications-snooze-name = snooze
+arg-anilist_notifications-snooze-desc = Hours without airing DMs, 0 to end the snooze.
+
+# anilist/progress
+arg-anilist_progress-media-name = media
+arg-anilist_progress-media-desc = An anime or manga from your current list.
+arg-anilist_progress-amount-name = amount
+arg-anilist_progress-amount-desc = How many episodes or chapters to add, 1 by default. Negative to go back.
+
+# anilist/recommend
+arg-anilist_recommend-username-name = username
+arg-anilist_recommend-username-desc = Username of the user, yourself if empty.
+arg-anilist_recommend-type-name = type
+arg-anilist_recommend-type-desc = Anime or manga, anime if empty.
+arg-anilist_recommend-genre-name = genre
+arg-anilist_recommend-genre-desc = Only titles of this genre.
+arg-anilist_recommend-format-name = format
+arg-anilist_recommend-format-desc = Only titles of this format.
+arg-anilist_recommend-year-name = year
+arg-anilist_recommend-year-desc = Only titles released this year.
+
+# anilist/schedule
+arg-anilist_schedule-day-name = day
+arg-anilist_schedule-day-desc = The day, today if empty.
+arg-anilist_schedule-scope-name = scope
+arg-anilist_schedule-scope-desc = Every anime, those the server follows or those on your list.
+
+# anilist/score
+arg-anilist_score-media-name = media
+arg-anilist_score-media-desc = An anime or manga from your current list.
+arg-anilist_score-score-name = score
+arg-anilist_score-score-desc = The score, in the format set on your AniList profile.
+
+# anilist/season
+arg-anilist_season-year-name = year
+arg-anilist_season-year-desc = Year of the season, the current one if empty.
+arg-anilist_season-season-name = season
+arg-anilist_season-season-desc = The season, the current one if empty.
+arg-anilist_season-format-name = format
+arg-anilist_season-format-desc = Only anime of this format.
+arg-anilist_season-genre-name = genre
+arg-anilist_season-genre-desc = Only anime of this genre.
+arg-anilist_season-studio-name = studio
+arg-anilist_season-studio-desc = Only anime made by this studio.
+
+# anilist/status
+arg-anilist_status-media-name = media
+arg-anilist_status-media-desc = An anime or manga from your current list.
+arg-anilist_status-status-name = status
+arg-anilist_status-status-desc = The new status.
+
+# anilist/unfollow
+arg-anilist_unfollow-anime-name = anime
+arg-anilist_unfollow-anime-desc = An anime you follow.
+
+# anilist_server/anilist_leaderboard
+arg-anilist_leaderboard-stat-name = stat
+arg-anilist_leaderboard-stat-desc = What to rank by, the level by default.
+arg-anilist_leaderboard-page-name = page
+arg-anilist_leaderboard-page-desc = The page to start on.
+
 # anilist_user/anime
 arg-anime-anime_name-name = anime_name
 arg-anime-anime_name-desc = Name of the anime you want to check.
@@ -345,6 +572,22 @@ arg-random_image-image_type-desc = Type of the image you want.
 arg-random_himage-image_type-name = image_type
 arg-random_himage-image_type-desc = Type of the image you want.
 
+# levels/rank
+arg-levels_rank-username-name = username
+arg-levels_rank-username-desc = The member to show the rank card of.
+
+# levels/leaderboard
+arg-levels_leaderboard-page-name = page
+arg-levels_leaderboard-page-desc = The page to start on.
+
+# levels/heatmap
+arg-levels_heatmap-username-name = username
+arg-levels_heatmap-username-desc = The member to show the activity of.
+arg-levels_heatmap-server-name = server
+arg-levels_heatmap-server-desc = Show the activity of the whole server instead.
+arg-levels_heatmap-period-name = period
+arg-levels_heatmap-period-desc = How far back to look.
+
 # management/give_premium_sub
 arg-give_premium_sub-user-name = user
 arg-give_premium_sub-user-desc = The user to give the subscription to.
@@ -361,6 +604,56 @@ arg-kill_switch-state-desc = The state you want to to.
 arg-remove_test_sub-user-name = user
 arg-remove_test_sub-user-desc = The user to remove the subscription from.
 
+# minigame/collection
+arg-minigame_collection-user-name = user
+arg-minigame_collection-user-desc = The member whose collection to show, you by default.
+arg-minigame_collection-page-name = page
+arg-minigame_collection-page-desc = The page to start on.
+
+# minigame/equip
+arg-minigame_equip-gear-name = gear
+arg-minigame_equip-gear-desc = The rod or bait to equip.
+
+# minigame/gift
+arg-minigame_gift-user-name = user
+arg-minigame_gift-user-desc = The member to give to.
+arg-minigame_gift-item-name = item
+arg-minigame_gift-item-desc = The item to give.
+arg-minigame_gift-coins-name = coins
+arg-minigame_gift-coins-desc = How many coins to give.
+
+# minigame/guess_anime
+arg-minigame_guess_anime-difficulty-name = difficulty
+arg-minigame_guess_anime-difficulty-desc = How well known the anime is, normal by default.
+
+# minigame/anisong_quiz
+arg-minigame_anisong_quiz-rounds-name = rounds
+arg-minigame_anisong_quiz-rounds-desc = Number of songs to play, 5 by default and 10 at most.
+
+# minigame/leaderboard
+arg-minigame_leaderboard-game-name = game
+arg-minigame_leaderboard-game-desc = The minigame to rank, all of them by default.
+arg-minigame_leaderboard-scope-name = scope
+arg-minigame_leaderboard-scope-desc = This server or every server.
+arg-minigame_leaderboard-window-name = window
+arg-minigame_leaderboard-window-desc = This week or all time.
+arg-minigame_leaderboard-page-name = page
+arg-minigame_leaderboard-page-desc = The page to start on.
+
+# minigame/sell
+arg-minigame_sell-item-name = item
+arg-minigame_sell-item-desc = The item to sell.
+
+# minigame/shop
+arg-minigame_shop-buy-name = buy
+arg-minigame_shop-buy-desc = The item to buy.
+arg-minigame_shop-quantity-name = quantity
+arg-minigame_shop-quantity-desc = How many to buy (1-10).
+
+# minigame/trade
+arg-minigame_trade-user-name = user
+arg-minigame_trade-user-desc = The member to trade with.
+
 # music/play
 arg-play-search-name = search
 arg-play-search-desc = Search for a song.
@@ -395,6 +688,10 @@ arg-banner-username-desc = Username of the user you want the avatar of.
 arg-command_usage-username-name = username
 arg-command_usage-username-desc = Username of the user you want the usage of.
 
+# user/message_content
+arg-user_message_content-state-name = state
+arg-user_message_content-state-desc = Whether your message content may be stored.
+
 # user/profile
 arg-profile-username-name = username
 arg-profile-username-desc = Username of the user you want the avatar of.
@@ -430,6 +727,94 @@ choice-lang-lang_choice-es-ES-name = Spanish
 choice-lang-lang_choice-zh-CN-name = Chinese (Simplified)
 choice-lang-lang_choice-ru-name = Russian
 
+# anilist/status status choices
+choice-anilist_status-status-current-name = Current
+choice-anilist_status-status-planning-name = Planning
+choice-anilist_status-status-completed-name = Completed
+choice-anilist_status-status-dropped-name = Dropped
+choice-anilist_status-status-paused-name = Paused
+choice-anilist_status-status-repeating-name = Repeating
+
+# anilist/recommend type choices
+choice-anilist_recommend-type-anime-name = Anime
+choice-anilist_recommend-type-manga-name = Manga
+
+# anilist/recommend format choices
+choice-anilist_recommend-format-tv-name = TV
+choice-anilist_recommend-format-tv_short-name = TV short
+choice-anilist_recommend-format-movie-name = Movie
+choice-anilist_recommend-format-special-name = Special
+choice-anilist_recommend-format-ova-name = OVA
+choice-anilist_recommend-format-ona-name = ONA
+choice-anilist_recommend-format-manga-name = Manga
+choice-anilist_recommend-format-novel-name = Light novel
+choice-anilist_recommend-format-one_shot-name = One shot
+
+# anilist/schedule day choices
+choice-anilist_schedule-day-today-name = Today
+choice-anilist_schedule-day-tomorrow-name = Tomorrow
+choice-anilist_schedule-day-monday-name = Monday
+choice-anilist_schedule-day-tuesday-name = Tuesday
+choice-anilist_schedule-day-wednesday-name = Wednesday
+choice-anilist_schedule-day-thursday-name = Thursday
+choice-anilist_schedule-day-friday-name = Friday
+choice-anilist_schedule-day-saturday-name = Saturday
+choice-anilist_schedule-day-sunday-name = Sunday
+
+# anilist/schedule scope choices
+choice-anilist_schedule-scope-all-name = Every anime
+choice-anilist_schedule-scope-server-name = Followed by the server
+choice-anilist_schedule-scope-list-name = On my list
+
+# anilist/season season choices
+choice-anilist_season-season-winter-name = Winter
+choice-anilist_season-season-spring-name = Spring
+choice-anilist_season-season-summer-name = Summer
+choice-anilist_season-season-fall-name = Fall
+
+# anilist/season format choices
+choice-anilist_season-format-tv-name = TV
+choice-anilist_season-format-tv_short-name = TV short
+choice-anilist_season-format-movie-name = Movie
+choice-anilist_season-format-special-name = Special
+choice-anilist_season-format-ova-name = OVA
+choice-anilist_season-format-ona-name = ONA
+choice-anilist_season-format-music-name = Music
+
+# anilist_server/anilist_leaderboard stat choices
+choice-anilist_leaderboard-stat-level-name = Level
+choice-anilist_leaderboard-stat-minutes_watched-name = Time watched
+choice-anilist_leaderboard-stat-chapters_read-name = Chapters read
+choice-anilist_leaderboard-stat-mean_score-name = Mean score
+choice-anilist_leaderboard-stat-completed-name = Completed titles
+
+# levels/heatmap period choices
+choice-levels_heatmap-period-week-name = Last 7 days
+choice-levels_heatmap-period-month-name = Last 30 days
+choice-levels_heatmap-period-quarter-name = Last 90 days
+choice-levels_heatmap-period-year-name = Last 365 days
+
+# minigame/guess_anime difficulty choices
+choice-minigame_guess_anime-difficulty-easy-name = Easy
+choice-minigame_guess_anime-difficulty-normal-name = Normal
+choice-minigame_guess_anime-difficulty-hard-name = Hard
+
+# minigame/leaderboard game choices
+choice-minigame_leaderboard-game-all-name = All minigames
+choice-minigame_leaderboard-game-fishing-name = Fishing
+choice-minigame_leaderboard-game-gacha-name = Character collection
+choice-minigame_leaderboard-game-guess_anime-name = Guess the anime
+choice-minigame_leaderboard-game-anisong_quiz-name = Anisong quiz
+choice-minigame_leaderboard-game-daily-name = Daily rewards
+
+# minigame/leaderboard scope choices
+choice-minigame_leaderboard-scope-server-name = This server
+choice-minigame_leaderboard-scope-global-name = Every server
+
+# minigame/leaderboard window choices
+choice-minigame_leaderboard-window-weekly-name = This week
+choice-minigame_leaderboard-window-all_time-name = All time
+
 # admin/general/module choices
 choice-module-name-AI-name = AI
 choice-module-name-ANILIST-name = ANILIST
@@ -0,0 +1,2 @@
+level_up-desc = Congratulations { $user }, you reached **level { $level }**!
+level_up-title = Level Up!
@@ -0,0 +1,5 @@
+levels_heatmap-desc = Activity over the last { $days } days: { $messages } messages and { $hours }h { $minutes }m in voice.
+levels_heatmap-footer = Hours are shown in { $timezone }.
+levels_heatmap-message_retention = Messages are only kept for { $message_days } days, older ones are not shown.
+levels_heatmap-title_server = Server activity
+levels_heatmap-title_user = { $user }'s activity
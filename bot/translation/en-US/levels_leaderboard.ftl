@@ -0,0 +1,6 @@
+levels_leaderboard-empty = Nobody has earned XP in this server yet.
+levels_leaderboard-entry = **#{ $rank }** { $user } — Level { $level } ({ $xp } XP)
+levels_leaderboard-next = Next
+levels_leaderboard-page = Page { $page }/{ $total }
+levels_leaderboard-previous = Previous
+levels_leaderboard-title = Server Leaderboard
@@ -0,0 +1,4 @@
+levels_rank-desc = **Rank:** #{ $rank }
+    **Level:** { $level }
+    **Total XP:** { $xp }
+levels_rank-title = { $user }'s rank card
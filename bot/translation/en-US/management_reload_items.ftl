@@ -0,0 +1,4 @@
+management_reload_items-failed = The item catalog was rejected, nothing was changed.
+management_reload_items-synced = Synced item catalog version { $version }: { $added } added, { $updated } updated, { $retired } retired, { $restored } restored.
+management_reload_items-title = Item catalog
+management_reload_items-up_to_date = The item catalog (version { $version }) is already up to date.
@@ -0,0 +1,2 @@
+minigame-disabled_desc = The minigame module is disabled on this server.
+minigame-disabled_title = Minigames disabled
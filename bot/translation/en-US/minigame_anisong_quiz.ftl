@@ -0,0 +1,23 @@
+minigame_anisong_quiz-already_running = A quiz is already running in this server, wait for it to end.
+minigame_anisong_quiz-anime = Anime
+minigame_anisong_quiz-answer = Answer
+minigame_anisong_quiz-answer_label = Anime title
+minigame_anisong_quiz-answer_placeholder = Romaji, English or any known title
+minigame_anisong_quiz-closed = This round is over.
+minigame_anisong_quiz-correct = Correct! You earned { $points } points.
+minigame_anisong_quiz-music_playing = Music is playing in the voice channel, stop it before starting a quiz.
+minigame_anisong_quiz-no_song = Could not find a song to play, the quiz ends here.
+minigame_anisong_quiz-not_starter = Only whoever started the quiz can stop it.
+minigame_anisong_quiz-round_description = Which anime is this song from? You have { $seconds } seconds, quicker answers score more.
+minigame_anisong_quiz-round_title = Anisong quiz · Round { $round }/{ $rounds }
+minigame_anisong_quiz-solved = { $user } found it!
+minigame_anisong_quiz-song = Song
+minigame_anisong_quiz-standing = **#{ $rank }** { $user } — { $points } points
+minigame_anisong_quiz-standings_empty = Nobody scored this time.
+minigame_anisong_quiz-standings_title = Anisong quiz results
+minigame_anisong_quiz-starting = The quiz starts with { $rounds } songs, listen closely!
+minigame_anisong_quiz-stop = Stop the quiz
+minigame_anisong_quiz-stopped = The quiz has been stopped.
+minigame_anisong_quiz-title = Anisong quiz
+minigame_anisong_quiz-unsolved = Nobody found it this time.
+minigame_anisong_quiz-wrong = “{ $guess }” is not it, keep trying!
@@ -0,0 +1,9 @@
+minigame_balance-desc = You have **{ $balance }** coins.
+minigame_balance-history = Recent transactions
+minigame_balance-no_history = No transactions yet.
+minigame_balance-reason_gacha_roll = Character roll
+minigame_balance-reason_gift = Gift
+minigame_balance-reason_sale = Sale
+minigame_balance-reason_shop_purchase = Shop purchase
+minigame_balance-reason_trade = Trade
+minigame_balance-title = Balance
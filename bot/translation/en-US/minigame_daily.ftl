@@ -0,0 +1,11 @@
+minigame_daily-already_claimed = You already claimed today's reward, your streak is at { $streak } days. The next one is available { $next }.
+minigame_daily-coins = You received **{ $coins }** coins, your balance is now { $balance }.
+minigame_daily-item = You found **{ $name }** { $rarity }
+minigame_daily-next = Next reward { $next }. Claim it before { $deadline } to keep your streak.
+minigame_daily-next_field = Next claim
+minigame_daily-next_tier = From day { $day }, the reward rises to { $coins } coins.
+minigame_daily-streak = **{ $streak }** days in a row (best: { $best })
+minigame_daily-streak_field = Streak
+minigame_daily-streak_grace = You were late, but the grace window kept your streak alive.
+minigame_daily-streak_lost = Your { $previous } day streak was lost, a new one begins.
+minigame_daily-title = Daily reward
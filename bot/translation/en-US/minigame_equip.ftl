@@ -0,0 +1,6 @@
+minigame_equip-equipped = Equipped **{ $name }** as your { $slot }. { $uses } uses left on the current one, { $owned } owned.
+minigame_equip-not_owned = You don't own that gear.
+minigame_equip-slot_bait = bait
+minigame_equip-slot_rod = rod
+minigame_equip-title = Fishing Gear
+minigame_equip-unequipped = Your { $slot } slot is now empty.
@@ -1,6 +1,9 @@
 minigame_fishing-average = average
 minigame_fishing-caught_fish = You caught a fish!
 minigame_fishing-common = Common
+minigame_fishing-conditions = { $time_of_day }, { $season }
+minigame_fishing-conditions_field = Conditions
+minigame_fishing-cooldown = Your line is still drying. You can cast again { $time }.
 minigame_fishing-description_field = Description
 minigame_fishing-epic = Epic
 minigame_fishing-fish_details = Fish Details
@@ -10,14 +13,28 @@ minigame_fishing-fish_details_format =
     {"**"}Rarity:** { $rarity }
     {"**"}XP Boost:** { $xp_boost }%
     {"**"}Value:** { $price } coins
+minigame_fishing-gear_broken = **{ $name }** broke!
+minigame_fishing-gear_entry = **{ $name }**: { $uses } uses left
+minigame_fishing-gear_field = Gear
 minigame_fishing-huge = huge
 minigame_fishing-large = large
 minigame_fishing-legendary = Legendary
 minigame_fishing-massive = massive
+minigame_fishing-no_gear = No gear equipped
+minigame_fishing-points = Leaderboard: **+{ $points } points**.
 minigame_fishing-rare = Rare
+minigame_fishing-season_autumn = Autumn
+minigame_fishing-season_spring = Spring
+minigame_fishing-season_summer = Summer
+minigame_fishing-season_winter = Winter
 minigame_fishing-small = small
+minigame_fishing-time_dawn = Dawn
+minigame_fishing-time_day = Day
+minigame_fishing-time_dusk = Dusk
+minigame_fishing-time_night = Night
 minigame_fishing-tiny = tiny
 minigame_fishing-title = Fishing Results
 minigame_fishing-uncommon = Uncommon
 minigame_fishing-unknown = Unknown
-minigame_fishing-unknown_size = unknown
\ No newline at end of file
+minigame_fishing-unknown_size = unknown
+minigame_fishing-xp_gained = You earned **{ $xp } XP**.
\ No newline at end of file
@@ -0,0 +1,18 @@
+minigame_gacha-card_gone = This card is no longer in that collection.
+minigame_gacha-collection_empty = No characters yet, roll one to start a collection.
+minigame_gacha-collection_page = Page { $page }/{ $total } · { $count } cards
+minigame_gacha-collection_title = { $user }'s collection
+minigame_gacha-duplicate = Already in your collection, no points this time.
+minigame_gacha-favourites = Favourites
+minigame_gacha-free_roll = Free roll used, the next one is available { $next }.
+minigame_gacha-insufficient_funds = Your free roll comes back { $next }. Another roll costs { $cost } coins and you have { $balance }.
+minigame_gacha-new_card = New character added to your collection!
+minigame_gacha-next = Next
+minigame_gacha-owned_by = Owned by { $user } since { $obtained }
+minigame_gacha-paid_roll = Paid { $cost } coins for this roll.
+minigame_gacha-pool_empty = The character pool is still being filled, try again later.
+minigame_gacha-previous = Previous
+minigame_gacha-rarity = Rarity
+minigame_gacha-roll_title = Character roll
+minigame_gacha-series = Series
+minigame_gacha-view_card = View a character
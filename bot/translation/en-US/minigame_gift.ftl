@@ -0,0 +1,8 @@
+minigame_gift-coins = You gave { $coins } coins to { $user }.
+minigame_gift-insufficient_funds = You only have { $balance } coins.
+minigame_gift-invalid_coins = You cannot give a negative amount of coins.
+minigame_gift-invalid_recipient = You can only give to another member who is not a bot.
+minigame_gift-items = You gave **{ $count }x { $name }** to { $user }.
+minigame_gift-nothing = That item is no longer in your inventory, or it is on offer in a trade.
+minigame_gift-nothing_chosen = Choose an item, some coins, or both.
+minigame_gift-title = Gift
@@ -0,0 +1,19 @@
+minigame_guess_anime-already_running = A round is already running in this channel, solve it or give up first.
+minigame_guess_anime-answer = Guess
+minigame_guess_anime-answer_label = Anime title
+minigame_guess_anime-answer_placeholder = Romaji, English or any known title
+minigame_guess_anime-description = Which anime is this? Difficulty: { $difficulty }
+    Worth { $points } points · Stage { $stage }/{ $total }
+minigame_guess_anime-difficulty_easy = easy
+minigame_guess_anime-difficulty_hard = hard
+minigame_guess_anime-difficulty_normal = normal
+minigame_guess_anime-fully_revealed = The image is already fully revealed.
+minigame_guess_anime-give_up = Give up
+minigame_guess_anime-no_anime = Could not find an anime to guess, try again.
+minigame_guess_anime-not_starter = Only whoever started the round can give up.
+minigame_guess_anime-over = This round is over.
+minigame_guess_anime-reveal = Reveal more
+minigame_guess_anime-solved = { $user } found it and earned { $points } points!
+minigame_guess_anime-title = Guess the anime
+minigame_guess_anime-unsolved = Nobody found it this time.
+minigame_guess_anime-wrong = “{ $guess }” is not it, keep trying!
@@ -0,0 +1,18 @@
+minigame_leaderboard-empty = Nobody has scored yet.
+minigame_leaderboard-entry = **#{ $rank }** { $user } — { $points } points
+minigame_leaderboard-game_all = Minigame
+minigame_leaderboard-game_anisong_quiz = Anisong quiz
+minigame_leaderboard-game_daily = Daily rewards
+minigame_leaderboard-game_fishing = Fishing
+minigame_leaderboard-game_gacha = Character collection
+minigame_leaderboard-game_guess_anime = Guess the anime
+minigame_leaderboard-next = Next
+minigame_leaderboard-page = Page { $page }/{ $total }
+minigame_leaderboard-position = You are #{ $rank } with { $points } points
+minigame_leaderboard-previous = Previous
+minigame_leaderboard-scope_global = global
+minigame_leaderboard-scope_server = server
+minigame_leaderboard-title = { $game } leaderboard ({ $scope }, { $window })
+minigame_leaderboard-unranked = You are not ranked yet
+minigame_leaderboard-window_all_time = all time
+minigame_leaderboard-window_weekly = this week
@@ -0,0 +1,3 @@
+minigame_sell-nothing = That item is no longer in your inventory.
+minigame_sell-sold = You sold **{ $count }x { $name }** for { $earned } coins. You now have { $balance } coins.
+minigame_sell-title = Sale
@@ -0,0 +1,7 @@
+minigame_shop-bought = You bought **{ $quantity }x { $name }** for { $total } coins. You now have { $balance } coins.
+minigame_shop-desc = You have **{ $balance }** coins. Use the `buy` option to purchase an item.
+minigame_shop-empty = The shop has nothing for sale right now.
+minigame_shop-entry = **{ $name }** — { $price } coins
+minigame_shop-insufficient = **{ $name }** costs { $total } coins but you only have { $balance }.
+minigame_shop-not_for_sale = This item is not for sale.
+minigame_shop-title = Shop
@@ -0,0 +1,28 @@
+minigame_trade-cancel = Cancel
+minigame_trade-cancelled = This trade was cancelled.
+minigame_trade-change_offer = Change my offer
+minigame_trade-closed = This trade is closed.
+minigame_trade-coins = { $coins } coins
+minigame_trade-completed = Trade completed! Everything on offer has changed hands.
+minigame_trade-confirm = Confirm
+minigame_trade-confirmed = confirmed
+minigame_trade-empty_offer = Nothing offered yet.
+minigame_trade-expired = This trade expired.
+minigame_trade-failed = The trade could not be completed because something on offer is gone or a wallet ran short. Review the offers and confirm again.
+minigame_trade-insufficient_funds = You only have { $balance } coins.
+minigame_trade-invalid_partner = You can only trade with another member who is not a bot.
+minigame_trade-missing = { $name } (no longer available)
+minigame_trade-not_participant = Only the two members of this trade can use it.
+minigame_trade-offer_desc =
+    Pick the items you offer below and set your coins with the buttons.
+    Coins offered: **{ $coins }**
+minigame_trade-offer_header = **{ $user }** — { $state }
+minigame_trade-offer_title = Your offer
+minigame_trade-offer_updated = Offer updated.
+minigame_trade-open = Both members add items or coins, then both confirm. Any change clears the confirmations. Expires { $expires }.
+minigame_trade-pending = not confirmed
+minigame_trade-pick_cards = Characters to offer
+minigame_trade-pick_items = Items to offer
+minigame_trade-reset_coins = Reset coins
+minigame_trade-stale = The offer changed since you looked at it, check it again before confirming.
+minigame_trade-title = Trade
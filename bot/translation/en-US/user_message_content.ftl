@@ -0,0 +1,3 @@
+user_message_content-off = The content of your messages will not be stored, and what was stored before has been removed. Only message length, channel and time are kept.
+user_message_content-on = The content of your messages will be stored on servers that allow it.
+user_message_content-title = Message content
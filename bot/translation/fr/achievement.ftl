@@ -0,0 +1,3 @@
+achievement-unlocked = { $user } a débloqué { $icon } **{ $name }**
+    { $description }
+achievement-unlocked_title = Succès débloqué !
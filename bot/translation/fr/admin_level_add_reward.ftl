@@ -0,0 +1,2 @@
+admin_level_add_reward-desc = { $role } sera donné aux membres atteignant le niveau { $level }.
+admin_level_add_reward-title = Récompense de niveau ajoutée
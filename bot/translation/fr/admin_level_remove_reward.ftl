@@ -0,0 +1,3 @@
+admin_level_remove_reward-desc = { $role } n'est plus une récompense de niveau.
+admin_level_remove_reward-not_found = { $role } n'est pas une récompense de niveau sur ce serveur.
+admin_level_remove_reward-title = Récompense de niveau retirée
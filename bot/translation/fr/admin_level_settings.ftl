@@ -0,0 +1,16 @@
+admin_level_settings-announce = Annonces de niveau
+admin_level_settings-channel = Salon d'annonce
+admin_level_settings-channel_default = Salon du message
+admin_level_settings-cooldown = Délai entre messages
+admin_level_settings-cooldown_value = { $seconds }s
+admin_level_settings-curve = Courbe de niveau
+admin_level_settings-curve_value = { $base } × niveau^{ $exponent }
+admin_level_settings-message_xp = XP par message
+admin_level_settings-module_off = Le module LEVEL est désactivé sur ce serveur, aucune XP ne sera gagnée tant qu'il n'est pas activé avec `/admin general module`.
+admin_level_settings-no_rewards = Aucun rôle de récompense configuré.
+admin_level_settings-off = Désactivées
+admin_level_settings-on = Activées
+admin_level_settings-reward = Niveau { $level } : { $role }
+admin_level_settings-rewards = Rôles de récompense
+admin_level_settings-title = Paramètres des niveaux
+admin_level_settings-voice_xp = XP par minute en vocal
@@ -0,0 +1,3 @@
+admin_server_anilist_verified-title = Comptes AniList vérifiés
+admin_server_anilist_verified-on = Les listes du serveur n'affichent plus que les comptes AniList vérifiés avec `/verify`.
+admin_server_anilist_verified-off = Les listes du serveur affichent tous les comptes AniList enregistrés, les comptes vérifiés sont marqués d'un ✓.
@@ -0,0 +1,3 @@
+admin_server_message_content-off = Le contenu des messages ne sera plus enregistré sur ce serveur. Le contenu déjà enregistré a été supprimé ; seuls la longueur, le salon et l'heure des messages sont conservés.
+admin_server_message_content-on = Le contenu des messages sera enregistré pour les membres qui l'ont accepté avec `/user message_content`.
+admin_server_message_content-title = Contenu des messages
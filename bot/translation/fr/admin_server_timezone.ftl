@@ -0,0 +1,2 @@
+admin_server_timezone-desc = Le fuseau horaire du serveur est maintenant : { $timezone }
+admin_server_timezone-title = Fuseau horaire
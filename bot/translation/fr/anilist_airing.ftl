@@ -0,0 +1,24 @@
+anilist_airing-dm_desc = L'épisode { $episode } de { $anime } vient d'être diffusé.
+anilist_airing-unfollow_button = Ne plus suivre
+anilist_airing-snooze_button = Pause { $hours } h
+anilist_airing-mute_button = Tout couper
+anilist_airing-following = Vous recevrez un MP à la diffusion de l'épisode { $episode } { $time }. Vérifiez que le bot peut vous envoyer des messages privés.
+anilist_airing-not_airing = Cet anime n'a aucun épisode à venir à suivre.
+anilist_airing-unfollow_title = MP de diffusion
+anilist_airing-unfollowed = Vous ne recevrez plus de MP pour cet anime.
+anilist_airing-not_following = Vous ne suivez pas cet anime.
+anilist_airing-snoozed = Les MP de diffusion sont en pause, ils reprennent { $time }.
+anilist_airing-muted = Les MP de diffusion sont coupés, réactivez-les avec `anilist notifications`.
+anilist_airing-settings_title = Notifications de diffusion
+anilist_airing-state = MP
+anilist_airing-state_on = Activés
+anilist_airing-state_muted = Coupés
+anilist_airing-state_snoozed = En pause jusqu'à { $time }
+anilist_airing-auto_follow = Suivi automatique
+anilist_airing-auto_follow_on = Liste en cours suivie
+anilist_airing-auto_follow_off = Désactivé
+anilist_airing-none_followed = Vous ne suivez aucun anime. Utilisez `anilist follow` ou activez le suivi automatique.
+anilist_airing-next = épisode { $episode } { $time }
+anilist_airing-no_next = aucun épisode prévu
+anilist_airing-from_list = depuis votre liste
+anilist_airing-more = …et { $count } de plus
@@ -0,0 +1,6 @@
+anilist_franchise-title = Franchise
+anilist_franchise-title_media = Franchise { $title }, { $count } titres
+anilist_franchise-not_found = Aucun anime ou manga n'a été trouvé avec ce nom.
+anilist_franchise-truncated = La franchise est trop grande, seuls les { $count } titres les plus proches sont affichés.
+anilist_franchise-cycles = Certaines relations bouclent, ces titres sont classés par date de sortie.
+anilist_franchise-more = …et { $count } de plus sur le graphe
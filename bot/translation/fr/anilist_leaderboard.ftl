@@ -0,0 +1,19 @@
+anilist_leaderboard-title = Classement AniList : { $stat }
+anilist_leaderboard-stat_level = Niveau
+anilist_leaderboard-stat_minutes_watched = Temps de visionnage
+anilist_leaderboard-stat_chapters_read = Chapitres lus
+anilist_leaderboard-stat_mean_score = Note moyenne
+anilist_leaderboard-stat_completed = Titres terminés
+anilist_leaderboard-entry = { $rank }. { $user } [{ $name }](<{ $url }>) · { $value }
+anilist_leaderboard-value_level = niveau { $level } ({ $xp } XP)
+anilist_leaderboard-value_minutes_watched = { $days } jours
+anilist_leaderboard-value_chapters_read = { $value } chapitres
+anilist_leaderboard-value_mean_score = { $value }/100
+anilist_leaderboard-value_completed = { $value } titres
+anilist_leaderboard-empty = Aucun membre de ce serveur n'est encore classé. Les comptes enregistrés sont pris en compte à la prochaine mise à jour des statistiques, toutes les quelques heures.
+anilist_leaderboard-updated = Statistiques mises à jour { $time }.
+anilist_leaderboard-page = Page { $page }/{ $total }
+anilist_leaderboard-position = Vous êtes n°{ $rank }
+anilist_leaderboard-unranked = Vous n'êtes pas classé
+anilist_leaderboard-previous = Précédent
+anilist_leaderboard-next = Suivant
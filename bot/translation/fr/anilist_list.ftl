@@ -0,0 +1,16 @@
+anilist_list-relink_title = Connexion AniList nécessaire
+anilist_list-not_linked = Connectez-vous sur AniList pour mettre à jour votre liste depuis Discord. Le bouton ci-dessous vous donne un lien de connexion personnel.
+anilist_list-expired = Votre connexion AniList a expiré ou a été révoquée. Reconnectez-vous avec le bouton ci-dessous pour continuer à mettre à jour votre liste.
+anilist_list-not_found_title = Absent de votre liste
+anilist_list-not_found = Rien dans votre liste en cours ne correspond à « { $media } ». Choisissez une entrée parmi les suggestions.
+anilist_list-progress = Progression
+anilist_list-status = Statut
+anilist_list-score = Note
+anilist_list-status_current = En cours
+anilist_list-status_planning = Prévu
+anilist_list-status_completed = Terminé
+anilist_list-status_dropped = Abandonné
+anilist_list-status_paused = En pause
+anilist_list-status_repeating = En revisionnage
+anilist_list-episode_done = { $anime } est déjà à { $progress } dans votre liste.
+anilist_list-episode_saved = Marqué comme vu, { $anime } est maintenant à { $progress } dans votre liste.
@@ -0,0 +1 @@
+anilist_progress-desc = Progression passée de { $previous } à { $progress }.
@@ -0,0 +1,6 @@
+anilist_recommend-title = Recommandations
+anilist_recommend-title_user = Recommandations pour { $user }
+anilist_recommend-no_user = Donnez un nom d'utilisateur ou enregistrez votre compte AniList avec la commande `register`.
+anilist_recommend-nothing = Rien à recommander. Les recommandations viennent des titres terminés notés 7/10 ou plus, essayez sans filtres ou notez plus de titres.
+anilist_recommend-because = Parce que vous avez noté { $title } { $score }/10
+anilist_recommend-tags = {" "}et aimez { $tags }
@@ -0,0 +1,12 @@
+anilist_schedule-title = Programme du { $date }
+anilist_schedule-scope_all = Tous les animes diffusés ce jour-là en { $timezone }.
+anilist_schedule-scope_server = Les animes suivis par ce serveur diffusés ce jour-là en { $timezone }.
+anilist_schedule-scope_list = Les animes de votre liste diffusés ce jour-là en { $timezone }.
+anilist_schedule-empty = Aucun épisode n'est diffusé ce jour-là.
+anilist_schedule-episode = épisode { $episode }
+anilist_schedule-episode_of = épisode { $episode }/{ $episodes }
+anilist_schedule-page = Page { $page }/{ $total }
+anilist_schedule-no_user_title = Programme de diffusion
+anilist_schedule-no_user = Enregistrez votre compte AniList avec `register` pour voir les épisodes de votre liste.
+anilist_schedule-previous = Précédent
+anilist_schedule-next = Suivant
@@ -0,0 +1,3 @@
+anilist_score-title = Note invalide
+anilist_score-invalid = { $score } ne correspond pas au format de note de votre profil AniList, utilisez une note entre 0 et { $max }.
+anilist_score-desc = Note fixée à { $score }.
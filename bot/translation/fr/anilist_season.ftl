@@ -0,0 +1,20 @@
+anilist_season-title = Animes { $season } { $year }
+anilist_season-season_winter = Hiver
+anilist_season-season_spring = Printemps
+anilist_season-season_summer = Été
+anilist_season-season_fall = Automne
+anilist_season-format_tv = TV
+anilist_season-format_tv_short = TV court
+anilist_season-format_movie = Film
+anilist_season-format_special = Spécial
+anilist_season-format_ova = OVA
+anilist_season-format_ona = ONA
+anilist_season-format_music = Musique
+anilist_season-filters = Filtres : { $filters }
+anilist_season-empty = Aucun anime de cette saison ne correspond à ces filtres.
+anilist_season-episodes = { $count } épisodes
+anilist_season-next = épisode { $episode } { $time }
+anilist_season-upcoming = pas encore diffusé
+anilist_season-page = Page { $page }/{ $total } · { $count } animes
+anilist_season-previous = Précédent
+anilist_season-next_page = Suivant
@@ -1,3 +1,5 @@
 anilist_server_list_register_user-next = Page suivante
 anilist_server_list_register_user-previous = Page précédente
 anilist_server_list_register_user-title = Liste des utilisateurs enregistrés.
+anilist_server_list_register_user-empty = Aucun membre de ce serveur n'a enregistré de compte AniList.
+anilist_server_list_register_user-page = Page { $page }/{ $total }
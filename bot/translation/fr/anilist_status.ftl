@@ -0,0 +1 @@
+anilist_status-desc = Statut changé en { $status }.
@@ -32,3 +32,27 @@ anilist_user_compare-tag_anime =
     { $var1 } préfère la balise { $var1a }, et { $var2 } préfère la balise { $var2a } pour les anime
 anilist_user_compare-tag_manga =
     { $var1 } préfère la balise { $var1a }, et { $var2 } préfère la balise { $var2a } pour les manga
+anilist_user_compare-correlation =
+    Corrélation des notes sur les titres communs : { $pearson } (Pearson), { $spearman } (Spearman)
+anilist_user_compare-disagreements =
+    Plus grands désaccords
+anilist_user_compare-disagreements_button =
+    Désaccords
+anilist_user_compare-for_first =
+    { $var2 } a adoré, { $var1 } ne l'a pas vu
+anilist_user_compare-for_second =
+    { $var1 } a adoré, { $var2 } ne l'a pas vu
+anilist_user_compare-list_title =
+    Comparaison des listes
+anilist_user_compare-next =
+    Suivant
+anilist_user_compare-no_correlation =
+    Pas assez de titres notés par les deux pour calculer une corrélation des notes
+anilist_user_compare-nothing =
+    Rien à afficher ici.
+anilist_user_compare-page =
+    Page { $page } sur { $total }
+anilist_user_compare-previous =
+    Précédent
+anilist_user_compare-shared =
+    { $var1 } et { $var2 } ont tous deux vu ou lu { $shared } titres, dont { $scored } notés par les deux
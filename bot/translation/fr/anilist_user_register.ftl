@@ -1 +1,4 @@
 anilist_user_register-desc = L'utilisateur { $user } (avec l'identifiant { $id }) a été lié au compte Anilist { $anilist }
+anilist_user_register-unverified = Ce lien n'est pas vérifié, utilisez `/verify` pour prouver que le compte est le vôtre.
+anilist_user_register-taken = Un autre membre a déjà enregistré ce compte AniList. S'il est à vous, utilisez `/verify` pour le récupérer.
+anilist_user_register-taken_verified = Ce compte AniList est vérifié par un autre membre. S'il est à vous, utilisez `/verify` pour le récupérer.
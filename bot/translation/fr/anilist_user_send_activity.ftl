@@ -1,2 +1,3 @@
 anilist_user_send_activity-desc = Épisode { $ep } de { $anime } vient de sortir.
 anilist_user_send_activity-title = Nouvel Épisode
+anilist_user_send_activity-episode_button = Épisode +1
@@ -0,0 +1,7 @@
+anilist_user_verify-title = Vérifier votre compte AniList
+anilist_user_verify-desc = N'importe qui peut enregistrer un nom d'utilisateur AniList. Connectez-vous sur AniList pour prouver que le compte est le vôtre ; un lien vérifié reprend le compte à quiconque l'avait enregistré avant et peut être exigé par les serveurs.
+anilist_user_verify-already = Votre compte Discord est déjà vérifié avec [ce compte AniList]({ $url }). Reconnectez-vous pour en lier un autre.
+anilist_user_verify-button = Obtenir mon lien de connexion
+anilist_user_verify-link = Voici votre lien de connexion personnel, il expire dans 15 minutes. Vous vous connecterez d'abord avec Discord, puis sur AniList.
+anilist_user_verify-open = Se connecter sur AniList
+anilist_user_verify-disabled = La liaison vérifiée avec AniList n'est pas activée sur ce bot.
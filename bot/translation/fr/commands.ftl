@@ -35,6 +35,9 @@ parent-levels-desc = Commande permettant d'obtenir le niveau de l'utilisateur et
 parent-minigame-name = mini-jeu
 parent-minigame-desc = Commandes pour jouer à des mini-jeux et gérer votre inventaire.
 
+parent-anilist-name = anilist
+parent-anilist-desc = Commandes utilisant votre liste AniList.
+
 parent-admin-name = admin
 parent-admin-desc = Configuration du bot réservée aux administrateurs.
 
@@ -46,6 +49,9 @@ group-admin-anilist-desc = Commandes pour le module AniList qui nécessitent des
 group-admin-general-name = general
 group-admin-general-desc = Commandes pour le module général qui nécessitent des autorisations d'administrateur.
 
+group-admin-level-name = level
+group-admin-level-desc = Commandes d'administration du module LEVEL.
+
 # ─── Commands ─────────────────────────────────────────────────────────────────
 
 # admin/anilist
@@ -59,9 +65,28 @@ cmd-delete_anime_activity-desc = Supprimer une activité anime.
 cmd-lang-name = lang
 cmd-lang-desc = La langue que vous souhaitez définir pour la réponse.
 
+cmd-admin_message_content-name = contenu_messages
+cmd-admin_message_content-desc = Autoriser l'enregistrement du contenu des messages des membres qui l'ont accepté.
+
+cmd-admin_anilist_verified-name = anilist_verifie
+cmd-admin_anilist_verified-desc = N'afficher que les comptes AniList vérifiés par la connexion AniList.
+
+cmd-admin_timezone-name = fuseau_horaire
+cmd-admin_timezone-desc = Définir le fuseau horaire du serveur.
+
 cmd-module-name = module
 cmd-module-desc = Activer ou désactiver un module.
 
+# admin/level
+cmd-admin_settings-name = parametres
+cmd-admin_settings-desc = Voir ou modifier les paramètres de niveaux.
+
+cmd-admin_add_reward-name = ajouter_recompense
+cmd-admin_add_reward-desc = Donner un rôle aux membres lorsqu'ils atteignent un niveau.
+
+cmd-admin_remove_reward-name = retirer_recompense
+cmd-admin_remove_reward-desc = Ne plus donner un rôle comme récompense de niveau.
+
 # ai
 cmd-image-name = image
 cmd-image-desc = Générer une image.
@@ -75,6 +100,37 @@ cmd-transcript-desc = Générer une transcription à partir d'une vidéo.
 cmd-translation-name = traduction
 cmd-translation-desc = Générer une traduction.
 
+# anilist
+cmd-anilist_follow-name = suivre
+cmd-anilist_follow-desc = Recevoir un MP à la diffusion de chaque épisode d'un anime.
+
+cmd-anilist_franchise-name = franchise
+cmd-anilist_franchise-desc = Obtenir l'ordre de visionnage et le graphe des relations d'une franchise.
+
+cmd-anilist_notifications-name = notifications
+cmd-anilist_notifications-desc = Configurer vos MP de diffusion et voir les animes suivis.
+
+cmd-anilist_progress-name = progression
+cmd-anilist_progress-desc = Ajouter des épisodes vus ou des chapitres lus à votre liste AniList.
+
+cmd-anilist_recommend-name = recommander
+cmd-anilist_recommend-desc = Obtenir des animes ou mangas choisis d'après les titres les mieux notés d'une liste.
+
+cmd-anilist_schedule-name = programme
+cmd-anilist_schedule-desc = Afficher les épisodes d'anime diffusés un jour donné.
+
+cmd-anilist_score-name = note
+cmd-anilist_score-desc = Noter un anime ou un manga de votre liste AniList.
+
+cmd-anilist_season-name = saison
+cmd-anilist_season-desc = Lister les animes d'une saison.
+
+cmd-anilist_status-name = statut
+cmd-anilist_status-desc = Changer le statut d'un anime ou d'un manga de votre liste AniList.
+
+cmd-anilist_unfollow-name = ne_plus_suivre
+cmd-anilist_unfollow-desc = Arrêter les MP de diffusion d'un anime.
+
 # anilist_server
 cmd-list_activity-name = liste_activites
 cmd-list_activity-desc = Obtenir la liste des activités enregistrées.
@@ -82,6 +138,9 @@ cmd-list_activity-desc = Obtenir la liste des activités enregistrées.
 cmd-list_user-name = liste_utilisateurs
 cmd-list_user-desc = Obtenir la liste des utilisateurs enregistrés.
 
+cmd-anilist_leaderboard-name = classement_anilist
+cmd-anilist_leaderboard-desc = Classer les membres du serveur selon leurs statistiques AniList.
+
 # anilist_user
 cmd-anime-name = anime
 cmd-anime-desc = Informations sur un anime.
@@ -107,6 +166,9 @@ cmd-random-desc = Obtenir un anime ou manga aléatoire.
 cmd-register-name = enregistrer
 cmd-register-desc = Enregistrez votre nom d'utilisateur sur AniList.
 
+cmd-verify-name = verifier
+cmd-verify-desc = Prouvez que vous possédez votre compte AniList en vous connectant sur AniList.
+
 cmd-seiyuu-name = seiyuu
 cmd-seiyuu-desc = Informations sur un seiyuu.
 
@@ -144,6 +206,15 @@ cmd-ping-desc = Obtenez le ping du bot (et l'id du shard).
 cmd-stats-name = statistiques
 cmd-stats-desc = Obtenir les statistiques de niveau.
 
+cmd-levels_rank-name = rang
+cmd-levels_rank-desc = Afficher la carte de rang d'un membre.
+
+cmd-levels_leaderboard-name = classement
+cmd-levels_leaderboard-desc = Afficher les membres les plus actifs du serveur.
+
+cmd-levels_heatmap-name = carte_activite
+cmd-levels_heatmap-desc = Afficher quand un membre ou le serveur est actif.
+
 # management
 cmd-give_premium_sub-name = give_premium_sub
 cmd-give_premium_sub-desc = Donner un abonnement premium à un utilisateur.
@@ -151,19 +222,58 @@ cmd-give_premium_sub-desc = Donner un abonnement premium à un utilisateur.
 cmd-kill_switch-name = kill_switch
 cmd-kill_switch-desc = Activer ou désactiver globalement un module
 
+cmd-reload_items-name = reload_items
+cmd-reload_items-desc = Valider et synchroniser le catalogue d'objets.
+
 cmd-remove_test_sub-name = remove_test_sub
 cmd-remove_test_sub-desc = Retirer les abonnements premium d'un utilisateur.
 
 # minigame
+cmd-minigame_balance-name = solde
+cmd-minigame_balance-desc = Consultez vos pièces et vos dernières transactions.
+
+cmd-minigame_collection-name = collection
+cmd-minigame_collection-desc = Afficher les personnages tirés par un membre.
+
+cmd-minigame_daily-name = quotidien
+cmd-minigame_daily-desc = Récupérer votre récompense quotidienne et prolonger votre série.
+
+cmd-minigame_equip-name = equiper
+cmd-minigame_equip-desc = Équipez une canne à pêche ou un appât.
+
 cmd-fish_inventory-name = inventaire_poisson
 cmd-fish_inventory-desc = Vérifiez votre inventaire de poissons.
 
 cmd-fishing-name = peche
 cmd-fishing-desc = Aller pêcher !
 
+cmd-minigame_gift-name = cadeau
+cmd-minigame_gift-desc = Donnez des objets ou des pièces à un autre membre.
+
+cmd-minigame_guess_anime-name = devine_anime
+cmd-minigame_guess_anime-desc = Lancer une manche de devine l'anime à partir de sa couverture.
+
+cmd-minigame_anisong_quiz-name = quiz_anisong
+cmd-minigame_anisong_quiz-desc = Lancer un quiz pour retrouver l'anime des chansons jouées dans votre salon vocal.
+
 cmd-inventory-name = inventaire
 cmd-inventory-desc = Vérifiez votre inventaire.
 
+cmd-minigame_leaderboard-name = classement
+cmd-minigame_leaderboard-desc = Afficher les meilleurs joueurs des mini-jeux.
+
+cmd-minigame_roll-name = tirage
+cmd-minigame_roll-desc = Tirer un personnage AniList au hasard pour votre collection.
+
+cmd-minigame_sell-name = vendre
+cmd-minigame_sell-desc = Vendez des objets de votre inventaire.
+
+cmd-minigame_shop-name = boutique
+cmd-minigame_shop-desc = Parcourez la boutique ou achetez un objet.
+
+cmd-minigame_trade-name = echange
+cmd-minigame_trade-desc = Échangez des objets et des pièces avec un autre membre.
+
 # music
 cmd-clear-name = vider
 cmd-clear-desc = Vider la file d'attente actuelle.
@@ -225,6 +335,9 @@ cmd-banner-desc = Obtenez la bannière.
 cmd-command_usage-name = utilisation_de_commande
 cmd-command_usage-desc = Affiche l'utilisation de chaque commande pour un utilisateur.
 
+cmd-user_message_content-name = contenu_messages
+cmd-user_message_content-desc = Choisir si le contenu de vos messages peut être enregistré.
+
 cmd-profile-name = profil
 cmd-profile-desc = Afficher le profil d'un utilisateur.
 
@@ -263,6 +376,46 @@ arg-module-name-desc = Le module dont vous voulez changer l'état.
 arg-module-state-name = statut
 arg-module-state-desc = L'état que vous voulez appliquer.
 
+# admin/general/message_content
+arg-admin_message_content-state-name = statut
+arg-admin_message_content-state-desc = Si le contenu des messages peut être enregistré.
+
+# admin/general/anilist_verified
+arg-admin_anilist_verified-state-name = statut
+arg-admin_anilist_verified-state-desc = N'afficher que les comptes vérifiés ou non.
+
+# admin/general/timezone
+arg-admin_timezone-timezone-name = fuseau
+arg-admin_timezone-timezone-desc = Le fuseau horaire, par exemple Europe/Paris.
+
+# admin/level/settings
+arg-admin_settings-message_xp-name = xp_message
+arg-admin_settings-message_xp-desc = XP gagné par message (0-1000).
+arg-admin_settings-voice_xp-name = xp_vocal
+arg-admin_settings-voice_xp-desc = XP gagné par minute en vocal (0-1000).
+arg-admin_settings-cooldown-name = delai
+arg-admin_settings-cooldown-desc = Secondes entre deux messages rapportant de l'XP (0-3600).
+arg-admin_settings-curve_base-name = base_courbe
+arg-admin_settings-curve_base-desc = XP nécessaire pour le niveau 1 (1-100000).
+arg-admin_settings-curve_exponent-name = exposant_courbe
+arg-admin_settings-curve_exponent-desc = La vitesse à laquelle les niveaux deviennent difficiles (1.0-3.0).
+arg-admin_settings-announce-name = annoncer
+arg-admin_settings-announce-desc = Annoncer les passages de niveau.
+arg-admin_settings-channel-name = salon
+arg-admin_settings-channel-desc = Salon des annonces de niveau.
+arg-admin_settings-reset_channel-name = reinitialiser_salon
+arg-admin_settings-reset_channel-desc = Annoncer dans le salon où le message a été envoyé.
+
+# admin/level/add_reward
+arg-admin_add_reward-level-name = niveau
+arg-admin_add_reward-level-desc = Le niveau qui débloque le rôle.
+arg-admin_add_reward-role-name = role
+arg-admin_add_reward-role-desc = Le rôle à donner.
+
+# admin/level/remove_reward
+arg-admin_remove_reward-role-name = role
+arg-admin_remove_reward-role-desc = Le rôle à retirer des récompenses.
+
 # ai/image
 arg-image-description-name = description
 arg-image-description-desc = Entrez une description de l'image que vous voulez générer.
@@ -287,6 +440,80 @@ arg-translation-video-desc = Télécharger le fichier vidéo (max. 25MB).
 arg-translation-lang-name = lang
 arg-translation-lang-desc = Sélectionnez la langue d'entrée (ISO-639-1)
 
+# anilist/follow
+arg-anilist_follow-anime-name = anime
+arg-anilist_follow-anime-desc = L'anime à suivre.
+
+# anilist/franchise
+arg-anilist_franchise-media-name = media
+arg-anilist_franchise-media-desc = Un anime ou manga de la franchise.
+
+# anilist/notifications
+arg-anilist_notifications-auto_follow-name = suivi_auto
+arg-anilist_notifications-auto_follow-desc = Suivre les animes en diffusion de votre liste AniList en cours.
+arg-anilist_notifications-mute-name = muet
+arg-anilist_notifications-mute-desc = Arrêter tous les MP de diffusion jusqu'à les réactiver.
+arg-anilist_notifications-snooze-name = pause
+arg-anilist_notifications-snooze-desc = Heures sans MP de diffusion, 0 pour terminer la pause.
+
+# anilist/progress
+arg-anilist_progress-media-name = media
+arg-anilist_progress-media-desc = Un anime ou un manga de votre liste en cours.
+arg-anilist_progress-amount-name = nombre
+arg-anilist_progress-amount-desc = Combien d'épisodes ou de chapitres ajouter, 1 par défaut. Négatif pour revenir en arrière.
+
+# anilist/recommend
+arg-anilist_recommend-username-name = utilisateur
+arg-anilist_recommend-username-desc = Nom de l'utilisateur, vous-même si vide.
+arg-anilist_recommend-type-name = type
+arg-anilist_recommend-type-desc = Anime ou manga, anime si vide.
+arg-anilist_recommend-genre-name = genre
+arg-anilist_recommend-genre-desc = Seulement les titres de ce genre.
+arg-anilist_recommend-format-name = format
+arg-anilist_recommend-format-desc = Seulement les titres de ce format.
+arg-anilist_recommend-year-name = annee
+arg-anilist_recommend-year-desc = Seulement les titres sortis cette année.
+
+# anilist/schedule
+arg-anilist_schedule-day-name = jour
+arg-anilist_schedule-day-desc = Le jour, aujourd'hui si vide.
+arg-anilist_schedule-scope-name = portee
+arg-anilist_schedule-scope-desc = Tous les animes, ceux suivis par le serveur ou ceux de votre liste.
+
+# anilist/score
+arg-anilist_score-media-name = media
+arg-anilist_score-media-desc = Un anime ou un manga de votre liste en cours.
+arg-anilist_score-score-name = note
+arg-anilist_score-score-desc = La note, dans le format choisi sur votre profil AniList.
+
+# anilist/season
+arg-anilist_season-year-name = annee
+arg-anilist_season-year-desc = Année de la saison, l'actuelle si vide.
+arg-anilist_season-season-name = saison
+arg-anilist_season-season-desc = La saison, l'actuelle si vide.
+arg-anilist_season-format-name = format
+arg-anilist_season-format-desc = Seulement les animes de ce format.
+arg-anilist_season-genre-name = genre
+arg-anilist_season-genre-desc = Seulement les animes de ce genre.
+arg-anilist_season-studio-name = studio
+arg-anilist_season-studio-desc = Seulement les animes de ce studio.
+
+# anilist/status
+arg-anilist_status-media-name = media
+arg-anilist_status-media-desc = Un anime ou un manga de votre liste en cours.
+arg-anilist_status-status-name = statut
+arg-anilist_status-status-desc = Le nouveau statut.
+
+# anilist/unfollow
+arg-anilist_unfollow-anime-name = anime
+arg-anilist_unfollow-anime-desc = Un anime que vous suivez.
+
+# anilist_server/anilist_leaderboard
+arg-anilist_leaderboard-stat-name = statistique
+arg-anilist_leaderboard-stat-desc = Ce qui sert au classement, le niveau par défaut.
+arg-anilist_leaderboard-page-name = page
+arg-anilist_leaderboard-page-desc = La page de départ.
+
 # anilist_user/anime
 arg-anime-anime_name-name = nom_anime
 arg-anime-anime_name-desc = Nom de l'anime que vous voulez vérifier.
@@ -345,6 +572,22 @@ arg-random_image-image_type-desc = Type de l'image que vous voulez.
 arg-random_himage-image_type-name = type_image
 arg-random_himage-image_type-desc = Type de l'image que vous voulez.
 
+# levels/rank
+arg-levels_rank-username-name = nom_dutilisateur
+arg-levels_rank-username-desc = Le membre dont afficher la carte de rang.
+
+# levels/leaderboard
+arg-levels_leaderboard-page-name = page
+arg-levels_leaderboard-page-desc = La page de départ.
+
+# levels/heatmap
+arg-levels_heatmap-username-name = utilisateur
+arg-levels_heatmap-username-desc = Le membre dont afficher l'activité.
+arg-levels_heatmap-server-name = serveur
+arg-levels_heatmap-server-desc = Afficher plutôt l'activité de tout le serveur.
+arg-levels_heatmap-period-name = periode
+arg-levels_heatmap-period-desc = Jusqu'où remonter dans le temps.
+
 # management/give_premium_sub
 arg-give_premium_sub-user-name = utilisateur
 arg-give_premium_sub-user-desc = L'utilisateur à qui donner l'abonnement.
@@ -361,6 +604,56 @@ arg-kill_switch-state-desc = L'état que vous voulez appliquer.
 arg-remove_test_sub-user-name = utilisateur
 arg-remove_test_sub-user-desc = L'utilisateur dont on veut retirer l'abonnement.
 
+# minigame/collection
+arg-minigame_collection-user-name = utilisateur
+arg-minigame_collection-user-desc = Le membre dont afficher la collection, vous par défaut.
+arg-minigame_collection-page-name = page
+arg-minigame_collection-page-desc = La page de départ.
+
+# minigame/equip
+arg-minigame_equip-gear-name = equipement
+arg-minigame_equip-gear-desc = La canne ou l'appât à équiper.
+
+# minigame/gift
+arg-minigame_gift-user-name = utilisateur
+arg-minigame_gift-user-desc = Le membre à qui donner.
+arg-minigame_gift-item-name = objet
+arg-minigame_gift-item-desc = L'objet à donner.
+arg-minigame_gift-coins-name = pieces
+arg-minigame_gift-coins-desc = Combien de pièces donner.
+
+# minigame/guess_anime
+arg-minigame_guess_anime-difficulty-name = difficulte
+arg-minigame_guess_anime-difficulty-desc = La notoriété de l'anime, normale par défaut.
+
+# minigame/anisong_quiz
+arg-minigame_anisong_quiz-rounds-name = manches
+arg-minigame_anisong_quiz-rounds-desc = Nombre de chansons à jouer, 5 par défaut et 10 au maximum.
+
+# minigame/leaderboard
+arg-minigame_leaderboard-game-name = jeu
+arg-minigame_leaderboard-game-desc = Le mini-jeu à classer, tous par défaut.
+arg-minigame_leaderboard-scope-name = portee
+arg-minigame_leaderboard-scope-desc = Ce serveur ou tous les serveurs.
+arg-minigame_leaderboard-window-name = periode
+arg-minigame_leaderboard-window-desc = Cette semaine ou depuis toujours.
+arg-minigame_leaderboard-page-name = page
+arg-minigame_leaderboard-page-desc = La page de départ.
+
+# minigame/sell
+arg-minigame_sell-item-name = objet
+arg-minigame_sell-item-desc = L'objet à vendre.
+
+# minigame/shop
+arg-minigame_shop-buy-name = acheter
+arg-minigame_shop-buy-desc = L'objet à acheter.
+arg-minigame_shop-quantity-name = quantite
+arg-minigame_shop-quantity-desc = Combien en acheter (1-10).
+
+# minigame/trade
+arg-minigame_trade-user-name = utilisateur
+arg-minigame_trade-user-desc = Le membre avec qui échanger.
+
 # music/play
 arg-play-search-name = recherche
 arg-play-search-desc = Rechercher une chanson.
@@ -395,6 +688,10 @@ arg-banner-username-desc = Nom d'utilisateur de l'utilisateur dont vous souhaite
 arg-command_usage-username-name = nom_dutilisateur
 arg-command_usage-username-desc = Nom d'utilisateur de l'utilisateur dont vous voulez l'utilisation.
 
+# user/message_content
+arg-user_message_content-state-name = statut
+arg-user_message_content-state-desc = Si le contenu de vos messages peut être enregistré.
+
 # user/profile
 arg-profile-username-name = nom_dutilisateur
 arg-profile-username-desc = Nom d'utilisateur de l'utilisateur dont vous voulez l'avatar.
@@ -430,6 +727,94 @@ choice-lang-lang_choice-es-ES-name = Espagnol
 choice-lang-lang_choice-zh-CN-name = Chinois (Simplifié)
 choice-lang-lang_choice-ru-name = Russe
 
+# anilist/status status choices
+choice-anilist_status-status-current-name = En cours
+choice-anilist_status-status-planning-name = Prévu
+choice-anilist_status-status-completed-name = Terminé
+choice-anilist_status-status-dropped-name = Abandonné
+choice-anilist_status-status-paused-name = En pause
+choice-anilist_status-status-repeating-name = En revisionnage
+
+# anilist/recommend type choices
+choice-anilist_recommend-type-anime-name = Anime
+choice-anilist_recommend-type-manga-name = Manga
+
+# anilist/recommend format choices
+choice-anilist_recommend-format-tv-name = TV
+choice-anilist_recommend-format-tv_short-name = TV court
+choice-anilist_recommend-format-movie-name = Film
+choice-anilist_recommend-format-special-name = Spécial
+choice-anilist_recommend-format-ova-name = OVA
+choice-anilist_recommend-format-ona-name = ONA
+choice-anilist_recommend-format-manga-name = Manga
+choice-anilist_recommend-format-novel-name = Light novel
+choice-anilist_recommend-format-one_shot-name = One shot
+
+# anilist/schedule day choices
+choice-anilist_schedule-day-today-name = Aujourd'hui
+choice-anilist_schedule-day-tomorrow-name = Demain
+choice-anilist_schedule-day-monday-name = Lundi
+choice-anilist_schedule-day-tuesday-name = Mardi
+choice-anilist_schedule-day-wednesday-name = Mercredi
+choice-anilist_schedule-day-thursday-name = Jeudi
+choice-anilist_schedule-day-friday-name = Vendredi
+choice-anilist_schedule-day-saturday-name = Samedi
+choice-anilist_schedule-day-sunday-name = Dimanche
+
+# anilist/schedule scope choices
+choice-anilist_schedule-scope-all-name = Tous les animes
+choice-anilist_schedule-scope-server-name = Suivis par le serveur
+choice-anilist_schedule-scope-list-name = Dans ma liste
+
+# anilist/season season choices
+choice-anilist_season-season-winter-name = Hiver
+choice-anilist_season-season-spring-name = Printemps
+choice-anilist_season-season-summer-name = Été
+choice-anilist_season-season-fall-name = Automne
+
+# anilist/season format choices
+choice-anilist_season-format-tv-name = TV
+choice-anilist_season-format-tv_short-name = TV court
+choice-anilist_season-format-movie-name = Film
+choice-anilist_season-format-special-name = Spécial
+choice-anilist_season-format-ova-name = OVA
+choice-anilist_season-format-ona-name = ONA
+choice-anilist_season-format-music-name = Musique
+
+# anilist_server/anilist_leaderboard stat choices
+choice-anilist_leaderboard-stat-level-name = Niveau
+choice-anilist_leaderboard-stat-minutes_watched-name = Temps de visionnage
+choice-anilist_leaderboard-stat-chapters_read-name = Chapitres lus
+choice-anilist_leaderboard-stat-mean_score-name = Note moyenne
+choice-anilist_leaderboard-stat-completed-name = Titres terminés
+
+# levels/heatmap period choices
+choice-levels_heatmap-period-week-name = 7 derniers jours
+choice-levels_heatmap-period-month-name = 30 derniers jours
+choice-levels_heatmap-period-quarter-name = 90 derniers jours
+choice-levels_heatmap-period-year-name = 365 derniers jours
+
+# minigame/guess_anime difficulty choices
+choice-minigame_guess_anime-difficulty-easy-name = Facile
+choice-minigame_guess_anime-difficulty-normal-name = Normale
+choice-minigame_guess_anime-difficulty-hard-name = Difficile
+
+# minigame/leaderboard game choices
+choice-minigame_leaderboard-game-all-name = Tous les mini-jeux
+choice-minigame_leaderboard-game-fishing-name = Pêche
+choice-minigame_leaderboard-game-gacha-name = Collection de personnages
+choice-minigame_leaderboard-game-guess_anime-name = Devine l'anime
+choice-minigame_leaderboard-game-anisong_quiz-name = Quiz anisong
+choice-minigame_leaderboard-game-daily-name = Récompenses quotidiennes
+
+# minigame/leaderboard scope choices
+choice-minigame_leaderboard-scope-server-name = Ce serveur
+choice-minigame_leaderboard-scope-global-name = Tous les serveurs
+
+# minigame/leaderboard window choices
+choice-minigame_leaderboard-window-weekly-name = Cette semaine
+choice-minigame_leaderboard-window-all_time-name = Depuis toujours
+
 # admin/general/module choices
 choice-module-name-AI-name = IA
 choice-module-name-ANILIST-name = ANILIST
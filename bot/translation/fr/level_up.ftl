@@ -0,0 +1,2 @@
+level_up-desc = Félicitations { $user }, tu as atteint le **niveau { $level }** !
+level_up-title = Niveau supérieur !
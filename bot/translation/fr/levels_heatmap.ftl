@@ -0,0 +1,5 @@
+levels_heatmap-desc = Activité des { $days } derniers jours : { $messages } messages et { $hours }h { $minutes }m en vocal.
+levels_heatmap-footer = Les heures sont affichées en { $timezone }.
+levels_heatmap-message_retention = Les messages ne sont conservés que { $message_days } jours, les plus anciens ne sont pas affichés.
+levels_heatmap-title_server = Activité du serveur
+levels_heatmap-title_user = Activité de { $user }
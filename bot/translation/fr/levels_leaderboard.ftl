@@ -0,0 +1,6 @@
+levels_leaderboard-empty = Personne n'a encore gagné d'XP sur ce serveur.
+levels_leaderboard-entry = **#{ $rank }** { $user } — Niveau { $level } ({ $xp } XP)
+levels_leaderboard-next = Suivant
+levels_leaderboard-page = Page { $page }/{ $total }
+levels_leaderboard-previous = Précédent
+levels_leaderboard-title = Classement du serveur
@@ -0,0 +1,4 @@
+levels_rank-desc = **Classement :** #{ $rank }
+    **Niveau :** { $level }
+    **XP total :** { $xp }
+levels_rank-title = Carte de rang de { $user }
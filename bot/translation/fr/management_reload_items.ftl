@@ -0,0 +1,4 @@
+management_reload_items-failed = Le catalogue d'objets a été refusé, rien n'a été modifié.
+management_reload_items-synced = Catalogue d'objets version { $version } synchronisé : { $added } ajoutés, { $updated } modifiés, { $retired } retirés, { $restored } restaurés.
+management_reload_items-title = Catalogue d'objets
+management_reload_items-up_to_date = Le catalogue d'objets (version { $version }) est déjà à jour.
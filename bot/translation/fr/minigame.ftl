@@ -0,0 +1,2 @@
+minigame-disabled_desc = Le module de mini-jeux est désactivé sur ce serveur.
+minigame-disabled_title = Mini-jeux désactivés
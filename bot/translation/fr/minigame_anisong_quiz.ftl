@@ -0,0 +1,23 @@
+minigame_anisong_quiz-already_running = Un quiz est déjà en cours sur ce serveur, attendez qu'il se termine.
+minigame_anisong_quiz-anime = Anime
+minigame_anisong_quiz-answer = Répondre
+minigame_anisong_quiz-answer_label = Titre de l'anime
+minigame_anisong_quiz-answer_placeholder = Romaji, anglais ou tout autre titre connu
+minigame_anisong_quiz-closed = Cette manche est terminée.
+minigame_anisong_quiz-correct = Bonne réponse ! Vous gagnez { $points } points.
+minigame_anisong_quiz-music_playing = De la musique est en cours dans le salon vocal, arrêtez-la avant de lancer un quiz.
+minigame_anisong_quiz-no_song = Impossible de trouver une chanson à jouer, le quiz s'arrête ici.
+minigame_anisong_quiz-not_starter = Seule la personne qui a lancé le quiz peut l'arrêter.
+minigame_anisong_quiz-round_description = De quel anime vient cette chanson ? Vous avez { $seconds } secondes, les réponses rapides rapportent plus.
+minigame_anisong_quiz-round_title = Quiz anisong · Manche { $round }/{ $rounds }
+minigame_anisong_quiz-solved = { $user } a trouvé !
+minigame_anisong_quiz-song = Chanson
+minigame_anisong_quiz-standing = **#{ $rank }** { $user } — { $points } points
+minigame_anisong_quiz-standings_empty = Personne n'a marqué de points cette fois.
+minigame_anisong_quiz-standings_title = Résultats du quiz anisong
+minigame_anisong_quiz-starting = Le quiz commence avec { $rounds } chansons, tendez l'oreille !
+minigame_anisong_quiz-stop = Arrêter le quiz
+minigame_anisong_quiz-stopped = Le quiz a été arrêté.
+minigame_anisong_quiz-title = Quiz anisong
+minigame_anisong_quiz-unsolved = Personne n'a trouvé cette fois.
+minigame_anisong_quiz-wrong = « { $guess } » n'est pas la bonne réponse, continuez !
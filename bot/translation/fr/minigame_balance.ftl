@@ -0,0 +1,9 @@
+minigame_balance-desc = Vous avez **{ $balance }** pièces.
+minigame_balance-history = Transactions récentes
+minigame_balance-no_history = Aucune transaction pour le moment.
+minigame_balance-reason_gacha_roll = Tirage de personnage
+minigame_balance-reason_gift = Cadeau
+minigame_balance-reason_sale = Vente
+minigame_balance-reason_shop_purchase = Achat en boutique
+minigame_balance-reason_trade = Échange
+minigame_balance-title = Solde
@@ -0,0 +1,11 @@
+minigame_daily-already_claimed = Vous avez déjà récupéré la récompense du jour, votre série est de { $streak } jours. La prochaine est disponible { $next }.
+minigame_daily-coins = Vous avez reçu **{ $coins }** pièces, votre solde est maintenant de { $balance }.
+minigame_daily-item = Vous avez trouvé **{ $name }** { $rarity }
+minigame_daily-next = Prochaine récompense { $next }. Récupérez-la avant { $deadline } pour garder votre série.
+minigame_daily-next_field = Prochaine récompense
+minigame_daily-next_tier = À partir du jour { $day }, la récompense passe à { $coins } pièces.
+minigame_daily-streak = **{ $streak }** jours d'affilée (record : { $best })
+minigame_daily-streak_field = Série
+minigame_daily-streak_grace = Vous étiez en retard, mais le délai de grâce a sauvé votre série.
+minigame_daily-streak_lost = Votre série de { $previous } jours est perdue, une nouvelle commence.
+minigame_daily-title = Récompense quotidienne
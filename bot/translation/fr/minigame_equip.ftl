@@ -0,0 +1,6 @@
+minigame_equip-equipped = **{ $name }** équipé comme { $slot }. { $uses } utilisations restantes sur l'actuel, { $owned } possédés.
+minigame_equip-not_owned = Vous ne possédez pas cet équipement.
+minigame_equip-slot_bait = appât
+minigame_equip-slot_rod = canne
+minigame_equip-title = Équipement de pêche
+minigame_equip-unequipped = Votre emplacement { $slot } est maintenant vide.
@@ -1,6 +1,9 @@
 minigame_fishing-average = moyen
 minigame_fishing-caught_fish = Vous avez attrapé un poisson !
 minigame_fishing-common = Commun
+minigame_fishing-conditions = { $time_of_day }, { $season }
+minigame_fishing-conditions_field = Conditions
+minigame_fishing-cooldown = Votre ligne sèche encore. Vous pourrez relancer { $time }.
 minigame_fishing-description_field = Description
 minigame_fishing-epic = Épique
 minigame_fishing-fish_details = Détails du Poisson
@@ -10,14 +13,28 @@ minigame_fishing-fish_details_format =
     {"**"}Rareté:** { $rarity }
     {"**"}Boost XP:** { $xp_boost }%
     {"**"}Valeur:** { $price } pièces
+minigame_fishing-gear_broken = **{ $name }** s'est cassé !
+minigame_fishing-gear_entry = **{ $name }** : { $uses } utilisations restantes
+minigame_fishing-gear_field = Équipement
 minigame_fishing-huge = énorme
 minigame_fishing-large = grand
 minigame_fishing-legendary = Légendaire
 minigame_fishing-massive = massif
+minigame_fishing-no_gear = Aucun équipement
+minigame_fishing-points = Classement : **+{ $points } points**.
 minigame_fishing-rare = Rare
+minigame_fishing-season_autumn = Automne
+minigame_fishing-season_spring = Printemps
+minigame_fishing-season_summer = Été
+minigame_fishing-season_winter = Hiver
 minigame_fishing-small = petit
+minigame_fishing-time_dawn = Aube
+minigame_fishing-time_day = Jour
+minigame_fishing-time_dusk = Crépuscule
+minigame_fishing-time_night = Nuit
 minigame_fishing-tiny = minuscule
 minigame_fishing-title = Résultats de Pêche
 minigame_fishing-uncommon = Peu commun
 minigame_fishing-unknown = Inconnu
 minigame_fishing-unknown_size = taille inconnue
+minigame_fishing-xp_gained = Vous avez gagné **{ $xp } XP**.
\ No newline at end of file
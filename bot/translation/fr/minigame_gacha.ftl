@@ -0,0 +1,18 @@
+minigame_gacha-card_gone = Cette carte n'est plus dans cette collection.
+minigame_gacha-collection_empty = Aucun personnage pour l'instant, faites un tirage pour commencer une collection.
+minigame_gacha-collection_page = Page { $page }/{ $total } · { $count } cartes
+minigame_gacha-collection_title = Collection de { $user }
+minigame_gacha-duplicate = Déjà dans votre collection, pas de points cette fois.
+minigame_gacha-favourites = Favoris
+minigame_gacha-free_roll = Tirage gratuit utilisé, le prochain sera disponible { $next }.
+minigame_gacha-insufficient_funds = Votre tirage gratuit revient { $next }. Un autre tirage coûte { $cost } pièces et vous en avez { $balance }.
+minigame_gacha-new_card = Nouveau personnage ajouté à votre collection !
+minigame_gacha-next = Suivant
+minigame_gacha-owned_by = Appartient à { $user } depuis le { $obtained }
+minigame_gacha-paid_roll = { $cost } pièces payées pour ce tirage.
+minigame_gacha-pool_empty = La liste de personnages est encore en cours de remplissage, réessayez plus tard.
+minigame_gacha-previous = Précédent
+minigame_gacha-rarity = Rareté
+minigame_gacha-roll_title = Tirage de personnage
+minigame_gacha-series = Série
+minigame_gacha-view_card = Voir un personnage
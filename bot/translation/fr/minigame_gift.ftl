@@ -0,0 +1,8 @@
+minigame_gift-coins = Vous avez donné { $coins } pièces à { $user }.
+minigame_gift-insufficient_funds = Vous n'avez que { $balance } pièces.
+minigame_gift-invalid_coins = Vous ne pouvez pas donner un nombre négatif de pièces.
+minigame_gift-invalid_recipient = Vous ne pouvez donner qu'à un autre membre qui n'est pas un bot.
+minigame_gift-items = Vous avez donné **{ $count }x { $name }** à { $user }.
+minigame_gift-nothing = Cet objet n'est plus dans votre inventaire, ou il est proposé dans un échange.
+minigame_gift-nothing_chosen = Choisissez un objet, des pièces, ou les deux.
+minigame_gift-title = Cadeau
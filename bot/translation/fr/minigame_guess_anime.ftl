@@ -0,0 +1,19 @@
+minigame_guess_anime-already_running = Une manche est déjà en cours dans ce salon, trouvez la réponse ou abandonnez d'abord.
+minigame_guess_anime-answer = Deviner
+minigame_guess_anime-answer_label = Titre de l'anime
+minigame_guess_anime-answer_placeholder = Romaji, anglais ou tout autre titre connu
+minigame_guess_anime-description = Quel est cet anime ? Difficulté : { $difficulty }
+    Vaut { $points } points · Étape { $stage }/{ $total }
+minigame_guess_anime-difficulty_easy = facile
+minigame_guess_anime-difficulty_hard = difficile
+minigame_guess_anime-difficulty_normal = normale
+minigame_guess_anime-fully_revealed = L'image est déjà entièrement révélée.
+minigame_guess_anime-give_up = Abandonner
+minigame_guess_anime-no_anime = Impossible de trouver un anime à deviner, réessayez.
+minigame_guess_anime-not_starter = Seule la personne qui a lancé la manche peut abandonner.
+minigame_guess_anime-over = Cette manche est terminée.
+minigame_guess_anime-reveal = Révéler plus
+minigame_guess_anime-solved = { $user } a trouvé et gagne { $points } points !
+minigame_guess_anime-title = Devine l'anime
+minigame_guess_anime-unsolved = Personne n'a trouvé cette fois.
+minigame_guess_anime-wrong = « { $guess } » n'est pas la bonne réponse, continuez !
@@ -0,0 +1,18 @@
+minigame_leaderboard-empty = Personne n'a encore marqué de points.
+minigame_leaderboard-entry = **#{ $rank }** { $user } — { $points } points
+minigame_leaderboard-game_all = Mini-jeux
+minigame_leaderboard-game_anisong_quiz = Quiz anisong
+minigame_leaderboard-game_daily = Récompenses quotidiennes
+minigame_leaderboard-game_fishing = Pêche
+minigame_leaderboard-game_gacha = Collection de personnages
+minigame_leaderboard-game_guess_anime = Devine l'anime
+minigame_leaderboard-next = Suivant
+minigame_leaderboard-page = Page { $page }/{ $total }
+minigame_leaderboard-position = Vous êtes #{ $rank } avec { $points } points
+minigame_leaderboard-previous = Précédent
+minigame_leaderboard-scope_global = global
+minigame_leaderboard-scope_server = serveur
+minigame_leaderboard-title = Classement { $game } ({ $scope }, { $window })
+minigame_leaderboard-unranked = Vous n'êtes pas encore classé
+minigame_leaderboard-window_all_time = depuis toujours
+minigame_leaderboard-window_weekly = cette semaine
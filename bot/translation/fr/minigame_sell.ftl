@@ -0,0 +1,3 @@
+minigame_sell-nothing = Cet objet n'est plus dans votre inventaire.
+minigame_sell-sold = Vous avez vendu **{ $count }x { $name }** pour { $earned } pièces. Vous avez maintenant { $balance } pièces.
+minigame_sell-title = Vente
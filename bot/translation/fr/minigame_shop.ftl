@@ -0,0 +1,7 @@
+minigame_shop-bought = Vous avez acheté **{ $quantity }x { $name }** pour { $total } pièces. Vous avez maintenant { $balance } pièces.
+minigame_shop-desc = Vous avez **{ $balance }** pièces. Utilisez l'option `acheter` pour acheter un objet.
+minigame_shop-empty = La boutique n'a rien à vendre pour le moment.
+minigame_shop-entry = **{ $name }** — { $price } pièces
+minigame_shop-insufficient = **{ $name }** coûte { $total } pièces mais vous n'en avez que { $balance }.
+minigame_shop-not_for_sale = Cet objet n'est pas à vendre.
+minigame_shop-title = Boutique
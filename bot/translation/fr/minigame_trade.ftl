@@ -0,0 +1,28 @@
+minigame_trade-cancel = Annuler
+minigame_trade-cancelled = Cet échange a été annulé.
+minigame_trade-change_offer = Modifier mon offre
+minigame_trade-closed = Cet échange est terminé.
+minigame_trade-coins = { $coins } pièces
+minigame_trade-completed = Échange conclu ! Tout ce qui était proposé a changé de mains.
+minigame_trade-confirm = Confirmer
+minigame_trade-confirmed = confirmé
+minigame_trade-empty_offer = Rien de proposé pour le moment.
+minigame_trade-expired = Cet échange a expiré.
+minigame_trade-failed = L'échange n'a pas pu être conclu car un élément proposé a disparu ou un portefeuille est insuffisant. Vérifiez les offres et confirmez à nouveau.
+minigame_trade-insufficient_funds = Vous n'avez que { $balance } pièces.
+minigame_trade-invalid_partner = Vous ne pouvez échanger qu'avec un autre membre qui n'est pas un bot.
+minigame_trade-missing = { $name } (n'est plus disponible)
+minigame_trade-not_participant = Seuls les deux membres de cet échange peuvent l'utiliser.
+minigame_trade-offer_desc =
+    Choisissez ci-dessous les objets que vous proposez et réglez vos pièces avec les boutons.
+    Pièces proposées : **{ $coins }**
+minigame_trade-offer_header = **{ $user }** — { $state }
+minigame_trade-offer_title = Votre offre
+minigame_trade-offer_updated = Offre mise à jour.
+minigame_trade-open = Les deux membres ajoutent des objets ou des pièces, puis confirment tous les deux. Toute modification annule les confirmations. Expire { $expires }.
+minigame_trade-pending = non confirmé
+minigame_trade-pick_cards = Personnages à proposer
+minigame_trade-pick_items = Objets à proposer
+minigame_trade-reset_coins = Remettre les pièces à zéro
+minigame_trade-stale = L'offre a changé depuis que vous l'avez consultée, vérifiez-la avant de confirmer.
+minigame_trade-title = Échange
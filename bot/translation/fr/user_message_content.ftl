@@ -0,0 +1,3 @@
+user_message_content-off = Le contenu de vos messages ne sera pas enregistré, et ce qui l'était a été supprimé. Seuls la longueur, le salon et l'heure des messages sont conservés.
+user_message_content-on = Le contenu de vos messages sera enregistré sur les serveurs qui l'autorisent.
+user_message_content-title = Contenu des messages
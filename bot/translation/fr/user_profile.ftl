@@ -1,8 +1,10 @@
+user_profile-achievements = Succès ({ $count }/{ $total })
 user_profile-bot = Est un bot
 user_profile-creation_date = Date de création du compte
 user_profile-id = ID de l'utilisateur
 user_profile-joined_date = Date d'adhésion
 user_profile-nitro = Type de Nitro
+user_profile-no_achievements = Aucun succès débloqué pour le moment.
 user_profile-premium = Premium
 user_profile-public_flag = Drapeau public
 user_profile-system = Est un système
@@ -0,0 +1,3 @@
+achievement-unlocked = { $user } が { $icon } **{ $name }** を解除しました
+    { $description }
+achievement-unlocked_title = 実績解除！
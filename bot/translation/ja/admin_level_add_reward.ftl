@@ -0,0 +1,2 @@
+admin_level_add_reward-desc = レベル { $level } に到達したメンバーに { $role } が付与されます。
+admin_level_add_reward-title = レベル報酬を追加しました
@@ -0,0 +1,3 @@
+admin_level_remove_reward-desc = { $role } はレベル報酬ではなくなりました。
+admin_level_remove_reward-not_found = { $role } はこのサーバーのレベル報酬ではありません。
+admin_level_remove_reward-title = レベル報酬を削除しました
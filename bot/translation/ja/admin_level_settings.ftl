@@ -0,0 +1,16 @@
+admin_level_settings-announce = レベルアップ通知
+admin_level_settings-channel = 通知チャンネル
+admin_level_settings-channel_default = メッセージのチャンネル
+admin_level_settings-cooldown = メッセージのクールダウン
+admin_level_settings-cooldown_value = { $seconds }秒
+admin_level_settings-curve = レベル曲線
+admin_level_settings-curve_value = { $base } × レベル^{ $exponent }
+admin_level_settings-message_xp = メッセージごとのXP
+admin_level_settings-module_off = このサーバーではLEVELモジュールが無効です。`/admin general module` で有効にするまでXPは獲得できません。
+admin_level_settings-no_rewards = 報酬ロールは設定されていません。
+admin_level_settings-off = 無効
+admin_level_settings-on = 有効
+admin_level_settings-reward = レベル { $level }: { $role }
+admin_level_settings-rewards = 報酬ロール
+admin_level_settings-title = レベル設定
+admin_level_settings-voice_xp = ボイス1分あたりのXP
@@ -0,0 +1,3 @@
+admin_server_anilist_verified-title = 認証済みAniListアカウント
+admin_server_anilist_verified-on = サーバーのリストには `/verify` で認証されたAniListアカウントのみ表示されます。
+admin_server_anilist_verified-off = サーバーのリストには登録されたすべてのAniListアカウントが表示され、認証済みのものには ✓ が付きます。
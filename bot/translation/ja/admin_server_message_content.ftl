@@ -0,0 +1,3 @@
+admin_server_message_content-off = このサーバーではメッセージ内容を保存しなくなりました。保存済みの内容は削除され、メッセージの長さ・チャンネル・時刻のみが保持されます。
+admin_server_message_content-on = `/user message_content` で同意したメンバーのメッセージ内容を保存します。
+admin_server_message_content-title = メッセージ内容
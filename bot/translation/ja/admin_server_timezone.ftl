@@ -0,0 +1,2 @@
+admin_server_timezone-desc = サーバーのタイムゾーンを次に設定しました：{ $timezone }
+admin_server_timezone-title = タイムゾーン
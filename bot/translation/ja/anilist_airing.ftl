@@ -0,0 +1,24 @@
+anilist_airing-dm_desc = { $anime }の第{ $episode }話が放送されました。
+anilist_airing-unfollow_button = フォロー解除
+anilist_airing-snooze_button = { $hours }時間スヌーズ
+anilist_airing-mute_button = すべてミュート
+anilist_airing-following = 第{ $episode }話の放送時（{ $time }）にDMが届きます。ボットからDMを受け取れるか確認してください。
+anilist_airing-not_airing = このアニメにはフォローできる今後のエピソードがありません。
+anilist_airing-unfollow_title = 放送DM
+anilist_airing-unfollowed = このアニメのDMは届かなくなります。
+anilist_airing-not_following = このアニメはフォローしていません。
+anilist_airing-snoozed = 放送DMはスヌーズ中です。{ $time }に再開します。
+anilist_airing-muted = 放送DMはミュート中です。`anilist notifications`で再開できます。
+anilist_airing-settings_title = 放送通知
+anilist_airing-state = DM
+anilist_airing-state_on = オン
+anilist_airing-state_muted = ミュート中
+anilist_airing-state_snoozed = { $time }までスヌーズ中
+anilist_airing-auto_follow = 自動フォロー
+anilist_airing-auto_follow_on = 視聴中リストをフォロー中
+anilist_airing-auto_follow_off = オフ
+anilist_airing-none_followed = フォロー中のアニメはありません。`anilist follow`を使うか、自動フォローをオンにしてください。
+anilist_airing-next = 第{ $episode }話 { $time }
+anilist_airing-no_next = 予定されたエピソードなし
+anilist_airing-from_list = リストから
+anilist_airing-more = …ほか{ $count }件
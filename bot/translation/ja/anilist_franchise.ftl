@@ -0,0 +1,6 @@
+anilist_franchise-title = フランチャイズ
+anilist_franchise-title_media = { $title }シリーズ（{ $count }作品）
+anilist_franchise-not_found = この名前のアニメまたはマンガは見つかりませんでした。
+anilist_franchise-truncated = シリーズが大きすぎるため、近い{ $count }作品のみ表示しています。
+anilist_franchise-cycles = 一部の関連がループしているため、それらの作品は公開日順に並べています。
+anilist_franchise-more = …ほか{ $count }作品はグラフに表示
@@ -0,0 +1,19 @@
+anilist_leaderboard-title = AniListランキング：{ $stat }
+anilist_leaderboard-stat_level = レベル
+anilist_leaderboard-stat_minutes_watched = 視聴時間
+anilist_leaderboard-stat_chapters_read = 読んだ章数
+anilist_leaderboard-stat_mean_score = 平均スコア
+anilist_leaderboard-stat_completed = 完了した作品
+anilist_leaderboard-entry = { $rank }. { $user } [{ $name }](<{ $url }>) · { $value }
+anilist_leaderboard-value_level = レベル{ $level }（{ $xp } XP）
+anilist_leaderboard-value_minutes_watched = { $days }日
+anilist_leaderboard-value_chapters_read = { $value }章
+anilist_leaderboard-value_mean_score = { $value }/100
+anilist_leaderboard-value_completed = { $value }作品
+anilist_leaderboard-empty = このサーバーでランク付けされたメンバーはまだいません。登録されたアカウントは、数時間ごとの次の統計更新で反映されます。
+anilist_leaderboard-updated = 統計の更新：{ $time }
+anilist_leaderboard-page = ページ { $page }/{ $total }
+anilist_leaderboard-position = あなたは{ $rank }位です
+anilist_leaderboard-unranked = あなたはランク外です
+anilist_leaderboard-previous = 前へ
+anilist_leaderboard-next = 次へ
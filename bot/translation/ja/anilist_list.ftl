@@ -0,0 +1,16 @@
+anilist_list-relink_title = AniListへのログインが必要です
+anilist_list-not_linked = Discordからリストを更新するにはAniListにログインしてください。下のボタンから専用のログインリンクを受け取れます。
+anilist_list-expired = AniListのログインが期限切れになったか取り消されました。リストの更新を続けるには下のボタンから再度ログインしてください。
+anilist_list-not_found_title = リストにありません
+anilist_list-not_found = 現在のリストに「{ $media }」に一致するものはありません。候補から選んでください。
+anilist_list-progress = 進捗
+anilist_list-status = ステータス
+anilist_list-score = スコア
+anilist_list-status_current = 視聴中
+anilist_list-status_planning = 予定
+anilist_list-status_completed = 完了
+anilist_list-status_dropped = 中断
+anilist_list-status_paused = 一時停止
+anilist_list-status_repeating = 再視聴中
+anilist_list-episode_done = { $anime }はリスト上で既に{ $progress }です。
+anilist_list-episode_saved = 視聴済みにしました。{ $anime }はリスト上で{ $progress }になりました。
@@ -0,0 +1 @@
+anilist_progress-desc = 進捗を{ $previous }から{ $progress }に更新しました。
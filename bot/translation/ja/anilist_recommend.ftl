@@ -0,0 +1,6 @@
+anilist_recommend-title = おすすめ
+anilist_recommend-title_user = { $user } へのおすすめ
+anilist_recommend-no_user = ユーザー名を指定するか、`register` コマンドでAniListアカウントを登録してください。
+anilist_recommend-nothing = おすすめできる作品がありません。おすすめは7/10以上で完了した作品から選ばれます。フィルターなしで試すか、もっと作品を評価してください。
+anilist_recommend-because = { $title } を { $score }/10 と評価したため
+anilist_recommend-tags = {" "}（好きなタグ: { $tags }）
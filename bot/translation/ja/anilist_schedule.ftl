@@ -0,0 +1,12 @@
+anilist_schedule-title = { $date }の放送予定
+anilist_schedule-scope_all = { $timezone }でその日に放送されるすべてのアニメ。
+anilist_schedule-scope_server = このサーバーがフォローしている、{ $timezone }でその日に放送されるアニメ。
+anilist_schedule-scope_list = あなたのリストにある、{ $timezone }でその日に放送されるアニメ。
+anilist_schedule-empty = その日に放送されるエピソードはありません。
+anilist_schedule-episode = 第{ $episode }話
+anilist_schedule-episode_of = 第{ $episode }話/全{ $episodes }話
+anilist_schedule-page = ページ { $page }/{ $total }
+anilist_schedule-no_user_title = 放送予定
+anilist_schedule-no_user = リストのエピソードを見るには、`register`でAniListアカウントを登録してください。
+anilist_schedule-previous = 前へ
+anilist_schedule-next = 次へ
@@ -0,0 +1,3 @@
+anilist_score-title = 無効なスコア
+anilist_score-invalid = { $score }はAniListプロフィールのスコア形式に合いません。0から{ $max }の間で指定してください。
+anilist_score-desc = スコアを{ $score }に設定しました。
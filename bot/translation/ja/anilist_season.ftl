@@ -0,0 +1,20 @@
+anilist_season-title = { $year }年{ $season }アニメ
+anilist_season-season_winter = 冬
+anilist_season-season_spring = 春
+anilist_season-season_summer = 夏
+anilist_season-season_fall = 秋
+anilist_season-format_tv = TV
+anilist_season-format_tv_short = TV（短編）
+anilist_season-format_movie = 映画
+anilist_season-format_special = スペシャル
+anilist_season-format_ova = OVA
+anilist_season-format_ona = ONA
+anilist_season-format_music = ミュージック
+anilist_season-filters = フィルター：{ $filters }
+anilist_season-empty = このシーズンにはフィルターに一致するアニメがありません。
+anilist_season-episodes = 全{ $count }話
+anilist_season-next = 第{ $episode }話 { $time }
+anilist_season-upcoming = 未放送
+anilist_season-page = ページ { $page }/{ $total } · { $count }作品
+anilist_season-previous = 前へ
+anilist_season-next_page = 次へ
@@ -1,3 +1,5 @@
 anilist_server_list_register_user-next = 次のページ
 anilist_server_list_register_user-previous = 前のページ
 anilist_server_list_register_user-title = 登録ユーザーのリスト
+anilist_server_list_register_user-empty = このサーバーにはAniListアカウントを登録したメンバーがいません。
+anilist_server_list_register_user-page = ページ { $page }/{ $total }
@@ -0,0 +1 @@
+anilist_status-desc = ステータスを{ $status }に変更しました。
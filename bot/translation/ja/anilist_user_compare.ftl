@@ -32,3 +32,27 @@ anilist_user_compare-tag_anime =
     { $var1 }はアニメのタグ{ $var1a }を好み、{ $var2 }はアニメのタグ{ $var2a }を好みます
 anilist_user_compare-tag_manga =
     { $var1 }はマンガのタグ{ $var1a }を好み、{ $var2 }はマンガのタグ{ $var2a }を好みます
+anilist_user_compare-correlation =
+    共通作品のスコア相関：{ $pearson }（ピアソン）、{ $spearman }（スピアマン）
+anilist_user_compare-disagreements =
+    評価が最も分かれた作品
+anilist_user_compare-disagreements_button =
+    評価の違い
+anilist_user_compare-for_first =
+    { $var2 }が大好きで、{ $var1 }がまだ見ていない作品
+anilist_user_compare-for_second =
+    { $var1 }が大好きで、{ $var2 }がまだ見ていない作品
+anilist_user_compare-list_title =
+    リスト比較
+anilist_user_compare-next =
+    次へ
+anilist_user_compare-no_correlation =
+    両者がスコアを付けた作品が少なすぎて相関を計算できません
+anilist_user_compare-nothing =
+    表示するものはありません。
+anilist_user_compare-page =
+    { $total }ページ中{ $page }ページ目
+anilist_user_compare-previous =
+    前へ
+anilist_user_compare-shared =
+    { $var1 }と{ $var2 }は共に{ $shared }作品を視聴または読了し、そのうち{ $scored }作品に両者がスコアを付けています
@@ -1 +1,4 @@
 anilist_user_register-desc = ユーザー { $user }（ID：{ $id }）はAnilistアカウント { $anilist } とリンクされました
+anilist_user_register-unverified = このリンクは未認証です。`/verify` でアカウントの所有を証明してください。
+anilist_user_register-taken = このAniListアカウントは既に別のメンバーが登録しています。あなたのものなら `/verify` で取得してください。
+anilist_user_register-taken_verified = このAniListアカウントは別のメンバーが認証済みです。あなたのものなら `/verify` で取得してください。
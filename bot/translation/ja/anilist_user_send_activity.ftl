@@ -1,2 +1,3 @@
 anilist_user_send_activity-desc = { $anime }のエピソード{ $ep }がリリースされました。
 anilist_user_send_activity-title = 新エピソード
+anilist_user_send_activity-episode_button = エピソード +1
@@ -0,0 +1,7 @@
+anilist_user_verify-title = AniListアカウントの認証
+anilist_user_verify-desc = AniListのユーザー名は誰でも登録できます。AniListにログインしてアカウントの所有を証明してください。認証されたリンクは以前に登録した人からアカウントを引き継ぎ、サーバーで必須にすることもできます。
+anilist_user_verify-already = あなたのDiscordアカウントは既に[このAniListアカウント]({ $url })で認証されています。別のアカウントをリンクするには再度ログインしてください。
+anilist_user_verify-button = ログインリンクを受け取る
+anilist_user_verify-link = あなた専用のログインリンクです。15分で期限切れになります。最初にDiscordで、次にAniListでログインします。
+anilist_user_verify-open = AniListにログイン
+anilist_user_verify-disabled = このボットではAniListの認証リンクが有効になっていません。
@@ -35,6 +35,9 @@ parent-levels-desc = ユーザーのレベル、統計情報を取得するコ
 parent-minigame-name = ミニゲーム
 parent-minigame-desc = ミニゲームをプレイしてインベントリを管理するためのコマンド。
 
+parent-anilist-name = anilist
+parent-anilist-desc = AniListのリストを使うコマンド。
+
 parent-admin-name = admin
 parent-admin-desc = 管理者専用のボット設定。
 
@@ -46,6 +49,9 @@ group-admin-anilist-desc = 管理者権限が必要なAniListモジュールの
 group-admin-general-name = general
 group-admin-general-desc = 管理者権限が必要な一般モジュールのコマンド。
 
+group-admin-level-name = level
+group-admin-level-desc = LEVELモジュールの管理者コマンド。
+
 # ─── Commands ─────────────────────────────────────────────────────────────────
 
 # admin/anilist
@@ -59,9 +65,28 @@ cmd-delete_anime_activity-desc = アニメ活動を削除します。
 cmd-lang-name = lang
 cmd-lang-desc = レスポンスに設定したい言語。
 
+cmd-admin_message_content-name = メッセージ内容
+cmd-admin_message_content-desc = 同意したメンバーのメッセージ内容の保存を許可します。
+
+cmd-admin_anilist_verified-name = anilist認証済み
+cmd-admin_anilist_verified-desc = AniListログインで認証されたAniListアカウントのみを表示する。
+
+cmd-admin_timezone-name = タイムゾーン
+cmd-admin_timezone-desc = サーバーのタイムゾーンを設定します。
+
 cmd-module-name = モジュール
 cmd-module-desc = モジュールをオンまたはオフにします。
 
+# admin/level
+cmd-admin_settings-name = 設定
+cmd-admin_settings-desc = レベル設定を表示または変更します。
+
+cmd-admin_add_reward-name = 報酬追加
+cmd-admin_add_reward-desc = レベル到達時にメンバーへロールを付与します。
+
+cmd-admin_remove_reward-name = 報酬削除
+cmd-admin_remove_reward-desc = レベル報酬としてのロール付与を停止します。
+
 # ai
 cmd-image-name = 画像
 cmd-image-desc = 画像を生成する。
@@ -75,6 +100,37 @@ cmd-transcript-desc = 動画から脚本を生成する。
 cmd-translation-name = honyaku
 cmd-translation-desc = 翻訳を生成する。
 
+# anilist
+cmd-anilist_follow-name = フォロー
+cmd-anilist_follow-desc = アニメの各エピソード放送時にDMを受け取る。
+
+cmd-anilist_franchise-name = フランチャイズ
+cmd-anilist_franchise-desc = シリーズの視聴順と関連グラフを表示する。
+
+cmd-anilist_notifications-name = 通知
+cmd-anilist_notifications-desc = 放送DMを設定し、フォロー中のアニメを表示する。
+
+cmd-anilist_progress-name = 進捗
+cmd-anilist_progress-desc = 視聴したエピソードや読んだチャプターをAniListのリストに追加する。
+
+cmd-anilist_recommend-name = おすすめ
+cmd-anilist_recommend-desc = リストで高く評価した作品から選んだアニメや漫画を表示する。
+
+cmd-anilist_schedule-name = 放送予定
+cmd-anilist_schedule-desc = ある日に放送されるアニメのエピソードを表示する。
+
+cmd-anilist_score-name = スコア
+cmd-anilist_score-desc = AniListのリストのアニメやマンガにスコアを付ける。
+
+cmd-anilist_season-name = シーズン
+cmd-anilist_season-desc = シーズンのアニメを一覧表示する。
+
+cmd-anilist_status-name = ステータス
+cmd-anilist_status-desc = AniListのリストのアニメやマンガのステータスを変更する。
+
+cmd-anilist_unfollow-name = フォロー解除
+cmd-anilist_unfollow-desc = アニメの放送DMを停止する。
+
 # anilist_server
 cmd-list_activity-name = アクティビティリスト
 cmd-list_activity-desc = 登録アクティビティのリストを取得する。
@@ -82,6 +138,9 @@ cmd-list_activity-desc = 登録アクティビティのリストを取得する
 cmd-list_user-name = ユーザーリスト
 cmd-list_user-desc = 登録ユーザーのリストを取得する。
 
+cmd-anilist_leaderboard-name = anilistランキング
+cmd-anilist_leaderboard-desc = サーバーのメンバーをAniListの統計でランク付けします。
+
 # anilist_user
 cmd-anime-name = anime
 cmd-anime-desc = アニメ情報。
@@ -107,6 +166,9 @@ cmd-random-desc = ランダムなアニメまたはマンガを取得する。
 cmd-register-name = 登録
 cmd-register-desc = AniListにユーザー名を登録する。
 
+cmd-verify-name = 認証
+cmd-verify-desc = AniListにログインしてAniListアカウントの所有を証明する。
+
 cmd-seiyuu-name = seiyuu
 cmd-seiyuu-desc = 声優情報。
 
@@ -144,6 +206,15 @@ cmd-ping-desc = ボットのピング（およびシャードID）を取得し
 cmd-stats-name = 統計
 cmd-stats-desc = レベルの統計を取得する。
 
+cmd-levels_rank-name = ランク
+cmd-levels_rank-desc = メンバーのランクカードを表示します。
+
+cmd-levels_leaderboard-name = ランキング
+cmd-levels_leaderboard-desc = サーバーで最もアクティブなメンバーを表示します。
+
+cmd-levels_heatmap-name = ヒートマップ
+cmd-levels_heatmap-desc = メンバーまたはサーバーが活動している時間帯を表示します。
+
 # management
 cmd-give_premium_sub-name = give_premium_sub
 cmd-give_premium_sub-desc = ユーザーにプレミアムサブスクリプションを付与する。
@@ -151,19 +222,58 @@ cmd-give_premium_sub-desc = ユーザーにプレミアムサブスクリプシ
 cmd-kill_switch-name = kill_switch
 cmd-kill_switch-desc = モジュールをグローバルにオン/オフにする
 
+cmd-reload_items-name = reload_items
+cmd-reload_items-desc = アイテムカタログを検証して同期する。
+
 cmd-remove_test_sub-name = remove_test_sub
 cmd-remove_test_sub-desc = ユーザーのプレミアムサブスクリプションを削除する。
 
 # minigame
+cmd-minigame_balance-name = 残高
+cmd-minigame_balance-desc = コインと最近の取引を確認する。
+
+cmd-minigame_collection-name = コレクション
+cmd-minigame_collection-desc = メンバーが引いたキャラクターを表示します。
+
+cmd-minigame_daily-name = デイリー
+cmd-minigame_daily-desc = デイリー報酬を受け取り、連続記録を伸ばします。
+
+cmd-minigame_equip-name = 装備
+cmd-minigame_equip-desc = 釣り竿やエサを装備する。
+
 cmd-fish_inventory-name = 魚インベントリ
 cmd-fish_inventory-desc = 魚のインベントリを確認する。
 
 cmd-fishing-name = 釣り
 cmd-fishing-desc = 釣りに行こう！
 
+cmd-minigame_gift-name = ギフト
+cmd-minigame_gift-desc = 他のメンバーにアイテムやコインを贈る。
+
+cmd-minigame_guess_anime-name = アニメ当て
+cmd-minigame_guess_anime-desc = カバー画像からアニメを当てるラウンドを始めます。
+
+cmd-minigame_anisong_quiz-name = アニソンクイズ
+cmd-minigame_anisong_quiz-desc = ボイスチャンネルで流れる曲のアニメを当てるクイズを始めます。
+
 cmd-inventory-name = インベントリ
 cmd-inventory-desc = インベントリを確認する。
 
+cmd-minigame_leaderboard-name = ランキング
+cmd-minigame_leaderboard-desc = ミニゲームの上位プレイヤーを表示する。
+
+cmd-minigame_roll-name = ガチャ
+cmd-minigame_roll-desc = コレクションに加えるAniListキャラクターをランダムに引きます。
+
+cmd-minigame_sell-name = 売却
+cmd-minigame_sell-desc = インベントリのアイテムを売る。
+
+cmd-minigame_shop-name = ショップ
+cmd-minigame_shop-desc = ショップを見る、またはアイテムを買う。
+
+cmd-minigame_trade-name = トレード
+cmd-minigame_trade-desc = 他のメンバーとアイテムやコインをトレードする。
+
 # music
 cmd-clear-name = クリア
 cmd-clear-desc = 現在のキューをクリアする。
@@ -225,6 +335,9 @@ cmd-banner-desc = バナーを取得します。
 cmd-command_usage-name = コマンドの使用状況
 cmd-command_usage-desc = 各コマンドの使用状況を表示します。
 
+cmd-user_message_content-name = メッセージ内容
+cmd-user_message_content-desc = メッセージ内容の保存を許可するかどうかを選択します。
+
 cmd-profile-name = プロフィール
 cmd-profile-desc = ユーザーのプロフィールを表示します。
 
@@ -263,6 +376,46 @@ arg-module-name-desc = 状態を変更したいモジュール。
 arg-module-state-name = 状態
 arg-module-state-desc = 適用したい状態。
 
+# admin/general/message_content
+arg-admin_message_content-state-name = 状態
+arg-admin_message_content-state-desc = メッセージ内容の保存を許可するかどうか。
+
+# admin/general/anilist_verified
+arg-admin_anilist_verified-state-name = 状態
+arg-admin_anilist_verified-state-desc = 認証済みアカウントのみを表示するかどうか。
+
+# admin/general/timezone
+arg-admin_timezone-timezone-name = タイムゾーン
+arg-admin_timezone-timezone-desc = タイムゾーン（例：Asia/Tokyo）。
+
+# admin/level/settings
+arg-admin_settings-message_xp-name = メッセージxp
+arg-admin_settings-message_xp-desc = メッセージごとのXP (0-1000)。
+arg-admin_settings-voice_xp-name = ボイスxp
+arg-admin_settings-voice_xp-desc = ボイスでの1分あたりのXP (0-1000)。
+arg-admin_settings-cooldown-name = クールダウン
+arg-admin_settings-cooldown-desc = XPを得られるメッセージ間の秒数 (0-3600)。
+arg-admin_settings-curve_base-name = 曲線ベース
+arg-admin_settings-curve_base-desc = レベル1に必要なXP (1-100000)。
+arg-admin_settings-curve_exponent-name = 曲線指数
+arg-admin_settings-curve_exponent-desc = レベルが難しくなる速さ (1.0-3.0)。
+arg-admin_settings-announce-name = 通知
+arg-admin_settings-announce-desc = レベルアップを通知します。
+arg-admin_settings-channel-name = チャンネル
+arg-admin_settings-channel-desc = レベルアップ通知のチャンネル。
+arg-admin_settings-reset_channel-name = チャンネルリセット
+arg-admin_settings-reset_channel-desc = メッセージが送信されたチャンネルで通知します。
+
+# admin/level/add_reward
+arg-admin_add_reward-level-name = レベル
+arg-admin_add_reward-level-desc = ロールが解放されるレベル。
+arg-admin_add_reward-role-name = ロール
+arg-admin_add_reward-role-desc = 付与するロール。
+
+# admin/level/remove_reward
+arg-admin_remove_reward-role-name = ロール
+arg-admin_remove_reward-role-desc = 報酬から外すロール。
+
 # ai/image
 arg-image-description-name = 説明
 arg-image-description-desc = 生成したい画像の説明を入力してください。
@@ -287,6 +440,80 @@ arg-translation-video-desc = ビデオファイルをアップロード（最大
 arg-translation-lang-name = lang
 arg-translation-lang-desc = 入力言語を選択（ISO-639-1)
 
+# anilist/follow
+arg-anilist_follow-anime-name = アニメ
+arg-anilist_follow-anime-desc = フォローするアニメ。
+
+# anilist/franchise
+arg-anilist_franchise-media-name = 作品
+arg-anilist_franchise-media-desc = シリーズのアニメまたはマンガ。
+
+# anilist/notifications
+arg-anilist_notifications-auto_follow-name = 自動フォロー
+arg-anilist_notifications-auto_follow-desc = AniListの視聴中リストにある放送中アニメをフォローする。
+arg-anilist_notifications-mute-name = ミュート
+arg-anilist_notifications-mute-desc = ミュート解除するまですべての放送DMを停止する。
+arg-anilist_notifications-snooze-name = スヌーズ
+arg-anilist_notifications-snooze-desc = 放送DMを止める時間数。0でスヌーズを終了。
+
+# anilist/progress
+arg-anilist_progress-media-name = 作品
+arg-anilist_progress-media-desc = 現在のリストにあるアニメやマンガ。
+arg-anilist_progress-amount-name = 数
+arg-anilist_progress-amount-desc = 追加するエピソードまたはチャプターの数。既定は1。負の値で戻す。
+
+# anilist/recommend
+arg-anilist_recommend-username-name = ユーザー名
+arg-anilist_recommend-username-desc = ユーザー名。空の場合は自分。
+arg-anilist_recommend-type-name = 種類
+arg-anilist_recommend-type-desc = アニメか漫画。空の場合はアニメ。
+arg-anilist_recommend-genre-name = ジャンル
+arg-anilist_recommend-genre-desc = このジャンルの作品のみ。
+arg-anilist_recommend-format-name = 形式
+arg-anilist_recommend-format-desc = この形式の作品のみ。
+arg-anilist_recommend-year-name = 年
+arg-anilist_recommend-year-desc = この年に発表された作品のみ。
+
+# anilist/schedule
+arg-anilist_schedule-day-name = 曜日
+arg-anilist_schedule-day-desc = 日付。空の場合は今日。
+arg-anilist_schedule-scope-name = 範囲
+arg-anilist_schedule-scope-desc = すべてのアニメ、サーバーがフォローしているもの、またはリストにあるもの。
+
+# anilist/score
+arg-anilist_score-media-name = 作品
+arg-anilist_score-media-desc = 現在のリストにあるアニメやマンガ。
+arg-anilist_score-score-name = スコア
+arg-anilist_score-score-desc = AniListプロフィールで設定した形式のスコア。
+
+# anilist/season
+arg-anilist_season-year-name = 年
+arg-anilist_season-year-desc = シーズンの年。空の場合は現在の年。
+arg-anilist_season-season-name = シーズン
+arg-anilist_season-season-desc = シーズン。空の場合は現在のシーズン。
+arg-anilist_season-format-name = 形式
+arg-anilist_season-format-desc = この形式のアニメのみ。
+arg-anilist_season-genre-name = ジャンル
+arg-anilist_season-genre-desc = このジャンルのアニメのみ。
+arg-anilist_season-studio-name = スタジオ
+arg-anilist_season-studio-desc = このスタジオが制作したアニメのみ。
+
+# anilist/status
+arg-anilist_status-media-name = 作品
+arg-anilist_status-media-desc = 現在のリストにあるアニメやマンガ。
+arg-anilist_status-status-name = ステータス
+arg-anilist_status-status-desc = 新しいステータス。
+
+# anilist/unfollow
+arg-anilist_unfollow-anime-name = アニメ
+arg-anilist_unfollow-anime-desc = フォロー中のアニメ。
+
+# anilist_server/anilist_leaderboard
+arg-anilist_leaderboard-stat-name = 統計
+arg-anilist_leaderboard-stat-desc = ランク付けの基準。デフォルトはレベル。
+arg-anilist_leaderboard-page-name = ページ
+arg-anilist_leaderboard-page-desc = 開始するページ。
+
 # anilist_user/anime
 arg-anime-anime_name-name = anime_no_namae
 arg-anime-anime_name-desc = チェックしたいアニメの名前。
@@ -345,6 +572,22 @@ arg-random_image-image_type-desc = 欲しい画像のタイプ。
 arg-random_himage-image_type-name = imeji_taipu
 arg-random_himage-image_type-desc = 欲しい画像のタイプ。
 
+# levels/rank
+arg-levels_rank-username-name = ユーザー名
+arg-levels_rank-username-desc = ランクカードを表示するメンバー。
+
+# levels/leaderboard
+arg-levels_leaderboard-page-name = ページ
+arg-levels_leaderboard-page-desc = 開始ページ。
+
+# levels/heatmap
+arg-levels_heatmap-username-name = ユーザー
+arg-levels_heatmap-username-desc = 活動を表示するメンバー。
+arg-levels_heatmap-server-name = サーバー
+arg-levels_heatmap-server-desc = 代わりにサーバー全体の活動を表示します。
+arg-levels_heatmap-period-name = 期間
+arg-levels_heatmap-period-desc = どこまで遡るか。
+
 # management/give_premium_sub
 arg-give_premium_sub-user-name = ユーザー
 arg-give_premium_sub-user-desc = サブスクリプションを付与するユーザー。
@@ -361,6 +604,56 @@ arg-kill_switch-state-desc = 適用したい状態。
 arg-remove_test_sub-user-name = ユーザー
 arg-remove_test_sub-user-desc = サブスクリプションを削除するユーザー。
 
+# minigame/collection
+arg-minigame_collection-user-name = ユーザー
+arg-minigame_collection-user-desc = コレクションを表示するメンバー（既定は自分）。
+arg-minigame_collection-page-name = ページ
+arg-minigame_collection-page-desc = 開始するページ。
+
+# minigame/equip
+arg-minigame_equip-gear-name = 装備品
+arg-minigame_equip-gear-desc = 装備する釣り竿またはエサ。
+
+# minigame/gift
+arg-minigame_gift-user-name = ユーザー
+arg-minigame_gift-user-desc = 贈る相手のメンバー。
+arg-minigame_gift-item-name = アイテム
+arg-minigame_gift-item-desc = 贈るアイテム。
+arg-minigame_gift-coins-name = コイン
+arg-minigame_gift-coins-desc = 贈るコインの枚数。
+
+# minigame/guess_anime
+arg-minigame_guess_anime-difficulty-name = 難易度
+arg-minigame_guess_anime-difficulty-desc = アニメの知名度（既定はふつう）。
+
+# minigame/anisong_quiz
+arg-minigame_anisong_quiz-rounds-name = ラウンド数
+arg-minigame_anisong_quiz-rounds-desc = 流す曲の数（既定は5、最大10）。
+
+# minigame/leaderboard
+arg-minigame_leaderboard-game-name = ゲーム
+arg-minigame_leaderboard-game-desc = ランキングするミニゲーム（既定はすべて）。
+arg-minigame_leaderboard-scope-name = 範囲
+arg-minigame_leaderboard-scope-desc = このサーバーまたは全サーバー。
+arg-minigame_leaderboard-window-name = 期間
+arg-minigame_leaderboard-window-desc = 今週または全期間。
+arg-minigame_leaderboard-page-name = ページ
+arg-minigame_leaderboard-page-desc = 開始するページ。
+
+# minigame/sell
+arg-minigame_sell-item-name = アイテム
+arg-minigame_sell-item-desc = 売るアイテム。
+
+# minigame/shop
+arg-minigame_shop-buy-name = 購入
+arg-minigame_shop-buy-desc = 買うアイテム。
+arg-minigame_shop-quantity-name = 数量
+arg-minigame_shop-quantity-desc = 買う数（1〜10）。
+
+# minigame/trade
+arg-minigame_trade-user-name = ユーザー
+arg-minigame_trade-user-desc = トレードする相手のメンバー。
+
 # music/play
 arg-play-search-name = 検索
 arg-play-search-desc = 曲を検索する。
@@ -395,6 +688,10 @@ arg-banner-username-desc = アバターを取得したいユーザーのユー
 arg-command_usage-username-name = ユーザー名
 arg-command_usage-username-desc = 使用状況を表示したいユーザーのユーザー名。
 
+# user/message_content
+arg-user_message_content-state-name = 状態
+arg-user_message_content-state-desc = メッセージ内容の保存を許可するかどうか。
+
 # user/profile
 arg-profile-username-name = ユーザー名
 arg-profile-username-desc = アバターを表示したいユーザーのユーザー名。
@@ -430,6 +727,94 @@ choice-lang-lang_choice-es-ES-name = スペイン語
 choice-lang-lang_choice-zh-CN-name = 中国語 (簡体字)
 choice-lang-lang_choice-ru-name = ロシア語
 
+# anilist/status status choices
+choice-anilist_status-status-current-name = 視聴中
+choice-anilist_status-status-planning-name = 予定
+choice-anilist_status-status-completed-name = 完了
+choice-anilist_status-status-dropped-name = 中断
+choice-anilist_status-status-paused-name = 一時停止
+choice-anilist_status-status-repeating-name = 再視聴中
+
+# anilist/recommend type choices
+choice-anilist_recommend-type-anime-name = アニメ
+choice-anilist_recommend-type-manga-name = 漫画
+
+# anilist/recommend format choices
+choice-anilist_recommend-format-tv-name = TV
+choice-anilist_recommend-format-tv_short-name = TV（短編）
+choice-anilist_recommend-format-movie-name = 映画
+choice-anilist_recommend-format-special-name = スペシャル
+choice-anilist_recommend-format-ova-name = OVA
+choice-anilist_recommend-format-ona-name = ONA
+choice-anilist_recommend-format-manga-name = 漫画
+choice-anilist_recommend-format-novel-name = ライトノベル
+choice-anilist_recommend-format-one_shot-name = 読み切り
+
+# anilist/schedule day choices
+choice-anilist_schedule-day-today-name = 今日
+choice-anilist_schedule-day-tomorrow-name = 明日
+choice-anilist_schedule-day-monday-name = 月曜日
+choice-anilist_schedule-day-tuesday-name = 火曜日
+choice-anilist_schedule-day-wednesday-name = 水曜日
+choice-anilist_schedule-day-thursday-name = 木曜日
+choice-anilist_schedule-day-friday-name = 金曜日
+choice-anilist_schedule-day-saturday-name = 土曜日
+choice-anilist_schedule-day-sunday-name = 日曜日
+
+# anilist/schedule scope choices
+choice-anilist_schedule-scope-all-name = すべてのアニメ
+choice-anilist_schedule-scope-server-name = サーバーがフォロー中
+choice-anilist_schedule-scope-list-name = 自分のリスト
+
+# anilist/season season choices
+choice-anilist_season-season-winter-name = 冬
+choice-anilist_season-season-spring-name = 春
+choice-anilist_season-season-summer-name = 夏
+choice-anilist_season-season-fall-name = 秋
+
+# anilist/season format choices
+choice-anilist_season-format-tv-name = TV
+choice-anilist_season-format-tv_short-name = TV（短編）
+choice-anilist_season-format-movie-name = 映画
+choice-anilist_season-format-special-name = スペシャル
+choice-anilist_season-format-ova-name = OVA
+choice-anilist_season-format-ona-name = ONA
+choice-anilist_season-format-music-name = ミュージック
+
+# anilist_server/anilist_leaderboard stat choices
+choice-anilist_leaderboard-stat-level-name = レベル
+choice-anilist_leaderboard-stat-minutes_watched-name = 視聴時間
+choice-anilist_leaderboard-stat-chapters_read-name = 読んだ章数
+choice-anilist_leaderboard-stat-mean_score-name = 平均スコア
+choice-anilist_leaderboard-stat-completed-name = 完了した作品
+
+# levels/heatmap period choices
+choice-levels_heatmap-period-week-name = 過去7日間
+choice-levels_heatmap-period-month-name = 過去30日間
+choice-levels_heatmap-period-quarter-name = 過去90日間
+choice-levels_heatmap-period-year-name = 過去365日間
+
+# minigame/guess_anime difficulty choices
+choice-minigame_guess_anime-difficulty-easy-name = かんたん
+choice-minigame_guess_anime-difficulty-normal-name = ふつう
+choice-minigame_guess_anime-difficulty-hard-name = むずかしい
+
+# minigame/leaderboard game choices
+choice-minigame_leaderboard-game-all-name = すべてのミニゲーム
+choice-minigame_leaderboard-game-fishing-name = 釣り
+choice-minigame_leaderboard-game-gacha-name = キャラクターコレクション
+choice-minigame_leaderboard-game-guess_anime-name = アニメ当てクイズ
+choice-minigame_leaderboard-game-anisong_quiz-name = アニソンクイズ
+choice-minigame_leaderboard-game-daily-name = デイリー報酬
+
+# minigame/leaderboard scope choices
+choice-minigame_leaderboard-scope-server-name = このサーバー
+choice-minigame_leaderboard-scope-global-name = 全サーバー
+
+# minigame/leaderboard window choices
+choice-minigame_leaderboard-window-weekly-name = 今週
+choice-minigame_leaderboard-window-all_time-name = 全期間
+
 # admin/general/module choices
 choice-module-name-AI-name = AI
 choice-module-name-ANILIST-name = アニリスト
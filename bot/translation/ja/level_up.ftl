@@ -0,0 +1,2 @@
+level_up-desc = おめでとうございます { $user } さん、**レベル { $level }** に到達しました！
+level_up-title = レベルアップ！
@@ -0,0 +1,5 @@
+levels_heatmap-desc = 過去{ $days }日間の活動：メッセージ{ $messages }件、ボイス{ $hours }時間{ $minutes }分。
+levels_heatmap-footer = 時刻は{ $timezone }で表示しています。
+levels_heatmap-message_retention = メッセージは{ $message_days }日間のみ保存されるため、それより古いものは表示されません。
+levels_heatmap-title_server = サーバーの活動
+levels_heatmap-title_user = { $user }の活動
@@ -0,0 +1,6 @@
+levels_leaderboard-empty = このサーバーではまだ誰もXPを獲得していません。
+levels_leaderboard-entry = **#{ $rank }** { $user } — レベル { $level } ({ $xp } XP)
+levels_leaderboard-next = 次へ
+levels_leaderboard-page = { $page }/{ $total } ページ
+levels_leaderboard-previous = 前へ
+levels_leaderboard-title = サーバーランキング
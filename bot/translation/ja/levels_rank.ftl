@@ -0,0 +1,4 @@
+levels_rank-desc = **順位:** #{ $rank }
+    **レベル:** { $level }
+    **合計XP:** { $xp }
+levels_rank-title = { $user } のランクカード
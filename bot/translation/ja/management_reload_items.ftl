@@ -0,0 +1,4 @@
+management_reload_items-failed = アイテムカタログは拒否されました。何も変更されていません。
+management_reload_items-synced = アイテムカタログ バージョン { $version } を同期しました: 追加 { $added }、更新 { $updated }、廃止 { $retired }、復活 { $restored }。
+management_reload_items-title = アイテムカタログ
+management_reload_items-up_to_date = アイテムカタログ（バージョン { $version }）はすでに最新です。
@@ -0,0 +1,2 @@
+minigame-disabled_desc = このサーバーではミニゲームモジュールが無効になっています。
+minigame-disabled_title = ミニゲーム無効
@@ -0,0 +1,23 @@
+minigame_anisong_quiz-already_running = このサーバーではすでにクイズが進行中です。終わるまでお待ちください。
+minigame_anisong_quiz-anime = アニメ
+minigame_anisong_quiz-answer = 回答する
+minigame_anisong_quiz-answer_label = アニメのタイトル
+minigame_anisong_quiz-answer_placeholder = ローマ字、英語、その他の既知のタイトル
+minigame_anisong_quiz-closed = このラウンドは終了しました。
+minigame_anisong_quiz-correct = 正解！{ $points } ポイント獲得しました。
+minigame_anisong_quiz-music_playing = ボイスチャンネルで音楽が再生中です。クイズを始める前に停止してください。
+minigame_anisong_quiz-no_song = 再生できる曲が見つからなかったため、クイズを終了します。
+minigame_anisong_quiz-not_starter = クイズを始めた人だけが停止できます。
+minigame_anisong_quiz-round_description = この曲はどのアニメのもの？制限時間は { $seconds } 秒、早く答えるほど高得点です。
+minigame_anisong_quiz-round_title = アニソンクイズ · ラウンド { $round }/{ $rounds }
+minigame_anisong_quiz-solved = { $user } が正解しました！
+minigame_anisong_quiz-song = 曲
+minigame_anisong_quiz-standing = **#{ $rank }** { $user } — { $points } ポイント
+minigame_anisong_quiz-standings_empty = 今回は誰も得点しませんでした。
+minigame_anisong_quiz-standings_title = アニソンクイズの結果
+minigame_anisong_quiz-starting = { $rounds } 曲でクイズを始めます。よく聴いてください！
+minigame_anisong_quiz-stop = クイズを停止
+minigame_anisong_quiz-stopped = クイズを停止しました。
+minigame_anisong_quiz-title = アニソンクイズ
+minigame_anisong_quiz-unsolved = 今回は誰も当てられませんでした。
+minigame_anisong_quiz-wrong = 「{ $guess }」ではありません。もう一度どうぞ！
@@ -0,0 +1,9 @@
+minigame_balance-desc = **{ $balance }** コインを持っています。
+minigame_balance-history = 最近の取引
+minigame_balance-no_history = まだ取引はありません。
+minigame_balance-reason_gacha_roll = キャラクターガチャ
+minigame_balance-reason_gift = ギフト
+minigame_balance-reason_sale = 売却
+minigame_balance-reason_shop_purchase = ショップでの購入
+minigame_balance-reason_trade = トレード
+minigame_balance-title = 残高
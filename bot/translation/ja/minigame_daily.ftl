@@ -0,0 +1,11 @@
+minigame_daily-already_claimed = 今日の報酬はすでに受け取っています。現在の連続記録は{ $streak }日です。次の報酬は{ $next }に受け取れます。
+minigame_daily-coins = **{ $coins }**コインを獲得しました。現在の残高は{ $balance }です。
+minigame_daily-item = **{ $name }** { $rarity } を見つけました
+minigame_daily-next = 次の報酬は{ $next }。連続記録を保つには{ $deadline }までに受け取ってください。
+minigame_daily-next_field = 次の受け取り
+minigame_daily-next_tier = { $day }日目から報酬が{ $coins }コインに増えます。
+minigame_daily-streak = **{ $streak }**日連続（最高記録：{ $best }日）
+minigame_daily-streak_field = 連続記録
+minigame_daily-streak_grace = 遅れましたが、猶予時間のおかげで連続記録は途切れませんでした。
+minigame_daily-streak_lost = { $previous }日の連続記録が途切れました。新しい記録の始まりです。
+minigame_daily-title = デイリー報酬
@@ -0,0 +1,6 @@
+minigame_equip-equipped = **{ $name }** を{ $slot }として装備しました。現在のものは残り { $uses } 回、所持数 { $owned }。
+minigame_equip-not_owned = その装備を持っていません。
+minigame_equip-slot_bait = エサ
+minigame_equip-slot_rod = 釣り竿
+minigame_equip-title = 釣り装備
+minigame_equip-unequipped = { $slot }の枠が空になりました。
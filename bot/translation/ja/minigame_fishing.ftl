@@ -1,6 +1,9 @@
 minigame_fishing-average = 平均
 minigame_fishing-caught_fish = 魚を釣りました！
 minigame_fishing-common = 一般
+minigame_fishing-conditions = { $time_of_day }、{ $season }
+minigame_fishing-conditions_field = コンディション
+minigame_fishing-cooldown = 釣り糸がまだ乾いていません。{ $time }にもう一度投げられます。
 minigame_fishing-description_field = 説明
 minigame_fishing-epic = エピック
 minigame_fishing-fish_details = 魚の詳細
@@ -10,14 +13,28 @@ minigame_fishing-fish_details_format =
     {"**"}レア度:** { $rarity }
     {"**"}XPブースト:** { $xp_boost }%
     {"**"}価値:** { $price } コイン
+minigame_fishing-gear_broken = **{ $name }** が壊れました！
+minigame_fishing-gear_entry = **{ $name }**：残り { $uses } 回
+minigame_fishing-gear_field = 装備
 minigame_fishing-huge = 巨大
 minigame_fishing-large = 大きい
 minigame_fishing-legendary = 伝説
 minigame_fishing-massive = 超巨大
+minigame_fishing-no_gear = 装備なし
+minigame_fishing-points = ランキング: **+{ $points } ポイント**。
 minigame_fishing-rare = レア
+minigame_fishing-season_autumn = 秋
+minigame_fishing-season_spring = 春
+minigame_fishing-season_summer = 夏
+minigame_fishing-season_winter = 冬
 minigame_fishing-small = 小さい
+minigame_fishing-time_dawn = 夜明け
+minigame_fishing-time_day = 昼
+minigame_fishing-time_dusk = 夕暮れ
+minigame_fishing-time_night = 夜
 minigame_fishing-tiny = 極小
 minigame_fishing-title = 釣りの結果
 minigame_fishing-uncommon = 珍しい
 minigame_fishing-unknown = 不明
 minigame_fishing-unknown_size = サイズ不明
+minigame_fishing-xp_gained = **{ $xp } XP** を獲得しました。
\ No newline at end of file
@@ -0,0 +1,18 @@
+minigame_gacha-card_gone = このカードはもうこのコレクションにありません。
+minigame_gacha-collection_empty = まだキャラクターがいません。ガチャを引いてコレクションを始めましょう。
+minigame_gacha-collection_page = { $page }/{ $total } ページ · { $count } 枚
+minigame_gacha-collection_title = { $user } のコレクション
+minigame_gacha-duplicate = すでにコレクションにいるため、今回はポイントなしです。
+minigame_gacha-favourites = お気に入り数
+minigame_gacha-free_roll = 無料ガチャを使用しました。次は { $next } に利用できます。
+minigame_gacha-insufficient_funds = 無料ガチャは { $next } に復活します。追加のガチャは { $cost } コインで、所持金は { $balance } コインです。
+minigame_gacha-new_card = 新しいキャラクターがコレクションに追加されました！
+minigame_gacha-next = 次へ
+minigame_gacha-owned_by = { $obtained } から { $user } が所有
+minigame_gacha-paid_roll = このガチャに { $cost } コインを支払いました。
+minigame_gacha-pool_empty = キャラクタープールを準備中です。しばらくしてからもう一度お試しください。
+minigame_gacha-previous = 前へ
+minigame_gacha-rarity = レアリティ
+minigame_gacha-roll_title = キャラクターガチャ
+minigame_gacha-series = 作品
+minigame_gacha-view_card = キャラクターを見る
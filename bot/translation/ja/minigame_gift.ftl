@@ -0,0 +1,8 @@
+minigame_gift-coins = { $user } に { $coins } コインを贈りました。
+minigame_gift-insufficient_funds = コインは { $balance } 枚しかありません。
+minigame_gift-invalid_coins = マイナスのコインは贈れません。
+minigame_gift-invalid_recipient = ボットではない他のメンバーにのみ贈れます。
+minigame_gift-items = { $user } に **{ $count }x { $name }** を贈りました。
+minigame_gift-nothing = そのアイテムはもうインベントリにないか、トレードで提示中です。
+minigame_gift-nothing_chosen = アイテム、コイン、またはその両方を選んでください。
+minigame_gift-title = ギフト
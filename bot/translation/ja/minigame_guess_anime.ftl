@@ -0,0 +1,19 @@
+minigame_guess_anime-already_running = このチャンネルではすでにラウンドが進行中です。先に正解するかギブアップしてください。
+minigame_guess_anime-answer = 回答する
+minigame_guess_anime-answer_label = アニメのタイトル
+minigame_guess_anime-answer_placeholder = ローマ字、英語、その他の既知のタイトル
+minigame_guess_anime-description = このアニメは何でしょう？ 難易度: { $difficulty }
+    { $points } ポイント · ステージ { $stage }/{ $total }
+minigame_guess_anime-difficulty_easy = かんたん
+minigame_guess_anime-difficulty_hard = むずかしい
+minigame_guess_anime-difficulty_normal = ふつう
+minigame_guess_anime-fully_revealed = 画像はすでにすべて公開されています。
+minigame_guess_anime-give_up = ギブアップ
+minigame_guess_anime-no_anime = 出題するアニメが見つかりませんでした。もう一度お試しください。
+minigame_guess_anime-not_starter = ギブアップできるのはラウンドを始めた人だけです。
+minigame_guess_anime-over = このラウンドは終了しました。
+minigame_guess_anime-reveal = もっと見る
+minigame_guess_anime-solved = { $user } が正解し、{ $points } ポイントを獲得しました！
+minigame_guess_anime-title = アニメ当てクイズ
+minigame_guess_anime-unsolved = 今回は誰も正解できませんでした。
+minigame_guess_anime-wrong = 「{ $guess }」は不正解です。がんばって！
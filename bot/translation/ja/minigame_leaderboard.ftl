@@ -0,0 +1,18 @@
+minigame_leaderboard-empty = まだ誰もスコアを獲得していません。
+minigame_leaderboard-entry = **#{ $rank }** { $user } — { $points } ポイント
+minigame_leaderboard-game_all = ミニゲーム
+minigame_leaderboard-game_anisong_quiz = アニソンクイズ
+minigame_leaderboard-game_daily = デイリー報酬
+minigame_leaderboard-game_fishing = 釣り
+minigame_leaderboard-game_gacha = キャラクターコレクション
+minigame_leaderboard-game_guess_anime = アニメ当てクイズ
+minigame_leaderboard-next = 次へ
+minigame_leaderboard-page = ページ { $page }/{ $total }
+minigame_leaderboard-position = あなたは { $points } ポイントで #{ $rank } 位です
+minigame_leaderboard-previous = 前へ
+minigame_leaderboard-scope_global = 全サーバー
+minigame_leaderboard-scope_server = サーバー
+minigame_leaderboard-title = { $game }ランキング（{ $scope }・{ $window }）
+minigame_leaderboard-unranked = まだランク外です
+minigame_leaderboard-window_all_time = 全期間
+minigame_leaderboard-window_weekly = 今週
@@ -0,0 +1,3 @@
+minigame_sell-nothing = そのアイテムはもうインベントリにありません。
+minigame_sell-sold = **{ $name }** を { $count } 個、{ $earned } コインで売りました。残高は { $balance } コインです。
+minigame_sell-title = 売却
@@ -0,0 +1,7 @@
+minigame_shop-bought = **{ $name }** を { $quantity } 個、{ $total } コインで購入しました。残高は { $balance } コインです。
+minigame_shop-desc = **{ $balance }** コインを持っています。`購入` オプションでアイテムを買えます。
+minigame_shop-empty = 現在ショップに商品はありません。
+minigame_shop-entry = **{ $name }** — { $price } コイン
+minigame_shop-insufficient = **{ $name }** は { $total } コインですが、{ $balance } コインしか持っていません。
+minigame_shop-not_for_sale = このアイテムは販売されていません。
+minigame_shop-title = ショップ
@@ -0,0 +1,28 @@
+minigame_trade-cancel = キャンセル
+minigame_trade-cancelled = このトレードはキャンセルされました。
+minigame_trade-change_offer = 自分の提示を変更
+minigame_trade-closed = このトレードは終了しています。
+minigame_trade-coins = { $coins } コイン
+minigame_trade-completed = トレード成立！提示されたものはすべて交換されました。
+minigame_trade-confirm = 確定
+minigame_trade-confirmed = 確定済み
+minigame_trade-empty_offer = まだ何も提示されていません。
+minigame_trade-expired = このトレードは期限切れです。
+minigame_trade-failed = 提示されたものがなくなったか、コインが足りないためトレードを完了できませんでした。提示内容を確認して、もう一度確定してください。
+minigame_trade-insufficient_funds = コインは { $balance } 枚しかありません。
+minigame_trade-invalid_partner = ボットではない他のメンバーとのみトレードできます。
+minigame_trade-missing = { $name }（もう利用できません）
+minigame_trade-not_participant = このトレードの2人のメンバーだけが操作できます。
+minigame_trade-offer_desc =
+    下から提示するアイテムを選び、ボタンでコインを設定してください。
+    提示コイン: **{ $coins }**
+minigame_trade-offer_header = **{ $user }** — { $state }
+minigame_trade-offer_title = あなたの提示
+minigame_trade-offer_updated = 提示を更新しました。
+minigame_trade-open = 2人ともアイテムやコインを追加し、両方が確定します。変更があると確定は取り消されます。期限: { $expires }
+minigame_trade-pending = 未確定
+minigame_trade-pick_cards = 提示するキャラクター
+minigame_trade-pick_items = 提示するアイテム
+minigame_trade-reset_coins = コインをリセット
+minigame_trade-stale = 確認した後に提示内容が変わりました。確定する前にもう一度確認してください。
+minigame_trade-title = トレード
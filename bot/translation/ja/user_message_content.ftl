@@ -0,0 +1,3 @@
+user_message_content-off = あなたのメッセージ内容は保存されず、これまでに保存された内容も削除されました。メッセージの長さ・チャンネル・時刻のみが保持されます。
+user_message_content-on = 許可しているサーバーでは、あなたのメッセージ内容が保存されます。
+user_message_content-title = メッセージ内容
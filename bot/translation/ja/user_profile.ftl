@@ -1,8 +1,10 @@
+user_profile-achievements = 実績（{ $count }/{ $total }）
 user_profile-bot = ボットです
 user_profile-creation_date = アカウントの作成日
 user_profile-id = ユーザーID
 user_profile-joined_date = 参加日
 user_profile-nitro = Nitroの種類
+user_profile-no_achievements = まだ実績を解除していません。
 user_profile-premium = プレミアム
 user_profile-public_flag = 公開フラグ
 user_profile-system = システムです
@@ -0,0 +1,344 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::sea_query::{OnConflict, Query};
+use sea_orm::ActiveValue::Set;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QuerySelect};
+use serenity::all::UserId;
+use serenity::builder::{
+	CreateActionRow, CreateButton, CreateComponent, CreateEmbed, CreateMessage,
+};
+use serenity::http::Http;
+use shared::anilist::airing_dm::{
+	is_quiet, refresh_next_episodes, sync_list_follows, take_dm_slot, AiringDmAction,
+	SNOOZE_BUTTON_HOURS,
+};
+use shared::anilist::episode_button::episode_button_id;
+use shared::cache::CacheInterface;
+use shared::database::prelude::{
+	AiringDmSetting, AiringEpisode, AiringSubscription, RegisteredUser,
+};
+use shared::database::{airing_dm_setting, airing_episode, airing_subscription, registered_user};
+use shared::localization::{
+	get_language_identifier, FluentValue, LanguageIdentifier, Loader, USABLE_LOCALES,
+};
+use tokio::sync::RwLock;
+use tokio::time::sleep;
+use tracing::{error, info, trace, warn};
+
+/// Episodes per DM, each gets a row of buttons and the last row holds the
+/// snooze and mute buttons.
+const EPISODES_PER_DM: usize = 4;
+/// Pause between two DMs, Discord limits how fast DM channels are opened.
+const DM_PACING: Duration = Duration::from_millis(250);
+/// Episodes that aired this long ago without being moved forward, while the
+/// worker was down for example, are fetched again by the sync.
+const STALE_AFTER_HOURS: i64 = 1;
+
+/// Sends the DMs of the followed episodes that aired since the last poll.
+/// Runs on the activity task tick, like the guild webhook notifications.
+pub async fn manage_airing_dms(
+	http: Arc<Http>, anilist_cache: Arc<RwLock<CacheInterface>>,
+	db_connection: Arc<DatabaseConnection>,
+) {
+	// On first run, we start from "now" so we don't replay old episodes.
+	use std::sync::OnceLock;
+	static LAST_CHECK: OnceLock<tokio::sync::Mutex<NaiveDateTime>> = OnceLock::new();
+
+	let now = Utc::now().naive_utc();
+	let last_check_mutex = LAST_CHECK.get_or_init(|| tokio::sync::Mutex::new(now));
+	let mut last_check = last_check_mutex.lock().await;
+
+	let aired = match AiringEpisode::find()
+		.filter(airing_episode::Column::AiringAt.gt(*last_check))
+		.filter(airing_episode::Column::AiringAt.lte(now))
+		.all(&*db_connection)
+		.await
+	{
+		Ok(aired) => aired,
+		Err(e) => {
+			error!("Failed to query airing episodes: {}", e);
+			return;
+		},
+	};
+
+	*last_check = now;
+	drop(last_check);
+
+	if aired.is_empty() {
+		return;
+	}
+	info!("Found {} aired episodes to notify", aired.len());
+
+	tokio::spawn(async move {
+		if let Err(e) = notify_aired(&http, aired, anilist_cache, db_connection).await {
+			error!("Failed to send airing DMs: {:#}", e);
+		}
+	});
+}
+
+async fn notify_aired(
+	http: &Arc<Http>, aired: Vec<airing_episode::Model>,
+	anilist_cache: Arc<RwLock<CacheInterface>>, db_connection: Arc<DatabaseConnection>,
+) -> Result<()> {
+	let anime_ids: Vec<i32> = aired.iter().map(|episode| episode.anime_id).collect();
+
+	let subscriptions = AiringSubscription::find()
+		.filter(airing_subscription::Column::AnimeId.is_in(anime_ids.clone()))
+		.filter(airing_subscription::Column::Muted.eq(false))
+		.all(&*db_connection)
+		.await
+		.context("Failed to load airing subscriptions")?;
+
+	let user_ids: HashSet<String> = subscriptions
+		.iter()
+		.map(|subscription| subscription.user_id.clone())
+		.collect();
+	let settings: HashMap<String, airing_dm_setting::Model> = AiringDmSetting::find()
+		.filter(airing_dm_setting::Column::UserId.is_in(user_ids))
+		.all(&*db_connection)
+		.await
+		.context("Failed to load airing DM settings")?
+		.into_iter()
+		.map(|setting| (setting.user_id.clone(), setting))
+		.collect();
+
+	let episodes: HashMap<i32, &airing_episode::Model> = aired
+		.iter()
+		.map(|episode| (episode.anime_id, episode))
+		.collect();
+	let mut by_user: BTreeMap<String, Vec<&airing_episode::Model>> = BTreeMap::new();
+	for subscription in &subscriptions {
+		if let Some(episode) = episodes.get(&subscription.anime_id) {
+			by_user
+				.entry(subscription.user_id.clone())
+				.or_default()
+				.push(episode);
+		}
+	}
+
+	// DMs are not sent from a guild, so they use the default language
+	let lang_id = get_language_identifier(String::from("0"), db_connection.clone()).await;
+	let now = Utc::now().naive_utc();
+
+	for (user_id, episodes) in by_user {
+		let setting = settings.get(&user_id);
+		if is_quiet(setting, now) {
+			continue;
+		}
+
+		let mut window = (
+			setting.and_then(|setting| setting.window_start),
+			setting.map(|setting| setting.window_count).unwrap_or(0),
+		);
+		for chunk in episodes.chunks(EPISODES_PER_DM) {
+			let Some((window_start, window_count)) = take_dm_slot(window.0, window.1, now) else {
+				trace!("Airing DMs of user {} are rate limited", user_id);
+				break;
+			};
+			window = (Some(window_start), window_count);
+
+			if let Err(e) = send_airing_dm(http, &user_id, chunk, &lang_id).await {
+				warn!("Failed to send an airing DM to user {}: {:#}", user_id, e);
+			}
+			sleep(DM_PACING).await;
+		}
+
+		if let Err(e) = save_window(&db_connection, &user_id, window).await {
+			warn!("Failed to save the DM window of user {}: {:#}", user_id, e);
+		}
+	}
+
+	refresh_next_episodes(&db_connection, &anime_ids, anilist_cache)
+		.await
+		.context("Failed to move the aired episodes forward")
+}
+
+async fn send_airing_dm(
+	http: &Arc<Http>, user_id: &str, episodes: &[&airing_episode::Model],
+	lang_id: &LanguageIdentifier,
+) -> Result<()> {
+	let user_id = UserId::new(user_id.parse()?);
+
+	let mut embeds = Vec::new();
+	let mut components = Vec::new();
+	for episode in episodes {
+		let mut args = HashMap::new();
+		args.insert(Cow::Borrowed("episode"), FluentValue::from(episode.episode));
+		args.insert(
+			Cow::Borrowed("anime"),
+			FluentValue::from(episode.name.clone()),
+		);
+
+		embeds.push(
+			CreateEmbed::new()
+				.title(episode.name.chars().take(256).collect::<String>())
+				.url(format!("https://anilist.co/anime/{}", episode.anime_id))
+				.description(USABLE_LOCALES.lookup_with_args(
+					lang_id,
+					"anilist_airing-dm_desc",
+					&args,
+				)),
+		);
+
+		// Members with a linked AniList account can mark the episode as
+		// watched, like on the guild notifications
+		let watched = CreateButton::new(episode_button_id(episode.anime_id, episode.episode))
+			.label(USABLE_LOCALES.lookup(lang_id, "anilist_user_send_activity-episode_button"));
+		let unfollow = CreateButton::new(AiringDmAction::Unfollow(episode.anime_id).custom_id())
+			.label(
+				USABLE_LOCALES
+					.lookup_with_args(lang_id, "anilist_airing-unfollow_button", &args)
+					.chars()
+					.take(80)
+					.collect::<String>(),
+			);
+		components.push(CreateComponent::ActionRow(CreateActionRow::Buttons(
+			Cow::Owned(vec![watched, unfollow]),
+		)));
+	}
+
+	let mut args = HashMap::new();
+	args.insert(
+		Cow::Borrowed("hours"),
+		FluentValue::from(SNOOZE_BUTTON_HOURS),
+	);
+	let snooze = CreateButton::new(AiringDmAction::Snooze.custom_id())
+		.label(USABLE_LOCALES.lookup_with_args(lang_id, "anilist_airing-snooze_button", &args));
+	let mute = CreateButton::new(AiringDmAction::Mute.custom_id())
+		.label(USABLE_LOCALES.lookup(lang_id, "anilist_airing-mute_button"));
+	components.push(CreateComponent::ActionRow(CreateActionRow::Buttons(
+		Cow::Owned(vec![snooze, mute]),
+	)));
+
+	let message = CreateMessage::new().embeds(embeds).components(components);
+	user_id.direct_message(http, message).await?;
+
+	Ok(())
+}
+
+async fn save_window(
+	db_connection: &DatabaseConnection, user_id: &str, window: (Option<NaiveDateTime>, i32),
+) -> Result<()> {
+	AiringDmSetting::insert(airing_dm_setting::ActiveModel {
+		user_id: Set(user_id.to_string()),
+		auto_follow: Set(false),
+		muted: Set(false),
+		snoozed_until: Set(None),
+		window_start: Set(window.0),
+		window_count: Set(window.1),
+	})
+	.on_conflict(
+		OnConflict::column(airing_dm_setting::Column::UserId)
+			.update_columns([
+				airing_dm_setting::Column::WindowStart,
+				airing_dm_setting::Column::WindowCount,
+			])
+			.to_owned(),
+	)
+	.exec(db_connection)
+	.await?;
+
+	Ok(())
+}
+
+/// Follows the CURRENT lists of members with auto-follow, then fixes the
+/// episodes the DMs missed, tracks again the followed anime that had nothing
+/// scheduled and forgets the anime nobody follows anymore.
+pub async fn sync_airing_follows(
+	anilist_cache: Arc<RwLock<CacheInterface>>, db: &DatabaseConnection,
+) -> Result<usize> {
+	let user_ids: Vec<String> = AiringDmSetting::find()
+		.select_only()
+		.column(airing_dm_setting::Column::UserId)
+		.filter(airing_dm_setting::Column::AutoFollow.eq(true))
+		.into_tuple()
+		.all(db)
+		.await
+		.context("Failed to load members with auto-follow")?;
+
+	// Members who unlinked their account keep the anime the list followed
+	// until they link one again
+	let accounts: Vec<(String, i32)> = RegisteredUser::find()
+		.select_only()
+		.column(registered_user::Column::UserId)
+		.column(registered_user::Column::AnilistId)
+		.filter(registered_user::Column::UserId.is_in(user_ids))
+		.into_tuple()
+		.all(db)
+		.await
+		.context("Failed to load linked AniList accounts")?;
+
+	let mut synced = 0;
+	for (index, (user_id, anilist_id)) in accounts.iter().enumerate() {
+		if index > 0 {
+			// Avoid API rate limiting
+			sleep(Duration::from_secs(2)).await;
+		}
+
+		match sync_list_follows(db, user_id, *anilist_id, anilist_cache.clone()).await {
+			Ok(_) => synced += 1,
+			Err(e) => warn!(
+				"Failed to sync the airing follows of user {}: {:#}",
+				user_id, e
+			),
+		}
+	}
+
+	let stale_before = Utc::now().naive_utc() - chrono::Duration::hours(STALE_AFTER_HOURS);
+	let stale: Vec<i32> = AiringEpisode::find()
+		.select_only()
+		.column(airing_episode::Column::AnimeId)
+		.filter(airing_episode::Column::AiringAt.lt(stale_before))
+		.into_tuple()
+		.all(db)
+		.await
+		.context("Failed to load stale airing episodes")?;
+	if !stale.is_empty() {
+		info!("Refreshing {} stale airing episodes", stale.len());
+		refresh_next_episodes(db, &stale, anilist_cache.clone()).await?;
+	}
+
+	// An anime on hiatus loses its episode row, nothing else brings it back
+	// for members who followed it themselves once it resumes
+	let untracked: Vec<i32> = AiringSubscription::find()
+		.select_only()
+		.column(airing_subscription::Column::AnimeId)
+		.distinct()
+		.filter(airing_subscription::Column::Muted.eq(false))
+		.filter(
+			airing_subscription::Column::AnimeId.not_in_subquery(
+				Query::select()
+					.column(airing_episode::Column::AnimeId)
+					.from(AiringEpisode)
+					.to_owned(),
+			),
+		)
+		.into_tuple()
+		.all(db)
+		.await
+		.context("Failed to load untracked followed anime")?;
+	if !untracked.is_empty() {
+		info!("Tracking {} followed anime again", untracked.len());
+		refresh_next_episodes(db, &untracked, anilist_cache).await?;
+	}
+
+	AiringEpisode::delete_many()
+		.filter(
+			airing_episode::Column::AnimeId.not_in_subquery(
+				Query::select()
+					.column(airing_subscription::Column::AnimeId)
+					.from(AiringSubscription)
+					.to_owned(),
+			),
+		)
+		.exec(db)
+		.await
+		.context("Failed to forget unfollowed anime")?;
+
+	Ok(synced)
+}
@@ -9,9 +9,12 @@ use base64::Engine;
 use chrono::{DateTime, NaiveDateTime, Utc};
 use sea_orm::ActiveValue::Set;
 use sea_orm::{ColumnTrait, DatabaseConnection, DeleteResult, EntityTrait, QueryFilter};
-use serenity::builder::{CreateAttachment, EditWebhook, ExecuteWebhook};
+use serenity::builder::{
+	CreateActionRow, CreateAttachment, CreateButton, CreateComponent, EditWebhook, ExecuteWebhook,
+};
 use serenity::http::Http;
 use serenity::model::webhook::Webhook;
+use shared::anilist::episode_button::episode_button_id;
 use shared::anilist::minimal_anime::get_minimal_anime_media;
 use shared::cache::CacheInterface;
 use shared::database::activity_data;
@@ -121,7 +124,19 @@ async fn send_specific_activity(
 		.url(format!("https://anilist.co/anime/{}", row.anime_id))
 		.title(title);
 
-	let builder_message = ExecuteWebhook::new().embed(embed);
+	// Members with a linked AniList account can mark the episode as watched,
+	// the bot answers the others with how to link one
+	let button = CreateButton::new(episode_button_id(row.anime_id, row.episode))
+		.label(USABLE_LOCALES.lookup(&lang_id, "anilist_user_send_activity-episode_button"));
+	let components = vec![CreateComponent::ActionRow(CreateActionRow::Buttons(
+		Cow::Owned(vec![button]),
+	))];
+
+	// The webhook belongs to the bot, so it may send interactive components
+	let builder_message = ExecuteWebhook::new()
+		.embed(embed)
+		.components(components)
+		.with_components(true);
 	webhook.execute(http, false, builder_message).await?;
 
 	let row_clone = row.clone();
@@ -1 +1,2 @@
+pub mod airing_dm;
 pub mod anime_activity;
@@ -0,0 +1,105 @@
+use anyhow::{Context, Result};
+use std::collections::HashSet;
+use std::time::Duration;
+
+use chrono::Utc;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+	ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QuerySelect,
+};
+use shared::anilist::user_stats::{fetch_user_stats, USER_BATCH};
+use shared::database::anilist_user_stats;
+use shared::database::prelude::{AnilistUserStats, RegisteredUser};
+use shared::database::registered_user;
+use tokio::time::sleep;
+use tracing::{info, warn};
+
+/// Refresh the stored AniList statistics of every registered account.
+///
+/// The server leaderboards and the registered user list only read this
+/// table, so a command never waits on AniList. Accounts that are no longer
+/// registered are dropped, a batch that fails keeps its previous stats.
+pub async fn refresh_anilist_stats(db: &DatabaseConnection) -> Result<usize> {
+	let anilist_ids: Vec<i32> = RegisteredUser::find()
+		.select_only()
+		.column(registered_user::Column::AnilistId)
+		.into_tuple()
+		.all(db)
+		.await
+		.context("Failed to load registered AniList accounts")?;
+
+	let mut refreshed = 0;
+
+	for (index, ids) in anilist_ids.chunks(USER_BATCH).enumerate() {
+		if index > 0 {
+			// Avoid API rate limiting
+			sleep(Duration::from_secs(2)).await;
+		}
+
+		let summaries = match fetch_user_stats(ids).await {
+			Ok(summaries) => summaries,
+			Err(e) => {
+				warn!("AniList stats batch {} failed: {:#}", index, e);
+				continue;
+			},
+		};
+
+		let now = Utc::now().naive_utc();
+		let rows: Vec<anilist_user_stats::ActiveModel> = summaries
+			.into_iter()
+			.map(|summary| anilist_user_stats::ActiveModel {
+				anilist_id: Set(summary.anilist_id),
+				name: Set(summary.name),
+				minutes_watched: Set(summary.minutes_watched),
+				chapters_read: Set(summary.chapters_read),
+				mean_score: Set(summary.mean_score),
+				completed: Set(summary.completed),
+				xp: Set(summary.xp),
+				updated_at: Set(now),
+			})
+			.collect();
+
+		refreshed += rows.len();
+		if !rows.is_empty() {
+			AnilistUserStats::insert_many(rows)
+				.on_conflict(
+					OnConflict::column(anilist_user_stats::Column::AnilistId)
+						.update_columns([
+							anilist_user_stats::Column::Name,
+							anilist_user_stats::Column::MinutesWatched,
+							anilist_user_stats::Column::ChaptersRead,
+							anilist_user_stats::Column::MeanScore,
+							anilist_user_stats::Column::Completed,
+							anilist_user_stats::Column::Xp,
+							anilist_user_stats::Column::UpdatedAt,
+						])
+						.to_owned(),
+				)
+				.exec_without_returning(db)
+				.await
+				.context("Failed to save AniList stats batch")?;
+		}
+	}
+
+	let registered: HashSet<i32> = anilist_ids.into_iter().collect();
+	let stored: Vec<i32> = AnilistUserStats::find()
+		.select_only()
+		.column(anilist_user_stats::Column::AnilistId)
+		.into_tuple()
+		.all(db)
+		.await?;
+	let unregistered: Vec<i32> = stored
+		.into_iter()
+		.filter(|anilist_id| !registered.contains(anilist_id))
+		.collect();
+	if !unregistered.is_empty() {
+		AnilistUserStats::delete_many()
+			.filter(anilist_user_stats::Column::AnilistId.is_in(unregistered))
+			.exec(db)
+			.await?;
+	}
+
+	info!("AniList stats refreshed for {} accounts", refreshed);
+
+	Ok(refreshed)
+}
@@ -0,0 +1,131 @@
+use anyhow::{Context, Result};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use cynic::{GraphQlResponse, QueryBuilder};
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ActiveValue::Set, DatabaseConnection, EntityTrait};
+use shared::anilist::character_pool::{CharacterPool, CharacterPoolVariables};
+use shared::anilist::make_request::make_request_anilist;
+use shared::cache::CacheInterface;
+use shared::database::character_pool;
+use shared::database::prelude::CharacterPool as CharacterPoolEntity;
+use tokio::sync::RwLock;
+use tokio::time::sleep;
+use tracing::{info, warn};
+
+/// Characters fetched per refresh, the most favourited first.
+const POOL_PAGES: i32 = 20;
+const PER_PAGE: i32 = 50;
+
+/// Favourites needed for rarities 5 down to 2, everything below is 1.
+const RARITY_FAVOURITES: [(i32, i32); 4] = [(5, 40_000), (4, 15_000), (3, 6_000), (2, 2_500)];
+
+fn rarity_for(favourites: i32) -> i32 {
+	RARITY_FAVOURITES
+		.iter()
+		.find(|(_, threshold)| favourites >= *threshold)
+		.map(|(rarity, _)| *rarity)
+		.unwrap_or(1)
+}
+
+/// Refresh the gacha pool with the most favourited AniList characters.
+///
+/// Characters are upserted and never removed: cards already rolled keep
+/// pointing at them even once they fall out of the fetched range, and they
+/// stay rollable with their last known rarity.
+pub async fn refresh_character_pool(
+	anilist_cache: Arc<RwLock<CacheInterface>>, db: &DatabaseConnection,
+) -> Result<usize> {
+	let mut refreshed = 0;
+
+	for page in 1..=POOL_PAGES {
+		let operation = CharacterPool::build(CharacterPoolVariables {
+			page: Some(page),
+			per_page: Some(PER_PAGE),
+		});
+		let data: GraphQlResponse<CharacterPool> =
+			match make_request_anilist(operation, true, anilist_cache.clone()).await {
+				Ok(data) => data,
+				Err(e) => {
+					warn!("Character pool page {} failed: {:#}", page, e);
+					continue;
+				},
+			};
+
+		let Some(page_data) = data.data.and_then(|data| data.page) else {
+			warn!("Character pool page {} returned no data", page);
+			continue;
+		};
+		let has_next = page_data
+			.page_info
+			.and_then(|info| info.has_next_page)
+			.unwrap_or(false);
+
+		let now = Utc::now().naive_utc();
+		let characters: Vec<character_pool::ActiveModel> = page_data
+			.characters
+			.unwrap_or_default()
+			.into_iter()
+			.flatten()
+			.filter_map(|character| {
+				let name = character.name?;
+				let favourites = character.favourites.unwrap_or_default();
+				let series = character
+					.media
+					.and_then(|media| media.nodes)
+					.and_then(|nodes| nodes.into_iter().flatten().next())
+					.and_then(|media| media.title)
+					.and_then(|title| title.user_preferred);
+
+				Some(character_pool::ActiveModel {
+					character_id: Set(character.id),
+					name: Set(name.user_preferred?),
+					native_name: Set(name.native),
+					series: Set(series),
+					image_url: Set(character.image.and_then(|image| image.large)),
+					site_url: Set(character.site_url.unwrap_or_else(|| {
+						format!("https://anilist.co/character/{}", character.id)
+					})),
+					favourites: Set(favourites),
+					rarity: Set(rarity_for(favourites)),
+					updated_at: Set(now),
+				})
+			})
+			.collect();
+
+		refreshed += characters.len();
+		if !characters.is_empty() {
+			CharacterPoolEntity::insert_many(characters)
+				.on_conflict(
+					OnConflict::column(character_pool::Column::CharacterId)
+						.update_columns([
+							character_pool::Column::Name,
+							character_pool::Column::NativeName,
+							character_pool::Column::Series,
+							character_pool::Column::ImageUrl,
+							character_pool::Column::SiteUrl,
+							character_pool::Column::Favourites,
+							character_pool::Column::Rarity,
+							character_pool::Column::UpdatedAt,
+						])
+						.to_owned(),
+				)
+				.exec_without_returning(db)
+				.await
+				.context("Failed to save character pool page")?;
+		}
+
+		if !has_next {
+			break;
+		}
+
+		// Avoid API rate limiting
+		sleep(Duration::from_secs(1)).await;
+	}
+
+	info!("Character pool refreshed with {} characters", refreshed);
+
+	Ok(refreshed)
+}
@@ -1,5 +1,9 @@
 mod activity;
+mod anilist_stats;
+mod character_pool;
 mod get_anisong_db;
+mod purge_messages;
+mod stats_rollup;
 mod update_random_stats;
 
 use anyhow::{Context, Result};
@@ -15,8 +19,13 @@ use tracing::{error, info, warn, Level};
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 
+use crate::activity::airing_dm::{manage_airing_dms, sync_airing_follows};
 use crate::activity::anime_activity::manage_activity;
+use crate::anilist_stats::refresh_anilist_stats;
+use crate::character_pool::refresh_character_pool;
 use crate::get_anisong_db::get_anisong;
+use crate::purge_messages::purge_messages;
+use crate::stats_rollup::rollup_stats;
 use crate::update_random_stats::update_random_stats_launcher;
 
 #[tokio::main]
@@ -138,6 +147,136 @@ async fn main() -> Result<()> {
 						db_clone.clone(),
 					)
 					.await;
+					manage_airing_dms(
+						http_clone.clone(),
+						cache_clone.clone(),
+						db_clone.clone(),
+					)
+					.await;
+				}
+			}
+		}
+	});
+
+	// Spawn Message Retention Task
+	let mut shutdown_rx = shutdown_tx.subscribe();
+	let db_clone = connection.clone();
+	let intervals_clone = task_intervals.clone();
+	let retention_days = config.message_tracking.retention_days;
+	let purge_handle = tokio::spawn(async move {
+		info!("Launching message retention task");
+		let mut interval =
+			tokio::time::interval(Duration::from_secs(intervals_clone.message_purge));
+
+		loop {
+			tokio::select! {
+				_ = shutdown_rx.recv() => {
+					info!("Message retention task received shutdown signal");
+					break;
+				}
+				_ = interval.tick() => {
+					if let Err(e) = purge_messages(&db_clone, retention_days).await {
+						error!("Message purge failed: {:#}", e);
+					}
+				}
+			}
+		}
+	});
+
+	// Spawn Stats Rollup Task
+	let mut shutdown_rx = shutdown_tx.subscribe();
+	let db_clone = connection.clone();
+	let intervals_clone = task_intervals.clone();
+	let rollup_handle = tokio::spawn(async move {
+		info!("Launching stats rollup task");
+		let mut interval =
+			tokio::time::interval(Duration::from_secs(intervals_clone.stats_rollup));
+
+		loop {
+			tokio::select! {
+				_ = shutdown_rx.recv() => {
+					info!("Stats rollup task received shutdown signal");
+					break;
+				}
+				_ = interval.tick() => {
+					if let Err(e) = rollup_stats(&db_clone).await {
+						error!("Stats rollup failed: {:#}", e);
+					}
+				}
+			}
+		}
+	});
+
+	// Spawn Character Pool Refresh Task
+	let mut shutdown_rx = shutdown_tx.subscribe();
+	let cache_clone = anilist_cache.clone();
+	let db_clone = connection.clone();
+	let intervals_clone = task_intervals.clone();
+	let character_pool_handle = tokio::spawn(async move {
+		info!("Launching character pool refresh task");
+		let mut interval =
+			tokio::time::interval(Duration::from_secs(intervals_clone.character_pool_update));
+
+		loop {
+			tokio::select! {
+				_ = shutdown_rx.recv() => {
+					info!("Character pool task received shutdown signal");
+					break;
+				}
+				_ = interval.tick() => {
+					if let Err(e) = refresh_character_pool(cache_clone.clone(), &db_clone).await {
+						error!("Character pool refresh failed: {:#}", e);
+					}
+				}
+			}
+		}
+	});
+
+	// Spawn AniList Stats Refresh Task
+	let mut shutdown_rx = shutdown_tx.subscribe();
+	let db_clone = connection.clone();
+	let intervals_clone = task_intervals.clone();
+	let anilist_stats_handle = tokio::spawn(async move {
+		info!("Launching AniList stats refresh task");
+		let mut interval =
+			tokio::time::interval(Duration::from_secs(intervals_clone.anilist_stats_update));
+
+		loop {
+			tokio::select! {
+				_ = shutdown_rx.recv() => {
+					info!("AniList stats task received shutdown signal");
+					break;
+				}
+				_ = interval.tick() => {
+					if let Err(e) = refresh_anilist_stats(&db_clone).await {
+						error!("AniList stats refresh failed: {:#}", e);
+					}
+				}
+			}
+		}
+	});
+
+	// Spawn Airing Follow Sync Task
+	let mut shutdown_rx = shutdown_tx.subscribe();
+	let cache_clone = anilist_cache.clone();
+	let db_clone = connection.clone();
+	let intervals_clone = task_intervals.clone();
+	let airing_follow_handle = tokio::spawn(async move {
+		info!("Launching airing follow sync task");
+		let mut interval =
+			tokio::time::interval(Duration::from_secs(intervals_clone.airing_follow_sync));
+
+		loop {
+			tokio::select! {
+				_ = shutdown_rx.recv() => {
+					info!("Airing follow task received shutdown signal");
+					break;
+				}
+				_ = interval.tick() => {
+					match sync_airing_follows(cache_clone.clone(), &db_clone).await {
+						Ok(count) => info!("Airing follows synced for {} members", count),
+						Err(e) => error!("Airing follow sync failed: {:#}", e),
+					}
 				}
 			}
 		}
@@ -156,7 +295,16 @@ async fn main() -> Result<()> {
 	// Give tasks time to finish current work
 	let timeout = Duration::from_secs(10);
 	let _ = tokio::time::timeout(timeout, async {
-		let _ = tokio::join!(anisong_handle, stats_handle, activity_handle);
+		let _ = tokio::join!(
+			anisong_handle,
+			stats_handle,
+			activity_handle,
+			purge_handle,
+			rollup_handle,
+			character_pool_handle,
+			anilist_stats_handle,
+			airing_follow_handle
+		);
 	})
 	.await;
 
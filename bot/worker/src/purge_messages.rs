@@ -0,0 +1,30 @@
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use shared::database::message;
+use shared::database::prelude::Message;
+use tracing::{debug, info};
+
+/// Delete tracked messages older than the retention window.
+/// Returns the number of rows removed. A window of 0 days disables the purge,
+/// it would otherwise wipe every stored message.
+pub async fn purge_messages(db: &DatabaseConnection, retention_days: u32) -> Result<u64> {
+	if retention_days == 0 {
+		debug!("Message purge disabled, retention_days is 0");
+		return Ok(0);
+	}
+
+	let cutoff = Utc::now().naive_utc() - Duration::days(retention_days as i64);
+
+	let result = Message::delete_many()
+		.filter(message::Column::CreatedAt.lt(cutoff))
+		.exec(db)
+		.await?;
+
+	info!(
+		"Purged {} messages older than {} days",
+		result.rows_affected, retention_days
+	);
+
+	Ok(result.rows_affected)
+}
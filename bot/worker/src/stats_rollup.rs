@@ -0,0 +1,211 @@
+use anyhow::Result;
+use chrono::NaiveDate;
+use sea_orm::prelude::Expr;
+use sea_orm::ActiveValue::Set;
+use sea_orm::{
+	ColumnTrait, DatabaseConnection, EntityTrait, FromQueryResult, QueryFilter, QueryOrder,
+	QuerySelect, TransactionTrait,
+};
+use shared::database::prelude::{Message, MessageDailyStats, Vocal, VocalDailyStats};
+use shared::database::{message, message_daily_stats, vocal, vocal_daily_stats};
+use tracing::{info, warn};
+
+// Cast to text so Postgres and SQLite return the day in the same shape
+const MESSAGE_DAY_EXPR: &str = "CAST(DATE(created_at) AS TEXT)";
+// A vocal row is written when its session closes, possibly long after it
+// started, so it goes to the day it was recorded. Bucketing on its start would
+// put it in a day that may already be rolled up, and it would never be counted
+const VOCAL_DAY_EXPR: &str = "CAST(DATE(recorded_at) AS TEXT)";
+// Old vocal rows have no guild, rollups key them under an empty one
+const GUILD_EXPR: &str = "COALESCE(guild_id, '')";
+
+// Keeps each INSERT well under the SQLite bound-variable limit
+const INSERT_CHUNK: usize = 500;
+
+#[derive(Debug, FromQueryResult)]
+struct MessageDayRow {
+	guild_id: String,
+	user_id: String,
+	channel_id: String,
+	day: String,
+	message_count: i64,
+	total_length: i64,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct VocalDayRow {
+	guild_id: String,
+	user_id: String,
+	channel_id: String,
+	day: String,
+	session_count: i64,
+	duration_secs: i64,
+	idle_secs: i64,
+}
+
+/// Fold raw message and vocal rows into the per-day rollup tables.
+///
+/// Each run rebuilds the rollups from the last day already present (that day
+/// may have been partial) up to now, so the job is idempotent and catches up
+/// after downtime. Days before it are never touched again, which keeps their
+/// totals once the raw rows are purged.
+pub async fn rollup_stats(db: &DatabaseConnection) -> Result<()> {
+	let messages = rollup_messages(db).await?;
+	let vocal = rollup_vocal(db).await?;
+
+	info!(
+		"Rolled up {} message days and {} vocal days",
+		messages, vocal
+	);
+
+	Ok(())
+}
+
+async fn rollup_messages(db: &DatabaseConnection) -> Result<usize> {
+	let from = MessageDailyStats::find()
+		.order_by_desc(message_daily_stats::Column::Day)
+		.one(db)
+		.await?
+		.map(|row| row.day);
+
+	let mut select = Message::find()
+		.select_only()
+		.column_as(Expr::cust(GUILD_EXPR), "guild_id")
+		.column(message::Column::UserId)
+		.column(message::Column::ChannelId)
+		.column_as(Expr::cust(MESSAGE_DAY_EXPR), "day")
+		.column_as(message::Column::Id.count(), "message_count")
+		.column_as(
+			Expr::cust("CAST(COALESCE(SUM(chat_length), 0) AS BIGINT)"),
+			"total_length",
+		);
+
+	if let Some(from) = from {
+		select = select.filter(message::Column::CreatedAt.gte(from.and_time(Default::default())));
+	}
+
+	let rows = select
+		.group_by(Expr::cust(GUILD_EXPR))
+		.group_by(message::Column::UserId)
+		.group_by(message::Column::ChannelId)
+		.group_by(Expr::cust(MESSAGE_DAY_EXPR))
+		.into_model::<MessageDayRow>()
+		.all(db)
+		.await?;
+
+	let models: Vec<message_daily_stats::ActiveModel> = rows
+		.into_iter()
+		.filter_map(|row| {
+			let day = parse_day(&row.day)?;
+			Some(message_daily_stats::ActiveModel {
+				guild_id: Set(row.guild_id),
+				user_id: Set(row.user_id),
+				channel_id: Set(row.channel_id),
+				day: Set(day),
+				message_count: Set(row.message_count),
+				total_length: Set(row.total_length),
+			})
+		})
+		.collect();
+	let count = models.len();
+
+	let txn = db.begin().await?;
+
+	let mut delete = MessageDailyStats::delete_many();
+	if let Some(from) = from {
+		delete = delete.filter(message_daily_stats::Column::Day.gte(from));
+	}
+	delete.exec(&txn).await?;
+
+	for chunk in models.chunks(INSERT_CHUNK) {
+		MessageDailyStats::insert_many(chunk.to_vec())
+			.exec(&txn)
+			.await?;
+	}
+
+	txn.commit().await?;
+
+	Ok(count)
+}
+
+async fn rollup_vocal(db: &DatabaseConnection) -> Result<usize> {
+	let from = VocalDailyStats::find()
+		.order_by_desc(vocal_daily_stats::Column::Day)
+		.one(db)
+		.await?
+		.map(|row| row.day);
+
+	let mut select = Vocal::find()
+		.select_only()
+		.column_as(Expr::cust(GUILD_EXPR), "guild_id")
+		.column(vocal::Column::UserId)
+		.column(vocal::Column::ChannelId)
+		.column_as(Expr::cust(VOCAL_DAY_EXPR), "day")
+		.column_as(vocal::Column::Id.count(), "session_count")
+		.column_as(
+			Expr::cust("CAST(COALESCE(SUM(duration), 0) AS BIGINT)"),
+			"duration_secs",
+		)
+		.column_as(
+			Expr::cust("CAST(COALESCE(SUM(idle_secs), 0) AS BIGINT)"),
+			"idle_secs",
+		);
+
+	if let Some(from) = from {
+		select = select.filter(vocal::Column::RecordedAt.gte(from.and_time(Default::default())));
+	}
+
+	let rows = select
+		.group_by(Expr::cust(GUILD_EXPR))
+		.group_by(vocal::Column::UserId)
+		.group_by(vocal::Column::ChannelId)
+		.group_by(Expr::cust(VOCAL_DAY_EXPR))
+		.into_model::<VocalDayRow>()
+		.all(db)
+		.await?;
+
+	let models: Vec<vocal_daily_stats::ActiveModel> = rows
+		.into_iter()
+		.filter_map(|row| {
+			let day = parse_day(&row.day)?;
+			Some(vocal_daily_stats::ActiveModel {
+				guild_id: Set(row.guild_id),
+				user_id: Set(row.user_id),
+				channel_id: Set(row.channel_id),
+				day: Set(day),
+				session_count: Set(row.session_count),
+				duration_secs: Set(row.duration_secs),
+				idle_secs: Set(row.idle_secs),
+			})
+		})
+		.collect();
+	let count = models.len();
+
+	let txn = db.begin().await?;
+
+	let mut delete = VocalDailyStats::delete_many();
+	if let Some(from) = from {
+		delete = delete.filter(vocal_daily_stats::Column::Day.gte(from));
+	}
+	delete.exec(&txn).await?;
+
+	for chunk in models.chunks(INSERT_CHUNK) {
+		VocalDailyStats::insert_many(chunk.to_vec())
+			.exec(&txn)
+			.await?;
+	}
+
+	txn.commit().await?;
+
+	Ok(count)
+}
+
+fn parse_day(day: &str) -> Option<NaiveDate> {
+	match NaiveDate::parse_from_str(day, "%Y-%m-%d") {
+		Ok(day) => Some(day),
+		Err(e) => {
+			warn!("Skipping rollup row with unparsable day {:?}: {}", day, e);
+			None
+		},
+	}
+}